@@ -1,6 +1,8 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Map, String, Symbol};
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, token, Address, Env, Map, String, Symbol,
+};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -14,6 +16,57 @@ pub struct Dispute {
     pub outcome: u32, // 0 for unresolved/tie, >0 for specific outcomes
 }
 
+/// Complete arbitration configuration, for diffing deployments (see `export_config`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitrationConfig {
+    pub admin: Option<Address>,
+    pub pending_admin: Option<Address>,
+    pub schema_version: u32,
+    pub features: u32,
+    pub stake_token: Option<Address>,
+    pub quorum_bps: u32,
+    pub deposit_cooldown: u64,
+}
+
+/// Aggregate counters maintained as disputes progress (see `get_metrics`).
+///
+/// # Fields
+/// * `total_disputes` - Disputes ever created, including escalated ones.
+/// * `resolved` - Disputes resolved, including ties.
+/// * `ties` - Resolutions without a winning outcome (tie or no votes). Disputes never
+///   expire in this contract; unresolved ones stay open until `resolve_dispute`.
+/// * `votes_cast` - Accepted votes.
+/// * `total_resolution_time` - Sum of `resolved_at - voting_start` over resolved disputes.
+/// * `average_resolution_time` - `total_resolution_time / resolved` (0 if none).
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ArbitrationMetrics {
+    pub total_disputes: u64,
+    pub resolved: u64,
+    pub ties: u64,
+    pub votes_cast: u64,
+    pub total_resolution_time: u64,
+    pub average_resolution_time: u64,
+}
+
+/// Token deposit backing an arbitrator's weight.
+///
+/// # Fields
+/// * `amount` - Deposited amount held by the contract.
+/// * `refundable_at` - Set on unregistration; the deposit can be withdrawn from this time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitratorDeposit {
+    pub amount: i128,
+    pub refundable_at: Option<u64>,
+}
+
+/// Feature flag: arbitrator votes are weighted (disabled = one vote per arbitrator).
+pub const FEATURE_WEIGHTED_VOTING: u32 = 1 << 0;
+/// All features known to this contract version.
+pub const ALL_FEATURES: u32 = FEATURE_WEIGHTED_VOTING;
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -22,8 +75,29 @@ pub enum DataKey {
     DisputeCounter,
     DisputeVotes(u64),         // Map<u32, i128> (outcome -> total_weight)
     VoterCasted(u64, Address), // (dispute_id, voter) -> bool
+    Features,                  // u32 bitmask of FEATURE_*
+    Callback(u64),             // dispute_id -> (callback contract, callback_ref)
+    StakeToken,                // Address of the deposit token
+    QuorumBps,                 // u32 share of total deposited stake required to resolve
+    DepositCooldown,           // u64 seconds between unregistration and refund
+    Deposit(Address),          // ArbitratorDeposit
+    TotalStake,                // i128 deposits of registered arbitrators
+    DisputeStake(u64),         // i128 deposited stake of voters on a dispute
+    SchemaVersion,             // u32 storage layout version (see `migrate`)
+    Metrics,                   // ArbitrationMetrics counters (see `get_metrics`)
+    PendingAdmin,              // Address nominated by `transfer_admin`
 }
 
+/// Interface implemented by contracts that escalate disputes into arbitration and want the
+/// final outcome delivered back (e.g. `dispute_resolution`).
+#[contractclient(name = "EscalationCallbackClient")]
+pub trait EscalationCallback {
+    fn on_arbitration_resolved(e: Env, callback_ref: u64, dispute_id: u64, outcome: u32);
+}
+
+/// Storage layout version this build reads and writes (see `migrate`).
+pub const SCHEMA_VERSION: u32 = 1;
+
 #[contract]
 pub struct CredenceArbitration;
 
@@ -35,10 +109,98 @@ impl CredenceArbitration {
             panic!("already initialized");
         }
         e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+    }
+
+    /// Bring stored data from schema `from_version` up to the layout of this build.
+    /// Idempotent: returns the current version without changes once migrated. Admin only.
+    pub fn migrate(e: Env, from_version: u32) -> u32 {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        let stored = Self::get_schema_version(e.clone());
+        if stored == SCHEMA_VERSION {
+            return stored;
+        }
+        if from_version != stored {
+            panic!("schema version mismatch");
+        }
+        if stored > SCHEMA_VERSION {
+            panic!("schema version newer than contract");
+        }
+        // 0 -> 1: versioning introduced; the layout itself is unchanged.
+        e.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        e.events().publish(
+            (Symbol::new(&e, "schema_migrated"),),
+            (stored, SCHEMA_VERSION),
+        );
+        SCHEMA_VERSION
+    }
+
+    /// Nominate `new_admin` as the next admin. The current admin stays in charge until the
+    /// nominee calls `accept_admin`; a new nomination replaces a pending one. Admin only.
+    pub fn transfer_admin(e: Env, new_admin: Address) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized");
+        admin.require_auth();
+        e.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        e.events().publish(
+            (Symbol::new(&e, "admin_transfer_started"), admin),
+            new_admin,
+        );
+    }
+
+    /// Complete a pending admin transfer. Requires the nominee's auth. Returns the new admin.
+    pub fn accept_admin(e: Env) -> Address {
+        let new_admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic!("no pending admin transfer"));
+        new_admin.require_auth();
+        let previous: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized");
+        e.storage().instance().set(&DataKey::Admin, &new_admin);
+        e.storage().instance().remove(&DataKey::PendingAdmin);
+        e.events().publish(
+            (Symbol::new(&e, "admin_transferred"),),
+            (previous, new_admin.clone()),
+        );
+        new_admin
+    }
+
+    /// Nominated admin awaiting acceptance, if any.
+    pub fn get_pending_admin(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Storage layout version of the stored data (0 if deployed before versioning).
+    pub fn get_schema_version(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(0)
     }
 
-    /// Register or update an arbitrator with a specific voting weight.
-    pub fn register_arbitrator(e: Env, arbitrator: Address, weight: i128) {
+    /// Register or update an arbitrator with a specific voting weight. A positive `deposit`
+    /// is pulled from the arbitrator in the stake token and added to its backing stake;
+    /// re-registering an arbitrator whose deposit is awaiting refund reactivates it.
+    pub fn register_arbitrator(e: Env, arbitrator: Address, weight: i128, deposit: i128) {
         let admin: Address = e
             .storage()
             .instance()
@@ -49,18 +211,55 @@ impl CredenceArbitration {
         if weight <= 0 {
             panic!("weight must be positive");
         }
+        if deposit < 0 {
+            panic!("deposit must be non-negative");
+        }
 
         e.storage()
             .instance()
             .set(&DataKey::Arbitrator(arbitrator.clone()), &weight);
 
+        let deposit_key = DataKey::Deposit(arbitrator.clone());
+        let mut record: ArbitratorDeposit =
+            e.storage()
+                .instance()
+                .get(&deposit_key)
+                .unwrap_or(ArbitratorDeposit {
+                    amount: 0,
+                    refundable_at: None,
+                });
+        let mut added = deposit;
+        if record.refundable_at.is_some() {
+            record.refundable_at = None;
+            added = added.checked_add(record.amount).expect("stake overflow");
+        }
+        if deposit > 0 {
+            let stake_token: Address = e
+                .storage()
+                .instance()
+                .get(&DataKey::StakeToken)
+                .unwrap_or_else(|| panic!("stake token not configured"));
+            arbitrator.require_auth();
+            token::Client::new(&e, &stake_token).transfer(
+                &arbitrator,
+                &e.current_contract_address(),
+                &deposit,
+            );
+            record.amount = record.amount.checked_add(deposit).expect("stake overflow");
+        }
+        if record.amount > 0 {
+            e.storage().instance().set(&deposit_key, &record);
+            Self::add_total_stake(&e, added);
+        }
+
         e.events().publish(
             (Symbol::new(&e, "arbitrator_registered"), arbitrator),
-            weight,
+            (weight, record.amount),
         );
     }
 
-    /// Remove an arbitrator.
+    /// Remove an arbitrator. Its deposit stops counting towards total stake and becomes
+    /// refundable after the deposit cooldown.
     pub fn unregister_arbitrator(e: Env, arbitrator: Address) {
         let admin: Address = e
             .storage()
@@ -73,10 +272,119 @@ impl CredenceArbitration {
             .instance()
             .remove(&DataKey::Arbitrator(arbitrator.clone()));
 
+        let deposit_key = DataKey::Deposit(arbitrator.clone());
+        let record: Option<ArbitratorDeposit> = e.storage().instance().get(&deposit_key);
+        if let Some(mut record) = record {
+            if record.refundable_at.is_none() {
+                Self::add_total_stake(&e, -record.amount);
+                let cooldown: u64 = e
+                    .storage()
+                    .instance()
+                    .get(&DataKey::DepositCooldown)
+                    .unwrap_or(0);
+                record.refundable_at = Some(
+                    e.ledger()
+                        .timestamp()
+                        .checked_add(cooldown)
+                        .expect("cooldown overflow"),
+                );
+                e.storage().instance().set(&deposit_key, &record);
+            }
+        }
+
         e.events()
             .publish((Symbol::new(&e, "arbitrator_unregistered"), arbitrator), ());
     }
 
+    /// Configure stake deposits: the deposit token, the share of total deposited stake (basis
+    /// points) that voters must represent for a dispute to resolve (0 disables the quorum),
+    /// and the refund cooldown after unregistration. Admin only.
+    pub fn set_stake_config(e: Env, stake_token: Address, quorum_bps: u32, cooldown: u64) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized");
+        admin.require_auth();
+        if quorum_bps > 10_000 {
+            panic!("quorum_bps must be <= 10000");
+        }
+        if let Some(current) = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::StakeToken)
+        {
+            if current != stake_token && Self::get_total_stake(e.clone()) > 0 {
+                panic!("cannot change stake token while deposits are held");
+            }
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::StakeToken, &stake_token);
+        e.storage().instance().set(&DataKey::QuorumBps, &quorum_bps);
+        e.storage()
+            .instance()
+            .set(&DataKey::DepositCooldown, &cooldown);
+        e.events().publish(
+            (Symbol::new(&e, "stake_config_updated"),),
+            (stake_token, quorum_bps, cooldown),
+        );
+    }
+
+    /// Refund an unregistered arbitrator's deposit once the cooldown has passed.
+    pub fn withdraw_deposit(e: Env, arbitrator: Address) -> i128 {
+        arbitrator.require_auth();
+        let deposit_key = DataKey::Deposit(arbitrator.clone());
+        let record: ArbitratorDeposit = e
+            .storage()
+            .instance()
+            .get(&deposit_key)
+            .unwrap_or_else(|| panic!("no deposit"));
+        let refundable_at = record
+            .refundable_at
+            .unwrap_or_else(|| panic!("arbitrator still registered"));
+        if e.ledger().timestamp() < refundable_at {
+            panic!("deposit cooldown active");
+        }
+        e.storage().instance().remove(&deposit_key);
+        let stake_token: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::StakeToken)
+            .expect("stake token not configured");
+        token::Client::new(&e, &stake_token).transfer(
+            &e.current_contract_address(),
+            &arbitrator,
+            &record.amount,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "deposit_withdrawn"), arbitrator),
+            record.amount,
+        );
+        record.amount
+    }
+
+    /// Deposit record of an arbitrator, if any.
+    pub fn get_deposit(e: Env, arbitrator: Address) -> Option<ArbitratorDeposit> {
+        e.storage().instance().get(&DataKey::Deposit(arbitrator))
+    }
+
+    /// Total deposited stake of registered arbitrators.
+    pub fn get_total_stake(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::TotalStake)
+            .unwrap_or(0)
+    }
+
+    /// Deposited stake of the arbitrators that voted on a dispute.
+    pub fn get_dispute_stake(e: Env, dispute_id: u64) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::DisputeStake(dispute_id))
+            .unwrap_or(0)
+    }
+
     /// Create a new dispute for arbitration.
     pub fn create_dispute(e: Env, creator: Address, description: String, duration: u64) -> u64 {
         creator.require_auth();
@@ -100,6 +408,7 @@ impl CredenceArbitration {
         };
 
         e.storage().instance().set(&DataKey::Dispute(id), &dispute);
+        Self::update_metrics(&e, |m| m.total_disputes += 1);
 
         e.events()
             .publish((Symbol::new(&e, "dispute_created"), id), creator);
@@ -107,6 +416,28 @@ impl CredenceArbitration {
         id
     }
 
+    /// Create a dispute escalated from another contract. When resolved, the outcome is
+    /// delivered to `callback` via `on_arbitration_resolved(callback_ref, dispute_id, outcome)`,
+    /// so the callback can check that `dispute_id` is the dispute it escalated.
+    pub fn create_escalated_dispute(
+        e: Env,
+        creator: Address,
+        description: String,
+        duration: u64,
+        callback: Address,
+        callback_ref: u64,
+    ) -> u64 {
+        let id = Self::create_dispute(e.clone(), creator, description, duration);
+        e.storage()
+            .instance()
+            .set(&DataKey::Callback(id), &(callback.clone(), callback_ref));
+        e.events().publish(
+            (Symbol::new(&e, "dispute_escalated"), id),
+            (callback, callback_ref),
+        );
+        id
+    }
+
     /// Cast a weighted vote for a dispute outcome.
     pub fn vote(e: Env, voter: Address, dispute_id: u64, outcome: u32) {
         voter.require_auth();
@@ -121,9 +452,14 @@ impl CredenceArbitration {
             .instance()
             .get(&DataKey::Arbitrator(voter.clone()))
             .unwrap_or_else(|| panic!("voter is not an authorized arbitrator"));
+        let weight = if Self::get_features(e.clone()) & FEATURE_WEIGHTED_VOTING != 0 {
+            weight
+        } else {
+            1
+        };
 
         // Verify dispute exists and is within voting period
-        let mut dispute: Dispute = e
+        let dispute: Dispute = e
             .storage()
             .instance()
             .get(&DataKey::Dispute(dispute_id))
@@ -160,6 +496,17 @@ impl CredenceArbitration {
         );
 
         e.storage().instance().set(&votes_key, &votes);
+        Self::update_metrics(&e, |m| m.votes_cast += 1);
+
+        let stake = Self::active_deposit(&e, &voter);
+        if stake > 0 {
+            let participation = Self::get_dispute_stake(e.clone(), dispute_id)
+                .checked_add(stake)
+                .expect("stake overflow");
+            e.storage()
+                .instance()
+                .set(&DataKey::DisputeStake(dispute_id), &participation);
+        }
 
         e.events().publish(
             (Symbol::new(&e, "vote_cast"), dispute_id, voter),
@@ -184,6 +531,15 @@ impl CredenceArbitration {
             panic!("voting period has not ended");
         }
 
+        let quorum_bps: u32 = e.storage().instance().get(&DataKey::QuorumBps).unwrap_or(0);
+        let participation = Self::get_dispute_stake(e.clone(), dispute_id);
+        let required = Self::get_total_stake(e.clone())
+            .checked_mul(quorum_bps as i128)
+            .expect("quorum overflow");
+        if participation.checked_mul(10_000).expect("quorum overflow") < required {
+            panic!("stake quorum not reached");
+        }
+
         let votes_key = DataKey::DisputeVotes(dispute_id);
         let votes: Map<u32, i128> = e
             .storage()
@@ -212,6 +568,14 @@ impl CredenceArbitration {
 
         dispute.resolved = true;
         dispute.outcome = winning_outcome;
+        let elapsed = now.saturating_sub(dispute.voting_start);
+        Self::update_metrics(&e, |m| {
+            m.resolved += 1;
+            if winning_outcome == 0 {
+                m.ties += 1;
+            }
+            m.total_resolution_time = m.total_resolution_time.saturating_add(elapsed);
+        });
         e.storage()
             .instance()
             .set(&DataKey::Dispute(dispute_id), &dispute);
@@ -221,9 +585,75 @@ impl CredenceArbitration {
             winning_outcome,
         );
 
+        let callback: Option<(Address, u64)> =
+            e.storage().instance().get(&DataKey::Callback(dispute_id));
+        if let Some((callback, callback_ref)) = callback {
+            EscalationCallbackClient::new(&e, &callback).on_arbitration_resolved(
+                &callback_ref,
+                &dispute_id,
+                &winning_outcome,
+            );
+        }
+
         winning_outcome
     }
 
+    /// Enabled optional subsystems as a bitmask (FEATURE_*). All features if never set.
+    pub fn get_features(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::Features)
+            .unwrap_or(ALL_FEATURES)
+    }
+
+    /// Replace the enabled feature mask. Admin only.
+    pub fn set_features(e: Env, features: u32) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized");
+        admin.require_auth();
+        if features & !ALL_FEATURES != 0 {
+            panic!("unknown feature flag");
+        }
+        e.storage().instance().set(&DataKey::Features, &features);
+        e.events()
+            .publish((Symbol::new(&e, "features_updated"),), features);
+    }
+
+    /// Complete current configuration (admin, feature flags, stake settings).
+    pub fn export_config(e: Env) -> ArbitrationConfig {
+        ArbitrationConfig {
+            admin: e.storage().instance().get(&DataKey::Admin),
+            pending_admin: e.storage().instance().get(&DataKey::PendingAdmin),
+            schema_version: Self::get_schema_version(e.clone()),
+            features: Self::get_features(e.clone()),
+            stake_token: e.storage().instance().get(&DataKey::StakeToken),
+            quorum_bps: e.storage().instance().get(&DataKey::QuorumBps).unwrap_or(0),
+            deposit_cooldown: e
+                .storage()
+                .instance()
+                .get(&DataKey::DepositCooldown)
+                .unwrap_or(0),
+        }
+    }
+
+    /// Aggregate dispute and vote counters for dashboards. Counters cover activity since
+    /// the contract version that introduced them.
+    pub fn get_metrics(e: Env) -> ArbitrationMetrics {
+        let mut metrics: ArbitrationMetrics = e
+            .storage()
+            .instance()
+            .get(&DataKey::Metrics)
+            .unwrap_or_default();
+        metrics.average_resolution_time = metrics
+            .total_resolution_time
+            .checked_div(metrics.resolved)
+            .unwrap_or(0);
+        metrics
+    }
+
     /// Get dispute details.
     pub fn get_dispute(e: Env, dispute_id: u64) -> Dispute {
         e.storage()
@@ -245,5 +675,35 @@ impl CredenceArbitration {
     }
 }
 
+impl CredenceArbitration {
+    fn update_metrics(e: &Env, update: impl FnOnce(&mut ArbitrationMetrics)) {
+        let mut metrics: ArbitrationMetrics = e
+            .storage()
+            .instance()
+            .get(&DataKey::Metrics)
+            .unwrap_or_default();
+        update(&mut metrics);
+        e.storage().instance().set(&DataKey::Metrics, &metrics);
+    }
+
+    fn add_total_stake(e: &Env, delta: i128) {
+        let total = Self::get_total_stake(e.clone())
+            .checked_add(delta)
+            .expect("stake overflow");
+        e.storage().instance().set(&DataKey::TotalStake, &total);
+    }
+
+    fn active_deposit(e: &Env, arbitrator: &Address) -> i128 {
+        let record: Option<ArbitratorDeposit> = e
+            .storage()
+            .instance()
+            .get(&DataKey::Deposit(arbitrator.clone()));
+        match record {
+            Some(r) if r.refundable_at.is_none() => r.amount,
+            _ => 0,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test;
@@ -20,8 +20,8 @@ fn test_arbitration_flow() {
     client.initialize(&admin);
 
     // Register arbitrators
-    client.register_arbitrator(&arb1, &10); // weight 10
-    client.register_arbitrator(&arb2, &5); // weight 5
+    client.register_arbitrator(&arb1, &10, &0); // weight 10
+    client.register_arbitrator(&arb2, &5, &0); // weight 5
 
     // Create dispute
     let description = String::from_str(&e, "Dispute #1");
@@ -30,7 +30,7 @@ fn test_arbitration_flow() {
     // Initial state
     let dispute = client.get_dispute(&dispute_id);
     assert_eq!(dispute.id, 0);
-    assert_eq!(dispute.resolved, false);
+    assert!(!dispute.resolved);
 
     // Voting
     client.vote(&arb1, &dispute_id, &1); // outcome 1, weight 10
@@ -58,7 +58,7 @@ fn test_arbitration_flow() {
     assert_eq!(winner, 1);
 
     let resolved_dispute = client.get_dispute(&dispute_id);
-    assert_eq!(resolved_dispute.resolved, true);
+    assert!(resolved_dispute.resolved);
     assert_eq!(resolved_dispute.outcome, 1);
 }
 
@@ -77,8 +77,8 @@ fn test_tie_scenario() {
 
     client.initialize(&admin);
 
-    client.register_arbitrator(&arb1, &10);
-    client.register_arbitrator(&arb2, &10);
+    client.register_arbitrator(&arb1, &10, &0);
+    client.register_arbitrator(&arb2, &10, &0);
 
     let description = String::from_str(&e, "Tie Test");
     let dispute_id = client.create_dispute(&creator, &description, &3600);
@@ -115,7 +115,7 @@ fn test_double_voting_prevention() {
     let client = CredenceArbitrationClient::new(&e, &contract_id);
 
     client.initialize(&admin);
-    client.register_arbitrator(&arb, &10);
+    client.register_arbitrator(&arb, &10, &0);
 
     let description = String::from_str(&e, "Double Vote");
     let dispute_id = client.create_dispute(&creator, &description, &3600);
@@ -144,3 +144,328 @@ fn test_unauthorized_voter() {
 
     client.vote(&non_arb, &dispute_id, &1);
 }
+
+#[test]
+fn test_unweighted_voting_when_feature_disabled() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let arb1 = Address::generate(&e);
+    let creator = Address::generate(&e);
+
+    let contract_id = e.register(CredenceArbitration, ());
+    let client = CredenceArbitrationClient::new(&e, &contract_id);
+
+    client.initialize(&admin);
+    assert_eq!(client.get_features(), ALL_FEATURES);
+    client.set_features(&0);
+    assert_eq!(client.get_features(), 0);
+
+    client.register_arbitrator(&arb1, &10, &0);
+    let description = String::from_str(&e, "Unweighted");
+    let dispute_id = client.create_dispute(&creator, &description, &3600);
+    client.vote(&arb1, &dispute_id, &1);
+
+    assert_eq!(client.get_tally(&dispute_id, &1), 1);
+}
+
+mod callback {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+
+    #[contract]
+    pub struct RecordingCallback;
+
+    #[contractimpl]
+    impl RecordingCallback {
+        pub fn on_arbitration_resolved(e: Env, callback_ref: u64, dispute_id: u64, outcome: u32) {
+            e.storage()
+                .instance()
+                .set(&symbol_short!("last"), &(callback_ref, dispute_id, outcome));
+        }
+
+        pub fn last(e: Env) -> Option<(u64, u64, u32)> {
+            e.storage().instance().get(&symbol_short!("last"))
+        }
+    }
+}
+
+#[test]
+fn test_escalated_dispute_invokes_callback() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let arb1 = Address::generate(&e);
+    let creator = Address::generate(&e);
+
+    let contract_id = e.register(CredenceArbitration, ());
+    let client = CredenceArbitrationClient::new(&e, &contract_id);
+    let callback_id = e.register(callback::RecordingCallback, ());
+    let callback_client = callback::RecordingCallbackClient::new(&e, &callback_id);
+
+    client.initialize(&admin);
+    client.register_arbitrator(&arb1, &10, &0);
+
+    let description = String::from_str(&e, "Escalated");
+    let dispute_id =
+        client.create_escalated_dispute(&creator, &description, &3600, &callback_id, &42);
+    client.vote(&arb1, &dispute_id, &1);
+
+    e.ledger().set_timestamp(e.ledger().timestamp() + 3601);
+    client.resolve_dispute(&dispute_id);
+
+    assert_eq!(callback_client.last(), Some((42, dispute_id, 1)));
+}
+
+#[test]
+fn test_export_config() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register(CredenceArbitration, ());
+    let client = CredenceArbitrationClient::new(&e, &contract_id);
+
+    assert_eq!(client.export_config().admin, None);
+    client.initialize(&admin);
+    client.set_features(&0);
+
+    let config = client.export_config();
+    assert_eq!(config.admin, Some(admin));
+    assert_eq!(config.features, 0);
+}
+
+fn setup_staked(
+    e: &Env,
+) -> (
+    CredenceArbitrationClient<'_>,
+    soroban_sdk::token::Client<'_>,
+    soroban_sdk::token::StellarAssetClient<'_>,
+) {
+    e.mock_all_auths();
+    let admin = Address::generate(e);
+    let contract_id = e.register(CredenceArbitration, ());
+    let client = CredenceArbitrationClient::new(e, &contract_id);
+    client.initialize(&admin);
+    let token_id = e
+        .register_stellar_asset_contract_v2(Address::generate(e))
+        .address();
+    client.set_stake_config(&token_id, &5_000, &1000);
+    (
+        client,
+        soroban_sdk::token::Client::new(e, &token_id),
+        soroban_sdk::token::StellarAssetClient::new(e, &token_id),
+    )
+}
+
+#[test]
+fn test_register_with_deposit() {
+    let e = Env::default();
+    let (client, token, token_admin) = setup_staked(&e);
+    let arb = Address::generate(&e);
+    token_admin.mint(&arb, &1000);
+
+    client.register_arbitrator(&arb, &10, &600);
+    assert_eq!(token.balance(&arb), 400);
+    assert_eq!(token.balance(&client.address), 600);
+    assert_eq!(client.get_total_stake(), 600);
+    assert_eq!(client.get_deposit(&arb).unwrap().amount, 600);
+
+    // Updating the weight can top up the deposit.
+    client.register_arbitrator(&arb, &20, &100);
+    assert_eq!(client.get_total_stake(), 700);
+}
+
+#[test]
+fn test_deposit_refund_after_cooldown() {
+    let e = Env::default();
+    let (client, token, token_admin) = setup_staked(&e);
+    let arb = Address::generate(&e);
+    token_admin.mint(&arb, &500);
+    client.register_arbitrator(&arb, &10, &500);
+
+    client.unregister_arbitrator(&arb);
+    assert_eq!(client.get_total_stake(), 0);
+    let refundable_at = client.get_deposit(&arb).unwrap().refundable_at.unwrap();
+    assert_eq!(refundable_at, e.ledger().timestamp() + 1000);
+
+    e.ledger().with_mut(|li| li.timestamp = refundable_at);
+    assert_eq!(client.withdraw_deposit(&arb), 500);
+    assert_eq!(token.balance(&arb), 500);
+    assert_eq!(client.get_deposit(&arb), None);
+}
+
+#[test]
+#[should_panic(expected = "deposit cooldown active")]
+fn test_deposit_refund_before_cooldown() {
+    let e = Env::default();
+    let (client, _token, token_admin) = setup_staked(&e);
+    let arb = Address::generate(&e);
+    token_admin.mint(&arb, &500);
+    client.register_arbitrator(&arb, &10, &500);
+    client.unregister_arbitrator(&arb);
+    client.withdraw_deposit(&arb);
+}
+
+#[test]
+#[should_panic(expected = "arbitrator still registered")]
+fn test_deposit_refund_while_registered() {
+    let e = Env::default();
+    let (client, _token, token_admin) = setup_staked(&e);
+    let arb = Address::generate(&e);
+    token_admin.mint(&arb, &500);
+    client.register_arbitrator(&arb, &10, &500);
+    client.withdraw_deposit(&arb);
+}
+
+#[test]
+fn test_reregistration_reactivates_deposit() {
+    let e = Env::default();
+    let (client, _token, token_admin) = setup_staked(&e);
+    let arb = Address::generate(&e);
+    token_admin.mint(&arb, &500);
+    client.register_arbitrator(&arb, &10, &500);
+    client.unregister_arbitrator(&arb);
+    client.register_arbitrator(&arb, &10, &0);
+    assert_eq!(client.get_total_stake(), 500);
+    assert_eq!(client.get_deposit(&arb).unwrap().refundable_at, None);
+}
+
+#[test]
+fn test_stake_quorum_reached() {
+    let e = Env::default();
+    let (client, _token, token_admin) = setup_staked(&e);
+    let big = Address::generate(&e);
+    let small = Address::generate(&e);
+    token_admin.mint(&big, &600);
+    token_admin.mint(&small, &400);
+    client.register_arbitrator(&big, &1, &600);
+    client.register_arbitrator(&small, &5, &400);
+
+    let creator = Address::generate(&e);
+    let id = client.create_dispute(&creator, &String::from_str(&e, "Quorum"), &3600);
+    client.vote(&big, &id, &1);
+    assert_eq!(client.get_dispute_stake(&id), 600);
+
+    e.ledger().with_mut(|li| li.timestamp += 3601);
+    assert_eq!(client.resolve_dispute(&id), 1);
+}
+
+#[test]
+#[should_panic(expected = "stake quorum not reached")]
+fn test_stake_quorum_not_reached() {
+    let e = Env::default();
+    let (client, _token, token_admin) = setup_staked(&e);
+    let big = Address::generate(&e);
+    let small = Address::generate(&e);
+    token_admin.mint(&big, &600);
+    token_admin.mint(&small, &400);
+    client.register_arbitrator(&big, &1, &600);
+    client.register_arbitrator(&small, &5, &400);
+
+    let creator = Address::generate(&e);
+    let id = client.create_dispute(&creator, &String::from_str(&e, "Quorum"), &3600);
+    // 400 of 1000 deposited stake is below the 50% quorum despite the larger weight.
+    client.vote(&small, &id, &1);
+
+    e.ledger().with_mut(|li| li.timestamp += 3601);
+    client.resolve_dispute(&id);
+}
+
+#[test]
+#[should_panic(expected = "stake token not configured")]
+fn test_deposit_requires_stake_token() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceArbitration, ());
+    let client = CredenceArbitrationClient::new(&e, &contract_id);
+    client.initialize(&Address::generate(&e));
+    client.register_arbitrator(&Address::generate(&e), &10, &100);
+}
+
+#[test]
+fn test_schema_version_and_migrate() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceArbitration, ());
+    let client = CredenceArbitrationClient::new(&e, &contract_id);
+    client.initialize(&Address::generate(&e));
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    // Simulate an instance deployed before versioning.
+    e.as_contract(&contract_id, || {
+        e.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 0);
+    assert!(client.try_migrate(&5).is_err());
+    assert_eq!(client.migrate(&0), SCHEMA_VERSION);
+    assert_eq!(client.migrate(&0), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
+
+#[test]
+fn test_metrics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceArbitration, ());
+    let client = CredenceArbitrationClient::new(&e, &contract_id);
+    client.initialize(&Address::generate(&e));
+    let arb1 = Address::generate(&e);
+    let arb2 = Address::generate(&e);
+    client.register_arbitrator(&arb1, &1, &0);
+    client.register_arbitrator(&arb2, &1, &0);
+    assert_eq!(client.get_metrics(), ArbitrationMetrics::default());
+
+    let creator = Address::generate(&e);
+    let description = String::from_str(&e, "metrics");
+    let decided = client.create_dispute(&creator, &description, &100);
+    let tied = client.create_dispute(&creator, &description, &100);
+    client.create_dispute(&creator, &description, &100);
+    client.vote(&arb1, &decided, &1);
+    client.vote(&arb1, &tied, &1);
+    client.vote(&arb2, &tied, &2);
+
+    e.ledger().with_mut(|li| li.timestamp += 101);
+    client.resolve_dispute(&decided);
+    e.ledger().with_mut(|li| li.timestamp += 200);
+    client.resolve_dispute(&tied);
+
+    let metrics = client.get_metrics();
+    assert_eq!(metrics.total_disputes, 3);
+    assert_eq!(metrics.resolved, 2);
+    assert_eq!(metrics.ties, 1);
+    assert_eq!(metrics.votes_cast, 3);
+    assert_eq!(metrics.total_resolution_time, 101 + 301);
+    assert_eq!(metrics.average_resolution_time, 201);
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let client = CredenceArbitrationClient::new(&e, &e.register(CredenceArbitration, ()));
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+
+    let new_admin = Address::generate(&e);
+    client.transfer_admin(&new_admin);
+    assert_eq!(e.auths()[0].0, admin);
+    assert_eq!(client.export_config().admin, Some(admin));
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    assert_eq!(client.accept_admin(), new_admin);
+    assert_eq!(e.auths()[0].0, new_admin);
+    assert_eq!(client.export_config().admin, Some(new_admin));
+    assert_eq!(client.export_config().pending_admin, None);
+}
+
+#[test]
+#[should_panic(expected = "no pending admin transfer")]
+fn test_accept_admin_without_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let client = CredenceArbitrationClient::new(&e, &e.register(CredenceArbitration, ()));
+    client.initialize(&Address::generate(&e));
+    client.accept_admin();
+}
@@ -0,0 +1,70 @@
+//! Attestation Activity Feed
+//!
+//! Keeps the last `ACTIVITY_BUFFER_SIZE` attestation-related actions per subject in a
+//! bounded buffer (oldest entry dropped first), so a wallet can render a recency timeline
+//! without running an indexer. Full history remains available from events.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::DataKey;
+
+/// Number of entries retained per subject.
+pub const ACTIVITY_BUFFER_SIZE: u32 = 10;
+
+/// Kind of attestation action.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ActivityKind {
+    Added = 0,
+    Revoked = 1,
+    Challenged = 2,
+    Expired = 3,
+}
+
+/// One entry of a subject's activity feed.
+///
+/// # Fields
+/// * `kind` - What happened.
+/// * `attestation_id` - Attestation the action applies to.
+/// * `actor` - Address that performed the action.
+/// * `timestamp` - Ledger timestamp of the action.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityEntry {
+    pub kind: ActivityKind,
+    pub attestation_id: u64,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// Recent activity for a subject, oldest first.
+#[must_use]
+pub fn get_recent(e: &Env, subject: &Address) -> Vec<ActivityEntry> {
+    e.storage()
+        .instance()
+        .get(&DataKey::SubjectActivity(subject.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+/// Append an action to the subject's feed, evicting the oldest entry when full.
+pub fn record(
+    e: &Env,
+    subject: &Address,
+    kind: ActivityKind,
+    attestation_id: u64,
+    actor: &Address,
+) {
+    let mut feed = get_recent(e, subject);
+    while feed.len() >= ACTIVITY_BUFFER_SIZE {
+        feed.pop_front();
+    }
+    feed.push_back(ActivityEntry {
+        kind,
+        attestation_id,
+        actor: actor.clone(),
+        timestamp: e.ledger().timestamp(),
+    });
+    e.storage()
+        .instance()
+        .set(&DataKey::SubjectActivity(subject.clone()), &feed);
+}
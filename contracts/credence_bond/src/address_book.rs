@@ -0,0 +1,73 @@
+//! Address Book
+//!
+//! Maps well-known roles (treasury, dispute, arbitration, delegation, oracle) to the
+//! contract addresses this deployment integrates with, so integrations can discover them
+//! with `resolve(role)` instead of knowing each individually-set address. Settings that have
+//! their own setter (e.g. the conflict dispute contract) fall back to the book when unset.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, Symbol};
+
+use crate::BondError;
+
+pub const ROLE_TREASURY: &str = "treasury";
+pub const ROLE_DISPUTE: &str = "dispute";
+pub const ROLE_ARBITRATION: &str = "arbitration";
+pub const ROLE_DELEGATION: &str = "delegation";
+pub const ROLE_ORACLE: &str = "oracle";
+
+/// Every role the book accepts.
+pub const ROLES: [&str; 5] = [
+    ROLE_TREASURY,
+    ROLE_DISPUTE,
+    ROLE_ARBITRATION,
+    ROLE_DELEGATION,
+    ROLE_ORACLE,
+];
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum AddressBookKey {
+    Entry(Symbol),
+}
+
+fn require_known(e: &Env, role: &Symbol) {
+    if !ROLES.iter().any(|r| Symbol::new(e, r) == *role) {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+}
+
+/// Address registered for `role`, if any.
+#[must_use]
+pub fn resolve(e: &Env, role: &Symbol) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get(&AddressBookKey::Entry(role.clone()))
+}
+
+/// Register `address` for `role`, or clear the role with `None`. Admin only (enforced by
+/// caller).
+pub fn set(e: &Env, role: &Symbol, address: Option<Address>) {
+    require_known(e, role);
+    let key = AddressBookKey::Entry(role.clone());
+    match &address {
+        Some(a) => e.storage().instance().set(&key, a),
+        None => e.storage().instance().remove(&key),
+    }
+    e.events().publish(
+        (Symbol::new(e, "address_book_updated"), role.clone()),
+        address,
+    );
+}
+
+/// All registered roles and their addresses.
+#[must_use]
+pub fn entries(e: &Env) -> Map<Symbol, Address> {
+    let mut book = Map::new(e);
+    for r in ROLES {
+        let role = Symbol::new(e, r);
+        if let Some(address) = resolve(e, &role) {
+            book.set(role, address);
+        }
+    }
+    book
+}
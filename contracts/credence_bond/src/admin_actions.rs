@@ -0,0 +1,189 @@
+//! Rate-Limited Admin Actions
+//!
+//! Sensitive admin actions (changing the bond token, large fee changes, unregistering
+//! attesters, recovering stray tokens, changing address book entries, nominating a new
+//! admin, upgrading the contract, changing this limit)
+//! are capped at `max_actions` per rolling `window` and appended to an on-chain action log.
+//! A compromised admin key can still act, but slowly and in plain view.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+use crate::BondError;
+
+/// Largest page returned by [`list`].
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Default fee change (bps) above which `set_fee_config` counts as sensitive.
+pub const DEFAULT_FEE_DELTA_BPS: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AdminActionKind {
+    SetToken = 0,
+    FeeChange = 1,
+    UnregisterAttester = 2,
+    SetRateLimit = 3,
+    RecoverToken = 4,
+    SetRoleAddress = 5,
+    TransferAdmin = 6,
+    Upgrade = 7,
+}
+
+/// One entry of the admin action log.
+///
+/// * `target` - Address acted on (token, fee treasury, attester), if any.
+/// * `value` - Kind-specific value (new fee bps, new action limit), otherwise 0.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminAction {
+    pub seq: u64,
+    pub kind: AdminActionKind,
+    pub actor: Address,
+    pub target: Option<Address>,
+    pub value: i128,
+    pub timestamp: u64,
+}
+
+/// Rate limit on sensitive admin actions.
+///
+/// * `max_actions` - Sensitive actions allowed per window; 0 disables the limit.
+/// * `window` - Rolling window length in seconds.
+/// * `fee_delta_bps` - Fee changes larger than this are sensitive; smaller ones are not.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminRateLimit {
+    pub max_actions: u32,
+    pub window: u64,
+    pub fee_delta_bps: u32,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum AdminActionKey {
+    RateLimit,
+    /// Timestamps of sensitive actions inside the current window.
+    Recent,
+    Count,
+    Entry(u64),
+}
+
+#[must_use]
+pub fn get_rate_limit(e: &Env) -> AdminRateLimit {
+    e.storage()
+        .instance()
+        .get(&AdminActionKey::RateLimit)
+        .unwrap_or(AdminRateLimit {
+            max_actions: 0,
+            window: 0,
+            fee_delta_bps: DEFAULT_FEE_DELTA_BPS,
+        })
+}
+
+/// Replace the rate limit. The change itself is a sensitive action, checked against the
+/// limit in force before it so a compromised key cannot loosen it past that limit. Admin
+/// only (enforced by caller).
+pub fn set_rate_limit(e: &Env, actor: &Address, limit: AdminRateLimit) {
+    if limit.max_actions > 0 && limit.window == 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    let was_limited = get_rate_limit(e).max_actions > 0;
+    record(
+        e,
+        actor,
+        AdminActionKind::SetRateLimit,
+        None,
+        limit.max_actions as i128,
+    );
+    e.storage()
+        .instance()
+        .set(&AdminActionKey::RateLimit, &limit);
+    if !was_limited && limit.max_actions > 0 {
+        // Enabling the limit counts against it too.
+        e.storage().instance().set(
+            &AdminActionKey::Recent,
+            &Vec::from_array(e, [e.ledger().timestamp()]),
+        );
+    }
+}
+
+/// True if moving the fee from `old_bps` to `new_bps` is large enough to be sensitive.
+#[must_use]
+pub fn is_sensitive_fee_change(e: &Env, old_bps: u32, new_bps: u32) -> bool {
+    old_bps.abs_diff(new_bps) > get_rate_limit(e).fee_delta_bps
+}
+
+/// Check the rate limit, then append a sensitive action to the log. Panics with
+/// `RateLimited` if the window is already full.
+pub fn record(
+    e: &Env,
+    actor: &Address,
+    kind: AdminActionKind,
+    target: Option<Address>,
+    value: i128,
+) {
+    let now = e.ledger().timestamp();
+    let limit = get_rate_limit(e);
+    if limit.max_actions > 0 {
+        let recent: Vec<u64> = e
+            .storage()
+            .instance()
+            .get(&AdminActionKey::Recent)
+            .unwrap_or(Vec::new(e));
+        let mut kept: Vec<u64> = Vec::new(e);
+        for t in recent.iter() {
+            if now.saturating_sub(t) < limit.window {
+                kept.push_back(t);
+            }
+        }
+        if kept.len() >= limit.max_actions {
+            panic_with_error!(e, BondError::RateLimited);
+        }
+        kept.push_back(now);
+        e.storage().instance().set(&AdminActionKey::Recent, &kept);
+    }
+
+    let seq = count(e);
+    let action = AdminAction {
+        seq,
+        kind,
+        actor: actor.clone(),
+        target,
+        value,
+        timestamp: now,
+    };
+    e.storage()
+        .instance()
+        .set(&AdminActionKey::Entry(seq), &action);
+    e.storage()
+        .instance()
+        .set(&AdminActionKey::Count, &(seq + 1));
+    e.events().publish(
+        (Symbol::new(e, "admin_action"), actor.clone()),
+        (seq, kind, action.target, value),
+    );
+}
+
+/// Number of logged admin actions.
+#[must_use]
+pub fn count(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&AdminActionKey::Count)
+        .unwrap_or(0)
+}
+
+/// Logged actions oldest first, paginated (`limit` capped at `MAX_PAGE_SIZE`).
+#[must_use]
+pub fn list(e: &Env, offset: u64, limit: u32) -> Vec<AdminAction> {
+    let end = offset
+        .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+        .min(count(e));
+    let mut page = Vec::new(e);
+    for seq in offset..end {
+        if let Some(action) = e.storage().instance().get(&AdminActionKey::Entry(seq)) {
+            page.push_back(action);
+        }
+    }
+    page
+}
@@ -0,0 +1,54 @@
+//! Admin Transfer
+//!
+//! Two-step admin handover, so the admin key can be rotated without the risk of handing
+//! the contract to a mistyped or unreachable address. The current admin nominates a
+//! successor, which only takes over once it accepts (proving it controls the key). Until
+//! then the current admin stays in charge and may nominate someone else, replacing the
+//! pending nomination.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{BondError, DataKey};
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum AdminTransferKey {
+    /// Nominated successor awaiting acceptance.
+    Pending,
+}
+
+/// Nominated successor, if a transfer is pending.
+#[must_use]
+pub fn get_pending(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&AdminTransferKey::Pending)
+}
+
+/// Nominate `new_admin`, replacing any pending nomination. Admin auth is enforced by the
+/// caller.
+pub fn start(e: &Env, admin: &Address, new_admin: &Address) {
+    e.storage()
+        .instance()
+        .set(&AdminTransferKey::Pending, new_admin);
+    e.events().publish(
+        (Symbol::new(e, "admin_transfer_started"), admin.clone()),
+        new_admin.clone(),
+    );
+}
+
+/// Complete the pending transfer; requires the nominee's auth. Returns the new admin.
+pub fn accept(e: &Env) -> Address {
+    let new_admin = get_pending(e).unwrap_or_else(|| panic_with_error!(e, BondError::Unauthorized));
+    new_admin.require_auth();
+    let previous: Address = e
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::NotInitialized));
+    e.storage().instance().set(&DataKey::Admin, &new_admin);
+    e.storage().instance().remove(&AdminTransferKey::Pending);
+    e.events().publish(
+        (Symbol::new(e, "admin_transferred"),),
+        (previous, new_admin.clone()),
+    );
+    new_admin
+}
@@ -0,0 +1,192 @@
+//! Attestation Archival
+//!
+//! Bounds hot-storage growth by compacting attestations that have been revoked or expired
+//! for longer than a configurable age. The full record is replaced by a small
+//! [`ArchivedAttestation`] holding the sha256 of the record's XDR, so anyone holding the
+//! original record can still prove it existed via [`verify`].
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol, Vec};
+
+use crate::dedup_policy::DedupPolicy;
+use crate::{attestation_summary, reputation, verification_snapshot, Attestation, DataKey};
+
+/// Default minimum age before a revoked or expired attestation may be archived (90 days).
+pub const DEFAULT_MIN_AGE: u64 = 90 * 86_400;
+
+/// Compact record left in place of an archived attestation.
+///
+/// * `content_hash` - sha256 of the archived `Attestation` XDR.
+/// * `revoked` - Whether the attestation was revoked (otherwise it had expired).
+/// * `ended_at` - Revocation or expiry time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedAttestation {
+    pub id: u64,
+    pub identity: Address,
+    pub verifier: Address,
+    pub content_hash: BytesN<32>,
+    pub revoked: bool,
+    pub ended_at: u64,
+    pub archived_at: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum ArchiveKey {
+    /// Minimum age in seconds since revocation or expiry.
+    ArchiveMinAge,
+    /// Revocation time of a not yet archived attestation.
+    RevokedAt(u64),
+    /// Archived record by attestation id.
+    Record(u64),
+}
+
+/// Minimum archival age in seconds.
+#[must_use]
+pub fn get_min_age(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&ArchiveKey::ArchiveMinAge)
+        .unwrap_or(DEFAULT_MIN_AGE)
+}
+
+/// Set the minimum archival age. Admin only (enforced by caller).
+pub fn set_min_age(e: &Env, seconds: u64) {
+    e.storage()
+        .instance()
+        .set(&ArchiveKey::ArchiveMinAge, &seconds);
+}
+
+/// Remember when attestation `id` was revoked.
+pub fn record_revoked(e: &Env, id: u64) {
+    e.storage()
+        .instance()
+        .set(&ArchiveKey::RevokedAt(id), &e.ledger().timestamp());
+}
+
+#[must_use]
+pub fn get_record(e: &Env, id: u64) -> Option<ArchivedAttestation> {
+    e.storage().instance().get(&ArchiveKey::Record(id))
+}
+
+/// sha256 of an attestation's XDR encoding.
+#[must_use]
+pub fn content_hash(e: &Env, attestation: &Attestation) -> BytesN<32> {
+    e.crypto().sha256(&attestation.clone().to_xdr(e)).into()
+}
+
+/// True if `attestation` is exactly the record archived under its id.
+#[must_use]
+pub fn verify(e: &Env, attestation: &Attestation) -> bool {
+    match get_record(e, attestation.id) {
+        Some(record) => record.content_hash == content_hash(e, attestation),
+        None => false,
+    }
+}
+
+/// Revocation or expiry time of `attestation`, or `None` while it is still live.
+fn ended_at(e: &Env, attestation: &Attestation, now: u64) -> Option<u64> {
+    if attestation.revoked {
+        // Attestations revoked before revocation times were tracked fall back to issuance.
+        return Some(
+            e.storage()
+                .instance()
+                .get(&ArchiveKey::RevokedAt(attestation.id))
+                .unwrap_or(attestation.timestamp),
+        );
+    }
+    if attestation.is_expired_at(now) {
+        return Some(attestation.expires_at);
+    }
+    None
+}
+
+/// Archive up to `limit` of `subject`'s attestations that ended at least the minimum age
+/// ago. Returns the number archived.
+pub fn compact(e: &Env, subject: &Address, limit: u32) -> u32 {
+    let now = e.ledger().timestamp();
+    let min_age = get_min_age(e);
+    let subject_key = DataKey::SubjectAttestations(subject.clone());
+    let ids: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&subject_key)
+        .unwrap_or(Vec::new(e));
+    let mut kept: Vec<u64> = Vec::new(e);
+    let mut archived: u32 = 0;
+    for id in ids.iter() {
+        if archived >= limit {
+            kept.push_back(id);
+            continue;
+        }
+        let attestation: Attestation = match e.storage().instance().get(&DataKey::Attestation(id)) {
+            Some(a) => a,
+            None => continue,
+        };
+        let ended = match ended_at(e, &attestation, now) {
+            Some(t) if now.saturating_sub(t) >= min_age => t,
+            _ => {
+                kept.push_back(id);
+                continue;
+            }
+        };
+        archive(e, &attestation, ended, now);
+        archived += 1;
+    }
+    if archived > 0 {
+        e.storage().instance().set(&subject_key, &kept);
+    }
+    archived
+}
+
+fn archive(e: &Env, attestation: &Attestation, ended_at: u64, now: u64) {
+    if !attestation.revoked {
+        // Expired attestations still count as live until they leave hot storage.
+        let count_key = DataKey::SubjectAttestationCount(attestation.identity.clone());
+        let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
+        e.storage()
+            .instance()
+            .set(&count_key, &count.saturating_sub(1));
+        verification_snapshot::on_attestation_revoked(e, &attestation.identity, attestation.weight);
+        attestation_summary::on_deactivated(e, attestation, false);
+        reputation::refresh(e, &attestation.identity);
+    }
+
+    let record = ArchivedAttestation {
+        id: attestation.id,
+        identity: attestation.identity.clone(),
+        verifier: attestation.verifier.clone(),
+        content_hash: content_hash(e, attestation),
+        revoked: attestation.revoked,
+        ended_at,
+        archived_at: now,
+    };
+    e.storage()
+        .instance()
+        .remove(&DataKey::Attestation(attestation.id));
+    e.storage()
+        .instance()
+        .remove(&ArchiveKey::RevokedAt(attestation.id));
+    e.storage()
+        .instance()
+        .set(&ArchiveKey::Record(attestation.id), &record);
+
+    e.events().publish(
+        (
+            Symbol::new(e, "attestation_archived"),
+            attestation.identity.clone(),
+        ),
+        (attestation.id, record.content_hash),
+    );
+}
+
+/// Dedup check against an archived record: archived attestations were revoked or expired.
+#[must_use]
+pub fn allows_reissue(policy: DedupPolicy, record: &ArchivedAttestation) -> bool {
+    match policy {
+        DedupPolicy::RejectAlways => false,
+        DedupPolicy::AllowAfterRevocation => record.revoked,
+        DedupPolicy::AllowAfterExpiry => true,
+    }
+}
@@ -0,0 +1,67 @@
+//! Hashed Attestations
+//!
+//! Storing full claim strings on-chain is costly and exposes the claim to anyone reading
+//! the ledger. A hashed attestation commits to its content with a 32-byte hash instead. Its
+//! `attestation_data` holds only the schema identifier, so claim grouping and per-claim
+//! dedup policies work by schema. Duplicates are detected on the content hash. Holders of
+//! the content can later prove it against the commitment with `verify`.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, BytesN, Env, String};
+
+use crate::types::AttestationHashDedupKey;
+use crate::BondError;
+
+/// Longest accepted schema identifier, in bytes.
+pub const MAX_SCHEMA_ID_LEN: u32 = 64;
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum AttestationHashKey {
+    /// Content hash committed by a hashed attestation.
+    Commitment(u64),
+}
+
+/// Panics with `InvalidInput` unless `schema_id` is non-empty and at most
+/// `MAX_SCHEMA_ID_LEN` bytes.
+pub fn validate_schema_id(e: &Env, schema_id: &String) {
+    if schema_id.is_empty() || schema_id.len() > MAX_SCHEMA_ID_LEN {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+}
+
+#[must_use]
+pub fn dedup_key(
+    verifier: &Address,
+    identity: &Address,
+    data_hash: &BytesN<32>,
+) -> AttestationHashDedupKey {
+    AttestationHashDedupKey {
+        verifier: verifier.clone(),
+        identity: identity.clone(),
+        data_hash: data_hash.clone(),
+    }
+}
+
+/// Record the content hash of attestation `id`.
+pub fn record(e: &Env, id: u64, data_hash: &BytesN<32>) {
+    e.storage()
+        .instance()
+        .set(&AttestationHashKey::Commitment(id), data_hash);
+}
+
+/// Content hash of attestation `id`, if it is a hashed attestation.
+#[must_use]
+pub fn get(e: &Env, id: u64) -> Option<BytesN<32>> {
+    e.storage()
+        .instance()
+        .get(&AttestationHashKey::Commitment(id))
+}
+
+/// True if attestation `id` is hashed and `content` hashes (SHA-256) to its commitment.
+#[must_use]
+pub fn verify(e: &Env, id: u64, content: &Bytes) -> bool {
+    match get(e, id) {
+        Some(hash) => BytesN::from(e.crypto().sha256(content)) == hash,
+        None => false,
+    }
+}
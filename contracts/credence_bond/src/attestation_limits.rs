@@ -0,0 +1,101 @@
+//! Attestation Limits
+//!
+//! Bounds the growth of a subject's attestation index, which lives in a single storage
+//! entry. The admin can cap the number of ids indexed per subject and the number of active
+//! attestations one verifier may hold on a subject; both are enforced when an attestation
+//! is stored. Revoked ids can be pruned from the index to make room again.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+use crate::{attestation_summary, Attestation, BondError, DataKey};
+
+/// Attestation caps (0 = unbounded).
+///
+/// * `max_per_subject` - Ids a subject's index may hold, revoked ones included until
+///   pruned.
+/// * `max_per_verifier_per_subject` - Active attestations one verifier may hold on a
+///   subject.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AttestationLimits {
+    pub max_per_subject: u32,
+    pub max_per_verifier_per_subject: u32,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum AttestationLimitKey {
+    /// Configured `AttestationLimits`.
+    AttestationCaps,
+}
+
+#[must_use]
+pub fn get(e: &Env) -> AttestationLimits {
+    e.storage()
+        .instance()
+        .get(&AttestationLimitKey::AttestationCaps)
+        .unwrap_or_default()
+}
+
+/// Set the caps. Lowering a cap does not remove attestations already stored. Admin only
+/// (enforced by caller).
+pub fn set(e: &Env, limits: &AttestationLimits) {
+    e.storage()
+        .instance()
+        .set(&AttestationLimitKey::AttestationCaps, limits);
+    e.events()
+        .publish((Symbol::new(e, "attestation_limits_set"),), limits.clone());
+}
+
+fn index(e: &Env, subject: &Address) -> Vec<u64> {
+    e.storage()
+        .instance()
+        .get(&DataKey::SubjectAttestations(subject.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+/// Panics with `RateLimited` if one more attestation by `verifier` on `subject` would
+/// exceed a cap.
+pub fn require_within(e: &Env, subject: &Address, verifier: &Address) {
+    let limits = get(e);
+    if limits.max_per_subject > 0 && index(e, subject).len() >= limits.max_per_subject {
+        panic_with_error!(e, BondError::RateLimited);
+    }
+    if limits.max_per_verifier_per_subject > 0
+        && attestation_summary::active_from(e, subject, verifier)
+            >= limits.max_per_verifier_per_subject
+    {
+        panic_with_error!(e, BondError::RateLimited);
+    }
+}
+
+/// Remove up to `limit` revoked ids from `subject`'s index. The attestations stay readable
+/// by id but are no longer listed or reached by archival compaction. Returns the number
+/// removed.
+pub fn prune_revoked(e: &Env, subject: &Address, limit: u32) -> u32 {
+    let ids = index(e, subject);
+    let mut kept: Vec<u64> = Vec::new(e);
+    let mut pruned: u32 = 0;
+    for id in ids.iter() {
+        let revoked = pruned < limit
+            && e.storage()
+                .instance()
+                .get::<_, Attestation>(&DataKey::Attestation(id))
+                .is_some_and(|a| a.revoked);
+        if revoked {
+            pruned += 1;
+        } else {
+            kept.push_back(id);
+        }
+    }
+    if pruned > 0 {
+        e.storage()
+            .instance()
+            .set(&DataKey::SubjectAttestations(subject.clone()), &kept);
+        e.events().publish(
+            (Symbol::new(e, "subject_index_pruned"), subject.clone()),
+            (pruned, kept.len()),
+        );
+    }
+    pruned
+}
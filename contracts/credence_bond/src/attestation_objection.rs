@@ -0,0 +1,133 @@
+//! Attestation Objections
+//!
+//! Gives a subject recourse against an attestation it considers false. The subject records
+//! an objection (a hash of its off-chain reasoning) on the attestation, which flags it for
+//! verifiers and integrators. When a dispute contract is configured and the admin set
+//! objection dispute terms, the objection also opens a dispute over the attestation, staked
+//! by the subject in the bond token.
+
+use soroban_sdk::{
+    contracttype, panic_with_error, Address, BytesN, Env, IntoVal, Symbol, Val, Vec,
+};
+
+use crate::{counter_attestation, token_info, Attestation, BondError, DataKey};
+
+/// A subject's objection to one of its attestations.
+///
+/// * `reason_hash` - Hash of the subject's off-chain statement.
+/// * `dispute_id` - Dispute opened for the objection (0 = none).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Objection {
+    pub attestation_id: u64,
+    pub subject: Address,
+    pub reason_hash: BytesN<32>,
+    pub raised_at: u64,
+    pub dispute_id: u64,
+}
+
+/// Terms of the dispute an objection opens automatically.
+///
+/// * `stake` - Bond-token stake the subject puts up (0 = objections open no dispute).
+/// * `resolution_window` - Seconds the dispute contract has to resolve it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObjectionTerms {
+    pub stake: i128,
+    pub resolution_window: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum ObjectionKey {
+    /// Objection by attestation id.
+    Objection(u64),
+    /// Configured `ObjectionTerms`.
+    Terms,
+}
+
+#[must_use]
+pub fn get_terms(e: &Env) -> ObjectionTerms {
+    e.storage()
+        .instance()
+        .get(&ObjectionKey::Terms)
+        .unwrap_or(ObjectionTerms {
+            stake: 0,
+            resolution_window: 0,
+        })
+}
+
+/// Set the dispute terms. A positive stake needs a non-zero resolution window. Admin only
+/// (enforced by caller).
+pub fn set_terms(e: &Env, terms: &ObjectionTerms) {
+    if terms.stake < 0 || (terms.stake > 0 && terms.resolution_window == 0) {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage().instance().set(&ObjectionKey::Terms, terms);
+}
+
+#[must_use]
+pub fn get(e: &Env, attestation_id: u64) -> Option<Objection> {
+    e.storage()
+        .instance()
+        .get(&ObjectionKey::Objection(attestation_id))
+}
+
+/// Record `subject`'s objection to attestation `attestation_id` and, if configured, open a
+/// dispute over it. Caller must enforce the subject's auth, which also covers the nested
+/// dispute call.
+pub fn object(
+    e: &Env,
+    subject: &Address,
+    attestation_id: u64,
+    reason_hash: &BytesN<32>,
+) -> Objection {
+    let attestation: Attestation = e
+        .storage()
+        .instance()
+        .get(&DataKey::Attestation(attestation_id))
+        .unwrap_or_else(|| panic_with_error!(e, BondError::AttestationNotFound));
+    if attestation.identity != *subject {
+        panic_with_error!(e, BondError::Unauthorized);
+    }
+    if attestation.revoked {
+        panic_with_error!(e, BondError::AttestationRevoked);
+    }
+    if get(e, attestation_id).is_some() {
+        panic_with_error!(e, BondError::InvalidAttestationState);
+    }
+
+    let mut objection = Objection {
+        attestation_id,
+        subject: subject.clone(),
+        reason_hash: reason_hash.clone(),
+        raised_at: e.ledger().timestamp(),
+        dispute_id: 0,
+    };
+    let terms = get_terms(e);
+    if terms.stake > 0 {
+        if let Some(dispute) = counter_attestation::get_dispute_contract(e) {
+            let token = token_info::get(e)
+                .unwrap_or_else(|| panic_with_error!(e, BondError::TokenNotSet))
+                .address;
+            let args: Vec<Val> = (
+                subject.clone(),
+                attestation_id,
+                terms.stake,
+                token,
+                terms.resolution_window,
+            )
+                .into_val(e);
+            objection.dispute_id =
+                e.invoke_contract(&dispute, &Symbol::new(e, "create_dispute"), args);
+        }
+    }
+    e.storage()
+        .instance()
+        .set(&ObjectionKey::Objection(attestation_id), &objection);
+    e.events().publish(
+        (Symbol::new(e, "attestation_objected"), attestation_id),
+        (subject.clone(), reason_hash.clone(), objection.dispute_id),
+    );
+    objection
+}
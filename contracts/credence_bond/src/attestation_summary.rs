@@ -0,0 +1,116 @@
+//! Attestation Summary
+//!
+//! Aggregated view of a subject's attestations, so callers do not have to fetch every
+//! attestation to learn how well a subject is attested. The counters are updated in place
+//! when an attestation is added, revoked or archived after expiring. Like the subject
+//! attestation count, an expired attestation stays active until it is archived.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::Attestation;
+
+/// Aggregate of a subject's attestations.
+///
+/// # Fields
+/// * `active_count` - Non-revoked attestations (including those still in a challenge period).
+/// * `active_weight` - Sum of the weights of the active attestations.
+/// * `distinct_verifiers` - Verifiers with at least one active attestation on the subject.
+/// * `last_attested_at` - Timestamp of the most recent attestation (0 if none).
+/// * `revoked_count` - Attestations revoked (or challenged) so far.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AttestationSummary {
+    pub active_count: u32,
+    pub active_weight: u64,
+    pub distinct_verifiers: u32,
+    pub last_attested_at: u64,
+    pub revoked_count: u32,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum SummaryKey {
+    /// Summary of a subject.
+    AttestationSummary(Address),
+    /// Active attestations of a verifier on a subject: (subject, verifier).
+    ActiveFromVerifier(Address, Address),
+}
+
+#[must_use]
+pub fn get(e: &Env, subject: &Address) -> AttestationSummary {
+    e.storage()
+        .instance()
+        .get(&SummaryKey::AttestationSummary(subject.clone()))
+        .unwrap_or_default()
+}
+
+fn save(e: &Env, subject: &Address, summary: &AttestationSummary) {
+    e.storage()
+        .instance()
+        .set(&SummaryKey::AttestationSummary(subject.clone()), summary);
+}
+
+/// Adjust the active count of `verifier` on `subject` by `delta` (+1 or -1). Returns the
+/// change in distinct verifiers (+1, -1 or 0).
+fn bump_verifier(e: &Env, subject: &Address, verifier: &Address, delta: i32) -> i32 {
+    let key = SummaryKey::ActiveFromVerifier(subject.clone(), verifier.clone());
+    let before: u32 = e.storage().instance().get(&key).unwrap_or(0);
+    let after = before.saturating_add_signed(delta);
+    if after == 0 {
+        e.storage().instance().remove(&key);
+    } else {
+        e.storage().instance().set(&key, &after);
+    }
+    match (before, after) {
+        (0, a) if a > 0 => 1,
+        (b, 0) if b > 0 => -1,
+        _ => 0,
+    }
+}
+
+/// Active attestations of `verifier` on `subject`.
+#[must_use]
+pub fn active_from(e: &Env, subject: &Address, verifier: &Address) -> u32 {
+    e.storage()
+        .instance()
+        .get(&SummaryKey::ActiveFromVerifier(
+            subject.clone(),
+            verifier.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+fn apply_distinct(summary: &mut AttestationSummary, change: i32) {
+    summary.distinct_verifiers = summary.distinct_verifiers.saturating_add_signed(change);
+}
+
+/// Account for a newly stored attestation.
+pub fn on_added(e: &Env, attestation: &Attestation) {
+    let subject = &attestation.identity;
+    let mut summary = get(e, subject);
+    summary.active_count = summary.active_count.saturating_add(1);
+    summary.active_weight = summary
+        .active_weight
+        .saturating_add(attestation.weight as u64);
+    summary.last_attested_at = summary.last_attested_at.max(attestation.timestamp);
+    let change = bump_verifier(e, subject, &attestation.verifier, 1);
+    apply_distinct(&mut summary, change);
+    save(e, subject, &summary);
+}
+
+/// Account for an active attestation leaving the active set, by revocation (`revoked`) or
+/// by archival after expiry.
+pub fn on_deactivated(e: &Env, attestation: &Attestation, revoked: bool) {
+    let subject = &attestation.identity;
+    let mut summary = get(e, subject);
+    summary.active_count = summary.active_count.saturating_sub(1);
+    summary.active_weight = summary
+        .active_weight
+        .saturating_sub(attestation.weight as u64);
+    if revoked {
+        summary.revoked_count = summary.revoked_count.saturating_add(1);
+    }
+    let change = bump_verifier(e, subject, &attestation.verifier, -1);
+    apply_distinct(&mut summary, change);
+    save(e, subject, &summary);
+}
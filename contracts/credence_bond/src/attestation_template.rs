@@ -0,0 +1,125 @@
+//! Attestation Template Registry
+//!
+//! Admin-defined claim templates (schema hash, default weight cap, expiry policy) so that
+//! attestations of the same kind are consistent across verifiers. Attestations created with
+//! a template carry its id, have their weight capped and receive an expiry timestamp.
+
+use soroban_sdk::{contracttype, panic_with_error, BytesN, Env, Symbol, Vec};
+
+use crate::types::attestation::MAX_ATTESTATION_WEIGHT;
+use crate::{BondError, DataKey};
+
+/// A standardized claim template.
+///
+/// # Fields
+/// * `id` - Template identifier (sequential from 0).
+/// * `schema_hash` - Hash of the off-chain claim schema.
+/// * `weight_cap` - Maximum weight for attestations using this template.
+/// * `expiry_seconds` - Lifetime of attestations using this template (0 = never expire).
+/// * `active` - Whether new attestations may reference this template.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationTemplate {
+    pub id: u32,
+    pub schema_hash: BytesN<32>,
+    pub weight_cap: u32,
+    pub expiry_seconds: u64,
+    pub active: bool,
+}
+
+/// Create a template. Admin only (enforced by caller). Returns the new template id.
+pub fn create_template(
+    e: &Env,
+    schema_hash: BytesN<32>,
+    weight_cap: u32,
+    expiry_seconds: u64,
+) -> u32 {
+    if weight_cap == 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    if weight_cap > MAX_ATTESTATION_WEIGHT {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    let id: u32 = e
+        .storage()
+        .instance()
+        .get(&DataKey::AttestationTemplateCounter)
+        .unwrap_or(0);
+    let next_id = id
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&DataKey::AttestationTemplateCounter, &next_id);
+
+    let template = AttestationTemplate {
+        id,
+        schema_hash: schema_hash.clone(),
+        weight_cap,
+        expiry_seconds,
+        active: true,
+    };
+    e.storage()
+        .instance()
+        .set(&DataKey::AttestationTemplate(id), &template);
+    e.events().publish(
+        (Symbol::new(e, "template_created"), id),
+        (schema_hash, weight_cap, expiry_seconds),
+    );
+    id
+}
+
+/// Enable or disable a template. Admin only (enforced by caller).
+pub fn set_active(e: &Env, template_id: u32, active: bool) {
+    let key = DataKey::AttestationTemplate(template_id);
+    let mut template: AttestationTemplate = e
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::TemplateNotFound));
+    template.active = active;
+    e.storage().instance().set(&key, &template);
+    e.events()
+        .publish((Symbol::new(e, "template_updated"), template_id), active);
+}
+
+/// Get template by id.
+#[must_use]
+pub fn get_template(e: &Env, template_id: u32) -> Option<AttestationTemplate> {
+    e.storage()
+        .instance()
+        .get(&DataKey::AttestationTemplate(template_id))
+}
+
+/// All templates in id order.
+#[must_use]
+pub fn list_templates(e: &Env) -> Vec<AttestationTemplate> {
+    let count: u32 = e
+        .storage()
+        .instance()
+        .get(&DataKey::AttestationTemplateCounter)
+        .unwrap_or(0);
+    let mut out = Vec::new(e);
+    for id in 0..count {
+        if let Some(t) = get_template(e, id) {
+            out.push_back(t);
+        }
+    }
+    out
+}
+
+/// Caps a stake-derived weight at the template's weight cap.
+#[must_use]
+pub fn apply_weight_cap(template: &AttestationTemplate, weight: u32) -> u32 {
+    core::cmp::min(weight, template.weight_cap)
+}
+
+/// Expiry timestamp for an attestation created at `now` (0 = never expires).
+#[must_use]
+pub fn expiry_for(template: &AttestationTemplate, now: u64) -> u64 {
+    if template.expiry_seconds == 0 {
+        return 0;
+    }
+    now.checked_add(template.expiry_seconds)
+        .expect("attestation expiry overflow")
+}
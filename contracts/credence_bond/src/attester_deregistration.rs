@@ -0,0 +1,116 @@
+//! Attester Deregistration
+//!
+//! With a notice period configured, unregistering an attester does not take effect at once.
+//! The attester is announced as leaving (`attester_deregistration_pending`) and, until the
+//! notice elapses, may still revoke its attestations or hand them off to another attester,
+//! but may not issue new ones, so subjects have time to find a replacement. Once the notice
+//! has elapsed the attester is treated as unregistered; `finalize` (callable by anyone)
+//! removes the registration from storage. Without a notice period, unregistration is
+//! immediate.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{attester_directory, BondError, DataKey};
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum DeregistrationKey {
+    /// Notice period in seconds (0 = immediate).
+    NoticePeriod,
+    /// Timestamp at which a pending deregistration takes effect.
+    EffectiveAt(Address),
+}
+
+/// Notice period for deregistrations (0 if unset).
+#[must_use]
+pub fn get_notice_period(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&DeregistrationKey::NoticePeriod)
+        .unwrap_or(0)
+}
+
+/// Set the notice period. Applies to deregistrations scheduled afterwards. Admin only
+/// (enforced by caller).
+pub fn set_notice_period(e: &Env, seconds: u64) {
+    e.storage()
+        .instance()
+        .set(&DeregistrationKey::NoticePeriod, &seconds);
+}
+
+/// When `attester`'s pending deregistration takes effect, if one is pending.
+#[must_use]
+pub fn get_effective_at(e: &Env, attester: &Address) -> Option<u64> {
+    e.storage()
+        .instance()
+        .get(&DeregistrationKey::EffectiveAt(attester.clone()))
+}
+
+/// True once `attester`'s deregistration notice has elapsed (even if not yet finalized).
+#[must_use]
+pub fn is_deregistered(e: &Env, attester: &Address) -> bool {
+    matches!(get_effective_at(e, attester), Some(at) if e.ledger().timestamp() >= at)
+}
+
+/// Announce `attester`'s deregistration effective after the notice period. Returns the
+/// effective timestamp.
+pub fn schedule(e: &Env, attester: &Address) -> u64 {
+    if get_effective_at(e, attester).is_some() {
+        panic_with_error!(e, BondError::AttesterDeregistering);
+    }
+    let effective_at = e
+        .ledger()
+        .timestamp()
+        .checked_add(get_notice_period(e))
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage().instance().set(
+        &DeregistrationKey::EffectiveAt(attester.clone()),
+        &effective_at,
+    );
+    e.events().publish(
+        (
+            Symbol::new(e, "attester_deregistration_pending"),
+            attester.clone(),
+        ),
+        effective_at,
+    );
+    effective_at
+}
+
+/// Drop any pending deregistration of `attester` (re-registration or immediate removal).
+pub fn clear(e: &Env, attester: &Address) {
+    e.storage()
+        .instance()
+        .remove(&DeregistrationKey::EffectiveAt(attester.clone()));
+}
+
+/// Panics if `verifier` is leaving: `AttesterDeregistering` during the notice period,
+/// `UnauthorizedAttester` once it has elapsed.
+pub fn require_can_issue(e: &Env, verifier: &Address) {
+    if get_effective_at(e, verifier).is_some() {
+        if is_deregistered(e, verifier) {
+            panic_with_error!(e, BondError::UnauthorizedAttester);
+        }
+        panic_with_error!(e, BondError::AttesterDeregistering);
+    }
+}
+
+/// Remove a registration whose notice has elapsed. Callable by anyone.
+pub fn finalize(e: &Env, attester: &Address) {
+    match get_effective_at(e, attester) {
+        None => panic_with_error!(e, BondError::InvalidInput),
+        Some(at) if e.ledger().timestamp() < at => {
+            panic_with_error!(e, BondError::NoticeNotElapsed)
+        }
+        Some(_) => {}
+    }
+    e.storage()
+        .instance()
+        .remove(&DeregistrationKey::EffectiveAt(attester.clone()));
+    e.storage()
+        .instance()
+        .remove(&DataKey::Attester(attester.clone()));
+    attester_directory::remove(e, attester);
+    e.events()
+        .publish((Symbol::new(e, "attester_unregistered"),), attester.clone());
+}
@@ -0,0 +1,198 @@
+//! Attester Directory
+//!
+//! On-chain directory of registered attesters so integrators can list verifiers without an
+//! indexer. Every registration adds the attester to an index (with its registration time)
+//! and every completed deregistration removes it. Attesters may publish a display name and a
+//! hash of richer off-chain metadata; the directory view combines it with the attester's
+//! stake, weight, status and issuance count.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::{
+    attester_deregistration, attester_org, attester_suspension, weighted_attestation, BondError,
+    DataKey,
+};
+
+/// Largest page returned by [`list`].
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Longest display name, in bytes.
+pub const MAX_NAME_LEN: u32 = 64;
+
+/// Self-published profile of an attester.
+///
+/// # Fields
+/// * `name` - Display name (1 to `MAX_NAME_LEN` bytes).
+/// * `uri_hash` - Hash of the off-chain metadata document (URI, logo, credentials, ...).
+/// * `updated_at` - Ledger timestamp of the last update.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttesterMetadata {
+    pub name: String,
+    pub uri_hash: BytesN<32>,
+    pub updated_at: u64,
+}
+
+/// Directory entry of a registered attester.
+///
+/// # Fields
+/// * `attester` - Attester address.
+/// * `registered_at` - Ledger timestamp of its (latest) registration.
+/// * `stake` - Stake its attestation weight is computed from.
+/// * `weight` - Weight its next attestation would carry.
+/// * `active` - Registered, not past a deregistration notice and not suspended.
+/// * `attestation_count` - Attestations attributed to it (directly or via its subs).
+/// * `name` - Published display name, if any.
+/// * `uri_hash` - Published metadata hash, if any.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttesterInfo {
+    pub attester: Address,
+    pub registered_at: u64,
+    pub stake: i128,
+    pub weight: u32,
+    pub active: bool,
+    pub attestation_count: u32,
+    pub name: Option<String>,
+    pub uri_hash: Option<BytesN<32>>,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum DirectoryKey {
+    /// Registered attesters, in registration order.
+    RegisteredAttesters,
+    /// Registration timestamp of an attester.
+    AttesterRegisteredAt(Address),
+    /// Published profile of an attester.
+    AttesterMetadata(Address),
+}
+
+fn index(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&DirectoryKey::RegisteredAttesters)
+        .unwrap_or(Vec::new(e))
+}
+
+/// Add `attester` to the directory, (re)setting its registration time.
+pub fn add(e: &Env, attester: &Address) {
+    let mut attesters = index(e);
+    if !attesters.contains(attester) {
+        attesters.push_back(attester.clone());
+        e.storage()
+            .instance()
+            .set(&DirectoryKey::RegisteredAttesters, &attesters);
+    }
+    e.storage().instance().set(
+        &DirectoryKey::AttesterRegisteredAt(attester.clone()),
+        &e.ledger().timestamp(),
+    );
+}
+
+/// Drop `attester` and its profile from the directory.
+pub fn remove(e: &Env, attester: &Address) {
+    let mut attesters = index(e);
+    if let Some(idx) = attesters.first_index_of(attester) {
+        attesters.remove(idx);
+        e.storage()
+            .instance()
+            .set(&DirectoryKey::RegisteredAttesters, &attesters);
+    }
+    e.storage()
+        .instance()
+        .remove(&DirectoryKey::AttesterRegisteredAt(attester.clone()));
+    e.storage()
+        .instance()
+        .remove(&DirectoryKey::AttesterMetadata(attester.clone()));
+}
+
+/// Number of attesters in the directory.
+#[must_use]
+pub fn count(e: &Env) -> u32 {
+    index(e).len()
+}
+
+#[must_use]
+pub fn get_metadata(e: &Env, attester: &Address) -> Option<AttesterMetadata> {
+    e.storage()
+        .instance()
+        .get(&DirectoryKey::AttesterMetadata(attester.clone()))
+}
+
+/// Publish the profile of `attester`. Caller must enforce attester auth.
+pub fn set_metadata(
+    e: &Env,
+    attester: &Address,
+    name: &String,
+    uri_hash: &BytesN<32>,
+) -> AttesterMetadata {
+    if !is_registered(e, attester) {
+        panic_with_error!(e, BondError::UnauthorizedAttester);
+    }
+    if name.is_empty() || name.len() > MAX_NAME_LEN {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    let metadata = AttesterMetadata {
+        name: name.clone(),
+        uri_hash: uri_hash.clone(),
+        updated_at: e.ledger().timestamp(),
+    };
+    e.storage()
+        .instance()
+        .set(&DirectoryKey::AttesterMetadata(attester.clone()), &metadata);
+    e.events().publish(
+        (Symbol::new(e, "attester_metadata_set"), attester.clone()),
+        (name.clone(), uri_hash.clone()),
+    );
+    metadata
+}
+
+fn is_registered(e: &Env, attester: &Address) -> bool {
+    e.storage()
+        .instance()
+        .get::<_, bool>(&DataKey::Attester(attester.clone()))
+        .unwrap_or(false)
+}
+
+/// Directory entry of `attester`, or `None` if it is not registered.
+#[must_use]
+pub fn info(e: &Env, attester: &Address) -> Option<AttesterInfo> {
+    if !is_registered(e, attester) {
+        return None;
+    }
+    let (stake, _) = weighted_attestation::effective_stake(e, attester);
+    let metadata = get_metadata(e, attester);
+    Some(AttesterInfo {
+        attester: attester.clone(),
+        registered_at: e
+            .storage()
+            .instance()
+            .get(&DirectoryKey::AttesterRegisteredAt(attester.clone()))
+            .unwrap_or(0),
+        stake,
+        weight: weighted_attestation::compute_weight(e, attester),
+        active: !attester_deregistration::is_deregistered(e, attester)
+            && !attester_suspension::is_suspended(e, attester),
+        attestation_count: attester_org::get_issued_count(e, attester),
+        name: metadata.as_ref().map(|m| m.name.clone()),
+        uri_hash: metadata.map(|m| m.uri_hash),
+    })
+}
+
+/// Directory entries in registration order, skipping `offset` and returning at most `limit`
+/// (capped at `MAX_PAGE_SIZE`).
+#[must_use]
+pub fn list(e: &Env, offset: u32, limit: u32) -> Vec<AttesterInfo> {
+    let attesters = index(e);
+    let end = offset
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(attesters.len());
+    let mut page = Vec::new(e);
+    for i in offset..end {
+        if let Some(entry) = info(e, &attesters.get_unchecked(i)) {
+            page.push_back(entry);
+        }
+    }
+    page
+}
@@ -0,0 +1,247 @@
+//! Attester Onboarding
+//!
+//! Registers many attesters, with a class label and initial stake, in one admin call.
+//! Every entry is validated before anything is written, so a batch either applies in full
+//! or not at all.
+//!
+//! Prospective attesters can also apply themselves with a hash of their off-chain metadata.
+//! Applications wait in a pending index until the admin approves (registering the attester)
+//! or rejects them.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Map, Symbol, Vec};
+
+use crate::{
+    attester_deregistration, attester_directory, weighted_attestation, BondError, DataKey,
+};
+
+/// Maximum number of entries in one onboarding batch.
+pub const MAX_BATCH_SIZE: u32 = 50;
+
+/// Largest page returned by [`list_pending`].
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// A pending request to become an attester.
+///
+/// # Fields
+/// * `applicant` - Address asking to be registered.
+/// * `metadata_hash` - Hash of the applicant's off-chain metadata (name, documents, ...).
+/// * `applied_at` - Ledger timestamp of the application.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttesterApplication {
+    pub applicant: Address,
+    pub metadata_hash: BytesN<32>,
+    pub applied_at: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum OnboardingKey {
+    /// Class label of an attester (free-form tier or category chosen by the admin).
+    AttesterClass(Address),
+    /// Pending application of an address.
+    AttesterApplication(Address),
+    /// Applicants with a pending application, oldest first.
+    PendingApplications,
+}
+
+/// Register `attester`. Admin only (enforced by caller). Emits `attester_registered`.
+pub fn register(e: &Env, attester: &Address) {
+    e.storage()
+        .instance()
+        .set(&DataKey::Attester(attester.clone()), &true);
+    attester_deregistration::clear(e, attester);
+    attester_directory::add(e, attester);
+    e.events()
+        .publish((Symbol::new(e, "attester_registered"),), attester.clone());
+}
+
+fn check_batch_size(e: &Env, len: u32) {
+    if len == 0 || len > MAX_BATCH_SIZE {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+}
+
+/// Reject duplicate addresses and negative stakes before any state is written.
+fn validate(e: &Env, entries: &Vec<(Address, i128)>) {
+    check_batch_size(e, entries.len());
+    let mut seen: Map<Address, bool> = Map::new(e);
+    for (attester, stake) in entries.iter() {
+        if seen.contains_key(attester.clone()) {
+            panic_with_error!(e, BondError::InvalidInput);
+        }
+        seen.set(attester, true);
+        if stake < 0 {
+            panic_with_error!(e, BondError::InvalidAmount);
+        }
+    }
+}
+
+/// Register each `(attester, class, stake)` entry. Admin only (enforced by caller).
+/// Emits `attester_registered` per entry, like single registration.
+pub fn register_batch(e: &Env, entries: &Vec<(Address, u32, i128)>) {
+    let mut checks = Vec::new(e);
+    for (attester, _, stake) in entries.iter() {
+        checks.push_back((attester, stake));
+    }
+    validate(e, &checks);
+    for (attester, class, stake) in entries.iter() {
+        register(e, &attester);
+        set_class(e, &attester, class);
+        weighted_attestation::set_attester_stake(e, &attester, stake);
+        e.events().publish(
+            (Symbol::new(e, "attester_onboarded"), attester),
+            (class, stake),
+        );
+    }
+}
+
+/// Set the stake of each `(attester, stake)` entry. Admin only (enforced by caller).
+/// Every attester must be registered.
+pub fn set_stakes_batch(e: &Env, entries: &Vec<(Address, i128)>) {
+    validate(e, entries);
+    for (attester, _) in entries.iter() {
+        if !e
+            .storage()
+            .instance()
+            .get::<_, bool>(&DataKey::Attester(attester))
+            .unwrap_or(false)
+        {
+            panic_with_error!(e, BondError::UnauthorizedAttester);
+        }
+    }
+    for (attester, stake) in entries.iter() {
+        weighted_attestation::set_attester_stake(e, &attester, stake);
+        e.events()
+            .publish((Symbol::new(e, "attester_stake_set"), attester), stake);
+    }
+}
+
+fn set_class(e: &Env, attester: &Address, class: u32) {
+    e.storage()
+        .instance()
+        .set(&OnboardingKey::AttesterClass(attester.clone()), &class);
+}
+
+/// Class label of an attester (0 if never set).
+#[must_use]
+pub fn get_class(e: &Env, attester: &Address) -> u32 {
+    e.storage()
+        .instance()
+        .get(&OnboardingKey::AttesterClass(attester.clone()))
+        .unwrap_or(0)
+}
+
+fn pending_applicants(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&OnboardingKey::PendingApplications)
+        .unwrap_or(Vec::new(e))
+}
+
+#[must_use]
+pub fn get_application(e: &Env, applicant: &Address) -> Option<AttesterApplication> {
+    e.storage()
+        .instance()
+        .get(&OnboardingKey::AttesterApplication(applicant.clone()))
+}
+
+/// File an application for `applicant`. Caller must enforce the applicant's auth. Fails with
+/// `InvalidInput` if it is already registered or has a pending application.
+pub fn apply(e: &Env, applicant: &Address, metadata_hash: &BytesN<32>) -> AttesterApplication {
+    let registered = e
+        .storage()
+        .instance()
+        .get::<_, bool>(&DataKey::Attester(applicant.clone()))
+        .unwrap_or(false);
+    if registered || get_application(e, applicant).is_some() {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    let application = AttesterApplication {
+        applicant: applicant.clone(),
+        metadata_hash: metadata_hash.clone(),
+        applied_at: e.ledger().timestamp(),
+    };
+    e.storage().instance().set(
+        &OnboardingKey::AttesterApplication(applicant.clone()),
+        &application,
+    );
+    let mut pending = pending_applicants(e);
+    pending.push_back(applicant.clone());
+    e.storage()
+        .instance()
+        .set(&OnboardingKey::PendingApplications, &pending);
+    e.events().publish(
+        (Symbol::new(e, "attester_applied"), applicant.clone()),
+        metadata_hash.clone(),
+    );
+    application
+}
+
+/// Remove a pending application, panicking with `InvalidInput` if there is none.
+fn take_application(e: &Env, applicant: &Address) -> AttesterApplication {
+    let application = get_application(e, applicant)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::InvalidInput));
+    e.storage()
+        .instance()
+        .remove(&OnboardingKey::AttesterApplication(applicant.clone()));
+    let mut pending = pending_applicants(e);
+    if let Some(idx) = pending.first_index_of(applicant) {
+        pending.remove(idx);
+        e.storage()
+            .instance()
+            .set(&OnboardingKey::PendingApplications, &pending);
+    }
+    application
+}
+
+/// Approve a pending application and register the applicant. Admin only (enforced by
+/// caller).
+pub fn approve(e: &Env, applicant: &Address) -> AttesterApplication {
+    let application = take_application(e, applicant);
+    register(e, applicant);
+    e.events().publish(
+        (
+            Symbol::new(e, "attester_application_approved"),
+            applicant.clone(),
+        ),
+        application.metadata_hash.clone(),
+    );
+    application
+}
+
+/// Reject a pending application. Admin only (enforced by caller).
+pub fn reject(e: &Env, applicant: &Address) -> AttesterApplication {
+    let application = take_application(e, applicant);
+    e.events().publish(
+        (
+            Symbol::new(e, "attester_application_rejected"),
+            applicant.clone(),
+        ),
+        application.metadata_hash.clone(),
+    );
+    application
+}
+
+/// Pending applications, oldest first, skipping `offset` and returning at most `limit`
+/// (capped at `MAX_PAGE_SIZE`).
+#[must_use]
+pub fn list_pending(e: &Env, offset: u32, limit: u32) -> Vec<AttesterApplication> {
+    let applicants = pending_applicants(e);
+    let end = offset
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(applicants.len());
+    let mut page = Vec::new(e);
+    for i in offset..end {
+        if let Some(application) = get_application(e, &applicants.get_unchecked(i)) {
+            page.push_back(application);
+        }
+    }
+    page
+}
+
+/// Number of pending applications.
+#[must_use]
+pub fn pending_count(e: &Env) -> u32 {
+    pending_applicants(e).len()
+}
@@ -0,0 +1,177 @@
+//! Attester Organizations
+//!
+//! A registered attester (the parent organization) can authorize operator keys as
+//! sub-attesters. Attestations issued by a sub-attester are attributed to the parent: they
+//! carry the parent as verifier, use the parent's stake-derived weight (capped per sub), and
+//! count towards the parent's issuance stats. The parent can suspend or remove subs at will.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+use crate::{BondError, DataKey};
+
+/// A sub-attester operating on behalf of a parent organization.
+///
+/// # Fields
+/// * `parent` - Registered attester the sub issues for.
+/// * `weight_cap` - Maximum weight of attestations issued by this sub.
+/// * `suspended` - Suspended subs cannot issue attestations.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubAttester {
+    pub parent: Address,
+    pub weight_cap: u32,
+    pub suspended: bool,
+}
+
+/// Get a sub-attester record.
+#[must_use]
+pub fn get_sub(e: &Env, sub: &Address) -> Option<SubAttester> {
+    e.storage()
+        .instance()
+        .get(&DataKey::SubAttester(sub.clone()))
+}
+
+/// Sub-attesters registered under a parent.
+#[must_use]
+pub fn get_subs(e: &Env, parent: &Address) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&DataKey::OrgSubAttesters(parent.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+fn load_owned_sub(e: &Env, parent: &Address, sub: &Address) -> SubAttester {
+    let record =
+        get_sub(e, sub).unwrap_or_else(|| panic_with_error!(e, BondError::SubAttesterNotFound));
+    if record.parent != *parent {
+        panic_with_error!(e, BondError::NotAttestationOwner);
+    }
+    record
+}
+
+/// Register `sub` under `parent`. Caller must enforce parent auth and that parent is a
+/// registered attester.
+pub fn register_sub(e: &Env, parent: &Address, sub: &Address, weight_cap: u32) {
+    if weight_cap == 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    if parent == sub {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    if get_sub(e, sub).is_some() {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    if e.storage()
+        .instance()
+        .get::<_, bool>(&DataKey::Attester(sub.clone()))
+        .unwrap_or(false)
+    {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+
+    e.storage().instance().set(
+        &DataKey::SubAttester(sub.clone()),
+        &SubAttester {
+            parent: parent.clone(),
+            weight_cap,
+            suspended: false,
+        },
+    );
+    let mut subs = get_subs(e, parent);
+    subs.push_back(sub.clone());
+    e.storage()
+        .instance()
+        .set(&DataKey::OrgSubAttesters(parent.clone()), &subs);
+    e.events().publish(
+        (Symbol::new(e, "sub_attester_registered"), parent.clone()),
+        (sub.clone(), weight_cap),
+    );
+}
+
+/// Suspend or reinstate a sub. Caller must enforce parent auth.
+pub fn set_suspended(e: &Env, parent: &Address, sub: &Address, suspended: bool) {
+    let mut record = load_owned_sub(e, parent, sub);
+    record.suspended = suspended;
+    e.storage()
+        .instance()
+        .set(&DataKey::SubAttester(sub.clone()), &record);
+    e.events().publish(
+        (Symbol::new(e, "sub_attester_suspended"), parent.clone()),
+        (sub.clone(), suspended),
+    );
+}
+
+/// Remove a sub from its parent. Caller must enforce parent auth.
+pub fn remove_sub(e: &Env, parent: &Address, sub: &Address) {
+    load_owned_sub(e, parent, sub);
+    e.storage()
+        .instance()
+        .remove(&DataKey::SubAttester(sub.clone()));
+    let subs = get_subs(e, parent);
+    let mut remaining = Vec::new(e);
+    for s in subs.iter() {
+        if s != *sub {
+            remaining.push_back(s);
+        }
+    }
+    e.storage()
+        .instance()
+        .set(&DataKey::OrgSubAttesters(parent.clone()), &remaining);
+    e.events().publish(
+        (Symbol::new(e, "sub_attester_removed"), parent.clone()),
+        sub.clone(),
+    );
+}
+
+/// Resolve the signer of an attestation to the attester it is attributed to. Returns
+/// `(verifier, weight_cap)`: the signer itself with no cap if it is a registered attester,
+/// or its parent with the sub's cap. Panics if the signer may not attest.
+pub fn resolve_issuer(e: &Env, signer: &Address) -> (Address, Option<u32>) {
+    let is_attester = |a: &Address| -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::Attester(a.clone()))
+            .unwrap_or(false)
+    };
+    if is_attester(signer) {
+        return (signer.clone(), None);
+    }
+    match get_sub(e, signer) {
+        Some(sub) => {
+            if sub.suspended {
+                panic_with_error!(e, BondError::AttesterSuspended);
+            }
+            if !is_attester(&sub.parent) {
+                panic_with_error!(e, BondError::UnauthorizedAttester);
+            }
+            (sub.parent, Some(sub.weight_cap))
+        }
+        None => panic_with_error!(e, BondError::UnauthorizedAttester),
+    }
+}
+
+/// True if `signer` is an active (not suspended) sub-attester of `parent`.
+#[must_use]
+pub fn is_active_sub_of(e: &Env, signer: &Address, parent: &Address) -> bool {
+    match get_sub(e, signer) {
+        Some(sub) => sub.parent == *parent && !sub.suspended,
+        None => false,
+    }
+}
+
+/// Number of attestations attributed to an attester (directly or via its subs).
+#[must_use]
+pub fn get_issued_count(e: &Env, attester: &Address) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::AttesterIssuedCount(attester.clone()))
+        .unwrap_or(0)
+}
+
+/// Increment the attester's issuance count.
+pub fn record_issued(e: &Env, attester: &Address) {
+    let count = get_issued_count(e, attester).saturating_add(1);
+    e.storage()
+        .instance()
+        .set(&DataKey::AttesterIssuedCount(attester.clone()), &count);
+}
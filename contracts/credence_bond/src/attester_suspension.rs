@@ -0,0 +1,108 @@
+//! Attester Suspension
+//!
+//! Temporarily blocks an attester from issuing new attestations without touching its
+//! registration or the attestations it already issued. A suspension lifts by itself once the
+//! ledger passes its end timestamp; setting an end timestamp in the past lifts it early.
+//! The admin or any governor may suspend. [`status`] folds suspension, deregistration and
+//! registration into one answer for integrators.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{attester_deregistration, governance_approval, BondError, DataKey};
+
+/// Lifecycle status of an attester.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AttesterStatus {
+    /// Registered and free to issue attestations.
+    Active = 0,
+    /// Registered but blocked from issuing until its suspension ends.
+    Suspended = 1,
+    /// Not registered (never registered, unregistered or past its deregistration notice).
+    Removed = 2,
+    /// Serving a deregistration notice: cannot issue, may still revoke or hand off.
+    Deregistering = 3,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum AttesterSuspensionKey {
+    /// Timestamp until which the attester is suspended.
+    SuspendedUntil(Address),
+}
+
+/// Timestamp until which the attester is suspended (0 if never suspended or lifted).
+#[must_use]
+pub fn get_suspended_until(e: &Env, attester: &Address) -> u64 {
+    e.storage()
+        .instance()
+        .get(&AttesterSuspensionKey::SuspendedUntil(attester.clone()))
+        .unwrap_or(0)
+}
+
+/// True while the attester's suspension is running.
+#[must_use]
+pub fn is_suspended(e: &Env, attester: &Address) -> bool {
+    e.ledger().timestamp() < get_suspended_until(e, attester)
+}
+
+/// Current status of `attester`. A suspension outranks a pending deregistration.
+#[must_use]
+pub fn status(e: &Env, attester: &Address) -> AttesterStatus {
+    let registered: bool = e
+        .storage()
+        .instance()
+        .get(&DataKey::Attester(attester.clone()))
+        .unwrap_or(false);
+    if !registered || attester_deregistration::is_deregistered(e, attester) {
+        AttesterStatus::Removed
+    } else if is_suspended(e, attester) {
+        AttesterStatus::Suspended
+    } else if attester_deregistration::get_effective_at(e, attester).is_some() {
+        AttesterStatus::Deregistering
+    } else {
+        AttesterStatus::Active
+    }
+}
+
+/// Panics unless `caller` is the admin or a governor. Caller auth is enforced by the caller.
+pub(crate) fn require_admin_or_governor(e: &Env, caller: &Address) {
+    let admin: Address = e
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::NotInitialized));
+    if admin != *caller && !governance_approval::get_governors(e).contains(caller) {
+        panic_with_error!(e, BondError::Unauthorized);
+    }
+}
+
+/// Suspend `attester` until `until` (a timestamp not after now lifts the suspension).
+pub fn suspend(e: &Env, caller: &Address, attester: &Address, until: u64) {
+    require_admin_or_governor(e, caller);
+    let registered: bool = e
+        .storage()
+        .instance()
+        .get(&DataKey::Attester(attester.clone()))
+        .unwrap_or(false);
+    if !registered {
+        panic_with_error!(e, BondError::UnauthorizedAttester);
+    }
+    let key = AttesterSuspensionKey::SuspendedUntil(attester.clone());
+    if until <= e.ledger().timestamp() {
+        e.storage().instance().remove(&key);
+        e.events().publish(
+            (
+                Symbol::new(e, "attester_suspension_lifted"),
+                attester.clone(),
+            ),
+            caller.clone(),
+        );
+        return;
+    }
+    e.storage().instance().set(&key, &until);
+    e.events().publish(
+        (Symbol::new(e, "attester_suspended"), attester.clone()),
+        (caller.clone(), until),
+    );
+}
@@ -0,0 +1,135 @@
+//! Auto Top-Up
+//!
+//! Lets an identity keep its bond at a target tier. When a slash leaves the unslashed
+//! balance (bonded minus slashed) below the target tier's threshold, the contract pulls the
+//! shortfall from the identity's bond token allowance with `transfer_from` and adds it to
+//! the bond, up to a total budget of `max_amount`.
+//!
+//! Voluntary withdrawals never trigger a top-up: they pay tokens out to the identity, and
+//! pulling them straight back would undo the exit.
+//!
+//! A top-up never blocks the slash that triggered it: if the shortfall exceeds the remaining
+//! budget or the allowance cannot cover it, nothing is pulled and `auto_topup_failed` is
+//! emitted instead.
+
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, Symbol};
+
+use crate::{
+    health_factor, reward_accrual, tiered_bond, token_info, verification_snapshot, BondError,
+    BondTier, DataKey, IdentityBond,
+};
+
+/// An identity's auto top-up settings.
+///
+/// # Fields
+/// * `target_tier` - Tier the unslashed balance is kept at.
+/// * `max_amount` - Total the contract may pull across all top-ups.
+/// * `used` - Amount pulled so far.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoTopUp {
+    pub target_tier: BondTier,
+    pub max_amount: i128,
+    pub used: i128,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum AutoTopUpKey {
+    /// Settings of an identity.
+    AutoTopUp(Address),
+}
+
+#[must_use]
+pub fn get(e: &Env, identity: &Address) -> Option<AutoTopUp> {
+    e.storage()
+        .instance()
+        .get(&AutoTopUpKey::AutoTopUp(identity.clone()))
+}
+
+/// Enable (or replace) auto top-up for `identity`, resetting the used budget. Caller must
+/// enforce identity auth.
+pub fn enable(e: &Env, identity: &Address, target_tier: BondTier, max_amount: i128) -> AutoTopUp {
+    if target_tier == BondTier::Bronze {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    if max_amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    let config = AutoTopUp {
+        target_tier: target_tier.clone(),
+        max_amount,
+        used: 0,
+    };
+    e.storage()
+        .instance()
+        .set(&AutoTopUpKey::AutoTopUp(identity.clone()), &config);
+    e.events().publish(
+        (Symbol::new(e, "auto_topup_enabled"), identity.clone()),
+        (target_tier, max_amount),
+    );
+    config
+}
+
+/// Disable auto top-up for `identity`. Caller must enforce identity auth.
+pub fn disable(e: &Env, identity: &Address) {
+    e.storage()
+        .instance()
+        .remove(&AutoTopUpKey::AutoTopUp(identity.clone()));
+}
+
+/// Restore `bond` to its identity's target tier from the allowance if it dropped below it.
+/// Updates and persists the bond; returns the amount pulled.
+pub fn restore_tier(e: &Env, bond: &mut IdentityBond) -> i128 {
+    if !bond.active {
+        return 0;
+    }
+    let Some(mut config) = get(e, &bond.identity) else {
+        return 0;
+    };
+    let threshold =
+        tiered_bond::min_amount_for(&config.target_tier, &tiered_bond::get_thresholds(e));
+    let shortfall =
+        threshold.saturating_sub(bond.bonded_amount.saturating_sub(bond.slashed_amount));
+    if shortfall <= 0 {
+        return 0;
+    }
+    let pulled = shortfall <= config.max_amount - config.used
+        && token_info::get(e).is_some_and(|info| {
+            let this = e.current_contract_address();
+            matches!(
+                token::Client::new(e, &info.address).try_transfer_from(
+                    &this,
+                    &bond.identity,
+                    &this,
+                    &shortfall,
+                ),
+                Ok(Ok(()))
+            )
+        });
+    if !pulled {
+        e.events().publish(
+            (Symbol::new(e, "auto_topup_failed"), bond.identity.clone()),
+            (shortfall, config.max_amount - config.used),
+        );
+        return 0;
+    }
+
+    config.used += shortfall;
+    e.storage()
+        .instance()
+        .set(&AutoTopUpKey::AutoTopUp(bond.identity.clone()), &config);
+    reward_accrual::checkpoint(e);
+    bond.bonded_amount = bond
+        .bonded_amount
+        .checked_add(shortfall)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage().instance().set(&DataKey::Bond, &*bond);
+    verification_snapshot::refresh_bond(e);
+    health_factor::refresh(e);
+    e.events().publish(
+        (Symbol::new(e, "auto_topup"), bond.identity.clone()),
+        (shortfall, config.target_tier),
+    );
+    shortfall
+}
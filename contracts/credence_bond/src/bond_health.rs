@@ -0,0 +1,71 @@
+//! Bond Health
+//!
+//! Read-only summary of a bond for integrators that gate access on it (e.g. lending dApps).
+//! One call returns what is otherwise spread over the bond, the tier, the unbond cooldown
+//! and the slash state, plus whether the bond covers an amount the caller requires.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{
+    rolling_bond, tiered_bond, time_math, unbond_cooldown, unbonding_queue, verification_snapshot,
+    BondTier, IdentityBond,
+};
+
+/// Health summary of a bond.
+///
+/// # Fields
+/// * `identity` - Bond owner.
+/// * `active` - Whether the bond is active.
+/// * `available_balance` - Bonded minus slashed, minus amounts reserved by pending unbonds.
+/// * `slashed_ratio_bps` - Slashed amount over bonded amount, in basis points.
+/// * `lockup_remaining` - Seconds until the current lock-up period ends (0 once ended).
+/// * `tier` - Current tier (including any rolling renewal bonus).
+/// * `is_rolling` - Whether the bond renews automatically.
+/// * `withdrawal_requested_at` - When a rolling withdrawal was requested (0 = none).
+/// * `pending_unbond` - Amount scheduled to leave through the unbond cooldown or queue.
+/// * `covers_required` - Active and `available_balance` is at least the required amount.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondHealth {
+    pub identity: Address,
+    pub active: bool,
+    pub available_balance: i128,
+    pub slashed_ratio_bps: u32,
+    pub lockup_remaining: u64,
+    pub tier: BondTier,
+    pub is_rolling: bool,
+    pub withdrawal_requested_at: u64,
+    pub pending_unbond: i128,
+    pub covers_required: bool,
+}
+
+/// Health of `bond`, checking coverage of `required_amount`.
+#[must_use]
+pub fn compute(e: &Env, bond: &IdentityBond, required_amount: i128) -> BondHealth {
+    let pending_unbond =
+        unbonding_queue::get_pending_total(e).saturating_add(unbond_cooldown::pending_amount(e));
+    let available_balance = bond
+        .bonded_amount
+        .saturating_sub(bond.slashed_amount)
+        .saturating_sub(pending_unbond)
+        .max(0);
+    BondHealth {
+        identity: bond.identity.clone(),
+        active: bond.active,
+        available_balance,
+        slashed_ratio_bps: verification_snapshot::slash_ratio_bps(
+            bond.bonded_amount,
+            bond.slashed_amount,
+        ),
+        lockup_remaining: time_math::remaining_in_period(
+            e.ledger().timestamp(),
+            bond.bond_start,
+            bond.bond_duration,
+        ),
+        tier: tiered_bond::get_tier_for_amount(e, rolling_bond::effective_tier_amount(e, bond)),
+        is_rolling: bond.is_rolling,
+        withdrawal_requested_at: bond.withdrawal_requested_at,
+        pending_unbond,
+        covers_required: bond.active && available_balance >= required_amount,
+    }
+}
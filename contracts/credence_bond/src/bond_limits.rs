@@ -0,0 +1,79 @@
+//! Bond Limits
+//!
+//! Bounds on the amount and duration a bond may be created with. Amounts must always be
+//! positive; the admin can raise the floor with `min_amount` and bound the lock-up with
+//! `min_duration` and `max_duration`. Front-ends read the limits with `get_bond_limits` to
+//! validate before submitting.
+
+use soroban_sdk::{contracttype, panic_with_error, Env, Symbol};
+
+use crate::BondError;
+
+/// Bounds enforced on bond creation, top-ups and duration extensions.
+///
+/// * `min_amount` - Smallest amount a bond may be created with, before fees (0 = any
+///   positive amount).
+/// * `min_duration` - Shortest bond duration in seconds.
+/// * `max_duration` - Longest bond duration in seconds (0 = unbounded).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondLimits {
+    pub min_amount: i128,
+    pub min_duration: u64,
+    pub max_duration: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum LimitsKey {
+    /// Configured `BondLimits`.
+    Limits,
+}
+
+/// Limits used until the admin configures others: any positive amount and duration.
+#[must_use]
+pub fn default_limits() -> BondLimits {
+    BondLimits {
+        min_amount: 0,
+        min_duration: 0,
+        max_duration: 0,
+    }
+}
+
+#[must_use]
+pub fn get(e: &Env) -> BondLimits {
+    e.storage()
+        .instance()
+        .get(&LimitsKey::Limits)
+        .unwrap_or_else(default_limits)
+}
+
+/// Set the limits. `min_amount` must be non-negative and a non-zero `max_duration` must not
+/// be below `min_duration`. Admin only (enforced by caller).
+pub fn set(e: &Env, limits: &BondLimits) {
+    if limits.min_amount < 0
+        || (limits.max_duration != 0 && limits.max_duration < limits.min_duration)
+    {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage().instance().set(&LimitsKey::Limits, limits);
+    e.events()
+        .publish((Symbol::new(e, "bond_limits_set"),), limits.clone());
+}
+
+/// Panics with `InvalidAmount` unless `amount` is positive and at least `min_amount`.
+pub fn require_amount(e: &Env, amount: i128) {
+    if amount <= 0 || amount < get(e).min_amount {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+}
+
+/// Panics with `InvalidInput` unless `duration` is within `[min_duration, max_duration]`.
+pub fn require_duration(e: &Env, duration: u64) {
+    let limits = get(e);
+    if duration < limits.min_duration
+        || (limits.max_duration != 0 && duration > limits.max_duration)
+    {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+}
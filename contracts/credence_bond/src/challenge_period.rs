@@ -0,0 +1,97 @@
+//! Attestation Challenge Period
+//!
+//! An optional activation delay for new attestations. While inside the challenge window an
+//! attestation is pending: it carries no weight and the admin, a governor or its subject can
+//! reject it without going through a dispute. Once the window ends it becomes active on its
+//! own. A window of 0 (the default) activates attestations immediately.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+
+use crate::BondError;
+
+/// Longest allowed challenge window (30 days).
+pub const MAX_CHALLENGE_WINDOW: u64 = 30 * 86_400;
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum ChallengeKey {
+    /// Challenge window in seconds.
+    Window,
+    /// Pending attestations of a subject as (id, weight, active_from).
+    Pending(Address),
+}
+
+/// Challenge window in seconds (0 if never set).
+#[must_use]
+pub fn get_window(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&ChallengeKey::Window)
+        .unwrap_or(0)
+}
+
+/// Set the challenge window. Admin only (enforced by caller).
+pub fn set_window(e: &Env, seconds: u64) {
+    if seconds > MAX_CHALLENGE_WINDOW {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage().instance().set(&ChallengeKey::Window, &seconds);
+}
+
+/// Activation timestamp for an attestation added at `now`.
+#[must_use]
+pub fn active_from(e: &Env, now: u64) -> u64 {
+    now.saturating_add(get_window(e))
+}
+
+fn pending_entries(e: &Env, subject: &Address) -> Vec<(u64, u64, u64)> {
+    e.storage()
+        .instance()
+        .get(&ChallengeKey::Pending(subject.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+/// Drop entries that are already active at `now` and store the rest.
+fn save_live(e: &Env, subject: &Address, entries: Vec<(u64, u64, u64)>, now: u64) {
+    let mut live = Vec::new(e);
+    for entry in entries.iter() {
+        if now < entry.2 {
+            live.push_back(entry);
+        }
+    }
+    let key = ChallengeKey::Pending(subject.clone());
+    if live.is_empty() {
+        e.storage().instance().remove(&key);
+    } else {
+        e.storage().instance().set(&key, &live);
+    }
+}
+
+/// Track a new attestation until it activates. No-op if it is active immediately.
+pub fn track(e: &Env, subject: &Address, id: u64, weight: u32, active_from: u64) {
+    let now = e.ledger().timestamp();
+    if active_from <= now {
+        return;
+    }
+    let mut entries = pending_entries(e, subject);
+    entries.push_back((id, weight as u64, active_from));
+    save_live(e, subject, entries, now);
+}
+
+/// Stop tracking an attestation (revoked or rejected).
+pub fn untrack(e: &Env, subject: &Address, id: u64) {
+    let mut entries = pending_entries(e, subject);
+    if let Some(idx) = entries.iter().position(|entry| entry.0 == id) {
+        entries.remove(idx as u32);
+    }
+    save_live(e, subject, entries, e.ledger().timestamp());
+}
+
+/// Total weight of `subject`'s attestations still pending at `now`.
+#[must_use]
+pub fn pending_weight(e: &Env, subject: &Address, now: u64) -> u64 {
+    pending_entries(e, subject)
+        .iter()
+        .filter(|entry| now < entry.2)
+        .fold(0_u64, |acc, entry| acc.saturating_add(entry.1))
+}
@@ -0,0 +1,199 @@
+//! Victim Compensation
+//!
+//! A slash may name a beneficiary (the counterparty harmed by the identity). A configured share
+//! of the slashed amount is held in escrow for the beneficiary, who can claim it in the bond
+//! token until the claim window closes. After expiry, unclaimed funds are swept into the slash
+//! proceeds and settled to the treasury with them.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{BondError, DataKey};
+
+/// Escrowed compensation for a slash with a named beneficiary.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompensationClaim {
+    pub slash_id: u64,
+    pub beneficiary: Address,
+    /// Amount actually slashed from the bond by this slash.
+    pub slashed_amount: i128,
+    /// Share of `slashed_amount` reserved for the beneficiary.
+    pub amount: i128,
+    /// Last timestamp (inclusive) at which the beneficiary may claim.
+    pub claim_deadline: u64,
+    pub claimed: bool,
+    /// True once unclaimed funds were routed to the treasury.
+    pub swept: bool,
+}
+
+/// Compensation config: treasury for unclaimed funds, beneficiary share and claim window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompensationConfig {
+    pub treasury: Address,
+    pub share_bps: u32,
+    pub claim_window: u64,
+}
+
+/// Set compensation config. Admin only (enforced by caller).
+pub fn set_config(e: &Env, treasury: Address, share_bps: u32, claim_window: u64) {
+    if share_bps > 10_000 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    let config = CompensationConfig {
+        treasury,
+        share_bps,
+        claim_window,
+    };
+    e.storage()
+        .instance()
+        .set(&DataKey::CompensationConfig, &config);
+}
+
+/// Returns the compensation config. Panics if not set.
+pub fn get_config(e: &Env) -> CompensationConfig {
+    e.storage()
+        .instance()
+        .get(&DataKey::CompensationConfig)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::ConfigNotSet))
+}
+
+/// Total compensation currently held in escrow (unclaimed and not swept).
+#[must_use]
+pub fn get_escrow_balance(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::CompensationEscrow)
+        .unwrap_or(0)
+}
+
+fn set_escrow_balance(e: &Env, amount: i128) {
+    e.storage()
+        .instance()
+        .set(&DataKey::CompensationEscrow, &amount);
+}
+
+/// Beneficiary share of a slashed amount.
+#[must_use]
+pub fn calculate_share(slashed_amount: i128, share_bps: u32) -> i128 {
+    slashed_amount
+        .checked_mul(share_bps as i128)
+        .expect("compensation calculation overflow")
+        / 10_000
+}
+
+/// Record an escrowed claim for a slash that removed `slashed_amount` from the bond.
+/// Returns the new slash id.
+pub fn record_claim(e: &Env, beneficiary: &Address, slashed_amount: i128) -> u64 {
+    let config = get_config(e);
+    let id: u64 = e
+        .storage()
+        .instance()
+        .get(&DataKey::CompensationCounter)
+        .unwrap_or(0);
+    let next_id = id
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&DataKey::CompensationCounter, &next_id);
+
+    let amount = calculate_share(slashed_amount, config.share_bps);
+    let claim_deadline = e
+        .ledger()
+        .timestamp()
+        .checked_add(config.claim_window)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    let claim = CompensationClaim {
+        slash_id: id,
+        beneficiary: beneficiary.clone(),
+        slashed_amount,
+        amount,
+        claim_deadline,
+        claimed: false,
+        swept: false,
+    };
+    e.storage()
+        .instance()
+        .set(&DataKey::CompensationClaim(id), &claim);
+    let escrow = get_escrow_balance(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    set_escrow_balance(e, escrow);
+    crate::slash_settlement::withhold(e, amount);
+
+    e.events().publish(
+        (Symbol::new(e, "compensation_escrowed"), id),
+        (beneficiary.clone(), amount, claim_deadline),
+    );
+    id
+}
+
+/// Get a claim by slash id.
+#[must_use]
+pub fn get_claim(e: &Env, slash_id: u64) -> Option<CompensationClaim> {
+    e.storage()
+        .instance()
+        .get(&DataKey::CompensationClaim(slash_id))
+}
+
+fn load_claim(e: &Env, slash_id: u64) -> CompensationClaim {
+    get_claim(e, slash_id).unwrap_or_else(|| panic_with_error!(e, BondError::ClaimNotFound))
+}
+
+/// Pay out an escrowed claim to its beneficiary. Caller must enforce beneficiary auth.
+pub fn claim(e: &Env, beneficiary: &Address, slash_id: u64) -> i128 {
+    let mut claim = load_claim(e, slash_id);
+    if claim.beneficiary != *beneficiary {
+        panic_with_error!(e, BondError::Unauthorized);
+    }
+    if claim.claimed || claim.swept {
+        panic_with_error!(e, BondError::ClaimSettled);
+    }
+    if e.ledger().timestamp() > claim.claim_deadline {
+        panic_with_error!(e, BondError::ClaimExpired);
+    }
+    claim.claimed = true;
+    e.storage()
+        .instance()
+        .set(&DataKey::CompensationClaim(slash_id), &claim);
+    let escrow = get_escrow_balance(e)
+        .checked_sub(claim.amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    set_escrow_balance(e, escrow);
+    crate::funding::release(e, beneficiary, claim.amount);
+
+    e.events().publish(
+        (Symbol::new(e, "compensation_claimed"), slash_id),
+        (beneficiary.clone(), claim.amount),
+    );
+    claim.amount
+}
+
+/// Move an expired, unclaimed claim into the pending slash proceeds owed to the treasury.
+/// Callable by anyone after the deadline.
+pub fn sweep_expired(e: &Env, slash_id: u64) -> i128 {
+    let mut claim = load_claim(e, slash_id);
+    if claim.claimed || claim.swept {
+        panic_with_error!(e, BondError::ClaimSettled);
+    }
+    if e.ledger().timestamp() <= claim.claim_deadline {
+        panic_with_error!(e, BondError::NoticeNotElapsed);
+    }
+    let config = get_config(e);
+    claim.swept = true;
+    e.storage()
+        .instance()
+        .set(&DataKey::CompensationClaim(slash_id), &claim);
+    let escrow = get_escrow_balance(e)
+        .checked_sub(claim.amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    set_escrow_balance(e, escrow);
+    crate::slash_settlement::accrue(e, claim.amount);
+
+    e.events().publish(
+        (Symbol::new(e, "compensation_swept"), slash_id),
+        (config.treasury, claim.amount),
+    );
+    claim.amount
+}
@@ -0,0 +1,127 @@
+//! Configuration Export
+//!
+//! Collects every admin-configurable parameter into one struct so deployment tooling can
+//! diff environments and check a deployment against a reviewed configuration. Unset optional
+//! settings are `None`; settings with defaults report the effective default.
+
+use soroban_sdk::{contracttype, Address, Env, Map, Symbol, Vec};
+
+use crate::admin_actions::AdminRateLimit;
+use crate::probation::ProbationConfig;
+use crate::tiered_bond::TierThresholds;
+use crate::unbonding_queue::UnbondingConfig;
+use crate::{
+    address_book, admin_actions, admin_transfer, attestation_archive, attester_deregistration,
+    challenge_period, compensation, counter_attestation, dedup_policy, early_exit_penalty,
+    features, fees, governance_approval, health_factor, pausable, probation, proposer_age,
+    referral, rolling_bond, schema_version, slash_settlement, tiered_bond, token_adaptor,
+    unbonding_queue, upgrade, weighted_attestation, DataKey,
+};
+
+/// Complete configuration of a bond contract instance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondConfig {
+    pub admin: Option<Address>,
+    pub pending_admin: Option<Address>,
+    pub schema_version: u32,
+    pub features: u32,
+    pub paused: bool,
+    pub upgrade_governance_required: bool,
+    pub fee_treasury: Option<Address>,
+    pub fee_bps: u32,
+    pub fee_schedule: Vec<(i128, u32)>,
+    pub early_exit_treasury: Option<Address>,
+    pub early_exit_penalty_bps: u32,
+    pub weight_multiplier_bps: u32,
+    pub max_weight: u32,
+    pub verifier_cap_bps: u32,
+    pub governors: Vec<Address>,
+    pub quorum_bps: u32,
+    pub min_governors: u32,
+    pub health_at_risk_bps: u32,
+    pub health_critical_bps: u32,
+    pub compensation_treasury: Option<Address>,
+    pub compensation_share_bps: u32,
+    pub compensation_claim_window: u64,
+    pub unbonding: UnbondingConfig,
+    pub renewal_bonus_bps: u32,
+    pub renewal_bonus_max_renewals: u32,
+    pub probation: ProbationConfig,
+    pub referral_fee_bps: u32,
+    pub dedup_policy: dedup_policy::DedupPolicy,
+    pub challenge_window: u64,
+    pub min_proposer_age: u64,
+    pub token_mode: token_adaptor::TokenMode,
+    pub conflict_netting: counter_attestation::ConflictNetting,
+    pub conflict_dispute_contract: Option<Address>,
+    pub archive_min_age: u64,
+    pub admin_rate_limit: AdminRateLimit,
+    pub address_book: Map<Symbol, Address>,
+    pub attester_deregistration_notice: u64,
+    pub tier_thresholds: TierThresholds,
+    pub slash_settlement: slash_settlement::SlashSettlementConfig,
+}
+
+/// Snapshot the current configuration.
+#[must_use]
+pub fn export(e: &Env) -> BondConfig {
+    let (fee_treasury, fee_bps) = fees::get_config(e);
+    let (early_exit_treasury, early_exit_penalty_bps) = match early_exit_penalty::try_get_config(e)
+    {
+        Some((treasury, bps)) => (Some(treasury), bps),
+        None => (None, 0),
+    };
+    let (weight_multiplier_bps, max_weight) = weighted_attestation::get_weight_config(e);
+    let (quorum_bps, min_governors) = governance_approval::get_quorum_config(e);
+    let (health_at_risk_bps, health_critical_bps) = health_factor::get_thresholds(e);
+    let (renewal_bonus_bps, renewal_bonus_max_renewals) = rolling_bond::get_renewal_bonus_config(e);
+    let (compensation_treasury, compensation_share_bps, compensation_claim_window) =
+        if e.storage().instance().has(&DataKey::CompensationConfig) {
+            let c = compensation::get_config(e);
+            (Some(c.treasury), c.share_bps, c.claim_window)
+        } else {
+            (None, 0, 0)
+        };
+    BondConfig {
+        admin: e.storage().instance().get(&DataKey::Admin),
+        pending_admin: admin_transfer::get_pending(e),
+        schema_version: schema_version::get(e),
+        features: features::get_features(e),
+        paused: pausable::is_paused(e),
+        upgrade_governance_required: upgrade::is_governance_required(e),
+        fee_treasury,
+        fee_bps,
+        fee_schedule: fees::get_schedule(e),
+        early_exit_treasury,
+        early_exit_penalty_bps,
+        weight_multiplier_bps,
+        max_weight,
+        verifier_cap_bps: weighted_attestation::get_verifier_cap_bps(e),
+        governors: governance_approval::get_governors(e),
+        quorum_bps,
+        min_governors,
+        health_at_risk_bps,
+        health_critical_bps,
+        compensation_treasury,
+        compensation_share_bps,
+        compensation_claim_window,
+        unbonding: unbonding_queue::get_config(e),
+        renewal_bonus_bps,
+        renewal_bonus_max_renewals,
+        probation: probation::get_config(e),
+        referral_fee_bps: referral::get_fee_bps(e),
+        dedup_policy: dedup_policy::get_global(e),
+        challenge_window: challenge_period::get_window(e),
+        min_proposer_age: proposer_age::get_min_age(e),
+        token_mode: token_adaptor::get_mode(e),
+        conflict_netting: counter_attestation::get_netting(e),
+        conflict_dispute_contract: counter_attestation::get_dispute_contract(e),
+        archive_min_age: attestation_archive::get_min_age(e),
+        admin_rate_limit: admin_actions::get_rate_limit(e),
+        address_book: address_book::entries(e),
+        attester_deregistration_notice: attester_deregistration::get_notice_period(e),
+        tier_thresholds: tiered_bond::get_thresholds(e),
+        slash_settlement: slash_settlement::get_config(e),
+    }
+}
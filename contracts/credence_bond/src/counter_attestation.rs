@@ -0,0 +1,271 @@
+//! Counter-Attestations
+//!
+//! A registered attester can formally contest another attester's attestation with a
+//! counter-attestation referencing it. Counters are indexed by the original attestation id
+//! (the conflict index), shown next to the original in views, and net its weight in the
+//! subject's aggregate according to the configured policy. The admin resolves a conflict by
+//! upholding or dismissing a counter; unresolved counters can instead be escalated to the
+//! dispute contract.
+
+use soroban_sdk::{
+    contracttype, panic_with_error, Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+use crate::{address_book, weighted_attestation, Attestation, BondError, DataKey};
+
+/// How unresolved counters affect the contested attestation's weight.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConflictNetting {
+    /// Counters do not change the original's weight.
+    Ignore = 0,
+    /// The counters' weights are subtracted from the original's (floored at 0). Default.
+    Subtract = 1,
+    /// The original carries no weight while any counter is unresolved.
+    Suspend = 2,
+}
+
+/// Resolution of a counter-attestation.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CounterStatus {
+    Open = 0,
+    /// The counter was right: the original attestation is revoked.
+    Upheld = 1,
+    /// The counter was rejected: it no longer affects the original.
+    Dismissed = 2,
+}
+
+/// A counter-attestation contesting attestation `original_id`.
+///
+/// # Fields
+/// * `dispute_id` - Dispute opened for this conflict, if escalated (0 = not escalated).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CounterAttestation {
+    pub id: u64,
+    pub original_id: u64,
+    pub attester: Address,
+    pub weight: u32,
+    pub reason: String,
+    pub issued_at: u64,
+    pub status: CounterStatus,
+    pub dispute_id: u64,
+}
+
+/// An attestation together with its counters and the weight it nets to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContestedAttestation {
+    pub attestation: Attestation,
+    pub counters: Vec<CounterAttestation>,
+    pub net_weight: u32,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum CounterKey {
+    NextId,
+    Counter(u64),
+    /// Conflict index: counter ids by original attestation id.
+    Conflicts(u64),
+    Netting,
+    /// Dispute contract that receives escalated conflicts.
+    DisputeContract,
+}
+
+#[must_use]
+pub fn get_netting(e: &Env) -> ConflictNetting {
+    e.storage()
+        .instance()
+        .get(&CounterKey::Netting)
+        .unwrap_or(ConflictNetting::Subtract)
+}
+
+/// Set the netting policy. Admin only (enforced by caller).
+pub fn set_netting(e: &Env, policy: ConflictNetting) {
+    e.storage().instance().set(&CounterKey::Netting, &policy);
+}
+
+/// Dispute contract for escalations; falls back to the address book's dispute role.
+#[must_use]
+pub fn get_dispute_contract(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get(&CounterKey::DisputeContract)
+        .or_else(|| address_book::resolve(e, &Symbol::new(e, address_book::ROLE_DISPUTE)))
+}
+
+/// Set the dispute contract for escalations. Admin only (enforced by caller).
+pub fn set_dispute_contract(e: &Env, contract: &Address) {
+    e.storage()
+        .instance()
+        .set(&CounterKey::DisputeContract, contract);
+}
+
+#[must_use]
+pub fn get_counter(e: &Env, id: u64) -> Option<CounterAttestation> {
+    e.storage().instance().get(&CounterKey::Counter(id))
+}
+
+fn require_counter(e: &Env, id: u64) -> CounterAttestation {
+    get_counter(e, id).unwrap_or_else(|| panic_with_error!(e, BondError::AttestationNotFound))
+}
+
+fn save_counter(e: &Env, counter: &CounterAttestation) {
+    e.storage()
+        .instance()
+        .set(&CounterKey::Counter(counter.id), counter);
+}
+
+/// Counters filed against `original_id`, oldest first.
+#[must_use]
+pub fn counters_of(e: &Env, original_id: u64) -> Vec<CounterAttestation> {
+    let ids: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&CounterKey::Conflicts(original_id))
+        .unwrap_or(Vec::new(e));
+    let mut counters = Vec::new(e);
+    for id in ids.iter() {
+        if let Some(c) = get_counter(e, id) {
+            counters.push_back(c);
+        }
+    }
+    counters
+}
+
+/// File a counter by registered attester `attester` against active attestation `original`.
+/// Caller enforces auth and nonce.
+pub fn issue(e: &Env, attester: &Address, original: &Attestation, reason: String) -> u64 {
+    let registered: bool = e
+        .storage()
+        .instance()
+        .get(&DataKey::Attester(attester.clone()))
+        .unwrap_or(false);
+    if !registered {
+        panic_with_error!(e, BondError::UnauthorizedAttester);
+    }
+    if original.verifier == *attester {
+        panic_with_error!(e, BondError::InvalidAttestationState);
+    }
+    if !original.is_active_at(e.ledger().timestamp()) {
+        panic_with_error!(e, BondError::InvalidAttestationState);
+    }
+    let existing = counters_of(e, original.id);
+    if existing.iter().any(|c| c.attester == *attester) {
+        panic_with_error!(e, BondError::InvalidAttestationState);
+    }
+
+    let id: u64 = e.storage().instance().get(&CounterKey::NextId).unwrap_or(0);
+    e.storage().instance().set(
+        &CounterKey::NextId,
+        &id.checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow)),
+    );
+    let counter = CounterAttestation {
+        id,
+        original_id: original.id,
+        attester: attester.clone(),
+        weight: weighted_attestation::compute_weight(e, attester),
+        reason,
+        issued_at: e.ledger().timestamp(),
+        status: CounterStatus::Open,
+        dispute_id: 0,
+    };
+    save_counter(e, &counter);
+    let key = CounterKey::Conflicts(original.id);
+    let mut ids: Vec<u64> = e.storage().instance().get(&key).unwrap_or(Vec::new(e));
+    ids.push_back(id);
+    e.storage().instance().set(&key, &ids);
+
+    e.events().publish(
+        (
+            Symbol::new(e, "attestation_countered"),
+            original.identity.clone(),
+        ),
+        (original.id, id, attester.clone(), counter.weight),
+    );
+    id
+}
+
+/// Close an open counter with `status`. Returns the counter. Admin only (enforced by
+/// caller); revoking the original on `Upheld` is the caller's job.
+pub fn resolve(e: &Env, counter_id: u64, status: CounterStatus) -> CounterAttestation {
+    if status == CounterStatus::Open {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    let mut counter = require_counter(e, counter_id);
+    if counter.status != CounterStatus::Open {
+        panic_with_error!(e, BondError::InvalidAttestationState);
+    }
+    counter.status = status;
+    save_counter(e, &counter);
+    e.events().publish(
+        (Symbol::new(e, "counter_resolved"), counter.original_id),
+        (counter_id, status),
+    );
+    counter
+}
+
+/// Open a dispute in the configured dispute contract for an open counter, staked by its
+/// attester. Caller enforces the attester's auth, which also covers the nested call.
+pub fn escalate(
+    e: &Env,
+    attester: &Address,
+    counter_id: u64,
+    stake: i128,
+    token: &Address,
+    resolution_deadline: u64,
+) -> u64 {
+    let mut counter = require_counter(e, counter_id);
+    if counter.attester != *attester {
+        panic_with_error!(e, BondError::NotAttestationOwner);
+    }
+    if counter.status != CounterStatus::Open {
+        panic_with_error!(e, BondError::InvalidAttestationState);
+    }
+    if counter.dispute_id != 0 {
+        panic_with_error!(e, BondError::InvalidAttestationState);
+    }
+    let dispute =
+        get_dispute_contract(e).unwrap_or_else(|| panic_with_error!(e, BondError::ConfigNotSet));
+    let args: Vec<Val> = (
+        attester.clone(),
+        counter.original_id,
+        stake,
+        token.clone(),
+        resolution_deadline,
+    )
+        .into_val(e);
+    let dispute_id: u64 = e.invoke_contract(&dispute, &Symbol::new(e, "create_dispute"), args);
+    counter.dispute_id = dispute_id;
+    save_counter(e, &counter);
+    e.events().publish(
+        (Symbol::new(e, "conflict_escalated"), counter.original_id),
+        (counter_id, dispute_id),
+    );
+    dispute_id
+}
+
+/// Weight `attestation` contributes after netting its open counters.
+#[must_use]
+pub fn net_weight(e: &Env, attestation: &Attestation) -> u32 {
+    let policy = get_netting(e);
+    if policy == ConflictNetting::Ignore {
+        return attestation.weight;
+    }
+    let mut countered = 0_u32;
+    let mut open = false;
+    for c in counters_of(e, attestation.id).iter() {
+        if c.status == CounterStatus::Open {
+            open = true;
+            countered = countered.saturating_add(c.weight);
+        }
+    }
+    match policy {
+        ConflictNetting::Suspend if open => 0,
+        ConflictNetting::Subtract => attestation.weight.saturating_sub(countered),
+        _ => attestation.weight,
+    }
+}
@@ -0,0 +1,106 @@
+//! Credential Proof Bundle
+//!
+//! A compact, hash-committed summary of a subject's credentials (tier, active weight per
+//! claim type, attester set) that the subject can hand to off-chain verifiers. The
+//! verifier checks the commitment locally and can later confirm the bundle against the
+//! chain with `verify_credential_proof`, without reading the subject's attestations itself.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map, String, Vec};
+
+use crate::{
+    counter_attestation, verification_snapshot, weighted_attestation, Attestation, BondTier,
+    DataKey,
+};
+
+/// Credential summary of a subject.
+///
+/// # Fields
+/// * `subject` - Identity the bundle describes.
+/// * `tier` - Tier of the subject's bond (Bronze if it never bonded here).
+/// * `total_weight` - Aggregate active weight (per-verifier cap applied).
+/// * `weights_by_claim` - Active weight per claim type (attestation data), net of open
+///   counter-attestations.
+/// * `attester_commitment` - `sha256` of the sorted set of verifiers with an active attestation.
+/// * `ledger_sequence` - Ledger sequence the bundle was produced at.
+/// * `timestamp` - Ledger timestamp the bundle was produced at.
+/// * `commitment` - `sha256` over all fields above, in XDR.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CredentialProof {
+    pub subject: Address,
+    pub tier: BondTier,
+    pub total_weight: u64,
+    pub weights_by_claim: Map<String, u64>,
+    pub attester_commitment: BytesN<32>,
+    pub ledger_sequence: u32,
+    pub timestamp: u64,
+    pub commitment: BytesN<32>,
+}
+
+/// Build the proof bundle for `subject` from current state.
+#[must_use]
+pub fn build(e: &Env, subject: &Address) -> CredentialProof {
+    let ids: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&DataKey::SubjectAttestations(subject.clone()))
+        .unwrap_or(Vec::new(e));
+    let now = e.ledger().timestamp();
+    let mut weights_by_claim: Map<String, u64> = Map::new(e);
+    let mut attesters: Map<Address, bool> = Map::new(e);
+    for id in ids.iter() {
+        let att: Option<Attestation> = e.storage().instance().get(&DataKey::Attestation(id));
+        if let Some(att) = att {
+            if att.is_active_at(now) {
+                let current = weights_by_claim
+                    .get(att.attestation_data.clone())
+                    .unwrap_or(0);
+                let weight = counter_attestation::net_weight(e, &att) as u64;
+                weights_by_claim.set(att.attestation_data, current.saturating_add(weight));
+                attesters.set(att.verifier, true);
+            }
+        }
+    }
+
+    let mut proof = CredentialProof {
+        subject: subject.clone(),
+        tier: verification_snapshot::get(e, subject).tier,
+        total_weight: weighted_attestation::aggregate_weight(e, subject),
+        weights_by_claim,
+        attester_commitment: e.crypto().sha256(&attesters.keys().to_xdr(e)).into(),
+        ledger_sequence: e.ledger().sequence(),
+        timestamp: now,
+        commitment: BytesN::from_array(e, &[0; 32]),
+    };
+    proof.commitment = commitment(e, &proof);
+    proof
+}
+
+/// Commitment over every field of `proof` except `commitment` itself.
+#[must_use]
+pub fn commitment(e: &Env, proof: &CredentialProof) -> BytesN<32> {
+    let mut preimage = Bytes::new(e);
+    preimage.append(&proof.subject.clone().to_xdr(e));
+    preimage.append(&proof.tier.clone().to_xdr(e));
+    preimage.append(&proof.total_weight.to_xdr(e));
+    preimage.append(&proof.weights_by_claim.clone().to_xdr(e));
+    preimage.append(&proof.attester_commitment.clone().to_xdr(e));
+    preimage.append(&proof.ledger_sequence.to_xdr(e));
+    preimage.append(&proof.timestamp.to_xdr(e));
+    e.crypto().sha256(&preimage).into()
+}
+
+/// True if `proof`'s commitment is intact and its credentials still match the chain.
+/// `ledger_sequence` and `timestamp` only describe when the bundle was produced.
+#[must_use]
+pub fn verify(e: &Env, proof: &CredentialProof) -> bool {
+    if commitment(e, proof) != proof.commitment {
+        return false;
+    }
+    let current = build(e, &proof.subject);
+    current.tier == proof.tier
+        && current.total_weight == proof.total_weight
+        && current.weights_by_claim == proof.weights_by_claim
+        && current.attester_commitment == proof.attester_commitment
+}
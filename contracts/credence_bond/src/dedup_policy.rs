@@ -0,0 +1,78 @@
+//! Attestation Duplication Policy
+//!
+//! Decides whether a verifier may issue the same claim (same verifier, subject and data)
+//! again while an earlier attestation of it exists. The policy is set globally and can be
+//! overridden per claim type (the attestation data).
+
+use soroban_sdk::{contracttype, Env, String};
+
+use crate::Attestation;
+
+/// When re-issuing an existing claim is allowed.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DedupPolicy {
+    /// Never: the claim can be issued once per verifier and subject.
+    RejectAlways = 0,
+    /// Once the earlier attestation was revoked (default).
+    AllowAfterRevocation = 1,
+    /// Once the earlier attestation is no longer active: expired or revoked.
+    AllowAfterExpiry = 2,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum DedupPolicyKey {
+    /// Policy applied to claim types without an override.
+    Global,
+    /// Override for one claim type.
+    ClaimType(String),
+}
+
+/// Global policy (`AllowAfterRevocation` if never set).
+#[must_use]
+pub fn get_global(e: &Env) -> DedupPolicy {
+    e.storage()
+        .instance()
+        .get(&DedupPolicyKey::Global)
+        .unwrap_or(DedupPolicy::AllowAfterRevocation)
+}
+
+/// Set the global policy. Admin only (enforced by caller).
+pub fn set_global(e: &Env, policy: DedupPolicy) {
+    e.storage().instance().set(&DedupPolicyKey::Global, &policy);
+}
+
+/// Override for a claim type, if any.
+#[must_use]
+pub fn get_for_claim(e: &Env, claim: &String) -> Option<DedupPolicy> {
+    e.storage()
+        .instance()
+        .get(&DedupPolicyKey::ClaimType(claim.clone()))
+}
+
+/// Set (`Some`) or clear (`None`) the override for a claim type. Admin only (enforced by
+/// caller).
+pub fn set_for_claim(e: &Env, claim: &String, policy: Option<DedupPolicy>) {
+    let key = DedupPolicyKey::ClaimType(claim.clone());
+    match policy {
+        Some(p) => e.storage().instance().set(&key, &p),
+        None => e.storage().instance().remove(&key),
+    }
+}
+
+/// Policy in force for a claim type: its override, else the global policy.
+#[must_use]
+pub fn effective(e: &Env, claim: &String) -> DedupPolicy {
+    get_for_claim(e, claim).unwrap_or_else(|| get_global(e))
+}
+
+/// True if `policy` allows issuing the claim again while `existing` is on record.
+#[must_use]
+pub fn allows_reissue(policy: DedupPolicy, existing: &Attestation, now: u64) -> bool {
+    match policy {
+        DedupPolicy::RejectAlways => false,
+        DedupPolicy::AllowAfterRevocation => existing.revoked,
+        DedupPolicy::AllowAfterExpiry => existing.revoked || existing.is_expired_at(now),
+    }
+}
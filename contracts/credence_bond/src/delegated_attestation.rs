@@ -0,0 +1,66 @@
+//! Delegated Attestation Issuance
+//!
+//! Lets a delegate issue attestations on behalf of a registered attester. The delegation
+//! itself lives in the credence_delegation contract set with `set_delegation_contract` (or,
+//! if unset, registered under the `delegation` role of the address book): before
+//! accepting, this contract asks it whether the delegate holds
+//! a valid (unrevoked, unexpired) attestation delegation from the attester. The attestation
+//! is issued as the attester's, consuming the attester's nonce.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::{address_book, BondError};
+
+/// Mirror of credence_delegation's `DelegationType` (same variant names).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DelegationKind {
+    Attestation,
+    Management,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum DelegationKey {
+    /// Explicitly configured delegation contract.
+    DelegationContract,
+}
+
+/// The configured delegation contract, else the one in the address book, if any.
+#[must_use]
+pub fn delegation_contract(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get(&DelegationKey::DelegationContract)
+        .or_else(|| address_book::resolve(e, &Symbol::new(e, address_book::ROLE_DELEGATION)))
+}
+
+/// Set the delegation contract consulted for delegated issuance. Admin only (enforced by
+/// caller).
+pub fn set_delegation_contract(e: &Env, contract: &Address) {
+    let previous = delegation_contract(e);
+    e.storage()
+        .instance()
+        .set(&DelegationKey::DelegationContract, contract);
+    e.events().publish(
+        (Symbol::new(e, "delegation_contract_set"),),
+        (previous, contract.clone()),
+    );
+}
+
+/// Panics with `Unauthorized` unless `delegate` holds a valid attestation delegation from
+/// `attester` (`ConfigNotSet` without a delegation contract).
+pub fn require_valid_delegate(e: &Env, attester: &Address, delegate: &Address) {
+    let contract =
+        delegation_contract(e).unwrap_or_else(|| panic_with_error!(e, BondError::ConfigNotSet));
+    let args: Vec<Val> = (
+        attester.clone(),
+        delegate.clone(),
+        DelegationKind::Attestation,
+    )
+        .into_val(e);
+    let valid: bool = e.invoke_contract(&contract, &Symbol::new(e, "is_valid_delegate"), args);
+    if !valid {
+        panic_with_error!(e, BondError::Unauthorized);
+    }
+}
@@ -0,0 +1,103 @@
+//! Dispute Linkage
+//!
+//! Tracks which disputes are open against an identity's bond and the outcome of the last
+//! resolved one, so relying contracts and wallets can see whether a slash is being contested
+//! without reading dispute-contract state. Disputes are reported by the admin (or the dispute
+//! contract acting as admin) when they are opened and resolved.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+use crate::BondError;
+
+/// Outcome of a resolved dispute, mirroring the dispute contract's `DisputeOutcome`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LinkedDisputeOutcome {
+    /// No dispute has been resolved for this identity yet.
+    None = 0,
+    FavorDisputer = 1,
+    FavorSlasher = 2,
+}
+
+/// Dispute status surfaced for an identity's bond.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondDisputeStatus {
+    /// Number of disputes currently open against the identity.
+    pub open_disputes: u32,
+    /// Ids of the open disputes, in the order they were reported.
+    pub open_dispute_ids: Vec<u64>,
+    pub last_dispute_outcome: LinkedDisputeOutcome,
+    /// Id of the last resolved dispute (0 if none).
+    pub last_dispute_id: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum DisputeLinkKey {
+    OpenDisputes(Address),
+    LastResolved(Address),
+}
+
+fn open_ids(e: &Env, identity: &Address) -> Vec<u64> {
+    e.storage()
+        .instance()
+        .get(&DisputeLinkKey::OpenDisputes(identity.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+/// Record a dispute opened against `identity`. Admin only (enforced by caller).
+pub fn link(e: &Env, identity: &Address, dispute_id: u64) {
+    let mut ids = open_ids(e, identity);
+    if ids.contains(dispute_id) {
+        panic_with_error!(e, BondError::InvalidDisputeState);
+    }
+    ids.push_back(dispute_id);
+    e.storage()
+        .instance()
+        .set(&DisputeLinkKey::OpenDisputes(identity.clone()), &ids);
+    e.events().publish(
+        (Symbol::new(e, "dispute_linked"), identity.clone()),
+        dispute_id,
+    );
+}
+
+/// Record the resolution of an open dispute. Admin only (enforced by caller).
+pub fn resolve(e: &Env, identity: &Address, dispute_id: u64, outcome: LinkedDisputeOutcome) {
+    if outcome == LinkedDisputeOutcome::None {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    let mut ids = open_ids(e, identity);
+    let idx = ids
+        .first_index_of(dispute_id)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::InvalidDisputeState));
+    ids.remove(idx);
+    e.storage()
+        .instance()
+        .set(&DisputeLinkKey::OpenDisputes(identity.clone()), &ids);
+    e.storage().instance().set(
+        &DisputeLinkKey::LastResolved(identity.clone()),
+        &(dispute_id, outcome),
+    );
+    e.events().publish(
+        (Symbol::new(e, "dispute_unlinked"), identity.clone()),
+        (dispute_id, outcome),
+    );
+}
+
+/// Current dispute status of `identity`.
+#[must_use]
+pub fn status(e: &Env, identity: &Address) -> BondDisputeStatus {
+    let ids = open_ids(e, identity);
+    let (last_dispute_id, last_dispute_outcome) = e
+        .storage()
+        .instance()
+        .get(&DisputeLinkKey::LastResolved(identity.clone()))
+        .unwrap_or((0, LinkedDisputeOutcome::None));
+    BondDisputeStatus {
+        open_disputes: ids.len(),
+        open_dispute_ids: ids,
+        last_dispute_outcome,
+        last_dispute_id,
+    }
+}
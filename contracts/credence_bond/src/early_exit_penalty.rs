@@ -2,13 +2,52 @@
 //!
 //! Charges a configurable fee when users withdraw before the lock-up period ends.
 //! Penalty is proportional to remaining lock time and is transferred to the treasury.
+//!
+//! By default the penalty is reserved until `collect_penalties`. With treasury
+//! routing enabled it is instead transferred to the treasury at exit time and booked there
+//! through credence_treasury's `receive_fee` as `ProtocolFee`, so penalties show up in the
+//! treasury's source accounting.
+//!
+//! An optional stepwise schedule replaces the linear formula with brackets keyed by the share
+//! of the lock-up still remaining, e.g. 10% with at least 75% remaining down to 1% near the
+//! end.
 
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::slash_settlement::TreasuryFundSource;
+use crate::{token_info, BondError};
 
 /// Storage key for treasury address.
 const KEY_TREASURY: &str = "treasury";
 /// Storage key for early exit penalty rate in basis points (e.g. 500 = 5%).
 const KEY_PENALTY_BPS: &str = "early_exit_penalty_bps";
+/// Storage key for routing penalties through a credence_treasury contract.
+const KEY_ROUTE_TO_TREASURY: &str = "early_exit_route";
+
+/// Max number of brackets in a penalty schedule.
+pub const MAX_PENALTY_BRACKETS: u32 = 10;
+
+/// Storage keys of the penalty schedule.
+#[contracttype]
+pub enum EarlyExitKey {
+    /// Ordered `(remaining_bps, penalty_bps)` brackets, see [`set_schedule`].
+    PenaltySchedule,
+}
+
+/// Returns (treasury, penalty_bps), or `None` if the treasury was never configured.
+#[must_use]
+pub fn try_get_config(e: &Env) -> Option<(Address, u32)> {
+    let treasury = e
+        .storage()
+        .instance()
+        .get::<_, Address>(&Symbol::new(e, KEY_TREASURY))?;
+    let bps = e
+        .storage()
+        .instance()
+        .get::<_, u32>(&Symbol::new(e, KEY_PENALTY_BPS))
+        .unwrap_or(0);
+    Some((treasury, bps))
+}
 
 /// Returns (treasury, penalty_bps). Panics if config not set.
 pub fn get_config(e: &Env) -> (Address, u32) {
@@ -16,19 +55,19 @@ pub fn get_config(e: &Env) -> (Address, u32) {
         .storage()
         .instance()
         .get::<_, Address>(&Symbol::new(e, KEY_TREASURY))
-        .unwrap_or_else(|| panic!("early exit config not set"));
+        .unwrap_or_else(|| panic_with_error!(e, BondError::ConfigNotSet));
     let bps = e
         .storage()
         .instance()
         .get::<_, u32>(&Symbol::new(e, KEY_PENALTY_BPS))
-        .unwrap_or_else(|| panic!("early exit penalty bps not set"));
+        .unwrap_or_else(|| panic_with_error!(e, BondError::ConfigNotSet));
     (treasury, bps)
 }
 
 /// Set early exit config. Only admin should call (enforced by caller).
 pub fn set_config(e: &Env, treasury: Address, penalty_bps: u32) {
     if penalty_bps > 10_000 {
-        panic!("penalty_bps must be <= 10000 (100%)");
+        panic_with_error!(e, BondError::InvalidConfig);
     }
     e.storage()
         .instance()
@@ -38,6 +77,121 @@ pub fn set_config(e: &Env, treasury: Address, penalty_bps: u32) {
         .set(&Symbol::new(e, KEY_PENALTY_BPS), &penalty_bps);
 }
 
+/// True if the treasury is a credence_treasury contract penalties are routed through.
+#[must_use]
+pub fn is_routed(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_ROUTE_TO_TREASURY))
+        .unwrap_or(false)
+}
+
+/// Enable or disable treasury routing. Enabling requires a bond token. Only admin should
+/// call (enforced by caller).
+pub fn set_routed(e: &Env, enabled: bool) {
+    if enabled && token_info::get(e).is_none() {
+        panic_with_error!(e, BondError::TokenNotSet);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_ROUTE_TO_TREASURY), &enabled);
+}
+
+/// Transfer `amount` of the bond token to `treasury` and book it there as a protocol fee.
+pub fn route_to_treasury(e: &Env, treasury: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let info = token_info::get(e).unwrap_or_else(|| panic_with_error!(e, BondError::TokenNotSet));
+    let this = e.current_contract_address();
+    token::Client::new(e, &info.address).transfer(&this, treasury, &amount);
+    let args: Vec<Val> = (this, amount, TreasuryFundSource::ProtocolFee).into_val(e);
+    e.invoke_contract::<()>(treasury, &Symbol::new(e, "receive_fee"), args);
+    e.events().publish(
+        (
+            Symbol::new(e, "early_exit_penalty_routed"),
+            treasury.clone(),
+        ),
+        (info.address, amount),
+    );
+}
+
+/// Penalty brackets, empty if none were set.
+#[must_use]
+pub fn get_schedule(e: &Env) -> Vec<(u32, u32)> {
+    e.storage()
+        .instance()
+        .get(&EarlyExitKey::PenaltySchedule)
+        .unwrap_or(Vec::new(e))
+}
+
+/// Set ordered `(remaining_bps, penalty_bps)` brackets with strictly increasing
+/// `remaining_bps`. An exit pays the bracket with the highest `remaining_bps` not above the
+/// share of the lock-up still remaining; shares below the first bracket use the linear
+/// formula. An empty schedule restores the linear formula. Admin only (enforced by caller).
+pub fn set_schedule(e: &Env, schedule: &Vec<(u32, u32)>) {
+    if schedule.len() > MAX_PENALTY_BRACKETS {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    let mut previous: Option<u32> = None;
+    for (remaining_bps, penalty_bps) in schedule.iter() {
+        if remaining_bps > 10_000 || penalty_bps > 10_000 {
+            panic_with_error!(e, BondError::InvalidConfig);
+        }
+        if previous.is_some_and(|p| remaining_bps <= p) {
+            panic_with_error!(e, BondError::InvalidConfig);
+        }
+        previous = Some(remaining_bps);
+    }
+    if schedule.is_empty() {
+        e.storage()
+            .instance()
+            .remove(&EarlyExitKey::PenaltySchedule);
+    } else {
+        e.storage()
+            .instance()
+            .set(&EarlyExitKey::PenaltySchedule, schedule);
+    }
+}
+
+/// Share of the lock-up still remaining, in basis points.
+#[must_use]
+pub fn remaining_share_bps(remaining_time: u64, total_duration: u64) -> u32 {
+    if total_duration == 0 {
+        return 0;
+    }
+    ((remaining_time.min(total_duration) as u128 * 10_000) / total_duration as u128) as u32
+}
+
+/// Penalty rate (bps) of the bracket `remaining_bps` falls in, if any.
+#[must_use]
+pub fn bracket_bps(e: &Env, remaining_bps: u32) -> Option<u32> {
+    let mut bps = None;
+    for (threshold, bracket) in get_schedule(e).iter() {
+        if remaining_bps < threshold {
+            break;
+        }
+        bps = Some(bracket);
+    }
+    bps
+}
+
+/// Penalty for withdrawing `amount` with `remaining_time` of `total_duration` left: the
+/// schedule bracket's rate if one applies, else the linear formula with `penalty_bps`.
+#[must_use]
+pub fn penalty_for(
+    e: &Env,
+    amount: i128,
+    remaining_time: u64,
+    total_duration: u64,
+    penalty_bps: u32,
+) -> i128 {
+    match bracket_bps(e, remaining_share_bps(remaining_time, total_duration)) {
+        Some(bps) if total_duration > 0 => amount.checked_mul(bps as i128).unwrap_or(0) / 10_000,
+        _ => calculate_penalty(amount, remaining_time, total_duration, penalty_bps),
+    }
+}
+
 /// Calculate early exit penalty based on remaining lock time.
 /// penalty = (amount * penalty_bps / 10000) * remaining_time / total_duration
 /// Uses integer math to avoid overflow: (amount * penalty_bps / 10000) * remaining_time / total_duration
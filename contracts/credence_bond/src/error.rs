@@ -0,0 +1,122 @@
+//! Contract errors.
+//!
+//! Codes are allocated from the three `credence_bond` ranges of the shared Credence error
+//! namespace (`credence_common::errors`): bond lifecycle 100-199, attestations 200-299 and
+//! governance / slashing 300-399. Entrypoints return these as `Err`; internal helpers that
+//! cannot return a `Result` raise them with `panic_with_error!`, which reaches the caller as
+//! the same contract error.
+
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BondError {
+    // ── Bond lifecycle (100-199) ─────────────────────────────────────────────
+    /// `initialize` has not been called.
+    NotInitialized = 101,
+    /// Caller is not the contract admin.
+    NotAdmin = 102,
+    /// Caller is not allowed to perform this action.
+    Unauthorized = 103,
+    /// No bond exists, or it does not belong to the given identity.
+    NoBond = 104,
+    /// No bond token has been configured.
+    TokenNotSet = 105,
+    /// Amount is zero, negative, below a configured minimum or above what may be taken, or a
+    /// balance that must be empty is not.
+    InvalidAmount = 106,
+    /// Available (unslashed, not queued) balance does not cover the request.
+    InsufficientBalance = 107,
+    /// Early withdrawal attempted after the lock-up ended, or a withdrawal request cancelled
+    /// after its notice period elapsed.
+    LockupElapsed = 108,
+    /// Rolling-bond withdrawal attempted without a prior request.
+    WithdrawalNotRequested = 109,
+    /// A withdrawal has already been requested.
+    WithdrawalAlreadyRequested = 110,
+    /// A notice period, cooldown or claim window has not elapsed yet.
+    NoticeNotElapsed = 111,
+    /// Operation requires a rolling bond.
+    NotRollingBond = 112,
+    /// Arithmetic overflow or underflow.
+    Overflow = 113,
+    /// Configuration value out of range.
+    InvalidConfig = 114,
+    /// Required configuration has not been set.
+    ConfigNotSet = 115,
+    /// Feature is switched off in the feature flags.
+    FeatureDisabled = 116,
+    /// Payout would leave the contract unable to cover its reserves.
+    ReserveBreach = 117,
+    /// Token is the bond token and cannot be recovered.
+    TokenNotRecoverable = 118,
+    /// Re-entrant call into a guarded entrypoint.
+    Reentrancy = 119,
+    /// Admin action rate limit or attestation cap exceeded.
+    RateLimited = 120,
+    /// Stored schema version does not match, or cannot be migrated.
+    SchemaVersionMismatch = 121,
+    /// Nothing accrued to claim.
+    NothingToClaim = 122,
+    /// Unbonding request does not exist.
+    UnbondingNotFound = 123,
+    /// Malformed argument (unknown key, self-reference, empty or oversized value, ...).
+    InvalidInput = 124,
+    /// Contract is paused by the admin.
+    Paused = 125,
+
+    // ── Attestations (200-299) ───────────────────────────────────────────────
+    /// Verifier is not a registered (or no longer registered) attester.
+    UnauthorizedAttester = 201,
+    /// Attester or sub-attester is suspended.
+    AttesterSuspended = 202,
+    /// Attester is serving a deregistration notice.
+    AttesterDeregistering = 203,
+    /// Identical attestation already exists.
+    DuplicateAttestation = 204,
+    /// Attestation (or counter-attestation) does not exist.
+    AttestationNotFound = 205,
+    /// Attestation has already been revoked.
+    AttestationRevoked = 206,
+    /// Attestation is not in the state the operation requires.
+    InvalidAttestationState = 207,
+    /// Nonce replayed or out of order, or signed authorization expired.
+    InvalidNonce = 208,
+    /// Attestation template does not exist.
+    TemplateNotFound = 209,
+    /// Attestation template is deactivated.
+    TemplateInactive = 210,
+    /// Caller is not the attester that owns the attestation or sub-attester.
+    NotAttestationOwner = 211,
+    /// Sub-attester does not exist.
+    SubAttesterNotFound = 212,
+
+    // ── Governance and slashing (300-399) ────────────────────────────────────
+    /// Slash governance has not been initialized.
+    GovernanceNotInitialized = 301,
+    /// Caller is not a governor (or a delegate of one).
+    NotGovernor = 302,
+    /// Slash proposal does not exist.
+    ProposalNotFound = 303,
+    /// Slash proposal is no longer open.
+    ProposalNotOpen = 304,
+    /// Slash proposal has not been approved.
+    ProposalNotApproved = 305,
+    /// Voter has already voted.
+    AlreadyVoted = 306,
+    /// Proposer has not held its seat long enough.
+    ProposerTooNew = 307,
+    /// A slash for this source has already been applied.
+    SlashAlreadyApplied = 308,
+    /// No slash recorded for this source.
+    SlashNotFound = 309,
+    /// Compensation claim does not exist.
+    ClaimNotFound = 310,
+    /// Compensation claim has already been settled.
+    ClaimSettled = 311,
+    /// Compensation claim or slash appeal window has closed.
+    ClaimExpired = 312,
+    /// Linked dispute is not in the state the operation requires.
+    InvalidDisputeState = 313,
+}
@@ -0,0 +1,59 @@
+//! Feature Flags
+//!
+//! Bitmask of optional subsystems enabled on this contract instance, queryable on-chain via
+//! `get_features()` so integrators can detect capabilities without trial calls. All features
+//! are enabled unless the admin narrows the mask.
+
+use soroban_sdk::{panic_with_error, Env, Symbol};
+
+use crate::{BondError, DataKey};
+
+/// Rolling (auto-renewing) bonds.
+pub const FEATURE_ROLLING_BONDS: u32 = 1 << 0;
+/// Bond creation fees.
+pub const FEATURE_FEES: u32 = 1 << 1;
+/// Governance-approved slashing.
+pub const FEATURE_GOVERNANCE: u32 = 1 << 2;
+/// Stake-weighted attestations (disabled = every attestation has default weight).
+pub const FEATURE_WEIGHTED_ATTESTATION: u32 = 1 << 3;
+/// Dispute exposure reporting.
+pub const FEATURE_DISPUTES: u32 = 1 << 4;
+
+/// All features known to this contract version.
+pub const ALL_FEATURES: u32 = FEATURE_ROLLING_BONDS
+    | FEATURE_FEES
+    | FEATURE_GOVERNANCE
+    | FEATURE_WEIGHTED_ATTESTATION
+    | FEATURE_DISPUTES;
+
+/// Returns the enabled feature mask (all features if never set).
+#[must_use]
+pub fn get_features(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::Features)
+        .unwrap_or(ALL_FEATURES)
+}
+
+/// Set the enabled feature mask. Admin only (enforced by caller).
+pub fn set_features(e: &Env, features: u32) {
+    if features & !ALL_FEATURES != 0 {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    e.storage().instance().set(&DataKey::Features, &features);
+    e.events()
+        .publish((Symbol::new(e, "features_updated"),), features);
+}
+
+/// Returns true if every bit in `feature` is enabled.
+#[must_use]
+pub fn is_enabled(e: &Env, feature: u32) -> bool {
+    get_features(e) & feature == feature
+}
+
+/// Panics with `FeatureDisabled` unless `feature` is enabled.
+pub fn require_enabled(e: &Env, feature: u32) {
+    if !is_enabled(e, feature) {
+        panic_with_error!(e, BondError::FeatureDisabled);
+    }
+}
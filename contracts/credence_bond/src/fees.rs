@@ -2,13 +2,29 @@
 //!
 //! Charges a configurable percentage of the bonded amount on creation, transfers
 //! the fee to the protocol treasury, and supports fee waiver for certain conditions.
+//! The rate can vary by bond size through an optional schedule of size bands.
+//! Allow-listed identities (partners, migrating users) are exempt from the fee.
 //! Emits fee collection events.
 
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, Symbol, Vec};
+
+use crate::BondError;
 
 /// Max fee in basis points (100%).
 const MAX_FEE_BPS: u32 = 10_000;
 
+/// Max number of bands in a fee schedule.
+pub const MAX_FEE_BANDS: u32 = 10;
+
+/// Storage keys of the fee schedule.
+#[contracttype]
+pub enum FeeKey {
+    /// Ordered `(threshold, bps)` bands, see [`set_schedule`].
+    Schedule,
+    /// Set for identities exempt from the creation fee.
+    FeeExempt(Address),
+}
+
 /// Get treasury and fee rate (basis points). Returns (treasury, fee_bps).
 /// If not set, fee is zero (no treasury = no fee).
 pub fn get_config(e: &Env) -> (Option<Address>, u32) {
@@ -24,7 +40,7 @@ pub fn get_config(e: &Env) -> (Option<Address>, u32) {
 /// Set fee config. Admin only (enforced by caller). fee_bps in basis points (e.g. 100 = 1%).
 pub fn set_config(e: &Env, treasury: Address, fee_bps: u32) {
     if fee_bps > MAX_FEE_BPS {
-        panic!("fee_bps must be <= 10000");
+        panic_with_error!(e, BondError::InvalidConfig);
     }
     e.storage()
         .instance()
@@ -34,37 +50,158 @@ pub fn set_config(e: &Env, treasury: Address, fee_bps: u32) {
         .set(&crate::DataKey::FeeBps, &fee_bps);
 }
 
-/// Calculate fee for a bond amount. Returns (fee_amount, net_amount).
-/// If fee is waived (e.g. fee_bps is 0 or waiver condition), fee is 0.
+/// Fee schedule as `(threshold, bps)` bands with strictly increasing thresholds. Empty if
+/// the flat `fee_bps` applies to every amount.
+#[must_use]
+pub fn get_schedule(e: &Env) -> Vec<(i128, u32)> {
+    e.storage()
+        .instance()
+        .get(&FeeKey::Schedule)
+        .unwrap_or(Vec::new(e))
+}
+
+/// Set the fee schedule. A bond of `amount` pays the bps of the band with the highest
+/// threshold not above `amount`; amounts below the first threshold pay the flat `fee_bps`.
+/// An empty schedule restores the flat fee. Admin only (enforced by caller).
+pub fn set_schedule(e: &Env, schedule: &Vec<(i128, u32)>) {
+    if schedule.len() > MAX_FEE_BANDS {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    let mut previous: Option<i128> = None;
+    for (threshold, bps) in schedule.iter() {
+        if threshold < 0 {
+            panic_with_error!(e, BondError::InvalidConfig);
+        }
+        if previous.is_some_and(|p| threshold <= p) {
+            panic_with_error!(e, BondError::InvalidConfig);
+        }
+        if bps > MAX_FEE_BPS {
+            panic_with_error!(e, BondError::InvalidConfig);
+        }
+        previous = Some(threshold);
+    }
+    if schedule.is_empty() {
+        e.storage().instance().remove(&FeeKey::Schedule);
+    } else {
+        e.storage().instance().set(&FeeKey::Schedule, schedule);
+    }
+}
+
+/// Fee rate (bps) applying to a bond of `amount`.
 #[must_use]
-pub fn calculate_fee(e: &Env, amount: i128) -> (i128, i128) {
+pub fn bps_for(e: &Env, amount: i128) -> u32 {
     let (_treasury, fee_bps) = get_config(e);
-    if fee_bps == 0 || amount <= 0 {
+    let mut bps = fee_bps;
+    for (threshold, band_bps) in get_schedule(e).iter() {
+        if amount < threshold {
+            break;
+        }
+        bps = band_bps;
+    }
+    bps
+}
+
+#[must_use]
+pub fn is_exempt(e: &Env, identity: &Address) -> bool {
+    e.storage()
+        .instance()
+        .has(&FeeKey::FeeExempt(identity.clone()))
+}
+
+/// Add `identity` to or remove it from the fee exemption allow-list. Admin only (enforced
+/// by caller).
+pub fn set_exempt(e: &Env, identity: &Address, exempt: bool) {
+    let key = FeeKey::FeeExempt(identity.clone());
+    if exempt {
+        e.storage().instance().set(&key, &true);
+    } else {
+        e.storage().instance().remove(&key);
+    }
+    e.events().publish(
+        (Symbol::new(e, "fee_exemption_set"), identity.clone()),
+        exempt,
+    );
+}
+
+/// Calculate the fee `identity` pays on a bond of `amount`. Returns (fee_amount,
+/// net_amount); exempt identities pay nothing.
+#[must_use]
+pub fn calculate_fee(e: &Env, identity: &Address, amount: i128) -> (i128, i128) {
+    if is_exempt(e, identity) {
+        return (0, amount);
+    }
+    quote(e, amount)
+}
+
+/// Fee for a bond amount ignoring exemptions. Returns (fee_amount, net_amount).
+/// If fee is waived (e.g. fee_bps is 0 or waiver condition), fee is 0.
+#[must_use]
+pub fn quote(e: &Env, amount: i128) -> (i128, i128) {
+    let fee_bps = bps_for(e, amount);
+    if fee_bps == 0 || amount <= 0 || !crate::features::is_enabled(e, crate::features::FEATURE_FEES)
+    {
         return (0, amount);
     }
     let fee = (amount * (fee_bps as i128)) / 10_000;
-    let net = amount.checked_sub(fee).expect("fee calculation underflow");
+    let net = amount
+        .checked_sub(fee)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
     (fee, net)
 }
 
-/// Check if fee is waived for this bond (e.g. zero amount, or future: whitelisted identity).
+/// Check if fee is waived for this bond (zero rate or amount, or exempt identity).
 #[must_use]
-pub fn is_fee_waived(e: &Env, amount: i128, _identity: &Address) -> bool {
-    let (_, fee_bps) = get_config(e);
-    fee_bps == 0 || amount <= 0
+pub fn is_fee_waived(e: &Env, amount: i128, identity: &Address) -> bool {
+    bps_for(e, amount) == 0 || amount <= 0 || is_exempt(e, identity)
 }
 
-/// Record fee to the contract's fee pool (for later transfer to treasury).
-/// In full implementation, transfer would happen here; we accumulate and emit event.
+/// Record fee to the contract's fee pool, transferred to the treasury by [`collect`].
 pub fn record_fee(e: &Env, identity: &Address, amount: i128, fee: i128, treasury: &Address) {
     if fee <= 0 {
         return;
     }
+    add_to_pool(e, fee);
+    emit_fee_event(e, identity, amount, fee, treasury);
+}
+
+/// Add `amount` to the fee pool drained by `collect_fees`.
+pub fn add_to_pool(e: &Env, amount: i128) {
     let key = Symbol::new(e, "fees");
     let current: i128 = e.storage().instance().get(&key).unwrap_or(0);
-    let new_total = current.checked_add(fee).expect("fee pool overflow");
+    let new_total = current
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
     e.storage().instance().set(&key, &new_total);
-    emit_fee_event(e, identity, amount, fee, treasury);
+}
+
+/// Add `amount` paid by `from` to the fee pool. With a bond token set, `from` transfers the
+/// amount in, so the pool stays backed by tokens. Caller must enforce `from` auth.
+pub fn deposit(e: &Env, from: &Address, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    if let Some(info) = crate::token_info::get(e) {
+        token::Client::new(e, &info.address).transfer(from, &e.current_contract_address(), &amount);
+    }
+    add_to_pool(e, amount);
+}
+
+/// Drain the fee pool and return the amount. With a bond token set, the amount is
+/// transferred to the fee treasury (`ConfigNotSet` if none is configured). Admin only
+/// (enforced by caller).
+pub fn collect(e: &Env) -> i128 {
+    let key = Symbol::new(e, "fees");
+    let collected: i128 = e.storage().instance().get(&key).unwrap_or(0);
+    e.storage().instance().set(&key, &0_i128);
+    if collected > 0 && crate::token_info::get(e).is_some() {
+        let treasury = get_config(e)
+            .0
+            .unwrap_or_else(|| panic_with_error!(e, BondError::ConfigNotSet));
+        crate::reserves::pay_out(e, &treasury, collected);
+        e.events()
+            .publish((Symbol::new(e, "fees_collected"), treasury), collected);
+    }
+    collected
 }
 
 /// Emit fee collection event.
@@ -0,0 +1,98 @@
+//! Bond Funding
+//!
+//! Pulls the bond amount from the identity in the same invocation that creates the bond, so
+//! onboarding takes a single signature. The preferred path is a plain `transfer` authorized
+//! inline by the identity: SAC-style tokens check `from.require_auth()`, and the identity's
+//! signature on `create_bond_funded` covers the nested call, so no prior `approve` is needed.
+//! If the token rejects the inline transfer, the contract falls back to `transfer_from`
+//! against an allowance the identity granted beforehand. The configured `TokenMode` can pin
+//! either path (see `token_adaptor`).
+//!
+//! Wallets that can sign neither path can transfer the bond token to the contract directly
+//! and then call `claim_deposit`. Tokens the contract holds beyond what it already owes (the
+//! unslashed bond, unsettled slash proceeds and reserves) are unclaimed deposits, and only
+//! the bond identity can claim them into its bond.
+//!
+//! Exits pay the other way: every path that reduces the bond (`withdraw`, `withdraw_early`,
+//! `withdraw_bond`, `claim_unbonded`, the unbonding queue) sends the released amount back to
+//! the identity with [`release`], and so do slash refunds claimed with `claim_slash_refund`.
+
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, Symbol};
+
+use crate::{reserves, slash_settlement, token_adaptor, BondError, DataKey, IdentityBond};
+
+/// How the bond amount was pulled from the identity.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FundingPath {
+    /// `transfer` authorized inline by the identity.
+    InlineTransfer = 0,
+    /// `transfer_from` using a prior allowance.
+    Allowance = 1,
+}
+
+/// Move `amount` of the bond token from `from` into this contract. Caller must enforce
+/// `from` auth. Panics if no bond token is set or both paths fail.
+pub fn pull(e: &Env, from: &Address, amount: i128) -> FundingPath {
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    let token = crate::token_info::get(e)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::TokenNotSet))
+        .address;
+    let path = token_adaptor::pull(e, &token, from, amount);
+    e.events().publish(
+        (Symbol::new(e, "bond_funded"), from.clone()),
+        (amount, path),
+    );
+    path
+}
+
+/// Transfer `amount` of the bond token from this contract to `to`. Panics with
+/// `ReserveBreach` if the balance left would not cover the reserves. Accounting-only
+/// deployments (no bond token set) transfer nothing.
+pub fn release(e: &Env, to: &Address, amount: i128) {
+    reserves::require_covered(e, amount);
+    if amount <= 0 {
+        return;
+    }
+    if let Some(info) = crate::token_info::get(e) {
+        token::Client::new(e, &info.address).transfer(&e.current_contract_address(), to, &amount);
+        e.events()
+            .publish((Symbol::new(e, "bond_released"), to.clone()), amount);
+    }
+}
+
+/// Bond token held by the contract beyond what it owes: the unslashed active bond, slash
+/// proceeds not yet settled and reserves. 0 without a bond token.
+#[must_use]
+pub fn unclaimed_deposits(e: &Env) -> i128 {
+    let Some(balance) = reserves::token_balance(e) else {
+        return 0;
+    };
+    let bonded = e
+        .storage()
+        .instance()
+        .get::<_, IdentityBond>(&DataKey::Bond)
+        .filter(|b| b.active)
+        .map(|b| b.bonded_amount.saturating_sub(b.slashed_amount))
+        .unwrap_or(0);
+    let owed = bonded
+        .saturating_add(slash_settlement::pending(e))
+        .saturating_add(reserves::total(e));
+    balance.saturating_sub(owed).max(0)
+}
+
+/// Panic unless `amount` of unclaimed deposits can be credited to the bond. Caller must
+/// enforce the identity's auth and apply the top-up.
+pub fn require_claimable(e: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    if crate::token_info::get(e).is_none() {
+        panic_with_error!(e, BondError::TokenNotSet);
+    }
+    if amount > unclaimed_deposits(e) {
+        panic_with_error!(e, BondError::InsufficientBalance);
+    }
+}
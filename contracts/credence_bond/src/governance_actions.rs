@@ -0,0 +1,197 @@
+//! Governance Actions
+//!
+//! Generic governor-approved proposals for protocol changes beyond slashing: fee and
+//! early-exit configuration, tier thresholds, admin transfer, slashes and upgrades. Votes
+//! follow the slash governance rules (delegation, voting mode, quorum and voting period).
+//!
+//! With action governance required, the admin can no longer call the covered setters
+//! directly; the change must be proposed, approved and executed here. That includes turning
+//! the requirement off again.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Symbol};
+
+use crate::governance_approval::{self, ProposalStatus, ProposalTally};
+use crate::tiered_bond::TierThresholds;
+use crate::BondError;
+
+/// A change governors can approve.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    /// `set_fee_config(treasury, fee_bps)`.
+    SetFeeConfig(Address, u32),
+    /// `set_tier_thresholds` with the given thresholds.
+    SetTierThresholds(TierThresholds),
+    /// `set_early_exit_config(treasury, penalty_bps)`.
+    SetEarlyExitConfig(Address, u32),
+    /// Nominate a new admin, who still has to `accept_admin`.
+    TransferAdmin(Address),
+    /// Slash `amount` from `identity`'s bond. Executes like an approved slash proposal: it is
+    /// recorded once, held for the appeal window and settled to the treasury.
+    Slash(Address, i128),
+    /// Replace the contract WASM.
+    UpgradeWasm(BytesN<32>),
+    /// Stop requiring approved actions. Once they are required, only governors can lift the
+    /// requirement.
+    DisableActionGovernance,
+    /// Stop requiring governor-approved upgrades (`set_upgrade_governance`).
+    DisableUpgradeGovernance,
+}
+
+/// A proposed action and its execution state.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionProposal {
+    pub id: u64,
+    pub action: ProposalAction,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    pub status: ProposalStatus,
+    /// Last timestamp votes are accepted and the action can execute (`None` = no deadline).
+    pub voting_deadline: Option<u64>,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum ActionKey {
+    /// True if covered admin setters require an approved action.
+    ActionsRequired,
+    NextActionId,
+    ActionProposal(u64),
+    ActionVote(u64, Address),
+}
+
+#[must_use]
+pub fn is_required(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&ActionKey::ActionsRequired)
+        .unwrap_or(false)
+}
+
+/// Require (or stop requiring) approved actions for the covered admin setters. Caller must
+/// enforce admin auth for enabling and an approved `DisableActionGovernance` for disabling.
+pub fn set_required(e: &Env, required: bool) {
+    e.storage()
+        .instance()
+        .set(&ActionKey::ActionsRequired, &required);
+    e.events()
+        .publish((Symbol::new(e, "action_governance_set"),), required);
+}
+
+/// Panic with `Unauthorized` if the change must go through an approved action.
+pub fn require_not_required(e: &Env) {
+    if is_required(e) {
+        panic_with_error!(e, BondError::Unauthorized);
+    }
+}
+
+#[must_use]
+pub fn get_proposal(e: &Env, id: u64) -> Option<ActionProposal> {
+    e.storage().instance().get(&ActionKey::ActionProposal(id))
+}
+
+fn set_proposal(e: &Env, proposal: &ActionProposal) {
+    e.storage()
+        .instance()
+        .set(&ActionKey::ActionProposal(proposal.id), proposal);
+}
+
+/// Propose `action`. Caller must check the proposer is the admin or a governor. Returns
+/// the proposal id.
+pub fn propose(e: &Env, proposer: &Address, action: ProposalAction) -> u64 {
+    if let ProposalAction::Slash(_, amount) = action {
+        if amount <= 0 {
+            panic_with_error!(e, BondError::InvalidAmount);
+        }
+    }
+    let id: u64 = e
+        .storage()
+        .instance()
+        .get(&ActionKey::NextActionId)
+        .unwrap_or(0);
+    let next_id = id
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&ActionKey::NextActionId, &next_id);
+    let now = e.ledger().timestamp();
+    let period = governance_approval::get_voting_period(e);
+    let proposal = ActionProposal {
+        id,
+        action: action.clone(),
+        proposed_by: proposer.clone(),
+        proposed_at: now,
+        status: ProposalStatus::Open,
+        voting_deadline: (period > 0).then(|| now.saturating_add(period)),
+    };
+    set_proposal(e, &proposal);
+    e.events().publish(
+        (Symbol::new(e, "action_proposed"), proposer.clone()),
+        (id, action),
+    );
+    id
+}
+
+fn is_expired(e: &Env, proposal: &ActionProposal) -> bool {
+    proposal
+        .voting_deadline
+        .is_some_and(|deadline| e.ledger().timestamp() > deadline)
+}
+
+/// Load a proposal that is open and within its voting deadline.
+fn get_open(e: &Env, id: u64) -> ActionProposal {
+    let proposal =
+        get_proposal(e, id).unwrap_or_else(|| panic_with_error!(e, BondError::ProposalNotFound));
+    if proposal.status != ProposalStatus::Open || is_expired(e, &proposal) {
+        panic_with_error!(e, BondError::ProposalNotOpen);
+    }
+    proposal
+}
+
+/// Record a governor's (or delegate's) vote on an open action.
+pub fn vote(e: &Env, voter: &Address, id: u64, approve: bool) {
+    get_open(e, id);
+    governance_approval::require_voter(e, voter);
+    let key = ActionKey::ActionVote(id, voter.clone());
+    if e.storage().instance().has(&key) {
+        panic_with_error!(e, BondError::AlreadyVoted);
+    }
+    e.storage().instance().set(&key, &approve);
+    e.events().publish(
+        (Symbol::new(e, "action_vote_cast"), voter.clone()),
+        (id, approve),
+    );
+}
+
+/// Vote weights of action `id` under the current voting mode. For a `Slash` action the
+/// target identity's seat is left out.
+#[must_use]
+pub fn tally(e: &Env, id: u64) -> ProposalTally {
+    let target = get_proposal(e, id).and_then(|p| match p.action {
+        ProposalAction::Slash(identity, _) => Some(identity),
+        _ => None,
+    });
+    governance_approval::tally_votes(e, target.as_ref(), |voter| {
+        e.storage()
+            .instance()
+            .get(&ActionKey::ActionVote(id, voter.clone()))
+    })
+}
+
+/// Mark an approved, open action executed and return it for the caller to apply. Panics
+/// with `ProposalNotApproved` if the governors have not approved it.
+pub fn take_approved(e: &Env, executor: &Address, id: u64) -> ActionProposal {
+    let mut proposal = get_open(e, id);
+    if !tally(e, id).approved {
+        panic_with_error!(e, BondError::ProposalNotApproved);
+    }
+    proposal.status = ProposalStatus::Executed;
+    set_proposal(e, &proposal);
+    e.events().publish(
+        (Symbol::new(e, "action_executed"), executor.clone()),
+        (id, proposal.action.clone()),
+    );
+    proposal
+}
@@ -3,8 +3,21 @@
 //! Multi-signature verification for slash requests: proposals are created, governors vote
 //! (with optional delegation), and slashing is executed only when quorum and approval
 //! requirements are met. Emits governance events for audit.
+//!
+//! With a voting period set, each proposal gets a `voting_deadline`: votes after it are
+//! rejected and the proposal can no longer execute, so it closes as Rejected on its next
+//! execution attempt or through `expire`. The proposer or admin can cancel an open proposal.
+//!
+//! The governor set can change after initialization. Quorum is always computed over the
+//! current governors, so votes of removed governors stop counting on open proposals.
+//!
+//! Votes count one per governor by default. In `Weighted` mode each governor's vote counts
+//! with its admin-set weight, or else its unslashed bond: quorum is then a share of the total
+//! weight and approval needs a weighted majority.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
 
-use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+use crate::{BondError, DataKey, IdentityBond};
 
 /// Status of a slash proposal.
 #[contracttype]
@@ -14,19 +27,125 @@ pub enum ProposalStatus {
     Open,
     /// Executed (slash applied).
     Executed,
-    /// Rejected (quorum not met or majority against).
+    /// Rejected (quorum not met, majority against, or voting deadline passed).
     Rejected,
+    /// Withdrawn by the proposer or admin before execution.
+    Cancelled,
 }
 
-/// A slash proposal: amount to slash, proposer, and execution state.
+/// A slash proposal: target identity, amount to slash, proposer, and execution state.
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct SlashProposal {
     pub id: u64,
+    /// Identity whose bond is slashed if the proposal executes.
+    pub identity: Address,
     pub amount: i128,
     pub proposed_by: Address,
     pub proposed_at: u64,
     pub status: ProposalStatus,
+    /// Last timestamp votes are accepted and the proposal can execute (`None` = no deadline).
+    pub voting_deadline: Option<u64>,
+}
+
+/// Largest page returned by `list_open`.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Vote tally and quorum progress of a proposal, for governor dashboards.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProposalSummary {
+    pub proposal: SlashProposal,
+    pub approve_count: u32,
+    pub reject_count: u32,
+    pub voted_count: u32,
+    pub total_governors: u32,
+    /// Votes needed for quorum: `max(total * quorum_bps / 10000, min_governors)`.
+    pub quorum_required: u32,
+    /// True if quorum is met and a majority approves.
+    pub approved: bool,
+    /// Seconds left to vote, if the proposal has a voting deadline (`None` = no deadline).
+    pub time_remaining: Option<u64>,
+}
+
+/// How governor votes are counted.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VotingMode {
+    /// One vote per governor.
+    Equal = 0,
+    /// Votes weighted by admin-set weight or bonded amount.
+    Weighted = 1,
+}
+
+/// Vote weights of a proposal under the current voting mode (1 per governor in `Equal`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalTally {
+    pub mode: VotingMode,
+    pub approve_weight: i128,
+    pub reject_weight: i128,
+    pub voted_weight: i128,
+    pub total_weight: i128,
+    /// Weight that must vote for quorum.
+    pub quorum_weight: i128,
+    /// True if quorum is met and a weighted majority approves.
+    pub approved: bool,
+}
+
+/// A governor's recorded vote on a proposal.
+///
+/// # Fields
+/// * `governor` - Governor the vote counts for.
+/// * `voter` - Address whose vote counts: the governor's delegate, if any, else the governor.
+/// * `vote` - `Some(true)` approve, `Some(false)` reject, `None` not voted yet.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteReceipt {
+    pub governor: Address,
+    pub voter: Address,
+    pub vote: Option<bool>,
+}
+
+/// Storage keys of the open-proposal index.
+#[contracttype]
+pub enum ProposalIndexKey {
+    /// Ids of open proposals, in creation order.
+    Open,
+}
+
+/// Storage keys of governance settings.
+#[contracttype]
+pub enum GovernanceConfigKey {
+    /// Seconds proposals stay open for voting (0 = no deadline).
+    VotingPeriod,
+    /// How votes are counted.
+    VotingMode,
+    /// Admin-set vote weight of a governor, overriding its bond.
+    GovernorWeight(Address),
+}
+
+/// Ids of open proposals, in creation order.
+pub(crate) fn open_ids(e: &Env) -> Vec<u64> {
+    e.storage()
+        .instance()
+        .get(&ProposalIndexKey::Open)
+        .unwrap_or(Vec::new(e))
+}
+
+/// Add a proposal to the open index.
+pub(crate) fn index_open(e: &Env, id: u64) {
+    let mut ids = open_ids(e);
+    ids.push_back(id);
+    e.storage().instance().set(&ProposalIndexKey::Open, &ids);
+}
+
+fn unindex_open(e: &Env, id: u64) {
+    let mut ids = open_ids(e);
+    if let Some(idx) = ids.first_index_of(id) {
+        ids.remove(idx);
+        e.storage().instance().set(&ProposalIndexKey::Open, &ids);
+    }
 }
 
 fn key_next_id() -> crate::DataKey {
@@ -74,7 +193,7 @@ pub fn initialize_governance(
     min_governors: u32,
 ) {
     if quorum_bps > 10_000 {
-        panic!("quorum_bps must be <= 10000");
+        panic_with_error!(e, BondError::InvalidConfig);
     }
     e.storage().instance().set(&key_governors(), &governors);
     e.storage().instance().set(&key_quorum_bps(), &quorum_bps);
@@ -84,59 +203,230 @@ pub fn initialize_governance(
     e.storage().instance().set(&key_next_id(), &0_u64);
 }
 
-/// Create a new slash proposal. Caller must be admin or governor. Returns proposal id.
-pub fn propose_slash(e: &Env, proposer: &Address, amount: i128) -> u64 {
+/// True once `initialize_governance` has been called.
+#[must_use]
+pub fn is_initialized(e: &Env) -> bool {
+    e.storage().instance().has(&key_governors())
+}
+
+fn require_governors(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&key_governors())
+        .unwrap_or_else(|| panic_with_error!(e, BondError::GovernanceNotInitialized))
+}
+
+/// Add a governor. Admin only (enforced by caller).
+pub fn add_governor(e: &Env, governor: &Address) {
+    let mut governors = require_governors(e);
+    if is_governor(&governors, governor) {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    governors.push_back(governor.clone());
+    e.storage().instance().set(&key_governors(), &governors);
+    e.events().publish(
+        (Symbol::new(e, "governor_added"), governor.clone()),
+        governors.len(),
+    );
+}
+
+/// Remove a governor, dropping its delegation and any delegation pointing to it. Fails with
+/// `InvalidConfig` if fewer than `min_governors` would remain. Admin only (enforced by
+/// caller).
+pub fn remove_governor(e: &Env, governor: &Address) {
+    let mut governors = require_governors(e);
+    let idx = governors
+        .first_index_of(governor)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::NotGovernor));
+    let (_, min_governors) = get_quorum_config(e);
+    if governors.len() - 1 < min_governors {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    governors.remove(idx);
+    e.storage().instance().set(&key_governors(), &governors);
+    e.storage()
+        .instance()
+        .remove(&key_delegate(governor.clone()));
+    e.storage()
+        .instance()
+        .remove(&GovernanceConfigKey::GovernorWeight(governor.clone()));
+    for g in governors.iter() {
+        if get_delegate(e, &g).as_ref() == Some(governor) {
+            e.storage().instance().remove(&key_delegate(g));
+        }
+    }
+    e.events().publish(
+        (Symbol::new(e, "governor_removed"), governor.clone()),
+        governors.len(),
+    );
+}
+
+#[must_use]
+pub fn get_voting_mode(e: &Env) -> VotingMode {
+    e.storage()
+        .instance()
+        .get(&GovernanceConfigKey::VotingMode)
+        .unwrap_or(VotingMode::Equal)
+}
+
+/// Set how votes are counted. Applies to open proposals too. Admin only (enforced by
+/// caller).
+pub fn set_voting_mode(e: &Env, mode: VotingMode) {
+    e.storage()
+        .instance()
+        .set(&GovernanceConfigKey::VotingMode, &mode);
+}
+
+/// Override the vote weight of `governor`; 0 clears the override so its bond counts again.
+/// Admin only (enforced by caller).
+pub fn set_governor_weight(e: &Env, governor: &Address, weight: i128) {
+    if weight < 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    if !is_governor(&require_governors(e), governor) {
+        panic_with_error!(e, BondError::NotGovernor);
+    }
+    let key = GovernanceConfigKey::GovernorWeight(governor.clone());
+    if weight == 0 {
+        e.storage().instance().remove(&key);
+    } else {
+        e.storage().instance().set(&key, &weight);
+    }
+}
+
+/// Weighted-mode vote weight of `governor`: its admin-set weight, else the unslashed amount
+/// of the active bond it holds, else 0.
+#[must_use]
+pub fn governor_weight(e: &Env, governor: &Address) -> i128 {
+    if let Some(weight) = e
+        .storage()
+        .instance()
+        .get(&GovernanceConfigKey::GovernorWeight(governor.clone()))
+    {
+        return weight;
+    }
+    match e
+        .storage()
+        .instance()
+        .get::<_, IdentityBond>(&DataKey::Bond)
+    {
+        Some(bond) if bond.active && bond.identity == *governor => bond
+            .bonded_amount
+            .saturating_sub(bond.slashed_amount)
+            .max(0),
+        _ => 0,
+    }
+}
+
+#[must_use]
+pub fn get_voting_period(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&GovernanceConfigKey::VotingPeriod)
+        .unwrap_or(0)
+}
+
+/// Set the voting period of new proposals. Admin only (enforced by caller). Existing
+/// proposals keep their deadline.
+pub fn set_voting_period(e: &Env, seconds: u64) {
+    e.storage()
+        .instance()
+        .set(&GovernanceConfigKey::VotingPeriod, &seconds);
+}
+
+/// True if the proposal's voting deadline has passed.
+#[must_use]
+pub fn is_expired(e: &Env, proposal: &SlashProposal) -> bool {
+    proposal
+        .voting_deadline
+        .is_some_and(|deadline| e.ledger().timestamp() > deadline)
+}
+
+/// Create a new slash proposal against `identity`. Caller must be admin or governor and must
+/// check that the identity has an active bond. Returns proposal id.
+pub fn propose_slash(e: &Env, proposer: &Address, identity: &Address, amount: i128) -> u64 {
     if amount <= 0 {
-        panic!("slash amount must be positive");
+        panic_with_error!(e, BondError::InvalidAmount);
     }
     let id: u64 = e.storage().instance().get(&key_next_id()).unwrap_or(0);
-    let next_id = id.checked_add(1).expect("proposal id overflow");
+    let next_id = id
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
     e.storage().instance().set(&key_next_id(), &next_id);
 
+    let now = e.ledger().timestamp();
+    let period = get_voting_period(e);
     let proposal = SlashProposal {
         id,
+        identity: identity.clone(),
         amount,
         proposed_by: proposer.clone(),
-        proposed_at: e.ledger().timestamp(),
+        proposed_at: now,
         status: ProposalStatus::Open,
+        voting_deadline: (period > 0).then(|| now.saturating_add(period)),
     };
     e.storage().instance().set(&key_proposal(id), &proposal);
-    emit_governance_event(e, "slash_proposed", id, proposer, amount);
+    index_open(e, id);
+    emit_proposal_event(e, "slash_proposed", identity, id, proposer, amount);
     id
 }
 
+/// Record a slash already approved through a governance action as an executed slash
+/// proposal, so it shares the slash record, appeal and settlement path of voted proposals.
+/// Returns the proposal id.
+pub fn record_approved_slash(e: &Env, proposer: &Address, identity: &Address, amount: i128) -> u64 {
+    let id: u64 = e.storage().instance().get(&key_next_id()).unwrap_or(0);
+    let next_id = id
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage().instance().set(&key_next_id(), &next_id);
+    let proposal = SlashProposal {
+        id,
+        identity: identity.clone(),
+        amount,
+        proposed_by: proposer.clone(),
+        proposed_at: e.ledger().timestamp(),
+        status: ProposalStatus::Executed,
+        voting_deadline: None,
+    };
+    e.storage().instance().set(&key_proposal(id), &proposal);
+    emit_proposal_event(e, "slash_proposal_executed", identity, id, proposer, amount);
+    id
+}
+
+/// Panic with `NotGovernor` unless `voter` is a governor or a governor's delegate.
+pub(crate) fn require_voter(e: &Env, voter: &Address) {
+    let governors = require_governors(e);
+    let is_gov = is_governor(&governors, voter);
+    let is_delegate_of_some = governors.iter().any(|g| {
+        let d: Option<Address> = e.storage().instance().get(&key_delegate(g.clone()));
+        d.as_ref() == Some(voter)
+    });
+    if !is_gov && !is_delegate_of_some {
+        panic_with_error!(e, BondError::NotGovernor);
+    }
+}
+
 /// Record a vote (approve = true, reject = false). Caller must be a governor or delegate.
 pub fn vote(e: &Env, voter: &Address, proposal_id: u64, approve: bool) {
     let proposal: SlashProposal = e
         .storage()
         .instance()
         .get(&key_proposal(proposal_id))
-        .unwrap_or_else(|| panic!("proposal not found"));
-    if proposal.status != ProposalStatus::Open {
-        panic!("proposal not open for voting");
-    }
-    let governors: Vec<Address> = e
-        .storage()
-        .instance()
-        .get(&key_governors())
-        .unwrap_or_else(|| panic!("governance not initialized"));
-    let is_gov = is_governor(&governors, voter);
-    let is_delegate_of_some = governors.iter().any(|g| {
-        let d: Option<Address> = e.storage().instance().get(&key_delegate(g.clone()));
-        d.as_ref() == Some(voter)
-    });
-    let can_vote = is_gov || is_delegate_of_some;
-    if !can_vote {
-        panic!("not a governor or delegate");
+        .unwrap_or_else(|| panic_with_error!(e, BondError::ProposalNotFound));
+    if proposal.status != ProposalStatus::Open || is_expired(e, &proposal) {
+        panic_with_error!(e, BondError::ProposalNotOpen);
     }
+    require_voter(e, voter);
     let vote_key = key_vote(proposal_id, voter.clone());
     if e.storage().instance().has(&vote_key) {
-        panic!("already voted");
+        panic_with_error!(e, BondError::AlreadyVoted);
     }
     e.storage().instance().set(&vote_key, &approve);
-    emit_governance_event(
+    emit_proposal_event(
         e,
         "governance_vote",
+        &proposal.identity,
         proposal_id,
         voter,
         if approve { 1_i128 } else { 0_i128 },
@@ -150,9 +440,9 @@ pub fn delegate(e: &Env, governor: &Address, to: &Address) {
         .storage()
         .instance()
         .get(&key_governors())
-        .unwrap_or_else(|| panic!("governance not initialized"));
+        .unwrap_or_else(|| panic_with_error!(e, BondError::GovernanceNotInitialized));
     if !is_governor(&governors, governor) {
-        panic!("not a governor");
+        panic_with_error!(e, BondError::NotGovernor);
     }
     e.storage()
         .instance()
@@ -166,13 +456,11 @@ fn effective_voter(e: &Env, governor: &Address) -> Address {
     delegated.unwrap_or_else(|| governor.clone())
 }
 
-/// Count votes for a proposal: (approve_count, reject_count, total_voted).
-fn count_votes(e: &Env, proposal_id: u64) -> (u32, u32, u32) {
-    let governors: Vec<Address> = e
-        .storage()
-        .instance()
-        .get(&key_governors())
-        .unwrap_or(Vec::new(e));
+/// Count votes for a proposal, leaving out the target identity's seat: (approve_count,
+/// reject_count, total_voted).
+fn count_votes(e: &Env, proposal: &SlashProposal) -> (u32, u32, u32) {
+    let proposal_id = proposal.id;
+    let governors = eligible_governors(e, Some(&proposal.identity));
     let mut approve = 0u32;
     let mut reject = 0u32;
     let mut voted = 0u32;
@@ -192,67 +480,241 @@ fn count_votes(e: &Env, proposal_id: u64) -> (u32, u32, u32) {
     (approve, reject, voted)
 }
 
-/// Check if quorum is met and majority approve.
+/// Votes needed for quorum with `total` governors.
+fn quorum_required(e: &Env, total: u32) -> u32 {
+    let (quorum_bps, min_governors) = get_quorum_config(e);
+    (total * quorum_bps / 10_000).max(min_governors)
+}
+
+/// Check if quorum is met and majority approve, under the current voting mode.
 pub fn is_approved(e: &Env, proposal_id: u64) -> bool {
-    let governors: Vec<Address> = e
-        .storage()
-        .instance()
-        .get(&key_governors())
-        .unwrap_or(Vec::new(e));
-    let total = governors.len() as u32;
-    if total == 0 {
-        return false;
+    tally(e, proposal_id).approved
+}
+
+/// Sum vote weights of a proposal under the current voting mode. The target identity's
+/// seat is left out.
+#[must_use]
+pub fn tally(e: &Env, proposal_id: u64) -> ProposalTally {
+    let target = get_proposal(e, proposal_id).map(|p| p.identity);
+    tally_votes(e, target.as_ref(), |voter| {
+        e.storage()
+            .instance()
+            .get(&key_vote(proposal_id, voter.clone()))
+    })
+}
+
+/// Governors allowed to count towards a slash of `target`: every governor except `target`
+/// itself, whose weight would otherwise come from the very bond being slashed.
+fn eligible_governors(e: &Env, target: Option<&Address>) -> Vec<Address> {
+    let mut governors = get_governors(e);
+    if let Some(i) = target.and_then(|t| governors.first_index_of(t)) {
+        governors.remove(i);
     }
-    let quorum_bps: u32 = e
-        .storage()
-        .instance()
-        .get(&key_quorum_bps())
-        .unwrap_or(5100);
-    let min_governors: u32 = e
-        .storage()
-        .instance()
-        .get(&key_min_governors())
-        .unwrap_or(1);
-    let (approve, _reject, voted) = count_votes(e, proposal_id);
-    let quorum_ok = voted >= (total * quorum_bps / 10_000).max(min_governors);
+    governors
+}
+
+/// Sum vote weights under the current voting mode, reading each governor's (or its
+/// delegate's) vote with `vote_of`. `target` is the identity the proposal slashes, if any;
+/// its seat does not count.
+pub(crate) fn tally_votes(
+    e: &Env,
+    target: Option<&Address>,
+    vote_of: impl Fn(&Address) -> Option<bool>,
+) -> ProposalTally {
+    let mode = get_voting_mode(e);
+    let governors = eligible_governors(e, target);
+    let mut t = ProposalTally {
+        mode,
+        approve_weight: 0,
+        reject_weight: 0,
+        voted_weight: 0,
+        total_weight: 0,
+        quorum_weight: 0,
+        approved: false,
+    };
+    let mut voted_count = 0u32;
+    for g in governors.iter() {
+        let weight = match mode {
+            VotingMode::Equal => 1,
+            VotingMode::Weighted => governor_weight(e, &g),
+        };
+        t.total_weight = t.total_weight.saturating_add(weight);
+        if let Some(approve) = vote_of(&effective_voter(e, &g)) {
+            voted_count += 1;
+            t.voted_weight = t.voted_weight.saturating_add(weight);
+            if approve {
+                t.approve_weight = t.approve_weight.saturating_add(weight);
+            } else {
+                t.reject_weight = t.reject_weight.saturating_add(weight);
+            }
+        }
+    }
+    let (quorum_bps, min_governors) = get_quorum_config(e);
+    t.quorum_weight = match mode {
+        VotingMode::Equal => quorum_required(e, governors.len()) as i128,
+        VotingMode::Weighted => t.total_weight.saturating_mul(quorum_bps as i128) / 10_000,
+    };
+    t.approved = t.total_weight > 0
+        && voted_count >= min_governors
+        && t.voted_weight >= t.quorum_weight
+        && t.voted_weight > 0
+        && t.approve_weight > t.voted_weight / 2;
+    t
+}
+
+/// True if `voted` of `total` governors meet quorum and a majority of them approve.
+pub(crate) fn meets_quorum(e: &Env, total: u32, approve: u32, voted: u32) -> bool {
+    let quorum_ok = voted >= quorum_required(e, total);
     let majority_approve = voted > 0 && approve > voted / 2;
     quorum_ok && majority_approve
 }
 
-/// Execute slash for an approved proposal. Returns true if executed.
-pub fn execute_slash_if_approved(e: &Env, proposal_id: u64) -> bool {
-    let mut proposal: SlashProposal = e
+/// Tally and quorum progress of a proposal.
+#[must_use]
+pub fn summary(e: &Env, proposal_id: u64) -> Option<ProposalSummary> {
+    let proposal = get_proposal(e, proposal_id)?;
+    let total = eligible_governors(e, Some(&proposal.identity)).len();
+    let (approve_count, reject_count, voted_count) = count_votes(e, &proposal);
+    Some(ProposalSummary {
+        approve_count,
+        reject_count,
+        voted_count,
+        total_governors: total,
+        quorum_required: quorum_required(e, total),
+        approved: is_approved(e, proposal_id),
+        time_remaining: proposal
+            .voting_deadline
+            .map(|deadline| deadline.saturating_sub(e.ledger().timestamp())),
+        proposal,
+    })
+}
+
+/// Vote receipts of every current governor on a proposal, in governor order.
+#[must_use]
+pub fn votes(e: &Env, proposal_id: u64) -> Option<Vec<VoteReceipt>> {
+    get_proposal(e, proposal_id)?;
+    let mut receipts = Vec::new(e);
+    for governor in get_governors(e).iter() {
+        let voter = effective_voter(e, &governor);
+        let vote = get_vote(e, proposal_id, &voter);
+        receipts.push_back(VoteReceipt {
+            governor,
+            voter,
+            vote,
+        });
+    }
+    Some(receipts)
+}
+
+/// Open proposals in creation order, skipping `offset` and returning at most `limit`
+/// (capped at `MAX_PAGE_SIZE`).
+#[must_use]
+pub fn list_open(e: &Env, offset: u32, limit: u32) -> Vec<SlashProposal> {
+    let ids = open_ids(e);
+    let end = offset
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(ids.len());
+    let mut page = Vec::new(e);
+    for i in offset..end {
+        if let Some(p) = get_proposal(e, ids.get_unchecked(i)) {
+            page.push_back(p);
+        }
+    }
+    page
+}
+
+/// Number of open proposals.
+#[must_use]
+pub fn open_count(e: &Env) -> u32 {
+    open_ids(e).len()
+}
+
+/// Load an open proposal, panicking if it is missing or closed.
+fn get_open(e: &Env, proposal_id: u64) -> SlashProposal {
+    let proposal: SlashProposal = e
         .storage()
         .instance()
         .get(&key_proposal(proposal_id))
-        .unwrap_or_else(|| panic!("proposal not found"));
+        .unwrap_or_else(|| panic_with_error!(e, BondError::ProposalNotFound));
     if proposal.status != ProposalStatus::Open {
-        panic!("proposal already closed");
+        panic_with_error!(e, BondError::ProposalNotOpen);
     }
+    proposal
+}
+
+/// Close an open proposal with `status` and emit `topic`.
+fn close(e: &Env, mut proposal: SlashProposal, status: ProposalStatus, topic: &str, by: &Address) {
+    proposal.status = status;
+    e.storage()
+        .instance()
+        .set(&key_proposal(proposal.id), &proposal);
+    unindex_open(e, proposal.id);
+    emit_proposal_event(
+        e,
+        topic,
+        &proposal.identity,
+        proposal.id,
+        by,
+        proposal.amount,
+    );
+}
+
+/// Cancel an open proposal. Caller must check that `caller` is the proposer or admin.
+pub fn cancel(e: &Env, caller: &Address, proposal_id: u64) -> SlashProposal {
+    let proposal = get_open(e, proposal_id);
+    close(
+        e,
+        proposal,
+        ProposalStatus::Cancelled,
+        "slash_proposal_cancelled",
+        caller,
+    );
+    get_proposal(e, proposal_id).unwrap()
+}
+
+/// Reject an open proposal whose voting deadline has passed. Panics with
+/// `NoticeNotElapsed` if it is still within its deadline.
+pub fn expire(e: &Env, proposal_id: u64) -> SlashProposal {
+    let proposal = get_open(e, proposal_id);
+    if !is_expired(e, &proposal) {
+        panic_with_error!(e, BondError::NoticeNotElapsed);
+    }
+    let proposer = proposal.proposed_by.clone();
+    close(
+        e,
+        proposal,
+        ProposalStatus::Rejected,
+        "slash_proposal_expired",
+        &proposer,
+    );
+    get_proposal(e, proposal_id).unwrap()
+}
+
+/// Execute slash for an approved proposal. Returns true if executed; proposals past their
+/// voting deadline are rejected as expired.
+pub fn execute_slash_if_approved(e: &Env, proposal_id: u64) -> bool {
+    let proposal = get_open(e, proposal_id);
+    if is_expired(e, &proposal) {
+        expire(e, proposal_id);
+        return false;
+    }
+    let proposer = proposal.proposed_by.clone();
     if !is_approved(e, proposal_id) {
-        proposal.status = ProposalStatus::Rejected;
-        e.storage()
-            .instance()
-            .set(&key_proposal(proposal_id), &proposal);
-        emit_governance_event(
+        close(
             e,
+            proposal,
+            ProposalStatus::Rejected,
             "slash_proposal_rejected",
-            proposal_id,
-            &proposal.proposed_by,
-            proposal.amount,
+            &proposer,
         );
         return false;
     }
-    proposal.status = ProposalStatus::Executed;
-    e.storage()
-        .instance()
-        .set(&key_proposal(proposal_id), &proposal);
-    emit_governance_event(
+    close(
         e,
+        proposal,
+        ProposalStatus::Executed,
         "slash_proposal_executed",
-        proposal_id,
-        &proposal.proposed_by,
-        proposal.amount,
+        &proposer,
     );
     true
 }
@@ -306,3 +768,18 @@ fn emit_governance_event(e: &Env, topic: &str, proposal_id: u64, addr: &Address,
         (proposal_id, addr.clone(), amount),
     );
 }
+
+/// Proposal-scoped event: topics `(topic, identity)`, data `(proposal_id, addr, amount)`.
+fn emit_proposal_event(
+    e: &Env,
+    topic: &str,
+    identity: &Address,
+    proposal_id: u64,
+    addr: &Address,
+    amount: i128,
+) {
+    e.events().publish(
+        (Symbol::new(e, topic), identity.clone()),
+        (proposal_id, addr.clone(), amount),
+    );
+}
@@ -0,0 +1,182 @@
+//! Bond Health Factor
+//!
+//! Combines the slashed ratio, open slash proposals and reported dispute exposure into a
+//! single health factor (basis points, 10_000 = fully healthy). Emits `health_status_changed`
+//! when the bond crosses a threshold (Healthy -> AtRisk -> Critical and back) so monitoring
+//! systems and relying contracts can react before a bond is fully slashed.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::governance_approval::{self, ProposalStatus};
+use crate::{BondError, DataKey, IdentityBond};
+
+/// Health factor of a fully healthy bond (no slashes, proposals or disputes).
+pub const MAX_HEALTH_FACTOR_BPS: u32 = 10_000;
+
+/// Default threshold below which a bond is considered at risk (50%).
+pub const DEFAULT_AT_RISK_BPS: u32 = 5_000;
+
+/// Default threshold below which a bond is considered critical (20%).
+pub const DEFAULT_CRITICAL_BPS: u32 = 2_000;
+
+/// Health classification derived from the health factor and configured thresholds.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HealthStatus {
+    Healthy,
+    AtRisk,
+    Critical,
+}
+
+/// Health factor breakdown for an identity's bond.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthFactor {
+    /// Combined health factor in basis points (0..=10_000).
+    pub factor_bps: u32,
+    pub status: HealthStatus,
+    /// Amount already slashed.
+    pub slashed_amount: i128,
+    /// Sum of amounts in open slash proposals.
+    pub pending_slash_amount: i128,
+    /// Amount currently exposed to open disputes.
+    pub dispute_exposure: i128,
+}
+
+/// Returns (at_risk_bps, critical_bps). Uses defaults if not set.
+#[must_use]
+pub fn get_thresholds(e: &Env) -> (u32, u32) {
+    e.storage()
+        .instance()
+        .get(&DataKey::HealthThresholds)
+        .unwrap_or((DEFAULT_AT_RISK_BPS, DEFAULT_CRITICAL_BPS))
+}
+
+/// Set health thresholds. Admin only (enforced by caller).
+pub fn set_thresholds(e: &Env, at_risk_bps: u32, critical_bps: u32) {
+    if at_risk_bps > MAX_HEALTH_FACTOR_BPS {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    if critical_bps > at_risk_bps {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&DataKey::HealthThresholds, &(at_risk_bps, critical_bps));
+}
+
+/// Returns the amount exposed to open disputes for an identity (0 if none reported).
+#[must_use]
+pub fn get_dispute_exposure(e: &Env, identity: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::DisputeExposure(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// Set the amount exposed to open disputes for an identity. Admin only (enforced by caller).
+pub fn set_dispute_exposure(e: &Env, identity: &Address, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    e.storage()
+        .instance()
+        .set(&DataKey::DisputeExposure(identity.clone()), &amount);
+}
+
+/// Sum of amounts in slash proposals against `identity` that are still open and not past
+/// their voting deadline.
+#[must_use]
+pub fn pending_slash_amount(e: &Env, identity: &Address) -> i128 {
+    let mut total: i128 = 0;
+    for id in governance_approval::open_ids(e).iter() {
+        if let Some(p) = governance_approval::get_proposal(e, id) {
+            if p.status == ProposalStatus::Open
+                && p.identity == *identity
+                && !governance_approval::is_expired(e, &p)
+            {
+                total = total.saturating_add(p.amount);
+            }
+        }
+    }
+    total
+}
+
+/// Pure health factor computation: 10_000 * (1 - (slashed + pending + disputed) / bonded).
+/// Returns 0 when bonded amount is zero or exposure meets/exceeds it.
+#[must_use]
+pub fn compute_factor_bps(bonded: i128, slashed: i128, pending: i128, disputed: i128) -> u32 {
+    if bonded <= 0 {
+        return 0;
+    }
+    let exposure = slashed
+        .saturating_add(pending.max(0))
+        .saturating_add(disputed.max(0));
+    if exposure >= bonded {
+        return 0;
+    }
+    let remaining = bonded - exposure;
+    let factor = remaining.saturating_mul(MAX_HEALTH_FACTOR_BPS as i128) / bonded;
+    factor as u32
+}
+
+/// Classify a health factor against (at_risk_bps, critical_bps).
+#[must_use]
+pub fn classify(factor_bps: u32, at_risk_bps: u32, critical_bps: u32) -> HealthStatus {
+    if factor_bps < critical_bps {
+        HealthStatus::Critical
+    } else if factor_bps < at_risk_bps {
+        HealthStatus::AtRisk
+    } else {
+        HealthStatus::Healthy
+    }
+}
+
+/// Compute the health factor breakdown for a bond.
+#[must_use]
+pub fn compute(e: &Env, bond: &IdentityBond) -> HealthFactor {
+    let pending = pending_slash_amount(e, &bond.identity);
+    let disputed = get_dispute_exposure(e, &bond.identity);
+    let factor_bps = compute_factor_bps(bond.bonded_amount, bond.slashed_amount, pending, disputed);
+    let (at_risk_bps, critical_bps) = get_thresholds(e);
+    HealthFactor {
+        factor_bps,
+        status: classify(factor_bps, at_risk_bps, critical_bps),
+        slashed_amount: bond.slashed_amount,
+        pending_slash_amount: pending,
+        dispute_exposure: disputed,
+    }
+}
+
+/// Recompute the current bond's health and emit `health_status_changed` if the status
+/// crossed a threshold since the last refresh. No-op when there is no active bond.
+pub fn refresh(e: &Env) {
+    let bond: IdentityBond = match e.storage().instance().get(&DataKey::Bond) {
+        Some(b) => b,
+        None => return,
+    };
+    if !bond.active {
+        return;
+    }
+    let health = compute(e, &bond);
+    let key = DataKey::HealthStatus(bond.identity.clone());
+    let previous: HealthStatus = e
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(HealthStatus::Healthy);
+    if previous != health.status {
+        e.storage().instance().set(&key, &health.status);
+        e.events().publish(
+            (Symbol::new(e, "health_status_changed"), bond.identity),
+            (previous, health.status, health.factor_bps),
+        );
+    }
+}
+
+/// Forget the last recorded health status of `identity` (bond closed).
+pub fn clear(e: &Env, identity: &Address) {
+    e.storage()
+        .instance()
+        .remove(&DataKey::HealthStatus(identity.clone()));
+}
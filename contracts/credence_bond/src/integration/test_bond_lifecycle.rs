@@ -9,7 +9,7 @@ use soroban_sdk::{Address, Env};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
     client.initialize(&admin);
@@ -20,7 +20,7 @@ fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
 #[test]
 fn test_lifecycle_create_then_withdraw() {
     let e = Env::default();
-    let (client, admin, identity) = setup(&e);
+    let (client, _admin, identity) = setup(&e);
     let amount = 1000_i128;
     let duration = 86400_u64;
     client.create_bond(&identity, &amount, &duration, &false, &0_u64);
@@ -38,7 +38,7 @@ fn test_lifecycle_create_then_withdraw() {
 #[test]
 fn test_lifecycle_create_topup_withdraw() {
     let e = Env::default();
-    let (client, admin, identity) = setup(&e);
+    let (client, _admin, identity) = setup(&e);
     client.create_bond(&identity, &500_i128, &86400_u64, &false, &0_u64);
     let after_topup = client.top_up(&300_i128);
     assert_eq!(after_topup.bonded_amount, 800);
@@ -107,7 +107,7 @@ fn test_lifecycle_state_consistency() {
 #[test]
 fn test_lifecycle_extend_duration() {
     let e = Env::default();
-    let (client, admin, identity) = setup(&e);
+    let (client, _admin, identity) = setup(&e);
     client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
     let before = client.get_identity_state();
     client.extend_duration(&86400_u64);
@@ -0,0 +1,121 @@
+//! Invariant Checker
+//!
+//! Read-only consistency probe over the contract's storage, for monitoring and as a sanity
+//! check after an upgrade. Each violated invariant is reported by its code; an empty result
+//! means the state is consistent. Subject-level checks sample the bonded identity.
+
+use soroban_sdk::{Env, Symbol, Vec};
+
+use crate::{reserves, unbonding_queue, Attestation, DataKey, IdentityBond};
+
+/// Bond slashed amount exceeds bonded amount.
+pub const INV_SLASHED_EXCEEDS_BONDED: u32 = 1;
+/// Bond bonded or slashed amount is negative.
+pub const INV_NEGATIVE_BOND_AMOUNT: u32 = 2;
+/// Fee pool balance is negative.
+pub const INV_NEGATIVE_FEE_POOL: u32 = 3;
+/// Sampled subject's attestation count differs from its non-revoked indexed attestations.
+pub const INV_SUBJECT_COUNT_MISMATCH: u32 = 4;
+/// Attestation counter is not ahead of every stored attestation id.
+pub const INV_ATTESTATION_COUNTER: u32 = 5;
+/// Governance proposal id counter is not ahead of every stored proposal.
+pub const INV_PROPOSAL_COUNTER: u32 = 6;
+/// Compensation slash id counter is not ahead of every stored claim.
+pub const INV_COMPENSATION_COUNTER: u32 = 7;
+/// Unbonding queue head is past its tail.
+pub const INV_UNBONDING_QUEUE_ORDER: u32 = 8;
+/// Amount pending in the unbonding queue exceeds the bond's value locked.
+pub const INV_UNBONDING_EXCEEDS_TVL: u32 = 9;
+/// Compensation escrow balance is negative.
+pub const INV_NEGATIVE_ESCROW: u32 = 10;
+/// Bond token balance is below the reserved balance.
+pub const INV_RESERVES_UNCOVERED: u32 = 11;
+
+fn get_u64(e: &Env, key: &DataKey) -> u64 {
+    e.storage().instance().get(key).unwrap_or(0)
+}
+
+/// Returns the codes of all violated invariants (empty if consistent).
+#[must_use]
+pub fn check(e: &Env) -> Vec<u32> {
+    let mut violations = Vec::new(e);
+    let storage = e.storage().instance();
+
+    let bond: Option<IdentityBond> = storage.get(&DataKey::Bond);
+    if let Some(bond) = &bond {
+        if bond.bonded_amount < 0 || bond.slashed_amount < 0 {
+            violations.push_back(INV_NEGATIVE_BOND_AMOUNT);
+        }
+        if bond.slashed_amount > bond.bonded_amount {
+            violations.push_back(INV_SLASHED_EXCEEDS_BONDED);
+        }
+    }
+
+    let fee_pool: i128 = storage.get(&Symbol::new(e, "fees")).unwrap_or(0);
+    if fee_pool < 0 {
+        violations.push_back(INV_NEGATIVE_FEE_POOL);
+    }
+
+    let attestation_counter = get_u64(e, &DataKey::AttestationCounter);
+    if storage.has(&DataKey::Attestation(attestation_counter)) {
+        violations.push_back(INV_ATTESTATION_COUNTER);
+    }
+    if let Some(bond) = &bond {
+        let ids: Vec<u64> = storage
+            .get(&DataKey::SubjectAttestations(bond.identity.clone()))
+            .unwrap_or(Vec::new(e));
+        let mut live: u32 = 0;
+        let mut counter_behind = false;
+        for id in ids.iter() {
+            if id >= attestation_counter {
+                counter_behind = true;
+            }
+            let attestation: Option<Attestation> = storage.get(&DataKey::Attestation(id));
+            if attestation.is_some_and(|a| !a.revoked) {
+                live += 1;
+            }
+        }
+        let count: u32 = storage
+            .get(&DataKey::SubjectAttestationCount(bond.identity.clone()))
+            .unwrap_or(0);
+        if count != live {
+            violations.push_back(INV_SUBJECT_COUNT_MISMATCH);
+        }
+        if counter_behind && !violations.contains(INV_ATTESTATION_COUNTER) {
+            violations.push_back(INV_ATTESTATION_COUNTER);
+        }
+    }
+
+    let next_proposal = get_u64(e, &DataKey::GovernanceNextProposalId);
+    if storage.has(&DataKey::GovernanceProposal(next_proposal)) {
+        violations.push_back(INV_PROPOSAL_COUNTER);
+    }
+
+    let compensation_counter = get_u64(e, &DataKey::CompensationCounter);
+    if storage.has(&DataKey::CompensationClaim(compensation_counter)) {
+        violations.push_back(INV_COMPENSATION_COUNTER);
+    }
+
+    if get_u64(e, &DataKey::UnbondingHead) > get_u64(e, &DataKey::UnbondingTail) {
+        violations.push_back(INV_UNBONDING_QUEUE_ORDER);
+    }
+    let pending = unbonding_queue::get_pending_total(e);
+    let tvl = bond
+        .as_ref()
+        .map(|b| b.bonded_amount.saturating_sub(b.slashed_amount).max(0))
+        .unwrap_or(0);
+    if pending > tvl {
+        violations.push_back(INV_UNBONDING_EXCEEDS_TVL);
+    }
+
+    let escrow: i128 = storage.get(&DataKey::CompensationEscrow).unwrap_or(0);
+    if escrow < 0 {
+        violations.push_back(INV_NEGATIVE_ESCROW);
+    }
+
+    if reserves::token_balance(e).is_some_and(|balance| balance < reserves::total(e)) {
+        violations.push_back(INV_RESERVES_UNCOVERED);
+    }
+
+    violations
+}
@@ -1,19 +1,87 @@
 #![no_std]
 
+pub mod activity_feed;
+pub mod address_book;
+pub mod admin_actions;
+pub mod admin_transfer;
+pub mod attestation_archive;
+pub mod attestation_hash;
+pub mod attestation_limits;
+pub mod attestation_objection;
+pub mod attestation_summary;
+pub mod attestation_template;
+pub mod attester_deregistration;
+pub mod attester_directory;
+pub mod attester_onboarding;
+pub mod attester_org;
+pub mod attester_suspension;
+pub mod auto_topup;
+pub mod bond_health;
+pub mod bond_limits;
+pub mod challenge_period;
+pub mod compensation;
+pub mod config_export;
+pub mod counter_attestation;
+pub mod credential_proof;
+pub mod dedup_policy;
+pub mod delegated_attestation;
+pub mod dispute_linkage;
 pub mod early_exit_penalty;
-mod fees;
+pub mod error;
+pub mod features;
+pub mod fees;
+pub mod funding;
+pub mod governance_actions;
 pub mod governance_approval;
+pub mod health_factor;
+pub mod invariants;
 mod nonce;
+pub mod notification_prefs;
+pub mod pausable;
+pub mod probation;
+pub mod proposer_age;
+pub mod query_access;
+pub mod read_grant;
+pub mod referral;
+pub mod reputation;
+pub mod reserves;
+pub mod revocation_registry;
+pub mod reward_accrual;
+pub mod rewards;
 pub mod rolling_bond;
-mod slashing;
+pub mod schema_version;
+pub mod score_breakdown;
+pub mod slash_appeal;
+pub mod slash_history;
+pub mod slash_records;
+pub mod slash_reversal;
+pub mod slash_settlement;
+pub mod slashing;
+pub mod sub_bonds;
+pub mod sybil_heuristics;
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
 pub mod tiered_bond;
+pub mod time_math;
+pub mod token_adaptor;
+pub mod token_info;
+pub mod token_recovery;
+pub mod unbond_cooldown;
+pub mod unbonding_queue;
+pub mod upgrade;
+pub mod verification_snapshot;
 mod weighted_attestation;
 
 pub mod types;
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error, Address, Bytes, BytesN, Env, Map,
+    String, Symbol, Vec,
+};
 
-pub use types::Attestation;
+pub use error::BondError;
+pub use types::{Attestation, RevocationReason};
+pub use weighted_attestation::WeightSource;
 
 /// Identity tier based on bonded amount (Bronze < Silver < Gold < Platinum).
 #[contracttype]
@@ -67,6 +135,51 @@ pub enum DataKey {
     // Bond creation fee
     FeeTreasury,
     FeeBps,
+    // Bond health factor
+    HealthThresholds,
+    HealthStatus(Address),
+    DisputeExposure(Address),
+    // Attestation claim templates
+    AttestationTemplate(u32),
+    AttestationTemplateCounter,
+    // Feature flags bitmask
+    Features,
+    // Victim compensation for slashes with a beneficiary
+    CompensationConfig,
+    CompensationCounter,
+    CompensationClaim(u64),
+    CompensationEscrow,
+    // Per-verifier share cap of a subject's aggregate weight (bps)
+    VerifierWeightCapBps,
+    // Throttled unbonding queue
+    UnbondingConfig,
+    UnbondingRequest(u64),
+    UnbondingHead,
+    UnbondingTail,
+    UnbondingPendingTotal,
+    UnbondingEpoch,
+    // Attester organizations (parent with sub-attesters)
+    SubAttester(Address),
+    OrgSubAttesters(Address),
+    AttesterIssuedCount(Address),
+    // Rolling bond renewal bonus
+    RenewalBonusConfig,
+    RenewalCount,
+    // Rolling bond auto-exit target period
+    AutoExitPeriod,
+    // Attestation read grants: (subject, reader) -> expires_at
+    ReadGrant(Address, Address),
+    // Re-bonding probation after a full slash
+    ProbationConfig,
+    FullSlashAt(Address),
+    ProbationUntil(Address),
+    // Per-subject recent attestation activity (bounded buffer)
+    SubjectActivity(Address),
+    ExpiryRecorded(u64),
+    // Claimable rewards
+    RewardBalance(Address),
+    // DataKey is at the contracttype limit of 50 variants: newer modules declare their own
+    // key enum (variant names must not collide with the ones above).
 }
 
 #[contract]
@@ -99,7 +212,7 @@ impl CredenceBond {
 
     fn with_reentrancy_guard<T, F: FnOnce() -> T>(e: &Env, f: F) -> T {
         if Self::check_lock(e) {
-            panic!("reentrancy detected");
+            panic_with_error!(e, BondError::Reentrancy);
         }
         Self::acquire_lock(e);
         let result = f();
@@ -107,61 +220,660 @@ impl CredenceBond {
         result
     }
 
-    fn require_admin(e: &Env, admin: &Address) {
+    fn require_admin(e: &Env, admin: &Address) -> Result<(), BondError> {
         let stored_admin: Address = e
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
+            .ok_or(BondError::NotInitialized)?;
         if stored_admin != *admin {
-            panic!("not admin");
+            return Err(BondError::NotAdmin);
         }
+        admin.require_auth();
+        Ok(())
+    }
+
+    /// `NoBond` unless `identity` owns the active bond held by this contract.
+    fn require_active_bond_for(e: &Env, identity: &Address) -> Result<(), BondError> {
+        let bond: Option<IdentityBond> = e.storage().instance().get(&DataKey::Bond);
+        match bond {
+            Some(b) if b.active && b.identity == *identity => {}
+            _ => return Err(BondError::NoBond),
+        }
+        Ok(())
     }
 
     /// Initialize the contract (admin).
-    pub fn initialize(e: Env, admin: Address) {
+    pub fn initialize(e: Env, admin: Address) -> Result<(), BondError> {
         e.storage().instance().set(&DataKey::Admin, &admin);
+        schema_version::init(&e);
+        Ok(())
+    }
+
+    /// Bring stored data from schema `from_version` up to the layout of this build.
+    /// Idempotent: returns the current version without changes once migrated. Admin only.
+    pub fn migrate(e: Env, admin: Address, from_version: u32) -> Result<u32, BondError> {
+        Self::require_admin(&e, &admin)?;
+        Ok(schema_version::migrate(&e, from_version))
+    }
+
+    /// Nominate `new_admin` as the next admin. The current admin stays in charge until the
+    /// nominee calls `accept_admin`; a new nomination replaces a pending one. Admin only.
+    pub fn transfer_admin(e: Env, admin: Address, new_admin: Address) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        governance_actions::require_not_required(&e);
+        admin_actions::record(
+            &e,
+            &admin,
+            admin_actions::AdminActionKind::TransferAdmin,
+            Some(new_admin.clone()),
+            0,
+        );
+        admin_transfer::start(&e, &admin, &new_admin);
+        Ok(())
+    }
+
+    /// Complete a pending admin transfer. Requires the nominee's auth. Returns the new admin.
+    pub fn accept_admin(e: Env) -> Result<Address, BondError> {
+        Ok(admin_transfer::accept(&e))
+    }
+
+    /// Nominated admin awaiting acceptance, if any.
+    pub fn get_pending_admin(e: Env) -> Option<Address> {
+        admin_transfer::get_pending(&e)
+    }
+
+    /// Emergency stop: refuse bond, withdrawal, slashing, attestation, claim and fee
+    /// operations until `unpause`. Admin only.
+    pub fn pause(e: Env, admin: Address) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        pausable::set_paused(&e, &admin, true);
+        Ok(())
+    }
+
+    /// Lift an emergency stop. Admin only.
+    pub fn unpause(e: Env, admin: Address) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        pausable::set_paused(&e, &admin, false);
+        Ok(())
+    }
+
+    pub fn is_paused(e: Env) -> bool {
+        pausable::is_paused(&e)
+    }
+
+    /// Storage layout version of the stored data (0 if deployed before versioning).
+    pub fn get_schema_version(e: Env) -> u32 {
+        schema_version::get(&e)
+    }
+
+    /// Crate version of the deployed build.
+    pub fn version(e: Env) -> String {
+        String::from_str(&e, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Replace the contract WASM with the uploaded `new_wasm_hash`. With upgrade governance
+    /// on, the hash needs an approved, unexecuted upgrade proposal. Admin only.
+    pub fn upgrade(e: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        admin_actions::record(&e, &admin, admin_actions::AdminActionKind::Upgrade, None, 0);
+        upgrade::apply(&e, &admin, &new_wasm_hash);
+        Ok(())
+    }
+
+    /// Require (or stop requiring) governor approval for upgrades. Admin only. Once required,
+    /// turning it off needs an approved `DisableUpgradeGovernance` action (`Unauthorized` here).
+    pub fn set_upgrade_governance(e: Env, admin: Address, required: bool) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        if !required && upgrade::is_governance_required(&e) {
+            return Err(BondError::Unauthorized);
+        }
+        upgrade::set_governance_required(&e, required);
+        Ok(())
+    }
+
+    pub fn is_upgrade_governance_required(e: Env) -> bool {
+        upgrade::is_governance_required(&e)
+    }
+
+    /// Propose upgrading to `wasm_hash`. Proposer must be the admin or a governor.
+    pub fn propose_upgrade(
+        e: Env,
+        proposer: Address,
+        wasm_hash: BytesN<32>,
+    ) -> Result<u64, BondError> {
+        proposer.require_auth();
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(BondError::NotInitialized)?;
+        if proposer != admin && !governance_approval::get_governors(&e).contains(&proposer) {
+            return Err(BondError::Unauthorized);
+        }
+        Ok(upgrade::propose(&e, &proposer, &wasm_hash))
+    }
+
+    /// Governor vote on an upgrade proposal.
+    pub fn vote_upgrade(
+        e: Env,
+        voter: Address,
+        proposal_id: u64,
+        approve: bool,
+    ) -> Result<(), BondError> {
+        voter.require_auth();
+        upgrade::vote(&e, &voter, proposal_id, approve);
+        Ok(())
+    }
+
+    pub fn get_upgrade_proposal(e: Env, proposal_id: u64) -> Option<upgrade::UpgradeProposal> {
+        upgrade::get_proposal(&e, proposal_id)
+    }
+
+    pub fn is_upgrade_approved(e: Env, proposal_id: u64) -> bool {
+        upgrade::is_approved(&e, proposal_id)
+    }
+
+    /// Require (or stop requiring) governor-approved actions for fee, early-exit and tier
+    /// configuration and admin transfers. Admin only. Once required, turning it off needs an
+    /// approved `DisableActionGovernance` action (`Unauthorized` here).
+    pub fn set_action_governance(e: Env, admin: Address, required: bool) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        if !required {
+            governance_actions::require_not_required(&e);
+        }
+        governance_actions::set_required(&e, required);
+        Ok(())
+    }
+
+    pub fn is_action_governance_required(e: Env) -> bool {
+        governance_actions::is_required(&e)
+    }
+
+    /// Propose a governed action. Proposer must be the admin or a governor.
+    pub fn propose_action(
+        e: Env,
+        proposer: Address,
+        action: governance_actions::ProposalAction,
+    ) -> Result<u64, BondError> {
+        proposer.require_auth();
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(BondError::NotInitialized)?;
+        if proposer != admin && !governance_approval::get_governors(&e).contains(&proposer) {
+            return Err(BondError::Unauthorized);
+        }
+        if let governance_actions::ProposalAction::Slash(identity, _) = &action {
+            Self::require_active_bond_for(&e, identity)?;
+        }
+        Ok(governance_actions::propose(&e, &proposer, action))
+    }
+
+    /// Governor (or delegate) vote on an action proposal.
+    pub fn vote_action(
+        e: Env,
+        voter: Address,
+        proposal_id: u64,
+        approve: bool,
+    ) -> Result<(), BondError> {
+        voter.require_auth();
+        governance_actions::vote(&e, &voter, proposal_id, approve);
+        Ok(())
+    }
+
+    /// Apply an approved action. Only its proposer or the admin may execute, once, before
+    /// the voting deadline.
+    pub fn execute_action(
+        e: Env,
+        executor: Address,
+        proposal_id: u64,
+    ) -> Result<governance_actions::ActionProposal, BondError> {
+        pausable::require_not_paused(&e);
+        executor.require_auth();
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(BondError::NotInitialized)?;
+        let proposal =
+            governance_actions::get_proposal(&e, proposal_id).ok_or(BondError::ProposalNotFound)?;
+        if executor != proposal.proposed_by && executor != admin {
+            return Err(BondError::Unauthorized);
+        }
+        let proposal = governance_actions::take_approved(&e, &executor, proposal_id);
+        match proposal.action.clone() {
+            governance_actions::ProposalAction::SetFeeConfig(treasury, fee_bps) => {
+                fees::set_config(&e, treasury, fee_bps);
+            }
+            governance_actions::ProposalAction::SetTierThresholds(thresholds) => {
+                tiered_bond::set_thresholds(&e, &thresholds);
+                verification_snapshot::refresh_bond(&e);
+            }
+            governance_actions::ProposalAction::SetEarlyExitConfig(treasury, penalty_bps) => {
+                early_exit_penalty::set_config(&e, treasury, penalty_bps);
+            }
+            governance_actions::ProposalAction::TransferAdmin(new_admin) => {
+                admin_transfer::start(&e, &admin, &new_admin);
+            }
+            governance_actions::ProposalAction::Slash(identity, amount) => {
+                Self::require_active_bond_for(&e, &identity)?;
+                let slash_id = governance_approval::record_approved_slash(
+                    &e,
+                    &proposal.proposed_by,
+                    &identity,
+                    amount,
+                );
+                Self::apply_proposal_slash(&e, &admin, slash_id, &identity, amount)?;
+            }
+            governance_actions::ProposalAction::UpgradeWasm(wasm_hash) => {
+                upgrade::update(&e, &executor, &wasm_hash);
+            }
+            governance_actions::ProposalAction::DisableActionGovernance => {
+                governance_actions::set_required(&e, false);
+            }
+            governance_actions::ProposalAction::DisableUpgradeGovernance => {
+                upgrade::set_governance_required(&e, false);
+            }
+        }
+        Ok(proposal)
+    }
+
+    pub fn get_action_proposal(
+        e: Env,
+        proposal_id: u64,
+    ) -> Option<governance_actions::ActionProposal> {
+        governance_actions::get_proposal(&e, proposal_id)
+    }
+
+    /// Vote weights of an action proposal under the current voting mode.
+    pub fn get_action_tally(
+        e: Env,
+        proposal_id: u64,
+    ) -> Option<governance_approval::ProposalTally> {
+        governance_actions::get_proposal(&e, proposal_id)?;
+        Some(governance_actions::tally(&e, proposal_id))
     }
 
     /// Set early exit penalty config. Only admin should call.
-    pub fn set_early_exit_config(e: Env, admin: Address, treasury: Address, penalty_bps: u32) {
-        Self::require_admin(&e, &admin);
+    pub fn set_early_exit_config(
+        e: Env,
+        admin: Address,
+        treasury: Address,
+        penalty_bps: u32,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        governance_actions::require_not_required(&e);
         early_exit_penalty::set_config(&e, treasury, penalty_bps);
+        Ok(())
+    }
+
+    /// Set the bond token. Caches its decimals and symbol and validates existing amount
+    /// configuration against one whole token unit. Admin only.
+    pub fn set_token(
+        e: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<token_info::TokenInfo, BondError> {
+        Self::require_admin(&e, &admin)?;
+        admin_actions::record(
+            &e,
+            &admin,
+            admin_actions::AdminActionKind::SetToken,
+            Some(token.clone()),
+            0,
+        );
+        token_recovery::mark_operational(&e, &token);
+        Ok(token_info::set_token(&e, &token))
+    }
+
+    /// Return `amount` of a token sent to the contract by mistake to `to`. The bond token
+    /// (current or any previously configured one) cannot be recovered. Admin only.
+    pub fn recover_token(
+        e: Env,
+        admin: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        admin_actions::record(
+            &e,
+            &admin,
+            admin_actions::AdminActionKind::RecoverToken,
+            Some(token.clone()),
+            amount,
+        );
+        token_recovery::recover(&e, &token, &to, amount);
+        Ok(())
+    }
+
+    /// Cached bond token address, decimals and symbol, if a token was set.
+    pub fn get_token_info(e: Env) -> Option<token_info::TokenInfo> {
+        token_info::get(&e)
+    }
+
+    /// Share of early-exit penalties (bps) redistributed to stake that stays locked; the
+    /// rest goes to the treasury. Admin only.
+    /// Replace the linear early-exit penalty with ordered `(remaining_bps, penalty_bps)`
+    /// brackets (strictly increasing `remaining_bps`, at most
+    /// `early_exit_penalty::MAX_PENALTY_BRACKETS`). An exit pays the bracket matching the share
+    /// of the lock-up still remaining. An empty schedule restores the linear formula. Admin
+    /// only.
+    pub fn set_early_exit_schedule(
+        e: Env,
+        admin: Address,
+        schedule: Vec<(u32, u32)>,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        early_exit_penalty::set_schedule(&e, &schedule);
+        Ok(())
+    }
+
+    pub fn get_early_exit_schedule(e: Env) -> Vec<(u32, u32)> {
+        early_exit_penalty::get_schedule(&e)
+    }
+
+    /// Route early-exit penalties through the early-exit treasury as a
+    /// credence_treasury contract: each penalty is transferred at exit time and booked with
+    /// `receive_fee` as `ProtocolFee` instead of being reserved for `collect_penalties`.
+    /// Enabling requires a bond token. Admin only.
+    pub fn set_early_exit_treasury_routing(
+        e: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        early_exit_penalty::set_routed(&e, enabled);
+        Ok(())
+    }
+
+    pub fn get_early_exit_treasury_routing(e: Env) -> bool {
+        early_exit_penalty::is_routed(&e)
+    }
+
+    /// Claimable rewards of `identity` as of its last checkpoint.
+    pub fn get_rewards_balance(e: Env, identity: Address) -> i128 {
+        rewards::get_balance(&e, &identity)
+    }
+
+    /// Claim accumulated rewards. Returns the amount paid out.
+    pub fn claim_rewards(e: Env, identity: Address) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        reward_accrual::checkpoint(&e);
+        let amount = rewards::claim(&e, &identity);
+        funding::release(&e, &identity, amount);
+        Ok(amount)
+    }
+
+    /// Claimable rewards of `identity`, including yield accrued since its last checkpoint.
+    pub fn get_pending_rewards(e: Env, identity: Address) -> i128 {
+        reward_accrual::pending(&e, &identity)
+    }
+
+    /// Fund the reward pool bonded funds accrue yield from with `amount` of the bond token.
+    /// `funder` must be the admin or the address book treasury. Returns the new pool size.
+    pub fn fund_reward_pool(e: Env, funder: Address, amount: i128) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        funder.require_auth();
+        let treasury = address_book::resolve(&e, &Symbol::new(&e, address_book::ROLE_TREASURY));
+        let admin: Option<Address> = e.storage().instance().get(&DataKey::Admin);
+        if admin.as_ref() != Some(&funder) && treasury.as_ref() != Some(&funder) {
+            return Err(BondError::Unauthorized);
+        }
+        Ok(reward_accrual::fund(&e, &funder, amount))
+    }
+
+    pub fn get_reward_pool(e: Env) -> i128 {
+        reward_accrual::get_pool(&e)
+    }
+
+    /// Annual yield (bps of locked stake) bonded funds accrue from the reward pool. Admin
+    /// only.
+    pub fn set_reward_rate(e: Env, admin: Address, rate_bps: u32) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        reward_accrual::set_rate_bps(&e, rate_bps);
+        Ok(())
     }
 
-    pub fn register_attester(e: Env, attester: Address) {
+    pub fn get_reward_rate(e: Env) -> u32 {
+        reward_accrual::get_rate_bps(&e)
+    }
+
+    /// Register an attester. Requires the admin's auth.
+    pub fn register_attester(e: Env, attester: Address) -> Result<(), BondError> {
         let admin: Address = e
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
+            .ok_or(BondError::NotInitialized)?;
+        admin.require_auth();
+        attester_onboarding::register(&e, &attester);
+        Ok(())
+    }
 
-        e.storage()
-            .instance()
-            .set(&DataKey::Attester(attester.clone()), &true);
-        e.events()
-            .publish((Symbol::new(&e, "attester_registered"),), attester);
+    /// Apply to become an attester, committing to off-chain metadata with `metadata_hash`.
+    /// The application waits for `approve_attester` or `reject_attester`.
+    pub fn apply_as_attester(
+        e: Env,
+        applicant: Address,
+        metadata_hash: BytesN<32>,
+    ) -> Result<attester_onboarding::AttesterApplication, BondError> {
+        pausable::require_not_paused(&e);
+        applicant.require_auth();
+        Ok(attester_onboarding::apply(&e, &applicant, &metadata_hash))
+    }
+
+    /// Approve a pending attester application, registering the applicant. Admin only.
+    pub fn approve_attester(
+        e: Env,
+        admin: Address,
+        applicant: Address,
+    ) -> Result<attester_onboarding::AttesterApplication, BondError> {
+        Self::require_admin(&e, &admin)?;
+        Ok(attester_onboarding::approve(&e, &applicant))
+    }
+
+    /// Reject a pending attester application. Admin only.
+    pub fn reject_attester(
+        e: Env,
+        admin: Address,
+        applicant: Address,
+    ) -> Result<attester_onboarding::AttesterApplication, BondError> {
+        Self::require_admin(&e, &admin)?;
+        Ok(attester_onboarding::reject(&e, &applicant))
+    }
+
+    pub fn get_attester_application(
+        e: Env,
+        applicant: Address,
+    ) -> Option<attester_onboarding::AttesterApplication> {
+        attester_onboarding::get_application(&e, &applicant)
+    }
+
+    /// Pending attester applications, oldest first (`limit` capped at 50).
+    pub fn get_attester_applications(
+        e: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<attester_onboarding::AttesterApplication> {
+        attester_onboarding::list_pending(&e, offset, limit)
+    }
+
+    pub fn get_attester_application_count(e: Env) -> u32 {
+        attester_onboarding::pending_count(&e)
     }
 
-    pub fn unregister_attester(e: Env, attester: Address) {
+    /// Unregister an attester. Requires the admin's auth. With a deregistration notice period
+    /// configured, the attester stops issuing at once but stays registered (to revoke or hand
+    /// off attestations) until the notice elapses; otherwise removal is immediate.
+    pub fn unregister_attester(e: Env, attester: Address) -> Result<(), BondError> {
         let admin: Address = e
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
+            .ok_or(BondError::NotInitialized)?;
+        admin.require_auth();
+        let registered = e
+            .storage()
+            .instance()
+            .has(&DataKey::Attester(attester.clone()));
+        if registered {
+            admin_actions::record(
+                &e,
+                &admin,
+                admin_actions::AdminActionKind::UnregisterAttester,
+                Some(attester.clone()),
+                0,
+            );
+            if attester_deregistration::get_notice_period(&e) > 0 {
+                attester_deregistration::schedule(&e, &attester);
+                return Ok(());
+            }
+        }
 
         e.storage()
             .instance()
             .remove(&DataKey::Attester(attester.clone()));
+        attester_deregistration::clear(&e, &attester);
+        attester_directory::remove(&e, &attester);
         e.events()
             .publish((Symbol::new(&e, "attester_unregistered"),), attester);
+        Ok(())
+    }
+
+    /// Set the notice period (seconds) for attester deregistration; 0 makes it immediate.
+    /// Admin only.
+    pub fn set_deregistration_notice(
+        e: Env,
+        admin: Address,
+        seconds: u64,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        attester_deregistration::set_notice_period(&e, seconds);
+        Ok(())
+    }
+
+    pub fn get_deregistration_notice(e: Env) -> u64 {
+        attester_deregistration::get_notice_period(&e)
+    }
+
+    /// When `attester`'s pending deregistration takes effect, if one is pending.
+    pub fn get_deregistration_effective_at(e: Env, attester: Address) -> Option<u64> {
+        attester_deregistration::get_effective_at(&e, &attester)
+    }
+
+    /// Remove the registration of an attester whose deregistration notice has elapsed.
+    /// Callable by anyone.
+    pub fn finalize_deregistration(e: Env, attester: Address) -> Result<(), BondError> {
+        attester_deregistration::finalize(&e, &attester);
+        Ok(())
+    }
+
+    /// Register up to `MAX_BATCH_SIZE` attesters with a class label and initial stake.
+    /// All-or-nothing: any invalid entry reverts the whole batch. Admin only.
+    pub fn register_attesters_batch(
+        e: Env,
+        admin: Address,
+        entries: Vec<(Address, u32, i128)>,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        attester_onboarding::register_batch(&e, &entries);
+        Ok(())
+    }
+
+    /// Set the stakes of up to `MAX_BATCH_SIZE` registered attesters. All-or-nothing.
+    /// Admin only.
+    pub fn set_attester_stakes_batch(
+        e: Env,
+        admin: Address,
+        entries: Vec<(Address, i128)>,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        attester_onboarding::set_stakes_batch(&e, &entries);
+        Ok(())
+    }
+
+    /// Class label assigned at batch onboarding (0 if none).
+    pub fn get_attester_class(e: Env, attester: Address) -> u32 {
+        attester_onboarding::get_class(&e, &attester)
     }
 
     pub fn is_attester(e: Env, attester: Address) -> bool {
-        e.storage()
+        let registered = e
+            .storage()
             .instance()
-            .get(&DataKey::Attester(attester))
-            .unwrap_or(false)
+            .get(&DataKey::Attester(attester.clone()))
+            .unwrap_or(false);
+        registered && !attester_deregistration::is_deregistered(&e, &attester)
+    }
+
+    /// Temporarily block `attester` from issuing new attestations until `until_timestamp`.
+    /// Registration and existing attestations are untouched. A timestamp not after now lifts
+    /// the suspension. Admin or governor only.
+    pub fn suspend_attester(
+        e: Env,
+        caller: Address,
+        attester: Address,
+        until_timestamp: u64,
+    ) -> Result<(), BondError> {
+        caller.require_auth();
+        attester_suspension::suspend(&e, &caller, &attester, until_timestamp);
+        Ok(())
+    }
+
+    /// True if `attester` is registered and not currently suspended.
+    pub fn is_attester_active(e: Env, attester: Address) -> bool {
+        Self::is_attester(e.clone(), attester.clone())
+            && !attester_suspension::is_suspended(&e, &attester)
+    }
+
+    /// Timestamp until which `attester` is suspended (0 if not suspended).
+    pub fn get_attester_suspended_until(e: Env, attester: Address) -> u64 {
+        attester_suspension::get_suspended_until(&e, &attester)
+    }
+
+    /// Lifecycle status of `attester`: Active, Suspended, Removed or Deregistering.
+    pub fn get_attester_status(e: Env, attester: Address) -> attester_suspension::AttesterStatus {
+        attester_suspension::status(&e, &attester)
+    }
+
+    /// Publish a display name and a hash of off-chain metadata for a registered attester.
+    pub fn set_attester_metadata(
+        e: Env,
+        attester: Address,
+        name: String,
+        uri_hash: BytesN<32>,
+    ) -> Result<attester_directory::AttesterMetadata, BondError> {
+        pausable::require_not_paused(&e);
+        attester.require_auth();
+        Ok(attester_directory::set_metadata(
+            &e, &attester, &name, &uri_hash,
+        ))
+    }
+
+    /// Directory entry of a registered attester: registration time, stake, weight, status,
+    /// attestation count and metadata.
+    pub fn get_attester_info(
+        e: Env,
+        attester: Address,
+    ) -> Option<attester_directory::AttesterInfo> {
+        attester_directory::info(&e, &attester)
+    }
+
+    /// Registered attesters in registration order (`limit` capped at 50).
+    pub fn get_attesters_page(
+        e: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<attester_directory::AttesterInfo> {
+        attester_directory::list(&e, offset, limit)
+    }
+
+    pub fn get_attester_count(e: Env) -> u32 {
+        attester_directory::count(&e)
     }
 
     /// Create a bond for an identity.
@@ -173,66 +885,309 @@ impl CredenceBond {
         duration: u64,
         is_rolling: bool,
         notice_period_duration: u64,
-    ) -> IdentityBond {
-        let bond_start = e.ledger().timestamp();
-
-        // Verify end timestamp wouldn't overflow.
-        let _end_timestamp = bond_start
-            .checked_add(duration)
-            .expect("bond end timestamp would overflow");
-
-        let (fee, net_amount) = fees::calculate_fee(&e, amount);
-        if fee > 0 {
-            let (treasury_opt, _) = fees::get_config(&e);
-            if let Some(treasury) = treasury_opt {
-                fees::record_fee(&e, &identity, amount, fee, &treasury);
-            }
-        }
-
-        let bond = IdentityBond {
-            identity: identity.clone(),
-            bonded_amount: net_amount,
-            bond_start,
-            bond_duration: duration,
-            slashed_amount: 0,
-            active: true,
+    ) -> Result<IdentityBond, BondError> {
+        Ok(Self::create_bond_internal(
+            e,
+            identity,
+            amount,
+            duration,
             is_rolling,
-            withdrawal_requested_at: 0,
             notice_period_duration,
-        };
+            None,
+        ))
+    }
 
-        e.storage().instance().set(&DataKey::Bond, &bond);
+    /// Select how tokens are pulled: `Auto`, `Sac` (inline transfer) or `Sep41`
+    /// (allowance). Admin only.
+    pub fn set_token_mode(
+        e: Env,
+        admin: Address,
+        mode: token_adaptor::TokenMode,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        token_adaptor::set_mode(&e, mode);
+        Ok(())
+    }
 
-        let old_tier = BondTier::Bronze;
-        let new_tier = tiered_bond::get_tier_for_amount(net_amount);
-        tiered_bond::emit_tier_change_if_needed(&e, &identity, old_tier, new_tier);
-        bond
+    pub fn get_token_mode(e: Env) -> token_adaptor::TokenMode {
+        token_adaptor::get_mode(&e)
     }
 
-    pub fn create_bond_with_rolling(
+    /// Create a bond and pull `amount` of the bond token from `identity` in one call. The
+    /// identity's signature authorizes the token transfer inline, so no `approve` step is
+    /// needed; tokens that reject the inline transfer are pulled via `transfer_from` against
+    /// an existing allowance instead. Requires a bond token (`set_token`).
+    pub fn create_bond_funded(
         e: Env,
         identity: Address,
         amount: i128,
         duration: u64,
         is_rolling: bool,
         notice_period_duration: u64,
-    ) -> IdentityBond {
-        Self::create_bond(
+    ) -> Result<IdentityBond, BondError> {
+        if token_info::get(&e).is_none() {
+            return Err(BondError::TokenNotSet);
+        }
+        Ok(Self::create_bond_internal(
             e,
             identity,
             amount,
             duration,
             is_rolling,
             notice_period_duration,
-        )
+            None,
+        ))
     }
 
-    pub fn get_identity_state(e: Env) -> IdentityBond {
-        e.storage()
-            .instance()
-            .get::<_, IdentityBond>(&DataKey::Bond)
-            .unwrap_or_else(|| panic!("no bond"))
-    }
+    /// Create a bond attributed to `referrer`. The referrer is recorded for the bond,
+    /// counted in its referral stats, and credited the configured share of the creation fee.
+    pub fn create_bond_with_referrer(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        duration: u64,
+        is_rolling: bool,
+        notice_period_duration: u64,
+        referrer: Address,
+    ) -> Result<IdentityBond, BondError> {
+        Ok(Self::create_bond_internal(
+            e,
+            identity,
+            amount,
+            duration,
+            is_rolling,
+            notice_period_duration,
+            Some(referrer),
+        ))
+    }
+
+    /// Referrer of the current bond, if it was created with one.
+    pub fn get_bond_referrer(e: Env) -> Option<Address> {
+        referral::get_bond_referrer(&e)
+    }
+
+    /// Aggregate referral counters of `referrer`.
+    pub fn get_referral_stats(e: Env, referrer: Address) -> referral::ReferralStats {
+        referral::get_stats(&e, &referrer)
+    }
+
+    /// Set the share of bond-creation fees credited to referrers (bps). Admin only.
+    pub fn set_referral_fee_share(e: Env, admin: Address, fee_bps: u32) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        referral::set_fee_bps(&e, fee_bps);
+        Ok(())
+    }
+
+    pub fn get_referral_fee_share(e: Env) -> u32 {
+        referral::get_fee_bps(&e)
+    }
+
+    /// Referral fees credited to `referrer` and not yet claimed.
+    pub fn get_unclaimed_referral_fees(e: Env, referrer: Address) -> i128 {
+        referral::get_unclaimed(&e, &referrer)
+    }
+
+    /// Claim accumulated referral fees. Returns the amount paid out.
+    pub fn claim_referral_fees(e: Env, referrer: Address) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        referrer.require_auth();
+        Ok(referral::claim(&e, &referrer))
+    }
+
+    /// Shared body of the `create_bond*` entrypoints.
+    fn create_bond_internal(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        duration: u64,
+        is_rolling: bool,
+        notice_period_duration: u64,
+        referrer: Option<Address>,
+    ) -> IdentityBond {
+        pausable::require_not_paused(&e);
+        if let Some(current) = e
+            .storage()
+            .instance()
+            .get::<_, IdentityBond>(&DataKey::Bond)
+        {
+            if current.active && current.bonded_amount > current.slashed_amount {
+                panic_with_error!(e, BondError::Unauthorized);
+            }
+        }
+        if is_rolling {
+            features::require_enabled(&e, features::FEATURE_ROLLING_BONDS);
+        }
+        bond_limits::require_amount(&e, amount);
+        if token_info::get(&e).is_some() {
+            identity.require_auth();
+            funding::pull(&e, &identity, amount);
+        }
+        bond_limits::require_duration(&e, duration);
+        probation::check_rebond(&e, &identity, amount);
+        let bond_start = e.ledger().timestamp();
+
+        // Verify end timestamp wouldn't overflow.
+        let _end_timestamp = bond_start
+            .checked_add(duration)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+
+        let (fee, net_amount) = fees::calculate_fee(&e, &identity, amount);
+        let referral_fee = if referrer.is_some() {
+            referral::fee_share(&e, fee)
+        } else {
+            0
+        };
+        if fee > 0 {
+            let (treasury_opt, _) = fees::get_config(&e);
+            if let Some(treasury) = treasury_opt {
+                let treasury_fee = fee
+                    .checked_sub(referral_fee)
+                    .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+                fees::record_fee(&e, &identity, amount, treasury_fee, &treasury);
+            }
+        }
+
+        let bond = IdentityBond {
+            identity: identity.clone(),
+            bonded_amount: net_amount,
+            bond_start,
+            bond_duration: duration,
+            slashed_amount: 0,
+            active: true,
+            is_rolling,
+            withdrawal_requested_at: 0,
+            notice_period_duration,
+        };
+
+        reward_accrual::checkpoint(&e);
+        e.storage().instance().set(&DataKey::Bond, &bond);
+        proposer_age::record_bond_start(&e, bond_start);
+        verification_snapshot::refresh_bond(&e);
+        rolling_bond::reset_renewal_count(&e);
+        rolling_bond::clear_auto_exit(&e);
+        rolling_bond::clear_period(&e);
+        referral::attribute(&e, &identity, referrer, amount, referral_fee);
+
+        let old_tier = BondTier::Bronze;
+        let new_tier = tiered_bond::get_tier_for_amount(&e, net_amount);
+        tiered_bond::emit_tier_change_if_needed(&e, &identity, old_tier, new_tier);
+        bond
+    }
+
+    /// Create a (non-rolling) bond and its initial attestations from `attester` in one
+    /// transaction, so the bond never exists without credentials. Requires auth from both
+    /// the identity and the attester. Attestation `i` consumes attester nonce `nonce + i`.
+    pub fn create_bond_with_attestations(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        duration: u64,
+        attester: Address,
+        attestations: Vec<String>,
+        nonce: u64,
+    ) -> Result<IdentityBond, BondError> {
+        identity.require_auth();
+        attester.require_auth();
+        if attestations.is_empty() {
+            return Err(BondError::InvalidInput);
+        }
+        let bond = Self::create_bond(e.clone(), identity.clone(), amount, duration, false, 0)?;
+        let mut next_nonce = nonce;
+        for data in attestations.iter() {
+            Self::store_attestation(
+                e.clone(),
+                attester.clone(),
+                identity.clone(),
+                data,
+                next_nonce,
+                None,
+                None,
+            );
+            next_nonce = next_nonce.checked_add(1).ok_or(BondError::Overflow)?;
+        }
+        Ok(bond)
+    }
+
+    /// Create a rolling bond that automatically requests withdrawal at the end of period
+    /// `exit_after_periods` (1-based), so no separate `request_withdrawal` is needed.
+    pub fn create_bond_with_auto_exit(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        duration: u64,
+        notice_period_duration: u64,
+        exit_after_periods: u32,
+    ) -> Result<IdentityBond, BondError> {
+        let bond = Self::create_bond(
+            e.clone(),
+            identity,
+            amount,
+            duration,
+            true,
+            notice_period_duration,
+        )?;
+        rolling_bond::set_auto_exit_period(&e, exit_after_periods);
+        Ok(bond)
+    }
+
+    /// Create a rolling bond with anchored period boundaries (`Fixed(n)` seconds or
+    /// `CalendarMonth`) starting now. Renewals do not drift: each starts the period that
+    /// contains the renewal time, and the bond's duration is that period's length.
+    pub fn create_bond_with_period_mode(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        notice_period_duration: u64,
+        mode: rolling_bond::PeriodMode,
+    ) -> Result<IdentityBond, BondError> {
+        let anchor = e.ledger().timestamp();
+        let probe = rolling_bond::boundary(mode, anchor, 1).saturating_sub(anchor);
+        let mut bond = Self::create_bond(
+            e.clone(),
+            identity,
+            amount,
+            probe,
+            true,
+            notice_period_duration,
+        )?;
+        bond.bond_duration = rolling_bond::set_period(&e, mode, bond.bond_start);
+        e.storage().instance().set(&DataKey::Bond, &bond);
+        Ok(bond)
+    }
+
+    /// Anchored period mode and anchor of the current bond, if any.
+    pub fn get_period_mode(e: Env) -> Option<(rolling_bond::PeriodMode, u64)> {
+        rolling_bond::get_period(&e)
+    }
+
+    pub fn get_auto_exit_period(e: Env) -> Option<u32> {
+        rolling_bond::get_auto_exit_period(&e)
+    }
+
+    pub fn create_bond_with_rolling(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        duration: u64,
+        is_rolling: bool,
+        notice_period_duration: u64,
+    ) -> Result<IdentityBond, BondError> {
+        Self::create_bond(
+            e,
+            identity,
+            amount,
+            duration,
+            is_rolling,
+            notice_period_duration,
+        )
+    }
+
+    pub fn get_identity_state(e: Env) -> IdentityBond {
+        e.storage()
+            .instance()
+            .get::<_, IdentityBond>(&DataKey::Bond)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::NoBond))
+    }
 
     /// Add an attestation for a subject (only authorized attesters can call).
     /// Requires correct nonce for replay prevention; rejects duplicate (verifier, identity, data).
@@ -243,51 +1198,294 @@ impl CredenceBond {
         subject: Address,
         attestation_data: String,
         nonce: u64,
-    ) -> Attestation {
+    ) -> Result<Attestation, BondError> {
         attester.require_auth();
+        Ok(Self::store_attestation(
+            e,
+            attester,
+            subject,
+            attestation_data,
+            nonce,
+            None,
+            None,
+        ))
+    }
 
-        let is_authorized: bool = e
-            .storage()
-            .instance()
-            .get(&DataKey::Attester(attester.clone()))
-            .unwrap_or(false);
-        if !is_authorized {
-            panic!("unauthorized attester");
+    /// Add an attestation that follows a registered claim template. The template's weight cap
+    /// bounds the stake-derived weight and its expiry policy sets `expires_at`.
+    pub fn add_attestation_with_template(
+        e: Env,
+        attester: Address,
+        subject: Address,
+        template_id: u32,
+        attestation_data: String,
+        nonce: u64,
+    ) -> Result<Attestation, BondError> {
+        attester.require_auth();
+        let template = attestation_template::get_template(&e, template_id)
+            .ok_or(BondError::TemplateNotFound)?;
+        if !template.active {
+            return Err(BondError::TemplateInactive);
+        }
+        Ok(Self::store_attestation(
+            e,
+            attester,
+            subject,
+            attestation_data,
+            nonce,
+            Some(template),
+            None,
+        ))
+    }
+
+    /// Add an attestation that stores only a 32-byte commitment `data_hash` to its content,
+    /// under the claim type `schema_id`, instead of the content itself. Duplicates are
+    /// detected on the hash.
+    pub fn add_attestation_hash(
+        e: Env,
+        attester: Address,
+        subject: Address,
+        data_hash: BytesN<32>,
+        schema_id: String,
+        nonce: u64,
+    ) -> Result<Attestation, BondError> {
+        attester.require_auth();
+        attestation_hash::validate_schema_id(&e, &schema_id);
+        Ok(Self::store_attestation(
+            e,
+            attester,
+            subject,
+            schema_id,
+            nonce,
+            None,
+            Some(data_hash),
+        ))
+    }
+
+    /// Register (or rotate) the ed25519 public key `attester` signs relayed attestations
+    /// with. Requires the attester's auth.
+    pub fn set_attester_signing_key(
+        e: Env,
+        attester: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), BondError> {
+        attester.require_auth();
+        nonce::set_signing_key(&e, &attester, &public_key);
+        e.events().publish(
+            (Symbol::new(&e, "attester_signing_key_set"), attester),
+            public_key,
+        );
+        Ok(())
+    }
+
+    pub fn get_attester_signing_key(e: Env, attester: Address) -> Option<BytesN<32>> {
+        nonce::get_signing_key(&e, &attester)
+    }
+
+    /// Message `attester` must sign to authorize `attestation_data` for `subject`: the XDR
+    /// encoding of `(contract, attester, subject, data_hash, nonce, expiry)`, where
+    /// `data_hash` is the SHA-256 of the XDR-encoded `attestation_data`.
+    pub fn get_attestation_signing_payload(
+        e: Env,
+        attester: Address,
+        subject: Address,
+        attestation_data: String,
+        nonce: u64,
+        expiry: u64,
+    ) -> Bytes {
+        let data_hash = nonce::data_hash(&e, &attestation_data);
+        nonce::signed_payload(&e, &attester, &subject, &data_hash, nonce, expiry)
+    }
+
+    /// Submit an attestation authorized off-chain by `attester`'s ed25519 `signature` over
+    /// `get_attestation_signing_payload`. Callable by anyone (e.g. a relayer paying fees);
+    /// consumes the attester's nonce. Fails with `InvalidNonce` after `expiry`.
+    pub fn add_attestation_signed(
+        e: Env,
+        attester: Address,
+        subject: Address,
+        attestation_data: String,
+        nonce: u64,
+        expiry: u64,
+        signature: BytesN<64>,
+    ) -> Result<Attestation, BondError> {
+        let data_hash = nonce::data_hash(&e, &attestation_data);
+        nonce::verify_signed(
+            &e, &attester, &subject, &data_hash, nonce, expiry, &signature,
+        );
+        let attestation = Self::store_attestation(
+            e.clone(),
+            attester.clone(),
+            subject,
+            attestation_data,
+            nonce,
+            None,
+            None,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "attestation_relayed"), attester),
+            attestation.id,
+        );
+        Ok(attestation)
+    }
+
+    /// Content hash committed by a hashed attestation (`None` for string attestations).
+    pub fn get_attestation_hash(e: Env, attestation_id: u64) -> Option<BytesN<32>> {
+        attestation_hash::get(&e, attestation_id)
+    }
+
+    /// True if `content` hashes (SHA-256) to the commitment of hashed attestation
+    /// `attestation_id`.
+    pub fn verify_attestation_content(e: Env, attestation_id: u64, content: Bytes) -> bool {
+        attestation_hash::verify(&e, attestation_id, &content)
+    }
+
+    /// Set the delegation contract consulted by `add_attestation_delegated`. Admin only.
+    pub fn set_delegation_contract(
+        e: Env,
+        admin: Address,
+        contract: Address,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        delegated_attestation::set_delegation_contract(&e, &contract);
+        Ok(())
+    }
+
+    /// Delegation contract in use: the configured one, else the address book's `delegation`
+    /// role.
+    pub fn get_delegation_contract(e: Env) -> Option<Address> {
+        delegated_attestation::delegation_contract(&e)
+    }
+
+    /// Issue an attestation on behalf of `attester` as its `delegate`. The delegation
+    /// contract (see `get_delegation_contract`) must report a valid attestation delegation from
+    /// `attester` to `delegate`. The attestation is the attester's and consumes its nonce.
+    pub fn add_attestation_delegated(
+        e: Env,
+        delegate: Address,
+        attester: Address,
+        subject: Address,
+        attestation_data: String,
+        nonce: u64,
+    ) -> Result<Attestation, BondError> {
+        delegate.require_auth();
+        delegated_attestation::require_valid_delegate(&e, &attester, &delegate);
+        let attestation = Self::store_attestation(
+            e.clone(),
+            attester.clone(),
+            subject,
+            attestation_data,
+            nonce,
+            None,
+            None,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "attestation_delegated"), attester),
+            (attestation.id, delegate),
+        );
+        Ok(attestation)
+    }
+
+    /// Validate and store an attestation signed by `attester`. Caller must enforce auth.
+    fn store_attestation(
+        e: Env,
+        attester: Address,
+        subject: Address,
+        attestation_data: String,
+        nonce: u64,
+        template: Option<attestation_template::AttestationTemplate>,
+        data_hash: Option<BytesN<32>>,
+    ) -> Attestation {
+        pausable::require_not_paused(&e);
+        // Sub-attesters issue on behalf of their parent organization.
+        let (verifier, sub_weight_cap) = attester_org::resolve_issuer(&e, &attester);
+        if attester_suspension::is_suspended(&e, &verifier) {
+            panic_with_error!(e, BondError::AttesterSuspended);
         }
+        attester_deregistration::require_can_issue(&e, &verifier);
 
         nonce::consume_nonce(&e, &attester, nonce);
 
         let dedup_key = types::AttestationDedupKey {
-            verifier: attester.clone(),
+            verifier: verifier.clone(),
             identity: subject.clone(),
             attestation_data: attestation_data.clone(),
         };
-        if e.storage().instance().has(&dedup_key) {
-            panic!("duplicate attestation");
+        let hash_dedup_key = data_hash
+            .as_ref()
+            .map(|hash| attestation_hash::dedup_key(&verifier, &subject, hash));
+        let previous_id: Option<u64> = match &hash_dedup_key {
+            Some(key) => e.storage().instance().get(key),
+            None => e.storage().instance().get(&dedup_key),
+        };
+        if let Some(previous_id) = previous_id {
+            let policy = dedup_policy::effective(&e, &attestation_data);
+            let previous: Option<Attestation> = e
+                .storage()
+                .instance()
+                .get(&DataKey::Attestation(previous_id));
+            let allowed = match previous {
+                Some(previous) => {
+                    dedup_policy::allows_reissue(policy, &previous, e.ledger().timestamp())
+                }
+                None => {
+                    let record = attestation_archive::get_record(&e, previous_id)
+                        .unwrap_or_else(|| panic_with_error!(e, BondError::AttestationNotFound));
+                    attestation_archive::allows_reissue(policy, &record)
+                }
+            };
+            if !allowed {
+                panic_with_error!(e, BondError::DuplicateAttestation);
+            }
         }
+        attestation_limits::require_within(&e, &subject, &verifier);
 
         let counter_key = DataKey::AttestationCounter;
         let id: u64 = e.storage().instance().get(&counter_key).unwrap_or(0);
-        let next_id = id.checked_add(1).expect("attestation counter overflow");
+        let next_id = id
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
         e.storage().instance().set(&counter_key, &next_id);
 
-        let weight = weighted_attestation::compute_weight(&e, &attester);
+        let (mut weight, weight_source) =
+            weighted_attestation::compute_weight_with_source(&e, &verifier);
+        if let Some(cap) = sub_weight_cap {
+            weight = core::cmp::min(weight, cap);
+        }
+        let now = e.ledger().timestamp();
+        let mut expires_at = 0_u64;
+        let mut template_id = None;
+        if let Some(t) = template {
+            weight = attestation_template::apply_weight_cap(&t, weight);
+            expires_at = attestation_template::expiry_for(&t, now);
+            template_id = Some(t.id);
+        }
         types::Attestation::validate_weight(weight);
 
         let attestation = Attestation {
             id,
-            verifier: attester.clone(),
+            verifier: verifier.clone(),
             identity: subject.clone(),
-            timestamp: e.ledger().timestamp(),
+            timestamp: now,
             weight,
             attestation_data: attestation_data.clone(),
             revoked: false,
+            template_id,
+            expires_at,
+            active_from: challenge_period::active_from(&e, now),
+            revocation_reason: RevocationReason::NotRevoked,
         };
 
         e.storage()
             .instance()
             .set(&DataKey::Attestation(id), &attestation);
-        e.storage().instance().set(&dedup_key, &id);
+        match &hash_dedup_key {
+            Some(key) => e.storage().instance().set(key, &id),
+            None => e.storage().instance().set(&dedup_key, &id),
+        }
+        if let Some(hash) = &data_hash {
+            attestation_hash::record(&e, id, hash);
+        }
 
         let subject_key = DataKey::SubjectAttestations(subject.clone());
         let mut attestations: Vec<u64> = e
@@ -304,333 +1502,2124 @@ impl CredenceBond {
             .instance()
             .set(&count_key, &count.saturating_add(1));
 
-        e.events().publish(
-            (Symbol::new(&e, "attestation_added"), subject),
-            (id, attester, attestation_data, weight),
+        attester_org::record_issued(&e, &verifier);
+        sybil_heuristics::record(&e, &verifier, &subject);
+        verification_snapshot::on_attestation_added(&e, &subject, weight);
+        attestation_summary::on_added(&e, &attestation);
+        challenge_period::track(&e, &subject, id, weight, attestation.active_from);
+        reputation::refresh(&e, &subject);
+        activity_feed::record(
+            &e,
+            &subject,
+            activity_feed::ActivityKind::Added,
+            id,
+            &verifier,
+        );
+        if sub_weight_cap.is_some() {
+            e.events().publish(
+                (Symbol::new(&e, "sub_attestation_issued"), verifier.clone()),
+                (id, attester),
+            );
+        }
+
+        if let Some(hash) = data_hash {
+            e.events().publish(
+                (Symbol::new(&e, "attestation_committed"), subject.clone()),
+                (id, hash),
+            );
+        }
+        e.events().publish(
+            (Symbol::new(&e, "attestation_added"), subject),
+            (id, verifier, attestation_data, weight, weight_source),
+        );
+
+        attestation
+    }
+
+    /// Revoke an attestation (only original attester) for `reason`. Requires correct nonce.
+    pub fn revoke_attestation(
+        e: Env,
+        attester: Address,
+        attestation_id: u64,
+        reason: RevocationReason,
+        nonce: u64,
+    ) -> Result<(), BondError> {
+        pausable::require_not_paused(&e);
+        attester.require_auth();
+        revocation_registry::require_valid(&e, reason);
+        nonce::consume_nonce(&e, &attester, nonce);
+
+        let key = DataKey::Attestation(attestation_id);
+        let mut attestation: Attestation = e
+            .storage()
+            .instance()
+            .get(&key)
+            .ok_or(BondError::AttestationNotFound)?;
+
+        if attestation.verifier != attester
+            && !attester_org::is_active_sub_of(&e, &attester, &attestation.verifier)
+        {
+            return Err(BondError::NotAttestationOwner);
+        }
+        if attestation.revoked {
+            return Err(BondError::AttestationRevoked);
+        }
+
+        Self::mark_revoked(&e, &mut attestation, &attester, reason);
+        e.events().publish(
+            (
+                Symbol::new(&e, "attestation_revoked"),
+                attestation.identity.clone(),
+            ),
+            (attestation_id, attester, reason),
+        );
+        Ok(())
+    }
+
+    /// Hand an attestation off to `successor`: it is revoked as `Superseded` and reissued
+    /// by `successor` for the same subject, claim and template. Lets a departing attester
+    /// move its subjects to another attester. Requires both attesters' auth and nonces.
+    pub fn hand_off_attestation(
+        e: Env,
+        attester: Address,
+        attestation_id: u64,
+        successor: Address,
+        attester_nonce: u64,
+        successor_nonce: u64,
+    ) -> Result<Attestation, BondError> {
+        attester.require_auth();
+        successor.require_auth();
+        if successor == attester {
+            return Err(BondError::InvalidInput);
+        }
+        nonce::consume_nonce(&e, &attester, attester_nonce);
+        let mut attestation: Attestation = e
+            .storage()
+            .instance()
+            .get(&DataKey::Attestation(attestation_id))
+            .ok_or(BondError::AttestationNotFound)?;
+        if attestation.verifier != attester {
+            return Err(BondError::NotAttestationOwner);
+        }
+        if attestation.revoked {
+            return Err(BondError::AttestationRevoked);
+        }
+        Self::mark_revoked(
+            &e,
+            &mut attestation,
+            &attester,
+            RevocationReason::Superseded,
+        );
+        let template = attestation
+            .template_id
+            .and_then(|id| attestation_template::get_template(&e, id));
+        let reissued = Self::store_attestation(
+            e.clone(),
+            successor.clone(),
+            attestation.identity.clone(),
+            attestation.attestation_data.clone(),
+            successor_nonce,
+            template,
+            attestation_hash::get(&e, attestation_id),
+        );
+        e.events().publish(
+            (Symbol::new(&e, "attestation_handed_off"), attestation_id),
+            (reissued.id, attester, successor),
+        );
+        Ok(reissued)
+    }
+
+    /// Reject an attestation still in its challenge period. Cheaper than a dispute: the
+    /// attestation never acquires weight. Callable by the admin, a governor or the subject.
+    pub fn challenge_attestation(
+        e: Env,
+        caller: Address,
+        attestation_id: u64,
+    ) -> Result<(), BondError> {
+        pausable::require_not_paused(&e);
+        caller.require_auth();
+        let mut attestation: Attestation = e
+            .storage()
+            .instance()
+            .get(&DataKey::Attestation(attestation_id))
+            .ok_or(BondError::AttestationNotFound)?;
+        if caller != attestation.identity {
+            attester_suspension::require_admin_or_governor(&e, &caller);
+        }
+        if !attestation.is_pending_at(e.ledger().timestamp()) {
+            return Err(BondError::InvalidAttestationState);
+        }
+
+        Self::mark_revoked(
+            &e,
+            &mut attestation,
+            &caller,
+            RevocationReason::IssuedInError,
+        );
+        e.events().publish(
+            (
+                Symbol::new(&e, "attestation_challenged"),
+                attestation.identity.clone(),
+            ),
+            (attestation_id, caller),
+        );
+        Ok(())
+    }
+
+    /// Mark an attestation revoked for `reason` and update the subject's count, snapshot,
+    /// summary, pending weight, activity feed and the revocation registry.
+    fn mark_revoked(
+        e: &Env,
+        attestation: &mut Attestation,
+        actor: &Address,
+        reason: RevocationReason,
+    ) {
+        attestation.revoked = true;
+        attestation.revocation_reason = reason;
+        e.storage()
+            .instance()
+            .set(&DataKey::Attestation(attestation.id), &*attestation);
+        attestation_archive::record_revoked(e, attestation.id);
+        revocation_registry::record(e, attestation, reason);
+
+        let count_key = DataKey::SubjectAttestationCount(attestation.identity.clone());
+        let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
+        e.storage()
+            .instance()
+            .set(&count_key, &count.saturating_sub(1));
+        verification_snapshot::on_attestation_revoked(e, &attestation.identity, attestation.weight);
+        attestation_summary::on_deactivated(e, attestation, true);
+        challenge_period::untrack(e, &attestation.identity, attestation.id);
+        reputation::refresh(e, &attestation.identity);
+        activity_feed::record(
+            e,
+            &attestation.identity,
+            activity_feed::ActivityKind::Revoked,
+            attestation.id,
+            actor,
+        );
+    }
+
+    /// Register `address` for a well-known `role` (see `address_book::ROLES`), or clear it
+    /// with `None`. Admin only.
+    pub fn set_role_address(
+        e: Env,
+        admin: Address,
+        role: Symbol,
+        address: Option<Address>,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        admin_actions::record(
+            &e,
+            &admin,
+            admin_actions::AdminActionKind::SetRoleAddress,
+            address.clone(),
+            0,
+        );
+        address_book::set(&e, &role, address);
+        Ok(())
+    }
+
+    /// Contract address registered for `role`, if any.
+    pub fn resolve(e: Env, role: Symbol) -> Option<Address> {
+        address_book::resolve(&e, &role)
+    }
+
+    /// Every registered role and its address.
+    pub fn get_address_book(e: Env) -> Map<Symbol, Address> {
+        address_book::entries(&e)
+    }
+
+    /// Set the challenge window (seconds) new attestations spend pending before carrying
+    /// weight. 0 disables it. Admin only.
+    pub fn set_challenge_window(e: Env, admin: Address, seconds: u64) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        challenge_period::set_window(&e, seconds);
+        Ok(())
+    }
+
+    pub fn get_challenge_window(e: Env) -> u64 {
+        challenge_period::get_window(&e)
+    }
+
+    /// Weight of a subject's attestations still in their challenge period. Gated: requires
+    /// the auth of an authorized query consumer and is charged to it as one query.
+    pub fn get_subject_pending_weight(
+        e: Env,
+        consumer: Address,
+        subject: Address,
+    ) -> Result<u64, BondError> {
+        consumer.require_auth();
+        query_access::consume_credit(&e, &consumer);
+        Ok(challenge_period::pending_weight(
+            &e,
+            &subject,
+            e.ledger().timestamp(),
+        ))
+    }
+
+    /// Set the global duplication policy for re-issuing an existing claim. Admin only.
+    pub fn set_dedup_policy(
+        e: Env,
+        admin: Address,
+        policy: dedup_policy::DedupPolicy,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        dedup_policy::set_global(&e, policy);
+        Ok(())
+    }
+
+    pub fn get_dedup_policy(e: Env) -> dedup_policy::DedupPolicy {
+        dedup_policy::get_global(&e)
+    }
+
+    /// Override the duplication policy for one claim type (attestation data), or clear the
+    /// override with `None`. Admin only.
+    pub fn set_claim_dedup_policy(
+        e: Env,
+        admin: Address,
+        claim: String,
+        policy: Option<dedup_policy::DedupPolicy>,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        dedup_policy::set_for_claim(&e, &claim, policy);
+        Ok(())
+    }
+
+    /// Duplication policy override of a claim type, if any.
+    pub fn get_claim_dedup_policy(e: Env, claim: String) -> Option<dedup_policy::DedupPolicy> {
+        dedup_policy::get_for_claim(&e, &claim)
+    }
+
+    /// Register an operator key that attests on behalf of `parent` (a registered attester).
+    /// Its attestations use the parent's weight, capped at `weight_cap`.
+    pub fn register_sub_attester(
+        e: Env,
+        parent: Address,
+        sub: Address,
+        weight_cap: u32,
+    ) -> Result<(), BondError> {
+        parent.require_auth();
+        if !Self::is_attester(e.clone(), parent.clone()) {
+            return Err(BondError::UnauthorizedAttester);
+        }
+        attester_org::register_sub(&e, &parent, &sub, weight_cap);
+        Ok(())
+    }
+
+    /// Suspend or reinstate a sub-attester. Parent only.
+    pub fn set_sub_attester_suspended(
+        e: Env,
+        parent: Address,
+        sub: Address,
+        suspended: bool,
+    ) -> Result<(), BondError> {
+        parent.require_auth();
+        attester_org::set_suspended(&e, &parent, &sub, suspended);
+        Ok(())
+    }
+
+    pub fn remove_sub_attester(e: Env, parent: Address, sub: Address) -> Result<(), BondError> {
+        parent.require_auth();
+        attester_org::remove_sub(&e, &parent, &sub);
+        Ok(())
+    }
+
+    pub fn get_sub_attester(e: Env, sub: Address) -> Option<attester_org::SubAttester> {
+        attester_org::get_sub(&e, &sub)
+    }
+
+    pub fn get_sub_attesters(e: Env, parent: Address) -> Vec<Address> {
+        attester_org::get_subs(&e, &parent)
+    }
+
+    /// Number of attestations attributed to an attester, including those issued by its subs.
+    pub fn get_attester_issued_count(e: Env, attester: Address) -> u32 {
+        attester_org::get_issued_count(&e, &attester)
+    }
+
+    /// Attestations issued by `attester` to `subject`, including revoked ones.
+    pub fn get_attestation_edge_count(e: Env, attester: Address, subject: Address) -> u32 {
+        sybil_heuristics::get_edge_count(&e, &attester, &subject)
+    }
+
+    /// Number of subjects attested by both attesters (ring detection support).
+    pub fn get_shared_subject_count(e: Env, attester_a: Address, attester_b: Address) -> u32 {
+        sybil_heuristics::shared_subject_count(&e, &attester_a, &attester_b)
+    }
+
+    /// Distinct subjects attested by `attester`, in first-attestation order.
+    pub fn get_attester_subjects(e: Env, attester: Address) -> Vec<Address> {
+        sybil_heuristics::get_subjects(&e, &attester)
+    }
+
+    /// How concentrated `attester`'s attestations are on a single subject.
+    pub fn get_attester_concentration(
+        e: Env,
+        attester: Address,
+    ) -> sybil_heuristics::AttesterConcentration {
+        sybil_heuristics::concentration(&e, &attester)
+    }
+
+    /// Register a claim template. Returns the new template id. Admin only.
+    pub fn create_template(
+        e: Env,
+        admin: Address,
+        schema_hash: BytesN<32>,
+        weight_cap: u32,
+        expiry_seconds: u64,
+    ) -> Result<u32, BondError> {
+        Self::require_admin(&e, &admin)?;
+        Ok(attestation_template::create_template(
+            &e,
+            schema_hash,
+            weight_cap,
+            expiry_seconds,
+        ))
+    }
+
+    /// Enable or disable a claim template. Existing attestations are unaffected. Admin only.
+    pub fn set_template_active(
+        e: Env,
+        admin: Address,
+        template_id: u32,
+        active: bool,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        attestation_template::set_active(&e, template_id, active);
+        Ok(())
+    }
+
+    pub fn get_template(e: Env, template_id: u32) -> attestation_template::AttestationTemplate {
+        attestation_template::get_template(&e, template_id)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::TemplateNotFound))
+    }
+
+    pub fn list_templates(e: Env) -> Vec<attestation_template::AttestationTemplate> {
+        attestation_template::list_templates(&e)
+    }
+
+    /// Full attestation read. `reader` must be the subject, the verifier, or hold an
+    /// unexpired read grant from the subject.
+    pub fn get_attestation(e: Env, reader: Address, attestation_id: u64) -> Attestation {
+        let attestation: Attestation = e
+            .storage()
+            .instance()
+            .get(&DataKey::Attestation(attestation_id))
+            .unwrap_or_else(|| panic_with_error!(e, BondError::AttestationNotFound));
+        reader.require_auth();
+        read_grant::require_can_read(&e, &reader, &attestation);
+        attestation
+    }
+
+    /// Contest another attester's active attestation with a counter-attestation. The
+    /// counter's weight comes from the caller's stake. Requires the caller's nonce.
+    pub fn counter_attest(
+        e: Env,
+        attester: Address,
+        attestation_id: u64,
+        reason: String,
+        nonce: u64,
+    ) -> Result<u64, BondError> {
+        pausable::require_not_paused(&e);
+        attester.require_auth();
+        if attester_suspension::is_suspended(&e, &attester) {
+            return Err(BondError::AttesterSuspended);
+        }
+        nonce::consume_nonce(&e, &attester, nonce);
+        let original: Attestation = e
+            .storage()
+            .instance()
+            .get(&DataKey::Attestation(attestation_id))
+            .ok_or(BondError::AttestationNotFound)?;
+        Ok(counter_attestation::issue(&e, &attester, &original, reason))
+    }
+
+    pub fn get_counter_attestation(
+        e: Env,
+        counter_id: u64,
+    ) -> Option<counter_attestation::CounterAttestation> {
+        counter_attestation::get_counter(&e, counter_id)
+    }
+
+    /// An attestation with its counter-attestations and netted weight. Same read rules as
+    /// `get_attestation`.
+    pub fn get_contested_attestation(
+        e: Env,
+        reader: Address,
+        attestation_id: u64,
+    ) -> counter_attestation::ContestedAttestation {
+        let attestation = Self::get_attestation(e.clone(), reader, attestation_id);
+        counter_attestation::ContestedAttestation {
+            counters: counter_attestation::counters_of(&e, attestation_id),
+            net_weight: counter_attestation::net_weight(&e, &attestation),
+            attestation,
+        }
+    }
+
+    /// Resolve a counter-attestation. `Upheld` revokes the contested attestation;
+    /// `Dismissed` removes the counter's effect. Admin only.
+    pub fn resolve_counter_attestation(
+        e: Env,
+        admin: Address,
+        counter_id: u64,
+        status: counter_attestation::CounterStatus,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        let counter = counter_attestation::resolve(&e, counter_id, status);
+        if status == counter_attestation::CounterStatus::Upheld {
+            let mut original: Attestation = e
+                .storage()
+                .instance()
+                .get(&DataKey::Attestation(counter.original_id))
+                .ok_or(BondError::AttestationNotFound)?;
+            if !original.revoked {
+                Self::mark_revoked(&e, &mut original, &admin, RevocationReason::IssuedInError);
+            }
+        }
+        Ok(())
+    }
+
+    /// Object, as its subject, to attestation `attestation_id` with the hash of an off-chain
+    /// statement. Flags the attestation and, when a dispute contract and objection dispute
+    /// terms are configured, opens a dispute over it staked by the subject.
+    pub fn object_to_attestation(
+        e: Env,
+        subject: Address,
+        attestation_id: u64,
+        reason_hash: BytesN<32>,
+    ) -> Result<attestation_objection::Objection, BondError> {
+        pausable::require_not_paused(&e);
+        subject.require_auth();
+        Ok(attestation_objection::object(
+            &e,
+            &subject,
+            attestation_id,
+            &reason_hash,
+        ))
+    }
+
+    /// Objection raised against an attestation, if any.
+    pub fn get_objection(e: Env, attestation_id: u64) -> Option<attestation_objection::Objection> {
+        attestation_objection::get(&e, attestation_id)
+    }
+
+    /// Set the stake and resolution window of disputes opened by objections (stake 0 opens
+    /// none). Admin only.
+    pub fn set_objection_dispute_terms(
+        e: Env,
+        admin: Address,
+        terms: attestation_objection::ObjectionTerms,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        attestation_objection::set_terms(&e, &terms);
+        Ok(())
+    }
+
+    pub fn get_objection_dispute_terms(e: Env) -> attestation_objection::ObjectionTerms {
+        attestation_objection::get_terms(&e)
+    }
+
+    /// Escalate an open counter-attestation to the dispute contract. The counter's attester
+    /// opens the dispute and stakes `stake` of `token`. Returns the dispute id.
+    pub fn escalate_conflict(
+        e: Env,
+        attester: Address,
+        counter_id: u64,
+        stake: i128,
+        token: Address,
+        resolution_deadline: u64,
+    ) -> Result<u64, BondError> {
+        attester.require_auth();
+        Ok(counter_attestation::escalate(
+            &e,
+            &attester,
+            counter_id,
+            stake,
+            &token,
+            resolution_deadline,
+        ))
+    }
+
+    /// Set how open counters net the contested attestation's weight. Admin only.
+    pub fn set_conflict_netting(
+        e: Env,
+        admin: Address,
+        policy: counter_attestation::ConflictNetting,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        counter_attestation::set_netting(&e, policy);
+        Ok(())
+    }
+
+    pub fn get_conflict_netting(e: Env) -> counter_attestation::ConflictNetting {
+        counter_attestation::get_netting(&e)
+    }
+
+    /// Set the dispute contract that receives escalated conflicts. Admin only.
+    pub fn set_conflict_dispute_contract(
+        e: Env,
+        admin: Address,
+        contract: Address,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        counter_attestation::set_dispute_contract(&e, &contract);
+        Ok(())
+    }
+
+    /// Move up to `limit` of `subject`'s attestations revoked or expired for at least the
+    /// archive minimum age into compact archival records. Returns the number archived.
+    /// Admin only.
+    pub fn compact_attestations(
+        e: Env,
+        admin: Address,
+        subject: Address,
+        limit: u32,
+    ) -> Result<u32, BondError> {
+        Self::require_admin(&e, &admin)?;
+        Ok(attestation_archive::compact(&e, &subject, limit))
+    }
+
+    /// Set the caps on attestations per subject and active attestations per verifier per
+    /// subject (0 = unbounded). Admin only.
+    pub fn set_attestation_limits(
+        e: Env,
+        admin: Address,
+        limits: attestation_limits::AttestationLimits,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        attestation_limits::set(&e, &limits);
+        Ok(())
+    }
+
+    pub fn get_attestation_limits(e: Env) -> attestation_limits::AttestationLimits {
+        attestation_limits::get(&e)
+    }
+
+    /// Remove up to `limit` revoked ids from `subject`'s attestation index, freeing room
+    /// under `max_per_subject`. Returns the number removed. Admin only.
+    pub fn prune_revoked_attestations(
+        e: Env,
+        admin: Address,
+        subject: Address,
+        limit: u32,
+    ) -> Result<u32, BondError> {
+        Self::require_admin(&e, &admin)?;
+        Ok(attestation_limits::prune_revoked(&e, &subject, limit))
+    }
+
+    /// Set how long (seconds) an attestation must have been revoked or expired before it
+    /// can be archived. Admin only.
+    pub fn set_archive_min_age(e: Env, admin: Address, seconds: u64) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        attestation_archive::set_min_age(&e, seconds);
+        Ok(())
+    }
+
+    pub fn get_archive_min_age(e: Env) -> u64 {
+        attestation_archive::get_min_age(&e)
+    }
+
+    pub fn get_archived_attestation(
+        e: Env,
+        attestation_id: u64,
+    ) -> Option<attestation_archive::ArchivedAttestation> {
+        attestation_archive::get_record(&e, attestation_id)
+    }
+
+    /// True if `attestation` matches the content hash archived under its id, proving it
+    /// existed with exactly these contents.
+    pub fn verify_archived_attestation(e: Env, attestation: Attestation) -> bool {
+        attestation_archive::verify(&e, &attestation)
+    }
+
+    /// Revocations of `attester`'s attestations for `reason`, oldest first, for audits of
+    /// verifier behavior. Pages of at most `revocation_registry::MAX_PAGE_SIZE`.
+    pub fn get_revocations_by_reason(
+        e: Env,
+        attester: Address,
+        reason: RevocationReason,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<revocation_registry::Revocation> {
+        revocation_registry::list(&e, &attester, reason, offset, limit)
+    }
+
+    /// Last attestation actions (added, revoked, challenged, expired) for `subject`, oldest
+    /// first, bounded to `activity_feed::ACTIVITY_BUFFER_SIZE` entries. Gated: requires the
+    /// auth of an authorized query consumer and is charged to it as one query.
+    pub fn get_recent_activity(
+        e: Env,
+        consumer: Address,
+        subject: Address,
+    ) -> Result<Vec<activity_feed::ActivityEntry>, BondError> {
+        consumer.require_auth();
+        query_access::consume_credit(&e, &consumer);
+        Ok(activity_feed::get_recent(&e, &subject))
+    }
+
+    /// Record the lapse of an expired attestation in its subject's activity feed. Callable
+    /// by anyone once, after `expires_at`; the actor recorded is the verifier.
+    pub fn record_attestation_expiry(e: Env, attestation_id: u64) -> Result<(), BondError> {
+        let attestation: Attestation = e
+            .storage()
+            .instance()
+            .get(&DataKey::Attestation(attestation_id))
+            .ok_or(BondError::AttestationNotFound)?;
+        if attestation.expires_at == 0 || e.ledger().timestamp() < attestation.expires_at {
+            return Err(BondError::InvalidAttestationState);
+        }
+        let key = DataKey::ExpiryRecorded(attestation_id);
+        if e.storage().instance().has(&key) {
+            return Err(BondError::InvalidAttestationState);
+        }
+        e.storage().instance().set(&key, &true);
+        activity_feed::record(
+            &e,
+            &attestation.identity,
+            activity_feed::ActivityKind::Expired,
+            attestation_id,
+            &attestation.verifier,
+        );
+        Ok(())
+    }
+
+    /// Subject grants `reader` full-read access to its attestations until `expires_at`.
+    pub fn grant_read(
+        e: Env,
+        subject: Address,
+        reader: Address,
+        expires_at: u64,
+    ) -> Result<(), BondError> {
+        subject.require_auth();
+        read_grant::grant(&e, &subject, &reader, expires_at);
+        Ok(())
+    }
+
+    pub fn revoke_read(e: Env, subject: Address, reader: Address) -> Result<(), BondError> {
+        subject.require_auth();
+        read_grant::revoke(&e, &subject, &reader);
+        Ok(())
+    }
+
+    /// Expiry of a read grant, if one was issued (may already be expired).
+    pub fn get_read_grant(e: Env, subject: Address, reader: Address) -> Option<u64> {
+        read_grant::get_grant(&e, &subject, &reader)
+    }
+
+    /// Ids of the attestations recorded for `subject`. Gated: requires the auth of an
+    /// authorized query consumer and is charged to it as one query.
+    pub fn get_subject_attestations(
+        e: Env,
+        consumer: Address,
+        subject: Address,
+    ) -> Result<Vec<u64>, BondError> {
+        consumer.require_auth();
+        query_access::consume_credit(&e, &consumer);
+        Ok(e.storage()
+            .instance()
+            .get(&DataKey::SubjectAttestations(subject))
+            .unwrap_or(Vec::new(&e)))
+    }
+
+    /// Number of attestations recorded for `subject`. Gated: requires the auth of an
+    /// authorized query consumer and is charged to it as one query.
+    pub fn get_subject_attestation_count(
+        e: Env,
+        consumer: Address,
+        subject: Address,
+    ) -> Result<u32, BondError> {
+        consumer.require_auth();
+        query_access::consume_credit(&e, &consumer);
+        Ok(e.storage()
+            .instance()
+            .get(&DataKey::SubjectAttestationCount(subject))
+            .unwrap_or(0))
+    }
+
+    /// Aggregate of `subject`'s attestations: active count and weight, distinct verifiers,
+    /// latest attestation time and revoked count. Gated: requires the auth of an authorized
+    /// query consumer and is charged to it as one query.
+    pub fn get_attestation_summary(
+        e: Env,
+        consumer: Address,
+        subject: Address,
+    ) -> Result<attestation_summary::AttestationSummary, BondError> {
+        consumer.require_auth();
+        query_access::consume_credit(&e, &consumer);
+        Ok(attestation_summary::get(&e, &subject))
+    }
+
+    pub fn get_nonce(e: Env, identity: Address) -> u64 {
+        nonce::get_nonce(&e, &identity)
+    }
+
+    pub fn set_attester_stake(
+        e: Env,
+        admin: Address,
+        attester: Address,
+        amount: i128,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        weighted_attestation::set_attester_stake(&e, &attester, amount);
+        Ok(())
+    }
+
+    /// Remove `attester`'s stake override so its weight follows its own bond again. Admin only.
+    pub fn clear_attester_stake(
+        e: Env,
+        admin: Address,
+        attester: Address,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        weighted_attestation::clear_attester_stake(&e, &attester);
+        Ok(())
+    }
+
+    /// Stake `attester`'s attestation weight is computed from, and whether it comes from its
+    /// bond or an admin override.
+    pub fn get_attester_stake(e: Env, attester: Address) -> (i128, WeightSource) {
+        weighted_attestation::effective_stake(&e, &attester)
+    }
+
+    pub fn set_weight_config(
+        e: Env,
+        admin: Address,
+        multiplier_bps: u32,
+        max_weight: u32,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        weighted_attestation::set_weight_config(&e, multiplier_bps, max_weight);
+        Ok(())
+    }
+
+    pub fn get_weight_config(e: Env) -> (u32, u32) {
+        weighted_attestation::get_weight_config(&e)
+    }
+
+    /// Set the max share (bps) of a subject's aggregate weight one verifier may contribute.
+    pub fn set_verifier_weight_cap(e: Env, admin: Address, cap_bps: u32) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        weighted_attestation::set_verifier_cap_bps(&e, cap_bps);
+        Ok(())
+    }
+
+    pub fn get_verifier_weight_cap(e: Env) -> u32 {
+        weighted_attestation::get_verifier_cap_bps(&e)
+    }
+
+    /// Sum of active attestation weights for a subject, each verifier capped at the
+    /// configured share of the subject's raw total. Pending attestations are excluded.
+    /// Gated: requires the auth of an authorized query consumer and is charged to it as one
+    /// query.
+    pub fn get_subject_aggregate_weight(
+        e: Env,
+        consumer: Address,
+        subject: Address,
+    ) -> Result<u64, BondError> {
+        consumer.require_auth();
+        query_access::consume_credit(&e, &consumer);
+        Ok(weighted_attestation::aggregate_weight(&e, &subject))
+    }
+
+    /// Early withdrawal path (only valid before lock-up end).
+    pub fn withdraw_early(e: Env, amount: i128) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        if unbond_cooldown::is_enabled(&e) || unbonding_queue::is_configured(&e) {
+            return Err(BondError::WithdrawalNotRequested);
+        }
+        let key = DataKey::Bond;
+        let mut bond = e
+            .storage()
+            .instance()
+            .get::<_, IdentityBond>(&key)
+            .ok_or(BondError::NoBond)?;
+        bond.identity.require_auth();
+
+        let now = e.ledger().timestamp();
+        if time_math::is_period_ended(now, bond.bond_start, bond.bond_duration) {
+            return Err(BondError::LockupElapsed);
+        }
+
+        let available = bond
+            .bonded_amount
+            .checked_sub(bond.slashed_amount)
+            .ok_or(BondError::InsufficientBalance)?;
+        if amount > available - unbond_cooldown::reserved(&e) {
+            return Err(BondError::InsufficientBalance);
+        }
+
+        let old_tier = tiered_bond::get_tier_for_amount(&e, bond.bonded_amount);
+        bond.bonded_amount = bond
+            .bonded_amount
+            .checked_sub(amount)
+            .ok_or(BondError::Overflow)?;
+
+        if bond.slashed_amount > bond.bonded_amount {
+            return Err(BondError::InsufficientBalance);
+        }
+
+        let new_tier = tiered_bond::get_tier_for_amount(&e, bond.bonded_amount);
+        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+
+        reward_accrual::checkpoint(&e);
+        e.storage().instance().set(&key, &bond);
+        verification_snapshot::refresh_bond(&e);
+        let penalty = Self::charge_early_exit_penalty(&e, &bond, amount, now);
+        funding::release(&e, &bond.identity, amount.saturating_sub(penalty));
+        health_factor::refresh(&e);
+        Ok(bond)
+    }
+
+    /// Charge the early-exit penalty on `amount` leaving `bond` before its lock-up ends and
+    /// route it to the treasury or reserve it until `collect_penalties`. Call after the
+    /// reduced bond is stored. Returns the penalty.
+    fn charge_early_exit_penalty(e: &Env, bond: &IdentityBond, amount: i128, now: u64) -> i128 {
+        let (treasury, penalty_bps) = early_exit_penalty::get_config(e);
+        let remaining = time_math::remaining_in_period(now, bond.bond_start, bond.bond_duration);
+        let penalty =
+            early_exit_penalty::penalty_for(e, amount, remaining, bond.bond_duration, penalty_bps);
+        early_exit_penalty::emit_penalty_event(e, &bond.identity, amount, penalty, &treasury);
+        if early_exit_penalty::is_routed(e) {
+            early_exit_penalty::route_to_treasury(e, &treasury, penalty);
+        } else {
+            reserves::add_pending_penalty(e, penalty);
+        }
+        penalty
+    }
+
+    /// Withdraw from bond. For rolling bonds requires prior notice and elapsed notice period.
+    /// Unavailable while an unbond cooldown is configured (see `request_unbond`).
+    pub fn withdraw(e: Env, amount: i128) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        if unbond_cooldown::is_enabled(&e) || unbonding_queue::is_configured(&e) {
+            return Err(BondError::WithdrawalNotRequested);
+        }
+        let key = DataKey::Bond;
+        let mut bond: IdentityBond = e.storage().instance().get(&key).ok_or(BondError::NoBond)?;
+        bond.identity.require_auth();
+
+        if bond.is_rolling {
+            if bond.withdrawal_requested_at == 0 {
+                return Err(BondError::WithdrawalNotRequested);
+            }
+            let now = e.ledger().timestamp();
+            if !time_math::notice_elapsed(
+                now,
+                bond.withdrawal_requested_at,
+                bond.notice_period_duration,
+            ) {
+                return Err(BondError::NoticeNotElapsed);
+            }
+        }
+
+        let available = bond
+            .bonded_amount
+            .checked_sub(bond.slashed_amount)
+            .ok_or(BondError::InsufficientBalance)?;
+        if amount > available - unbond_cooldown::reserved(&e) {
+            return Err(BondError::InsufficientBalance);
+        }
+
+        let old_tier = tiered_bond::get_tier_for_amount(&e, bond.bonded_amount);
+        bond.bonded_amount = bond
+            .bonded_amount
+            .checked_sub(amount)
+            .ok_or(BondError::Overflow)?;
+
+        if bond.slashed_amount > bond.bonded_amount {
+            return Err(BondError::InsufficientBalance);
+        }
+
+        let new_tier = tiered_bond::get_tier_for_amount(&e, bond.bonded_amount);
+        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+
+        reward_accrual::checkpoint(&e);
+        e.storage().instance().set(&key, &bond);
+        verification_snapshot::refresh_bond(&e);
+        funding::release(&e, &bond.identity, amount);
+        health_factor::refresh(&e);
+        Ok(bond)
+    }
+
+    pub fn request_withdrawal(e: Env) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        let key = DataKey::Bond;
+        let mut bond: IdentityBond = e.storage().instance().get(&key).ok_or(BondError::NoBond)?;
+        bond.identity.require_auth();
+        if !bond.is_rolling {
+            return Err(BondError::NotRollingBond);
+        }
+        if bond.withdrawal_requested_at != 0 {
+            return Err(BondError::WithdrawalAlreadyRequested);
+        }
+
+        bond.withdrawal_requested_at = e.ledger().timestamp();
+        e.storage().instance().set(&key, &bond);
+        e.events().publish(
+            (Symbol::new(&e, "withdrawal_requested"),),
+            (bond.identity.clone(), bond.withdrawal_requested_at),
+        );
+        Ok(bond)
+    }
+
+    /// Cancel a rolling bond's pending withdrawal request while its notice period is still
+    /// running, so the bond keeps renewing. Fails with `LockupElapsed` once the notice has
+    /// elapsed. Requires the identity's auth.
+    pub fn cancel_withdrawal_request(e: Env) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        let key = DataKey::Bond;
+        let mut bond: IdentityBond = e.storage().instance().get(&key).ok_or(BondError::NoBond)?;
+        bond.identity.require_auth();
+        if !bond.is_rolling {
+            return Err(BondError::NotRollingBond);
+        }
+        if bond.withdrawal_requested_at == 0 {
+            return Err(BondError::WithdrawalNotRequested);
+        }
+        if time_math::notice_elapsed(
+            e.ledger().timestamp(),
+            bond.withdrawal_requested_at,
+            bond.notice_period_duration,
+        ) {
+            return Err(BondError::LockupElapsed);
+        }
+
+        let requested_at = bond.withdrawal_requested_at;
+        bond.withdrawal_requested_at = 0;
+        e.storage().instance().set(&key, &bond);
+        e.events().publish(
+            (Symbol::new(&e, "withdrawal_request_cancelled"),),
+            (bond.identity.clone(), requested_at),
+        );
+        Ok(bond)
+    }
+
+    pub fn renew_if_rolling(e: Env) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        Self::renew_rolling(&e).map(|(bond, _)| bond)
+    }
+
+    /// Renew every eligible rolling bond among `identities` (at most
+    /// `rolling_bond::MAX_RENEWAL_BATCH`) and return how many were advanced, so a keeper can
+    /// maintain all bonds in one call. Identities without an active rolling bond here, or
+    /// whose period has not ended, are skipped. Callable by anyone.
+    pub fn renew_rolling_batch(e: Env, identities: Vec<Address>) -> Result<u32, BondError> {
+        pausable::require_not_paused(&e);
+        if identities.is_empty() || identities.len() > rolling_bond::MAX_RENEWAL_BATCH {
+            return Err(BondError::InvalidInput);
+        }
+        let mut renewed: u32 = 0;
+        for identity in identities.iter() {
+            if Self::require_active_bond_for(&e, &identity).is_err() {
+                continue;
+            }
+            if Self::renew_rolling(&e)?.1 {
+                renewed += 1;
+            }
+        }
+        e.events()
+            .publish((Symbol::new(&e, "rolling_batch_renewed"),), renewed);
+        Ok(renewed)
+    }
+
+    /// Renew the bond if it is rolling and its period ended. Returns the bond and whether it
+    /// was renewed (auto-exit turns a due renewal into a withdrawal request instead).
+    fn renew_rolling(e: &Env) -> Result<(IdentityBond, bool), BondError> {
+        let key = DataKey::Bond;
+        let mut bond: IdentityBond = e.storage().instance().get(&key).ok_or(BondError::NoBond)?;
+        if !bond.is_rolling {
+            return Ok((bond, false));
+        }
+
+        let now = e.ledger().timestamp();
+        if !time_math::is_period_ended(now, bond.bond_start, bond.bond_duration) {
+            return Ok((bond, false));
+        }
+
+        if rolling_bond::is_auto_exit_due(e) {
+            // Auto-exit: the period end counts as the withdrawal request; no renewal.
+            if bond.withdrawal_requested_at == 0 {
+                bond.withdrawal_requested_at =
+                    time_math::period_end(bond.bond_start, bond.bond_duration);
+                e.storage().instance().set(&key, &bond);
+                e.events().publish(
+                    (Symbol::new(e, "withdrawal_requested"),),
+                    (bond.identity.clone(), bond.withdrawal_requested_at),
+                );
+            }
+            return Ok((bond, false));
+        }
+
+        let old_tier =
+            tiered_bond::get_tier_for_amount(e, rolling_bond::effective_tier_amount(e, &bond));
+        rolling_bond::renew(e, &mut bond, now);
+        rolling_bond::record_renewal(e);
+        e.storage().instance().set(&key, &bond);
+        verification_snapshot::refresh_bond(e);
+        e.events().publish(
+            (Symbol::new(e, "bond_renewed"),),
+            (bond.identity.clone(), bond.bond_start, bond.bond_duration),
+        );
+        let new_tier =
+            tiered_bond::get_tier_for_amount(e, rolling_bond::effective_tier_amount(e, &bond));
+        tiered_bond::emit_tier_change_if_needed(e, &bond.identity, old_tier, new_tier);
+        Ok((bond, true))
+    }
+
+    /// Queue a withdrawal subject to the per-epoch exit capacity. Returns the request id.
+    pub fn queue_unbonding(e: Env, amount: i128) -> Result<u64, BondError> {
+        pausable::require_not_paused(&e);
+        if unbond_cooldown::is_enabled(&e) {
+            return Err(BondError::WithdrawalNotRequested);
+        }
+        let bond = Self::get_identity_state(e.clone());
+        bond.identity.require_auth();
+        Ok(unbonding_queue::enqueue(&e, &bond, amount))
+    }
+
+    /// Release queued withdrawals FIFO up to this epoch's capacity. Callable by anyone.
+    /// Returns the total amount released.
+    pub fn process_unbonding_queue(e: Env, max_requests: u32) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        let mut bond = Self::get_identity_state(e.clone());
+        let old_tier = tiered_bond::get_tier_for_amount(&e, bond.bonded_amount);
+        let released = unbonding_queue::process(&e, &mut bond, max_requests);
+        if released > 0 {
+            let new_tier = tiered_bond::get_tier_for_amount(&e, bond.bonded_amount);
+            tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+            reward_accrual::checkpoint(&e);
+            e.storage().instance().set(&DataKey::Bond, &bond);
+            verification_snapshot::refresh_bond(&e);
+            funding::release(&e, &bond.identity, released);
+            health_factor::refresh(&e);
+        }
+        Ok(released)
+    }
+
+    /// Schedule `amount` for withdrawal once the unbond cooldown has passed. The amount stays
+    /// slashable until claimed.
+    pub fn request_unbond(
+        e: Env,
+        amount: i128,
+    ) -> Result<unbond_cooldown::PendingUnbond, BondError> {
+        pausable::require_not_paused(&e);
+        let bond = Self::get_identity_state(e.clone());
+        bond.identity.require_auth();
+        Ok(unbond_cooldown::request(&e, &bond, amount))
+    }
+
+    /// Withdraw the amount scheduled by `request_unbond` after its cooldown, less any slashes
+    /// taken meanwhile. Returns the amount released.
+    pub fn claim_unbonded(e: Env) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        let mut bond = Self::get_identity_state(e.clone());
+        bond.identity.require_auth();
+        let old_tier = tiered_bond::get_tier_for_amount(&e, bond.bonded_amount);
+        let released = unbond_cooldown::claim(&e, &mut bond);
+        let new_tier = tiered_bond::get_tier_for_amount(&e, bond.bonded_amount);
+        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+        reward_accrual::checkpoint(&e);
+        e.storage().instance().set(&DataKey::Bond, &bond);
+        verification_snapshot::refresh_bond(&e);
+        funding::release(&e, &bond.identity, released);
+        health_factor::refresh(&e);
+        Ok(released)
+    }
+
+    pub fn get_pending_unbond(e: Env) -> Option<unbond_cooldown::PendingUnbond> {
+        unbond_cooldown::get_pending(&e)
+    }
+
+    /// Set the cooldown (seconds) all withdrawals wait through. 0 disables it and restores
+    /// direct withdrawals. Admin only.
+    pub fn set_unbond_delay(e: Env, admin: Address, seconds: u64) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        unbond_cooldown::set_delay(&e, seconds);
+        Ok(())
+    }
+
+    pub fn get_unbond_delay(e: Env) -> u64 {
+        unbond_cooldown::get_delay(&e)
+    }
+
+    pub fn get_unbonding_request(e: Env, request_id: u64) -> unbonding_queue::UnbondingRequest {
+        unbonding_queue::get_request(&e, request_id)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::UnbondingNotFound))
+    }
+
+    /// Queue position and estimated release time for a request.
+    pub fn get_unbonding_status(e: Env, request_id: u64) -> unbonding_queue::UnbondingStatus {
+        let bond = Self::get_identity_state(e.clone());
+        unbonding_queue::status(&e, &bond, request_id)
+    }
+
+    pub fn get_unbonding_queue_length(e: Env) -> u32 {
+        unbonding_queue::queue_length(&e)
+    }
+
+    /// Set the queue's epoch length and per-epoch exit capacity. From then on `withdraw`,
+    /// `withdraw_early` and `withdraw_bond` fail with `WithdrawalNotRequested` and exits go
+    /// through the queue. Admin only.
+    pub fn set_unbonding_config(
+        e: Env,
+        admin: Address,
+        epoch_length: u64,
+        exit_capacity_bps: u32,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        unbonding_queue::set_config(&e, epoch_length, exit_capacity_bps);
+        Ok(())
+    }
+
+    pub fn get_unbonding_config(e: Env) -> unbonding_queue::UnbondingConfig {
+        unbonding_queue::get_config(&e)
+    }
+
+    /// Tier from the effective amount (bonded amount plus any rolling renewal bonus).
+    /// Tier, active and pending attestation weight, attestation count and slash ratio of `identity` in
+    /// a single read, kept up to date on every relevant mutation. Gated: requires the auth of
+    /// an authorized query consumer and is charged to it as one query.
+    pub fn get_verification_snapshot(
+        e: Env,
+        consumer: Address,
+        identity: Address,
+    ) -> Result<verification_snapshot::VerificationSnapshot, BondError> {
+        consumer.require_auth();
+        query_access::consume_credit(&e, &consumer);
+        Ok(verification_snapshot::view(&e, &identity))
+    }
+
+    /// Components behind `subject`'s verification figures: per-claim weights, netting and
+    /// verifier-cap deductions, expired and pending weight, tier and slash ratio. Gated:
+    /// requires the auth of an authorized query consumer and is charged to it as one query.
+    pub fn explain_score(
+        e: Env,
+        consumer: Address,
+        subject: Address,
+    ) -> Result<score_breakdown::ScoreBreakdown, BondError> {
+        consumer.require_auth();
+        query_access::consume_credit(&e, &consumer);
+        Ok(score_breakdown::explain(&e, &subject))
+    }
+
+    /// Reputation score of `subject` computed now from its attestation weight, bond tier,
+    /// bond age and slash count. Gated: requires the auth of an authorized query consumer
+    /// and is charged to it as one query.
+    pub fn get_reputation(
+        e: Env,
+        consumer: Address,
+        subject: Address,
+    ) -> Result<reputation::Reputation, BondError> {
+        consumer.require_auth();
+        query_access::consume_credit(&e, &consumer);
+        Ok(reputation::get(&e, &subject))
+    }
+
+    /// Reputation of `subject` as cached at its last attestation change or slash. Gated:
+    /// requires the auth of an authorized query consumer and is charged to it as one query.
+    pub fn get_cached_reputation(
+        e: Env,
+        consumer: Address,
+        subject: Address,
+    ) -> Result<Option<reputation::Reputation>, BondError> {
+        consumer.require_auth();
+        query_access::consume_credit(&e, &consumer);
+        Ok(reputation::get_cached(&e, &subject))
+    }
+
+    /// Set the points of each reputation component. Admin only.
+    pub fn set_reputation_config(
+        e: Env,
+        admin: Address,
+        config: reputation::ReputationConfig,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        reputation::set_config(&e, &config);
+        Ok(())
+    }
+
+    pub fn get_reputation_config(e: Env) -> reputation::ReputationConfig {
+        reputation::get_config(&e)
+    }
+
+    /// Set the pricing of gated queries. Admin only.
+    pub fn set_query_pricing(
+        e: Env,
+        admin: Address,
+        pricing: query_access::QueryPricing,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        query_access::set_pricing(&e, &pricing);
+        Ok(())
+    }
+
+    pub fn get_query_pricing(e: Env) -> query_access::QueryPricing {
+        query_access::get_pricing(&e)
+    }
+
+    /// Add `consumer` to (or remove it from) the query consumer allow-list. Admin only.
+    pub fn set_query_consumer(
+        e: Env,
+        admin: Address,
+        consumer: Address,
+        authorized: bool,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        query_access::set_authorized(&e, &consumer, authorized);
+        Ok(())
+    }
+
+    pub fn get_consumer_account(e: Env, consumer: Address) -> query_access::ConsumerAccount {
+        query_access::get_account(&e, &consumer)
+    }
+
+    /// Prepay `amount` of the bond token as query credit. Returns the new credit. Requires
+    /// the consumer's auth; the consumer must be authorized.
+    pub fn deposit_query_credit(
+        e: Env,
+        consumer: Address,
+        amount: i128,
+    ) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        consumer.require_auth();
+        Ok(query_access::deposit(&e, &consumer, amount))
+    }
+
+    /// Pay `amount` of the consumer's uncharged query credit back to it. Returns the credit
+    /// left. Requires the consumer's auth; also open to consumers removed from the allow-list.
+    pub fn withdraw_query_credit(
+        e: Env,
+        consumer: Address,
+        amount: i128,
+    ) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        consumer.require_auth();
+        Ok(query_access::withdraw(&e, &consumer, amount))
+    }
+
+    /// Buy a query subscription period from the consumer's credit. Returns its end.
+    pub fn subscribe_queries(e: Env, consumer: Address) -> Result<u64, BondError> {
+        pausable::require_not_paused(&e);
+        consumer.require_auth();
+        Ok(query_access::subscribe(&e, &consumer))
+    }
+
+    /// Charge one query to `consumer` (free during a subscription). Returns the amount
+    /// charged. Used by consumers metering reads they make through other endpoints.
+    pub fn consume_credit(e: Env, consumer: Address) -> Result<i128, BondError> {
+        consumer.require_auth();
+        Ok(query_access::consume_credit(&e, &consumer))
+    }
+
+    /// Hash-committed credential summary of `subject` for presentation to off-chain
+    /// verifiers. Only the subject can build its proof.
+    pub fn get_credential_proof(e: Env, subject: Address) -> credential_proof::CredentialProof {
+        subject.require_auth();
+        credential_proof::build(&e, &subject)
+    }
+
+    /// True if the proof's commitment is intact and its tier, weights and attester set
+    /// still match current state.
+    pub fn verify_credential_proof(e: Env, proof: credential_proof::CredentialProof) -> bool {
+        credential_proof::verify(&e, &proof)
+    }
+
+    pub fn get_tier(e: Env) -> BondTier {
+        let amount = Self::get_effective_tier_amount(e.clone());
+        tiered_bond::get_tier_for_amount(&e, amount)
+    }
+
+    /// Set the minimum bonded amount of each tier, in the bond token's smallest unit.
+    /// Thresholds must be strictly increasing; non-zero ones must be at least one whole
+    /// token unit. Admin only.
+    pub fn set_tier_thresholds(
+        e: Env,
+        admin: Address,
+        bronze: i128,
+        silver: i128,
+        gold: i128,
+        platinum: i128,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        governance_actions::require_not_required(&e);
+        tiered_bond::set_thresholds(
+            &e,
+            &tiered_bond::TierThresholds {
+                bronze,
+                silver,
+                gold,
+                platinum,
+            },
+        );
+        verification_snapshot::refresh_bond(&e);
+        Ok(())
+    }
+
+    /// Set the minimum bond amount and the duration bounds of new bonds. Admin only.
+    pub fn set_bond_limits(
+        e: Env,
+        admin: Address,
+        limits: bond_limits::BondLimits,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        bond_limits::set(&e, &limits);
+        Ok(())
+    }
+
+    /// Minimum amount and duration bounds enforced on `create_bond*`, `top_up` and
+    /// `extend_duration`.
+    pub fn get_bond_limits(e: Env) -> bond_limits::BondLimits {
+        bond_limits::get(&e)
+    }
+
+    /// Configured tier thresholds (defaults if never set).
+    pub fn get_tier_thresholds(e: Env) -> tiered_bond::TierThresholds {
+        tiered_bond::get_thresholds(&e)
+    }
+
+    /// Amount used for tier computation: bonded amount plus the rolling renewal bonus.
+    pub fn get_effective_tier_amount(e: Env) -> i128 {
+        let bond = Self::get_identity_state(e.clone());
+        rolling_bond::effective_tier_amount(&e, &bond)
+    }
+
+    pub fn get_renewal_count(e: Env) -> u32 {
+        rolling_bond::get_renewal_count(&e)
+    }
+
+    /// Configure the loyalty bonus: `bonus_bps` per renewal, counted for at most
+    /// `max_renewals` renewals. Admin only.
+    pub fn set_renewal_bonus_config(
+        e: Env,
+        admin: Address,
+        bonus_bps: u32,
+        max_renewals: u32,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        rolling_bond::set_renewal_bonus_config(&e, bonus_bps, max_renewals);
+        Ok(())
+    }
+
+    pub fn get_renewal_bonus_config(e: Env) -> (u32, u32) {
+        rolling_bond::get_renewal_bonus_config(&e)
+    }
+
+    /// Configure re-bonding after a full slash: `cooldown` before re-bonding is allowed, the
+    /// `min_amount` of the new bond and the probation `duration`. Admin only.
+    pub fn set_probation_config(
+        e: Env,
+        admin: Address,
+        cooldown: u64,
+        min_amount: i128,
+        duration: u64,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        probation::set_config(&e, cooldown, min_amount, duration);
+        Ok(())
+    }
+
+    pub fn get_probation_config(e: Env) -> probation::ProbationConfig {
+        probation::get_config(&e)
+    }
+
+    /// True while `identity` is on probation after re-bonding from a full slash.
+    pub fn is_on_probation(e: Env, identity: Address) -> bool {
+        probation::is_on_probation(&e, &identity)
+    }
+
+    /// Timestamp until which `identity` is on probation (0 if never).
+    pub fn get_probation_until(e: Env, identity: Address) -> u64 {
+        probation::get_probation_until(&e, &identity)
+    }
+
+    /// When `identity` was fully slashed, if it has not re-bonded since.
+    pub fn get_full_slash_at(e: Env, identity: Address) -> Option<u64> {
+        probation::get_full_slash_at(&e, &identity)
+    }
+
+    pub fn slash(e: Env, admin: Address, amount: i128) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        let bond = slashing::slash_bond(&e, &admin, amount);
+        slash_settlement::settle_if_auto(&e);
+        Ok(bond)
+    }
+
+    /// Slash the bond on behalf of a harmed counterparty. A configured share of the amount
+    /// actually slashed is escrowed for `beneficiary`. Returns the slash id used for claims.
+    pub fn slash_with_beneficiary(
+        e: Env,
+        admin: Address,
+        amount: i128,
+        beneficiary: Address,
+    ) -> Result<u64, BondError> {
+        pausable::require_not_paused(&e);
+        let before = Self::get_identity_state(e.clone()).slashed_amount;
+        let after = slashing::slash_bond(&e, &admin, amount).slashed_amount;
+        let delta = after.checked_sub(before).ok_or(BondError::Overflow)?;
+        let slash_id = compensation::record_claim(&e, &beneficiary, delta);
+        slash_settlement::settle_if_auto(&e);
+        Ok(slash_id)
+    }
+
+    /// Beneficiary claims escrowed compensation for a slash before the claim deadline.
+    pub fn claim_compensation(
+        e: Env,
+        beneficiary: Address,
+        slash_id: u64,
+    ) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        beneficiary.require_auth();
+        Ok(compensation::claim(&e, &beneficiary, slash_id))
+    }
+
+    /// Route expired, unclaimed compensation to the treasury through the slash proceeds.
+    /// Callable by anyone.
+    pub fn sweep_expired_compensation(e: Env, slash_id: u64) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        let swept = compensation::sweep_expired(&e, slash_id);
+        slash_settlement::settle_if_auto(&e);
+        Ok(swept)
+    }
+
+    pub fn set_compensation_config(
+        e: Env,
+        admin: Address,
+        treasury: Address,
+        share_bps: u32,
+        claim_window: u64,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        compensation::set_config(&e, treasury, share_bps, claim_window);
+        Ok(())
+    }
+
+    pub fn get_compensation_config(e: Env) -> compensation::CompensationConfig {
+        compensation::get_config(&e)
+    }
+
+    pub fn get_compensation_claim(e: Env, slash_id: u64) -> compensation::CompensationClaim {
+        compensation::get_claim(&e, slash_id)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::ClaimNotFound))
+    }
+
+    pub fn get_compensation_escrow(e: Env) -> i128 {
+        compensation::get_escrow_balance(&e)
+    }
+
+    /// Set governors and quorum. Non-admin governors can only propose slashes once they
+    /// have held an active bond or their seat for `min_proposer_age` seconds (0 = no gate).
+    pub fn initialize_governance(
+        e: Env,
+        admin: Address,
+        governors: Vec<Address>,
+        quorum_bps: u32,
+        min_governors: u32,
+        min_proposer_age: u64,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        features::require_enabled(&e, features::FEATURE_GOVERNANCE);
+        proposer_age::record_governor_seats(&e, &governors, e.ledger().timestamp());
+        proposer_age::set_min_age(&e, min_proposer_age);
+        governance_approval::initialize_governance(&e, governors, quorum_bps, min_governors);
+        Ok(())
+    }
+
+    /// Add a slash governor. Its seat age starts now. Admin only.
+    pub fn add_governor(e: Env, admin: Address, governor: Address) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        governance_approval::add_governor(&e, &governor);
+        proposer_age::record_governor_seats(
+            &e,
+            &Vec::from_array(&e, [governor]),
+            e.ledger().timestamp(),
         );
-
-        attestation
+        Ok(())
     }
 
-    /// Revoke an attestation (only original attester). Requires correct nonce.
-    pub fn revoke_attestation(e: Env, attester: Address, attestation_id: u64, nonce: u64) {
-        attester.require_auth();
-        nonce::consume_nonce(&e, &attester, nonce);
+    /// Remove a slash governor and the delegations to and from it. Admin only.
+    pub fn remove_governor(e: Env, admin: Address, governor: Address) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        governance_approval::remove_governor(&e, &governor);
+        proposer_age::clear_governor_seat(&e, &governor);
+        Ok(())
+    }
 
-        let key = DataKey::Attestation(attestation_id);
-        let mut attestation: Attestation = e
+    /// True if `proposer` may propose a slash now: the admin, or a governor that meets the
+    /// minimum bond or seat age.
+    pub fn is_eligible_proposer(e: Env, proposer: Address) -> bool {
+        let admin: Address = e
             .storage()
             .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("attestation not found"));
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::NotInitialized));
+        if proposer == admin {
+            return true;
+        }
+        governance_approval::get_governors(&e).contains(&proposer)
+            && proposer_age::meets_min_age(&e, &proposer)
+    }
 
-        if attestation.verifier != attester {
-            panic!("only original attester can revoke");
+    pub fn get_min_proposer_age(e: Env) -> u64 {
+        proposer_age::get_min_age(&e)
+    }
+
+    /// Propose slashing `identity`'s bond. The identity must have an active bond.
+    pub fn propose_slash(
+        e: Env,
+        proposer: Address,
+        identity: Address,
+        amount: i128,
+    ) -> Result<u64, BondError> {
+        proposer.require_auth();
+        features::require_enabled(&e, features::FEATURE_GOVERNANCE);
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(BondError::NotInitialized)?;
+        let governors = governance_approval::get_governors(&e);
+        let is_governor = governors.iter().any(|g| g == proposer);
+        if proposer != admin && !is_governor {
+            return Err(BondError::Unauthorized);
         }
-        if attestation.revoked {
-            panic!("attestation already revoked");
+        if proposer != admin && !proposer_age::meets_min_age(&e, &proposer) {
+            return Err(BondError::ProposerTooNew);
         }
+        Self::require_active_bond_for(&e, &identity)?;
+        let id = governance_approval::propose_slash(&e, &proposer, &identity, amount);
+        health_factor::refresh(&e);
+        Ok(id)
+    }
 
-        attestation.revoked = true;
-        e.storage().instance().set(&key, &attestation);
-
-        let dedup_key = types::AttestationDedupKey {
-            verifier: attestation.verifier.clone(),
-            identity: attestation.identity.clone(),
-            attestation_data: attestation.attestation_data.clone(),
-        };
-        e.storage().instance().remove(&dedup_key);
+    pub fn governance_vote(
+        e: Env,
+        voter: Address,
+        proposal_id: u64,
+        approve: bool,
+    ) -> Result<(), BondError> {
+        voter.require_auth();
+        governance_approval::vote(&e, &voter, proposal_id, approve);
+        Ok(())
+    }
 
-        let count_key = DataKey::SubjectAttestationCount(attestation.identity.clone());
-        let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
-        e.storage()
+    /// Cancel an open slash proposal. Only its proposer or the admin may cancel.
+    pub fn cancel_slash_proposal(
+        e: Env,
+        caller: Address,
+        proposal_id: u64,
+    ) -> Result<governance_approval::SlashProposal, BondError> {
+        caller.require_auth();
+        let admin: Address = e
+            .storage()
             .instance()
-            .set(&count_key, &count.saturating_sub(1));
+            .get(&DataKey::Admin)
+            .ok_or(BondError::NotInitialized)?;
+        let proposal = governance_approval::get_proposal(&e, proposal_id)
+            .ok_or(BondError::ProposalNotFound)?;
+        if caller != proposal.proposed_by && caller != admin {
+            return Err(BondError::Unauthorized);
+        }
+        let proposal = governance_approval::cancel(&e, &caller, proposal_id);
+        health_factor::refresh(&e);
+        Ok(proposal)
+    }
 
-        e.events().publish(
-            (
-                Symbol::new(&e, "attestation_revoked"),
-                attestation.identity.clone(),
-            ),
-            (attestation_id, attester),
-        );
+    /// Close an open slash proposal whose voting deadline has passed as Rejected. Callable
+    /// by anyone.
+    pub fn expire_slash_proposal(
+        e: Env,
+        proposal_id: u64,
+    ) -> Result<governance_approval::SlashProposal, BondError> {
+        let proposal = governance_approval::expire(&e, proposal_id);
+        health_factor::refresh(&e);
+        Ok(proposal)
     }
 
-    pub fn get_attestation(e: Env, attestation_id: u64) -> Attestation {
-        e.storage()
-            .instance()
-            .get(&DataKey::Attestation(attestation_id))
-            .unwrap_or_else(|| panic!("attestation not found"))
+    /// Count slash proposal votes one per governor (`Equal`) or by governor weight
+    /// (`Weighted`). Admin only.
+    pub fn set_governance_voting_mode(
+        e: Env,
+        admin: Address,
+        mode: governance_approval::VotingMode,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        governance_approval::set_voting_mode(&e, mode);
+        Ok(())
     }
 
-    pub fn get_subject_attestations(e: Env, subject: Address) -> Vec<u64> {
-        e.storage()
-            .instance()
-            .get(&DataKey::SubjectAttestations(subject))
-            .unwrap_or(Vec::new(&e))
+    pub fn get_governance_voting_mode(e: Env) -> governance_approval::VotingMode {
+        governance_approval::get_voting_mode(&e)
     }
 
-    pub fn get_subject_attestation_count(e: Env, subject: Address) -> u32 {
-        e.storage()
-            .instance()
-            .get(&DataKey::SubjectAttestationCount(subject))
-            .unwrap_or(0)
+    /// Override a governor's vote weight in `Weighted` mode; 0 restores its bonded amount.
+    /// Admin only.
+    pub fn set_governor_weight(
+        e: Env,
+        admin: Address,
+        governor: Address,
+        weight: i128,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        governance_approval::set_governor_weight(&e, &governor, weight);
+        Ok(())
     }
 
-    pub fn get_nonce(e: Env, identity: Address) -> u64 {
-        nonce::get_nonce(&e, &identity)
+    pub fn get_governor_weight(e: Env, governor: Address) -> i128 {
+        governance_approval::governor_weight(&e, &governor)
     }
 
-    pub fn set_attester_stake(e: Env, admin: Address, attester: Address, amount: i128) {
-        Self::require_admin(&e, &admin);
-        weighted_attestation::set_attester_stake(&e, &attester, amount);
+    /// Vote weights and quorum progress of a slash proposal under the current voting mode.
+    pub fn get_proposal_tally(
+        e: Env,
+        proposal_id: u64,
+    ) -> Option<governance_approval::ProposalTally> {
+        governance_approval::get_proposal(&e, proposal_id)?;
+        Some(governance_approval::tally(&e, proposal_id))
     }
 
-    pub fn set_weight_config(e: Env, admin: Address, multiplier_bps: u32, max_weight: u32) {
-        Self::require_admin(&e, &admin);
-        weighted_attestation::set_weight_config(&e, multiplier_bps, max_weight);
+    /// Voting period (seconds) of new slash proposals; 0 means no deadline. Admin only.
+    pub fn set_governance_voting_period(
+        e: Env,
+        admin: Address,
+        seconds: u64,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        governance_approval::set_voting_period(&e, seconds);
+        Ok(())
     }
 
-    pub fn get_weight_config(e: Env) -> (u32, u32) {
-        weighted_attestation::get_weight_config(&e)
+    pub fn get_governance_voting_period(e: Env) -> u64 {
+        governance_approval::get_voting_period(&e)
     }
 
-    /// Early withdrawal path (only valid before lock-up end).
-    pub fn withdraw_early(e: Env, amount: i128) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond = e
-            .storage()
-            .instance()
-            .get::<_, IdentityBond>(&key)
-            .unwrap_or_else(|| panic!("no bond"));
+    pub fn governance_delegate(e: Env, governor: Address, to: Address) -> Result<(), BondError> {
+        governance_approval::delegate(&e, &governor, &to);
+        Ok(())
+    }
 
-        let now = e.ledger().timestamp();
-        let end = bond.bond_start.saturating_add(bond.bond_duration);
-        if now >= end {
-            panic!("use withdraw for post lock-up");
+    pub fn execute_slash_with_governance(
+        e: Env,
+        proposer: Address,
+        proposal_id: u64,
+    ) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        proposer.require_auth();
+        let proposal = governance_approval::get_proposal(&e, proposal_id)
+            .ok_or(BondError::ProposalNotFound)?;
+        if proposal.proposed_by != proposer {
+            return Err(BondError::Unauthorized);
         }
+        Self::require_active_bond_for(&e, &proposal.identity)?;
+        let source = slash_records::SlashSource::Proposal(proposal_id);
+        slash_records::require_not_applied(&e, &source);
+        let executed = governance_approval::execute_slash_if_approved(&e, proposal_id);
+        if !executed {
+            return Err(BondError::ProposalNotApproved);
+        }
+        Self::apply_proposal_slash(
+            &e,
+            &proposer,
+            proposal_id,
+            &proposal.identity,
+            proposal.amount,
+        )
+    }
 
-        let available = bond
-            .bonded_amount
-            .checked_sub(bond.slashed_amount)
-            .expect("slashed amount exceeds bonded amount");
-        if amount > available {
-            panic!("insufficient balance for withdrawal");
+    /// Slash for an approved slash proposal: record it once, then hold it for the appeal
+    /// window or settle it right away.
+    fn apply_proposal_slash(
+        e: &Env,
+        executor: &Address,
+        proposal_id: u64,
+        identity: &Address,
+        amount: i128,
+    ) -> Result<IdentityBond, BondError> {
+        let source = slash_records::SlashSource::Proposal(proposal_id);
+        let before = Self::get_identity_state(e.clone()).slashed_amount;
+        let bond = slashing::apply_slash(e, executor, amount, Some(&source));
+        let delta = bond
+            .slashed_amount
+            .checked_sub(before)
+            .ok_or(BondError::Overflow)?;
+        slash_records::record(e, &source, delta);
+        if slash_appeal::get_window(e) > 0 {
+            slash_appeal::open(e, proposal_id, identity, delta);
+        } else {
+            slash_settlement::settle_if_auto(e);
         }
+        Ok(bond)
+    }
 
-        let (treasury, penalty_bps) = early_exit_penalty::get_config(&e);
-        let remaining = end.saturating_sub(now);
-        let penalty = early_exit_penalty::calculate_penalty(
-            amount,
-            remaining,
-            bond.bond_duration,
-            penalty_bps,
-        );
-        early_exit_penalty::emit_penalty_event(&e, &bond.identity, amount, penalty, &treasury);
+    /// Set the appeal window (seconds) governance slashes stay provisional for. 0 finalizes
+    /// them immediately. Admin only.
+    pub fn set_slash_appeal_window(e: Env, admin: Address, seconds: u64) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        slash_appeal::set_window(&e, seconds);
+        Ok(())
+    }
 
-        let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        bond.bonded_amount = bond
-            .bonded_amount
-            .checked_sub(amount)
-            .expect("withdrawal caused underflow");
+    pub fn get_slash_appeal_window(e: Env) -> u64 {
+        slash_appeal::get_window(&e)
+    }
 
-        if bond.slashed_amount > bond.bonded_amount {
-            panic!("slashed amount exceeds bonded amount");
-        }
+    /// Pending slash executed for a governance proposal, if it went through the appeal window.
+    pub fn get_pending_slash(e: Env, proposal_id: u64) -> Option<slash_appeal::PendingSlash> {
+        slash_appeal::get(&e, proposal_id)
+    }
 
-        let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+    /// Appeal a pending slash before its window closes by opening a dispute with `stake` of
+    /// the bond token in the dispute contract. Returns the dispute id.
+    pub fn appeal_slash(
+        e: Env,
+        identity: Address,
+        proposal_id: u64,
+        stake: i128,
+        resolution_deadline: u64,
+    ) -> Result<u64, BondError> {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        Ok(slash_appeal::appeal(
+            &e,
+            &identity,
+            proposal_id,
+            stake,
+            resolution_deadline,
+        ))
+    }
 
-        e.storage().instance().set(&key, &bond);
-        bond
+    /// Report the outcome of an appeal dispute. `authorized_by` is the admin or the
+    /// dispute contract, which calls this when the dispute resolves. `FavorDisputer`
+    /// reverses the slash; `FavorSlasher` finalizes it.
+    pub fn resolve_slash_appeal(
+        e: Env,
+        proposal_id: u64,
+        outcome: dispute_linkage::LinkedDisputeOutcome,
+        authorized_by: Address,
+    ) -> Result<slash_appeal::PendingSlash, BondError> {
+        pausable::require_not_paused(&e);
+        authorized_by.require_auth();
+        let slash = slash_appeal::get(&e, proposal_id).ok_or(BondError::SlashNotFound)?;
+        slash_reversal::require_authorized(
+            &e,
+            &authorized_by,
+            &slash_records::SlashSource::Dispute(slash.dispute_id),
+        );
+        let slash = slash_appeal::resolve(&e, proposal_id, outcome, &authorized_by);
+        slash_settlement::settle_if_auto(&e);
+        Ok(slash)
     }
 
-    /// Withdraw from bond. For rolling bonds requires prior notice and elapsed notice period.
-    pub fn withdraw(e: Env, amount: i128) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond: IdentityBond = e
-            .storage()
-            .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("no bond"));
+    /// Finalize a pending slash once its appeal window elapsed unappealed, releasing the
+    /// funds for treasury settlement. Callable by anyone.
+    pub fn finalize_slash(
+        e: Env,
+        proposal_id: u64,
+    ) -> Result<slash_appeal::PendingSlash, BondError> {
+        pausable::require_not_paused(&e);
+        let slash = slash_appeal::finalize(&e, proposal_id);
+        slash_settlement::settle_if_auto(&e);
+        Ok(slash)
+    }
 
-        if bond.is_rolling {
-            if bond.withdrawal_requested_at == 0 {
-                panic!("withdrawal not requested");
-            }
-            let now = e.ledger().timestamp();
-            if !rolling_bond::can_withdraw_after_notice(
-                now,
-                bond.withdrawal_requested_at,
-                bond.notice_period_duration,
-            ) {
-                panic!("notice period not elapsed");
+    /// Admin slash executing a specific proposal or dispute decision. Fails with
+    /// `SlashAlreadyApplied` if that decision was already executed on either path, so
+    /// the same decision never changes the balance twice.
+    pub fn slash_for_source(
+        e: Env,
+        admin: Address,
+        amount: i128,
+        source: slash_records::SlashSource,
+    ) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        Self::require_admin(&e, &admin)?;
+        if let slash_records::SlashSource::Proposal(proposal_id) = source {
+            if governance_approval::get_proposal(&e, proposal_id).is_none() {
+                return Err(BondError::ProposalNotFound);
             }
         }
+        slash_records::require_not_applied(&e, &source);
+        let before = Self::get_identity_state(e.clone()).slashed_amount;
+        let bond = slashing::apply_slash(&e, &admin, amount, Some(&source));
+        let delta = bond
+            .slashed_amount
+            .checked_sub(before)
+            .ok_or(BondError::Overflow)?;
+        slash_records::record(&e, &source, delta);
+        slash_settlement::settle_if_auto(&e);
+        Ok(bond)
+    }
 
-        let available = bond
-            .bonded_amount
-            .checked_sub(bond.slashed_amount)
-            .expect("slashed amount exceeds bonded amount");
-        if amount > available {
-            panic!("insufficient balance for withdrawal");
+    /// Reverse `amount` of the slash executed for `source` (e.g. a dispute found it half
+    /// wrong). `authorized_by` is the admin, or the configured dispute contract for dispute
+    /// decisions. The amount is restored to the bond if it is still active; otherwise it is
+    /// credited as a refund claimable with `claim_slash_refund`.
+    pub fn reverse_slash(
+        e: Env,
+        source: slash_records::SlashSource,
+        amount: i128,
+        authorized_by: Address,
+    ) -> Result<slash_records::SlashRecord, BondError> {
+        pausable::require_not_paused(&e);
+        authorized_by.require_auth();
+        slash_reversal::require_authorized(&e, &authorized_by, &source);
+        if let slash_records::SlashSource::Proposal(proposal_id) = source {
+            if slash_appeal::is_open(&e, proposal_id) {
+                return Err(BondError::InvalidDisputeState);
+            }
         }
+        Ok(slash_reversal::reverse(&e, &source, amount, &authorized_by))
+    }
 
-        let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        bond.bonded_amount = bond
-            .bonded_amount
-            .checked_sub(amount)
-            .expect("withdrawal caused underflow");
+    /// Claim the refund credited to `identity` by slash reversals. Returns the amount.
+    pub fn claim_slash_refund(e: Env, identity: Address) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        Ok(slash_reversal::claim_refund(&e, &identity))
+    }
 
-        if bond.slashed_amount > bond.bonded_amount {
-            panic!("slashed amount exceeds bonded amount");
-        }
+    /// Refund claimable by `identity` from slash reversals.
+    pub fn get_slash_refund(e: Env, identity: Address) -> i128 {
+        slash_reversal::get_refund(&e, &identity)
+    }
 
-        let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+    /// Slash executed for a proposal or dispute decision, if any.
+    pub fn get_slash_record(
+        e: Env,
+        source: slash_records::SlashSource,
+    ) -> Option<slash_records::SlashRecord> {
+        slash_records::get(&e, &source)
+    }
 
-        e.storage().instance().set(&key, &bond);
-        bond
+    /// Executed slash by its sequential history id.
+    pub fn get_slash_history_record(e: Env, id: u64) -> Option<slash_history::SlashHistoryRecord> {
+        slash_history::get(&e, id)
     }
 
-    pub fn request_withdrawal(e: Env) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond: IdentityBond = e
-            .storage()
-            .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("no bond"));
-        if !bond.is_rolling {
-            panic!("not a rolling bond");
-        }
-        if bond.withdrawal_requested_at != 0 {
-            panic!("withdrawal already requested");
-        }
+    /// Slashes executed against `identity`, oldest first. Pages of at most
+    /// `slash_history::MAX_PAGE_SIZE`.
+    pub fn get_slash_history_page(
+        e: Env,
+        identity: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<slash_history::SlashHistoryRecord> {
+        slash_history::list(&e, &identity, offset, limit)
+    }
 
-        bond.withdrawal_requested_at = e.ledger().timestamp();
-        e.storage().instance().set(&key, &bond);
-        e.events().publish(
-            (Symbol::new(&e, "withdrawal_requested"),),
-            (bond.identity.clone(), bond.withdrawal_requested_at),
-        );
-        bond
+    /// Number of slashes executed against `identity`.
+    pub fn get_slash_history_count(e: Env, identity: Address) -> u32 {
+        slash_history::count(&e, &identity)
     }
 
-    pub fn renew_if_rolling(e: Env) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond: IdentityBond = e
-            .storage()
-            .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("no bond"));
-        if !bond.is_rolling {
-            return bond;
-        }
+    /// Configure where slash proceeds go. `notify_treasury` books them in a credence_treasury
+    /// contract via `receive_fee`; `auto_settle` settles at slash time. Admin only.
+    pub fn set_slash_settlement(
+        e: Env,
+        admin: Address,
+        treasury: Address,
+        notify_treasury: bool,
+        auto_settle: bool,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        slash_settlement::set_config(
+            &e,
+            &slash_settlement::SlashSettlementConfig {
+                treasury: Some(treasury),
+                notify_treasury,
+                auto_settle,
+            },
+        );
+        Ok(())
+    }
 
-        let now = e.ledger().timestamp();
-        if !rolling_bond::is_period_ended(now, bond.bond_start, bond.bond_duration) {
-            return bond;
-        }
+    pub fn get_slash_settlement(e: Env) -> slash_settlement::SlashSettlementConfig {
+        slash_settlement::get_config(&e)
+    }
 
-        rolling_bond::apply_renewal(&mut bond, now);
-        e.storage().instance().set(&key, &bond);
-        e.events().publish(
-            (Symbol::new(&e, "bond_renewed"),),
-            (bond.identity.clone(), bond.bond_start, bond.bond_duration),
-        );
-        bond
+    /// Transfer slash proceeds not yet settled to the treasury. Callable by anyone.
+    pub fn settle_slash(e: Env) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        Ok(Self::with_reentrancy_guard(&e, || {
+            slash_settlement::settle(&e)
+        }))
     }
 
-    pub fn get_tier(e: Env) -> BondTier {
-        let bond = Self::get_identity_state(e);
-        tiered_bond::get_tier_for_amount(bond.bonded_amount)
+    /// Slash proceeds still held by this contract.
+    pub fn get_pending_slash_proceeds(e: Env) -> i128 {
+        slash_settlement::pending(&e)
     }
 
-    pub fn slash(e: Env, admin: Address, amount: i128) -> IdentityBond {
-        slashing::slash_bond(&e, &admin, amount)
+    /// Slash proceeds transferred to the treasury so far.
+    pub fn get_settled_slash_proceeds(e: Env) -> i128 {
+        slash_settlement::settled(&e)
     }
 
-    pub fn initialize_governance(
+    pub fn set_fee_config(
         e: Env,
         admin: Address,
-        governors: Vec<Address>,
-        quorum_bps: u32,
-        min_governors: u32,
-    ) {
-        Self::require_admin(&e, &admin);
-        governance_approval::initialize_governance(&e, governors, quorum_bps, min_governors);
+        treasury: Address,
+        fee_bps: u32,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        governance_actions::require_not_required(&e);
+        let (_, old_bps) = fees::get_config(&e);
+        if admin_actions::is_sensitive_fee_change(&e, old_bps, fee_bps) {
+            admin_actions::record(
+                &e,
+                &admin,
+                admin_actions::AdminActionKind::FeeChange,
+                Some(treasury.clone()),
+                fee_bps as i128,
+            );
+        }
+        fees::set_config(&e, treasury, fee_bps);
+        Ok(())
     }
 
-    pub fn propose_slash(e: Env, proposer: Address, amount: i128) -> u64 {
-        proposer.require_auth();
-        let admin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
-        let governors = governance_approval::get_governors(&e);
-        let is_governor = governors.iter().any(|g| g == proposer);
-        if proposer != admin && !is_governor {
-            panic!("not admin or governor");
+    /// Vary the creation fee by bond size with ordered `(threshold, bps)` bands (strictly
+    /// increasing thresholds). Amounts below the first threshold pay the flat fee. An empty
+    /// schedule restores the flat fee. Admin only.
+    pub fn set_fee_schedule(
+        e: Env,
+        admin: Address,
+        schedule: Vec<(i128, u32)>,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        let mut sensitive = false;
+        let mut max_bps: u32 = 0;
+        for (threshold, bps) in schedule.iter() {
+            let old_bps = fees::bps_for(&e, threshold);
+            sensitive |= admin_actions::is_sensitive_fee_change(&e, old_bps, bps);
+            max_bps = max_bps.max(bps);
+        }
+        if sensitive {
+            admin_actions::record(
+                &e,
+                &admin,
+                admin_actions::AdminActionKind::FeeChange,
+                None,
+                max_bps as i128,
+            );
         }
-        governance_approval::propose_slash(&e, &proposer, amount)
+        fees::set_schedule(&e, &schedule);
+        Ok(())
     }
 
-    pub fn governance_vote(e: Env, voter: Address, proposal_id: u64, approve: bool) {
-        voter.require_auth();
-        governance_approval::vote(&e, &voter, proposal_id, approve);
+    pub fn get_fee_schedule(e: Env) -> Vec<(i128, u32)> {
+        fees::get_schedule(&e)
     }
 
-    pub fn governance_delegate(e: Env, governor: Address, to: Address) {
-        governance_approval::delegate(&e, &governor, &to);
+    /// Fee and net amount a bond of `amount` would be charged now: `(fee, net)`. Does not
+    /// account for fee exemptions.
+    pub fn preview_fee(e: Env, amount: i128) -> (i128, i128) {
+        fees::quote(&e, amount)
     }
 
-    pub fn execute_slash_with_governance(
+    /// Exempt `identity` from (or make it pay again) the bond creation fee. Admin only.
+    pub fn set_fee_exempt(
         e: Env,
-        proposer: Address,
-        proposal_id: u64,
-    ) -> IdentityBond {
-        proposer.require_auth();
-        let proposal = governance_approval::get_proposal(&e, proposal_id)
-            .unwrap_or_else(|| panic!("proposal not found"));
-        if proposal.proposed_by != proposer {
-            panic!("only proposer can execute");
-        }
-        let executed = governance_approval::execute_slash_if_approved(&e, proposal_id);
-        if !executed {
-            panic!("proposal not approved");
-        }
-        slashing::slash_bond(&e, &proposer, proposal.amount)
+        admin: Address,
+        identity: Address,
+        exempt: bool,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        fees::set_exempt(&e, &identity, exempt);
+        Ok(())
+    }
+
+    pub fn is_fee_exempt(e: Env, identity: Address) -> bool {
+        fees::is_exempt(&e, &identity)
+    }
+
+    /// Cap sensitive admin actions (token change, fee change above `fee_delta_bps`, attester
+    /// unregistration, this setting) at `max_actions` per rolling `window`. Admin only.
+    pub fn set_admin_rate_limit(
+        e: Env,
+        admin: Address,
+        limit: admin_actions::AdminRateLimit,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        admin_actions::set_rate_limit(&e, &admin, limit);
+        Ok(())
+    }
+
+    pub fn get_admin_rate_limit(e: Env) -> admin_actions::AdminRateLimit {
+        admin_actions::get_rate_limit(&e)
+    }
+
+    /// Logged sensitive admin actions oldest first, paginated (`limit` capped at 50).
+    pub fn get_admin_actions(e: Env, offset: u64, limit: u32) -> Vec<admin_actions::AdminAction> {
+        admin_actions::list(&e, offset, limit)
     }
 
-    pub fn set_fee_config(e: Env, admin: Address, treasury: Address, fee_bps: u32) {
-        Self::require_admin(&e, &admin);
-        fees::set_config(&e, treasury, fee_bps);
+    pub fn get_admin_action_count(e: Env) -> u64 {
+        admin_actions::count(&e)
+    }
+
+    /// Enabled optional subsystems as a bitmask (see `features::FEATURE_*`).
+    /// Complete current configuration, for diffing deployments.
+    pub fn export_config(e: Env) -> config_export::BondConfig {
+        config_export::export(&e)
+    }
+
+    /// Codes of violated internal invariants (see `invariants::INV_*`); empty if consistent.
+    pub fn check_invariants(e: Env) -> Vec<u32> {
+        invariants::check(&e)
+    }
+
+    pub fn get_features(e: Env) -> u32 {
+        features::get_features(&e)
+    }
+
+    /// Replace the enabled feature mask. Admin only.
+    pub fn set_features(e: Env, admin: Address, features: u32) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        features::set_features(&e, features);
+        Ok(())
     }
 
     pub fn get_fee_config(e: Env) -> (Option<Address>, u32) {
         fees::get_config(&e)
     }
 
-    pub fn collect_fees(e: Env, admin: Address) -> i128 {
-        Self::require_admin(&e, &admin);
-        let key = Symbol::new(&e, "fees");
-        let collected: i128 = e.storage().instance().get(&key).unwrap_or(0);
-        e.storage().instance().set(&key, &0_i128);
-        collected
+    /// Drain the fee pool (creation fees and spent query credit) and return the amount. With
+    /// a bond token set it is transferred to the fee treasury. Admin only.
+    pub fn collect_fees(e: Env, admin: Address) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        Self::require_admin(&e, &admin)?;
+        Ok(Self::with_reentrancy_guard(&e, || fees::collect(&e)))
+    }
+
+    /// Release the early-exit penalties reserved so far and return them.
+    /// With a bond token set it is transferred to the early-exit treasury. Admin only.
+    pub fn collect_penalties(e: Env, admin: Address) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        Self::require_admin(&e, &admin)?;
+        Ok(reserves::collect_penalties(&e))
+    }
+
+    /// Balance the contract must keep for recorded obligations: pending fees, pending
+    /// penalties and escrowed claims. Withdrawals fail if they would leave the bond token
+    /// balance below it.
+    pub fn get_reserved_balance(e: Env) -> i128 {
+        reserves::total(&e)
     }
 
-    pub fn deposit_fees(e: Env, amount: i128) {
-        let key = Symbol::new(&e, "fees");
-        let current: i128 = e.storage().instance().get(&key).unwrap_or(0);
-        let next = current.checked_add(amount).expect("fee pool overflow");
-        e.storage().instance().set(&key, &next);
+    /// Add `amount` to the fee pool. Admin only; with a bond token set the admin pays the
+    /// amount in. Fails with `InvalidAmount` unless `amount` is positive.
+    pub fn deposit_fees(e: Env, admin: Address, amount: i128) -> Result<(), BondError> {
+        pausable::require_not_paused(&e);
+        Self::require_admin(&e, &admin)?;
+        fees::deposit(&e, &admin, amount);
+        Ok(())
     }
 
-    pub fn set_callback(e: Env, callback: Address) {
+    pub fn set_callback(e: Env, callback: Address) -> Result<(), BondError> {
         e.storage()
             .instance()
             .set(&Self::callback_key(&e), &callback);
+        Ok(())
     }
 
     pub fn is_locked(e: Env) -> bool {
@@ -640,34 +3629,98 @@ impl CredenceBond {
             .unwrap_or(false)
     }
 
-    pub fn withdraw_bond(e: Env, identity: Address) -> i128 {
+    /// Withdraw the whole unslashed bond and deactivate it. Requires the identity's auth.
+    /// A rolling bond needs a withdrawal request whose notice has elapsed; a fixed bond
+    /// leaving before its lock-up ends pays the early-exit penalty. Returns the amount paid.
+    pub fn withdraw_bond(e: Env, identity: Address) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        if unbond_cooldown::is_enabled(&e) || unbonding_queue::is_configured(&e) {
+            return Err(BondError::WithdrawalNotRequested);
+        }
         let key = DataKey::Bond;
         Self::with_reentrancy_guard(&e, || {
-            let mut bond: IdentityBond = e
-                .storage()
-                .instance()
-                .get(&key)
-                .unwrap_or_else(|| panic!("no bond"));
+            let mut bond: IdentityBond =
+                e.storage().instance().get(&key).ok_or(BondError::NoBond)?;
             if bond.identity != identity {
-                panic!("not bond identity");
+                return Err(BondError::NoBond);
             }
+            if sub_bonds::total(&e, &identity) > 0 {
+                return Err(BondError::InsufficientBalance);
+            }
+            let now = e.ledger().timestamp();
+            if bond.is_rolling {
+                if bond.withdrawal_requested_at == 0 {
+                    return Err(BondError::WithdrawalNotRequested);
+                }
+                if !time_math::notice_elapsed(
+                    now,
+                    bond.withdrawal_requested_at,
+                    bond.notice_period_duration,
+                ) {
+                    return Err(BondError::NoticeNotElapsed);
+                }
+            }
+            let early = !bond.is_rolling
+                && !time_math::is_period_ended(now, bond.bond_start, bond.bond_duration);
 
             let amount = bond
                 .bonded_amount
                 .checked_sub(bond.slashed_amount)
-                .expect("slashed amount exceeds bonded amount");
+                .ok_or(BondError::InsufficientBalance)?;
             bond.bonded_amount = 0;
             bond.active = false;
+            reward_accrual::checkpoint(&e);
             e.storage().instance().set(&key, &bond);
-            amount
+            verification_snapshot::refresh_bond(&e);
+            let penalty = if early {
+                Self::charge_early_exit_penalty(&e, &bond, amount, now)
+            } else {
+                0
+            };
+            let paid = amount.saturating_sub(penalty);
+            funding::release(&e, &identity, paid);
+            Ok(paid)
         })
     }
 
-    pub fn slash_bond(e: Env, admin: Address, amount: i128) -> i128 {
+    /// Close `identity`'s bond once nothing is left in it: the bonded amount is fully withdrawn
+    /// or slashed and no withdrawal is queued. The bond is marked inactive and its rolling
+    /// state cleared, so a later `create_bond*` starts from a clean slate.
+    pub fn close_bond(e: Env, identity: Address) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        Self::require_active_bond_for(&e, &identity)?;
+        let key = DataKey::Bond;
+        let mut bond: IdentityBond = e.storage().instance().get(&key).ok_or(BondError::NoBond)?;
+        if unbonding_queue::tvl(&bond) != 0 || unbond_cooldown::reserved(&e) != 0 {
+            return Err(BondError::InvalidAmount);
+        }
+
+        bond.active = false;
+        bond.is_rolling = false;
+        bond.withdrawal_requested_at = 0;
+        bond.notice_period_duration = 0;
+        e.storage().instance().set(&key, &bond);
+        rolling_bond::reset_renewal_count(&e);
+        rolling_bond::clear_auto_exit(&e);
+        rolling_bond::clear_period(&e);
+        verification_snapshot::refresh_bond(&e);
+        health_factor::clear(&e, &identity);
+        e.events().publish(
+            (Symbol::new(&e, "bond_closed"), identity),
+            (bond.bonded_amount, bond.slashed_amount),
+        );
+        Ok(bond)
+    }
+
+    pub fn slash_bond(e: Env, admin: Address, amount: i128) -> Result<i128, BondError> {
+        pausable::require_not_paused(&e);
         Self::with_reentrancy_guard(&e, || {
             let before = Self::get_identity_state(e.clone()).slashed_amount;
             let after = slashing::slash_bond(&e, &admin, amount).slashed_amount;
-            after.checked_sub(before).expect("slashing delta underflow")
+            slash_settlement::settle_if_auto(&e);
+            after.checked_sub(before).ok_or(BondError::Overflow)
         })
     }
 
@@ -682,6 +3735,36 @@ impl CredenceBond {
         governance_approval::get_vote(&e, proposal_id, &voter)
     }
 
+    /// Open slash proposals in creation order, paginated (`limit` capped at 50).
+    pub fn list_open_proposals(
+        e: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<governance_approval::SlashProposal> {
+        governance_approval::list_open(&e, offset, limit)
+    }
+
+    pub fn get_open_proposal_count(e: Env) -> u32 {
+        governance_approval::open_count(&e)
+    }
+
+    /// Vote tally and quorum progress of a proposal.
+    pub fn get_proposal_summary(
+        e: Env,
+        proposal_id: u64,
+    ) -> Option<governance_approval::ProposalSummary> {
+        governance_approval::summary(&e, proposal_id)
+    }
+
+    /// Each current governor with the vote recorded for it (cast by itself or its delegate),
+    /// or `None` for an unknown proposal.
+    pub fn get_proposal_votes(
+        e: Env,
+        proposal_id: u64,
+    ) -> Option<Vec<governance_approval::VoteReceipt>> {
+        governance_approval::votes(&e, proposal_id)
+    }
+
     pub fn get_governors(e: Env) -> Vec<Address> {
         governance_approval::get_governors(&e)
     }
@@ -694,46 +3777,282 @@ impl CredenceBond {
         governance_approval::get_quorum_config(&e)
     }
 
-    pub fn top_up(e: Env, amount: i128) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond: IdentityBond = e
+    /// Health factor for the identity's bond: slashed ratio, open slash proposals and
+    /// dispute exposure combined into basis points with a Healthy/AtRisk/Critical status.
+    pub fn get_health_factor(e: Env, identity: Address) -> health_factor::HealthFactor {
+        let bond = Self::get_identity_state(e.clone());
+        if bond.identity != identity {
+            panic_with_error!(e, BondError::NoBond);
+        }
+        health_factor::compute(&e, &bond)
+    }
+
+    /// One-call view of the identity's bond for integrators: available balance, slashed
+    /// ratio, remaining lock-up, tier, rolling and withdrawal state, and whether the bond
+    /// covers `required_amount`.
+    pub fn get_bond_health(
+        e: Env,
+        identity: Address,
+        required_amount: i128,
+    ) -> Result<bond_health::BondHealth, BondError> {
+        let bond: IdentityBond = e
             .storage()
             .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("no bond"));
+            .get(&DataKey::Bond)
+            .ok_or(BondError::NoBond)?;
+        if bond.identity != identity {
+            return Err(BondError::NoBond);
+        }
+        Ok(bond_health::compute(&e, &bond, required_amount))
+    }
+
+    /// Recompute health and emit `health_status_changed` if a threshold was crossed.
+    /// Callable by anyone (e.g. monitoring keepers).
+    pub fn check_health(e: Env) -> health_factor::HealthFactor {
+        let bond = Self::get_identity_state(e.clone());
+        health_factor::refresh(&e);
+        health_factor::compute(&e, &bond)
+    }
+
+    pub fn set_health_thresholds(
+        e: Env,
+        admin: Address,
+        at_risk_bps: u32,
+        critical_bps: u32,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        health_factor::set_thresholds(&e, at_risk_bps, critical_bps);
+        health_factor::refresh(&e);
+        Ok(())
+    }
+
+    pub fn get_health_thresholds(e: Env) -> (u32, u32) {
+        health_factor::get_thresholds(&e)
+    }
+
+    /// Publish where off-chain notifiers should send alerts for the bond (an opaque hash or
+    /// URI pointer) and which `notification_prefs::TOPIC_*` alerts are wanted. Bond
+    /// identity only.
+    pub fn set_notification_prefs(
+        e: Env,
+        identity: Address,
+        endpoint: Bytes,
+        topics: u32,
+    ) -> Result<(), BondError> {
+        identity.require_auth();
+        Self::require_bond_identity(&e, &identity)?;
+        notification_prefs::set(&e, &identity, endpoint, topics);
+        Ok(())
+    }
+
+    /// Remove the bond's notification preferences. Bond identity only.
+    pub fn clear_notification_prefs(e: Env, identity: Address) -> Result<(), BondError> {
+        identity.require_auth();
+        Self::require_bond_identity(&e, &identity)?;
+        notification_prefs::clear(&e, &identity);
+        Ok(())
+    }
+
+    pub fn get_notification_prefs(
+        e: Env,
+        identity: Address,
+    ) -> Option<notification_prefs::NotificationPrefs> {
+        notification_prefs::get(&e, &identity)
+    }
+
+    fn require_bond_identity(e: &Env, identity: &Address) -> Result<(), BondError> {
+        let bond: IdentityBond = e
+            .storage()
+            .instance()
+            .get(&DataKey::Bond)
+            .ok_or(BondError::NoBond)?;
+        if bond.identity != *identity {
+            return Err(BondError::NoBond);
+        }
+        Ok(())
+    }
+
+    /// Report the amount of an identity's bond exposed to open disputes. Admin only.
+    pub fn set_dispute_exposure(
+        e: Env,
+        admin: Address,
+        identity: Address,
+        amount: i128,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        features::require_enabled(&e, features::FEATURE_DISPUTES);
+        health_factor::set_dispute_exposure(&e, &identity, amount);
+        health_factor::refresh(&e);
+        Ok(())
+    }
+
+    /// Link a dispute opened against an identity's bond. Admin only.
+    pub fn link_dispute(
+        e: Env,
+        admin: Address,
+        identity: Address,
+        dispute_id: u64,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        features::require_enabled(&e, features::FEATURE_DISPUTES);
+        dispute_linkage::link(&e, &identity, dispute_id);
+        Ok(())
+    }
+
+    /// Record the outcome of a linked dispute and unlink it. Admin only.
+    pub fn resolve_linked_dispute(
+        e: Env,
+        admin: Address,
+        identity: Address,
+        dispute_id: u64,
+        outcome: dispute_linkage::LinkedDisputeOutcome,
+    ) -> Result<(), BondError> {
+        Self::require_admin(&e, &admin)?;
+        dispute_linkage::resolve(&e, &identity, dispute_id, outcome);
+        Ok(())
+    }
+
+    /// Open disputes and last dispute outcome for an identity.
+    pub fn get_dispute_status(e: Env, identity: Address) -> dispute_linkage::BondDisputeStatus {
+        dispute_linkage::status(&e, &identity)
+    }
+
+    /// Keep `identity`'s unslashed balance at `target_tier`: when a slash or partial
+    /// withdrawal drops it below the tier threshold, the shortfall is pulled from the
+    /// identity's bond token allowance, up to `max_amount` in total.
+    pub fn enable_auto_topup(
+        e: Env,
+        identity: Address,
+        target_tier: BondTier,
+        max_amount: i128,
+    ) -> Result<auto_topup::AutoTopUp, BondError> {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        Self::require_active_bond_for(&e, &identity)?;
+        Ok(auto_topup::enable(&e, &identity, target_tier, max_amount))
+    }
+
+    pub fn disable_auto_topup(e: Env, identity: Address) -> Result<(), BondError> {
+        identity.require_auth();
+        auto_topup::disable(&e, &identity);
+        Ok(())
+    }
 
-        let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
+    pub fn get_auto_topup(e: Env, identity: Address) -> Option<auto_topup::AutoTopUp> {
+        auto_topup::get(&e, &identity)
+    }
+
+    pub fn top_up(e: Env, amount: i128) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        Self::apply_top_up(&e, amount)
+    }
+
+    /// Credit bond token transferred directly to the contract to `identity`'s bond, for
+    /// wallets that cannot sign an inline transfer or an approve. `amount` must not exceed
+    /// the unclaimed deposits. Requires the identity's auth.
+    pub fn claim_deposit(
+        e: Env,
+        identity: Address,
+        amount: i128,
+    ) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        Self::require_active_bond_for(&e, &identity)?;
+        funding::require_claimable(&e, amount);
+        let bond = Self::apply_top_up(&e, amount)?;
+        e.events()
+            .publish((Symbol::new(&e, "deposit_claimed"), identity), amount);
+        Ok(bond)
+    }
+
+    /// Bond token held beyond the bond, unsettled slash proceeds and reserves, claimable with
+    /// `claim_deposit`.
+    pub fn get_unclaimed_deposits(e: Env) -> i128 {
+        funding::unclaimed_deposits(&e)
+    }
+
+    fn apply_top_up(e: &Env, amount: i128) -> Result<IdentityBond, BondError> {
+        let key = DataKey::Bond;
+        let mut bond: IdentityBond = e.storage().instance().get(&key).ok_or(BondError::NoBond)?;
+        if amount <= 0 {
+            return Err(BondError::InvalidAmount);
+        }
+
+        let old_tier = tiered_bond::get_tier_for_amount(e, bond.bonded_amount);
         bond.bonded_amount = bond
             .bonded_amount
             .checked_add(amount)
-            .expect("top-up caused overflow");
-        let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
+            .ok_or(BondError::Overflow)?;
+        bond_limits::require_amount(e, bond.bonded_amount);
+        let new_tier = tiered_bond::get_tier_for_amount(e, bond.bonded_amount);
 
+        reward_accrual::checkpoint(e);
         e.storage().instance().set(&key, &bond);
-        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
-        bond
+        verification_snapshot::refresh_bond(e);
+        tiered_bond::emit_tier_change_if_needed(e, &bond.identity, old_tier, new_tier);
+        health_factor::refresh(e);
+        Ok(bond)
+    }
+
+    /// Split `amount` of the bond into a sub-bond locked for `new_duration` from now. The
+    /// amount stays bonded (tier, slashing) but is held back from withdrawals until merged.
+    /// Not available for rolling bonds. Requires the identity's auth.
+    pub fn split_bond(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        new_duration: u64,
+    ) -> Result<sub_bonds::SubBond, BondError> {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        Self::require_active_bond_for(&e, &identity)?;
+        let bond = Self::get_identity_state(e.clone());
+        Ok(sub_bonds::split(&e, &bond, amount, new_duration))
+    }
+
+    /// Merge sub-bonds back into the bond. The bond's lock-up is extended to the latest
+    /// merged sub-bond end if that is later. Requires the identity's auth.
+    pub fn merge_bonds(
+        e: Env,
+        identity: Address,
+        sub_bond_ids: Vec<u32>,
+    ) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        Self::require_active_bond_for(&e, &identity)?;
+        let mut bond = Self::get_identity_state(e.clone());
+        sub_bonds::merge(&e, &mut bond, &sub_bond_ids);
+        e.storage().instance().set(&DataKey::Bond, &bond);
+        Ok(bond)
+    }
+
+    pub fn get_sub_bond(e: Env, identity: Address, sub_bond_id: u32) -> Option<sub_bonds::SubBond> {
+        sub_bonds::get(&e, &identity, sub_bond_id)
+    }
+
+    /// Sub-bonds of `identity`, oldest first.
+    pub fn get_sub_bonds(e: Env, identity: Address) -> Vec<sub_bonds::SubBond> {
+        sub_bonds::list(&e, &identity)
     }
 
-    pub fn extend_duration(e: Env, additional_duration: u64) -> IdentityBond {
+    pub fn extend_duration(e: Env, additional_duration: u64) -> Result<IdentityBond, BondError> {
+        pausable::require_not_paused(&e);
         let key = DataKey::Bond;
-        let mut bond: IdentityBond = e
-            .storage()
-            .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("no bond"));
+        let mut bond: IdentityBond = e.storage().instance().get(&key).ok_or(BondError::NoBond)?;
 
         bond.bond_duration = bond
             .bond_duration
             .checked_add(additional_duration)
-            .expect("duration extension caused overflow");
+            .ok_or(BondError::Overflow)?;
+        bond_limits::require_duration(&e, bond.bond_duration);
 
         let _end_timestamp = bond
             .bond_start
             .checked_add(bond.bond_duration)
-            .expect("bond end timestamp would overflow");
+            .ok_or(BondError::Overflow)?;
 
         e.storage().instance().set(&key, &bond);
-        bond
+        Ok(bond)
     }
 }
 
@@ -778,3 +4097,190 @@ mod test_slashing;
 
 #[cfg(test)]
 mod test_withdraw_bond;
+
+#[cfg(test)]
+mod test_health_factor;
+
+#[cfg(test)]
+mod test_attestation_template;
+
+#[cfg(test)]
+mod test_features;
+
+#[cfg(test)]
+mod test_compensation;
+
+#[cfg(test)]
+mod test_unbonding_queue;
+
+#[cfg(test)]
+mod test_testutils;
+
+#[cfg(test)]
+mod test_attester_org;
+
+#[cfg(test)]
+mod test_read_grant;
+
+#[cfg(test)]
+mod test_config_export;
+
+#[cfg(test)]
+mod test_invariants;
+
+#[cfg(test)]
+mod test_probation;
+
+#[cfg(test)]
+mod test_activity_feed;
+
+#[cfg(test)]
+#[cfg(test)]
+mod test_token_info;
+
+#[cfg(test)]
+mod test_attester_suspension;
+
+#[cfg(test)]
+mod test_slash_records;
+
+#[cfg(test)]
+mod test_referral;
+
+#[cfg(test)]
+mod test_verification_snapshot;
+
+#[cfg(test)]
+mod test_funding;
+
+#[cfg(test)]
+mod test_time_math;
+
+#[cfg(test)]
+mod test_dedup_policy;
+
+#[cfg(test)]
+mod test_dispute_linkage;
+
+#[cfg(test)]
+mod test_attester_onboarding;
+
+#[cfg(test)]
+mod test_challenge_period;
+
+#[cfg(test)]
+mod test_credential_proof;
+
+#[cfg(test)]
+mod test_proposer_age;
+
+#[cfg(test)]
+mod test_token_adaptor;
+
+#[cfg(test)]
+mod test_counter_attestation;
+
+#[cfg(test)]
+mod test_attestation_archive;
+
+#[cfg(test)]
+mod test_notification_prefs;
+
+#[cfg(test)]
+mod test_admin_actions;
+
+#[cfg(test)]
+mod test_score_breakdown;
+
+#[cfg(test)]
+mod test_schema_version;
+
+#[cfg(test)]
+mod test_revocation_registry;
+
+#[cfg(test)]
+mod test_token_recovery;
+
+#[cfg(test)]
+mod test_address_book;
+
+#[cfg(test)]
+mod test_reserves;
+
+#[cfg(test)]
+mod test_slash_reversal;
+
+#[cfg(test)]
+mod test_sybil_heuristics;
+
+#[cfg(test)]
+mod test_attester_deregistration;
+
+#[cfg(test)]
+mod test_error;
+
+#[cfg(test)]
+mod test_admin_transfer;
+
+#[cfg(test)]
+mod test_pausable;
+
+#[cfg(test)]
+mod test_upgrade;
+
+#[cfg(test)]
+mod test_slash_settlement;
+
+#[cfg(test)]
+mod test_slash_appeal;
+
+#[cfg(test)]
+mod test_reputation;
+
+#[cfg(test)]
+mod test_bond_limits;
+
+#[cfg(test)]
+mod test_close_bond;
+
+#[cfg(test)]
+mod test_attestation_hash;
+
+#[cfg(test)]
+mod test_delegated_attestation;
+
+#[cfg(test)]
+mod test_attestation_objection;
+
+#[cfg(test)]
+mod test_slash_history;
+
+#[cfg(test)]
+mod test_unbond_cooldown;
+
+#[cfg(test)]
+mod test_reward_accrual;
+
+#[cfg(test)]
+mod test_auto_topup;
+
+#[cfg(test)]
+mod test_governance_actions;
+
+#[cfg(test)]
+mod test_attester_directory;
+
+#[cfg(test)]
+mod test_attestation_summary;
+
+#[cfg(test)]
+mod test_attestation_limits;
+
+#[cfg(test)]
+mod test_bond_health;
+
+#[cfg(test)]
+mod test_sub_bonds;
+
+#[cfg(test)]
+mod test_query_access;
@@ -3,10 +3,23 @@
 //! Each identity has a nonce that must be included in state-changing calls.
 //! The contract rejects replayed transactions by requiring nonce to match
 //! the stored value, then incrementing it. Handles nonce overflow by wrapping.
+//!
+//! Attesters that registered an ed25519 public key can also authorize an attestation
+//! off-chain by signing `(contract, attester, subject, data_hash, nonce, expiry)` (XDR
+//! encoded). Anyone, e.g. a relayer paying the fees, can then submit it; the signature
+//! replaces the attester's auth and the attester's nonce is consumed as usual.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, BytesN, Env, String};
 
-use soroban_sdk::Env;
+use crate::{BondError, DataKey};
 
-use crate::DataKey;
+/// Storage keys of signed authorizations.
+#[contracttype]
+pub enum NonceKey {
+    /// ed25519 public key an attester signs attestations with.
+    AttesterSigningKey(Address),
+}
 
 /// Returns the current nonce for an identity. Caller must use this value in the next state-changing call.
 ///
@@ -28,10 +41,79 @@ pub fn get_nonce(e: &Env, identity: &soroban_sdk::Address) -> u64 {
 pub fn consume_nonce(e: &Env, identity: &soroban_sdk::Address, expected_nonce: u64) {
     let current = get_nonce(e, identity);
     if current != expected_nonce {
-        panic!("invalid nonce: replay or out-of-order");
+        panic_with_error!(e, BondError::InvalidNonce);
     }
-    let next = current.checked_add(1).expect("nonce overflow");
+    let next = current
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
     e.storage()
         .instance()
         .set(&DataKey::Nonce(identity.clone()), &next);
 }
+
+/// ed25519 public key registered by `attester`, if any.
+#[must_use]
+pub fn get_signing_key(e: &Env, attester: &Address) -> Option<BytesN<32>> {
+    e.storage()
+        .instance()
+        .get(&NonceKey::AttesterSigningKey(attester.clone()))
+}
+
+/// Register (or rotate) the ed25519 public key of `attester`. Caller must enforce the
+/// attester's auth.
+pub fn set_signing_key(e: &Env, attester: &Address, public_key: &BytesN<32>) {
+    e.storage()
+        .instance()
+        .set(&NonceKey::AttesterSigningKey(attester.clone()), public_key);
+}
+
+/// Hash a signed attestation commits to: SHA-256 of the XDR-encoded `attestation_data`.
+#[must_use]
+pub fn data_hash(e: &Env, attestation_data: &String) -> BytesN<32> {
+    e.crypto()
+        .sha256(&attestation_data.clone().to_xdr(e))
+        .into()
+}
+
+/// Message an attester signs to authorize an attestation: the XDR encoding of
+/// `(contract, attester, subject, data_hash, nonce, expiry)`.
+#[must_use]
+pub fn signed_payload(
+    e: &Env,
+    attester: &Address,
+    subject: &Address,
+    data_hash: &BytesN<32>,
+    nonce: u64,
+    expiry: u64,
+) -> Bytes {
+    (
+        e.current_contract_address(),
+        attester.clone(),
+        subject.clone(),
+        data_hash.clone(),
+        nonce,
+        expiry,
+    )
+        .to_xdr(e)
+}
+
+/// Check an attester's signature over the payload. Panics with `ConfigNotSet` if the
+/// attester has no signing key, `InvalidNonce` once `expiry` has passed, and with a host
+/// error if the signature is invalid. The nonce itself is consumed by the caller.
+pub fn verify_signed(
+    e: &Env,
+    attester: &Address,
+    subject: &Address,
+    data_hash: &BytesN<32>,
+    nonce: u64,
+    expiry: u64,
+    signature: &BytesN<64>,
+) {
+    let public_key = get_signing_key(e, attester)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::ConfigNotSet));
+    if e.ledger().timestamp() > expiry {
+        panic_with_error!(e, BondError::InvalidNonce);
+    }
+    let payload = signed_payload(e, attester, subject, data_hash, nonce, expiry);
+    e.crypto().ed25519_verify(&public_key, &payload, signature);
+}
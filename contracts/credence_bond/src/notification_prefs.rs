@@ -0,0 +1,88 @@
+//! Notification Preferences
+//!
+//! Lets the bond identity publish an opaque notification endpoint commitment (a hash or a
+//! short URI pointer) and the alert topics it wants, so off-chain notifier services can
+//! discover where to send renewal, slash and dispute alerts without a separate registry.
+//! The contract never interprets the endpoint.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, Env, Symbol};
+
+use crate::BondError;
+
+/// Renewal of the bond is due.
+pub const TOPIC_RENEWAL_DUE: u32 = 1;
+/// A slash was proposed against the bond.
+pub const TOPIC_SLASH_PROPOSED: u32 = 1 << 1;
+/// A dispute was opened against the bond.
+pub const TOPIC_DISPUTE_OPENED: u32 = 1 << 2;
+/// All known topics.
+pub const ALL_TOPICS: u32 = TOPIC_RENEWAL_DUE | TOPIC_SLASH_PROPOSED | TOPIC_DISPUTE_OPENED;
+
+/// Longest accepted endpoint commitment in bytes.
+pub const MAX_ENDPOINT_LEN: u32 = 256;
+
+/// Notification preferences of a bond identity.
+///
+/// * `endpoint` - Opaque commitment or pointer to the notification endpoint.
+/// * `topics` - Bitmask of `TOPIC_*` flags the identity wants alerts for.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationPrefs {
+    pub endpoint: Bytes,
+    pub topics: u32,
+    pub updated_at: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum NotificationKey {
+    Prefs(Address),
+}
+
+#[must_use]
+pub fn get(e: &Env, identity: &Address) -> Option<NotificationPrefs> {
+    e.storage()
+        .instance()
+        .get(&NotificationKey::Prefs(identity.clone()))
+}
+
+/// Store `identity`'s preferences. Caller must enforce identity auth.
+pub fn set(e: &Env, identity: &Address, endpoint: Bytes, topics: u32) {
+    if endpoint.is_empty() {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    if endpoint.len() > MAX_ENDPOINT_LEN {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    if topics & !ALL_TOPICS != 0 {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    let prefs = NotificationPrefs {
+        endpoint,
+        topics,
+        updated_at: e.ledger().timestamp(),
+    };
+    e.storage()
+        .instance()
+        .set(&NotificationKey::Prefs(identity.clone()), &prefs);
+    e.events().publish(
+        (Symbol::new(e, "notification_prefs_set"), identity.clone()),
+        (prefs.endpoint, prefs.topics),
+    );
+}
+
+/// Remove `identity`'s preferences. Caller must enforce identity auth.
+pub fn clear(e: &Env, identity: &Address) {
+    let key = NotificationKey::Prefs(identity.clone());
+    if !e.storage().instance().has(&key) {
+        panic_with_error!(e, BondError::ConfigNotSet);
+    }
+    e.storage().instance().remove(&key);
+    e.events().publish(
+        (
+            Symbol::new(e, "notification_prefs_cleared"),
+            identity.clone(),
+        ),
+        (),
+    );
+}
@@ -0,0 +1,46 @@
+//! Emergency Stop
+//!
+//! Circuit breaker for operators who discover a token or governance bug. While paused, the
+//! contract refuses everything that moves value or changes bond and attestation state:
+//! bond creation, top-ups and renewals, withdrawals and unbonding, slashing, attestation
+//! issuance and revocation, claims, and fee deposits and collection. Admin configuration,
+//! governance votes and all reads stay available, so the admin can fix the configuration
+//! before unpausing.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::BondError;
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum PauseKey {
+    /// True while the contract is paused.
+    Paused,
+}
+
+/// True while the contract is paused.
+#[must_use]
+pub fn is_paused(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&PauseKey::Paused)
+        .unwrap_or(false)
+}
+
+/// Pause or unpause, emitting `paused` / `unpaused` with the admin as data. Setting the
+/// current state again is a no-op. Admin only (enforced by caller).
+pub fn set_paused(e: &Env, admin: &Address, paused: bool) {
+    if is_paused(e) == paused {
+        return;
+    }
+    e.storage().instance().set(&PauseKey::Paused, &paused);
+    let name = if paused { "paused" } else { "unpaused" };
+    e.events().publish((Symbol::new(e, name),), admin.clone());
+}
+
+/// Panics with `Paused` while the contract is paused.
+pub fn require_not_paused(e: &Env) {
+    if is_paused(e) {
+        panic_with_error!(e, BondError::Paused);
+    }
+}
@@ -0,0 +1,125 @@
+//! Re-bonding Probation
+//!
+//! An identity whose bond was fully slashed is not locked out forever, but must earn trust
+//! back: it can only re-bond after a cooldown, with at least a raised minimum amount, and the
+//! new bond carries a probation flag that relying contracts can read until it lapses.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{BondError, DataKey};
+
+/// Probation policy applied to identities re-bonding after a full slash.
+///
+/// # Fields
+/// * `cooldown` - Seconds after the full slash before the identity may re-bond.
+/// * `min_amount` - Minimum amount for the re-bond.
+/// * `duration` - Seconds the probation flag stays set after re-bonding.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProbationConfig {
+    pub cooldown: u64,
+    pub min_amount: i128,
+    pub duration: u64,
+}
+
+/// Returns the probation policy (all zero if never set).
+#[must_use]
+pub fn get_config(e: &Env) -> ProbationConfig {
+    e.storage()
+        .instance()
+        .get(&DataKey::ProbationConfig)
+        .unwrap_or(ProbationConfig {
+            cooldown: 0,
+            min_amount: 0,
+            duration: 0,
+        })
+}
+
+/// Set the probation policy. Admin only (enforced by caller).
+pub fn set_config(e: &Env, cooldown: u64, min_amount: i128, duration: u64) {
+    if min_amount < 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    if crate::token_info::is_below_whole_unit(e, min_amount) {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage().instance().set(
+        &DataKey::ProbationConfig,
+        &ProbationConfig {
+            cooldown,
+            min_amount,
+            duration,
+        },
+    );
+}
+
+/// Timestamp of the identity's unresolved full slash, if any.
+#[must_use]
+pub fn get_full_slash_at(e: &Env, identity: &Address) -> Option<u64> {
+    e.storage()
+        .instance()
+        .get(&DataKey::FullSlashAt(identity.clone()))
+}
+
+/// Timestamp until which the identity is on probation (0 if never).
+#[must_use]
+pub fn get_probation_until(e: &Env, identity: &Address) -> u64 {
+    e.storage()
+        .instance()
+        .get(&DataKey::ProbationUntil(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// True while the identity's probation period is running.
+#[must_use]
+pub fn is_on_probation(e: &Env, identity: &Address) -> bool {
+    e.ledger().timestamp() < get_probation_until(e, identity)
+}
+
+/// Record that the identity's bond was fully slashed (keeps the first timestamp).
+pub fn record_full_slash(e: &Env, identity: &Address) {
+    let key = DataKey::FullSlashAt(identity.clone());
+    if e.storage().instance().has(&key) {
+        return;
+    }
+    let now = e.ledger().timestamp();
+    e.storage().instance().set(&key, &now);
+    e.events().publish(
+        (Symbol::new(e, "identity_fully_slashed"), identity.clone()),
+        now,
+    );
+}
+
+/// Clear the full-slash record (e.g. after the slash was partially reverted).
+pub fn clear_full_slash(e: &Env, identity: &Address) {
+    e.storage()
+        .instance()
+        .remove(&DataKey::FullSlashAt(identity.clone()));
+}
+
+/// Enforce the probation policy for a new bond of `amount`. If the identity was fully
+/// slashed, checks cooldown and minimum amount, then starts the probation period.
+pub fn check_rebond(e: &Env, identity: &Address, amount: i128) {
+    let Some(slashed_at) = get_full_slash_at(e, identity) else {
+        return;
+    };
+    let config = get_config(e);
+    let now = e.ledger().timestamp();
+    if now < slashed_at.saturating_add(config.cooldown) {
+        panic_with_error!(e, BondError::NoticeNotElapsed);
+    }
+    if amount < config.min_amount {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    clear_full_slash(e, identity);
+    let until = now
+        .checked_add(config.duration)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&DataKey::ProbationUntil(identity.clone()), &until);
+    e.events().publish(
+        (Symbol::new(e, "probation_started"), identity.clone()),
+        until,
+    );
+}
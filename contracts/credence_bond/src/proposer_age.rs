@@ -0,0 +1,93 @@
+//! Proposer Age Gate
+//!
+//! Raises the cost of flash capture of slash governance: a non-admin governor may only
+//! propose once it has held an active bond or its governor seat for a minimum period. The
+//! minimum is set at governance initialization; 0 disables the gate.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{DataKey, IdentityBond};
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum ProposerAgeKey {
+    /// Minimum age (seconds) of a bond or governor seat to propose.
+    MinAge,
+    /// Timestamp the current bond was first created at (renewals keep it).
+    BondSince,
+    /// Timestamp a governor got its seat.
+    GovernorSince(Address),
+}
+
+/// Minimum proposer age in seconds (0 if never set).
+#[must_use]
+pub fn get_min_age(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&ProposerAgeKey::MinAge)
+        .unwrap_or(0)
+}
+
+/// Set the minimum proposer age. Admin only (enforced by caller).
+pub fn set_min_age(e: &Env, seconds: u64) {
+    e.storage()
+        .instance()
+        .set(&ProposerAgeKey::MinAge, &seconds);
+}
+
+/// Record the creation time of a new bond.
+pub fn record_bond_start(e: &Env, now: u64) {
+    e.storage().instance().set(&ProposerAgeKey::BondSince, &now);
+}
+
+/// Record seat start for each governor that does not have one yet.
+pub fn record_governor_seats(e: &Env, governors: &Vec<Address>, now: u64) {
+    for governor in governors.iter() {
+        let key = ProposerAgeKey::GovernorSince(governor);
+        if !e.storage().instance().has(&key) {
+            e.storage().instance().set(&key, &now);
+        }
+    }
+}
+
+/// Forget the seat start of a removed governor, so a later re-seat starts a new age.
+pub fn clear_governor_seat(e: &Env, governor: &Address) {
+    e.storage()
+        .instance()
+        .remove(&ProposerAgeKey::GovernorSince(governor.clone()));
+}
+
+/// Timestamp `governor` got its seat, if recorded.
+#[must_use]
+pub fn governor_since(e: &Env, governor: &Address) -> Option<u64> {
+    e.storage()
+        .instance()
+        .get(&ProposerAgeKey::GovernorSince(governor.clone()))
+}
+
+/// Timestamp since which `who` has held the active bond, if it holds it.
+#[must_use]
+pub fn bond_since(e: &Env, who: &Address) -> Option<u64> {
+    let bond: IdentityBond = e.storage().instance().get(&DataKey::Bond)?;
+    if !bond.active || bond.identity != *who {
+        return None;
+    }
+    Some(
+        e.storage()
+            .instance()
+            .get(&ProposerAgeKey::BondSince)
+            .unwrap_or(bond.bond_start),
+    )
+}
+
+/// True if `who` has held an active bond or a governor seat for at least the minimum age.
+#[must_use]
+pub fn meets_min_age(e: &Env, who: &Address) -> bool {
+    let min_age = get_min_age(e);
+    if min_age == 0 {
+        return true;
+    }
+    let now = e.ledger().timestamp();
+    let old_enough = |since: Option<u64>| since.is_some_and(|s| now.saturating_sub(s) >= min_age);
+    old_enough(governor_since(e, who)) || old_enough(bond_since(e, who))
+}
@@ -0,0 +1,233 @@
+//! Query Access
+//!
+//! Paid access to credibility data for consumers (dApps, verifiers) reading it on-chain.
+//! The admin keeps an allow-list of authorized consumers and sets the pricing. Consumers
+//! prepay credit in the bond token with `deposit`; each gated query then draws the per-query
+//! fee from that credit, unless the consumer holds an active subscription bought from the
+//! same credit. Charged amounts move to the fee pool and are collected with the other fees.
+//!
+//! The views that feed or reveal a subject's score (`get_reputation`, `get_cached_reputation`,
+//! `explain_score`, `get_verification_snapshot`, `get_attestation_summary` and the subject
+//! attestation lists, counts, weights and activity) are gated: each takes the consumer and
+//! charges one query. Prepaid credit counts as reserved balance until it is
+//! charged, and a consumer can take uncharged credit back with `withdraw`.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{features, fees, funding, token_adaptor, BondError};
+
+/// Pricing of gated queries, in bond token units.
+///
+/// # Fields
+/// * `query_fee` - Charged per gated query outside a subscription (0 = free).
+/// * `subscription_fee` - Price of one subscription period (0 = no subscriptions).
+/// * `subscription_period` - Length of a subscription period in seconds.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct QueryPricing {
+    pub query_fee: i128,
+    pub subscription_fee: i128,
+    pub subscription_period: u64,
+}
+
+/// Account of a query consumer.
+///
+/// # Fields
+/// * `authorized` - On the allow-list; only authorized consumers can deposit and query.
+/// * `credit` - Prepaid credit not yet charged.
+/// * `subscribed_until` - End of the current subscription (0 = never subscribed).
+/// * `query_count` - Gated queries made so far.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConsumerAccount {
+    pub authorized: bool,
+    pub credit: i128,
+    pub subscribed_until: u64,
+    pub query_count: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum QueryAccessKey {
+    /// Configured `QueryPricing`.
+    QueryPricing,
+    /// Account of a consumer.
+    QueryConsumer(Address),
+    /// Sum of all consumers' uncharged credit.
+    OutstandingCredit,
+}
+
+#[must_use]
+pub fn get_pricing(e: &Env) -> QueryPricing {
+    e.storage()
+        .instance()
+        .get(&QueryAccessKey::QueryPricing)
+        .unwrap_or_default()
+}
+
+/// Set the pricing. Admin only (enforced by caller).
+pub fn set_pricing(e: &Env, pricing: &QueryPricing) {
+    if pricing.query_fee < 0 || pricing.subscription_fee < 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    if pricing.subscription_fee > 0 && pricing.subscription_period == 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&QueryAccessKey::QueryPricing, pricing);
+    e.events()
+        .publish((Symbol::new(e, "query_pricing_set"),), pricing.clone());
+}
+
+#[must_use]
+pub fn get_account(e: &Env, consumer: &Address) -> ConsumerAccount {
+    e.storage()
+        .instance()
+        .get(&QueryAccessKey::QueryConsumer(consumer.clone()))
+        .unwrap_or_default()
+}
+
+fn save_account(e: &Env, consumer: &Address, account: &ConsumerAccount) {
+    e.storage()
+        .instance()
+        .set(&QueryAccessKey::QueryConsumer(consumer.clone()), account);
+}
+
+fn authorized_account(e: &Env, consumer: &Address) -> ConsumerAccount {
+    let account = get_account(e, consumer);
+    if !account.authorized {
+        panic_with_error!(e, BondError::Unauthorized);
+    }
+    account
+}
+
+/// Uncharged credit of all consumers.
+#[must_use]
+pub fn outstanding_credit(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&QueryAccessKey::OutstandingCredit)
+        .unwrap_or(0)
+}
+
+fn set_outstanding(e: &Env, amount: i128) {
+    e.storage()
+        .instance()
+        .set(&QueryAccessKey::OutstandingCredit, &amount);
+}
+
+/// Add `consumer` to or remove it from the allow-list. A removed consumer keeps its credit
+/// and subscription for when it is authorized again. Admin only (enforced by caller).
+pub fn set_authorized(e: &Env, consumer: &Address, authorized: bool) {
+    let mut account = get_account(e, consumer);
+    account.authorized = authorized;
+    save_account(e, consumer, &account);
+    e.events().publish(
+        (Symbol::new(e, "query_consumer_set"), consumer.clone()),
+        authorized,
+    );
+}
+
+/// Pull `amount` of the bond token from `consumer` and add it to its credit. Returns the
+/// new credit. Caller must enforce consumer auth.
+pub fn deposit(e: &Env, consumer: &Address, amount: i128) -> i128 {
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    let mut account = authorized_account(e, consumer);
+    let token = crate::token_info::get(e)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::TokenNotSet))
+        .address;
+    token_adaptor::pull(e, &token, consumer, amount);
+    account.credit = account
+        .credit
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    save_account(e, consumer, &account);
+    set_outstanding(e, outstanding_credit(e).saturating_add(amount));
+    e.events().publish(
+        (Symbol::new(e, "query_credit_deposited"), consumer.clone()),
+        (amount, account.credit),
+    );
+    account.credit
+}
+
+/// Pay `amount` of `consumer`'s uncharged credit back to it in the bond token. Allowed
+/// after the consumer was removed from the allow-list. Returns the credit left. Caller must
+/// enforce consumer auth.
+pub fn withdraw(e: &Env, consumer: &Address, amount: i128) -> i128 {
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    let mut account = get_account(e, consumer);
+    if account.credit < amount {
+        panic_with_error!(e, BondError::InsufficientBalance);
+    }
+    account.credit -= amount;
+    save_account(e, consumer, &account);
+    set_outstanding(e, outstanding_credit(e).saturating_sub(amount));
+    funding::release(e, consumer, amount);
+    e.events().publish(
+        (Symbol::new(e, "query_credit_withdrawn"), consumer.clone()),
+        (amount, account.credit),
+    );
+    account.credit
+}
+
+/// Move `fee` from the account's credit to the fee pool. Nothing is charged while fees are
+/// disabled. Returns the amount charged.
+fn charge(e: &Env, account: &mut ConsumerAccount, fee: i128) -> i128 {
+    if fee <= 0 || !features::is_enabled(e, features::FEATURE_FEES) {
+        return 0;
+    }
+    if account.credit < fee {
+        panic_with_error!(e, BondError::InsufficientBalance);
+    }
+    account.credit -= fee;
+    set_outstanding(e, outstanding_credit(e).saturating_sub(fee));
+    fees::add_to_pool(e, fee);
+    fee
+}
+
+/// Buy one subscription period from `consumer`'s credit. A running subscription is
+/// extended from its end. Returns the new end. Caller must enforce consumer auth.
+pub fn subscribe(e: &Env, consumer: &Address) -> u64 {
+    let pricing = get_pricing(e);
+    if pricing.subscription_fee == 0 {
+        panic_with_error!(e, BondError::ConfigNotSet);
+    }
+    let mut account = authorized_account(e, consumer);
+    let paid = charge(e, &mut account, pricing.subscription_fee);
+    account.subscribed_until = account
+        .subscribed_until
+        .max(e.ledger().timestamp())
+        .saturating_add(pricing.subscription_period);
+    save_account(e, consumer, &account);
+    e.events().publish(
+        (Symbol::new(e, "query_subscribed"), consumer.clone()),
+        (paid, account.subscribed_until),
+    );
+    account.subscribed_until
+}
+
+/// Account for one gated query by `consumer`: free during a subscription, otherwise the
+/// per-query fee is drawn from its credit. Returns the amount charged. Caller must enforce
+/// consumer auth.
+pub fn consume_credit(e: &Env, consumer: &Address) -> i128 {
+    let mut account = authorized_account(e, consumer);
+    let fee = if account.subscribed_until > e.ledger().timestamp() {
+        0
+    } else {
+        charge(e, &mut account, get_pricing(e).query_fee)
+    };
+    account.query_count = account.query_count.saturating_add(1);
+    save_account(e, consumer, &account);
+    if fee > 0 {
+        e.events().publish(
+            (Symbol::new(e, "query_credit_consumed"), consumer.clone()),
+            (fee, account.credit),
+        );
+    }
+    fee
+}
@@ -0,0 +1,65 @@
+//! Attestation Read Grants
+//!
+//! Full attestation structs are only returned to the subject, the issuing verifier, or a
+//! reader holding an unexpired grant from the subject. Everyone else is limited to the
+//! aggregate views (attestation count, aggregate weight), which are gated by query access.
+
+use soroban_sdk::{panic_with_error, Address, Env, Symbol};
+
+use crate::types::Attestation;
+use crate::{BondError, DataKey};
+
+/// Grant `reader` full-read access to the subject's attestations until `expires_at`
+/// (exclusive). Caller must enforce subject auth.
+pub fn grant(e: &Env, subject: &Address, reader: &Address, expires_at: u64) {
+    if expires_at <= e.ledger().timestamp() {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    e.storage().instance().set(
+        &DataKey::ReadGrant(subject.clone(), reader.clone()),
+        &expires_at,
+    );
+    e.events().publish(
+        (Symbol::new(e, "read_granted"), subject.clone()),
+        (reader.clone(), expires_at),
+    );
+}
+
+/// Revoke a reader's grant. Caller must enforce subject auth.
+pub fn revoke(e: &Env, subject: &Address, reader: &Address) {
+    e.storage()
+        .instance()
+        .remove(&DataKey::ReadGrant(subject.clone(), reader.clone()));
+    e.events().publish(
+        (Symbol::new(e, "read_revoked"), subject.clone()),
+        reader.clone(),
+    );
+}
+
+/// Expiry of a reader's grant, if any (may be in the past).
+#[must_use]
+pub fn get_grant(e: &Env, subject: &Address, reader: &Address) -> Option<u64> {
+    e.storage()
+        .instance()
+        .get(&DataKey::ReadGrant(subject.clone(), reader.clone()))
+}
+
+/// True if `reader` holds an unexpired grant from `subject`.
+#[must_use]
+pub fn has_valid_grant(e: &Env, subject: &Address, reader: &Address) -> bool {
+    match get_grant(e, subject, reader) {
+        Some(expires_at) => e.ledger().timestamp() < expires_at,
+        None => false,
+    }
+}
+
+/// Panics with `Unauthorized` unless `reader` may read the full attestation.
+pub fn require_can_read(e: &Env, reader: &Address, attestation: &Attestation) {
+    if *reader == attestation.identity
+        || *reader == attestation.verifier
+        || has_valid_grant(e, &attestation.identity, reader)
+    {
+        return;
+    }
+    panic_with_error!(e, BondError::Unauthorized);
+}
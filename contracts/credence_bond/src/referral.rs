@@ -0,0 +1,179 @@
+//! Referral Attribution
+//!
+//! A bond can name the address that referred the identity. The referrer is kept alongside
+//! the bond and emitted in `bond_referred`. Per-referrer counters are aggregated on-chain. An
+//! admin-configured share of the bond-creation fee is credited to the referrer instead of
+//! the fee pool, so growth programs can pay out without off-chain bookkeeping. Claims are
+//! paid in the bond token, and unclaimed fees count as reserved balance until then.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::BondError;
+
+/// Aggregate referral counters of a referrer.
+///
+/// # Fields
+/// * `bonds_referred` - Bonds created naming this referrer.
+/// * `amount_referred` - Sum of the gross amounts of those bonds.
+/// * `fees_earned` - Total referral fees credited (claimed or not).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralStats {
+    pub bonds_referred: u32,
+    pub amount_referred: i128,
+    pub fees_earned: i128,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum ReferralKey {
+    /// Share of bond-creation fees paid to referrers (bps).
+    ReferralFeeBps,
+    /// Referrer of the current bond.
+    BondReferrer,
+    /// Aggregate counters per referrer.
+    Stats(Address),
+    /// Unclaimed referral fees per referrer.
+    Unclaimed(Address),
+    /// Sum of all referrers' unclaimed fees.
+    ReferralUnclaimedTotal,
+}
+
+/// Share of bond-creation fees paid to referrers (bps, 0 if never set).
+#[must_use]
+pub fn get_fee_bps(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&ReferralKey::ReferralFeeBps)
+        .unwrap_or(0)
+}
+
+/// Set the referrer share of bond-creation fees. Admin only (enforced by caller).
+pub fn set_fee_bps(e: &Env, fee_bps: u32) {
+    if fee_bps > 10_000 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&ReferralKey::ReferralFeeBps, &fee_bps);
+}
+
+/// Referrer of the current bond, if any.
+#[must_use]
+pub fn get_bond_referrer(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&ReferralKey::BondReferrer)
+}
+
+/// Aggregate counters of a referrer (zero if never named).
+#[must_use]
+pub fn get_stats(e: &Env, referrer: &Address) -> ReferralStats {
+    e.storage()
+        .instance()
+        .get(&ReferralKey::Stats(referrer.clone()))
+        .unwrap_or(ReferralStats {
+            bonds_referred: 0,
+            amount_referred: 0,
+            fees_earned: 0,
+        })
+}
+
+/// Referral fees credited to a referrer and not yet claimed.
+#[must_use]
+pub fn get_unclaimed(e: &Env, referrer: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&ReferralKey::Unclaimed(referrer.clone()))
+        .unwrap_or(0)
+}
+
+/// Unclaimed referral fees of all referrers.
+#[must_use]
+pub fn unclaimed_total(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&ReferralKey::ReferralUnclaimedTotal)
+        .unwrap_or(0)
+}
+
+fn set_unclaimed_total(e: &Env, amount: i128) {
+    e.storage()
+        .instance()
+        .set(&ReferralKey::ReferralUnclaimedTotal, &amount);
+}
+
+/// Referrer's share of a bond-creation `fee`.
+#[must_use]
+pub fn fee_share(e: &Env, fee: i128) -> i128 {
+    fee.checked_mul(get_fee_bps(e) as i128)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow))
+        / 10_000
+}
+
+/// Attribute a newly created bond to `referrer` (or clear the attribution when `None`) and
+/// credit the referrer's fee share.
+pub fn attribute(
+    e: &Env,
+    identity: &Address,
+    referrer: Option<Address>,
+    amount: i128,
+    referral_fee: i128,
+) {
+    let Some(referrer) = referrer else {
+        e.storage().instance().remove(&ReferralKey::BondReferrer);
+        return;
+    };
+    if referrer == *identity {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    e.storage()
+        .instance()
+        .set(&ReferralKey::BondReferrer, &referrer);
+
+    let mut stats = get_stats(e, &referrer);
+    stats.bonds_referred = stats
+        .bonds_referred
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    stats.amount_referred = stats
+        .amount_referred
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    stats.fees_earned = stats
+        .fees_earned
+        .checked_add(referral_fee)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&ReferralKey::Stats(referrer.clone()), &stats);
+    if referral_fee > 0 {
+        let unclaimed = get_unclaimed(e, &referrer)
+            .checked_add(referral_fee)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+        e.storage()
+            .instance()
+            .set(&ReferralKey::Unclaimed(referrer.clone()), &unclaimed);
+        set_unclaimed_total(e, unclaimed_total(e).saturating_add(referral_fee));
+    }
+    e.events().publish(
+        (Symbol::new(e, "bond_referred"), referrer),
+        (identity.clone(), amount, referral_fee),
+    );
+}
+
+/// Pay out a referrer's unclaimed fees in the bond token. Caller must enforce referrer auth.
+pub fn claim(e: &Env, referrer: &Address) -> i128 {
+    let amount = get_unclaimed(e, referrer);
+    if amount <= 0 {
+        panic_with_error!(e, BondError::NothingToClaim);
+    }
+    e.storage()
+        .instance()
+        .remove(&ReferralKey::Unclaimed(referrer.clone()));
+    set_unclaimed_total(e, unclaimed_total(e).saturating_sub(amount));
+    crate::funding::release(e, referrer, amount);
+    e.events().publish(
+        (Symbol::new(e, "referral_fees_claimed"), referrer.clone()),
+        amount,
+    );
+    amount
+}
@@ -0,0 +1,183 @@
+//! Reputation Score
+//!
+//! Folds a subject's verification figures into one number so integrators do not each invent
+//! their own weighting. The score combines the active attestation weight, the bond tier, the
+//! bond age in days (capped) and the number of slashes taken, with per-component points the
+//! admin configures:
+//!
+//! `score = weight * weight_points + tier * tier_points + min(age_days, max_age_days) *
+//! age_points_per_day - slash_count * slash_penalty`, floored at 0, where `tier` counts from
+//! Bronze = 0 to Platinum = 3.
+//!
+//! `get` computes the score live. A cached copy per subject is refreshed whenever one of its
+//! attestations is added or revoked and whenever its bond is slashed.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{verification_snapshot, BondTier, DataKey, IdentityBond};
+
+/// Points awarded per component.
+///
+/// * `weight_points` - Per unit of active attestation weight.
+/// * `tier_points` - Per tier above Bronze.
+/// * `age_points_per_day` - Per full day since the bond started.
+/// * `max_age_days` - Days after which the bond age stops adding points.
+/// * `slash_penalty` - Deducted per slash taken.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationConfig {
+    pub weight_points: u32,
+    pub tier_points: u32,
+    pub age_points_per_day: u32,
+    pub max_age_days: u32,
+    pub slash_penalty: u32,
+}
+
+/// A subject's reputation score and the inputs it was computed from.
+///
+/// * `active_weight` - Attestation weight past its challenge period.
+/// * `tier` - Tier of the subject's bond (Bronze if it never bonded here).
+/// * `bond_age_days` - Full days since the subject's bond started (0 without a bond).
+/// * `slash_count` - Slashes taken by the subject's bond.
+/// * `score` - Result of the configured formula.
+/// * `updated_at` - Ledger timestamp of the computation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reputation {
+    pub subject: Address,
+    pub active_weight: u64,
+    pub tier: BondTier,
+    pub bond_age_days: u64,
+    pub slash_count: u32,
+    pub score: u64,
+    pub updated_at: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum ReputationKey {
+    /// Configured `ReputationConfig`.
+    Formula,
+    /// Slashes taken by a subject.
+    SlashCount(Address),
+    /// Last cached `Reputation` of a subject.
+    Cached(Address),
+}
+
+/// Formula used until the admin configures another.
+#[must_use]
+pub fn default_config() -> ReputationConfig {
+    ReputationConfig {
+        weight_points: 1,
+        tier_points: 100,
+        age_points_per_day: 1,
+        max_age_days: 365,
+        slash_penalty: 100,
+    }
+}
+
+#[must_use]
+pub fn get_config(e: &Env) -> ReputationConfig {
+    e.storage()
+        .instance()
+        .get(&ReputationKey::Formula)
+        .unwrap_or_else(default_config)
+}
+
+/// Set the formula. Admin only (enforced by caller). Cached scores are refreshed lazily on
+/// the subject's next change.
+pub fn set_config(e: &Env, config: &ReputationConfig) {
+    e.storage().instance().set(&ReputationKey::Formula, config);
+}
+
+#[must_use]
+pub fn slash_count(e: &Env, subject: &Address) -> u32 {
+    e.storage()
+        .instance()
+        .get(&ReputationKey::SlashCount(subject.clone()))
+        .unwrap_or(0)
+}
+
+/// Count a slash against `subject` and refresh its cached reputation.
+pub fn record_slash(e: &Env, subject: &Address) {
+    let count = slash_count(e, subject).saturating_add(1);
+    e.storage()
+        .instance()
+        .set(&ReputationKey::SlashCount(subject.clone()), &count);
+    refresh(e, subject);
+}
+
+/// Tier position counted by `tier_points` (Bronze = 0).
+#[must_use]
+pub fn tier_rank(tier: &BondTier) -> u64 {
+    match tier {
+        BondTier::Bronze => 0,
+        BondTier::Silver => 1,
+        BondTier::Gold => 2,
+        BondTier::Platinum => 3,
+    }
+}
+
+/// Apply `config` to the given inputs.
+#[must_use]
+pub fn score(
+    config: &ReputationConfig,
+    active_weight: u64,
+    tier: &BondTier,
+    bond_age_days: u64,
+    slash_count: u32,
+) -> u64 {
+    let age_days = bond_age_days.min(config.max_age_days as u64);
+    let positive = active_weight
+        .saturating_mul(config.weight_points as u64)
+        .saturating_add(tier_rank(tier).saturating_mul(config.tier_points as u64))
+        .saturating_add(age_days.saturating_mul(config.age_points_per_day as u64));
+    let penalty = (slash_count as u64).saturating_mul(config.slash_penalty as u64);
+    positive.saturating_sub(penalty)
+}
+
+/// Compute the current reputation of `subject`.
+#[must_use]
+pub fn get(e: &Env, subject: &Address) -> Reputation {
+    let now = e.ledger().timestamp();
+    let snapshot = verification_snapshot::view(e, subject);
+    let bond: Option<IdentityBond> = e.storage().instance().get(&DataKey::Bond);
+    let bond_age_days = match bond {
+        Some(b) if b.identity == *subject => now.saturating_sub(b.bond_start) / 86_400,
+        _ => 0,
+    };
+    let slash_count = slash_count(e, subject);
+    let score = score(
+        &get_config(e),
+        snapshot.active_weight,
+        &snapshot.tier,
+        bond_age_days,
+        slash_count,
+    );
+    Reputation {
+        subject: subject.clone(),
+        active_weight: snapshot.active_weight,
+        tier: snapshot.tier,
+        bond_age_days,
+        slash_count,
+        score,
+        updated_at: now,
+    }
+}
+
+/// Last cached reputation of `subject`, if any.
+#[must_use]
+pub fn get_cached(e: &Env, subject: &Address) -> Option<Reputation> {
+    e.storage()
+        .instance()
+        .get(&ReputationKey::Cached(subject.clone()))
+}
+
+/// Recompute and cache the reputation of `subject`.
+pub fn refresh(e: &Env, subject: &Address) -> Reputation {
+    let reputation = get(e, subject);
+    e.storage()
+        .instance()
+        .set(&ReputationKey::Cached(subject.clone()), &reputation);
+    reputation
+}
@@ -0,0 +1,119 @@
+//! Reserved Balance
+//!
+//! Tracks what the contract owes beyond the bond itself: fees recorded but not yet
+//! collected, early-exit penalties not yet collected, and escrowed
+//! claims (compensation escrow, claimable rewards, the reward pool, prepaid query
+//! credit and unclaimed referral fees). Withdrawals
+//! must leave the contract's bond token balance covering these reserves, so recorded
+//! obligations can always be paid out.
+
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, Symbol};
+
+use crate::{
+    compensation, early_exit_penalty, query_access, referral, reward_accrual, rewards,
+    slash_reversal, token_info, BondError, DataKey, IdentityBond,
+};
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum ReserveKey {
+    /// Early-exit penalties not yet collected.
+    PendingPenalties,
+}
+
+/// Fees recorded but not yet collected.
+#[must_use]
+pub fn pending_fees(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, "fees"))
+        .unwrap_or(0)
+}
+
+#[must_use]
+pub fn pending_penalties(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&ReserveKey::PendingPenalties)
+        .unwrap_or(0)
+}
+
+/// Reserve an early-exit penalty for the treasury.
+pub fn add_pending_penalty(e: &Env, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let total = pending_penalties(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&ReserveKey::PendingPenalties, &total);
+}
+
+/// Release all pending penalties and return the amount. With a bond token set, the amount
+/// is transferred to the early-exit treasury. Admin only (enforced by caller).
+pub fn collect_penalties(e: &Env) -> i128 {
+    let collected = pending_penalties(e);
+    e.storage()
+        .instance()
+        .set(&ReserveKey::PendingPenalties, &0_i128);
+    if collected > 0 && token_info::get(e).is_some() {
+        let (treasury, _bps) = early_exit_penalty::get_config(e);
+        pay_out(e, &treasury, collected);
+        e.events()
+            .publish((Symbol::new(e, "penalties_collected"), treasury), collected);
+    }
+    collected
+}
+
+/// Transfer `amount` of the bond token released from the reserves to `to`. Call after the
+/// amount left the reserves; panics with `ReserveBreach` if the balance left would not
+/// cover what is still reserved.
+pub fn pay_out(e: &Env, to: &Address, amount: i128) {
+    require_covered(e, amount);
+    if let Some(info) = token_info::get(e) {
+        token::Client::new(e, &info.address).transfer(&e.current_contract_address(), to, &amount);
+    }
+}
+
+/// Compensation escrow, claimable and pooled rewards, pending slash refunds,
+/// prepaid query credit and unclaimed referral fees.
+#[must_use]
+pub fn escrowed_claims(e: &Env) -> i128 {
+    let bond: Option<IdentityBond> = e.storage().instance().get(&DataKey::Bond);
+    let claimable = bond
+        .map(|b| rewards::get_balance(e, &b.identity))
+        .unwrap_or(0);
+    compensation::get_escrow_balance(e)
+        .saturating_add(reward_accrual::get_pool(e))
+        .saturating_add(claimable)
+        .saturating_add(slash_reversal::pending_refunds(e))
+        .saturating_add(query_access::outstanding_credit(e))
+        .saturating_add(referral::unclaimed_total(e))
+}
+
+/// Total reserved balance: pending fees, pending penalties and escrowed claims.
+#[must_use]
+pub fn total(e: &Env) -> i128 {
+    pending_fees(e)
+        .saturating_add(pending_penalties(e))
+        .saturating_add(escrowed_claims(e))
+}
+
+/// Contract's bond token balance, if a bond token is set.
+#[must_use]
+pub fn token_balance(e: &Env) -> Option<i128> {
+    token_info::get(e)
+        .map(|info| token::Client::new(e, &info.address).balance(&e.current_contract_address()))
+}
+
+/// Panic unless the bond token balance left after paying out `outgoing` covers the reserves.
+/// Skipped when no bond token is set (accounting-only deployments).
+pub fn require_covered(e: &Env, outgoing: i128) {
+    if let Some(balance) = token_balance(e) {
+        if balance.saturating_sub(outgoing) < total(e) {
+            panic_with_error!(e, BondError::ReserveBreach);
+        }
+    }
+}
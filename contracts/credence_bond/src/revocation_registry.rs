@@ -0,0 +1,75 @@
+//! Revocation Registry
+//!
+//! Every revocation carries a [`RevocationReason`]. Besides being stored on the attestation,
+//! each revocation is indexed by the issuing verifier and reason so auditors can review a
+//! verifier's behavior (e.g. how often it revokes for fraud versus issuing in error). The
+//! index survives archival compaction of the attestations themselves.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+
+use crate::{Attestation, BondError, RevocationReason};
+
+/// Largest page returned by [`list`].
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// One indexed revocation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Revocation {
+    pub attestation_id: u64,
+    pub identity: Address,
+    pub reason: RevocationReason,
+    pub revoked_at: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum RevocationKey {
+    /// Revocations of attestations issued by a verifier, for one reason, oldest first.
+    ByReason(Address, RevocationReason),
+}
+
+/// Panic with `InvalidInput` unless `reason` names an actual revocation reason.
+pub fn require_valid(e: &Env, reason: RevocationReason) {
+    if reason == RevocationReason::NotRevoked {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+}
+
+/// Index a revocation under the attestation's verifier and reason.
+pub fn record(e: &Env, attestation: &Attestation, reason: RevocationReason) {
+    let key = RevocationKey::ByReason(attestation.verifier.clone(), reason);
+    let mut entries: Vec<Revocation> = e.storage().instance().get(&key).unwrap_or(Vec::new(e));
+    entries.push_back(Revocation {
+        attestation_id: attestation.id,
+        identity: attestation.identity.clone(),
+        reason,
+        revoked_at: e.ledger().timestamp(),
+    });
+    e.storage().instance().set(&key, &entries);
+}
+
+/// Revocations of `attester`'s attestations for `reason`, oldest first, skipping `offset`
+/// and returning at most `limit` (capped at `MAX_PAGE_SIZE`).
+#[must_use]
+pub fn list(
+    e: &Env,
+    attester: &Address,
+    reason: RevocationReason,
+    offset: u32,
+    limit: u32,
+) -> Vec<Revocation> {
+    let entries: Vec<Revocation> = e
+        .storage()
+        .instance()
+        .get(&RevocationKey::ByReason(attester.clone(), reason))
+        .unwrap_or(Vec::new(e));
+    let end = offset
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(entries.len());
+    let mut page = Vec::new(e);
+    for i in offset..end {
+        page.push_back(entries.get_unchecked(i));
+    }
+    page
+}
@@ -0,0 +1,144 @@
+//! Reward Accrual
+//!
+//! Bonded funds earn yield out of a reward pool that the admin or the treasury funds with
+//! the bond token. Yield accrues linearly on the locked stake (bonded minus slashed) at an
+//! annual rate in basis points:
+//!
+//! `yield = locked * reward_rate_bps * elapsed / (10_000 * SECONDS_PER_YEAR)`
+//!
+//! Every balance-changing call first checkpoints the bond, crediting the yield earned at
+//! the old balance to the identity's claimable rewards (paid out by `claim_rewards`). Yield
+//! is capped by what is left in the pool; time that passes with an empty pool earns nothing.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{rewards, time_math, token_adaptor, token_info, BondError, DataKey, IdentityBond};
+
+/// Seconds in the 365-day year the reward rate is expressed over.
+pub const SECONDS_PER_YEAR: u64 = 365 * time_math::SECONDS_PER_DAY;
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum AccrualKey {
+    /// Annual reward rate (bps of locked stake).
+    RewardRateBps,
+    /// Funded rewards not yet accrued to a bond.
+    RewardPool,
+    /// Timestamp the bond last accrued up to.
+    RewardsAccruedAt,
+}
+
+#[must_use]
+pub fn get_rate_bps(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&AccrualKey::RewardRateBps)
+        .unwrap_or(0)
+}
+
+/// Set the annual rate. Admin only (enforced by caller). Yield up to now accrues at the
+/// previous rate.
+pub fn set_rate_bps(e: &Env, rate_bps: u32) {
+    if rate_bps > 10_000 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    checkpoint(e);
+    e.storage()
+        .instance()
+        .set(&AccrualKey::RewardRateBps, &rate_bps);
+}
+
+/// Funded rewards not yet accrued.
+#[must_use]
+pub fn get_pool(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&AccrualKey::RewardPool)
+        .unwrap_or(0)
+}
+
+fn set_pool(e: &Env, amount: i128) {
+    e.storage().instance().set(&AccrualKey::RewardPool, &amount);
+}
+
+/// Pull `amount` of the bond token from `funder` into the pool. Caller must enforce
+/// `funder` auth and role. Returns the new pool size.
+pub fn fund(e: &Env, funder: &Address, amount: i128) -> i128 {
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    let token = token_info::get(e)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::TokenNotSet))
+        .address;
+    token_adaptor::pull(e, &token, funder, amount);
+    let pool = get_pool(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    set_pool(e, pool);
+    e.events().publish(
+        (Symbol::new(e, "reward_pool_funded"), funder.clone()),
+        (amount, pool),
+    );
+    pool
+}
+
+/// Yield `bond` earned between its last checkpoint and `now`, capped by the pool.
+#[must_use]
+pub fn accrued(e: &Env, bond: &IdentityBond, now: u64) -> i128 {
+    let locked = bond.bonded_amount.saturating_sub(bond.slashed_amount);
+    if !bond.active || locked <= 0 {
+        return 0;
+    }
+    let since: u64 = e
+        .storage()
+        .instance()
+        .get(&AccrualKey::RewardsAccruedAt)
+        .unwrap_or(bond.bond_start)
+        .max(bond.bond_start);
+    let elapsed = now.saturating_sub(since);
+    let earned = locked
+        .saturating_mul(get_rate_bps(e) as i128)
+        .saturating_mul(elapsed as i128)
+        / (10_000 * SECONDS_PER_YEAR as i128);
+    earned.min(get_pool(e))
+}
+
+/// Credit the yield earned by the stored bond up to now and restart accrual from now. Call
+/// before any change to the bond's balance.
+pub fn checkpoint(e: &Env) {
+    let now = e.ledger().timestamp();
+    if let Some(bond) = e
+        .storage()
+        .instance()
+        .get::<_, IdentityBond>(&DataKey::Bond)
+    {
+        let amount = accrued(e, &bond, now);
+        if amount > 0 {
+            set_pool(e, get_pool(e) - amount);
+            rewards::credit(e, &bond.identity, amount);
+            e.events().publish(
+                (Symbol::new(e, "rewards_accrued"), bond.identity),
+                (amount, now),
+            );
+        }
+    }
+    e.storage()
+        .instance()
+        .set(&AccrualKey::RewardsAccruedAt, &now);
+}
+
+/// Claimable rewards of `identity` including yield accrued since the last checkpoint.
+#[must_use]
+pub fn pending(e: &Env, identity: &Address) -> i128 {
+    let balance = rewards::get_balance(e, identity);
+    match e
+        .storage()
+        .instance()
+        .get::<_, IdentityBond>(&DataKey::Bond)
+    {
+        Some(bond) if bond.identity == *identity => {
+            balance.saturating_add(accrued(e, &bond, e.ledger().timestamp()))
+        }
+        _ => balance,
+    }
+}
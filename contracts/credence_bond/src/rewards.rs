@@ -0,0 +1,45 @@
+//! Claimable Rewards
+//!
+//! Per-identity balance of rewards earned but not yet paid out. Yield accrual
+//! (`reward_accrual`) credits it at every checkpoint, and `claim_rewards` pays it out in
+//! the bond token.
+
+use soroban_sdk::{panic_with_error, Address, Env, Symbol};
+
+use crate::{BondError, DataKey};
+
+/// Claimable rewards of an identity.
+#[must_use]
+pub fn get_balance(e: &Env, identity: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::RewardBalance(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// Add `amount` to an identity's claimable rewards.
+pub fn credit(e: &Env, identity: &Address, amount: i128) {
+    let balance = get_balance(e, identity)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&DataKey::RewardBalance(identity.clone()), &balance);
+}
+
+/// Clear an identity's claimable rewards and return the amount for the caller to transfer.
+/// Caller must enforce identity auth.
+pub fn claim(e: &Env, identity: &Address) -> i128 {
+    let amount = get_balance(e, identity);
+    if amount <= 0 {
+        panic_with_error!(e, BondError::NothingToClaim);
+    }
+    e.storage()
+        .instance()
+        .remove(&DataKey::RewardBalance(identity.clone()));
+    e.events().publish(
+        (Symbol::new(e, "rewards_claimed"), identity.clone()),
+        amount,
+    );
+    amount
+}
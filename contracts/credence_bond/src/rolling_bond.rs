@@ -2,29 +2,142 @@
 //!
 //! Auto-renews at period end unless withdrawal was requested with notice.
 //! Tracks withdrawal request and notice period for scoring.
+//! Each renewal can add a configurable loyalty bonus to the amount used for tier computation,
+//! capped after a number of renewals.
+//! Bonds can opt into auto-exit at creation: the end of the Nth period is treated as a
+//! withdrawal request instead of a renewal.
+//!
+//! By default a renewal starts the next period at the renewal time, so late renewals drift.
+//! Bonds can instead opt into an anchored [`PeriodMode`] at creation: period boundaries are
+//! then fixed relative to the creation time (the anchor), either every `n` seconds (e.g.
+//! [`THIRTY_DAYS`]) or on the anchor's day of each calendar month, and a renewal starts the
+//! period containing the renewal time.
+
+use soroban_sdk::{contracttype, panic_with_error, Env};
+
+use crate::{time_math, BondError, DataKey, IdentityBond};
+
+/// Length of a 30-day billing period in seconds.
+pub const THIRTY_DAYS: u64 = 30 * time_math::SECONDS_PER_DAY;
+
+/// Maximum number of identities in one `renew_rolling_batch` call.
+pub const MAX_RENEWAL_BATCH: u32 = 50;
+
+/// Anchored period boundaries for rolling bonds.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PeriodMode {
+    /// Boundaries every `n` seconds after the anchor.
+    Fixed(u64),
+    /// Boundaries on the anchor's day of month and time of day, clamped to short months.
+    CalendarMonth,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum RollingKey {
+    /// `(PeriodMode, anchor)` of the current bond, if anchored.
+    Period,
+}
+
+/// The `k`-th period boundary after `anchor` (`k == 0` is the anchor itself).
+#[must_use]
+pub fn boundary(mode: PeriodMode, anchor: u64, k: u64) -> u64 {
+    match mode {
+        PeriodMode::Fixed(length) => anchor.saturating_add(length.saturating_mul(k)),
+        PeriodMode::CalendarMonth => time_math::add_months(anchor, k),
+    }
+}
+
+/// Index of the period containing `now`: the largest `k` with `boundary(k) <= now`
+/// (0 before the anchor).
+#[must_use]
+pub fn period_index_at(mode: PeriodMode, anchor: u64, now: u64) -> u64 {
+    if now < anchor {
+        return 0;
+    }
+    match mode {
+        PeriodMode::Fixed(length) => time_math::periods_elapsed(now, anchor, length),
+        PeriodMode::CalendarMonth => {
+            let (ay, am, _) = time_math::civil_from_timestamp(anchor);
+            let (ny, nm, _) = time_math::civil_from_timestamp(now);
+            let months = u64::from(ny - ay) * 12 + u64::from(nm) - u64::from(am);
+            if boundary(mode, anchor, months) > now {
+                months - 1
+            } else {
+                months
+            }
+        }
+    }
+}
 
-use crate::IdentityBond;
+/// Start of the period containing `now` (half-open: a boundary starts the next period).
+#[must_use]
+pub fn period_start_at(mode: PeriodMode, anchor: u64, now: u64) -> u64 {
+    boundary(mode, anchor, period_index_at(mode, anchor, now))
+}
+
+/// First boundary strictly after `now`.
+#[must_use]
+pub fn next_boundary(mode: PeriodMode, anchor: u64, now: u64) -> u64 {
+    boundary(
+        mode,
+        anchor,
+        period_index_at(mode, anchor, now).saturating_add(1),
+    )
+}
+
+/// Anchored period mode and anchor of the current bond, if any.
+#[must_use]
+pub fn get_period(e: &Env) -> Option<(PeriodMode, u64)> {
+    e.storage().instance().get(&RollingKey::Period)
+}
+
+/// Anchor the current bond's periods at `anchor`. Returns the first period's duration.
+pub fn set_period(e: &Env, mode: PeriodMode, anchor: u64) -> u64 {
+    if mode == PeriodMode::Fixed(0) {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&RollingKey::Period, &(mode, anchor));
+    next_boundary(mode, anchor, anchor) - anchor
+}
+
+/// Clear any anchored period mode (new bond).
+pub fn clear_period(e: &Env) {
+    e.storage().instance().remove(&RollingKey::Period);
+}
+
+/// Start the period following an ended one. Anchored bonds start the period containing
+/// `now` and take its length as duration; others start a new period of the same duration
+/// at `now`.
+pub fn renew(e: &Env, bond: &mut IdentityBond, now: u64) {
+    match get_period(e) {
+        Some((mode, anchor)) => {
+            let start = period_start_at(mode, anchor, now);
+            bond.bond_duration = next_boundary(mode, anchor, now) - start;
+            apply_renewal(bond, start);
+        }
+        None => apply_renewal(bond, now),
+    }
+}
 
-/// Returns true if the bond has passed its period end (bond_start + bond_duration).
+/// Returns true if the bond has passed its period end (see `time_math::is_period_ended`).
 #[must_use]
 pub fn is_period_ended(now: u64, bond_start: u64, bond_duration: u64) -> bool {
-    let end = bond_start.saturating_add(bond_duration);
-    now >= end
+    time_math::is_period_ended(now, bond_start, bond_duration)
 }
 
-/// Returns true if a withdrawal was requested and the notice period has elapsed.
+/// Returns true if a withdrawal was requested and the notice period has elapsed
+/// (see `time_math::notice_elapsed`).
 #[must_use]
-#[allow(dead_code)] // Public API for off-chain / frontends
 pub fn can_withdraw_after_notice(
     now: u64,
     withdrawal_requested_at: u64,
     notice_period_duration: u64,
 ) -> bool {
-    if withdrawal_requested_at == 0 {
-        return false;
-    }
-    let notice_end = withdrawal_requested_at.saturating_add(notice_period_duration);
-    now >= notice_end
+    time_math::notice_elapsed(now, withdrawal_requested_at, notice_period_duration)
 }
 
 /// Advance bond to a new period (set bond_start to now, keep duration and rolling flag).
@@ -33,3 +146,98 @@ pub fn apply_renewal(bond: &mut IdentityBond, new_start: u64) {
     bond.bond_start = new_start;
     bond.withdrawal_requested_at = 0; // reset withdrawal request on renewal
 }
+
+/// Renewal bonus config: (bonus_bps per renewal, max counted renewals). Default: no bonus.
+#[must_use]
+pub fn get_renewal_bonus_config(e: &Env) -> (u32, u32) {
+    e.storage()
+        .instance()
+        .get(&DataKey::RenewalBonusConfig)
+        .unwrap_or((0, 0))
+}
+
+/// Set renewal bonus config. Admin only (enforced by caller).
+pub fn set_renewal_bonus_config(e: &Env, bonus_bps: u32, max_renewals: u32) {
+    if bonus_bps.saturating_mul(max_renewals) > 10_000 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&DataKey::RenewalBonusConfig, &(bonus_bps, max_renewals));
+}
+
+/// Number of successful renewals of the current bond.
+#[must_use]
+pub fn get_renewal_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::RenewalCount)
+        .unwrap_or(0)
+}
+
+/// Record a successful renewal.
+pub fn record_renewal(e: &Env) {
+    let count = get_renewal_count(e).saturating_add(1);
+    e.storage().instance().set(&DataKey::RenewalCount, &count);
+}
+
+/// Reset the renewal count (new bond).
+pub fn reset_renewal_count(e: &Env) {
+    e.storage().instance().set(&DataKey::RenewalCount, &0_u32);
+}
+
+/// Loyalty bonus in bps: `bonus_bps * min(renewals, max_renewals)`.
+#[must_use]
+pub fn renewal_bonus_bps(renewals: u32, bonus_bps: u32, max_renewals: u32) -> u32 {
+    bonus_bps.saturating_mul(core::cmp::min(renewals, max_renewals))
+}
+
+/// Amount used for tier computation: bonded amount plus the renewal bonus. The bonus is
+/// never added to the actual balance.
+#[must_use]
+pub fn effective_tier_amount(e: &Env, bond: &IdentityBond) -> i128 {
+    if !bond.is_rolling {
+        return bond.bonded_amount;
+    }
+    let (bonus_bps, max_renewals) = get_renewal_bonus_config(e);
+    let bonus = renewal_bonus_bps(get_renewal_count(e), bonus_bps, max_renewals);
+    let extra = bond
+        .bonded_amount
+        .checked_mul(bonus as i128)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow))
+        / 10_000;
+    bond.bonded_amount
+        .checked_add(extra)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow))
+}
+
+/// Period (1-based) at whose end the bond auto-requests withdrawal, if opted in at creation.
+#[must_use]
+pub fn get_auto_exit_period(e: &Env) -> Option<u32> {
+    e.storage().instance().get(&DataKey::AutoExitPeriod)
+}
+
+/// Opt the current bond into auto-exit at the end of period `period` (1-based).
+pub fn set_auto_exit_period(e: &Env, period: u32) {
+    if period == 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&DataKey::AutoExitPeriod, &period);
+}
+
+/// Clear any auto-exit target (new bond).
+pub fn clear_auto_exit(e: &Env) {
+    e.storage().instance().remove(&DataKey::AutoExitPeriod);
+}
+
+/// True if the period that just ended is the auto-exit target (or later). The current
+/// period number is `renewals + 1`.
+#[must_use]
+pub fn is_auto_exit_due(e: &Env) -> bool {
+    match get_auto_exit_period(e) {
+        Some(target) => get_renewal_count(e).saturating_add(1) >= target,
+        None => false,
+    }
+}
@@ -0,0 +1,67 @@
+//! Storage Schema Versioning
+//!
+//! Records which storage layout the contract's data follows so structural changes can be
+//! deployed against live data. `initialize` stamps new deployments with
+//! [`CURRENT_VERSION`]; instances deployed before versioning read as version 0 until
+//! migrated. [`migrate`] applies each step from the stored version up to the current one
+//! and is a no-op once the data is current.
+
+use soroban_sdk::{contracttype, panic_with_error, Env, Symbol};
+
+use crate::BondError;
+
+/// Storage layout version this build reads and writes.
+pub const CURRENT_VERSION: u32 = 1;
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum SchemaKey {
+    Version,
+}
+
+/// Stored schema version (0 for data written before versioning).
+#[must_use]
+pub fn get(e: &Env) -> u32 {
+    e.storage().instance().get(&SchemaKey::Version).unwrap_or(0)
+}
+
+/// Stamp a fresh deployment with the current version.
+pub fn init(e: &Env) {
+    e.storage()
+        .instance()
+        .set(&SchemaKey::Version, &CURRENT_VERSION);
+}
+
+/// Migrate stored data from `from_version` to [`CURRENT_VERSION`] and return the new
+/// version. `from_version` must match the stored version so a migration prepared against
+/// another state cannot run. Admin only (enforced by caller).
+pub fn migrate(e: &Env, from_version: u32) -> u32 {
+    let stored = get(e);
+    if stored == CURRENT_VERSION {
+        return stored;
+    }
+    if from_version != stored {
+        panic_with_error!(e, BondError::SchemaVersionMismatch);
+    }
+    if stored > CURRENT_VERSION {
+        panic_with_error!(e, BondError::SchemaVersionMismatch);
+    }
+    let mut version = stored;
+    while version < CURRENT_VERSION {
+        apply_step(e, version);
+        version += 1;
+    }
+    e.storage().instance().set(&SchemaKey::Version, &version);
+    e.events()
+        .publish((Symbol::new(e, "schema_migrated"),), (stored, version));
+    version
+}
+
+/// Transform storage from layout `from` to `from + 1`.
+fn apply_step(_e: &Env, from: u32) {
+    match from {
+        // 0 -> 1: versioning introduced; the layout itself is unchanged.
+        0 => {}
+        _ => panic_with_error!(_e, BondError::SchemaVersionMismatch),
+    }
+}
@@ -0,0 +1,111 @@
+//! Score Breakdown
+//!
+//! Explains a subject's verification figures instead of returning an opaque result: the
+//! weight per claim type, what the counter-attestation netting and the per-verifier cap took
+//! away, weight lost to expiry, weight still pending, and the bond tier and slash state from
+//! the verification snapshot.
+
+use soroban_sdk::{contracttype, Address, Env, Map, String, Vec};
+
+use crate::{counter_attestation, verification_snapshot, weighted_attestation, Attestation};
+use crate::{BondTier, DataKey};
+
+/// Weight of one claim type (attestation data) of a subject.
+///
+/// * `raw_weight` - Sum of the active attestations' issued weights.
+/// * `net_weight` - Same after counter-attestation netting, before the per-verifier cap.
+/// * `attestation_count` - Active attestations of this claim type.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimWeight {
+    pub claim: String,
+    pub raw_weight: u64,
+    pub net_weight: u64,
+    pub attestation_count: u32,
+}
+
+/// Components of a subject's verification figures.
+///
+/// * `claims` - Per-claim-type weights, ordered by claim.
+/// * `raw_weight` - Sum of `claims[].raw_weight`.
+/// * `counter_deduction` - Weight removed by open counter-attestations.
+/// * `verifier_cap_deduction` - Weight removed by the per-verifier cap.
+/// * `aggregate_weight` - Final weight (`get_subject_aggregate_weight`):
+///   `raw_weight - counter_deduction - verifier_cap_deduction`.
+/// * `expired_weight` - Weight of non-revoked attestations that have expired (decay).
+/// * `pending_weight` - Weight of attestations still in their challenge period.
+/// * `tier` / `slash_ratio_bps` - From the verification snapshot.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScoreBreakdown {
+    pub subject: Address,
+    pub claims: Vec<ClaimWeight>,
+    pub raw_weight: u64,
+    pub counter_deduction: u64,
+    pub verifier_cap_deduction: u64,
+    pub aggregate_weight: u64,
+    pub expired_weight: u64,
+    pub pending_weight: u64,
+    pub tier: BondTier,
+    pub slash_ratio_bps: u32,
+    pub timestamp: u64,
+}
+
+#[must_use]
+pub fn explain(e: &Env, subject: &Address) -> ScoreBreakdown {
+    let now = e.ledger().timestamp();
+    let ids: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&DataKey::SubjectAttestations(subject.clone()))
+        .unwrap_or(Vec::new(e));
+    let mut by_claim: Map<String, ClaimWeight> = Map::new(e);
+    let mut raw_weight: u64 = 0;
+    let mut net_total: u64 = 0;
+    let mut expired_weight: u64 = 0;
+    for id in ids.iter() {
+        let att: Option<Attestation> = e.storage().instance().get(&DataKey::Attestation(id));
+        let Some(att) = att else { continue };
+        if att.revoked {
+            continue;
+        }
+        if att.is_expired_at(now) {
+            expired_weight = expired_weight.saturating_add(att.weight as u64);
+            continue;
+        }
+        if !att.is_active_at(now) {
+            continue;
+        }
+        let net = counter_attestation::net_weight(e, &att) as u64;
+        let mut entry = by_claim
+            .get(att.attestation_data.clone())
+            .unwrap_or(ClaimWeight {
+                claim: att.attestation_data.clone(),
+                raw_weight: 0,
+                net_weight: 0,
+                attestation_count: 0,
+            });
+        entry.raw_weight = entry.raw_weight.saturating_add(att.weight as u64);
+        entry.net_weight = entry.net_weight.saturating_add(net);
+        entry.attestation_count = entry.attestation_count.saturating_add(1);
+        by_claim.set(att.attestation_data, entry);
+        raw_weight = raw_weight.saturating_add(att.weight as u64);
+        net_total = net_total.saturating_add(net);
+    }
+
+    let aggregate_weight = weighted_attestation::aggregate_weight(e, subject);
+    let snapshot = verification_snapshot::view(e, subject);
+    ScoreBreakdown {
+        subject: subject.clone(),
+        claims: by_claim.values(),
+        raw_weight,
+        counter_deduction: raw_weight.saturating_sub(net_total),
+        verifier_cap_deduction: net_total.saturating_sub(aggregate_weight),
+        aggregate_weight,
+        expired_weight,
+        pending_weight: snapshot.pending_weight,
+        tier: snapshot.tier,
+        slash_ratio_bps: snapshot.slash_ratio_bps,
+        timestamp: now,
+    }
+}
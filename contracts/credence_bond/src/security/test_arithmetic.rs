@@ -33,14 +33,13 @@ fn test_i128_bond_amount_at_max() {
     let identity = Address::generate(&e);
     // Test creating bond with maximum i128 value
     let bond = client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
-    let bond = client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
 
     assert_eq!(bond.bonded_amount, i128::MAX);
     assert!(bond.active);
 }
 
 #[test]
-#[should_panic(expected = "top-up caused overflow")]
+#[should_panic(expected = "Error(Contract, #113)")]
 fn test_i128_overflow_on_top_up() {
     let e = Env::default();
     e.mock_all_auths();
@@ -53,14 +52,13 @@ fn test_i128_overflow_on_top_up() {
     let identity = Address::generate(&e);
     // Create bond with max - 1000
     client.create_bond(&identity, &(i128::MAX - 1000), &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &(i128::MAX - 1000), &86400_u64, &false, &0_u64);
 
     // Attempt to top up by 2000, which should overflow
     client.top_up(&2000);
 }
 
 #[test]
-#[should_panic(expected = "top-up caused overflow")]
+#[should_panic(expected = "Error(Contract, #113)")]
 fn test_i128_overflow_on_max_top_up() {
     let e = Env::default();
     e.mock_all_auths();
@@ -73,14 +71,13 @@ fn test_i128_overflow_on_max_top_up() {
     let identity = Address::generate(&e);
     // Create bond with max value
     client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
 
     // Attempt to top up by 1, which should overflow
     client.top_up(&1);
 }
 
 #[test]
-#[should_panic(expected = "slashing caused overflow")]
+#[should_panic(expected = "Error(Contract, #113)")]
 fn test_i128_overflow_on_massive_slashing() {
     let e = Env::default();
     e.mock_all_auths();
@@ -93,7 +90,6 @@ fn test_i128_overflow_on_massive_slashing() {
     let identity = Address::generate(&e);
     // Create bond with large amount
     client.create_bond(&identity, &(i128::MAX / 2), &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &(i128::MAX / 2), &86400_u64, &false, &0_u64);
 
     // Slash near-maximum amount first
     client.slash(&admin, &(i128::MAX / 2));
@@ -122,7 +118,6 @@ fn test_i128_large_bond_operations() {
 
     // Create bond with large amount
     let bond = client.create_bond(&identity, &large_amount, &86400_u64, &false, &0_u64);
-    let bond = client.create_bond(&identity, &large_amount, &86400_u64, &false, &0_u64);
     assert_eq!(bond.bonded_amount, large_amount);
 
     // Top up with another large amount (should succeed as sum < i128::MAX)
@@ -142,11 +137,9 @@ fn test_negative_bond_amount_handling() {
 
     let identity = Address::generate(&e);
 
-    // Test with negative amount (technically allowed by i128, but may be business logic violation)
-    // This documents current behavior
-    let bond = client.create_bond(&identity, &(-1000), &86400_u64, &false, &0_u64);
-    let bond = client.create_bond(&identity, &(-1000), &86400_u64, &false, &0_u64);
-    assert_eq!(bond.bonded_amount, -1000);
+    // Negative amounts are representable in i128 but rejected by bond limits
+    let result = client.try_create_bond(&identity, &(-1000), &86400_u64, &false, &0_u64);
+    assert_eq!(result.err(), Some(Ok(BondError::InvalidAmount)));
 }
 
 // ============================================================================
@@ -166,13 +159,12 @@ fn test_u64_max_duration() {
     let identity = Address::generate(&e);
     // Test creating bond with maximum u64 duration
     let bond = client.create_bond(&identity, &1000, &u64::MAX, &false, &0_u64);
-    let bond = client.create_bond(&identity, &1000, &u64::MAX, &false, &0_u64);
 
     assert_eq!(bond.bond_duration, u64::MAX);
 }
 
 #[test]
-#[should_panic(expected = "duration extension caused overflow")]
+#[should_panic(expected = "Error(Contract, #113)")]
 fn test_u64_overflow_on_duration_extension() {
     let e = Env::default();
     e.mock_all_auths();
@@ -185,14 +177,13 @@ fn test_u64_overflow_on_duration_extension() {
     let identity = Address::generate(&e);
     // Create bond with max - 1000 duration
     client.create_bond(&identity, &1000, &(u64::MAX - 1000), &false, &0_u64);
-    client.create_bond(&identity, &1000, &(u64::MAX - 1000), &false, &0_u64);
 
     // Attempt to extend by 2000, which should overflow
     client.extend_duration(&2000);
 }
 
 #[test]
-#[should_panic(expected = "bond end timestamp would overflow")]
+#[should_panic(expected = "Error(Contract, #113)")]
 fn test_u64_overflow_on_end_timestamp() {
     let e = Env::default();
     e.mock_all_auths();
@@ -211,7 +202,6 @@ fn test_u64_overflow_on_end_timestamp() {
     // Create bond with duration that would cause end timestamp to overflow
     // bond_start will be u64::MAX - 1000, adding 2000 duration will overflow
     client.create_bond(&identity, &1000, &2000, &false, &0_u64);
-    client.create_bond(&identity, &1000, &2000, &false, &0_u64);
 }
 
 #[test]
@@ -229,7 +219,6 @@ fn test_u64_large_duration_extension() {
 
     // Create bond with large duration
     let bond = client.create_bond(&identity, &1000, &duration, &false, &0_u64);
-    let bond = client.create_bond(&identity, &1000, &duration, &false, &0_u64);
     assert_eq!(bond.bond_duration, duration);
 
     // Extend with another large duration (should succeed as sum < u64::MAX)
@@ -255,7 +244,6 @@ fn test_timestamp_boundary_conditions() {
     let identity = Address::generate(&e);
     // Create bond with safe duration
     let bond = client.create_bond(&identity, &1000, &5000, &false, &0_u64);
-    let bond = client.create_bond(&identity, &1000, &5000, &false, &0_u64);
 
     assert_eq!(bond.bond_duration, 5000);
     assert!(bond.bond_start >= u64::MAX - 10000);
@@ -266,7 +254,7 @@ fn test_timestamp_boundary_conditions() {
 // ============================================================================
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #107)")]
 fn test_withdrawal_exceeds_available_balance() {
     let e = Env::default();
     e.mock_all_auths();
@@ -278,14 +266,13 @@ fn test_withdrawal_exceeds_available_balance() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Attempt to withdraw more than available
     client.withdraw(&1001);
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #107)")]
 fn test_withdrawal_after_slashing() {
     let e = Env::default();
     e.mock_all_auths();
@@ -297,7 +284,6 @@ fn test_withdrawal_after_slashing() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash 400
     client.slash(&admin, &400);
@@ -319,7 +305,6 @@ fn test_withdrawal_exact_available_balance() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Withdraw exact available amount
     let bond = client.withdraw(&1000);
@@ -338,7 +323,6 @@ fn test_withdrawal_zero_amount() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Withdraw zero amount (should succeed)
     let bond = client.withdraw(&0);
@@ -346,7 +330,7 @@ fn test_withdrawal_zero_amount() {
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #107)")]
 fn test_multiple_withdrawals_causing_underflow() {
     let e = Env::default();
     e.mock_all_auths();
@@ -358,7 +342,6 @@ fn test_multiple_withdrawals_causing_underflow() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Multiple withdrawals
     client.withdraw(&400);
@@ -379,7 +362,6 @@ fn test_withdrawal_with_max_i128_bond() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
 
     // Withdraw large amount
     let bond = client.withdraw(&(i128::MAX / 2));
@@ -387,7 +369,7 @@ fn test_withdrawal_with_max_i128_bond() {
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #107)")]
 fn test_withdrawal_when_fully_slashed() {
     let e = Env::default();
     e.mock_all_auths();
@@ -399,7 +381,6 @@ fn test_withdrawal_when_fully_slashed() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash entire amount
     client.slash(&admin, &1000);
@@ -425,7 +406,6 @@ fn test_slashing_normal_amount() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash normal amount
     let bond = client.slash(&admin, &300);
@@ -445,10 +425,9 @@ fn test_slashing_exceeds_bonded_amount() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash more than bonded amount (should cap at bonded amount)
-    let bond = client.slash(&admin, &2000);
+    let _bond = client.slash(&admin, &2000);
     let bond = client.slash(&admin, &2000);
     assert_eq!(bond.slashed_amount, 1000); // Capped at bonded_amount
     assert_eq!(bond.bonded_amount, 1000);
@@ -466,7 +445,6 @@ fn test_multiple_slashing_operations() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Multiple slashing operations
     let bond = client.slash(&admin, &200);
@@ -491,10 +469,9 @@ fn test_slashing_zero_amount() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash zero amount
-    let bond = client.slash(&admin, &0);
+    let _bond = client.slash(&admin, &0);
     let bond = client.slash(&admin, &0);
     assert_eq!(bond.slashed_amount, 0);
 }
@@ -511,7 +488,6 @@ fn test_slashing_after_withdrawal() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Withdraw first
     client.withdraw(&300);
@@ -534,7 +510,6 @@ fn test_slashing_with_max_values() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
 
     // Slash large amount
     let bond = client.slash(&admin, &(i128::MAX / 2));
@@ -558,7 +533,6 @@ fn test_complex_arithmetic_scenario() {
     let identity = Address::generate(&e);
     // Initial bond
     client.create_bond(&identity, &10000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &10000, &86400_u64, &false, &0_u64);
 
     // Top up
     let bond = client.top_up(&5000);
@@ -578,7 +552,7 @@ fn test_complex_arithmetic_scenario() {
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #107)")]
 fn test_withdrawal_leaves_insufficient_for_slashed() {
     let e = Env::default();
     e.mock_all_auths();
@@ -590,7 +564,6 @@ fn test_withdrawal_leaves_insufficient_for_slashed() {
 
     let identity = Address::generate(&e);
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash 500
     client.slash(&admin, &500);
@@ -612,16 +585,19 @@ fn test_boundary_arithmetic_with_zero_values() {
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    // Create bond with zero amount
-    let bond = client.create_bond(&identity, &0, &86400_u64, &false, &0_u64);
-    let bond = client.create_bond(&identity, &0, &86400_u64, &false, &0_u64);
-    assert_eq!(bond.bonded_amount, 0);
+    // Zero-amount bonds are rejected
+    let result = client.try_create_bond(&identity, &0, &86400_u64, &false, &0_u64);
+    assert_eq!(result.err(), Some(Ok(BondError::InvalidAmount)));
 
-    // Try operations on zero bond
-    let bond = client.slash(&admin, &0);
+    // Zero-valued operations on a bond leave it unchanged
+    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
     let bond = client.slash(&admin, &0);
     assert_eq!(bond.slashed_amount, 0);
 
     let bond = client.withdraw(&0);
-    assert_eq!(bond.bonded_amount, 0);
+    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(
+        client.try_top_up(&0).err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
 }
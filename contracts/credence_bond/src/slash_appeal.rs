@@ -0,0 +1,230 @@
+//! Slash Appeals
+//!
+//! With an appeal window configured, an executed governance slash is only provisional. The
+//! slashed funds are locked in the bond but held back from treasury settlement, and the
+//! slashed identity may appeal by opening a dispute in the dispute contract before the
+//! window closes. The appeal dispute is linked to this contract, so the dispute contract
+//! reports its outcome back through `resolve_slash_appeal`: a dispute resolved for the
+//! slasher finalizes the slash, one resolved for the identity reverses it. Unappealed slashes
+//! are finalized with `finalize` once the window elapsed. A window of 0 (the default)
+//! finalizes governance slashes immediately.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::dispute_linkage::{self, LinkedDisputeOutcome};
+use crate::slash_records::{self, SlashSource};
+use crate::{counter_attestation, slash_reversal, slash_settlement, token_info, BondError};
+
+/// Longest allowed appeal window (30 days).
+pub const MAX_APPEAL_WINDOW: u64 = 30 * 86_400;
+
+/// Lifecycle of a pending slash.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AppealStatus {
+    /// Inside the appeal window, not appealed.
+    Pending = 0,
+    /// A dispute is open against the slash.
+    Appealed = 1,
+    /// The dispute was resolved for the identity; the slash was reversed.
+    Overturned = 2,
+    /// The slash is final and its funds were released for settlement.
+    Finalized = 3,
+}
+
+/// A governance slash awaiting finalization.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingSlash {
+    pub proposal_id: u64,
+    pub identity: Address,
+    /// Amount actually slashed from the bond.
+    pub amount: i128,
+    /// Timestamp at which the appeal window closes.
+    pub finalize_after: u64,
+    /// Dispute opened by the appeal (0 if none).
+    pub dispute_id: u64,
+    pub status: AppealStatus,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum AppealKey {
+    /// Appeal window in seconds.
+    AppealWindow,
+    /// Pending slash by proposal id.
+    Slash(u64),
+}
+
+/// Appeal window in seconds (0 if never set).
+#[must_use]
+pub fn get_window(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&AppealKey::AppealWindow)
+        .unwrap_or(0)
+}
+
+/// Set the appeal window. Admin only (enforced by caller).
+pub fn set_window(e: &Env, seconds: u64) {
+    if seconds > MAX_APPEAL_WINDOW {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&AppealKey::AppealWindow, &seconds);
+}
+
+#[must_use]
+pub fn get(e: &Env, proposal_id: u64) -> Option<PendingSlash> {
+    e.storage().instance().get(&AppealKey::Slash(proposal_id))
+}
+
+fn load(e: &Env, proposal_id: u64) -> PendingSlash {
+    get(e, proposal_id).unwrap_or_else(|| panic_with_error!(e, BondError::SlashNotFound))
+}
+
+fn save(e: &Env, slash: &PendingSlash) {
+    e.storage()
+        .instance()
+        .set(&AppealKey::Slash(slash.proposal_id), slash);
+}
+
+/// True while the slash executed for `proposal_id` is not final (pending or under appeal).
+#[must_use]
+pub fn is_open(e: &Env, proposal_id: u64) -> bool {
+    matches!(
+        get(e, proposal_id).map(|s| s.status),
+        Some(AppealStatus::Pending | AppealStatus::Appealed)
+    )
+}
+
+/// Hold back `amount` just slashed for `proposal_id` until the appeal window closes.
+pub fn open(e: &Env, proposal_id: u64, identity: &Address, amount: i128) -> PendingSlash {
+    slash_settlement::withhold(e, amount);
+    let finalize_after = e.ledger().timestamp().saturating_add(get_window(e));
+    let slash = PendingSlash {
+        proposal_id,
+        identity: identity.clone(),
+        amount,
+        finalize_after,
+        dispute_id: 0,
+        status: AppealStatus::Pending,
+    };
+    save(e, &slash);
+    e.events().publish(
+        (Symbol::new(e, "slash_pending"), proposal_id),
+        (identity.clone(), amount, finalize_after),
+    );
+    slash
+}
+
+/// Appeal a pending slash by opening a dispute with `stake` of the bond token in the
+/// dispute contract, linked back to this contract and `proposal_id`. Returns the dispute id.
+/// Caller must enforce identity auth.
+pub fn appeal(
+    e: &Env,
+    identity: &Address,
+    proposal_id: u64,
+    stake: i128,
+    resolution_deadline: u64,
+) -> u64 {
+    let mut slash = load(e, proposal_id);
+    if slash.identity != *identity {
+        panic_with_error!(e, BondError::Unauthorized);
+    }
+    if slash.status != AppealStatus::Pending {
+        panic_with_error!(e, BondError::InvalidDisputeState);
+    }
+    if e.ledger().timestamp() >= slash.finalize_after {
+        panic_with_error!(e, BondError::ClaimExpired);
+    }
+    let dispute = counter_attestation::get_dispute_contract(e)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::ConfigNotSet));
+    let token = token_info::get(e)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::TokenNotSet))
+        .address;
+    let args: Vec<Val> = (
+        identity.clone(),
+        e.current_contract_address(),
+        proposal_id,
+        stake,
+        token,
+        resolution_deadline,
+    )
+        .into_val(e);
+    let dispute_id: u64 =
+        e.invoke_contract(&dispute, &Symbol::new(e, "create_slash_dispute"), args);
+    slash.dispute_id = dispute_id;
+    slash.status = AppealStatus::Appealed;
+    save(e, &slash);
+    dispute_linkage::link(e, identity, dispute_id);
+    e.events().publish(
+        (Symbol::new(e, "slash_appealed"), proposal_id),
+        (identity.clone(), dispute_id),
+    );
+    dispute_id
+}
+
+/// Apply the outcome of the appeal dispute. `FavorDisputer` reverses what is left of the
+/// slash; `FavorSlasher` finalizes it. Authorization is enforced by the caller
+/// (see [`slash_reversal::require_authorized`] for `SlashSource::Dispute(dispute_id)`).
+pub fn resolve(
+    e: &Env,
+    proposal_id: u64,
+    outcome: LinkedDisputeOutcome,
+    authorized_by: &Address,
+) -> PendingSlash {
+    let mut slash = load(e, proposal_id);
+    if slash.status != AppealStatus::Appealed {
+        panic_with_error!(e, BondError::InvalidDisputeState);
+    }
+    dispute_linkage::resolve(e, &slash.identity, slash.dispute_id, outcome);
+    match outcome {
+        LinkedDisputeOutcome::FavorDisputer => {
+            slash.status = AppealStatus::Overturned;
+            save(e, &slash);
+            let source = SlashSource::Proposal(proposal_id);
+            let record = slash_records::get(e, &source)
+                .unwrap_or_else(|| panic_with_error!(e, BondError::SlashNotFound));
+            let remaining = record.amount - record.reversed;
+            if remaining > 0 {
+                // The held-back funds never entered the settlement balance, which the
+                // reversal withholds from.
+                slash_settlement::accrue(e, remaining);
+                slash_reversal::reverse(e, &source, remaining, authorized_by);
+            }
+        }
+        LinkedDisputeOutcome::FavorSlasher => release(e, &mut slash),
+        LinkedDisputeOutcome::None => panic_with_error!(e, BondError::InvalidInput),
+    }
+    e.events().publish(
+        (Symbol::new(e, "slash_appeal_resolved"), proposal_id),
+        (slash.dispute_id, outcome),
+    );
+    slash
+}
+
+/// Make an unappealed pending slash final once its appeal window elapsed. Callable by anyone.
+pub fn finalize(e: &Env, proposal_id: u64) -> PendingSlash {
+    let mut slash = load(e, proposal_id);
+    if slash.status != AppealStatus::Pending {
+        panic_with_error!(e, BondError::InvalidDisputeState);
+    }
+    if e.ledger().timestamp() < slash.finalize_after {
+        panic_with_error!(e, BondError::NoticeNotElapsed);
+    }
+    release(e, &mut slash);
+    slash
+}
+
+/// Mark `slash` final and release its funds for settlement.
+fn release(e: &Env, slash: &mut PendingSlash) {
+    slash.status = AppealStatus::Finalized;
+    save(e, slash);
+    slash_settlement::accrue(e, slash.amount);
+    e.events().publish(
+        (Symbol::new(e, "slash_finalized"), slash.proposal_id),
+        (slash.identity.clone(), slash.amount),
+    );
+}
@@ -0,0 +1,141 @@
+//! Slash History
+//!
+//! `slashed_amount` only holds the running total. This module keeps one record per slash that
+//! actually moved it, on every path (admin, governance execution, dispute decisions), so
+//! auditors can see who slashed how much, when and on what authority. Slashes executed for a
+//! governance proposal carry its id.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::slash_records::SlashSource;
+
+/// Largest page returned by [`list`].
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Authority a slash was executed on.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SlashReason {
+    /// Direct admin slash not tied to a decision.
+    Admin = 0,
+    /// Execution of a governance slash proposal.
+    Proposal = 1,
+    /// Execution of a dispute decision.
+    Dispute = 2,
+}
+
+/// One executed slash.
+///
+/// # Fields
+/// * `id` - Sequential id, starting at 0.
+/// * `identity` - Identity whose bond was slashed.
+/// * `amount` - Amount actually added to `slashed_amount` (after over-slash capping).
+/// * `executed_by` - Admin or proposer that executed the slash.
+/// * `reason` - Authority the slash was executed on.
+/// * `timestamp` - Ledger timestamp of execution.
+/// * `proposal_id` - Governance proposal executed, if any.
+/// * `dispute_id` - Dispute decision executed, if any.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashHistoryRecord {
+    pub id: u64,
+    pub identity: Address,
+    pub amount: i128,
+    pub executed_by: Address,
+    pub reason: SlashReason,
+    pub timestamp: u64,
+    pub proposal_id: Option<u64>,
+    pub dispute_id: Option<u64>,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum SlashHistoryKey {
+    /// Id the next record gets.
+    NextSlashRecordId,
+    /// Record by id.
+    SlashHistory(u64),
+    /// Record ids of an identity, oldest first.
+    IdentitySlashes(Address),
+}
+
+/// Record a slash of `amount` against `identity`, executed by `executed_by` for `source`
+/// (`None` for a direct admin slash). Returns the record id.
+pub fn record(
+    e: &Env,
+    identity: &Address,
+    amount: i128,
+    executed_by: &Address,
+    source: Option<&SlashSource>,
+) -> u64 {
+    let id: u64 = e
+        .storage()
+        .instance()
+        .get(&SlashHistoryKey::NextSlashRecordId)
+        .unwrap_or(0);
+    let (reason, proposal_id, dispute_id) = match source {
+        None => (SlashReason::Admin, None, None),
+        Some(SlashSource::Proposal(p)) => (SlashReason::Proposal, Some(*p), None),
+        Some(SlashSource::Dispute(d)) => (SlashReason::Dispute, None, Some(*d)),
+    };
+    let record = SlashHistoryRecord {
+        id,
+        identity: identity.clone(),
+        amount,
+        executed_by: executed_by.clone(),
+        reason,
+        timestamp: e.ledger().timestamp(),
+        proposal_id,
+        dispute_id,
+    };
+    e.storage()
+        .instance()
+        .set(&SlashHistoryKey::SlashHistory(id), &record);
+    e.storage()
+        .instance()
+        .set(&SlashHistoryKey::NextSlashRecordId, &(id + 1));
+
+    let key = SlashHistoryKey::IdentitySlashes(identity.clone());
+    let mut ids: Vec<u64> = e.storage().instance().get(&key).unwrap_or(Vec::new(e));
+    ids.push_back(id);
+    e.storage().instance().set(&key, &ids);
+    id
+}
+
+#[must_use]
+pub fn get(e: &Env, id: u64) -> Option<SlashHistoryRecord> {
+    e.storage()
+        .instance()
+        .get(&SlashHistoryKey::SlashHistory(id))
+}
+
+/// Number of slashes recorded against `identity`.
+#[must_use]
+pub fn count(e: &Env, identity: &Address) -> u32 {
+    e.storage()
+        .instance()
+        .get::<_, Vec<u64>>(&SlashHistoryKey::IdentitySlashes(identity.clone()))
+        .map(|ids| ids.len())
+        .unwrap_or(0)
+}
+
+/// Slash records of `identity`, oldest first, skipping `offset` and returning at most
+/// `limit` (capped at `MAX_PAGE_SIZE`).
+#[must_use]
+pub fn list(e: &Env, identity: &Address, offset: u32, limit: u32) -> Vec<SlashHistoryRecord> {
+    let ids: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&SlashHistoryKey::IdentitySlashes(identity.clone()))
+        .unwrap_or(Vec::new(e));
+    let end = offset
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(ids.len());
+    let mut page = Vec::new(e);
+    for i in offset..end {
+        if let Some(record) = get(e, ids.get_unchecked(i)) {
+            page.push_back(record);
+        }
+    }
+    page
+}
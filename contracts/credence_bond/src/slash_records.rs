@@ -0,0 +1,94 @@
+//! Slash Records
+//!
+//! Links each executed slash to the proposal or dispute that authorized it, so a single
+//! decision can change the bond balance at most once, whichever path executes it: governance
+//! execution of a proposal and the admin path citing the same proposal or dispute are
+//! mutually exclusive.
+//!
+//! Later partial reversals (see `slash_reversal`) are accumulated on the record.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{BondError, DataKey, IdentityBond};
+
+/// Decision a slash is executed for.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SlashSource {
+    /// Governance slash proposal id.
+    Proposal(u64),
+    /// Dispute id (as assigned by the dispute resolution contract).
+    Dispute(u64),
+}
+
+/// A slash applied for a `SlashSource`.
+///
+/// # Fields
+/// * `source` - Decision the slash executed.
+/// * `identity` - Identity whose bond was slashed.
+/// * `amount` - Amount actually added to `slashed_amount` (after over-slash capping).
+/// * `executed_at` - Ledger timestamp of execution.
+/// * `reversed` - Part of `amount` reversed since (never more than `amount`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashRecord {
+    pub source: SlashSource,
+    pub identity: Address,
+    pub amount: i128,
+    pub executed_at: u64,
+    pub reversed: i128,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum SlashRecordKey {
+    /// Record of the slash executed for a source.
+    Applied(SlashSource),
+}
+
+/// Slash executed for `source`, if any.
+#[must_use]
+pub fn get(e: &Env, source: &SlashSource) -> Option<SlashRecord> {
+    e.storage()
+        .instance()
+        .get(&SlashRecordKey::Applied(source.clone()))
+}
+
+/// Panics if a slash was already executed for `source`.
+pub fn require_not_applied(e: &Env, source: &SlashSource) {
+    if e.storage()
+        .instance()
+        .has(&SlashRecordKey::Applied(source.clone()))
+    {
+        panic_with_error!(e, BondError::SlashAlreadyApplied);
+    }
+}
+
+/// Record that a slash of `amount` was executed for `source` (panics if one already was).
+pub fn record(e: &Env, source: &SlashSource, amount: i128) {
+    require_not_applied(e, source);
+    let bond: IdentityBond = e
+        .storage()
+        .instance()
+        .get(&DataKey::Bond)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::NoBond));
+    let record = SlashRecord {
+        source: source.clone(),
+        identity: bond.identity,
+        amount,
+        executed_at: e.ledger().timestamp(),
+        reversed: 0,
+    };
+    save(e, &record);
+    e.events().publish(
+        (Symbol::new(e, "slash_recorded"), source.clone()),
+        (amount, record.executed_at),
+    );
+}
+
+/// Store an updated record.
+pub fn save(e: &Env, record: &SlashRecord) {
+    e.storage()
+        .instance()
+        .set(&SlashRecordKey::Applied(record.source.clone()), record);
+}
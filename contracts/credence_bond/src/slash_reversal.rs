@@ -0,0 +1,139 @@
+//! Slash Reversal
+//!
+//! A dispute or governance review may find an executed slash partly wrong. `reverse` undoes
+//! part of a recorded slash (see `slash_records`). The reversed amount is restored to the
+//! slashed identity's bond while that bond is still active; whatever the bond no longer holds
+//! (it was closed, or its slashed balance was already reduced) is credited as a refund the
+//! identity can claim in the bond token. Claimable refunds count towards the contract's
+//! reserves.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::slash_records::{self, SlashRecord, SlashSource};
+use crate::{counter_attestation, slashing, BondError, DataKey, IdentityBond};
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum ReversalKey {
+    /// Claimable refund of an identity.
+    Refund(Address),
+    /// Sum of all claimable refunds.
+    PendingRefunds,
+}
+
+/// Panics unless `authorized_by` may reverse slashes for `source`: the admin, or the
+/// configured dispute contract for dispute decisions. Caller must enforce
+/// `authorized_by` auth.
+pub fn require_authorized(e: &Env, authorized_by: &Address, source: &SlashSource) {
+    let admin: Address = e
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::NotInitialized));
+    if *authorized_by == admin {
+        return;
+    }
+    let is_dispute_contract = matches!(source, SlashSource::Dispute(_))
+        && counter_attestation::get_dispute_contract(e).as_ref() == Some(authorized_by);
+    if !is_dispute_contract {
+        panic_with_error!(e, BondError::Unauthorized);
+    }
+}
+
+/// Reverse `amount` of the slash executed for `source`. Returns the updated record.
+/// Authorization is enforced by the caller (see [`require_authorized`]).
+pub fn reverse(
+    e: &Env,
+    source: &SlashSource,
+    amount: i128,
+    authorized_by: &Address,
+) -> SlashRecord {
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    let mut record = slash_records::get(e, source)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::SlashNotFound));
+    let reversed = record
+        .reversed
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    if reversed > record.amount {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    record.reversed = reversed;
+    slash_records::save(e, &record);
+
+    let bond: Option<IdentityBond> = e.storage().instance().get(&DataKey::Bond);
+    let restored = match bond {
+        Some(b) if b.active && b.identity == record.identity => amount.min(b.slashed_amount),
+        _ => 0,
+    };
+    if restored > 0 {
+        slashing::restore(e, restored);
+        crate::health_factor::refresh(e);
+    }
+    let refunded = amount - restored;
+    if refunded > 0 {
+        credit_refund(e, &record.identity, refunded);
+        crate::slash_settlement::withhold(e, refunded);
+    }
+
+    e.events().publish(
+        (Symbol::new(e, "slash_reversed"), source.clone()),
+        (authorized_by.clone(), amount, restored, refunded),
+    );
+    record
+}
+
+fn credit_refund(e: &Env, identity: &Address, amount: i128) {
+    let balance = get_refund(e, identity)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&ReversalKey::Refund(identity.clone()), &balance);
+    let pending = pending_refunds(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&ReversalKey::PendingRefunds, &pending);
+}
+
+/// Claimable refund of `identity`.
+#[must_use]
+pub fn get_refund(e: &Env, identity: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&ReversalKey::Refund(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// Sum of all claimable refunds.
+#[must_use]
+pub fn pending_refunds(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&ReversalKey::PendingRefunds)
+        .unwrap_or(0)
+}
+
+/// Pay out `identity`'s refund in the bond token. Caller must enforce identity auth.
+pub fn claim_refund(e: &Env, identity: &Address) -> i128 {
+    let amount = get_refund(e, identity);
+    if amount == 0 {
+        panic_with_error!(e, BondError::NothingToClaim);
+    }
+    e.storage()
+        .instance()
+        .remove(&ReversalKey::Refund(identity.clone()));
+    e.storage()
+        .instance()
+        .set(&ReversalKey::PendingRefunds, &(pending_refunds(e) - amount));
+    crate::funding::release(e, identity, amount);
+    e.events().publish(
+        (Symbol::new(e, "slash_refund_claimed"), identity.clone()),
+        amount,
+    );
+    amount
+}
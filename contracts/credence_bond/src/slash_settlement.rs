@@ -0,0 +1,156 @@
+//! Slash Settlement
+//!
+//! Slashing only moves funds on paper: `slashed_amount` grows and the identity can withdraw
+//! less. The slashed tokens stay in this contract until they are settled. Each slash adds the
+//! amount actually slashed to the pending proceeds; a beneficiary's compensation share and any
+//! slash restored before settlement are withheld again. `settle` transfers the pending
+//! proceeds to the configured treasury and, when the treasury is a credence_treasury
+//! contract, books them there with `receive_fee(.., FundSource::SlashedFunds)` (the bond
+//! contract must be an authorized depositor). With `auto_settle` on, every slash entrypoint
+//! settles right away.
+
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::{reserves, token_info, BondError};
+
+/// Where and when slash proceeds are settled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashSettlementConfig {
+    /// Recipient of slash proceeds; `None` until configured.
+    pub treasury: Option<Address>,
+    /// True if `treasury` is a credence_treasury contract to notify with `receive_fee`.
+    pub notify_treasury: bool,
+    /// True to settle at slash time instead of waiting for `settle_slash`.
+    pub auto_settle: bool,
+}
+
+/// Mirror of credence_treasury's `FundSource` (same discriminants).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TreasuryFundSource {
+    ProtocolFee = 0,
+    SlashedFunds = 1,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum SettlementKey {
+    Config,
+    /// Slashed tokens still held by this contract.
+    ProceedsPending,
+    /// Total slashed tokens transferred to the treasury so far.
+    Settled,
+}
+
+/// Set the settlement config. Admin only (enforced by caller).
+pub fn set_config(e: &Env, config: &SlashSettlementConfig) {
+    e.storage().instance().set(&SettlementKey::Config, config);
+    e.events().publish(
+        (Symbol::new(e, "slash_settlement_set"),),
+        (
+            config.treasury.clone(),
+            config.notify_treasury,
+            config.auto_settle,
+        ),
+    );
+}
+
+/// Settlement config (no treasury, manual settlement if never set).
+#[must_use]
+pub fn get_config(e: &Env) -> SlashSettlementConfig {
+    e.storage()
+        .instance()
+        .get(&SettlementKey::Config)
+        .unwrap_or(SlashSettlementConfig {
+            treasury: None,
+            notify_treasury: false,
+            auto_settle: false,
+        })
+}
+
+/// Slash proceeds not yet transferred to the treasury.
+#[must_use]
+pub fn pending(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&SettlementKey::ProceedsPending)
+        .unwrap_or(0)
+}
+
+/// Slash proceeds transferred to the treasury so far.
+#[must_use]
+pub fn settled(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&SettlementKey::Settled)
+        .unwrap_or(0)
+}
+
+/// Add `amount` actually slashed from the bond to the pending proceeds.
+pub fn accrue(e: &Env, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let total = pending(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&SettlementKey::ProceedsPending, &total);
+}
+
+/// Keep up to `amount` of the pending proceeds in the contract (compensation escrow, or a
+/// slash restored to the bond before it was settled).
+pub fn withhold(e: &Env, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let remaining = pending(e).saturating_sub(amount).max(0);
+    e.storage()
+        .instance()
+        .set(&SettlementKey::ProceedsPending, &remaining);
+}
+
+/// Transfer the pending proceeds to the treasury and return the amount. Callable by anyone.
+pub fn settle(e: &Env) -> i128 {
+    let config = get_config(e);
+    let treasury = config
+        .treasury
+        .unwrap_or_else(|| panic_with_error!(e, BondError::ConfigNotSet));
+    let info = token_info::get(e).unwrap_or_else(|| panic_with_error!(e, BondError::TokenNotSet));
+    let amount = pending(e);
+    if amount == 0 {
+        return 0;
+    }
+    reserves::require_covered(e, amount);
+    e.storage()
+        .instance()
+        .set(&SettlementKey::ProceedsPending, &0_i128);
+    let total = settled(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage().instance().set(&SettlementKey::Settled, &total);
+
+    let this = e.current_contract_address();
+    token::Client::new(e, &info.address).transfer(&this, &treasury, &amount);
+    if config.notify_treasury {
+        let args: Vec<Val> = (this, amount, TreasuryFundSource::SlashedFunds).into_val(e);
+        e.invoke_contract::<()>(&treasury, &Symbol::new(e, "receive_fee"), args);
+    }
+    e.events().publish(
+        (Symbol::new(e, "slash_settled"), treasury),
+        (info.address, amount),
+    );
+    amount
+}
+
+/// Settle now if `auto_settle` is on and a bond token is set. Returns the amount settled.
+pub fn settle_if_auto(e: &Env) -> i128 {
+    let config = get_config(e);
+    if config.auto_settle && config.treasury.is_some() && token_info::get(e).is_some() {
+        settle(e)
+    } else {
+        0
+    }
+}
@@ -5,8 +5,8 @@
 //! treasury fund transfers.
 //!
 //! ## Authorization
-//! Only the contract admin can execute slashing operations. Non-admin calls panic with
-//! "not admin" error message.
+//! Only the contract admin can execute slashing operations. Non-admin calls fail with
+//! `BondError::NotAdmin`.
 //!
 //! ## Design
 //! - **Partial Slashing**: Can slash any amount up to bonded_amount
@@ -15,7 +15,9 @@
 //! - **Over-slash Protection**: Ensures slashed_amount never exceeds bonded_amount
 //! - **Withdrawals**: Affected by slashing (withdrawable = bonded - slashed)
 
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{panic_with_error, Address, Env, Symbol};
+
+use crate::BondError;
 
 /// Storage key for tracking accumulated slashed funds (for treasury transfer purposes).
 /// Not currently used for fund transfers in this implementation, but reserved for future use.
@@ -49,16 +51,18 @@ pub fn get_slashed_amount(e: &Env, _bond_identity: &Address) -> i128 {
 /// * `caller` - Address to validate as admin
 ///
 /// # Panics
-/// If caller is not the stored admin address with message "not admin"
+/// With `NotAdmin` if caller is not the stored admin address, or if the caller
+/// did not authorize the invocation
 pub fn validate_admin(e: &Env, caller: &Address) {
     let stored_admin: Address = e
         .storage()
         .instance()
         .get(&crate::DataKey::Admin)
-        .unwrap_or_else(|| panic!("not initialized"));
+        .unwrap_or_else(|| panic_with_error!(e, BondError::NotInitialized));
     if caller != &stored_admin {
-        panic!("not admin");
+        panic_with_error!(e, BondError::NotAdmin);
     }
+    caller.require_auth();
 }
 
 /// NatSpec-style: Core slashing logic for reducing bond value.
@@ -80,32 +84,74 @@ pub fn validate_admin(e: &Env, caller: &Address) {
 /// Updated `IdentityBond` with modified `slashed_amount`
 ///
 /// # Panics
-/// - "not admin" if caller is not the contract admin
-/// - "not initialized" if contract not initialized
-/// - "no bond" if no bond exists for this contract instance
+/// - `NotAdmin` if caller is not the contract admin
+/// - `NotInitialized` if contract not initialized
+/// - `NoBond` if no bond exists for this contract instance
+/// - `Unauthorized` once slash governance is initialized
 /// - If arithmetic overflows (checked_add protection)
 ///
 /// # Security Notes
 /// - Over-slash is prevented by capping at bonded_amount
 /// - Slashing is monotonic (always increases or stays same, never decreases)
-/// - Cannot slash bonds that don't exist (panic on "no bond")
+/// - Cannot slash bonds that don't exist (panic with `NoBond`)
+/// - With governance on, a slash without a source could repeat one a proposal already
+///   applied, so it must go through `slash_bond_for` with the decision it executes
 pub fn slash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond {
+    if crate::governance_approval::is_initialized(e) {
+        panic_with_error!(e, BondError::Unauthorized);
+    }
+    slash_bond_for(e, admin, amount, None)
+}
+
+/// NatSpec-style: `slash_bond` executing a specific proposal or dispute decision.
+///
+/// The slash history record links `source` (`None` for a direct admin slash).
+///
+/// # Arguments
+/// * `e` - Soroban environment
+/// * `admin` - Address executing the slash (admin, or the proposer for governance)
+/// * `amount` - Amount to slash (i128)
+/// * `source` - Decision executed, if any
+pub fn slash_bond_for(
+    e: &Env,
+    admin: &Address,
+    amount: i128,
+    source: Option<&crate::slash_records::SlashSource>,
+) -> crate::IdentityBond {
     // 1. Authorization check
     validate_admin(e, admin);
+    apply_slash(e, admin, amount, source)
+}
 
+/// NatSpec-style: Slashes the bond without an authorization check.
+///
+/// For decisions already authorized elsewhere, e.g. an approved governance proposal.
+///
+/// # Arguments
+/// * `e` - Soroban environment
+/// * `executor` - Address recorded as executing the slash
+/// * `amount` - Amount to slash (i128)
+/// * `source` - Decision executed, if any
+pub fn apply_slash(
+    e: &Env,
+    executor: &Address,
+    amount: i128,
+    source: Option<&crate::slash_records::SlashSource>,
+) -> crate::IdentityBond {
     // 2. Retrieve current bond state
     let key = crate::DataKey::Bond;
     let mut bond = e
         .storage()
         .instance()
         .get::<_, crate::IdentityBond>(&key)
-        .unwrap_or_else(|| panic!("no bond"));
+        .unwrap_or_else(|| panic_with_error!(e, BondError::NoBond));
 
     // 3. Calculate new slashed amount with overflow protection
+    let previous_slashed = bond.slashed_amount;
     let new_slashed = bond
         .slashed_amount
         .checked_add(amount)
-        .expect("slashing caused overflow");
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
 
     // 4. Cap slashing at bonded amount (over-slash prevention)
     bond.slashed_amount = if new_slashed > bond.bonded_amount {
@@ -114,11 +160,29 @@ pub fn slash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond
         new_slashed
     };
 
-    // 5. Persist updated bond state
+    // 5. Persist updated bond state and owe the slashed tokens to the treasury
+    crate::reward_accrual::checkpoint(e);
     e.storage().instance().set(&key, &bond);
+    crate::slash_settlement::accrue(e, bond.slashed_amount - previous_slashed);
 
     // 6. Emit slashing event for off-chain tracking
     emit_slashing_event(e, &bond.identity, amount, bond.slashed_amount);
+    if bond.bonded_amount > 0 && is_fully_slashed(bond.bonded_amount, bond.slashed_amount) {
+        crate::probation::record_full_slash(e, &bond.identity);
+    }
+    crate::health_factor::refresh(e);
+    crate::verification_snapshot::refresh_bond(e);
+    if bond.slashed_amount > previous_slashed {
+        crate::slash_history::record(
+            e,
+            &bond.identity,
+            bond.slashed_amount - previous_slashed,
+            executor,
+            source,
+        );
+        crate::reputation::record_slash(e, &bond.identity);
+    }
+    crate::auto_topup::restore_tier(e, &mut bond);
 
     // 7. Return updated bond state
     bond
@@ -138,24 +202,42 @@ pub fn slash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond
 /// Updated bond with reduced slashed_amount
 ///
 /// # Panics
-/// - "not admin" if not authorized
+/// - `NotAdmin` if not authorized
 /// - If amount would reduce slashed_amount below 0
 pub fn unslash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond {
     validate_admin(e, admin);
+    restore(e, amount)
+}
 
+/// NatSpec-style: Reduces the slashed amount without an authorization check.
+///
+/// # Arguments
+/// * `e` - Soroban environment
+/// * `amount` - Amount to restore to the bond (i128)
+///
+/// # Panics
+/// - `NoBond` if no bond exists
+/// - If amount would reduce slashed_amount below 0
+pub fn restore(e: &Env, amount: i128) -> crate::IdentityBond {
     let key = crate::DataKey::Bond;
     let mut bond = e
         .storage()
         .instance()
         .get::<_, crate::IdentityBond>(&key)
-        .unwrap_or_else(|| panic!("no bond"));
+        .unwrap_or_else(|| panic_with_error!(e, BondError::NoBond));
 
     bond.slashed_amount = bond
         .slashed_amount
         .checked_sub(amount)
-        .expect("unslashing would reduce below 0");
+        .unwrap_or_else(|| panic_with_error!(e, BondError::InvalidAmount));
 
+    crate::reward_accrual::checkpoint(e);
     e.storage().instance().set(&key, &bond);
+    crate::slash_settlement::withhold(e, amount);
+    if !is_fully_slashed(bond.bonded_amount, bond.slashed_amount) {
+        crate::probation::clear_full_slash(e, &bond.identity);
+    }
+    crate::verification_snapshot::refresh_bond(e);
     emit_unslashing_event(e, &bond.identity, amount, bond.slashed_amount);
 
     bond
@@ -0,0 +1,185 @@
+//! Sub-Bonds
+//!
+//! Lets an identity stagger lock-ups without a second identity. `split` carves an amount out
+//! of the bond into a sub-bond with its own lock-up, stored under the composite key
+//! (identity, id). The amount stays in the bond (it still counts for the tier and can still
+//! be slashed) but is held back from withdrawals and exit requests. `merge` folds sub-bonds
+//! back into the bond; a sub-bond locked past the bond's lock-up end extends the bond, so
+//! merging never shortens a commitment.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+use crate::{bond_limits, time_math, unbond_cooldown, unbonding_queue, BondError, IdentityBond};
+
+/// Most sub-bonds an identity may hold at once.
+pub const MAX_SUB_BONDS: u32 = 10;
+
+/// A tranche of the bond with its own lock-up.
+///
+/// # Fields
+/// * `id` - Id, unique per identity.
+/// * `amount` - Amount held back from withdrawals.
+/// * `start` - Ledger timestamp of the split.
+/// * `duration` - Lock-up duration in seconds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubBond {
+    pub id: u32,
+    pub amount: i128,
+    pub start: u64,
+    pub duration: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum SubBondKey {
+    /// Sub-bond by (identity, id).
+    SubBond(Address, u32),
+    /// Ids of an identity's sub-bonds, oldest first.
+    SubBondIds(Address),
+    /// Id the identity's next sub-bond gets.
+    NextSubBondId(Address),
+    /// Sum of the identity's sub-bond amounts.
+    SubBondTotal(Address),
+}
+
+fn ids(e: &Env, identity: &Address) -> Vec<u32> {
+    e.storage()
+        .instance()
+        .get(&SubBondKey::SubBondIds(identity.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+#[must_use]
+pub fn get(e: &Env, identity: &Address, id: u32) -> Option<SubBond> {
+    e.storage()
+        .instance()
+        .get(&SubBondKey::SubBond(identity.clone(), id))
+}
+
+/// Sub-bonds of `identity`, oldest first.
+#[must_use]
+pub fn list(e: &Env, identity: &Address) -> Vec<SubBond> {
+    let mut out = Vec::new(e);
+    for id in ids(e, identity).iter() {
+        if let Some(sub) = get(e, identity, id) {
+            out.push_back(sub);
+        }
+    }
+    out
+}
+
+/// Sum of `identity`'s sub-bond amounts.
+#[must_use]
+pub fn total(e: &Env, identity: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&SubBondKey::SubBondTotal(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// Sub-bond total of the stored bond's identity (0 without a bond).
+#[must_use]
+pub fn locked(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get::<_, IdentityBond>(&crate::DataKey::Bond)
+        .map(|bond| total(e, &bond.identity))
+        .unwrap_or(0)
+}
+
+fn set_total(e: &Env, identity: &Address, amount: i128) {
+    e.storage()
+        .instance()
+        .set(&SubBondKey::SubBondTotal(identity.clone()), &amount);
+}
+
+/// Split `amount` of `bond` into a sub-bond locked for `duration`. Caller must enforce
+/// identity auth and that `bond` is the identity's active bond.
+pub fn split(e: &Env, bond: &IdentityBond, amount: i128, duration: u64) -> SubBond {
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    if bond.is_rolling || duration == 0 {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    bond_limits::require_duration(e, duration);
+    let identity = &bond.identity;
+    let mut sub_ids = ids(e, identity);
+    if sub_ids.len() >= MAX_SUB_BONDS {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    let reserved = unbond_cooldown::reserved(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    if reserved > unbonding_queue::tvl(bond) {
+        panic_with_error!(e, BondError::InsufficientBalance);
+    }
+
+    let id: u32 = e
+        .storage()
+        .instance()
+        .get(&SubBondKey::NextSubBondId(identity.clone()))
+        .unwrap_or(0);
+    let sub = SubBond {
+        id,
+        amount,
+        start: e.ledger().timestamp(),
+        duration,
+    };
+    e.storage()
+        .instance()
+        .set(&SubBondKey::SubBond(identity.clone(), id), &sub);
+    e.storage()
+        .instance()
+        .set(&SubBondKey::NextSubBondId(identity.clone()), &(id + 1));
+    sub_ids.push_back(id);
+    e.storage()
+        .instance()
+        .set(&SubBondKey::SubBondIds(identity.clone()), &sub_ids);
+    set_total(e, identity, total(e, identity) + amount);
+    e.events().publish(
+        (Symbol::new(e, "bond_split"), identity.clone()),
+        (id, amount, time_math::period_end(sub.start, sub.duration)),
+    );
+    sub
+}
+
+/// Fold the sub-bonds `merge_ids` back into `bond`, extending its lock-up to the latest
+/// sub-bond end if that is later. Updates `bond` in place (caller persists it) and returns
+/// the amount merged. Caller must enforce identity auth.
+pub fn merge(e: &Env, bond: &mut IdentityBond, merge_ids: &Vec<u32>) -> i128 {
+    if merge_ids.is_empty() {
+        panic_with_error!(e, BondError::InvalidInput);
+    }
+    let identity = bond.identity.clone();
+    let mut sub_ids = ids(e, &identity);
+    let mut merged: i128 = 0;
+    let mut end = time_math::period_end(bond.bond_start, bond.bond_duration);
+    for id in merge_ids.iter() {
+        let sub =
+            get(e, &identity, id).unwrap_or_else(|| panic_with_error!(e, BondError::InvalidInput));
+        merged += sub.amount;
+        end = end.max(time_math::period_end(sub.start, sub.duration));
+        e.storage()
+            .instance()
+            .remove(&SubBondKey::SubBond(identity.clone(), id));
+        if let Some(idx) = sub_ids.first_index_of(id) {
+            sub_ids.remove(idx);
+        }
+    }
+    let duration = end - bond.bond_start;
+    if duration != bond.bond_duration {
+        bond_limits::require_duration(e, duration);
+        bond.bond_duration = duration;
+    }
+    e.storage()
+        .instance()
+        .set(&SubBondKey::SubBondIds(identity.clone()), &sub_ids);
+    set_total(e, &identity, total(e, &identity) - merged);
+    e.events().publish(
+        (Symbol::new(e, "bonds_merged"), identity),
+        (merge_ids.clone(), merged, end),
+    );
+    merged
+}
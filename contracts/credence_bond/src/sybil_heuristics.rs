@@ -0,0 +1,132 @@
+//! Sybil Heuristics
+//!
+//! Keeps the verifier→subject attestation graph on-chain so governance can look for
+//! collusive attestation rings when reviewing slash proposals against attesters, without an
+//! off-chain indexer. For every verifier it stores how many attestations it issued to each
+//! subject and the set of distinct subjects. Counts include attestations that were later
+//! revoked: a revoked attestation is still evidence of the relationship.
+//!
+//! The module only exposes data; interpreting it (e.g. two attesters sharing most of their
+//! subjects, or an attester whose attestations go almost entirely to one subject) is left to
+//! governance.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// How concentrated an attester's attestations are on its most attested subject.
+///
+/// # Fields
+/// * `total_attestations` - Attestations issued.
+/// * `distinct_subjects` - Subjects attested at least once.
+/// * `top_subject` - Subject with the most attestations (the earliest one to reach that
+///   count on ties), if any.
+/// * `top_subject_count` - Attestations issued to `top_subject`.
+/// * `concentration_bps` - `top_subject_count / total_attestations` in basis points.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttesterConcentration {
+    pub total_attestations: u32,
+    pub distinct_subjects: u32,
+    pub top_subject: Option<Address>,
+    pub top_subject_count: u32,
+    pub concentration_bps: u32,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum SybilKey {
+    /// Attestations issued by a verifier to a subject: (verifier, subject) -> u32.
+    Edge(Address, Address),
+    /// Distinct subjects of a verifier, in first-attestation order.
+    Subjects(Address),
+    /// Running totals of a verifier: (total, top subject, top subject count).
+    Totals(Address),
+}
+
+/// Attestations issued by `attester` to `subject`.
+#[must_use]
+pub fn get_edge_count(e: &Env, attester: &Address, subject: &Address) -> u32 {
+    e.storage()
+        .instance()
+        .get(&SybilKey::Edge(attester.clone(), subject.clone()))
+        .unwrap_or(0)
+}
+
+/// Distinct subjects attested by `attester`, in first-attestation order.
+#[must_use]
+pub fn get_subjects(e: &Env, attester: &Address) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&SybilKey::Subjects(attester.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+/// Record an issued attestation from `verifier` to `subject`.
+pub fn record(e: &Env, verifier: &Address, subject: &Address) {
+    let edge = get_edge_count(e, verifier, subject).saturating_add(1);
+    e.storage()
+        .instance()
+        .set(&SybilKey::Edge(verifier.clone(), subject.clone()), &edge);
+    if edge == 1 {
+        let mut subjects = get_subjects(e, verifier);
+        subjects.push_back(subject.clone());
+        e.storage()
+            .instance()
+            .set(&SybilKey::Subjects(verifier.clone()), &subjects);
+    }
+
+    let totals_key = SybilKey::Totals(verifier.clone());
+    let (total, top, top_count): (u32, Option<Address>, u32) = e
+        .storage()
+        .instance()
+        .get(&totals_key)
+        .unwrap_or((0, None, 0));
+    let (top, top_count) = if edge > top_count {
+        (Some(subject.clone()), edge)
+    } else {
+        (top, top_count)
+    };
+    e.storage()
+        .instance()
+        .set(&totals_key, &(total.saturating_add(1), top, top_count));
+}
+
+/// Number of subjects attested by both `attester_a` and `attester_b`.
+#[must_use]
+pub fn shared_subject_count(e: &Env, attester_a: &Address, attester_b: &Address) -> u32 {
+    let subjects_a = get_subjects(e, attester_a);
+    let subjects_b = get_subjects(e, attester_b);
+    let (scan, other) = if subjects_a.len() <= subjects_b.len() {
+        (subjects_a, attester_b)
+    } else {
+        (subjects_b, attester_a)
+    };
+    let mut shared = 0_u32;
+    for subject in scan.iter() {
+        if get_edge_count(e, other, &subject) > 0 {
+            shared += 1;
+        }
+    }
+    shared
+}
+
+/// Subject concentration metrics of `attester`.
+#[must_use]
+pub fn concentration(e: &Env, attester: &Address) -> AttesterConcentration {
+    let (total, top_subject, top_subject_count): (u32, Option<Address>, u32) = e
+        .storage()
+        .instance()
+        .get(&SybilKey::Totals(attester.clone()))
+        .unwrap_or((0, None, 0));
+    let concentration_bps = if total == 0 {
+        0
+    } else {
+        (u64::from(top_subject_count) * 10_000 / u64::from(total)) as u32
+    };
+    AttesterConcentration {
+        total_attestations: total,
+        distinct_subjects: get_subjects(e, attester).len(),
+        top_subject,
+        top_subject_count,
+        concentration_bps,
+    }
+}
@@ -0,0 +1,112 @@
+//! Tests for the per-subject attestation activity feed: recording of added, revoked and
+//! expired actions, and eviction of the oldest entries once the buffer is full.
+
+use crate::activity_feed::{ActivityKind, ACTIVITY_BUFFER_SIZE};
+use crate::{testutils, CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, attester)
+}
+
+#[test]
+fn test_added_and_revoked_recorded() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_recent_activity(&consumer, &subject).len(), 0);
+
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"), &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    client.revoke_attestation(&attester, &att.id, &RevocationReason::IssuedInError, &1_u64);
+
+    let feed = client.get_recent_activity(&consumer, &subject);
+    assert_eq!(feed.len(), 2);
+    let added = feed.get(0).unwrap();
+    assert_eq!(added.kind, ActivityKind::Added);
+    assert_eq!(added.attestation_id, att.id);
+    assert_eq!(added.actor, attester);
+    assert_eq!(added.timestamp, 1000);
+    let revoked = feed.get(1).unwrap();
+    assert_eq!(revoked.kind, ActivityKind::Revoked);
+    assert_eq!(revoked.timestamp, 2000);
+}
+
+#[test]
+fn test_buffer_evicts_oldest() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    let total = ACTIVITY_BUFFER_SIZE as u64 + 3;
+    for i in 0..total {
+        let data = String::from_str(&e, if i % 2 == 0 { "even" } else { "odd" });
+        let att = client.add_attestation(&attester, &subject, &data, &(2 * i));
+        client.revoke_attestation(
+            &attester,
+            &att.id,
+            &RevocationReason::IssuedInError,
+            &(2 * i + 1),
+        );
+    }
+    let consumer = testutils::query_consumer(&e, &client.address);
+    let feed = client.get_recent_activity(&consumer, &subject);
+    assert_eq!(feed.len(), ACTIVITY_BUFFER_SIZE);
+    // Newest entry is the last revocation.
+    let last = feed.get(ACTIVITY_BUFFER_SIZE - 1).unwrap();
+    assert_eq!(last.kind, ActivityKind::Revoked);
+    assert_eq!(last.attestation_id, total - 1);
+}
+
+#[test]
+fn test_feeds_are_per_subject() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let a = Address::generate(&e);
+    let b = Address::generate(&e);
+    client.add_attestation(&attester, &a, &String::from_str(&e, "kyc"), &0_u64);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_recent_activity(&consumer, &a).len(), 1);
+    assert_eq!(client.get_recent_activity(&consumer, &b).len(), 0);
+}
+
+#[test]
+fn test_expiry_recorded_once() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let template =
+        client.create_template(&admin, &BytesN::from_array(&e, &[1; 32]), &50_u32, &100_u64);
+    let subject = Address::generate(&e);
+    let att = client.add_attestation_with_template(
+        &attester,
+        &subject,
+        &template,
+        &String::from_str(&e, "kyc"),
+        &0_u64,
+    );
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    client.record_attestation_expiry(&att.id);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    let feed = client.get_recent_activity(&consumer, &subject);
+    assert_eq!(feed.get(1).unwrap().kind, ActivityKind::Expired);
+    assert!(client.try_record_attestation_expiry(&att.id).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #207)")]
+fn test_expiry_before_expires_at_rejected() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"), &0_u64);
+    client.record_attestation_expiry(&att.id);
+}
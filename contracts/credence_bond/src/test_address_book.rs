@@ -0,0 +1,84 @@
+//! Tests for the cross-contract address book.
+
+use crate::address_book::{ROLE_DISPUTE, ROLE_TREASURY};
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_set_resolve_and_clear_role() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let treasury = Symbol::new(&e, ROLE_TREASURY);
+    assert_eq!(client.resolve(&treasury), None);
+
+    let address = Address::generate(&e);
+    client.set_role_address(&admin, &treasury, &Some(address.clone()));
+    assert_eq!(client.resolve(&treasury), Some(address.clone()));
+    let book = client.get_address_book();
+    assert_eq!(book.len(), 1);
+    assert_eq!(book.get(treasury.clone()), Some(address));
+    assert_eq!(client.export_config().address_book, book);
+    assert_eq!(client.get_admin_action_count(), 1);
+
+    client.set_role_address(&admin, &treasury, &None);
+    assert_eq!(client.resolve(&treasury), None);
+    assert!(client.get_address_book().is_empty());
+}
+
+#[test]
+fn test_conflict_dispute_contract_falls_back_to_book() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let from_book = Address::generate(&e);
+    client.set_role_address(
+        &admin,
+        &Symbol::new(&e, ROLE_DISPUTE),
+        &Some(from_book.clone()),
+    );
+    assert_eq!(
+        client.export_config().conflict_dispute_contract,
+        Some(from_book)
+    );
+
+    let explicit = Address::generate(&e);
+    client.set_conflict_dispute_contract(&admin, &explicit);
+    assert_eq!(
+        client.export_config().conflict_dispute_contract,
+        Some(explicit)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_unknown_role_rejected() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_role_address(
+        &admin,
+        &Symbol::new(&e, "bank"),
+        &Some(Address::generate(&e)),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_role_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let other = Address::generate(&e);
+    client.set_role_address(
+        &other,
+        &Symbol::new(&e, ROLE_TREASURY),
+        &Some(other.clone()),
+    );
+}
@@ -0,0 +1,110 @@
+//! Tests for rate-limited sensitive admin actions and the admin action log.
+
+use crate::admin_actions::{AdminActionKind, AdminRateLimit};
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+fn limit(max_actions: u32, window: u64) -> AdminRateLimit {
+    AdminRateLimit {
+        max_actions,
+        window,
+        fee_delta_bps: 100,
+    }
+}
+
+#[test]
+fn test_defaults_are_unlimited() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let l = client.get_admin_rate_limit();
+    assert_eq!(l.max_actions, 0);
+    assert_eq!(l.fee_delta_bps, 100);
+    assert_eq!(client.get_admin_action_count(), 0);
+}
+
+#[test]
+fn test_sensitive_actions_are_logged() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let treasury = Address::generate(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+
+    client.set_fee_config(&admin, &treasury, &50_u32);
+    client.set_fee_config(&admin, &treasury, &500_u32);
+    client.unregister_attester(&attester);
+    // Unregistering an unknown attester changes nothing and is not logged.
+    client.unregister_attester(&Address::generate(&e));
+
+    let actions = client.get_admin_actions(&0_u64, &10_u32);
+    assert_eq!(actions.len(), 2);
+    let fee = actions.get(0).unwrap();
+    assert_eq!(fee.kind, AdminActionKind::FeeChange);
+    assert_eq!(fee.actor, admin);
+    assert_eq!(fee.target, Some(treasury));
+    assert_eq!(fee.value, 500);
+    let unregister = actions.get(1).unwrap();
+    assert_eq!(unregister.kind, AdminActionKind::UnregisterAttester);
+    assert_eq!(unregister.target, Some(attester));
+    assert_eq!(unregister.seq, 1);
+}
+
+#[test]
+fn test_rate_limit_blocks_then_window_rolls() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_admin_rate_limit(&admin, &limit(3, 3600));
+    let attesters: [Address; 3] = core::array::from_fn(|_| Address::generate(&e));
+    for a in attesters.iter() {
+        client.register_attester(a);
+    }
+    client.unregister_attester(&attesters[0]);
+    client.unregister_attester(&attesters[1]);
+    assert!(client.try_unregister_attester(&attesters[2]).is_err());
+
+    e.ledger().with_mut(|l| l.timestamp += 3600);
+    client.unregister_attester(&attesters[2]);
+    assert_eq!(client.get_admin_action_count(), 4);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn test_rate_limit_covers_limit_changes() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_admin_rate_limit(&admin, &limit(1, 3600));
+    client.set_admin_rate_limit(&admin, &limit(100, 3600));
+}
+
+#[test]
+fn test_pagination() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let treasury = Address::generate(&e);
+    for bps in [200_u32, 400, 600, 800] {
+        client.set_fee_config(&admin, &treasury, &bps);
+    }
+    let page = client.get_admin_actions(&1_u64, &2_u32);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().value, 400);
+    assert_eq!(page.get(1).unwrap().value, 600);
+    assert_eq!(client.get_admin_actions(&4_u64, &10_u32).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_zero_window_rejected() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_admin_rate_limit(&admin, &limit(3, 0));
+}
@@ -0,0 +1,96 @@
+//! Tests for the two-step admin transfer.
+
+use crate::admin_actions::AdminActionKind;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_transfer_and_accept() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let new_admin = Address::generate(&e);
+    client.transfer_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(client.export_config().admin, Some(admin.clone()));
+    assert_eq!(
+        client.export_config().pending_admin,
+        Some(new_admin.clone())
+    );
+
+    assert_eq!(client.accept_admin(), new_admin);
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == (Symbol::new(&e, "admin_transferred"),).into_val(&e))
+        .expect("admin_transferred not emitted");
+    let data: (Address, Address) = data.into_val(&e);
+    assert_eq!(data, (admin.clone(), new_admin.clone()));
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.export_config().admin, Some(new_admin.clone()));
+
+    // The old key is retired, the new one works.
+    assert_eq!(
+        client.try_set_features(&admin, &0),
+        Err(Ok(BondError::NotAdmin))
+    );
+    client.set_features(&new_admin, &0);
+}
+
+#[test]
+fn test_transfer_is_logged() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let new_admin = Address::generate(&e);
+    client.transfer_admin(&admin, &new_admin);
+    let log = client.get_admin_actions(&0, &10);
+    assert_eq!(log.len(), 1);
+    assert_eq!(log.get(0).unwrap().kind, AdminActionKind::TransferAdmin);
+    assert_eq!(log.get(0).unwrap().target, Some(new_admin));
+}
+
+#[test]
+fn test_renomination_replaces_pending() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.transfer_admin(&admin, &Address::generate(&e));
+    let second = Address::generate(&e);
+    client.transfer_admin(&admin, &second);
+    assert_eq!(client.accept_admin(), second);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_transfer_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.transfer_admin(&Address::generate(&e), &Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_accept_without_pending_transfer() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.accept_admin();
+}
+
+#[test]
+#[should_panic]
+fn test_accept_requires_nominee_auth() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.transfer_admin(&admin, &Address::generate(&e));
+    e.set_auths(&[]);
+    client.accept_admin();
+}
@@ -230,7 +230,7 @@ fn test_add_attestation_empty_data() {
 // ============================================================================
 
 #[test]
-#[should_panic(expected = "unauthorized attester")]
+#[should_panic(expected = "Error(Contract, #201)")]
 fn test_unauthorized_attester_rejected() {
     let e = Env::default();
     e.mock_all_auths();
@@ -249,7 +249,7 @@ fn test_unauthorized_attester_rejected() {
 }
 
 #[test]
-#[should_panic(expected = "unauthorized attester")]
+#[should_panic(expected = "Error(Contract, #201)")]
 fn test_unregistered_attester_cannot_attest() {
     let e = Env::default();
     e.mock_all_auths();
@@ -305,14 +305,19 @@ fn test_revoke_attestation() {
     let att = client.add_attestation(&attester, &subject, &data, &client.get_nonce(&attester));
     assert!(!att.revoked);
 
-    client.revoke_attestation(&attester, &att.id, &client.get_nonce(&attester));
+    client.revoke_attestation(
+        &attester,
+        &att.id,
+        &RevocationReason::IssuedInError,
+        &client.get_nonce(&attester),
+    );
 
-    let revoked = client.get_attestation(&att.id);
+    let revoked = client.get_attestation(&attester, &att.id);
     assert!(revoked.revoked);
 }
 
 #[test]
-#[should_panic(expected = "only original attester can revoke")]
+#[should_panic(expected = "Error(Contract, #211)")]
 fn test_revoke_wrong_attester() {
     let e = Env::default();
     e.mock_all_auths();
@@ -336,11 +341,16 @@ fn test_revoke_wrong_attester() {
         &client.get_nonce(&att1),
     );
 
-    client.revoke_attestation(&att2, &att.id, &client.get_nonce(&att2));
+    client.revoke_attestation(
+        &att2,
+        &att.id,
+        &RevocationReason::IssuedInError,
+        &client.get_nonce(&att2),
+    );
 }
 
 #[test]
-#[should_panic(expected = "attestation already revoked")]
+#[should_panic(expected = "Error(Contract, #206)")]
 fn test_revoke_twice() {
     let e = Env::default();
     e.mock_all_auths();
@@ -362,12 +372,22 @@ fn test_revoke_twice() {
         &client.get_nonce(&attester),
     );
 
-    client.revoke_attestation(&attester, &att.id, &client.get_nonce(&attester));
-    client.revoke_attestation(&attester, &att.id, &client.get_nonce(&attester));
+    client.revoke_attestation(
+        &attester,
+        &att.id,
+        &RevocationReason::IssuedInError,
+        &client.get_nonce(&attester),
+    );
+    client.revoke_attestation(
+        &attester,
+        &att.id,
+        &RevocationReason::IssuedInError,
+        &client.get_nonce(&attester),
+    );
 }
 
 #[test]
-#[should_panic(expected = "attestation not found")]
+#[should_panic(expected = "Error(Contract, #205)")]
 fn test_revoke_nonexistent() {
     let e = Env::default();
     e.mock_all_auths();
@@ -381,7 +401,12 @@ fn test_revoke_nonexistent() {
     let attester = Address::generate(&e);
     client.register_attester(&attester);
 
-    client.revoke_attestation(&attester, &999, &client.get_nonce(&attester));
+    client.revoke_attestation(
+        &attester,
+        &999,
+        &RevocationReason::IssuedInError,
+        &client.get_nonce(&attester),
+    );
 }
 
 // ============================================================================
@@ -389,7 +414,7 @@ fn test_revoke_nonexistent() {
 // ============================================================================
 
 #[test]
-#[should_panic(expected = "duplicate attestation")]
+#[should_panic(expected = "Error(Contract, #204)")]
 fn test_duplicate_attestation_rejected() {
     let e = Env::default();
     e.mock_all_auths();
@@ -415,7 +440,7 @@ fn test_same_attester_different_data_gets_unique_id() {
     let e = Env::default();
     e.mock_all_auths();
 
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -476,10 +501,11 @@ fn test_same_attester_multiple_for_subject() {
         &String::from_str(&e, "3"),
         &client.get_nonce(&attester),
     );
+    let consumer = testutils::query_consumer(&e, &client.address);
 
-    let atts = client.get_subject_attestations(&subject);
+    let atts = client.get_subject_attestations(&consumer, &subject);
     assert_eq!(atts.len(), 3);
-    assert_eq!(client.get_subject_attestation_count(&subject), 3);
+    assert_eq!(client.get_subject_attestation_count(&consumer, &subject), 3);
 }
 
 // ============================================================================
@@ -507,9 +533,14 @@ fn test_events_published() {
         &String::from_str(&e, "test"),
         &client.get_nonce(&attester),
     );
-    client.revoke_attestation(&attester, &att.id, &client.get_nonce(&attester));
+    client.revoke_attestation(
+        &attester,
+        &att.id,
+        &RevocationReason::IssuedInError,
+        &client.get_nonce(&attester),
+    );
 
-    let revoked = client.get_attestation(&att.id);
+    let revoked = client.get_attestation(&attester, &att.id);
     assert!(revoked.revoked);
 }
 
@@ -535,7 +566,7 @@ fn test_get_attestation() {
     let data = String::from_str(&e, "get test");
 
     let original = client.add_attestation(&attester, &subject, &data, &client.get_nonce(&attester));
-    let retrieved = client.get_attestation(&original.id);
+    let retrieved = client.get_attestation(&subject, &original.id);
 
     assert_eq!(retrieved.id, original.id);
     assert_eq!(retrieved.verifier, original.verifier);
@@ -544,7 +575,7 @@ fn test_get_attestation() {
 }
 
 #[test]
-#[should_panic(expected = "attestation not found")]
+#[should_panic(expected = "Error(Contract, #205)")]
 fn test_get_nonexistent_attestation() {
     let e = Env::default();
     e.mock_all_auths();
@@ -555,7 +586,7 @@ fn test_get_nonexistent_attestation() {
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
-    client.get_attestation(&999);
+    client.get_attestation(&admin, &999);
 }
 
 #[test]
@@ -592,8 +623,9 @@ fn test_get_subject_attestations() {
         &String::from_str(&e, "3"),
         &client.get_nonce(&attester),
     );
+    let consumer = testutils::query_consumer(&e, &client.address);
 
-    let atts = client.get_subject_attestations(&subject);
+    let atts = client.get_subject_attestations(&consumer, &subject);
     assert_eq!(atts.len(), 3);
 }
 
@@ -609,7 +641,8 @@ fn test_get_subject_attestations_empty() {
     client.initialize(&admin);
 
     let subject = Address::generate(&e);
-    let atts = client.get_subject_attestations(&subject);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    let atts = client.get_subject_attestations(&consumer, &subject);
 
     assert_eq!(atts.len(), 0);
 }
@@ -649,14 +682,15 @@ fn test_get_subject_attestations_different_subjects() {
         &String::from_str(&e, "s2_1"),
         &client.get_nonce(&attester),
     );
+    let consumer = testutils::query_consumer(&e, &client.address);
 
-    let s1_atts = client.get_subject_attestations(&sub1);
-    let s2_atts = client.get_subject_attestations(&sub2);
+    let s1_atts = client.get_subject_attestations(&consumer, &sub1);
+    let s2_atts = client.get_subject_attestations(&consumer, &sub2);
 
     assert_eq!(s1_atts.len(), 2);
     assert_eq!(s2_atts.len(), 1);
-    assert_eq!(client.get_subject_attestation_count(&sub1), 2);
-    assert_eq!(client.get_subject_attestation_count(&sub2), 1);
+    assert_eq!(client.get_subject_attestation_count(&consumer, &sub1), 2);
+    assert_eq!(client.get_subject_attestation_count(&consumer, &sub2), 1);
 }
 
 // ============================================================================
@@ -730,9 +764,14 @@ fn test_revoke_preserves_data() {
     let data = String::from_str(&e, "preserved");
 
     let original = client.add_attestation(&attester, &subject, &data, &client.get_nonce(&attester));
-    client.revoke_attestation(&attester, &original.id, &client.get_nonce(&attester));
+    client.revoke_attestation(
+        &attester,
+        &original.id,
+        &RevocationReason::IssuedInError,
+        &client.get_nonce(&attester),
+    );
 
-    let revoked = client.get_attestation(&original.id);
+    let revoked = client.get_attestation(&attester, &original.id);
 
     assert_eq!(revoked.id, original.id);
     assert_eq!(revoked.verifier, original.verifier);
@@ -798,18 +837,104 @@ fn test_complex_scenario() {
     );
 
     // Revoke one
-    client.revoke_attestation(&att1, &a1.id, &client.get_nonce(&att1));
+    client.revoke_attestation(
+        &att1,
+        &a1.id,
+        &RevocationReason::IssuedInError,
+        &client.get_nonce(&att1),
+    );
+    let consumer = testutils::query_consumer(&e, &client.address);
 
     // Verify
-    let s1_atts = client.get_subject_attestations(&sub1);
-    let s2_atts = client.get_subject_attestations(&sub2);
+    let s1_atts = client.get_subject_attestations(&consumer, &sub1);
+    let s2_atts = client.get_subject_attestations(&consumer, &sub2);
 
     assert_eq!(s1_atts.len(), 3);
     assert_eq!(s2_atts.len(), 2);
 
-    let revoked = client.get_attestation(&a1.id);
+    let revoked = client.get_attestation(&att1, &a1.id);
     assert!(revoked.revoked);
 
-    let not_revoked = client.get_attestation(&a2.id);
+    let not_revoked = client.get_attestation(&sub1, &a2.id);
     assert!(!not_revoked.revoked);
 }
+
+// ============================================================================
+// ATOMIC BOND CREATION WITH ATTESTATION BUNDLE
+// ============================================================================
+
+#[test]
+fn test_create_bond_with_attestations() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+
+    let identity = Address::generate(&e);
+    let bundle = soroban_sdk::vec![
+        &e,
+        String::from_str(&e, "kyc"),
+        String::from_str(&e, "email")
+    ];
+    let bond = client.create_bond_with_attestations(
+        &identity, &1000_i128, &86400_u64, &attester, &bundle, &0_u64,
+    );
+
+    // Both identity and attester authorized the call.
+    let auths = e.auths();
+    assert!(auths.iter().any(|(a, _)| *a == identity));
+    assert!(auths.iter().any(|(a, _)| *a == attester));
+
+    assert_eq!(bond.identity, identity);
+    assert_eq!(client.get_identity_state().bonded_amount, 1000);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(
+        client.get_subject_attestation_count(&consumer, &identity),
+        2
+    );
+    assert_eq!(client.get_nonce(&attester), 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn test_create_bond_with_attestations_unregistered_attester() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+
+    let identity = Address::generate(&e);
+    let attester = Address::generate(&e);
+    let bundle = soroban_sdk::vec![&e, String::from_str(&e, "kyc")];
+    client.create_bond_with_attestations(
+        &identity, &1000_i128, &86400_u64, &attester, &bundle, &0_u64,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_create_bond_with_empty_attestation_bundle() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+
+    let identity = Address::generate(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let bundle: Vec<String> = Vec::new(&e);
+    client.create_bond_with_attestations(
+        &identity, &1000_i128, &86400_u64, &attester, &bundle, &0_u64,
+    );
+}
@@ -0,0 +1,190 @@
+//! Tests for archival compaction of long-revoked and long-expired attestations.
+
+#![cfg(test)]
+
+use crate::dedup_policy::DedupPolicy;
+use crate::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{BytesN, Env, String};
+
+const DAY: u64 = 86_400;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    client.set_archive_min_age(&admin, &DAY);
+    (client, admin, attester)
+}
+
+fn add(client: &CredenceBondClient<'_>, attester: &Address, subject: &Address, data: &str) -> u64 {
+    let nonce = client.get_nonce(attester);
+    let data = String::from_str(&client.env, data);
+    client.add_attestation(attester, subject, &data, &nonce).id
+}
+
+fn revoke(client: &CredenceBondClient<'_>, attester: &Address, id: u64) {
+    let nonce = client.get_nonce(attester);
+    client.revoke_attestation(attester, &id, &RevocationReason::IssuedInError, &nonce);
+}
+
+#[test]
+fn test_default_min_age() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(&e, &e.register(CredenceBond, ()));
+    assert_eq!(
+        client.get_archive_min_age(),
+        attestation_archive::DEFAULT_MIN_AGE
+    );
+}
+
+#[test]
+fn test_compacts_only_after_min_age() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    let live = add(&client, &attester, &subject, "kyc");
+    let gone = add(&client, &attester, &subject, "aml");
+    revoke(&client, &attester, gone);
+    let record = client.get_attestation(&subject, &gone);
+
+    e.ledger().with_mut(|l| l.timestamp += DAY - 1);
+    assert_eq!(client.compact_attestations(&admin, &subject, &10_u32), 0);
+
+    e.ledger().with_mut(|l| l.timestamp += 1);
+    assert_eq!(client.compact_attestations(&admin, &subject, &10_u32), 1);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(
+        client.get_subject_attestations(&consumer, &subject),
+        Vec::from_array(&e, [live])
+    );
+    let archived = client.get_archived_attestation(&gone).unwrap();
+    assert!(archived.revoked);
+    assert_eq!(archived.identity, subject);
+    assert!(client.verify_archived_attestation(&record));
+    assert!(client.check_invariants().is_empty());
+}
+
+#[test]
+fn test_tampered_record_fails_verification() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    let id = add(&client, &attester, &subject, "kyc");
+    revoke(&client, &attester, id);
+    let mut record = client.get_attestation(&subject, &id);
+    e.ledger().with_mut(|l| l.timestamp += DAY);
+    client.compact_attestations(&admin, &subject, &10_u32);
+
+    record.weight += 1;
+    assert!(!client.verify_archived_attestation(&record));
+}
+
+#[test]
+fn test_compacts_expired_and_updates_count() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let template =
+        client.create_template(&admin, &BytesN::from_array(&e, &[1; 32]), &50_u32, &100_u64);
+    let subject = Address::generate(&e);
+    let nonce = client.get_nonce(&attester);
+    let id = client
+        .add_attestation_with_template(
+            &attester,
+            &subject,
+            &template,
+            &String::from_str(&e, "kyc"),
+            &nonce,
+        )
+        .id;
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_subject_attestation_count(&consumer, &subject), 1);
+
+    e.ledger().with_mut(|l| l.timestamp += 100 + DAY);
+    assert_eq!(client.compact_attestations(&admin, &subject, &10_u32), 1);
+    assert_eq!(client.get_subject_attestation_count(&consumer, &subject), 0);
+    let archived = client.get_archived_attestation(&id).unwrap();
+    assert!(!archived.revoked);
+    assert_eq!(archived.ended_at, 100);
+}
+
+#[test]
+fn test_limit_bounds_work_per_call() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    for data in ["a", "b", "c"] {
+        let id = add(&client, &attester, &subject, data);
+        revoke(&client, &attester, id);
+    }
+    e.ledger().with_mut(|l| l.timestamp += DAY);
+    assert_eq!(client.compact_attestations(&admin, &subject, &2_u32), 2);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(
+        client.get_subject_attestations(&consumer, &subject).len(),
+        1
+    );
+    assert_eq!(client.compact_attestations(&admin, &subject, &2_u32), 1);
+    assert_eq!(
+        client.get_subject_attestations(&consumer, &subject).len(),
+        0
+    );
+}
+
+#[test]
+fn test_dedup_policy_applies_to_archived_claim() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    let id = add(&client, &attester, &subject, "kyc");
+    revoke(&client, &attester, id);
+    e.ledger().with_mut(|l| l.timestamp += DAY);
+    client.compact_attestations(&admin, &subject, &10_u32);
+
+    // Default policy allows re-issue of a revoked claim, archived or not.
+    let reissued = add(&client, &attester, &subject, "kyc");
+    assert!(reissued > id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn test_reject_always_survives_archival() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_dedup_policy(&admin, &DedupPolicy::RejectAlways);
+    let subject = Address::generate(&e);
+    let id = add(&client, &attester, &subject, "kyc");
+    revoke(&client, &attester, id);
+    e.ledger().with_mut(|l| l.timestamp += DAY);
+    client.compact_attestations(&admin, &subject, &10_u32);
+    add(&client, &attester, &subject, "kyc");
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_compact_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, _attester) = setup(&e);
+    let other = Address::generate(&e);
+    client.compact_attestations(&other, &other, &10_u32);
+}
+
+#[test]
+fn test_archive_min_age_independent_of_proposer_age() {
+    let e = Env::default();
+    let (client, admin, _attester) = setup(&e);
+    client.initialize_governance(
+        &admin,
+        &soroban_sdk::vec![&e, Address::generate(&e)],
+        &5100,
+        &1,
+        &60,
+    );
+    assert_eq!(client.get_archive_min_age(), DAY);
+    assert_eq!(client.get_min_proposer_age(), 60);
+}
@@ -0,0 +1,92 @@
+//! Tests for hashed attestations: commitment storage, hash-keyed dedup, content
+//! verification and coexistence with string attestations.
+
+use crate::{testutils, BondError, CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, attester, Address::generate(e))
+}
+
+fn hash_of(e: &Env, content: &str) -> BytesN<32> {
+    e.crypto()
+        .sha256(&Bytes::from_slice(e, content.as_bytes()))
+        .into()
+}
+
+#[test]
+fn test_add_attestation_hash_stores_commitment() {
+    let e = Env::default();
+    let (client, attester, subject) = setup(&e);
+    let hash = hash_of(&e, "passport 123 verified");
+    let schema = String::from_str(&e, "kyc.v1");
+    let att = client.add_attestation_hash(&attester, &subject, &hash, &schema, &0);
+
+    assert_eq!(att.attestation_data, schema);
+    assert_eq!(client.get_attestation_hash(&att.id), Some(hash));
+    assert!(client
+        .verify_attestation_content(&att.id, &Bytes::from_slice(&e, b"passport 123 verified")));
+    assert!(!client.verify_attestation_content(&att.id, &Bytes::from_slice(&e, b"other")));
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_subject_attestation_count(&consumer, &subject), 1);
+}
+
+#[test]
+fn test_dedup_keyed_on_hash() {
+    let e = Env::default();
+    let (client, attester, subject) = setup(&e);
+    let schema = String::from_str(&e, "kyc.v1");
+    let first = hash_of(&e, "a");
+    let att = client.add_attestation_hash(&attester, &subject, &first, &schema, &0);
+
+    // Same schema, different content: allowed.
+    client.add_attestation_hash(&attester, &subject, &hash_of(&e, "b"), &schema, &1);
+    // A string attestation equal to the schema id is not a duplicate either.
+    client.add_attestation(&attester, &subject, &schema, &2);
+
+    assert_eq!(
+        client
+            .try_add_attestation_hash(&attester, &subject, &first, &schema, &3)
+            .err(),
+        Some(Ok(BondError::DuplicateAttestation))
+    );
+    client.revoke_attestation(&attester, &att.id, &RevocationReason::IssuedInError, &3);
+    client.add_attestation_hash(&attester, &subject, &first, &schema, &4);
+}
+
+#[test]
+fn test_string_attestation_has_no_hash() {
+    let e = Env::default();
+    let (client, attester, subject) = setup(&e);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"), &0);
+    assert_eq!(client.get_attestation_hash(&att.id), None);
+    assert!(!client.verify_attestation_content(&att.id, &Bytes::from_slice(&e, b"kyc")));
+}
+
+#[test]
+fn test_schema_id_validated() {
+    let e = Env::default();
+    let (client, attester, subject) = setup(&e);
+    let hash = hash_of(&e, "a");
+    assert_eq!(
+        client
+            .try_add_attestation_hash(&attester, &subject, &hash, &String::from_str(&e, ""), &0)
+            .err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+    let long = String::from_str(&e, &"x".repeat(65));
+    assert_eq!(
+        client
+            .try_add_attestation_hash(&attester, &subject, &hash, &long, &0)
+            .err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+}
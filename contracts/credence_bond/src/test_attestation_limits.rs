@@ -0,0 +1,170 @@
+//! Tests for attestation caps per subject and per verifier, and pruning of revoked ids from
+//! the subject index.
+
+use crate::attestation_limits::AttestationLimits;
+use crate::{testutils, BondError, CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+fn attester(e: &Env, client: &CredenceBondClient<'_>) -> Address {
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    attester
+}
+
+fn try_add(
+    client: &CredenceBondClient<'_>,
+    attester: &Address,
+    subject: &Address,
+    data: &str,
+) -> Option<Result<BondError, soroban_sdk::InvokeError>> {
+    let nonce = client.get_nonce(attester);
+    let data = String::from_str(&client.env, data);
+    client
+        .try_add_attestation(attester, subject, &data, &nonce)
+        .err()
+}
+
+fn add(client: &CredenceBondClient<'_>, attester: &Address, subject: &Address, data: &str) -> u64 {
+    let nonce = client.get_nonce(attester);
+    let data = String::from_str(&client.env, data);
+    client.add_attestation(attester, subject, &data, &nonce).id
+}
+
+fn revoke(client: &CredenceBondClient<'_>, attester: &Address, id: u64) {
+    let nonce = client.get_nonce(attester);
+    client.revoke_attestation(attester, &id, &RevocationReason::IssuedInError, &nonce);
+}
+
+fn limits(per_subject: u32, per_verifier: u32) -> AttestationLimits {
+    AttestationLimits {
+        max_per_subject: per_subject,
+        max_per_verifier_per_subject: per_verifier,
+    }
+}
+
+#[test]
+fn test_unbounded_by_default() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(
+        client.get_attestation_limits(),
+        AttestationLimits::default()
+    );
+    let a = attester(&e, &client);
+    let subject = Address::generate(&e);
+    for data in ["a", "b", "c", "d"] {
+        add(&client, &a, &subject, data);
+    }
+}
+
+#[test]
+fn test_subject_cap() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_attestation_limits(&admin, &limits(2, 0));
+    let a = attester(&e, &client);
+    let b = attester(&e, &client);
+    let subject = Address::generate(&e);
+    add(&client, &a, &subject, "kyc");
+    add(&client, &b, &subject, "kyc");
+    assert_eq!(
+        try_add(&client, &a, &subject, "aml"),
+        Some(Ok(BondError::RateLimited))
+    );
+    // Other subjects are unaffected.
+    add(&client, &a, &Address::generate(&e), "kyc");
+}
+
+#[test]
+fn test_verifier_cap_counts_active_only() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_attestation_limits(&admin, &limits(0, 1));
+    let a = attester(&e, &client);
+    let b = attester(&e, &client);
+    let subject = Address::generate(&e);
+    let first = add(&client, &a, &subject, "kyc");
+    assert_eq!(
+        try_add(&client, &a, &subject, "aml"),
+        Some(Ok(BondError::RateLimited))
+    );
+    add(&client, &b, &subject, "aml");
+
+    revoke(&client, &a, first);
+    add(&client, &a, &subject, "aml");
+}
+
+#[test]
+fn test_prune_frees_subject_cap() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_attestation_limits(&admin, &limits(2, 0));
+    let a = attester(&e, &client);
+    let subject = Address::generate(&e);
+    let gone = add(&client, &a, &subject, "kyc");
+    let live = add(&client, &a, &subject, "aml");
+    revoke(&client, &a, gone);
+    assert_eq!(
+        try_add(&client, &a, &subject, "sanctions"),
+        Some(Ok(BondError::RateLimited))
+    );
+
+    assert_eq!(client.prune_revoked_attestations(&admin, &subject, &10), 1);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(
+        client.get_subject_attestations(&consumer, &subject),
+        soroban_sdk::vec![&e, live]
+    );
+    // The pruned attestation is still readable by id.
+    assert!(client.get_attestation(&subject, &gone).revoked);
+    add(&client, &a, &subject, "sanctions");
+}
+
+#[test]
+fn test_prune_respects_limit() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let a = attester(&e, &client);
+    let subject = Address::generate(&e);
+    for data in ["a", "b", "c"] {
+        let id = add(&client, &a, &subject, data);
+        revoke(&client, &a, id);
+    }
+    assert_eq!(client.prune_revoked_attestations(&admin, &subject, &2), 2);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(
+        client.get_subject_attestations(&consumer, &subject).len(),
+        1
+    );
+    assert_eq!(client.prune_revoked_attestations(&admin, &subject, &2), 1);
+    assert_eq!(client.prune_revoked_attestations(&admin, &subject, &2), 0);
+}
+
+#[test]
+fn test_limits_and_prune_require_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let other = Address::generate(&e);
+    assert_eq!(
+        client
+            .try_set_attestation_limits(&other, &limits(1, 1))
+            .err(),
+        Some(Ok(BondError::NotAdmin))
+    );
+    assert_eq!(
+        client
+            .try_prune_revoked_attestations(&other, &other, &1)
+            .err(),
+        Some(Ok(BondError::NotAdmin))
+    );
+}
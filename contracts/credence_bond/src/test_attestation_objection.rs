@@ -0,0 +1,163 @@
+//! Tests for subject objections to attestations and the disputes they open.
+
+use crate::attestation_objection::ObjectionTerms;
+use crate::{BondError, CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String};
+
+/// Dispute contract stand-in recording the last `create_dispute` call.
+#[contract]
+pub struct MockDispute;
+
+#[contractimpl]
+impl MockDispute {
+    pub fn create_dispute(
+        e: Env,
+        disputer: Address,
+        slash_request_id: u64,
+        stake: i128,
+        _token: Address,
+        resolution_deadline: u64,
+    ) -> u64 {
+        disputer.require_auth();
+        e.storage().instance().set(
+            &0_u32,
+            &(disputer, slash_request_id, stake, resolution_deadline),
+        );
+        4
+    }
+
+    pub fn last(e: Env) -> (Address, u64, i128, u64) {
+        e.storage().instance().get(&0_u32).unwrap()
+    }
+}
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    attester: Address,
+    subject: Address,
+    attestation_id: u64,
+}
+
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    let subject = Address::generate(e);
+    let attestation_id = client
+        .add_attestation(&attester, &subject, &String::from_str(e, "kyc"), &0)
+        .id;
+    Setup {
+        client,
+        admin,
+        attester,
+        subject,
+        attestation_id,
+    }
+}
+
+fn reason(e: &Env) -> BytesN<32> {
+    BytesN::from_array(e, &[7; 32])
+}
+
+#[test]
+fn test_subject_objection_recorded() {
+    let e = Env::default();
+    let s = setup(&e);
+    let objection = s
+        .client
+        .object_to_attestation(&s.subject, &s.attestation_id, &reason(&e));
+    assert_eq!(objection.reason_hash, reason(&e));
+    assert_eq!(objection.dispute_id, 0);
+    assert_eq!(s.client.get_objection(&s.attestation_id), Some(objection));
+    assert_eq!(
+        s.client
+            .try_object_to_attestation(&s.subject, &s.attestation_id, &reason(&e))
+            .err(),
+        Some(Ok(BondError::InvalidAttestationState))
+    );
+}
+
+#[test]
+fn test_only_subject_of_live_attestation_may_object() {
+    let e = Env::default();
+    let s = setup(&e);
+    let outsider = Address::generate(&e);
+    assert_eq!(
+        s.client
+            .try_object_to_attestation(&outsider, &s.attestation_id, &reason(&e))
+            .err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_object_to_attestation(&s.subject, &99, &reason(&e))
+            .err(),
+        Some(Ok(BondError::AttestationNotFound))
+    );
+    s.client.revoke_attestation(
+        &s.attester,
+        &s.attestation_id,
+        &RevocationReason::IssuedInError,
+        &1,
+    );
+    assert_eq!(
+        s.client
+            .try_object_to_attestation(&s.subject, &s.attestation_id, &reason(&e))
+            .err(),
+        Some(Ok(BondError::AttestationRevoked))
+    );
+}
+
+#[test]
+fn test_objection_opens_dispute_when_configured() {
+    let e = Env::default();
+    let s = setup(&e);
+    let dispute = e.register(MockDispute, ());
+    s.client.set_conflict_dispute_contract(&s.admin, &dispute);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    s.client.set_token(&s.admin, &sac.address());
+
+    let terms = ObjectionTerms {
+        stake: 500,
+        resolution_window: 86_400,
+    };
+    s.client.set_objection_dispute_terms(&s.admin, &terms);
+    assert_eq!(s.client.get_objection_dispute_terms(), terms);
+
+    let objection = s
+        .client
+        .object_to_attestation(&s.subject, &s.attestation_id, &reason(&e));
+    assert_eq!(objection.dispute_id, 4);
+    assert_eq!(
+        MockDisputeClient::new(&e, &dispute).last(),
+        (s.subject.clone(), s.attestation_id, 500, 86_400)
+    );
+}
+
+#[test]
+fn test_objection_terms_validated() {
+    let e = Env::default();
+    let s = setup(&e);
+    let bad = ObjectionTerms {
+        stake: 500,
+        resolution_window: 0,
+    };
+    assert_eq!(
+        s.client
+            .try_set_objection_dispute_terms(&s.admin, &bad)
+            .err(),
+        Some(Ok(BondError::InvalidConfig))
+    );
+    assert_eq!(
+        s.client
+            .try_set_objection_dispute_terms(&s.subject, &bad)
+            .err(),
+        Some(Ok(BondError::NotAdmin))
+    );
+}
@@ -0,0 +1,125 @@
+//! Tests for the per-subject attestation summary: counters kept on add, revoke and archival
+//! of expired attestations.
+
+use crate::attestation_summary::AttestationSummary;
+use crate::{CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let reader = Address::generate(e);
+    client.set_query_consumer(&admin, &reader, &true);
+    (client, admin, reader)
+}
+
+fn attester(e: &Env, client: &CredenceBondClient<'_>) -> Address {
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    attester
+}
+
+fn add(client: &CredenceBondClient<'_>, attester: &Address, subject: &Address, data: &str) -> u64 {
+    let nonce = client.get_nonce(attester);
+    let data = String::from_str(&client.env, data);
+    client.add_attestation(attester, subject, &data, &nonce).id
+}
+
+fn revoke(client: &CredenceBondClient<'_>, attester: &Address, id: u64) {
+    let nonce = client.get_nonce(attester);
+    client.revoke_attestation(attester, &id, &RevocationReason::IssuedInError, &nonce);
+}
+
+#[test]
+fn test_empty_summary() {
+    let e = Env::default();
+    let (client, _admin, reader) = setup(&e);
+    assert_eq!(
+        client.get_attestation_summary(&reader, &Address::generate(&e)),
+        AttestationSummary::default()
+    );
+}
+
+#[test]
+fn test_summary_counts_adds() {
+    let e = Env::default();
+    let (client, _admin, reader) = setup(&e);
+    let a = attester(&e, &client);
+    let b = attester(&e, &client);
+    let subject = Address::generate(&e);
+    add(&client, &a, &subject, "kyc");
+    add(&client, &a, &subject, "aml");
+    e.ledger().with_mut(|li| li.timestamp = 1500);
+    add(&client, &b, &subject, "kyc");
+
+    let summary = client.get_attestation_summary(&reader, &subject);
+    assert_eq!(summary.active_count, 3);
+    assert_eq!(summary.active_weight, 3);
+    assert_eq!(summary.distinct_verifiers, 2);
+    assert_eq!(summary.last_attested_at, 1500);
+    assert_eq!(summary.revoked_count, 0);
+}
+
+#[test]
+fn test_revocation_updates_summary() {
+    let e = Env::default();
+    let (client, _admin, reader) = setup(&e);
+    let a = attester(&e, &client);
+    let b = attester(&e, &client);
+    let subject = Address::generate(&e);
+    let first = add(&client, &a, &subject, "kyc");
+    let second = add(&client, &a, &subject, "aml");
+    let other = add(&client, &b, &subject, "kyc");
+
+    revoke(&client, &a, first);
+    let summary = client.get_attestation_summary(&reader, &subject);
+    assert_eq!(summary.active_count, 2);
+    assert_eq!(summary.distinct_verifiers, 2);
+    assert_eq!(summary.revoked_count, 1);
+
+    revoke(&client, &a, second);
+    revoke(&client, &b, other);
+    let summary = client.get_attestation_summary(&reader, &subject);
+    assert_eq!(summary.active_count, 0);
+    assert_eq!(summary.active_weight, 0);
+    assert_eq!(summary.distinct_verifiers, 0);
+    assert_eq!(summary.last_attested_at, 1000);
+    assert_eq!(summary.revoked_count, 3);
+}
+
+#[test]
+fn test_archived_expiry_leaves_active_set() {
+    let e = Env::default();
+    let (client, admin, reader) = setup(&e);
+    let a = attester(&e, &client);
+    client.set_archive_min_age(&admin, &0_u64);
+    let template =
+        client.create_template(&admin, &BytesN::from_array(&e, &[1; 32]), &50_u32, &100_u64);
+    let subject = Address::generate(&e);
+    let nonce = client.get_nonce(&a);
+    client.add_attestation_with_template(
+        &a,
+        &subject,
+        &template,
+        &String::from_str(&e, "kyc"),
+        &nonce,
+    );
+    assert_eq!(
+        client
+            .get_attestation_summary(&reader, &subject)
+            .active_count,
+        1
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    assert_eq!(client.compact_attestations(&admin, &subject, &10_u32), 1);
+    let summary = client.get_attestation_summary(&reader, &subject);
+    assert_eq!(summary.active_count, 0);
+    assert_eq!(summary.distinct_verifiers, 0);
+    assert_eq!(summary.revoked_count, 0);
+}
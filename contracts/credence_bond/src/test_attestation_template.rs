@@ -0,0 +1,128 @@
+//! Tests for the attestation template registry: creation, listing, weight caps,
+//! expiry policy and template-gated attestation.
+
+#![cfg(test)]
+
+use crate::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{BytesN, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, attester)
+}
+
+fn schema(e: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(e, &[byte; 32])
+}
+
+#[test]
+fn test_create_and_get_template() {
+    let e = Env::default();
+    let (client, admin, _attester) = setup(&e);
+    let id = client.create_template(&admin, &schema(&e, 1), &50_u32, &3600_u64);
+    assert_eq!(id, 0);
+    let t = client.get_template(&id);
+    assert_eq!(t.schema_hash, schema(&e, 1));
+    assert_eq!(t.weight_cap, 50);
+    assert_eq!(t.expiry_seconds, 3600);
+    assert!(t.active);
+}
+
+#[test]
+fn test_list_templates() {
+    let e = Env::default();
+    let (client, admin, _attester) = setup(&e);
+    assert_eq!(client.list_templates().len(), 0);
+    client.create_template(&admin, &schema(&e, 1), &50_u32, &0_u64);
+    client.create_template(&admin, &schema(&e, 2), &10_u32, &60_u64);
+    let list = client.list_templates();
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.get(1).unwrap().id, 1);
+}
+
+#[test]
+fn test_templated_attestation_caps_weight_and_sets_expiry() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, attester) = setup(&e);
+    client.set_attester_stake(&admin, &attester, &1_000_000_i128); // weight 10_000
+    let id = client.create_template(&admin, &schema(&e, 1), &50_u32, &3600_u64);
+    let subject = Address::generate(&e);
+    let att = client.add_attestation_with_template(
+        &attester,
+        &subject,
+        &id,
+        &String::from_str(&e, "kyc"),
+        &0_u64,
+    );
+    assert_eq!(att.weight, 50);
+    assert_eq!(att.template_id, Some(id));
+    assert_eq!(att.expires_at, 4600);
+    assert!(att.is_active_at(4599));
+    assert!(!att.is_active_at(4600));
+}
+
+#[test]
+fn test_plain_attestation_has_no_template() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "x"), &0_u64);
+    assert_eq!(att.template_id, None);
+    assert_eq!(att.expires_at, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #209)")]
+fn test_unknown_template_rejected() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    client.add_attestation_with_template(
+        &attester,
+        &subject,
+        &7_u32,
+        &String::from_str(&e, "x"),
+        &0_u64,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #210)")]
+fn test_inactive_template_rejected() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let id = client.create_template(&admin, &schema(&e, 1), &50_u32, &0_u64);
+    client.set_template_active(&admin, &id, &false);
+    let subject = Address::generate(&e);
+    client.add_attestation_with_template(
+        &attester,
+        &subject,
+        &id,
+        &String::from_str(&e, "x"),
+        &0_u64,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_zero_weight_cap_rejected() {
+    let e = Env::default();
+    let (client, admin, _attester) = setup(&e);
+    client.create_template(&admin, &schema(&e, 1), &0_u32, &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_create_template_unauthorized() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    client.create_template(&attester, &schema(&e, 1), &10_u32, &0_u64);
+}
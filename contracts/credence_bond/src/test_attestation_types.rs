@@ -3,7 +3,7 @@
 #![cfg(test)]
 
 use crate::types::attestation::{DEFAULT_ATTESTATION_WEIGHT, MAX_ATTESTATION_WEIGHT};
-use crate::types::{Attestation, AttestationDedupKey};
+use crate::types::{Attestation, AttestationDedupKey, RevocationReason};
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Env, String};
 
@@ -40,6 +40,10 @@ fn attestation_is_active() {
         weight: DEFAULT_ATTESTATION_WEIGHT,
         attestation_data: data,
         revoked: false,
+        template_id: None,
+        expires_at: 0,
+        active_from: 0,
+        revocation_reason: RevocationReason::NotRevoked,
     };
     assert!(att.is_active());
     let mut revoked = att.clone();
@@ -0,0 +1,159 @@
+//! Tests for time-locked attester deregistration: notice period, issuance block, revocation
+//! and hand-off during the notice, and finalization.
+
+use crate::{CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    client.set_deregistration_notice(&admin, &500_u64);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, attester)
+}
+
+fn attest(e: &Env, client: &CredenceBondClient, attester: &Address, subject: &Address) -> u64 {
+    let nonce = client.get_nonce(attester);
+    client
+        .add_attestation(attester, subject, &String::from_str(e, "kyc"), &nonce)
+        .id
+}
+
+#[test]
+fn test_unregister_schedules_deregistration() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    client.unregister_attester(&attester);
+    assert_eq!(
+        client.get_deregistration_effective_at(&attester),
+        Some(1_500)
+    );
+    assert!(client.is_attester(&attester));
+
+    e.ledger().with_mut(|li| li.timestamp = 1_500);
+    assert!(!client.is_attester(&attester));
+    client.finalize_deregistration(&attester);
+    assert!(!client.is_attester(&attester));
+    assert_eq!(client.get_deregistration_effective_at(&attester), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #203)")]
+fn test_no_new_attestations_during_notice() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    client.unregister_attester(&attester);
+    attest(&e, &client, &attester, &Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn test_no_attestations_after_notice_before_finalization() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    client.unregister_attester(&attester);
+    e.ledger().with_mut(|li| li.timestamp = 1_500);
+    attest(&e, &client, &attester, &Address::generate(&e));
+}
+
+#[test]
+fn test_revoke_during_notice() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let id = attest(&e, &client, &attester, &Address::generate(&e));
+    client.unregister_attester(&attester);
+    let nonce = client.get_nonce(&attester);
+    client.revoke_attestation(&attester, &id, &RevocationReason::SubjectRequest, &nonce);
+    assert!(client.get_attestation(&attester, &id).revoked);
+}
+
+#[test]
+fn test_hand_off_during_notice() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let successor = Address::generate(&e);
+    client.register_attester(&successor);
+    let subject = Address::generate(&e);
+    let id = attest(&e, &client, &attester, &subject);
+    client.unregister_attester(&attester);
+
+    let attester_nonce = client.get_nonce(&attester);
+    let successor_nonce = client.get_nonce(&successor);
+    let reissued = client.hand_off_attestation(
+        &attester,
+        &id,
+        &successor,
+        &attester_nonce,
+        &successor_nonce,
+    );
+    assert_eq!(reissued.verifier, successor);
+    assert_eq!(reissued.identity, subject);
+    assert_eq!(reissued.attestation_data, String::from_str(&e, "kyc"));
+    let original = client.get_attestation(&attester, &id);
+    assert!(original.revoked);
+    assert_eq!(original.revocation_reason, RevocationReason::Superseded);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #203)")]
+fn test_hand_off_to_deregistering_successor() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let successor = Address::generate(&e);
+    client.register_attester(&successor);
+    let id = attest(&e, &client, &attester, &Address::generate(&e));
+    client.unregister_attester(&successor);
+    let attester_nonce = client.get_nonce(&attester);
+    let successor_nonce = client.get_nonce(&successor);
+    client.hand_off_attestation(
+        &attester,
+        &id,
+        &successor,
+        &attester_nonce,
+        &successor_nonce,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #111)")]
+fn test_finalize_before_notice() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    client.unregister_attester(&attester);
+    client.finalize_deregistration(&attester);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #203)")]
+fn test_double_unregister() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    client.unregister_attester(&attester);
+    client.unregister_attester(&attester);
+}
+
+#[test]
+fn test_zero_notice_is_immediate() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_deregistration_notice(&admin, &0_u64);
+    client.unregister_attester(&attester);
+    assert!(!client.is_attester(&attester));
+    assert_eq!(client.get_deregistration_effective_at(&attester), None);
+}
+
+#[test]
+fn test_reregistration_cancels_pending() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    client.unregister_attester(&attester);
+    client.register_attester(&attester);
+    assert_eq!(client.get_deregistration_effective_at(&attester), None);
+    attest(&e, &client, &attester, &Address::generate(&e));
+}
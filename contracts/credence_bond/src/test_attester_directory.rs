@@ -0,0 +1,178 @@
+//! Tests for the attester directory: registration index, published metadata, the info view
+//! and pagination.
+
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, BytesN, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_info_of_registered_attester() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let subject = Address::generate(&e);
+    client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"), &0_u64);
+
+    let info = client.get_attester_info(&attester).unwrap();
+    assert_eq!(info.attester, attester);
+    assert_eq!(info.registered_at, 1000);
+    assert_eq!(info.stake, 0);
+    assert_eq!(info.weight, 1);
+    assert!(info.active);
+    assert_eq!(info.attestation_count, 1);
+    assert_eq!(info.name, None);
+    assert_eq!(info.uri_hash, None);
+}
+
+#[test]
+fn test_info_of_unregistered_is_none() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.get_attester_info(&Address::generate(&e)), None);
+}
+
+#[test]
+fn test_set_attester_metadata() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let name = String::from_str(&e, "Acme KYC");
+    let hash = BytesN::from_array(&e, &[9; 32]);
+    let metadata = client.set_attester_metadata(&attester, &name, &hash);
+    assert_eq!(metadata.updated_at, 1000);
+
+    let info = client.get_attester_info(&attester).unwrap();
+    assert_eq!(info.name, Some(name));
+    assert_eq!(info.uri_hash, Some(hash));
+}
+
+#[test]
+fn test_metadata_requires_registration() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let outsider = Address::generate(&e);
+    assert_eq!(
+        client
+            .try_set_attester_metadata(
+                &outsider,
+                &String::from_str(&e, "x"),
+                &BytesN::from_array(&e, &[0; 32]),
+            )
+            .err(),
+        Some(Ok(BondError::UnauthorizedAttester))
+    );
+}
+
+#[test]
+fn test_metadata_name_bounds() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let hash = BytesN::from_array(&e, &[0; 32]);
+    assert_eq!(
+        client
+            .try_set_attester_metadata(&attester, &String::from_str(&e, ""), &hash)
+            .err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+    let long = String::from_str(&e, &"a".repeat(65));
+    assert_eq!(
+        client
+            .try_set_attester_metadata(&attester, &long, &hash)
+            .err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_suspended_attester_inactive() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    client.suspend_attester(&admin, &attester, &2000);
+    assert!(!client.get_attester_info(&attester).unwrap().active);
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    assert!(client.get_attester_info(&attester).unwrap().active);
+}
+
+#[test]
+fn test_unregister_removes_from_directory() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let a = Address::generate(&e);
+    let b = Address::generate(&e);
+    client.register_attester(&a);
+    client.register_attester(&b);
+    client.set_attester_metadata(
+        &a,
+        &String::from_str(&e, "A"),
+        &BytesN::from_array(&e, &[1; 32]),
+    );
+    assert_eq!(client.get_attester_count(), 2);
+
+    client.unregister_attester(&a);
+    assert_eq!(client.get_attester_count(), 1);
+    assert_eq!(client.get_attester_info(&a), None);
+    let page = client.get_attesters_page(&0, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get_unchecked(0).attester, b);
+
+    // Re-registration starts a fresh entry without the old profile.
+    client.register_attester(&a);
+    assert_eq!(client.get_attester_info(&a).unwrap().name, None);
+}
+
+#[test]
+fn test_deregistration_notice_keeps_entry_until_finalized() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let a = Address::generate(&e);
+    client.register_attester(&a);
+    client.set_deregistration_notice(&admin, &100);
+    client.unregister_attester(&a);
+    assert_eq!(client.get_attester_count(), 1);
+
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    assert!(!client.get_attester_info(&a).unwrap().active);
+    client.finalize_deregistration(&a);
+    assert_eq!(client.get_attester_count(), 0);
+}
+
+#[test]
+fn test_attesters_page() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let a = Address::generate(&e);
+    let b = Address::generate(&e);
+    let c = Address::generate(&e);
+    client.register_attester(&a);
+    client.register_attesters_batch(
+        &admin,
+        &vec![&e, (b.clone(), 1_u32, 0_i128), (c.clone(), 1_u32, 0_i128)],
+    );
+    // Registering again keeps the original position.
+    client.register_attester(&a);
+
+    let first = client.get_attesters_page(&0, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get_unchecked(0).attester, a);
+    assert_eq!(first.get_unchecked(1).attester, b);
+    let rest = client.get_attesters_page(&2, &2);
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest.get_unchecked(0).attester, c);
+    assert_eq!(client.get_attesters_page(&3, &2).len(), 0);
+}
@@ -0,0 +1,269 @@
+//! Tests for attester onboarding: admin auth on registration, the application flow, batch
+//! registration with class and stake, batch stake updates, batch size bounds and
+//! all-or-nothing validation.
+
+use crate::attester_onboarding::MAX_BATCH_SIZE;
+use crate::{weighted_attestation, BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, Symbol, Vec};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+fn stake_of(e: &Env, client: &CredenceBondClient<'_>, attester: &Address) -> i128 {
+    e.as_contract(&client.address, || {
+        weighted_attestation::get_attester_stake(e, attester)
+    })
+}
+
+#[test]
+fn test_register_attesters_batch() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let a = Address::generate(&e);
+    let b = Address::generate(&e);
+    client.register_attesters_batch(
+        &admin,
+        &vec![&e, (a.clone(), 1_u32, 500_i128), (b.clone(), 2_u32, 0_i128)],
+    );
+    let events = e.events().all().len();
+    assert!(client.is_attester(&a));
+    assert!(client.is_attester(&b));
+    assert_eq!(client.get_attester_class(&a), 1);
+    assert_eq!(client.get_attester_class(&b), 2);
+    assert_eq!(stake_of(&e, &client, &a), 500);
+    // attester_registered + attester_onboarded per entry.
+    assert_eq!(events, 4);
+}
+
+#[test]
+fn test_set_attester_stakes_batch() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let a = Address::generate(&e);
+    let b = Address::generate(&e);
+    client.register_attesters_batch(
+        &admin,
+        &vec![&e, (a.clone(), 0_u32, 0_i128), (b.clone(), 0_u32, 0_i128)],
+    );
+    client.set_attester_stakes_batch(
+        &admin,
+        &vec![&e, (a.clone(), 10_i128), (b.clone(), 20_i128)],
+    );
+    assert_eq!(stake_of(&e, &client, &a), 10);
+    assert_eq!(stake_of(&e, &client, &b), 20);
+}
+
+#[test]
+fn test_invalid_entry_reverts_whole_batch() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let a = Address::generate(&e);
+    let b = Address::generate(&e);
+    let result = client.try_register_attesters_batch(
+        &admin,
+        &vec![
+            &e,
+            (a.clone(), 1_u32, 100_i128),
+            (b.clone(), 1_u32, -1_i128),
+        ],
+    );
+    assert!(result.is_err());
+    assert!(!client.is_attester(&a));
+    assert!(!client.is_attester(&b));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn test_stakes_batch_requires_registered() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let a = Address::generate(&e);
+    client.set_attester_stakes_batch(&admin, &vec![&e, (a, 10_i128)]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_duplicate_in_batch_rejected() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let a = Address::generate(&e);
+    client.register_attesters_batch(
+        &admin,
+        &vec![&e, (a.clone(), 1_u32, 0_i128), (a, 2_u32, 0_i128)],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_batch_size_bounded() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let mut entries = Vec::new(&e);
+    for _ in 0..=MAX_BATCH_SIZE {
+        entries.push_back((Address::generate(&e), 0_u32, 0_i128));
+    }
+    client.register_attesters_batch(&admin, &entries);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_empty_batch_rejected() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.register_attesters_batch(&admin, &Vec::new(&e));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_batch_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let other = Address::generate(&e);
+    client.register_attesters_batch(&other, &vec![&e, (other.clone(), 0_u32, 0_i128)]);
+}
+
+#[test]
+fn test_register_attester_requires_admin_auth() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let a = Address::generate(&e);
+    e.set_auths(&[]);
+    assert!(client.try_register_attester(&a).is_err());
+    assert!(!client.is_attester(&a));
+}
+
+#[test]
+fn test_unregister_attester_requires_admin_auth() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let a = Address::generate(&e);
+    client.register_attester(&a);
+    e.set_auths(&[]);
+    assert!(client.try_unregister_attester(&a).is_err());
+    assert!(client.is_attester(&a));
+}
+
+#[test]
+fn test_apply_and_approve_attester() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let a = Address::generate(&e);
+    let hash = BytesN::from_array(&e, &[7; 32]);
+    let application = client.apply_as_attester(&a, &hash);
+    assert_eq!(application.metadata_hash, hash);
+    assert_eq!(client.get_attester_application(&a), Some(application));
+    assert_eq!(client.get_attester_application_count(), 1);
+    assert!(!client.is_attester(&a));
+
+    client.approve_attester(&admin, &a);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "attester_application_approved"), a.clone()).into_val(&e)
+    );
+    assert!(client.is_attester(&a));
+    assert_eq!(client.get_attester_application(&a), None);
+    assert_eq!(client.get_attester_application_count(), 0);
+}
+
+#[test]
+fn test_reject_attester() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let a = Address::generate(&e);
+    client.apply_as_attester(&a, &BytesN::from_array(&e, &[1; 32]));
+    client.reject_attester(&admin, &a);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "attester_application_rejected"), a.clone()).into_val(&e)
+    );
+    assert!(!client.is_attester(&a));
+    assert_eq!(client.get_attester_application_count(), 0);
+    // A rejected applicant may apply again.
+    client.apply_as_attester(&a, &BytesN::from_array(&e, &[2; 32]));
+    assert_eq!(client.get_attester_application_count(), 1);
+}
+
+#[test]
+fn test_duplicate_application_rejected() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let a = Address::generate(&e);
+    let hash = BytesN::from_array(&e, &[1; 32]);
+    client.apply_as_attester(&a, &hash);
+    assert_eq!(
+        client.try_apply_as_attester(&a, &hash).err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_registered_attester_cannot_apply() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let a = Address::generate(&e);
+    client.register_attester(&a);
+    assert_eq!(
+        client
+            .try_apply_as_attester(&a, &BytesN::from_array(&e, &[1; 32]))
+            .err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_approve_without_application_fails() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let a = Address::generate(&e);
+    assert_eq!(
+        client.try_approve_attester(&admin, &a).err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_approve_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let a = Address::generate(&e);
+    client.apply_as_attester(&a, &BytesN::from_array(&e, &[1; 32]));
+    assert_eq!(
+        client.try_approve_attester(&a, &a).err(),
+        Some(Ok(BondError::NotAdmin))
+    );
+    assert_eq!(
+        client.try_reject_attester(&a, &a).err(),
+        Some(Ok(BondError::NotAdmin))
+    );
+}
+
+#[test]
+fn test_pending_applications_paginated() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let mut applicants = Vec::new(&e);
+    for i in 0..5_u8 {
+        let a = Address::generate(&e);
+        client.apply_as_attester(&a, &BytesN::from_array(&e, &[i; 32]));
+        applicants.push_back(a);
+    }
+    client.approve_attester(&admin, &applicants.get_unchecked(1));
+
+    let page = client.get_attester_applications(&0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get_unchecked(0).applicant, applicants.get_unchecked(0));
+    assert_eq!(page.get_unchecked(1).applicant, applicants.get_unchecked(2));
+    let rest = client.get_attester_applications(&2, &10);
+    assert_eq!(rest.len(), 2);
+    assert_eq!(rest.get_unchecked(1).applicant, applicants.get_unchecked(4));
+    assert_eq!(client.get_attester_applications(&4, &10).len(), 0);
+}
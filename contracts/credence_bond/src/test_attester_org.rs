@@ -0,0 +1,116 @@
+//! Tests for attester organizations: sub-attester registration, parent-attributed weight
+//! and issuance, per-sub caps, suspension and removal.
+
+use crate::{CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let parent = Address::generate(e);
+    client.register_attester(&parent);
+    client.set_attester_stake(&admin, &parent, &100_000_i128); // weight 1000
+    let sub = Address::generate(e);
+    client.register_sub_attester(&parent, &sub, &200_u32);
+    (client, admin, parent, sub)
+}
+
+#[test]
+fn test_sub_attestation_attributed_to_parent() {
+    let e = Env::default();
+    let (client, _admin, parent, sub) = setup(&e);
+    let subject = Address::generate(&e);
+    let att = client.add_attestation(&sub, &subject, &String::from_str(&e, "kyc"), &0_u64);
+    assert_eq!(att.verifier, parent);
+    assert_eq!(att.weight, 200);
+    assert_eq!(client.get_attester_issued_count(&parent), 1);
+    assert_eq!(client.get_attester_issued_count(&sub), 0);
+    // Nonce is tracked per signing key.
+    assert_eq!(client.get_nonce(&sub), 1);
+}
+
+#[test]
+fn test_sub_weight_cap_does_not_raise_weight() {
+    let e = Env::default();
+    let (client, admin, parent, sub) = setup(&e);
+    client.set_attester_stake(&admin, &parent, &5_000_i128); // weight 50
+    let subject = Address::generate(&e);
+    let att = client.add_attestation(&sub, &subject, &String::from_str(&e, "x"), &0_u64);
+    assert_eq!(att.weight, 50);
+}
+
+#[test]
+fn test_list_subs() {
+    let e = Env::default();
+    let (client, _admin, parent, sub) = setup(&e);
+    let sub2 = Address::generate(&e);
+    client.register_sub_attester(&parent, &sub2, &10_u32);
+    assert_eq!(client.get_sub_attesters(&parent).len(), 2);
+    client.remove_sub_attester(&parent, &sub);
+    let subs = client.get_sub_attesters(&parent);
+    assert_eq!(subs.len(), 1);
+    assert_eq!(subs.get(0).unwrap(), sub2);
+    assert!(client.get_sub_attester(&sub).is_none());
+}
+
+#[test]
+fn test_sub_can_revoke_parent_attestation() {
+    let e = Env::default();
+    let (client, _admin, _parent, sub) = setup(&e);
+    let subject = Address::generate(&e);
+    let att = client.add_attestation(&sub, &subject, &String::from_str(&e, "x"), &0_u64);
+    client.revoke_attestation(&sub, &att.id, &RevocationReason::IssuedInError, &1_u64);
+    assert!(client.get_attestation(&subject, &att.id).revoked);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn test_suspended_sub_cannot_attest() {
+    let e = Env::default();
+    let (client, _admin, parent, sub) = setup(&e);
+    client.set_sub_attester_suspended(&parent, &sub, &true);
+    assert!(client.get_sub_attester(&sub).unwrap().suspended);
+    let subject = Address::generate(&e);
+    client.add_attestation(&sub, &subject, &String::from_str(&e, "x"), &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn test_sub_of_unregistered_parent_cannot_attest() {
+    let e = Env::default();
+    let (client, _admin, parent, sub) = setup(&e);
+    client.unregister_attester(&parent);
+    let subject = Address::generate(&e);
+    client.add_attestation(&sub, &subject, &String::from_str(&e, "x"), &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #211)")]
+fn test_other_parent_cannot_suspend() {
+    let e = Env::default();
+    let (client, _admin, _parent, sub) = setup(&e);
+    let other = Address::generate(&e);
+    client.register_attester(&other);
+    client.set_sub_attester_suspended(&other, &sub, &true);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn test_non_attester_cannot_register_subs() {
+    let e = Env::default();
+    let (client, _admin, _parent, _sub) = setup(&e);
+    let outsider = Address::generate(&e);
+    client.register_sub_attester(&outsider, &Address::generate(&e), &10_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_duplicate_sub_rejected() {
+    let e = Env::default();
+    let (client, _admin, parent, sub) = setup(&e);
+    client.register_sub_attester(&parent, &sub, &10_u32);
+}
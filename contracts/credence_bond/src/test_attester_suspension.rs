@@ -0,0 +1,160 @@
+//! Tests for temporary attester suspension: new attestations are blocked until the end
+//! timestamp, registration and existing attestations are kept, only admin or governors
+//! can suspend, and the combined attester status.
+
+use crate::attester_suspension::AttesterStatus;
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    let subject = Address::generate(e);
+    (client, admin, attester, subject)
+}
+
+#[test]
+fn test_suspension_blocks_then_lifts() {
+    let e = Env::default();
+    let (client, admin, attester, subject) = setup(&e);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"), &0_u64);
+
+    client.suspend_attester(&admin, &attester, &2000_u64);
+    assert!(client.is_attester(&attester));
+    assert!(!client.is_attester_active(&attester));
+    assert_eq!(client.get_attester_suspended_until(&attester), 2000);
+    assert!(!client.get_attestation(&subject, &att.id).revoked);
+
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    assert!(client.is_attester_active(&attester));
+    client.add_attestation(&attester, &subject, &String::from_str(&e, "aml"), &1_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn test_suspended_attester_cannot_attest() {
+    let e = Env::default();
+    let (client, admin, attester, subject) = setup(&e);
+    client.suspend_attester(&admin, &attester, &2000_u64);
+    client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"), &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn test_sub_attester_of_suspended_parent_cannot_attest() {
+    let e = Env::default();
+    let (client, admin, attester, subject) = setup(&e);
+    let sub = Address::generate(&e);
+    client.register_sub_attester(&attester, &sub, &100_u32);
+    client.suspend_attester(&admin, &attester, &2000_u64);
+    client.add_attestation(&sub, &subject, &String::from_str(&e, "kyc"), &0_u64);
+}
+
+#[test]
+fn test_past_timestamp_lifts_suspension() {
+    let e = Env::default();
+    let (client, admin, attester, _subject) = setup(&e);
+    client.suspend_attester(&admin, &attester, &5000_u64);
+    client.suspend_attester(&admin, &attester, &0_u64);
+    assert!(client.is_attester_active(&attester));
+    assert_eq!(client.get_attester_suspended_until(&attester), 0);
+}
+
+#[test]
+fn test_governor_can_suspend() {
+    let e = Env::default();
+    let (client, admin, attester, _subject) = setup(&e);
+    let governor = Address::generate(&e);
+    client.initialize_governance(
+        &admin,
+        &vec![&e, governor.clone()],
+        &5100_u32,
+        &1_u32,
+        &0_u64,
+    );
+    client.suspend_attester(&governor, &attester, &2000_u64);
+    assert!(!client.is_attester_active(&attester));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_stranger_cannot_suspend() {
+    let e = Env::default();
+    let (client, _admin, attester, _subject) = setup(&e);
+    client.suspend_attester(&Address::generate(&e), &attester, &2000_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn test_suspend_unregistered_attester() {
+    let e = Env::default();
+    let (client, admin, _attester, _subject) = setup(&e);
+    client.suspend_attester(&admin, &Address::generate(&e), &2000_u64);
+}
+
+#[test]
+fn test_unregistered_attester_not_active() {
+    let e = Env::default();
+    let (client, _admin, attester, _subject) = setup(&e);
+    client.unregister_attester(&attester);
+    assert!(!client.is_attester_active(&attester));
+}
+
+#[test]
+fn test_status_follows_suspension() {
+    let e = Env::default();
+    let (client, admin, attester, _subject) = setup(&e);
+    assert_eq!(
+        client.get_attester_status(&attester),
+        AttesterStatus::Active
+    );
+    client.suspend_attester(&admin, &attester, &2000_u64);
+    assert_eq!(
+        client.get_attester_status(&attester),
+        AttesterStatus::Suspended
+    );
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    assert_eq!(
+        client.get_attester_status(&attester),
+        AttesterStatus::Active
+    );
+}
+
+#[test]
+fn test_status_removed() {
+    let e = Env::default();
+    let (client, _admin, attester, _subject) = setup(&e);
+    assert_eq!(
+        client.get_attester_status(&Address::generate(&e)),
+        AttesterStatus::Removed
+    );
+    client.unregister_attester(&attester);
+    assert_eq!(
+        client.get_attester_status(&attester),
+        AttesterStatus::Removed
+    );
+}
+
+#[test]
+fn test_status_during_deregistration_notice() {
+    let e = Env::default();
+    let (client, admin, attester, _subject) = setup(&e);
+    client.set_deregistration_notice(&admin, &500_u64);
+    client.unregister_attester(&attester);
+    assert_eq!(
+        client.get_attester_status(&attester),
+        AttesterStatus::Deregistering
+    );
+    e.ledger().with_mut(|li| li.timestamp = 1500);
+    assert_eq!(
+        client.get_attester_status(&attester),
+        AttesterStatus::Removed
+    );
+}
@@ -0,0 +1,117 @@
+//! Tests for auto top-up: restoring the target tier from the allowance after slashes and
+//! partial withdrawals, the total budget and configuration checks.
+
+use crate::auto_topup::AutoTopUp;
+use crate::tiered_bond::TIER_SILVER_MAX;
+use crate::{BondError, BondTier, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{token, Address, Env, IntoVal, Symbol};
+
+/// Exactly the Gold threshold.
+const BOND: i128 = TIER_SILVER_MAX;
+const SPARE: i128 = 3_000_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|l| l.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    token::StellarAssetClient::new(e, &sac.address()).mint(&identity, &(BOND + SPARE));
+    client.set_token(&admin, &sac.address());
+    client.create_bond_funded(&identity, &BOND, &86_400_u64, &false, &0_u64);
+    token::Client::new(e, &sac.address()).approve(&identity, &contract_id, &SPARE, &1_000);
+    (client, admin, identity, sac.address())
+}
+
+#[test]
+fn test_slash_restored_from_allowance() {
+    let e = Env::default();
+    let (client, admin, identity, token) = setup(&e);
+    client.enable_auto_topup(&identity, &BondTier::Gold, &SPARE);
+    client.slash(&admin, &1_000_000_000_i128);
+
+    let bond = client.get_identity_state();
+    assert_eq!(bond.bonded_amount - bond.slashed_amount, BOND);
+    assert_eq!(
+        client.get_auto_topup(&identity),
+        Some(AutoTopUp {
+            target_tier: BondTier::Gold,
+            max_amount: SPARE,
+            used: 1_000_000_000,
+        })
+    );
+    assert_eq!(
+        token::Client::new(&e, &token).balance(&identity),
+        SPARE - 1_000_000_000
+    );
+}
+
+#[test]
+fn test_withdrawal_not_topped_up() {
+    let e = Env::default();
+    let (client, _admin, identity, token) = setup(&e);
+    client.enable_auto_topup(&identity, &BondTier::Gold, &SPARE);
+    let bond = client.withdraw(&500_000_000_i128);
+    assert_eq!(bond.bonded_amount, BOND - 500_000_000);
+    assert_eq!(client.get_auto_topup(&identity).unwrap().used, 0);
+    assert_eq!(
+        token::Client::new(&e, &token).balance(&identity),
+        SPARE + 500_000_000
+    );
+}
+
+#[test]
+fn test_shortfall_over_budget_not_pulled() {
+    let e = Env::default();
+    let (client, admin, identity, _token) = setup(&e);
+    client.enable_auto_topup(&identity, &BondTier::Gold, &100_i128);
+    client.slash(&admin, &1_000_000_000_i128);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "auto_topup_failed"), identity.clone()).into_val(&e)
+    );
+
+    let bond = client.get_identity_state();
+    assert_eq!(bond.bonded_amount, BOND);
+    assert_eq!(client.get_auto_topup(&identity).unwrap().used, 0);
+}
+
+#[test]
+fn test_disabled_does_nothing() {
+    let e = Env::default();
+    let (client, admin, identity, _token) = setup(&e);
+    client.enable_auto_topup(&identity, &BondTier::Gold, &SPARE);
+    client.disable_auto_topup(&identity);
+    assert_eq!(client.get_auto_topup(&identity), None);
+    client.slash(&admin, &1_000_000_000_i128);
+    assert_eq!(client.get_identity_state().bonded_amount, BOND);
+}
+
+#[test]
+fn test_enable_validation() {
+    let e = Env::default();
+    let (client, _admin, identity, _token) = setup(&e);
+    assert_eq!(
+        client
+            .try_enable_auto_topup(&identity, &BondTier::Bronze, &SPARE)
+            .err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+    assert_eq!(
+        client
+            .try_enable_auto_topup(&identity, &BondTier::Gold, &0_i128)
+            .err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+    assert_eq!(
+        client
+            .try_enable_auto_topup(&Address::generate(&e), &BondTier::Gold, &SPARE)
+            .err(),
+        Some(Ok(BondError::NoBond))
+    );
+}
@@ -0,0 +1,94 @@
+//! Tests for the bond health view: balances, lock-up, rolling and withdrawal state and the
+//! required-amount coverage check.
+
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+fn setup(e: &Env, is_rolling: bool) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &10_000_i128, &86400_u64, &is_rolling, &3600_u64);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_fresh_bond_health() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e, false);
+    let health = client.get_bond_health(&identity, &5_000_i128);
+    assert_eq!(health.identity, identity);
+    assert!(health.active);
+    assert_eq!(health.available_balance, 10_000);
+    assert_eq!(health.slashed_ratio_bps, 0);
+    assert_eq!(health.lockup_remaining, 86400);
+    assert_eq!(health.tier, client.get_tier());
+    assert!(!health.is_rolling);
+    assert_eq!(health.withdrawal_requested_at, 0);
+    assert_eq!(health.pending_unbond, 0);
+    assert!(health.covers_required);
+}
+
+#[test]
+fn test_slash_reduces_coverage() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e, false);
+    client.slash(&admin, &2_500_i128);
+    let health = client.get_bond_health(&identity, &8_000_i128);
+    assert_eq!(health.available_balance, 7_500);
+    assert_eq!(health.slashed_ratio_bps, 2_500);
+    assert!(!health.covers_required);
+    assert!(
+        client
+            .get_bond_health(&identity, &7_500_i128)
+            .covers_required
+    );
+}
+
+#[test]
+fn test_lockup_counts_down() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e, false);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 86000);
+    assert_eq!(client.get_bond_health(&identity, &0).lockup_remaining, 400);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 90000);
+    assert_eq!(client.get_bond_health(&identity, &0).lockup_remaining, 0);
+}
+
+#[test]
+fn test_rolling_withdrawal_request() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e, true);
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    client.request_withdrawal();
+    let health = client.get_bond_health(&identity, &0);
+    assert!(health.is_rolling);
+    assert_eq!(health.withdrawal_requested_at, 2000);
+}
+
+#[test]
+fn test_pending_unbond_reserved() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e, false);
+    client.set_unbond_delay(&admin, &3600_u64);
+    client.request_unbond(&4_000_i128);
+    let health = client.get_bond_health(&identity, &6_000_i128);
+    assert_eq!(health.pending_unbond, 4_000);
+    assert_eq!(health.available_balance, 6_000);
+    assert!(health.covers_required);
+}
+
+#[test]
+fn test_other_identity_has_no_bond() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e, false);
+    assert_eq!(
+        client.try_get_bond_health(&Address::generate(&e), &0).err(),
+        Some(Ok(BondError::NoBond))
+    );
+}
@@ -0,0 +1,111 @@
+//! Tests for bond limits: positive amounts, configured minimum amount and duration bounds on
+//! creation, top-ups and extensions.
+
+use crate::bond_limits::BondLimits;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+const DAY: u64 = 86_400;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin, Address::generate(e))
+}
+
+fn limits(min_amount: i128, min_duration: u64, max_duration: u64) -> BondLimits {
+    BondLimits {
+        min_amount,
+        min_duration,
+        max_duration,
+    }
+}
+
+#[test]
+fn test_default_limits_reject_non_positive_amounts() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    assert_eq!(client.get_bond_limits(), limits(0, 0, 0));
+    assert_eq!(
+        client
+            .try_create_bond(&identity, &0, &DAY, &false, &0)
+            .err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+    assert_eq!(
+        client
+            .try_create_bond(&identity, &-5, &DAY, &false, &0)
+            .err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+    client.create_bond(&identity, &1, &0, &false, &0);
+    assert_eq!(
+        client.try_top_up(&0).err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_min_amount_enforced() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_bond_limits(&admin, &limits(1_000, 0, 0));
+    assert_eq!(
+        client
+            .try_create_bond(&identity, &999, &DAY, &false, &0)
+            .err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+    let bond = client.create_bond(&identity, &1_000, &DAY, &false, &0);
+    assert_eq!(bond.bonded_amount, 1_000);
+    assert_eq!(client.top_up(&1).bonded_amount, 1_001);
+}
+
+#[test]
+fn test_duration_bounds_enforced() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_bond_limits(&admin, &limits(0, DAY, 30 * DAY));
+    assert_eq!(
+        client
+            .try_create_bond(&identity, &1_000, &(DAY - 1), &false, &0)
+            .err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+    assert_eq!(
+        client
+            .try_create_bond(&identity, &1_000, &(30 * DAY + 1), &false, &0)
+            .err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+    client.create_bond(&identity, &1_000, &(29 * DAY), &false, &0);
+    assert_eq!(client.extend_duration(&DAY).bond_duration, 30 * DAY);
+    assert_eq!(
+        client.try_extend_duration(&1).err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_set_bond_limits_validation() {
+    let e = Env::default();
+    let (client, admin, outsider) = setup(&e);
+    assert_eq!(
+        client.try_set_bond_limits(&outsider, &limits(0, 0, 0)),
+        Err(Ok(BondError::NotAdmin))
+    );
+    assert_eq!(
+        client.try_set_bond_limits(&admin, &limits(-1, 0, 0)),
+        Err(Ok(BondError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_bond_limits(&admin, &limits(0, 2 * DAY, DAY)),
+        Err(Ok(BondError::InvalidConfig))
+    );
+    client.set_bond_limits(&admin, &limits(0, 2 * DAY, 0));
+    assert_eq!(client.get_bond_limits(), limits(0, 2 * DAY, 0));
+}
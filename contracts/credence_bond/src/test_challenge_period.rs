@@ -0,0 +1,145 @@
+//! Tests for the attestation challenge period: pending vs active weight, automatic
+//! activation, and rejection of pending attestations.
+
+use crate::challenge_period::MAX_CHALLENGE_WINDOW;
+use crate::{testutils, CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    client.set_attester_stake(&admin, &attester, &1_000_i128);
+    (client, admin, attester)
+}
+
+fn attest(e: &Env, client: &CredenceBondClient<'_>, attester: &Address, subject: &Address) -> u64 {
+    let nonce = client.get_nonce(attester);
+    client
+        .add_attestation(attester, subject, &String::from_str(e, "kyc"), &nonce)
+        .id
+}
+
+#[test]
+fn test_no_window_activates_immediately() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    attest(&e, &client, &attester, &subject);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_subject_pending_weight(&consumer, &subject), 0);
+    assert!(client.get_subject_aggregate_weight(&consumer, &subject) > 0);
+}
+
+#[test]
+fn test_attestation_pending_then_active() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_challenge_window(&admin, &3600_u64);
+    let subject = Address::generate(&e);
+    let id = attest(&e, &client, &attester, &subject);
+    let att = client.get_attestation(&subject, &id);
+    assert_eq!(att.active_from, 4600);
+    assert!(att.is_pending_at(1000));
+
+    let weight = att.weight as u64;
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_subject_aggregate_weight(&consumer, &subject), 0);
+    assert_eq!(
+        client.get_subject_pending_weight(&consumer, &subject),
+        weight
+    );
+    let snapshot = client.get_verification_snapshot(&consumer, &subject);
+    assert_eq!(snapshot.active_weight, 0);
+    assert_eq!(snapshot.pending_weight, weight);
+    assert_eq!(snapshot.attestation_count, 1);
+
+    e.ledger().with_mut(|li| li.timestamp = 4600);
+    assert_eq!(
+        client.get_subject_aggregate_weight(&consumer, &subject),
+        weight
+    );
+    assert_eq!(client.get_subject_pending_weight(&consumer, &subject), 0);
+    let snapshot = client.get_verification_snapshot(&consumer, &subject);
+    assert_eq!(snapshot.active_weight, weight);
+    assert_eq!(snapshot.pending_weight, 0);
+}
+
+#[test]
+fn test_subject_can_challenge_pending_attestation() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_challenge_window(&admin, &3600_u64);
+    let subject = Address::generate(&e);
+    let id = attest(&e, &client, &attester, &subject);
+    client.challenge_attestation(&subject, &id);
+
+    assert!(client.get_attestation(&subject, &id).revoked);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_subject_pending_weight(&consumer, &subject), 0);
+    assert_eq!(client.get_subject_attestation_count(&consumer, &subject), 0);
+    let snapshot = client.get_verification_snapshot(&consumer, &subject);
+    assert_eq!(snapshot.active_weight, 0);
+    assert_eq!(snapshot.pending_weight, 0);
+}
+
+#[test]
+fn test_admin_can_challenge_pending_attestation() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_challenge_window(&admin, &3600_u64);
+    let subject = Address::generate(&e);
+    let id = attest(&e, &client, &attester, &subject);
+    client.challenge_attestation(&admin, &id);
+    assert!(client.get_attestation(&subject, &id).revoked);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #207)")]
+fn test_challenge_after_window_rejected() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_challenge_window(&admin, &3600_u64);
+    let subject = Address::generate(&e);
+    let id = attest(&e, &client, &attester, &subject);
+    e.ledger().with_mut(|li| li.timestamp = 4600);
+    client.challenge_attestation(&subject, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_outsider_cannot_challenge() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_challenge_window(&admin, &3600_u64);
+    let subject = Address::generate(&e);
+    let id = attest(&e, &client, &attester, &subject);
+    client.challenge_attestation(&Address::generate(&e), &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_window_bounded() {
+    let e = Env::default();
+    let (client, admin, _attester) = setup(&e);
+    client.set_challenge_window(&admin, &(MAX_CHALLENGE_WINDOW + 1));
+}
+
+#[test]
+fn test_revoking_pending_attestation_clears_pending_weight() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_challenge_window(&admin, &3600_u64);
+    let subject = Address::generate(&e);
+    let id = attest(&e, &client, &attester, &subject);
+    let nonce = client.get_nonce(&attester);
+    client.revoke_attestation(&attester, &id, &RevocationReason::IssuedInError, &nonce);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_subject_pending_weight(&consumer, &subject), 0);
+}
@@ -0,0 +1,89 @@
+//! Tests for closing a bond: empty-balance requirement, cleared rolling state, and creating
+//! a fresh bond afterwards.
+
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin, Address::generate(e))
+}
+
+#[test]
+fn test_close_requires_empty_bond() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
+    assert_eq!(
+        client.try_close_bond(&identity).err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+
+    client.withdraw(&1000);
+    let bond = client.close_bond(&identity);
+    assert!(!bond.active);
+    let (_, topics, _) = e.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "bond_closed"), identity.clone()).into_val(&e)
+    );
+    assert_eq!(
+        client.try_close_bond(&identity).err(),
+        Some(Ok(BondError::NoBond))
+    );
+}
+
+#[test]
+fn test_close_fully_slashed_bond() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
+    client.slash(&admin, &1000);
+    let bond = client.close_bond(&identity);
+    assert!(!bond.active);
+    assert_eq!(bond.slashed_amount, 1000);
+}
+
+#[test]
+fn test_close_only_by_bond_identity() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
+    client.withdraw(&1000);
+    let outsider = Address::generate(&e);
+    assert_eq!(
+        client.try_close_bond(&outsider).err(),
+        Some(Ok(BondError::NoBond))
+    );
+}
+
+#[test]
+fn test_rebond_after_close_starts_clean() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
+    e.ledger().with_mut(|li| li.timestamp = 1101);
+    client.renew_if_rolling();
+    assert_eq!(client.get_renewal_count(), 1);
+    client.request_withdrawal();
+    e.ledger().with_mut(|li| li.timestamp = 1112);
+    client.withdraw(&1000);
+
+    let closed = client.close_bond(&identity);
+    assert!(!closed.is_rolling);
+    assert_eq!(closed.withdrawal_requested_at, 0);
+    assert_eq!(client.get_renewal_count(), 0);
+
+    let bond = client.create_bond(&identity, &500_i128, &200_u64, &false, &0_u64);
+    assert!(bond.active);
+    assert_eq!(bond.bonded_amount, 500);
+    assert_eq!(bond.slashed_amount, 0);
+    assert_eq!(bond.bond_start, 1112);
+    assert_eq!(client.get_identity_state().withdrawal_requested_at, 0);
+}
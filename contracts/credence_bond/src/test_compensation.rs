@@ -0,0 +1,130 @@
+//! Tests for victim compensation: escrow on slash, beneficiary claims, expiry and sweep.
+
+use crate::compensation::calculate_share;
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    let treasury = Address::generate(e);
+    client.set_compensation_config(&admin, &treasury, &5000_u32, &100_u64);
+    (client, admin, treasury)
+}
+
+#[test]
+fn test_calculate_share() {
+    assert_eq!(calculate_share(1000, 5000), 500);
+    assert_eq!(calculate_share(1000, 0), 0);
+    assert_eq!(calculate_share(1000, 10_000), 1000);
+}
+
+#[test]
+fn test_slash_with_beneficiary_escrows_share() {
+    let e = Env::default();
+    let (client, admin, _treasury) = setup(&e);
+    let victim = Address::generate(&e);
+    let slash_id = client.slash_with_beneficiary(&admin, &400_i128, &victim);
+    assert_eq!(slash_id, 0);
+    assert_eq!(client.get_identity_state().slashed_amount, 400);
+
+    let claim = client.get_compensation_claim(&slash_id);
+    assert_eq!(claim.beneficiary, victim);
+    assert_eq!(claim.slashed_amount, 400);
+    assert_eq!(claim.amount, 200);
+    assert_eq!(claim.claim_deadline, 1100);
+    assert_eq!(client.get_compensation_escrow(), 200);
+}
+
+#[test]
+fn test_share_based_on_capped_slash() {
+    let e = Env::default();
+    let (client, admin, _treasury) = setup(&e);
+    client.slash(&admin, &900_i128);
+    let victim = Address::generate(&e);
+    let slash_id = client.slash_with_beneficiary(&admin, &500_i128, &victim);
+    let claim = client.get_compensation_claim(&slash_id);
+    assert_eq!(claim.slashed_amount, 100);
+    assert_eq!(claim.amount, 50);
+}
+
+#[test]
+fn test_claim_compensation() {
+    let e = Env::default();
+    let (client, admin, _treasury) = setup(&e);
+    let victim = Address::generate(&e);
+    let slash_id = client.slash_with_beneficiary(&admin, &400_i128, &victim);
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    assert_eq!(client.claim_compensation(&victim, &slash_id), 200);
+    assert!(client.get_compensation_claim(&slash_id).claimed);
+    assert_eq!(client.get_compensation_escrow(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_claim_by_other_rejected() {
+    let e = Env::default();
+    let (client, admin, _treasury) = setup(&e);
+    let victim = Address::generate(&e);
+    let slash_id = client.slash_with_beneficiary(&admin, &400_i128, &victim);
+    client.claim_compensation(&Address::generate(&e), &slash_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #311)")]
+fn test_double_claim_rejected() {
+    let e = Env::default();
+    let (client, admin, _treasury) = setup(&e);
+    let victim = Address::generate(&e);
+    let slash_id = client.slash_with_beneficiary(&admin, &400_i128, &victim);
+    client.claim_compensation(&victim, &slash_id);
+    client.claim_compensation(&victim, &slash_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn test_claim_after_deadline_rejected() {
+    let e = Env::default();
+    let (client, admin, _treasury) = setup(&e);
+    let victim = Address::generate(&e);
+    let slash_id = client.slash_with_beneficiary(&admin, &400_i128, &victim);
+    e.ledger().with_mut(|li| li.timestamp = 1101);
+    client.claim_compensation(&victim, &slash_id);
+}
+
+#[test]
+fn test_sweep_expired_to_treasury() {
+    let e = Env::default();
+    let (client, admin, _treasury) = setup(&e);
+    let victim = Address::generate(&e);
+    let slash_id = client.slash_with_beneficiary(&admin, &400_i128, &victim);
+    e.ledger().with_mut(|li| li.timestamp = 1101);
+    assert_eq!(client.sweep_expired_compensation(&slash_id), 200);
+    assert!(client.get_compensation_claim(&slash_id).swept);
+    assert_eq!(client.get_compensation_escrow(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #111)")]
+fn test_sweep_before_deadline_rejected() {
+    let e = Env::default();
+    let (client, admin, _treasury) = setup(&e);
+    let victim = Address::generate(&e);
+    let slash_id = client.slash_with_beneficiary(&admin, &400_i128, &victim);
+    client.sweep_expired_compensation(&slash_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_invalid_share_rejected() {
+    let e = Env::default();
+    let (client, admin, treasury) = setup(&e);
+    client.set_compensation_config(&admin, &treasury, &10_001_u32, &100_u64);
+}
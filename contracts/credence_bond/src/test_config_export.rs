@@ -0,0 +1,70 @@
+//! Tests for `export_config`: defaults on a fresh instance and reflection of admin updates.
+
+use crate::features::{ALL_FEATURES, FEATURE_FEES};
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_export_defaults() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let config = client.export_config();
+    assert_eq!(config.admin, Some(admin));
+    assert_eq!(config.features, ALL_FEATURES);
+    assert_eq!(config.fee_treasury, None);
+    assert_eq!(config.fee_bps, 0);
+    assert_eq!(config.early_exit_treasury, None);
+    assert_eq!(config.verifier_cap_bps, 10_000);
+    assert_eq!(config.governors.len(), 0);
+    assert_eq!(config.compensation_treasury, None);
+    assert_eq!(config.unbonding.exit_capacity_bps, 1_000);
+}
+
+#[test]
+fn test_export_reflects_updates() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let treasury = Address::generate(&e);
+    let governor = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32);
+    client.set_early_exit_config(&admin, &treasury, &500_u32);
+    client.set_weight_config(&admin, &200_u32, &5_000_u32);
+    client.initialize_governance(
+        &admin,
+        &vec![&e, governor.clone()],
+        &6000_u32,
+        &1_u32,
+        &0_u64,
+    );
+    client.set_health_thresholds(&admin, &4000_u32, &1000_u32);
+    client.set_compensation_config(&admin, &treasury, &2500_u32, &3600_u64);
+    client.set_features(&admin, &FEATURE_FEES);
+    client.set_renewal_bonus_config(&admin, &50_u32, &4_u32);
+
+    let config = client.export_config();
+    assert_eq!(config.fee_treasury, Some(treasury.clone()));
+    assert_eq!(config.fee_bps, 100);
+    assert_eq!(config.early_exit_treasury, Some(treasury.clone()));
+    assert_eq!(config.early_exit_penalty_bps, 500);
+    assert_eq!(config.weight_multiplier_bps, 200);
+    assert_eq!(config.max_weight, 5_000);
+    assert_eq!(config.governors, vec![&e, governor]);
+    assert_eq!(config.quorum_bps, 6000);
+    assert_eq!(config.health_at_risk_bps, 4000);
+    assert_eq!(config.health_critical_bps, 1000);
+    assert_eq!(config.compensation_share_bps, 2500);
+    assert_eq!(config.compensation_claim_window, 3600);
+    assert_eq!(config.features, FEATURE_FEES);
+    assert_eq!(config.renewal_bonus_bps, 50);
+    assert_eq!(config.renewal_bonus_max_renewals, 4);
+}
@@ -0,0 +1,282 @@
+//! Tests for counter-attestations: issuing, conflict index and views, weight netting
+//! policies, resolution and escalation to the dispute contract.
+
+use crate::counter_attestation::{ConflictNetting, CounterStatus};
+use crate::{testutils, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+/// Dispute contract stand-in recording the last `create_dispute` call.
+#[contract]
+pub struct MockDispute;
+
+#[contractimpl]
+impl MockDispute {
+    pub fn create_dispute(
+        e: Env,
+        disputer: Address,
+        slash_request_id: u64,
+        stake: i128,
+        _token: Address,
+        _resolution_deadline: u64,
+    ) -> u64 {
+        disputer.require_auth();
+        e.storage()
+            .instance()
+            .set(&0_u32, &(slash_request_id, stake));
+        77
+    }
+
+    pub fn last(e: Env) -> (u64, i128) {
+        e.storage().instance().get(&0_u32).unwrap()
+    }
+}
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    original_attester: Address,
+    counter_attester: Address,
+    subject: Address,
+    attestation_id: u64,
+}
+
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let original_attester = Address::generate(e);
+    let counter_attester = Address::generate(e);
+    client.register_attester(&original_attester);
+    client.register_attester(&counter_attester);
+    client.set_attester_stake(&admin, &original_attester, &1_000_i128);
+    client.set_attester_stake(&admin, &counter_attester, &400_i128);
+    let subject = Address::generate(e);
+    let attestation_id = client
+        .add_attestation(
+            &original_attester,
+            &subject,
+            &String::from_str(e, "kyc"),
+            &0_u64,
+        )
+        .id;
+    Setup {
+        client,
+        admin,
+        original_attester,
+        counter_attester,
+        subject,
+        attestation_id,
+    }
+}
+
+fn counter(e: &Env, s: &Setup<'_>) -> u64 {
+    let nonce = s.client.get_nonce(&s.counter_attester);
+    s.client.counter_attest(
+        &s.counter_attester,
+        &s.attestation_id,
+        &String::from_str(e, "documents forged"),
+        &nonce,
+    )
+}
+
+#[test]
+fn test_counter_is_indexed_and_shown_with_original() {
+    let e = Env::default();
+    let s = setup(&e);
+    let consumer = testutils::query_consumer(&e, &s.client.address);
+    let original_weight = s.client.get_subject_aggregate_weight(&consumer, &s.subject);
+    let id = counter(&e, &s);
+    let c = s.client.get_counter_attestation(&id).unwrap();
+    assert_eq!(c.original_id, s.attestation_id);
+    assert_eq!(c.status, CounterStatus::Open);
+
+    let contested = s
+        .client
+        .get_contested_attestation(&s.subject, &s.attestation_id);
+    assert_eq!(contested.counters.len(), 1);
+    assert_eq!(contested.counters.get(0).unwrap().id, id);
+    assert_eq!(contested.net_weight, (original_weight as u32) - c.weight);
+}
+
+#[test]
+fn test_netting_policies() {
+    let e = Env::default();
+    let s = setup(&e);
+    let consumer = testutils::query_consumer(&e, &s.client.address);
+    let full = s.client.get_subject_aggregate_weight(&consumer, &s.subject);
+    let id = counter(&e, &s);
+    let counter_weight = s.client.get_counter_attestation(&id).unwrap().weight as u64;
+    assert_eq!(s.client.get_conflict_netting(), ConflictNetting::Subtract);
+    assert_eq!(
+        s.client.get_subject_aggregate_weight(&consumer, &s.subject),
+        full - counter_weight
+    );
+
+    s.client
+        .set_conflict_netting(&s.admin, &ConflictNetting::Suspend);
+    assert_eq!(
+        s.client.get_subject_aggregate_weight(&consumer, &s.subject),
+        0
+    );
+
+    s.client
+        .set_conflict_netting(&s.admin, &ConflictNetting::Ignore);
+    assert_eq!(
+        s.client.get_subject_aggregate_weight(&consumer, &s.subject),
+        full
+    );
+}
+
+#[test]
+fn test_dismissed_counter_restores_weight() {
+    let e = Env::default();
+    let s = setup(&e);
+    let consumer = testutils::query_consumer(&e, &s.client.address);
+    let full = s.client.get_subject_aggregate_weight(&consumer, &s.subject);
+    let id = counter(&e, &s);
+    s.client
+        .resolve_counter_attestation(&s.admin, &id, &CounterStatus::Dismissed);
+    assert_eq!(
+        s.client.get_subject_aggregate_weight(&consumer, &s.subject),
+        full
+    );
+    assert_eq!(
+        s.client.get_counter_attestation(&id).unwrap().status,
+        CounterStatus::Dismissed
+    );
+}
+
+#[test]
+fn test_upheld_counter_revokes_original() {
+    let e = Env::default();
+    let s = setup(&e);
+    let id = counter(&e, &s);
+    s.client
+        .resolve_counter_attestation(&s.admin, &id, &CounterStatus::Upheld);
+    assert!(
+        s.client
+            .get_attestation(&s.subject, &s.attestation_id)
+            .revoked
+    );
+    let consumer = testutils::query_consumer(&e, &s.client.address);
+    assert_eq!(
+        s.client
+            .get_subject_attestation_count(&consumer, &s.subject),
+        0
+    );
+    assert_eq!(
+        s.client.get_subject_aggregate_weight(&consumer, &s.subject),
+        0
+    );
+}
+
+#[test]
+fn test_escalate_conflict_opens_dispute() {
+    let e = Env::default();
+    let s = setup(&e);
+    let dispute_id = e.register(MockDispute, ());
+    s.client
+        .set_conflict_dispute_contract(&s.admin, &dispute_id);
+    let id = counter(&e, &s);
+    let token = Address::generate(&e);
+    let opened = s
+        .client
+        .escalate_conflict(&s.counter_attester, &id, &500_i128, &token, &3600_u64);
+    assert_eq!(opened, 77);
+    assert_eq!(
+        s.client.get_counter_attestation(&id).unwrap().dispute_id,
+        77
+    );
+    assert_eq!(
+        MockDisputeClient::new(&e, &dispute_id).last(),
+        (s.attestation_id, 500)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #115)")]
+fn test_escalate_without_dispute_contract() {
+    let e = Env::default();
+    let s = setup(&e);
+    let id = counter(&e, &s);
+    s.client.escalate_conflict(
+        &s.counter_attester,
+        &id,
+        &500_i128,
+        &Address::generate(&e),
+        &3600_u64,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #207)")]
+fn test_cannot_counter_own_attestation() {
+    let e = Env::default();
+    let s = setup(&e);
+    let nonce = s.client.get_nonce(&s.original_attester);
+    s.client.counter_attest(
+        &s.original_attester,
+        &s.attestation_id,
+        &String::from_str(&e, "oops"),
+        &nonce,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #207)")]
+fn test_one_counter_per_attester() {
+    let e = Env::default();
+    let s = setup(&e);
+    counter(&e, &s);
+    counter(&e, &s);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn test_unregistered_cannot_counter() {
+    let e = Env::default();
+    let s = setup(&e);
+    let outsider = Address::generate(&e);
+    s.client.counter_attest(
+        &outsider,
+        &s.attestation_id,
+        &String::from_str(&e, "x"),
+        &0_u64,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #207)")]
+fn test_resolve_twice_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    let id = counter(&e, &s);
+    s.client
+        .resolve_counter_attestation(&s.admin, &id, &CounterStatus::Dismissed);
+    s.client
+        .resolve_counter_attestation(&s.admin, &id, &CounterStatus::Upheld);
+}
+
+#[test]
+fn test_counter_ids_independent_of_upgrade_proposal_ids() {
+    let e = Env::default();
+    let s = setup(&e);
+    let id = counter(&e, &s);
+    let governor = Address::generate(&e);
+    s.client.initialize_governance(
+        &s.admin,
+        &soroban_sdk::vec![&e, governor.clone()],
+        &10_000,
+        &1,
+        &0,
+    );
+    let hash = soroban_sdk::BytesN::from_array(&e, &[7; 32]);
+    assert_eq!(s.client.propose_upgrade(&governor, &hash), 0);
+    assert_eq!(
+        s.client.get_counter_attestation(&id).unwrap().original_id,
+        s.attestation_id
+    );
+}
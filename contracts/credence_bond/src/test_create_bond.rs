@@ -113,7 +113,7 @@ fn test_create_bond_max_duration() {
 
 /// Test bond creation with duration that causes timestamp overflow
 #[test]
-#[should_panic(expected = "bond end timestamp would overflow")]
+#[should_panic(expected = "Error(Contract, #113)")]
 fn test_create_bond_duration_overflow() {
     let e = Env::default();
     e.ledger().with_mut(|li| {
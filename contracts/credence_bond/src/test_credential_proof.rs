@@ -0,0 +1,138 @@
+//! Tests for the credential proof bundle: contents, determinism, commitment checks and
+//! verification against current state, and subject-only access.
+
+use crate::{testutils, BondTier, CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let subject = Address::generate(e);
+    client.create_bond(&subject, &1000_i128, &86400_u64, &false, &0_u64);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, attester, subject)
+}
+
+fn attest(
+    e: &Env,
+    client: &CredenceBondClient<'_>,
+    attester: &Address,
+    subject: &Address,
+    data: &str,
+) -> u64 {
+    let nonce = client.get_nonce(attester);
+    client
+        .add_attestation(attester, subject, &String::from_str(e, data), &nonce)
+        .id
+}
+
+#[test]
+fn test_proof_contents() {
+    let e = Env::default();
+    let (client, _admin, attester, subject) = setup(&e);
+    let other = Address::generate(&e);
+    client.register_attester(&other);
+    attest(&e, &client, &attester, &subject, "kyc");
+    attest(&e, &client, &other, &subject, "kyc");
+    attest(&e, &client, &attester, &subject, "aml");
+
+    let proof = client.get_credential_proof(&subject);
+    assert_eq!(proof.subject, subject);
+    assert_eq!(proof.tier, BondTier::Bronze);
+    assert_eq!(proof.weights_by_claim.len(), 2);
+    assert_eq!(
+        proof.weights_by_claim.get(String::from_str(&e, "kyc")),
+        Some(2)
+    );
+    assert_eq!(
+        proof.weights_by_claim.get(String::from_str(&e, "aml")),
+        Some(1)
+    );
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(
+        proof.total_weight,
+        client.get_subject_aggregate_weight(&consumer, &subject)
+    );
+    assert_eq!(proof.ledger_sequence, e.ledger().sequence());
+}
+
+#[test]
+fn test_proof_is_deterministic() {
+    let e = Env::default();
+    let (client, _admin, attester, subject) = setup(&e);
+    attest(&e, &client, &attester, &subject, "kyc");
+    assert_eq!(
+        client.get_credential_proof(&subject),
+        client.get_credential_proof(&subject)
+    );
+}
+
+#[test]
+fn test_verify_valid_proof_in_later_ledger() {
+    let e = Env::default();
+    let (client, _admin, attester, subject) = setup(&e);
+    attest(&e, &client, &attester, &subject, "kyc");
+    let proof = client.get_credential_proof(&subject);
+    e.ledger().with_mut(|li| {
+        li.sequence_number += 10;
+        li.timestamp += 60;
+    });
+    assert!(client.verify_credential_proof(&proof));
+}
+
+#[test]
+fn test_verify_rejects_tampered_proof() {
+    let e = Env::default();
+    let (client, _admin, attester, subject) = setup(&e);
+    attest(&e, &client, &attester, &subject, "kyc");
+    let mut proof = client.get_credential_proof(&subject);
+    proof.total_weight += 1;
+    assert!(!client.verify_credential_proof(&proof));
+
+    let mut proof = client.get_credential_proof(&subject);
+    proof.attester_commitment = BytesN::from_array(&e, &[7; 32]);
+    assert!(!client.verify_credential_proof(&proof));
+}
+
+#[test]
+fn test_verify_rejects_stale_proof_after_revocation() {
+    let e = Env::default();
+    let (client, _admin, attester, subject) = setup(&e);
+    let id = attest(&e, &client, &attester, &subject, "kyc");
+    let proof = client.get_credential_proof(&subject);
+    let nonce = client.get_nonce(&attester);
+    client.revoke_attestation(&attester, &id, &RevocationReason::IssuedInError, &nonce);
+    assert!(!client.verify_credential_proof(&proof));
+    assert_eq!(
+        client.get_credential_proof(&subject).weights_by_claim.len(),
+        0
+    );
+}
+
+#[test]
+fn test_attester_commitment_tracks_attester_set() {
+    let e = Env::default();
+    let (client, _admin, attester, subject) = setup(&e);
+    let empty = client.get_credential_proof(&subject).attester_commitment;
+    attest(&e, &client, &attester, &subject, "kyc");
+    let one = client.get_credential_proof(&subject).attester_commitment;
+    assert_ne!(empty, one);
+    attest(&e, &client, &attester, &subject, "aml");
+    assert_eq!(
+        client.get_credential_proof(&subject).attester_commitment,
+        one
+    );
+}
+
+#[test]
+fn test_proof_requires_subject_auth() {
+    let e = Env::default();
+    let (client, _admin, _attester, subject) = setup(&e);
+    e.set_auths(&[]);
+    assert!(client.try_get_credential_proof(&subject).is_err());
+}
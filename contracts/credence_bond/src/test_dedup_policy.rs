@@ -0,0 +1,137 @@
+//! Tests for the configurable attestation duplication policy: global default, per-claim
+//! overrides, and re-issue after revocation or expiry.
+
+#![cfg(test)]
+
+use crate::dedup_policy::DedupPolicy;
+use crate::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{BytesN, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, attester)
+}
+
+fn add(
+    client: &CredenceBondClient<'_>,
+    attester: &Address,
+    subject: &Address,
+    data: &String,
+) -> u64 {
+    let nonce = client.get_nonce(attester);
+    client.add_attestation(attester, subject, data, &nonce).id
+}
+
+#[test]
+fn test_default_policy_allows_after_revocation() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    assert_eq!(client.get_dedup_policy(), DedupPolicy::AllowAfterRevocation);
+    let subject = Address::generate(&e);
+    let data = String::from_str(&e, "kyc");
+    let id = add(&client, &attester, &subject, &data);
+    let nonce = client.get_nonce(&attester);
+    client.revoke_attestation(&attester, &id, &RevocationReason::Superseded, &nonce);
+    let again = add(&client, &attester, &subject, &data);
+    assert_ne!(again, id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn test_reject_always_blocks_reissue_after_revocation() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_dedup_policy(&admin, &DedupPolicy::RejectAlways);
+    let subject = Address::generate(&e);
+    let data = String::from_str(&e, "kyc");
+    let id = add(&client, &attester, &subject, &data);
+    let nonce = client.get_nonce(&attester);
+    client.revoke_attestation(&attester, &id, &RevocationReason::Superseded, &nonce);
+    add(&client, &attester, &subject, &data);
+}
+
+#[test]
+fn test_allow_after_expiry_reissues_lapsed_claim() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, attester) = setup(&e);
+    client.set_dedup_policy(&admin, &DedupPolicy::AllowAfterExpiry);
+    let template =
+        client.create_template(&admin, &BytesN::from_array(&e, &[1; 32]), &50_u32, &100_u64);
+    let subject = Address::generate(&e);
+    let data = String::from_str(&e, "kyc");
+    let nonce = client.get_nonce(&attester);
+    let first = client.add_attestation_with_template(&attester, &subject, &template, &data, &nonce);
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    let second = add(&client, &attester, &subject, &data);
+    assert_ne!(second, first.id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn test_allow_after_expiry_rejects_live_claim() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, attester) = setup(&e);
+    client.set_dedup_policy(&admin, &DedupPolicy::AllowAfterExpiry);
+    let template =
+        client.create_template(&admin, &BytesN::from_array(&e, &[1; 32]), &50_u32, &100_u64);
+    let subject = Address::generate(&e);
+    let data = String::from_str(&e, "kyc");
+    let nonce = client.get_nonce(&attester);
+    client.add_attestation_with_template(&attester, &subject, &template, &data, &nonce);
+    e.ledger().with_mut(|li| li.timestamp = 1099);
+    add(&client, &attester, &subject, &data);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn test_allow_after_revocation_rejects_expired_claim() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, attester) = setup(&e);
+    let template =
+        client.create_template(&admin, &BytesN::from_array(&e, &[1; 32]), &50_u32, &100_u64);
+    let subject = Address::generate(&e);
+    let data = String::from_str(&e, "kyc");
+    let nonce = client.get_nonce(&attester);
+    client.add_attestation_with_template(&attester, &subject, &template, &data, &nonce);
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    add(&client, &attester, &subject, &data);
+}
+
+#[test]
+fn test_claim_override_takes_precedence() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_dedup_policy(&admin, &DedupPolicy::RejectAlways);
+    let data = String::from_str(&e, "kyc");
+    client.set_claim_dedup_policy(&admin, &data, &Some(DedupPolicy::AllowAfterRevocation));
+    assert_eq!(
+        client.get_claim_dedup_policy(&data),
+        Some(DedupPolicy::AllowAfterRevocation)
+    );
+    let subject = Address::generate(&e);
+    let id = add(&client, &attester, &subject, &data);
+    let nonce = client.get_nonce(&attester);
+    client.revoke_attestation(&attester, &id, &RevocationReason::Superseded, &nonce);
+    add(&client, &attester, &subject, &data);
+
+    client.set_claim_dedup_policy(&admin, &data, &None);
+    assert_eq!(client.get_claim_dedup_policy(&data), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_dedup_policy_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    client.set_dedup_policy(&attester, &DedupPolicy::RejectAlways);
+}
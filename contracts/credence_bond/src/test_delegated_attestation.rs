@@ -0,0 +1,181 @@
+//! Tests for delegated attestation issuance through the delegation contract.
+
+use crate::address_book::ROLE_DELEGATION;
+use crate::delegated_attestation::DelegationKind;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{contract, contractimpl, Address, Env, IntoVal, String, Symbol};
+
+/// Delegation contract stand-in holding a single attestation delegation.
+#[contract]
+pub struct MockDelegation;
+
+#[contractimpl]
+impl MockDelegation {
+    pub fn grant(e: Env, owner: Address, delegate: Address) {
+        e.storage().instance().set(&(owner, delegate), &true);
+    }
+
+    pub fn is_valid_delegate(
+        e: Env,
+        owner: Address,
+        delegate: Address,
+        delegation_type: DelegationKind,
+    ) -> bool {
+        delegation_type == DelegationKind::Attestation
+            && e.storage()
+                .instance()
+                .get(&(owner, delegate))
+                .unwrap_or(false)
+    }
+}
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    attester: Address,
+    delegate: Address,
+    delegation: Address,
+}
+
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    let delegation = e.register(MockDelegation, ());
+    client.set_role_address(
+        &admin,
+        &Symbol::new(e, ROLE_DELEGATION),
+        &Some(delegation.clone()),
+    );
+    Setup {
+        client,
+        admin,
+        attester,
+        delegate: Address::generate(e),
+        delegation,
+    }
+}
+
+#[test]
+fn test_valid_delegate_issues_for_attester() {
+    let e = Env::default();
+    let s = setup(&e);
+    MockDelegationClient::new(&e, &s.delegation).grant(&s.attester, &s.delegate);
+    let subject = Address::generate(&e);
+    let att = s.client.add_attestation_delegated(
+        &s.delegate,
+        &s.attester,
+        &subject,
+        &String::from_str(&e, "kyc"),
+        &0,
+    );
+    assert_eq!(att.verifier, s.attester);
+    assert_eq!(att.identity, subject);
+    assert_eq!(s.client.get_nonce(&s.attester), 1);
+}
+
+#[test]
+fn test_invalid_delegate_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    let subject = Address::generate(&e);
+    assert_eq!(
+        s.client
+            .try_add_attestation_delegated(
+                &s.delegate,
+                &s.attester,
+                &subject,
+                &String::from_str(&e, "kyc"),
+                &0,
+            )
+            .err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_requires_delegation_contract() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client
+        .set_role_address(&s.admin, &Symbol::new(&e, ROLE_DELEGATION), &None);
+    let subject = Address::generate(&e);
+    assert_eq!(
+        s.client
+            .try_add_attestation_delegated(
+                &s.delegate,
+                &s.attester,
+                &subject,
+                &String::from_str(&e, "kyc"),
+                &0,
+            )
+            .err(),
+        Some(Ok(BondError::ConfigNotSet))
+    );
+}
+
+#[test]
+fn test_delegation_does_not_bypass_registration() {
+    let e = Env::default();
+    let s = setup(&e);
+    let unregistered = Address::generate(&e);
+    MockDelegationClient::new(&e, &s.delegation).grant(&unregistered, &s.delegate);
+    let subject = Address::generate(&e);
+    assert_eq!(
+        s.client
+            .try_add_attestation_delegated(
+                &s.delegate,
+                &unregistered,
+                &subject,
+                &String::from_str(&e, "kyc"),
+                &0,
+            )
+            .err(),
+        Some(Ok(BondError::UnauthorizedAttester))
+    );
+}
+
+#[test]
+fn test_set_delegation_contract_overrides_address_book() {
+    let e = Env::default();
+    let s = setup(&e);
+    assert_eq!(
+        s.client.get_delegation_contract(),
+        Some(s.delegation.clone())
+    );
+
+    let other = e.register(MockDelegation, ());
+    let outsider = Address::generate(&e);
+    assert_eq!(
+        s.client
+            .try_set_delegation_contract(&outsider, &other)
+            .err(),
+        Some(Ok(BondError::NotAdmin))
+    );
+    s.client.set_delegation_contract(&s.admin, &other);
+    let (_, topics, _) = e.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "delegation_contract_set"),).into_val(&e)
+    );
+    assert_eq!(s.client.get_delegation_contract(), Some(other.clone()));
+
+    // The grant lives in the old contract only.
+    MockDelegationClient::new(&e, &s.delegation).grant(&s.attester, &s.delegate);
+    let subject = Address::generate(&e);
+    let data = String::from_str(&e, "kyc");
+    assert_eq!(
+        s.client
+            .try_add_attestation_delegated(&s.delegate, &s.attester, &subject, &data, &0)
+            .err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    MockDelegationClient::new(&e, &other).grant(&s.attester, &s.delegate);
+    s.client
+        .add_attestation_delegated(&s.delegate, &s.attester, &subject, &data, &0);
+}
@@ -0,0 +1,118 @@
+//! Tests for dispute linkage: open dispute tracking and last outcome surfacing.
+
+use crate::dispute_linkage::LinkedDisputeOutcome;
+use crate::{features, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_status_defaults_to_no_disputes() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let status = client.get_dispute_status(&identity);
+    assert_eq!(status.open_disputes, 0);
+    assert_eq!(status.last_dispute_outcome, LinkedDisputeOutcome::None);
+    assert_eq!(status.last_dispute_id, 0);
+}
+
+#[test]
+fn test_link_and_resolve_disputes() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.link_dispute(&admin, &identity, &7_u64);
+    client.link_dispute(&admin, &identity, &9_u64);
+    let status = client.get_dispute_status(&identity);
+    assert_eq!(status.open_disputes, 2);
+    assert_eq!(status.open_dispute_ids, vec![&e, 7_u64, 9_u64]);
+
+    client.resolve_linked_dispute(
+        &admin,
+        &identity,
+        &7_u64,
+        &LinkedDisputeOutcome::FavorSlasher,
+    );
+    let status = client.get_dispute_status(&identity);
+    assert_eq!(status.open_disputes, 1);
+    assert_eq!(status.open_dispute_ids, vec![&e, 9_u64]);
+    assert_eq!(
+        status.last_dispute_outcome,
+        LinkedDisputeOutcome::FavorSlasher
+    );
+    assert_eq!(status.last_dispute_id, 7);
+
+    client.resolve_linked_dispute(
+        &admin,
+        &identity,
+        &9_u64,
+        &LinkedDisputeOutcome::FavorDisputer,
+    );
+    let status = client.get_dispute_status(&identity);
+    assert_eq!(status.open_disputes, 0);
+    assert_eq!(
+        status.last_dispute_outcome,
+        LinkedDisputeOutcome::FavorDisputer
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn test_link_twice_rejected() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.link_dispute(&admin, &identity, &1_u64);
+    client.link_dispute(&admin, &identity, &1_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn test_resolve_unknown_dispute_rejected() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.resolve_linked_dispute(
+        &admin,
+        &identity,
+        &1_u64,
+        &LinkedDisputeOutcome::FavorSlasher,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_resolve_requires_outcome() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.link_dispute(&admin, &identity, &1_u64);
+    client.resolve_linked_dispute(&admin, &identity, &1_u64, &LinkedDisputeOutcome::None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_link_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let other = Address::generate(&e);
+    client.link_dispute(&other, &identity, &1_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #116)")]
+fn test_link_requires_disputes_feature() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_features(
+        &admin,
+        &(features::ALL_FEATURES & !features::FEATURE_DISPUTES),
+    );
+    client.link_dispute(&admin, &identity, &1_u64);
+}
@@ -1,17 +1,20 @@
 //! Tests for Early Exit Penalty Mechanism.
 //! Covers: penalty calculation from remaining lock time, configurable rates,
-//! penalty event emission, and security (zero/max penalty edge cases).
+//! penalty event emission, routing through a treasury contract, bracketed schedules, and
+//! security (zero/max penalty edge cases).
 
 use crate::early_exit_penalty;
-use crate::{CredenceBond, CredenceBondClient};
+use crate::slash_settlement::TreasuryFundSource;
+use crate::{BondError, CredenceBond, CredenceBondClient};
 use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{contract, contractimpl, token, vec, Address, Env};
 
 fn setup<'a>(
     e: &'a Env,
     treasury: &Address,
     penalty_bps: u32,
 ) -> (CredenceBondClient<'a>, Address) {
+    e.mock_all_auths();
     let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
@@ -78,7 +81,7 @@ fn test_early_exit_emits_penalty_event() {
 }
 
 #[test]
-#[should_panic(expected = "use withdraw for post lock-up")]
+#[should_panic(expected = "Error(Contract, #108)")]
 fn test_early_exit_rejected_after_lock_up() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
@@ -91,9 +94,10 @@ fn test_early_exit_rejected_after_lock_up() {
 }
 
 #[test]
-#[should_panic(expected = "early exit config not set")]
+#[should_panic(expected = "Error(Contract, #115)")]
 fn test_early_exit_fails_without_config() {
     let e = Env::default();
+    e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
@@ -105,7 +109,7 @@ fn test_early_exit_fails_without_config() {
 }
 
 #[test]
-#[should_panic(expected = "not admin")]
+#[should_panic(expected = "Error(Contract, #102)")]
 fn test_set_early_exit_config_unauthorized() {
     let e = Env::default();
     let contract_id = e.register(CredenceBond, ());
@@ -118,9 +122,10 @@ fn test_set_early_exit_config_unauthorized() {
 }
 
 #[test]
-#[should_panic(expected = "penalty_bps must be <= 10000")]
+#[should_panic(expected = "Error(Contract, #114)")]
 fn test_set_early_exit_config_invalid_bps() {
     let e = Env::default();
+    e.mock_all_auths();
     let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
@@ -139,3 +144,145 @@ fn test_calculate_penalty_unit() {
     let p = early_exit_penalty::calculate_penalty(1000, 50, 100, 10000);
     assert_eq!(p, 500);
 }
+
+/// Treasury stand-in recording the last `receive_fee` call.
+#[contract]
+pub struct MockTreasury;
+
+#[contractimpl]
+impl MockTreasury {
+    pub fn receive_fee(e: Env, from: Address, amount: i128, source: TreasuryFundSource) {
+        from.require_auth();
+        e.storage().instance().set(&0_u32, &(from, amount, source));
+    }
+
+    pub fn last(e: Env) -> (Address, i128, TreasuryFundSource) {
+        e.storage().instance().get(&0_u32).unwrap()
+    }
+}
+
+#[test]
+fn test_penalty_routed_to_treasury_contract() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = e.register(MockTreasury, ());
+    let (client, admin) = setup(&e, &treasury, 1000);
+    let identity = Address::generate(&e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    token::StellarAssetClient::new(&e, &sac.address()).mint(&identity, &1_000_000);
+    client.set_token(&admin, &sac.address());
+    client.create_bond_funded(&identity, &1_000_000_i128, &100_u64, &false, &0_u64);
+    client.set_early_exit_treasury_routing(&admin, &true);
+    assert!(client.get_early_exit_treasury_routing());
+
+    e.ledger().with_mut(|li| li.timestamp = 1050);
+    client.withdraw_early(&500_000);
+    // 10% of 500_000, half the lock-up remaining.
+    assert_eq!(
+        token::Client::new(&e, &sac.address()).balance(&treasury),
+        25_000
+    );
+    assert_eq!(
+        MockTreasuryClient::new(&e, &treasury).last(),
+        (
+            client.address.clone(),
+            25_000,
+            TreasuryFundSource::ProtocolFee
+        )
+    );
+    assert_eq!(client.get_reserved_balance(), 0);
+}
+
+#[test]
+fn test_routing_requires_token() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1000);
+    assert_eq!(
+        client.try_set_early_exit_treasury_routing(&admin, &true),
+        Err(Ok(BondError::TokenNotSet))
+    );
+    client.set_early_exit_treasury_routing(&admin, &false);
+    assert!(!client.get_early_exit_treasury_routing());
+}
+
+#[test]
+fn test_schedule_picks_bracket_by_remaining_share() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1000);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &100_000_i128, &100_u64, &false, &0_u64);
+    let schedule = vec![&e, (0_u32, 100_u32), (2500, 400), (5000, 700), (7500, 1000)];
+    client.set_early_exit_schedule(&admin, &schedule);
+    assert_eq!(client.get_early_exit_schedule(), schedule);
+
+    // 80% remaining -> 10% bracket.
+    e.ledger().with_mut(|li| li.timestamp = 1020);
+    client.withdraw_early(&10_000);
+    assert_eq!(client.get_reserved_balance(), 1_000);
+    // Exactly 50% remaining -> 7% bracket.
+    e.ledger().with_mut(|li| li.timestamp = 1050);
+    client.withdraw_early(&10_000);
+    assert_eq!(client.get_reserved_balance(), 1_700);
+    // 10% remaining -> 1% bracket.
+    e.ledger().with_mut(|li| li.timestamp = 1090);
+    client.withdraw_early(&10_000);
+    assert_eq!(client.get_reserved_balance(), 1_800);
+}
+
+#[test]
+fn test_schedule_below_first_bracket_uses_linear_formula() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1000);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &100_000_i128, &100_u64, &false, &0_u64);
+    client.set_early_exit_schedule(&admin, &vec![&e, (5000_u32, 2000_u32)]);
+
+    // 40% remaining, below the only bracket: 10% * 40% = 4%.
+    e.ledger().with_mut(|li| li.timestamp = 1060);
+    client.withdraw_early(&10_000);
+    assert_eq!(client.get_reserved_balance(), 400);
+
+    // Clearing the schedule restores the linear formula everywhere.
+    client.set_early_exit_schedule(&admin, &vec![&e]);
+    assert!(client.get_early_exit_schedule().is_empty());
+    e.ledger().with_mut(|li| li.timestamp = 1080);
+    client.withdraw_early(&10_000);
+    assert_eq!(client.get_reserved_balance(), 600);
+}
+
+#[test]
+fn test_set_schedule_rejects_invalid_brackets() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1000);
+    let not_increasing = vec![&e, (5000_u32, 500_u32), (5000, 700)];
+    assert_eq!(
+        client.try_set_early_exit_schedule(&admin, &not_increasing),
+        Err(Ok(BondError::InvalidConfig))
+    );
+    let over_max = vec![&e, (0_u32, 10_001_u32)];
+    assert_eq!(
+        client.try_set_early_exit_schedule(&admin, &over_max),
+        Err(Ok(BondError::InvalidConfig))
+    );
+    let mut too_long = vec![&e];
+    for i in 0..=early_exit_penalty::MAX_PENALTY_BRACKETS {
+        too_long.push_back((i * 100, 100_u32));
+    }
+    assert_eq!(
+        client.try_set_early_exit_schedule(&admin, &too_long),
+        Err(Ok(BondError::InvalidConfig))
+    );
+    let other = Address::generate(&e);
+    assert_eq!(
+        client.try_set_early_exit_schedule(&other, &vec![&e, (0_u32, 100_u32)]),
+        Err(Ok(BondError::NotAdmin))
+    );
+    assert!(client.get_early_exit_schedule().is_empty());
+}
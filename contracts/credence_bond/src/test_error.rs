@@ -0,0 +1,140 @@
+//! Tests for the typed contract errors: codes stay inside the bond ranges of the shared
+//! namespace, and entrypoints surface them as `Err` to `try_` callers.
+
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use credence_common::errors;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_codes_within_bond_ranges() {
+    let lifecycle = [
+        BondError::NotInitialized,
+        BondError::NotAdmin,
+        BondError::Unauthorized,
+        BondError::NoBond,
+        BondError::TokenNotSet,
+        BondError::InvalidAmount,
+        BondError::InsufficientBalance,
+        BondError::LockupElapsed,
+        BondError::WithdrawalNotRequested,
+        BondError::WithdrawalAlreadyRequested,
+        BondError::NoticeNotElapsed,
+        BondError::NotRollingBond,
+        BondError::Overflow,
+        BondError::InvalidConfig,
+        BondError::ConfigNotSet,
+        BondError::FeatureDisabled,
+        BondError::ReserveBreach,
+        BondError::TokenNotRecoverable,
+        BondError::Reentrancy,
+        BondError::RateLimited,
+        BondError::SchemaVersionMismatch,
+        BondError::NothingToClaim,
+        BondError::UnbondingNotFound,
+        BondError::InvalidInput,
+    ];
+    let attestation = [
+        BondError::UnauthorizedAttester,
+        BondError::AttesterSuspended,
+        BondError::AttesterDeregistering,
+        BondError::DuplicateAttestation,
+        BondError::AttestationNotFound,
+        BondError::AttestationRevoked,
+        BondError::InvalidAttestationState,
+        BondError::InvalidNonce,
+        BondError::TemplateNotFound,
+        BondError::TemplateInactive,
+        BondError::NotAttestationOwner,
+        BondError::SubAttesterNotFound,
+    ];
+    let governance = [
+        BondError::GovernanceNotInitialized,
+        BondError::NotGovernor,
+        BondError::ProposalNotFound,
+        BondError::ProposalNotOpen,
+        BondError::ProposalNotApproved,
+        BondError::AlreadyVoted,
+        BondError::ProposerTooNew,
+        BondError::SlashAlreadyApplied,
+        BondError::SlashNotFound,
+        BondError::ClaimNotFound,
+        BondError::ClaimSettled,
+        BondError::ClaimExpired,
+        BondError::InvalidDisputeState,
+    ];
+    for code in lifecycle {
+        assert!(errors::BOND.contains(&(code as u32)));
+    }
+    for code in attestation {
+        assert!(errors::ATTESTATION.contains(&(code as u32)));
+    }
+    for code in governance {
+        assert!(errors::GOVERNANCE.contains(&(code as u32)));
+    }
+}
+
+#[test]
+fn test_not_initialized() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(&e, &e.register(CredenceBond, ()));
+    assert_eq!(
+        client.try_set_fee_config(&Address::generate(&e), &Address::generate(&e), &100),
+        Err(Ok(BondError::NotInitialized))
+    );
+}
+
+#[test]
+fn test_not_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(
+        client.try_set_features(&Address::generate(&e), &0),
+        Err(Ok(BondError::NotAdmin))
+    );
+}
+
+#[test]
+fn test_withdraw_errors() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.try_withdraw(&100).err(), Some(Ok(BondError::NoBond)));
+
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(
+        client.try_withdraw(&1_001).err(),
+        Some(Ok(BondError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_request_withdrawal().err(),
+        Some(Ok(BondError::NotRollingBond))
+    );
+}
+
+#[test]
+fn test_module_errors_surface_as_contract_errors() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    assert_eq!(
+        client.try_add_attestation(
+            &attester,
+            &Address::generate(&e),
+            &soroban_sdk::String::from_str(&e, "kyc"),
+            &5
+        ),
+        Err(Ok(BondError::InvalidNonce))
+    );
+}
@@ -0,0 +1,102 @@
+//! Tests for feature flags: defaults, admin updates and enforcement per subsystem.
+
+use crate::features::{
+    ALL_FEATURES, FEATURE_DISPUTES, FEATURE_FEES, FEATURE_GOVERNANCE, FEATURE_ROLLING_BONDS,
+    FEATURE_WEIGHTED_ATTESTATION,
+};
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String, Vec};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_all_features_enabled_by_default() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.get_features(), ALL_FEATURES);
+}
+
+#[test]
+fn test_set_features() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_features(&admin, &(FEATURE_FEES | FEATURE_GOVERNANCE));
+    assert_eq!(client.get_features(), FEATURE_FEES | FEATURE_GOVERNANCE);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_unknown_feature_rejected() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_features(&admin, &(1 << 31));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_features_unauthorized() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.set_features(&Address::generate(&e), &0_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #116)")]
+fn test_rolling_bond_disabled() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_features(&admin, &(ALL_FEATURES & !FEATURE_ROLLING_BONDS));
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
+}
+
+#[test]
+fn test_fees_disabled_skips_fee() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_fee_config(&admin, &Address::generate(&e), &100_u32);
+    client.set_features(&admin, &(ALL_FEATURES & !FEATURE_FEES));
+    let identity = Address::generate(&e);
+    let bond = client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #116)")]
+fn test_governance_disabled() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_features(&admin, &(ALL_FEATURES & !FEATURE_GOVERNANCE));
+    let g = Address::generate(&e);
+    client.initialize_governance(&admin, &Vec::from_array(&e, [g]), &5100_u32, &1_u32, &0_u64);
+}
+
+#[test]
+fn test_weighted_attestation_disabled_uses_default_weight() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    client.set_attester_stake(&admin, &attester, &1_000_000_i128);
+    client.set_features(&admin, &(ALL_FEATURES & !FEATURE_WEIGHTED_ATTESTATION));
+    let subject = Address::generate(&e);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "x"), &0_u64);
+    assert_eq!(att.weight, 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #116)")]
+fn test_disputes_disabled() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_features(&admin, &(ALL_FEATURES & !FEATURE_DISPUTES));
+    client.set_dispute_exposure(&admin, &Address::generate(&e), &10_i128);
+}
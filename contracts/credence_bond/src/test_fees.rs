@@ -1,15 +1,15 @@
 //! Comprehensive tests for bond creation fee mechanism (#15).
-//! Covers fee calculation, treasury config, fee waiver, events, and edge cases.
+//! Covers fee calculation, treasury config, fee waiver, exemptions, events, and edge cases.
 
 #![cfg(test)]
 
-use crate::{CredenceBond, CredenceBondClient};
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env};
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
     client.initialize(&admin);
@@ -19,7 +19,7 @@ fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
 #[test]
 fn test_fee_zero_when_not_configured() {
     let e = Env::default();
-    let (client, admin, identity) = setup(&e);
+    let (client, _admin, identity) = setup(&e);
     let (treasury, fee_bps) = client.get_fee_config();
     assert!(treasury.is_none());
     assert_eq!(fee_bps, 0);
@@ -30,7 +30,7 @@ fn test_fee_zero_when_not_configured() {
 #[test]
 fn test_set_fee_config() {
     let e = Env::default();
-    let (client, admin, identity) = setup(&e);
+    let (client, admin, _identity) = setup(&e);
     let treasury = Address::generate(&e);
     client.set_fee_config(&admin, &treasury, &100_u32);
     let (t, bps) = client.get_fee_config();
@@ -79,19 +79,19 @@ fn test_fee_max_bps_capped() {
 }
 
 #[test]
-#[should_panic(expected = "fee_bps must be <= 10000")]
+#[should_panic(expected = "Error(Contract, #114)")]
 fn test_fee_over_max_rejected() {
     let e = Env::default();
-    let (client, admin, identity) = setup(&e);
+    let (client, admin, _identity) = setup(&e);
     let treasury = Address::generate(&e);
     client.set_fee_config(&admin, &treasury, &10_001_u32);
 }
 
 #[test]
-#[should_panic(expected = "not admin")]
+#[should_panic(expected = "Error(Contract, #102)")]
 fn test_set_fee_config_unauthorized() {
     let e = Env::default();
-    let (client, admin, identity) = setup(&e);
+    let (client, _admin, _identity) = setup(&e);
     let other = Address::generate(&e);
     let treasury = Address::generate(&e);
     client.set_fee_config(&other, &treasury, &100_u32);
@@ -115,7 +115,128 @@ fn test_fee_accumulates_in_pool() {
     let treasury = Address::generate(&e);
     client.set_fee_config(&admin, &treasury, &100_u32); // 1%
     client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64); // fee 10
+    e.ledger().with_mut(|li| li.timestamp += 86400);
+    client.withdraw_bond(&identity);
     client.create_bond(&identity, &2000_i128, &86400_u64, &false, &0_u64); // fee 20
     let collected = client.collect_fees(&admin);
     assert_eq!(collected, 10 + 20);
 }
+
+fn tiered(e: &Env) -> Vec<(i128, u32)> {
+    Vec::from_array(e, [(0_i128, 100_u32), (1_000, 50), (10_000, 25)])
+}
+
+#[test]
+fn test_fee_schedule_bands() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.set_fee_config(&admin, &Address::generate(&e), &0_u32);
+    client.set_fee_schedule(&admin, &tiered(&e));
+    assert_eq!(client.get_fee_schedule(), tiered(&e));
+    assert_eq!(client.preview_fee(&999_i128), (9, 990));
+    assert_eq!(client.preview_fee(&1_000_i128), (5, 995));
+    assert_eq!(client.preview_fee(&9_999_i128), (49, 9_950));
+    assert_eq!(client.preview_fee(&20_000_i128), (50, 19_950));
+}
+
+#[test]
+fn test_fee_schedule_applied_on_create() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_fee_config(&admin, &Address::generate(&e), &0_u32);
+    client.set_fee_schedule(&admin, &tiered(&e));
+    let bond = client.create_bond(&identity, &20_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 19_950);
+}
+
+#[test]
+fn test_below_first_band_uses_flat_fee() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.set_fee_config(&admin, &Address::generate(&e), &200_u32);
+    client.set_fee_schedule(&admin, &Vec::from_array(&e, [(1_000_i128, 50_u32)]));
+    assert_eq!(client.preview_fee(&500_i128), (10, 490));
+    assert_eq!(client.preview_fee(&2_000_i128), (10, 1_990));
+
+    client.set_fee_schedule(&admin, &Vec::new(&e));
+    assert_eq!(client.get_fee_schedule().len(), 0);
+    assert_eq!(client.preview_fee(&2_000_i128), (40, 1_960));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_fee_schedule_rejects_unordered_bands() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.set_fee_schedule(
+        &admin,
+        &Vec::from_array(&e, [(1_000_i128, 50_u32), (1_000, 25)]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_fee_schedule_rejects_excess_bps() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.set_fee_schedule(&admin, &Vec::from_array(&e, [(0_i128, 10_001_u32)]));
+}
+
+#[test]
+fn test_large_fee_schedule_change_is_logged() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.set_fee_schedule(&admin, &Vec::from_array(&e, [(0_i128, 50_u32)]));
+    assert_eq!(client.get_admin_action_count(), 0);
+    client.set_fee_schedule(&admin, &tiered(&e));
+    assert_eq!(client.get_admin_action_count(), 0);
+    client.set_fee_schedule(&admin, &Vec::from_array(&e, [(0_i128, 500_u32)]));
+    assert_eq!(client.get_admin_action_count(), 1);
+}
+
+#[test]
+fn test_fee_exempt_identity_pays_no_fee() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32);
+    assert!(!client.is_fee_exempt(&identity));
+
+    client.set_fee_exempt(&admin, &identity, &true);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "fee_exemption_set"), identity.clone()).into_val(&e)
+    );
+    assert!(client.is_fee_exempt(&identity));
+
+    let bond = client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 1000);
+    // Previews ignore exemptions.
+    assert_eq!(client.preview_fee(&1000), (10, 990));
+}
+
+#[test]
+fn test_fee_exemption_revoked() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32);
+    client.set_fee_exempt(&admin, &identity, &true);
+    client.set_fee_exempt(&admin, &identity, &false);
+    assert!(!client.is_fee_exempt(&identity));
+    let bond = client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 990);
+}
+
+#[test]
+fn test_set_fee_exempt_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let other = Address::generate(&e);
+    assert_eq!(
+        client.try_set_fee_exempt(&other, &identity, &true),
+        Err(Ok(BondError::NotAdmin))
+    );
+    assert!(!client.is_fee_exempt(&identity));
+}
@@ -0,0 +1,357 @@
+//! Tests for single-signature bond funding: inline transfer for SAC tokens, the
+//! `transfer_from` fallback for tokens that reject the inline transfer, and claiming
+//! direct transfers with `claim_deposit`.
+
+use crate::slash_records::SlashSource;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::{contract, contractimpl, token, Address, Env, IntoVal, String, Symbol};
+
+/// Token without inline-transfer support: `transfer` always fails, `transfer_from` records
+/// the pulled amount per recipient.
+#[contract]
+pub struct AllowanceOnlyToken;
+
+#[contractimpl]
+impl AllowanceOnlyToken {
+    pub fn decimals(_e: Env) -> u32 {
+        7
+    }
+
+    pub fn symbol(e: Env) -> String {
+        String::from_str(&e, "ALLOW")
+    }
+
+    pub fn transfer(_e: Env, _from: Address, _to: Address, _amount: i128) {
+        panic!("transfer not supported");
+    }
+
+    pub fn transfer_from(e: Env, _spender: Address, _from: Address, to: Address, amount: i128) {
+        let received: i128 = e.storage().instance().get(&to).unwrap_or(0);
+        e.storage().instance().set(&to, &(received + amount));
+    }
+
+    pub fn received(e: Env, to: Address) -> i128 {
+        e.storage().instance().get(&to).unwrap_or(0)
+    }
+}
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    e.mock_all_auths();
+    client.initialize(&admin);
+    (client, admin, Address::generate(e))
+}
+
+fn setup_sac(e: &Env) -> (CredenceBondClient<'_>, Address, token::Client<'_>) {
+    let (client, admin, identity) = setup(e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    token::StellarAssetClient::new(e, &sac.address()).mint(&identity, &5_000_000_000);
+    client.set_token(&admin, &sac.address());
+    (client, identity, token::Client::new(e, &sac.address()))
+}
+
+#[test]
+fn test_inline_transfer_funds_bond() {
+    let e = Env::default();
+    let (client, identity, token) = setup_sac(&e);
+    let bond = client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 2_000_000_000);
+    assert_eq!(token.balance(&identity), 3_000_000_000);
+    assert_eq!(token.balance(&client.address), 2_000_000_000);
+    assert_eq!(token.allowance(&identity, &client.address), 0);
+}
+
+#[test]
+fn test_single_signature_covers_transfer() {
+    let e = Env::default();
+    let (client, identity, token) = setup_sac(&e);
+    let amount = 1_000_000_000_i128;
+    let args = (identity.clone(), amount, 86400_u64, false, 0_u64).into_val(&e);
+    client
+        .mock_auths(&[MockAuth {
+            address: &identity,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "create_bond_funded",
+                args,
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &token.address,
+                    fn_name: "transfer",
+                    args: (identity.clone(), client.address.clone(), amount).into_val(&e),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .create_bond_funded(&identity, &amount, &86400_u64, &false, &0_u64);
+    let auths = e.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, identity);
+    assert_eq!(
+        auths[0].1.function,
+        soroban_sdk::testutils::AuthorizedFunction::Contract((
+            client.address.clone(),
+            Symbol::new(&e, "create_bond_funded"),
+            (identity.clone(), amount, 86400_u64, false, 0_u64).into_val(&e),
+        ))
+    );
+    assert_eq!(token.balance(&client.address), amount);
+}
+
+#[test]
+fn test_falls_back_to_transfer_from() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let token = e.register(AllowanceOnlyToken, ());
+    client.set_token(&admin, &token);
+    client.create_bond_funded(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
+    assert_eq!(
+        AllowanceOnlyTokenClient::new(&e, &token).received(&client.address),
+        1_000_000_000
+    );
+    assert_eq!(client.get_identity_state().bonded_amount, 1_000_000_000);
+}
+
+#[test]
+#[should_panic]
+fn test_insufficient_balance_rejected() {
+    let e = Env::default();
+    let (client, identity, _token) = setup_sac(&e);
+    client.create_bond_funded(&identity, &6_000_000_000, &86400_u64, &false, &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #105)")]
+fn test_requires_bond_token() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond_funded(&identity, &1_000, &86400_u64, &false, &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_rejects_non_positive_amount() {
+    let e = Env::default();
+    let (client, identity, _token) = setup_sac(&e);
+    client.create_bond_funded(&identity, &0, &86400_u64, &false, &0_u64);
+}
+
+#[test]
+fn test_claim_direct_transfer() {
+    let e = Env::default();
+    let (client, identity, token) = setup_sac(&e);
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    assert_eq!(client.get_unclaimed_deposits(), 0);
+
+    token.transfer(&identity, &client.address, &1_000_000_000);
+    assert_eq!(client.get_unclaimed_deposits(), 1_000_000_000);
+    let bond = client.claim_deposit(&identity, &600_000_000);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "deposit_claimed"), identity.clone()).into_val(&e)
+    );
+    assert_eq!(bond.bonded_amount, 2_600_000_000);
+    assert_eq!(client.get_unclaimed_deposits(), 400_000_000);
+}
+
+#[test]
+fn test_claim_exceeding_deposits_rejected() {
+    let e = Env::default();
+    let (client, identity, token) = setup_sac(&e);
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    token.transfer(&identity, &client.address, &100);
+    assert_eq!(
+        client.try_claim_deposit(&identity, &101).err(),
+        Some(Ok(BondError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_claim_deposit(&identity, &0).err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_slash_proceeds_not_claimable() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    token::StellarAssetClient::new(&e, &sac.address()).mint(&identity, &2_000_000_000);
+    client.set_token(&admin, &sac.address());
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    client.slash(&admin, &500_000_000);
+    assert_eq!(client.get_unclaimed_deposits(), 0);
+}
+
+#[test]
+fn test_only_bond_identity_can_claim() {
+    let e = Env::default();
+    let (client, identity, token) = setup_sac(&e);
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    token.transfer(&identity, &client.address, &1_000);
+    assert_eq!(
+        client
+            .try_claim_deposit(&Address::generate(&e), &1_000)
+            .err(),
+        Some(Ok(BondError::NoBond))
+    );
+}
+
+#[test]
+fn test_claim_requires_identity_auth() {
+    let e = Env::default();
+    let (client, identity, token) = setup_sac(&e);
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    token.transfer(&identity, &client.address, &1_000);
+    e.set_auths(&[]);
+    assert!(client.try_claim_deposit(&identity, &1_000).is_err());
+}
+
+fn setup_funded(e: &Env) -> (CredenceBondClient<'_>, Address, Address, token::Client<'_>) {
+    let (client, admin, identity) = setup(e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    token::StellarAssetClient::new(e, &sac.address()).mint(&identity, &2_000_000_000);
+    client.set_token(&admin, &sac.address());
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    (
+        client,
+        admin,
+        identity,
+        token::Client::new(e, &sac.address()),
+    )
+}
+
+#[test]
+fn test_withdraw_pays_identity() {
+    let e = Env::default();
+    let (client, _admin, identity, token) = setup_funded(&e);
+    client.withdraw(&500_000_000);
+    assert_eq!(token.balance(&identity), 500_000_000);
+    assert_eq!(token.balance(&client.address), 1_500_000_000);
+}
+
+#[test]
+fn test_withdraw_early_pays_amount_less_penalty() {
+    let e = Env::default();
+    let (client, admin, identity, token) = setup_funded(&e);
+    client.set_early_exit_config(&admin, &Address::generate(&e), &1_000_u32);
+    client.withdraw_early(&1_000_000_000);
+    // 10% penalty with the whole lock-up remaining stays reserved for the treasury.
+    assert_eq!(token.balance(&identity), 900_000_000);
+    assert_eq!(token.balance(&client.address), 1_100_000_000);
+}
+
+#[test]
+fn test_withdraw_bond_pays_unslashed_balance() {
+    let e = Env::default();
+    let (client, admin, identity, token) = setup_funded(&e);
+    client.slash(&admin, &200_000_000);
+    e.ledger().with_mut(|li| li.timestamp += 86400);
+    assert_eq!(client.withdraw_bond(&identity), 1_800_000_000);
+    assert_eq!(token.balance(&identity), 1_800_000_000);
+    assert_eq!(token.balance(&client.address), 200_000_000);
+}
+
+#[test]
+fn test_withdraw_bond_before_lockup_pays_less_penalty() {
+    let e = Env::default();
+    let (client, admin, identity, token) = setup_funded(&e);
+    client.set_early_exit_config(&admin, &Address::generate(&e), &1_000_u32);
+    assert_eq!(client.withdraw_bond(&identity), 1_800_000_000);
+    assert_eq!(token.balance(&identity), 1_800_000_000);
+    assert_eq!(client.get_reserved_balance(), 200_000_000);
+}
+
+#[test]
+fn test_withdraw_bond_rolling_requires_notice() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, identity, _token) = setup_sac(&e);
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &true, &100_u64);
+    assert_eq!(
+        client.try_withdraw_bond(&identity).err(),
+        Some(Ok(BondError::WithdrawalNotRequested))
+    );
+    client.request_withdrawal();
+    assert_eq!(
+        client.try_withdraw_bond(&identity).err(),
+        Some(Ok(BondError::NoticeNotElapsed))
+    );
+    e.ledger().with_mut(|li| li.timestamp += 100);
+    assert_eq!(client.withdraw_bond(&identity), 2_000_000_000);
+}
+
+#[test]
+fn test_withdrawals_require_identity_auth() {
+    let e = Env::default();
+    let (client, _admin, identity, token) = setup_funded(&e);
+    e.ledger().with_mut(|li| li.timestamp += 86400);
+    e.set_auths(&[]);
+    assert!(client.try_withdraw_bond(&identity).is_err());
+    assert!(client.try_withdraw(&1_000).is_err());
+    assert!(client.try_withdraw_early(&1_000).is_err());
+    assert_eq!(token.balance(&client.address), 2_000_000_000);
+}
+
+#[test]
+fn test_create_bond_pulls_tokens_when_token_set() {
+    let e = Env::default();
+    let (client, identity, token) = setup_sac(&e);
+    client.create_bond(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    assert_eq!(token.balance(&identity), 3_000_000_000);
+    assert_eq!(token.balance(&client.address), 2_000_000_000);
+}
+
+#[test]
+fn test_create_bond_cannot_replace_active_bond() {
+    let e = Env::default();
+    let (client, _admin, identity, token) = setup_funded(&e);
+    let attacker = Address::generate(&e);
+    assert_eq!(
+        client
+            .try_create_bond(&attacker, &1, &86400_u64, &false, &0_u64)
+            .err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(client.get_identity_state().identity, identity);
+    assert_eq!(token.balance(&client.address), 2_000_000_000);
+}
+
+#[test]
+fn test_claim_unbonded_pays_identity() {
+    let e = Env::default();
+    let (client, admin, identity, token) = setup_funded(&e);
+    client.set_unbond_delay(&admin, &100_u64);
+    client.request_unbond(&700_000_000);
+    e.ledger().with_mut(|li| li.timestamp += 100);
+    assert_eq!(client.claim_unbonded(), 700_000_000);
+    assert_eq!(token.balance(&identity), 700_000_000);
+}
+
+#[test]
+fn test_unbonding_queue_pays_identity() {
+    let e = Env::default();
+    let (client, admin, identity, token) = setup_funded(&e);
+    client.set_unbonding_config(&admin, &1000_u64, &1000_u32);
+    client.queue_unbonding(&100_000_000);
+    assert_eq!(client.process_unbonding_queue(&10_u32), 100_000_000);
+    assert_eq!(token.balance(&identity), 100_000_000);
+}
+
+#[test]
+fn test_slash_refund_claim_pays_identity() {
+    let e = Env::default();
+    let (client, admin, identity, token) = setup_funded(&e);
+    let source = SlashSource::Dispute(1);
+    client.slash_for_source(&admin, &400_000_000, &source);
+    e.ledger().with_mut(|li| li.timestamp += 86400);
+    client.withdraw_bond(&identity);
+    assert_eq!(token.balance(&identity), 1_600_000_000);
+
+    client.reverse_slash(&source, &150_000_000, &admin);
+    assert_eq!(client.claim_slash_refund(&identity), 150_000_000);
+    assert_eq!(token.balance(&identity), 1_750_000_000);
+    assert_eq!(token.balance(&client.address), 250_000_000);
+}
@@ -0,0 +1,230 @@
+//! Tests for governor-approved protocol actions: proposal, voting, execution of each
+//! parameter action, gating of direct admin setters, and deadlines.
+
+use crate::governance_actions::ProposalAction;
+use crate::governance_approval::ProposalStatus;
+use crate::slash_records::SlashSource;
+use crate::tiered_bond::TierThresholds;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+
+fn setup<'a>(
+    e: &'a Env,
+    governors: &[Address],
+    quorum_bps: u32,
+) -> (CredenceBondClient<'a>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    let mut gov_vec = Vec::new(e);
+    for g in governors {
+        gov_vec.push_back(g.clone());
+    }
+    client.initialize_governance(&admin, &gov_vec, &quorum_bps, &1_u32, &0_u64);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_fee_config_action_executes_after_approval() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let g2 = Address::generate(&e);
+    let (client, admin, _identity) = setup(&e, &[g1.clone(), g2.clone()], 10_000);
+    let treasury = Address::generate(&e);
+    let action = ProposalAction::SetFeeConfig(treasury.clone(), 250);
+    let id = client.propose_action(&g1, &action);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "action_proposed"), g1.clone()).into_val(&e)
+    );
+
+    client.vote_action(&g1, &id, &true);
+    assert_eq!(
+        client.try_execute_action(&g1, &id).err(),
+        Some(Ok(BondError::ProposalNotApproved))
+    );
+    client.vote_action(&g2, &id, &true);
+    let tally = client.get_action_tally(&id).unwrap();
+    assert_eq!((tally.approve_weight, tally.voted_weight), (2, 2));
+
+    let executed = client.execute_action(&admin, &id);
+    assert_eq!(executed.status, ProposalStatus::Executed);
+    assert_eq!(executed.action, action);
+    assert_eq!(client.get_fee_config(), (Some(treasury), 250));
+    assert_eq!(
+        client.try_execute_action(&admin, &id).err(),
+        Some(Ok(BondError::ProposalNotOpen))
+    );
+}
+
+#[test]
+fn test_parameter_actions_apply() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let (client, admin, identity) = setup(&e, core::slice::from_ref(&g1), 5100);
+    let treasury = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let thresholds = TierThresholds {
+        bronze: 0,
+        silver: 500,
+        gold: 2_000,
+        platinum: 5_000,
+    };
+    for action in [
+        ProposalAction::SetTierThresholds(thresholds.clone()),
+        ProposalAction::SetEarlyExitConfig(treasury.clone(), 300),
+        ProposalAction::Slash(identity.clone(), 100),
+        ProposalAction::TransferAdmin(new_admin.clone()),
+    ] {
+        let id = client.propose_action(&admin, &action);
+        client.vote_action(&g1, &id, &true);
+        client.execute_action(&admin, &id);
+    }
+    assert_eq!(client.get_tier_thresholds(), thresholds);
+    assert_eq!(client.get_identity_state().slashed_amount, 100);
+    assert_eq!(client.get_pending_admin(), Some(new_admin));
+}
+
+#[test]
+fn test_action_governance_gates_admin_setters() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let (client, admin, _identity) = setup(&e, core::slice::from_ref(&g1), 5100);
+    client.set_action_governance(&admin, &true);
+    assert!(client.is_action_governance_required());
+    let treasury = Address::generate(&e);
+    assert_eq!(
+        client.try_set_fee_config(&admin, &treasury, &100),
+        Err(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_early_exit_config(&admin, &treasury, &100),
+        Err(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_tier_thresholds(&admin, &0, &500, &2_000, &5_000),
+        Err(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_transfer_admin(&admin, &Address::generate(&e)),
+        Err(Ok(BondError::Unauthorized))
+    );
+
+    let id = client.propose_action(&admin, &ProposalAction::SetFeeConfig(treasury.clone(), 100));
+    client.vote_action(&g1, &id, &true);
+    client.execute_action(&admin, &id);
+    assert_eq!(client.get_fee_config(), (Some(treasury), 100));
+}
+
+#[test]
+fn test_action_authorization() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let g2 = Address::generate(&e);
+    let (client, admin, identity) = setup(&e, &[g1.clone(), g2.clone()], 5100);
+    let outsider = Address::generate(&e);
+    let action = ProposalAction::SetEarlyExitConfig(Address::generate(&e), 100);
+    assert_eq!(
+        client.try_propose_action(&outsider, &action),
+        Err(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_propose_action(&admin, &ProposalAction::Slash(outsider.clone(), 10)),
+        Err(Ok(BondError::NoBond))
+    );
+    assert_eq!(
+        client.try_propose_action(&admin, &ProposalAction::Slash(identity, 0)),
+        Err(Ok(BondError::InvalidAmount))
+    );
+
+    let id = client.propose_action(&g1, &action);
+    assert_eq!(
+        client.try_vote_action(&outsider, &id, &true),
+        Err(Ok(BondError::NotGovernor))
+    );
+    client.vote_action(&g1, &id, &true);
+    assert_eq!(
+        client.try_vote_action(&g1, &id, &true),
+        Err(Ok(BondError::AlreadyVoted))
+    );
+    client.vote_action(&g2, &id, &true);
+    assert_eq!(
+        client.try_execute_action(&g2, &id).err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_execute_action(&g1, &99_u64).err(),
+        Some(Ok(BondError::ProposalNotFound))
+    );
+    client.execute_action(&g1, &id);
+}
+
+#[test]
+fn test_action_expires_after_voting_period() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let g1 = Address::generate(&e);
+    let (client, admin, _identity) = setup(&e, core::slice::from_ref(&g1), 5100);
+    client.set_governance_voting_period(&admin, &100_u64);
+    let action = ProposalAction::SetFeeConfig(Address::generate(&e), 100);
+    let id = client.propose_action(&admin, &action);
+    assert_eq!(
+        client.get_action_proposal(&id).unwrap().voting_deadline,
+        Some(1_100)
+    );
+    client.vote_action(&g1, &id, &true);
+    e.ledger().with_mut(|li| li.timestamp = 1_101);
+    assert_eq!(
+        client.try_execute_action(&admin, &id).err(),
+        Some(Ok(BondError::ProposalNotOpen))
+    );
+    assert_eq!(client.get_fee_config(), (None, 0));
+}
+
+#[test]
+fn test_slash_action_recorded_and_held_for_appeal() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let (client, admin, identity) = setup(&e, core::slice::from_ref(&g1), 5100);
+    client.set_slash_appeal_window(&admin, &3_600_u64);
+    let id = client.propose_action(&g1, &ProposalAction::Slash(identity.clone(), 100));
+    client.vote_action(&g1, &id, &true);
+    client.execute_action(&admin, &id);
+
+    let proposal = client.get_slash_proposal(&0_u64).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+    assert_eq!(proposal.proposed_by, g1);
+    let record = client.get_slash_record(&SlashSource::Proposal(0)).unwrap();
+    assert_eq!((record.identity, record.amount), (identity, 100));
+    assert_eq!(client.get_pending_slash(&0_u64).unwrap().amount, 100);
+    assert_eq!(
+        client
+            .try_slash_for_source(&admin, &100, &SlashSource::Proposal(0))
+            .err(),
+        Some(Ok(BondError::SlashAlreadyApplied))
+    );
+}
+
+#[test]
+fn test_disabling_action_governance_needs_approval() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let (client, admin, _identity) = setup(&e, core::slice::from_ref(&g1), 5100);
+    client.set_action_governance(&admin, &true);
+    assert_eq!(
+        client.try_set_action_governance(&admin, &false),
+        Err(Ok(BondError::Unauthorized))
+    );
+    assert!(client.is_action_governance_required());
+
+    let id = client.propose_action(&admin, &ProposalAction::DisableActionGovernance);
+    client.vote_action(&g1, &id, &true);
+    client.execute_action(&admin, &id);
+    assert!(!client.is_action_governance_required());
+}
@@ -1,15 +1,17 @@
 //! Comprehensive tests for governance approval for slashing (#7).
-//! Covers multi-sig verification, vote tracking, quorum, delegation, and events.
+//! Covers multi-sig verification, vote tracking, quorum, delegation, voting deadlines,
+//! cancellation, governor membership changes, weighted voting, and events.
 
 #![cfg(test)]
 
-use crate::{CredenceBond, CredenceBondClient};
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env, Vec};
+use crate::governance_approval::{ProposalStatus, VoteReceipt, VotingMode};
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
     client.initialize(&admin);
@@ -28,7 +30,7 @@ fn setup_with_bond_and_governance<'a>(
     for g in governors {
         gov_vec.push_back(g.clone());
     }
-    client.initialize_governance(&admin, &gov_vec, &quorum_bps, &min_governors);
+    client.initialize_governance(&admin, &gov_vec, &quorum_bps, &min_governors, &0_u64);
     (client, admin, identity)
 }
 
@@ -39,7 +41,7 @@ fn test_initialize_governance() {
     let g1 = Address::generate(&e);
     let g2 = Address::generate(&e);
     let governors = Vec::from_array(&e, [g1.clone(), g2.clone()]);
-    client.initialize_governance(&admin, &governors, &5100_u32, &1_u32);
+    client.initialize_governance(&admin, &governors, &5100_u32, &1_u32, &0_u64);
     let govs = client.get_governors();
     assert_eq!(govs.len(), 2);
     let (q, min) = client.get_quorum_config();
@@ -48,21 +50,22 @@ fn test_initialize_governance() {
 }
 
 #[test]
-#[should_panic(expected = "not admin")]
+#[should_panic(expected = "Error(Contract, #102)")]
 fn test_initialize_governance_unauthorized() {
     let e = Env::default();
-    let (client, admin, _) = setup(&e);
+    let (client, _admin, _) = setup(&e);
     let other = Address::generate(&e);
     let governors = Vec::from_array(&e, [other.clone()]);
-    client.initialize_governance(&other, &governors, &5100_u32, &1_u32);
+    client.initialize_governance(&other, &governors, &5100_u32, &1_u32, &0_u64);
 }
 
 #[test]
 fn test_propose_slash() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, identity) = setup_with_bond_and_governance(&e, &[g1.clone()], 5100, 1);
-    let id = client.propose_slash(&admin, &100_i128);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    let id = client.propose_slash(&admin, &identity, &100_i128);
     assert_eq!(id, 0);
     let prop = client.get_slash_proposal(&id);
     let prop = prop.unwrap();
@@ -78,20 +81,22 @@ fn test_propose_slash() {
 fn test_vote_approve_and_execute() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, identity) = setup_with_bond_and_governance(&e, &[g1.clone()], 5100, 1);
-    let _id = client.propose_slash(&admin, &100_i128);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    let _id = client.propose_slash(&admin, &identity, &100_i128);
     client.governance_vote(&g1, &0_u64, &true);
     let bond = client.execute_slash_with_governance(&admin, &0_u64);
     assert_eq!(bond.slashed_amount, 100);
 }
 
 #[test]
-#[should_panic(expected = "proposal not approved")]
+#[should_panic(expected = "Error(Contract, #305)")]
 fn test_vote_reject_then_execute_fails() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, _identity) = setup_with_bond_and_governance(&e, &[g1.clone()], 5100, 1);
-    let _id = client.propose_slash(&admin, &100_i128);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    let _id = client.propose_slash(&admin, &identity, &100_i128);
     client.governance_vote(&g1, &0_u64, &false);
     client.execute_slash_with_governance(&admin, &0_u64);
 }
@@ -102,9 +107,9 @@ fn test_quorum_two_of_three() {
     let g1 = Address::generate(&e);
     let g2 = Address::generate(&e);
     let g3 = Address::generate(&e);
-    let (client, admin, _) =
+    let (client, admin, identity) =
         setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone(), g3.clone()], 6600, 2);
-    let _id = client.propose_slash(&admin, &50_i128);
+    let _id = client.propose_slash(&admin, &identity, &50_i128);
     client.governance_vote(&g1, &0_u64, &true);
     client.governance_vote(&g2, &0_u64, &true);
     let bond = client.execute_slash_with_governance(&admin, &0_u64);
@@ -117,9 +122,10 @@ fn test_delegate_vote() {
     let g1 = Address::generate(&e);
     let g2 = Address::generate(&e);
     let delegate_to = Address::generate(&e);
-    let (client, admin, _) = setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone()], 5100, 1);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone()], 5100, 1);
     client.governance_delegate(&g1, &delegate_to);
-    let _id = client.propose_slash(&admin, &75_i128);
+    let _id = client.propose_slash(&admin, &identity, &75_i128);
     client.governance_vote(&delegate_to, &0_u64, &true);
     client.governance_vote(&g2, &0_u64, &true);
     let bond = client.execute_slash_with_governance(&admin, &0_u64);
@@ -130,44 +136,537 @@ fn test_delegate_vote() {
 fn test_get_governance_vote() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, _) = setup_with_bond_and_governance(&e, &[g1.clone()], 5100, 1);
-    client.propose_slash(&admin, &10_i128);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    client.propose_slash(&admin, &identity, &10_i128);
     assert!(client.get_governance_vote(&0_u64, &g1).is_none());
     client.governance_vote(&g1, &0_u64, &true);
     assert_eq!(client.get_governance_vote(&0_u64, &g1), Some(true));
 }
 
 #[test]
-#[should_panic(expected = "already voted")]
+#[should_panic(expected = "Error(Contract, #306)")]
 fn test_double_vote_rejected() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, _) = setup_with_bond_and_governance(&e, &[g1.clone()], 5100, 1);
-    client.propose_slash(&admin, &10_i128);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    client.propose_slash(&admin, &identity, &10_i128);
     client.governance_vote(&g1, &0_u64, &true);
     client.governance_vote(&g1, &0_u64, &false);
 }
 
 #[test]
-#[should_panic(expected = "not a governor or delegate")]
+#[should_panic(expected = "Error(Contract, #302)")]
 fn test_non_governor_cannot_vote() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, _) = setup_with_bond_and_governance(&e, &[g1.clone()], 5100, 1);
-    client.propose_slash(&admin, &10_i128);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    client.propose_slash(&admin, &identity, &10_i128);
     let other = Address::generate(&e);
     client.governance_vote(&other, &0_u64, &true);
 }
 
 #[test]
-#[should_panic(expected = "only proposer can execute")]
+#[should_panic(expected = "Error(Contract, #103)")]
 fn test_only_proposer_executes() {
     let e = Env::default();
     let g1 = Address::generate(&e);
     let g2 = Address::generate(&e);
-    let (client, admin, _) = setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone()], 5100, 1);
-    client.propose_slash(&admin, &50_i128);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone()], 5100, 1);
+    client.propose_slash(&admin, &identity, &50_i128);
     client.governance_vote(&g1, &0_u64, &true);
     client.governance_vote(&g2, &0_u64, &true);
     client.execute_slash_with_governance(&g1, &0_u64);
 }
+
+#[test]
+fn test_proposal_records_target_identity() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    let id = client.propose_slash(&admin, &identity, &100_i128);
+    assert_eq!(client.get_slash_proposal(&id).unwrap().identity, identity);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_propose_slash_unknown_identity_rejected() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let (client, admin, _identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    let stranger = Address::generate(&e);
+    client.propose_slash(&admin, &stranger, &100_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_propose_slash_without_bond_rejected() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let g1 = Address::generate(&e);
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(&e, [g1]),
+        &5100_u32,
+        &1_u32,
+        &0_u64,
+    );
+    client.propose_slash(&admin, &identity, &100_i128);
+}
+
+#[test]
+fn test_open_proposal_index_and_pagination() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    let a = client.propose_slash(&admin, &identity, &10_i128);
+    let b = client.propose_slash(&admin, &identity, &20_i128);
+    let c = client.propose_slash(&admin, &identity, &30_i128);
+    assert_eq!(client.get_open_proposal_count(), 3);
+
+    let page = client.list_open_proposals(&1_u32, &10_u32);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, b);
+    assert_eq!(page.get(1).unwrap().id, c);
+    assert_eq!(client.list_open_proposals(&5_u32, &10_u32).len(), 0);
+
+    client.governance_vote(&g1, &b, &true);
+    client.execute_slash_with_governance(&admin, &b);
+    let open = client.list_open_proposals(&0_u32, &10_u32);
+    assert_eq!(open.len(), 2);
+    assert_eq!(open.get(0).unwrap().id, a);
+    assert_eq!(open.get(1).unwrap().id, c);
+}
+
+#[test]
+fn test_proposal_summary_tracks_votes_and_quorum() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let g2 = Address::generate(&e);
+    let g3 = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone(), g3], 5100, 1);
+    let id = client.propose_slash(&admin, &identity, &100_i128);
+    let s = client.get_proposal_summary(&id).unwrap();
+    assert_eq!((s.approve_count, s.reject_count, s.voted_count), (0, 0, 0));
+    assert_eq!(s.total_governors, 3);
+    assert_eq!(s.quorum_required, 1);
+    assert!(!s.approved);
+    assert_eq!(s.time_remaining, None);
+
+    client.governance_vote(&g1, &id, &true);
+    client.governance_vote(&g2, &id, &false);
+    let s = client.get_proposal_summary(&id).unwrap();
+    assert_eq!((s.approve_count, s.reject_count, s.voted_count), (1, 1, 2));
+    assert!(!s.approved);
+    assert!(client.get_proposal_summary(&99_u64).is_none());
+}
+
+#[test]
+fn test_voting_deadline_rejects_late_votes() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let g1 = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    client.set_governance_voting_period(&admin, &100_u64);
+    assert_eq!(client.get_governance_voting_period(), 100);
+    let id = client.propose_slash(&admin, &identity, &100_i128);
+    assert_eq!(
+        client.get_slash_proposal(&id).unwrap().voting_deadline,
+        Some(1_100)
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1_040);
+    assert_eq!(
+        client.get_proposal_summary(&id).unwrap().time_remaining,
+        Some(60)
+    );
+    e.ledger().with_mut(|li| li.timestamp = 1_101);
+    assert_eq!(
+        client.try_governance_vote(&g1, &id, &true),
+        Err(Ok(BondError::ProposalNotOpen))
+    );
+}
+
+#[test]
+fn test_expired_proposal_cannot_execute() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let g1 = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    client.set_governance_voting_period(&admin, &100_u64);
+    let id = client.propose_slash(&admin, &identity, &100_i128);
+    client.governance_vote(&g1, &id, &true);
+
+    e.ledger().with_mut(|li| li.timestamp = 1_101);
+    assert_eq!(
+        client.try_execute_slash_with_governance(&admin, &id).err(),
+        Some(Ok(BondError::ProposalNotApproved))
+    );
+    assert_eq!(client.get_identity_state().slashed_amount, 0);
+
+    let proposal = client.expire_slash_proposal(&id);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "slash_proposal_expired"), identity.clone()).into_val(&e)
+    );
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+    assert_eq!(client.get_open_proposal_count(), 0);
+}
+
+#[test]
+fn test_expire_before_deadline_rejected() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let g1 = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    let no_deadline = client.propose_slash(&admin, &identity, &100_i128);
+    client.set_governance_voting_period(&admin, &100_u64);
+    let id = client.propose_slash(&admin, &identity, &100_i128);
+    assert_eq!(
+        client.try_expire_slash_proposal(&id).err(),
+        Some(Ok(BondError::NoticeNotElapsed))
+    );
+    e.ledger().with_mut(|li| li.timestamp = 10_000);
+    assert_eq!(
+        client.try_expire_slash_proposal(&no_deadline).err(),
+        Some(Ok(BondError::NoticeNotElapsed))
+    );
+    client.governance_vote(&g1, &no_deadline, &true);
+    client.execute_slash_with_governance(&admin, &no_deadline);
+}
+
+#[test]
+fn test_cancel_slash_proposal_by_proposer() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let (client, _admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
+    let id = client.propose_slash(&g1, &identity, &100_i128);
+
+    let proposal = client.cancel_slash_proposal(&g1, &id);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (
+            Symbol::new(&e, "slash_proposal_cancelled"),
+            identity.clone()
+        )
+            .into_val(&e)
+    );
+    assert_eq!(proposal.status, ProposalStatus::Cancelled);
+    assert_eq!(client.get_open_proposal_count(), 0);
+    assert_eq!(
+        client.try_governance_vote(&g1, &id, &true),
+        Err(Ok(BondError::ProposalNotOpen))
+    );
+    assert_eq!(
+        client.try_cancel_slash_proposal(&g1, &id).err(),
+        Some(Ok(BondError::ProposalNotOpen))
+    );
+}
+
+#[test]
+fn test_cancel_slash_proposal_authorization() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let g2 = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone()], 5100, 1);
+    let id = client.propose_slash(&g1, &identity, &100_i128);
+    assert_eq!(
+        client.try_cancel_slash_proposal(&g2, &id).err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_cancel_slash_proposal(&admin, &99_u64).err(),
+        Some(Ok(BondError::ProposalNotFound))
+    );
+    assert_eq!(
+        client.cancel_slash_proposal(&admin, &id).status,
+        ProposalStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_add_governor_after_initialization() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let g1 = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 10_000, 1);
+    let g2 = Address::generate(&e);
+    client.add_governor(&admin, &g2);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "governor_added"), g2.clone()).into_val(&e)
+    );
+    assert_eq!(client.get_governors().len(), 2);
+    assert_eq!(
+        client.try_add_governor(&admin, &g2),
+        Err(Ok(BondError::InvalidInput))
+    );
+
+    // Full quorum now needs both governors.
+    let id = client.propose_slash(&g2, &identity, &100_i128);
+    client.governance_vote(&g1, &id, &true);
+    assert!(!client.get_proposal_summary(&id).unwrap().approved);
+    client.governance_vote(&g2, &id, &true);
+    assert!(client.get_proposal_summary(&id).unwrap().approved);
+}
+
+#[test]
+fn test_remove_governor_recomputes_quorum_and_clears_delegations() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let g2 = Address::generate(&e);
+    let g3 = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone(), g3.clone()], 10_000, 1);
+    client.governance_delegate(&g1, &g3);
+    client.governance_delegate(&g3, &g2);
+    let id = client.propose_slash(&admin, &identity, &100_i128);
+    client.governance_vote(&g2, &id, &true);
+
+    client.remove_governor(&admin, &g3);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "governor_removed"), g3.clone()).into_val(&e)
+    );
+    assert_eq!(
+        client.get_governors(),
+        Vec::from_array(&e, [g1.clone(), g2])
+    );
+    assert_eq!(client.get_governance_delegate(&g1), None);
+    assert_eq!(client.get_governance_delegate(&g3), None);
+    assert_eq!(
+        client.try_governance_vote(&g3, &id, &true),
+        Err(Ok(BondError::NotGovernor))
+    );
+
+    let s = client.get_proposal_summary(&id).unwrap();
+    assert_eq!(
+        (s.total_governors, s.quorum_required, s.voted_count),
+        (2, 2, 1)
+    );
+    client.governance_vote(&g1, &id, &true);
+    assert!(client.get_proposal_summary(&id).unwrap().approved);
+}
+
+#[test]
+fn test_remove_governor_validation() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let g2 = Address::generate(&e);
+    let (client, admin, _identity) =
+        setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone()], 5100, 2);
+    assert_eq!(
+        client.try_remove_governor(&admin, &g1),
+        Err(Ok(BondError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_remove_governor(&admin, &Address::generate(&e)),
+        Err(Ok(BondError::NotGovernor))
+    );
+    assert_eq!(
+        client.try_remove_governor(&g1, &g2),
+        Err(Ok(BondError::NotAdmin))
+    );
+}
+
+#[test]
+fn test_add_governor_requires_governance() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    assert_eq!(
+        client.try_add_governor(&admin, &Address::generate(&e)),
+        Err(Ok(BondError::GovernanceNotInitialized))
+    );
+}
+
+#[test]
+fn test_governor_changes_require_admin_auth() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let (client, admin, _identity) =
+        setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 10_000, 1);
+    e.set_auths(&[]);
+    assert!(client
+        .try_add_governor(&admin, &Address::generate(&e))
+        .is_err());
+    assert!(client.try_remove_governor(&admin, &g1).is_err());
+    assert_eq!(client.get_governors().len(), 1);
+}
+
+#[test]
+fn test_weighted_voting_uses_admin_weights() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let g2 = Address::generate(&e);
+    let g3 = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone(), g3.clone()], 5100, 1);
+    client.set_governance_voting_mode(&admin, &VotingMode::Weighted);
+    assert_eq!(client.get_governance_voting_mode(), VotingMode::Weighted);
+    client.set_governor_weight(&admin, &g1, &700);
+    client.set_governor_weight(&admin, &g2, &200);
+    client.set_governor_weight(&admin, &g3, &100);
+    let id = client.propose_slash(&admin, &identity, &100_i128);
+
+    // Two of three governors reject, but g1 alone carries quorum and majority.
+    client.governance_vote(&g1, &id, &true);
+    client.governance_vote(&g2, &id, &false);
+    client.governance_vote(&g3, &id, &false);
+    let t = client.get_proposal_tally(&id).unwrap();
+    assert_eq!(t.mode, VotingMode::Weighted);
+    assert_eq!((t.approve_weight, t.reject_weight), (700, 300));
+    assert_eq!(
+        (t.voted_weight, t.total_weight, t.quorum_weight),
+        (1000, 1000, 510)
+    );
+    assert!(t.approved);
+    assert!(client.get_proposal_summary(&id).unwrap().approved);
+
+    client.set_governance_voting_mode(&admin, &VotingMode::Equal);
+    let t = client.get_proposal_tally(&id).unwrap();
+    assert_eq!(
+        (t.approve_weight, t.reject_weight, t.total_weight),
+        (1, 2, 3)
+    );
+    assert!(!t.approved);
+}
+
+#[test]
+fn test_weighted_quorum_needs_share_of_weight() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let g2 = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone()], 5100, 1);
+    client.set_governance_voting_mode(&admin, &VotingMode::Weighted);
+    client.set_governor_weight(&admin, &g1, &100);
+    client.set_governor_weight(&admin, &g2, &900);
+    let id = client.propose_slash(&admin, &identity, &100_i128);
+    client.governance_vote(&g1, &id, &true);
+    assert!(!client.get_proposal_tally(&id).unwrap().approved);
+    assert_eq!(
+        client.try_execute_slash_with_governance(&admin, &id).err(),
+        Some(Ok(BondError::ProposalNotApproved))
+    );
+}
+
+#[test]
+fn test_governor_weight_defaults_to_bond() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    let g2 = Address::generate(&e);
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(&e, [identity.clone(), g2.clone()]),
+        &5100_u32,
+        &1_u32,
+        &0_u64,
+    );
+    assert_eq!(client.get_governor_weight(&identity), 1000);
+    assert_eq!(client.get_governor_weight(&g2), 0);
+    client.set_governor_weight(&admin, &identity, &5);
+    assert_eq!(client.get_governor_weight(&identity), 5);
+    client.set_governor_weight(&admin, &identity, &0);
+    assert_eq!(client.get_governor_weight(&identity), 1000);
+
+    assert_eq!(
+        client.try_set_governor_weight(&admin, &g2, &-1),
+        Err(Ok(BondError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_governor_weight(&admin, &Address::generate(&e), &1),
+        Err(Ok(BondError::NotGovernor))
+    );
+    assert!(client.get_proposal_tally(&99_u64).is_none());
+}
+
+#[test]
+fn test_slashed_identity_excluded_from_tally() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    let g2 = Address::generate(&e);
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(&e, [identity.clone(), g2.clone()]),
+        &5100_u32,
+        &1_u32,
+        &0_u64,
+    );
+    client.set_governance_voting_mode(&admin, &VotingMode::Weighted);
+    client.set_governor_weight(&admin, &g2, &10);
+    let id = client.propose_slash(&admin, &identity, &100_i128);
+
+    // The identity's bond weight would outvote g2 on its own slash.
+    client.governance_vote(&identity, &id, &false);
+    client.governance_vote(&g2, &id, &true);
+    let t = client.get_proposal_tally(&id).unwrap();
+    assert_eq!(
+        (t.approve_weight, t.reject_weight, t.total_weight),
+        (10, 0, 10)
+    );
+    assert!(t.approved);
+    let summary = client.get_proposal_summary(&id).unwrap();
+    assert_eq!((summary.total_governors, summary.voted_count), (1, 1));
+    client.execute_slash_with_governance(&admin, &id);
+    assert_eq!(client.get_identity_state().slashed_amount, 100);
+}
+
+#[test]
+fn test_proposal_votes_lists_every_governor() {
+    let e = Env::default();
+    let g1 = Address::generate(&e);
+    let g2 = Address::generate(&e);
+    let g3 = Address::generate(&e);
+    let delegate = Address::generate(&e);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone(), g3.clone()], 10_000, 1);
+    client.governance_delegate(&g3, &delegate);
+    let id = client.propose_slash(&admin, &identity, &100_i128);
+    client.governance_vote(&g1, &id, &true);
+    client.governance_vote(&delegate, &id, &false);
+
+    let receipts = client.get_proposal_votes(&id).unwrap();
+    assert_eq!(
+        receipts,
+        Vec::from_array(
+            &e,
+            [
+                VoteReceipt {
+                    governor: g1.clone(),
+                    voter: g1,
+                    vote: Some(true),
+                },
+                VoteReceipt {
+                    governor: g2.clone(),
+                    voter: g2,
+                    vote: None,
+                },
+                VoteReceipt {
+                    governor: g3,
+                    voter: delegate,
+                    vote: Some(false),
+                },
+            ]
+        )
+    );
+    assert!(client.get_proposal_votes(&99_u64).is_none());
+}
@@ -0,0 +1,152 @@
+//! Tests for Bond Health Factor: slashed ratio, pending proposals, dispute exposure,
+//! threshold classification and threshold-crossing events.
+
+use crate::health_factor::{classify, compute_factor_bps, HealthStatus};
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Env, Vec};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_compute_factor_bps() {
+    assert_eq!(compute_factor_bps(1000, 0, 0, 0), 10_000);
+    assert_eq!(compute_factor_bps(1000, 250, 0, 0), 7_500);
+    assert_eq!(compute_factor_bps(1000, 250, 250, 100), 4_000);
+    assert_eq!(compute_factor_bps(1000, 1000, 0, 0), 0);
+    assert_eq!(compute_factor_bps(1000, 600, 600, 0), 0);
+    assert_eq!(compute_factor_bps(0, 0, 0, 0), 0);
+}
+
+#[test]
+fn test_classify() {
+    assert_eq!(classify(10_000, 5_000, 2_000), HealthStatus::Healthy);
+    assert_eq!(classify(5_000, 5_000, 2_000), HealthStatus::Healthy);
+    assert_eq!(classify(4_999, 5_000, 2_000), HealthStatus::AtRisk);
+    assert_eq!(classify(2_000, 5_000, 2_000), HealthStatus::AtRisk);
+    assert_eq!(classify(1_999, 5_000, 2_000), HealthStatus::Critical);
+}
+
+#[test]
+fn test_health_factor_fresh_bond() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let health = client.get_health_factor(&identity);
+    assert_eq!(health.factor_bps, 10_000);
+    assert_eq!(health.status, HealthStatus::Healthy);
+    assert_eq!(health.slashed_amount, 0);
+    assert_eq!(health.pending_slash_amount, 0);
+    assert_eq!(health.dispute_exposure, 0);
+}
+
+#[test]
+fn test_health_factor_after_slash() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.slash(&admin, &600_i128);
+    let health = client.get_health_factor(&identity);
+    assert_eq!(health.factor_bps, 4_000);
+    assert_eq!(health.status, HealthStatus::AtRisk);
+
+    client.slash(&admin, &250_i128);
+    let health = client.get_health_factor(&identity);
+    assert_eq!(health.factor_bps, 1_500);
+    assert_eq!(health.status, HealthStatus::Critical);
+}
+
+#[test]
+fn test_health_factor_counts_open_proposals() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let g1 = Address::generate(&e);
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(&e, [g1]),
+        &5100_u32,
+        &1_u32,
+        &0_u64,
+    );
+    client.propose_slash(&admin, &identity, &300_i128);
+    let health = client.get_health_factor(&identity);
+    assert_eq!(health.pending_slash_amount, 300);
+    assert_eq!(health.factor_bps, 7_000);
+}
+
+#[test]
+fn test_health_factor_counts_dispute_exposure() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_dispute_exposure(&admin, &identity, &850_i128);
+    let health = client.get_health_factor(&identity);
+    assert_eq!(health.dispute_exposure, 850);
+    assert_eq!(health.status, HealthStatus::Critical);
+}
+
+#[test]
+fn test_custom_thresholds() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_health_thresholds(&admin, &9_000_u32, &7_000_u32);
+    assert_eq!(client.get_health_thresholds(), (9_000, 7_000));
+    client.slash(&admin, &200_i128);
+    assert_eq!(
+        client.get_health_factor(&identity).status,
+        HealthStatus::AtRisk
+    );
+}
+
+#[test]
+fn test_status_change_emits_event() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.slash(&admin, &900_i128);
+    // bond_slashed + health_status_changed
+    assert_eq!(e.events().all().len(), 2);
+    client.slash(&admin, &10_i128);
+    // still critical: only bond_slashed
+    assert_eq!(e.events().all().len(), 1);
+}
+
+#[test]
+fn test_check_health_returns_current() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.slash(&admin, &500_i128);
+    let health = client.check_health();
+    assert_eq!(health.factor_bps, 5_000);
+    assert_eq!(health.status, HealthStatus::Healthy);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_invalid_thresholds_rejected() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.set_health_thresholds(&admin, &2_000_u32, &5_000_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_dispute_exposure_unauthorized() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let other = Address::generate(&e);
+    client.set_dispute_exposure(&other, &identity, &100_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_health_factor_unknown_identity() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    client.get_health_factor(&Address::generate(&e));
+}
@@ -0,0 +1,91 @@
+//! Tests for the invariant checker: a consistent contract reports nothing, and corrupted
+//! state is reported under the matching invariant code.
+
+use crate::invariants::{
+    INV_ATTESTATION_COUNTER, INV_NEGATIVE_FEE_POOL, INV_PROPOSAL_COUNTER,
+    INV_SLASHED_EXCEEDS_BONDED, INV_SUBJECT_COUNT_MISMATCH,
+};
+use crate::{testutils, CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &10_000_i128, &86400_u64, &false, &0_u64);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    client.add_attestation(&attester, &identity, &String::from_str(e, "kyc"), &0_u64);
+    client.add_attestation(&attester, &identity, &String::from_str(e, "aml"), &1_u64);
+    client.revoke_attestation(&attester, &0_u64, &RevocationReason::IssuedInError, &2_u64);
+    (client, identity, contract_id)
+}
+
+#[test]
+fn test_consistent_state_has_no_violations() {
+    let e = Env::default();
+    let (client, _identity, _contract) = setup(&e);
+    client.slash(&client.export_config().admin.unwrap(), &500_i128);
+    assert_eq!(client.check_invariants().len(), 0);
+}
+
+#[test]
+fn test_empty_contract_has_no_violations() {
+    let e = Env::default();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    assert_eq!(client.check_invariants().len(), 0);
+}
+
+#[test]
+fn test_slashed_exceeds_bonded_detected() {
+    let e = Env::default();
+    let (client, _identity, contract) = setup(&e);
+    let mut bond = testutils::get_bond(&e, &contract).unwrap();
+    bond.slashed_amount = bond.bonded_amount + 1;
+    testutils::set_bond(&e, &contract, &bond);
+    assert!(client
+        .check_invariants()
+        .contains(INV_SLASHED_EXCEEDS_BONDED));
+}
+
+#[test]
+fn test_negative_fee_pool_detected() {
+    let e = Env::default();
+    let (client, _identity, contract) = setup(&e);
+    testutils::set_fee_pool(&e, &contract, -1);
+    assert_eq!(client.check_invariants(), vec![&e, INV_NEGATIVE_FEE_POOL]);
+}
+
+#[test]
+fn test_subject_count_mismatch_detected() {
+    let e = Env::default();
+    let (client, identity, contract) = setup(&e);
+    testutils::set_subject_attestation_count(&e, &contract, &identity, 2);
+    assert_eq!(
+        client.check_invariants(),
+        vec![&e, INV_SUBJECT_COUNT_MISMATCH]
+    );
+}
+
+#[test]
+fn test_counters_behind_detected() {
+    let e = Env::default();
+    let (client, identity, contract) = setup(&e);
+    testutils::set_attestation_counter(&e, &contract, 1);
+    let proposer = Address::generate(&e);
+    testutils::add_open_proposal(&e, &contract, &proposer, &identity, 100);
+    e.as_contract(&contract, || {
+        e.storage()
+            .instance()
+            .set(&crate::DataKey::GovernanceNextProposalId, &0_u64);
+    });
+    assert_eq!(
+        client.check_invariants(),
+        vec![&e, INV_ATTESTATION_COUNTER, INV_PROPOSAL_COUNTER]
+    );
+}
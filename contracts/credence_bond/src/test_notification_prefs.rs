@@ -0,0 +1,93 @@
+//! Tests for per-identity notification preferences: set, update, clear, validation and
+//! events.
+
+use crate::notification_prefs::{TOPIC_DISPUTE_OPENED, TOPIC_RENEWAL_DUE, TOPIC_SLASH_PROPOSED};
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Bytes, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    (client, identity)
+}
+
+#[test]
+fn test_set_and_get_prefs() {
+    let e = Env::default();
+    let (client, identity) = setup(&e);
+    e.ledger().with_mut(|l| l.timestamp = 500);
+    let endpoint = Bytes::from_array(&e, &[7; 32]);
+    client.set_notification_prefs(
+        &identity,
+        &endpoint,
+        &(TOPIC_RENEWAL_DUE | TOPIC_DISPUTE_OPENED),
+    );
+    assert!(!e.events().all().is_empty());
+
+    let prefs = client.get_notification_prefs(&identity).unwrap();
+    assert_eq!(prefs.endpoint, endpoint);
+    assert_eq!(prefs.topics, TOPIC_RENEWAL_DUE | TOPIC_DISPUTE_OPENED);
+    assert_eq!(prefs.updated_at, 500);
+}
+
+#[test]
+fn test_update_and_clear_prefs() {
+    let e = Env::default();
+    let (client, identity) = setup(&e);
+    client.set_notification_prefs(
+        &identity,
+        &Bytes::from_array(&e, &[1; 8]),
+        &TOPIC_RENEWAL_DUE,
+    );
+    let endpoint = Bytes::from_slice(&e, b"ipfs://notify");
+    client.set_notification_prefs(&identity, &endpoint, &TOPIC_SLASH_PROPOSED);
+    let prefs = client.get_notification_prefs(&identity).unwrap();
+    assert_eq!(prefs.endpoint, endpoint);
+    assert_eq!(prefs.topics, TOPIC_SLASH_PROPOSED);
+
+    client.clear_notification_prefs(&identity);
+    assert_eq!(client.get_notification_prefs(&identity), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_only_bond_identity_can_set() {
+    let e = Env::default();
+    let (client, _identity) = setup(&e);
+    let other = Address::generate(&e);
+    client.set_notification_prefs(&other, &Bytes::from_array(&e, &[1; 8]), &TOPIC_RENEWAL_DUE);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_unknown_topic_rejected() {
+    let e = Env::default();
+    let (client, identity) = setup(&e);
+    client.set_notification_prefs(&identity, &Bytes::from_array(&e, &[1; 8]), &(1 << 5));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_long_endpoint_rejected() {
+    let e = Env::default();
+    let (client, identity) = setup(&e);
+    client.set_notification_prefs(
+        &identity,
+        &Bytes::from_array(&e, &[1; 257]),
+        &TOPIC_RENEWAL_DUE,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #115)")]
+fn test_clear_without_prefs() {
+    let e = Env::default();
+    let (client, identity) = setup(&e);
+    client.clear_notification_prefs(&identity);
+}
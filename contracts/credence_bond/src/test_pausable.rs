@@ -0,0 +1,97 @@
+//! Tests for the emergency stop: guarded entrypoints, events and admin-only toggling.
+
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, IntoVal, String, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1_000_i128, &86400_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_pause_and_unpause_emit_events() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    assert!(!client.is_paused());
+
+    client.pause(&admin);
+    let topics = (Symbol::new(&e, "paused"),).into_val(&e);
+    assert!(e.events().all().iter().any(|(_, t, _)| t == topics));
+    assert!(client.is_paused());
+    assert!(client.export_config().paused);
+
+    client.unpause(&admin);
+    let topics = (Symbol::new(&e, "unpaused"),).into_val(&e);
+    assert!(e.events().all().iter().any(|(_, t, _)| t == topics));
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_paused_blocks_guarded_entrypoints() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    client.pause(&admin);
+
+    let paused = Some(Ok(BondError::Paused));
+    assert_eq!(
+        client
+            .try_create_bond(&Address::generate(&e), &1_000, &86400, &false, &0)
+            .err(),
+        paused
+    );
+    assert_eq!(client.try_withdraw_bond(&identity).err(), paused);
+    assert_eq!(client.try_withdraw(&100).err(), paused);
+    assert_eq!(client.try_top_up(&100).err(), paused);
+    assert_eq!(client.try_slash(&admin, &100).err(), paused);
+    assert_eq!(client.try_collect_fees(&admin).err(), paused);
+    assert_eq!(client.try_deposit_fees(&admin, &100).err(), paused);
+    assert_eq!(
+        client
+            .try_add_attestation(
+                &attester,
+                &identity,
+                &String::from_str(&e, "kyc"),
+                &client.get_nonce(&attester)
+            )
+            .err(),
+        paused
+    );
+}
+
+#[test]
+fn test_admin_configuration_allowed_while_paused() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.pause(&admin);
+    client.set_fee_config(&admin, &Address::generate(&e), &100);
+    client.unpause(&admin);
+    e.ledger().with_mut(|li| li.timestamp += 86400);
+    assert_eq!(client.withdraw_bond(&identity), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_pause_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    client.pause(&Address::generate(&e));
+}
+
+#[test]
+fn test_pause_requires_admin_signature() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    e.set_auths(&[]);
+    assert!(client.try_pause(&admin).is_err());
+    assert!(client.try_set_unbond_delay(&admin, &10_u64).is_err());
+    assert!(!client.is_paused());
+}
@@ -0,0 +1,99 @@
+//! Tests for re-bonding after a full slash: cooldown, raised minimum, probation flag and
+//! clearing of the full-slash record on unslash.
+
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1_000_i128, &86400_u64, &false, &0_u64);
+    client.set_probation_config(&admin, &500_u64, &5_000_i128, &2_000_u64);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_full_slash_recorded() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.slash(&admin, &400_i128);
+    assert_eq!(client.get_full_slash_at(&identity), None);
+    client.slash(&admin, &600_i128);
+    assert_eq!(client.get_full_slash_at(&identity), Some(1000));
+}
+
+#[test]
+fn test_rebond_after_cooldown_starts_probation() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.slash(&admin, &1_000_i128);
+
+    e.ledger().with_mut(|li| li.timestamp = 1500);
+    client.create_bond(&identity, &5_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(client.get_full_slash_at(&identity), None);
+    assert_eq!(client.get_probation_until(&identity), 3500);
+    assert!(client.is_on_probation(&identity));
+
+    e.ledger().with_mut(|li| li.timestamp = 3500);
+    assert!(!client.is_on_probation(&identity));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #111)")]
+fn test_rebond_during_cooldown_rejected() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.slash(&admin, &1_000_i128);
+    e.ledger().with_mut(|li| li.timestamp = 1499);
+    client.create_bond(&identity, &5_000_i128, &86400_u64, &false, &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_rebond_below_minimum_rejected() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.slash(&admin, &1_000_i128);
+    e.ledger().with_mut(|li| li.timestamp = 1500);
+    client.create_bond(&identity, &4_999_i128, &86400_u64, &false, &0_u64);
+}
+
+#[test]
+fn test_unslash_clears_full_slash() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.slash(&admin, &1_000_i128);
+    e.as_contract(&client.address, || {
+        crate::slashing::unslash_bond(&e, &admin, 100);
+    });
+    assert_eq!(client.get_full_slash_at(&identity), None);
+    e.ledger().with_mut(|li| li.timestamp += 86400);
+    client.withdraw_bond(&identity);
+    client.create_bond(&identity, &100_i128, &86400_u64, &false, &0_u64);
+    assert!(!client.is_on_probation(&identity));
+}
+
+#[test]
+fn test_other_identities_unaffected() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.slash(&admin, &1_000_i128);
+    let other = Address::generate(&e);
+    client.create_bond(&other, &100_i128, &86400_u64, &false, &0_u64);
+    assert!(!client.is_on_probation(&other));
+    assert_eq!(client.get_full_slash_at(&identity), Some(1000));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_probation_config_unauthorized() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    client.set_probation_config(&Address::generate(&e), &0_u64, &0_i128, &0_u64);
+}
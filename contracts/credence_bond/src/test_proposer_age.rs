@@ -0,0 +1,105 @@
+//! Tests for the proposer age gate on slash proposals.
+
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env};
+
+const DAY: u64 = 86_400;
+
+fn setup(e: &Env, min_age: u64) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1000_i128, &(30 * DAY), &false, &0_u64);
+    let governor = Address::generate(e);
+    client.initialize_governance(
+        &admin,
+        &vec![e, governor.clone()],
+        &5100_u32,
+        &1_u32,
+        &min_age,
+    );
+    (client, admin, identity, governor)
+}
+
+fn advance(e: &Env, seconds: u64) {
+    e.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_no_gate_by_default() {
+    let e = Env::default();
+    let (client, _admin, identity, governor) = setup(&e, 0);
+    assert!(client.is_eligible_proposer(&governor));
+    client.propose_slash(&governor, &identity, &100_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #307)")]
+fn test_new_governor_cannot_propose() {
+    let e = Env::default();
+    let (client, _admin, identity, governor) = setup(&e, 7 * DAY);
+    assert!(!client.is_eligible_proposer(&governor));
+    client.propose_slash(&governor, &identity, &100_i128);
+}
+
+#[test]
+fn test_governor_seat_age_qualifies() {
+    let e = Env::default();
+    let (client, _admin, identity, governor) = setup(&e, 7 * DAY);
+    advance(&e, 7 * DAY);
+    assert!(client.is_eligible_proposer(&governor));
+    client.propose_slash(&governor, &identity, &100_i128);
+    assert_eq!(client.get_min_proposer_age(), 7 * DAY);
+}
+
+#[test]
+fn test_bond_age_qualifies_new_governor() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1000_i128, &(30 * DAY), &false, &0_u64);
+    advance(&e, 7 * DAY);
+    // The bond holder only now gets a seat, but has been bonded long enough.
+    client.initialize_governance(
+        &admin,
+        &vec![&e, identity.clone()],
+        &5100_u32,
+        &1_u32,
+        &(7 * DAY),
+    );
+    assert!(client.is_eligible_proposer(&identity));
+}
+
+#[test]
+fn test_reinitialization_keeps_seat_age() {
+    let e = Env::default();
+    let (client, admin, _identity, governor) = setup(&e, 7 * DAY);
+    advance(&e, 7 * DAY);
+    client.initialize_governance(
+        &admin,
+        &vec![&e, governor.clone()],
+        &5100_u32,
+        &1_u32,
+        &(7 * DAY),
+    );
+    assert!(client.is_eligible_proposer(&governor));
+}
+
+#[test]
+fn test_admin_exempt_and_outsider_ineligible() {
+    let e = Env::default();
+    let (client, admin, identity, _governor) = setup(&e, 7 * DAY);
+    assert!(client.is_eligible_proposer(&admin));
+    assert!(!client.is_eligible_proposer(&Address::generate(&e)));
+    client.propose_slash(&admin, &identity, &100_i128);
+}
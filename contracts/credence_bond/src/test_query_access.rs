@@ -0,0 +1,328 @@
+//! Tests for paid query access: consumer allow-list, prepaid credit, per-query and
+//! subscription charging into the fee pool, credit withdrawal and the gated views.
+
+use crate::attestation_summary::AttestationSummary;
+use crate::query_access::QueryPricing;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{token, Address, Env, IntoVal, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, token::Client<'_>) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    client.set_token(&admin, &sac.address());
+    let consumer = Address::generate(e);
+    token::StellarAssetClient::new(e, &sac.address()).mint(&consumer, &1_000_000);
+    client.set_query_pricing(
+        &admin,
+        &QueryPricing {
+            query_fee: 100,
+            subscription_fee: 5_000,
+            subscription_period: 86400,
+        },
+    );
+    client.set_query_consumer(&admin, &consumer, &true);
+    (
+        client,
+        admin,
+        consumer,
+        token::Client::new(e, &sac.address()),
+    )
+}
+
+#[test]
+fn test_deposit_credits_consumer() {
+    let e = Env::default();
+    let (client, _admin, consumer, token) = setup(&e);
+    assert_eq!(client.deposit_query_credit(&consumer, &10_000_i128), 10_000);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "query_credit_deposited"), consumer.clone()).into_val(&e)
+    );
+    assert_eq!(token.balance(&consumer), 990_000);
+    assert_eq!(token.balance(&client.address), 10_000);
+    let account = client.get_consumer_account(&consumer);
+    assert!(account.authorized);
+    assert_eq!(account.credit, 10_000);
+    // Prepaid credit is reserved, not claimable as a bond deposit.
+    assert_eq!(client.get_reserved_balance(), 10_000);
+    assert_eq!(client.get_unclaimed_deposits(), 0);
+}
+
+#[test]
+fn test_query_charges_credit_into_fee_pool() {
+    let e = Env::default();
+    let (client, admin, consumer, token) = setup(&e);
+    client.deposit_query_credit(&consumer, &250_i128);
+    let subject = Address::generate(&e);
+    assert_eq!(client.get_reputation(&consumer, &subject).score, 0);
+    assert_eq!(
+        client.get_attestation_summary(&consumer, &subject),
+        AttestationSummary::default()
+    );
+    let account = client.get_consumer_account(&consumer);
+    assert_eq!(account.credit, 50);
+    assert_eq!(account.query_count, 2);
+    assert_eq!(client.get_reserved_balance(), 250);
+    assert_eq!(
+        client.try_get_reputation(&consumer, &subject).err(),
+        Some(Ok(BondError::InsufficientBalance))
+    );
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &0_u32);
+    assert_eq!(client.collect_fees(&admin), 200);
+    assert_eq!(token.balance(&treasury), 200);
+    assert_eq!(client.get_reserved_balance(), 50);
+}
+
+#[test]
+fn test_score_views_are_charged() {
+    let e = Env::default();
+    let (client, _admin, consumer, _token) = setup(&e);
+    client.deposit_query_credit(&consumer, &1_000_i128);
+    let subject = Address::generate(&e);
+    assert_eq!(
+        client.get_subject_attestations(&consumer, &subject).len(),
+        0
+    );
+    assert_eq!(client.get_subject_attestation_count(&consumer, &subject), 0);
+    assert_eq!(client.get_subject_aggregate_weight(&consumer, &subject), 0);
+    assert_eq!(client.get_subject_pending_weight(&consumer, &subject), 0);
+    assert_eq!(client.get_recent_activity(&consumer, &subject).len(), 0);
+    assert_eq!(
+        client
+            .get_verification_snapshot(&consumer, &subject)
+            .attestation_count,
+        0
+    );
+    assert_eq!(
+        client.explain_score(&consumer, &subject).aggregate_weight,
+        0
+    );
+    assert_eq!(client.get_cached_reputation(&consumer, &subject), None);
+    let account = client.get_consumer_account(&consumer);
+    assert_eq!(account.query_count, 8);
+    assert_eq!(account.credit, 200);
+
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        client
+            .try_get_verification_snapshot(&stranger, &subject)
+            .err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_explain_score(&stranger, &subject).err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_get_cached_reputation(&stranger, &subject).err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client
+            .try_get_subject_attestation_count(&stranger, &subject)
+            .err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_subscription_makes_queries_free() {
+    let e = Env::default();
+    let (client, admin, consumer, _token) = setup(&e);
+    client.deposit_query_credit(&consumer, &6_000_i128);
+    assert_eq!(client.subscribe_queries(&consumer), 1000 + 86400);
+    assert_eq!(client.consume_credit(&consumer), 0);
+    assert_eq!(client.get_consumer_account(&consumer).credit, 1_000);
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 86400);
+    assert_eq!(client.consume_credit(&consumer), 100);
+    assert_eq!(client.get_consumer_account(&consumer).credit, 900);
+    client.set_fee_config(&admin, &Address::generate(&e), &0_u32);
+    assert_eq!(client.collect_fees(&admin), 5_100);
+    assert_eq!(
+        client.try_subscribe_queries(&consumer).err(),
+        Some(Ok(BondError::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_subscription_extends_from_end() {
+    let e = Env::default();
+    let (client, _admin, consumer, _token) = setup(&e);
+    client.deposit_query_credit(&consumer, &10_000_i128);
+    client.subscribe_queries(&consumer);
+    e.ledger().with_mut(|li| li.timestamp = 5000);
+    assert_eq!(client.subscribe_queries(&consumer), 1000 + 2 * 86400);
+}
+
+#[test]
+fn test_unauthorized_consumer_rejected() {
+    let e = Env::default();
+    let (client, admin, consumer, _token) = setup(&e);
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        client.try_deposit_query_credit(&stranger, &100_i128).err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client
+            .try_get_reputation(&stranger, &Address::generate(&e))
+            .err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+
+    client.deposit_query_credit(&consumer, &1_000_i128);
+    client.set_query_consumer(&admin, &consumer, &false);
+    assert_eq!(
+        client.try_consume_credit(&consumer).err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    // Credit is kept for re-authorization.
+    client.set_query_consumer(&admin, &consumer, &true);
+    assert_eq!(client.consume_credit(&consumer), 100);
+}
+
+#[test]
+fn test_invalid_pricing_and_deposit_rejected() {
+    let e = Env::default();
+    let (client, admin, consumer, _token) = setup(&e);
+    assert_eq!(
+        client
+            .try_set_query_pricing(
+                &admin,
+                &QueryPricing {
+                    query_fee: -1,
+                    subscription_fee: 0,
+                    subscription_period: 0,
+                },
+            )
+            .err(),
+        Some(Ok(BondError::InvalidConfig))
+    );
+    assert_eq!(
+        client
+            .try_set_query_pricing(
+                &admin,
+                &QueryPricing {
+                    query_fee: 0,
+                    subscription_fee: 10,
+                    subscription_period: 0,
+                },
+            )
+            .err(),
+        Some(Ok(BondError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_deposit_query_credit(&consumer, &0_i128).err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+
+    client.set_query_pricing(&admin, &QueryPricing::default());
+    assert_eq!(
+        client.try_subscribe_queries(&consumer).err(),
+        Some(Ok(BondError::ConfigNotSet))
+    );
+    // Free queries are still counted.
+    assert_eq!(client.consume_credit(&consumer), 0);
+    assert_eq!(client.get_consumer_account(&consumer).query_count, 1);
+}
+
+#[test]
+fn test_fees_feature_disabled_waives_charges() {
+    let e = Env::default();
+    let (client, admin, consumer, _token) = setup(&e);
+    client.set_features(&admin, &0);
+    assert_eq!(client.consume_credit(&consumer), 0);
+}
+
+#[test]
+fn test_admin_only_configuration() {
+    let e = Env::default();
+    let (client, _admin, consumer, _token) = setup(&e);
+    let other = Address::generate(&e);
+    assert_eq!(
+        client
+            .try_set_query_consumer(&other, &consumer, &false)
+            .err(),
+        Some(Ok(BondError::NotAdmin))
+    );
+    assert_eq!(
+        client
+            .try_set_query_pricing(&other, &QueryPricing::default())
+            .err(),
+        Some(Ok(BondError::NotAdmin))
+    );
+}
+
+#[test]
+fn test_query_requires_consumer_auth() {
+    let e = Env::default();
+    let (client, _admin, consumer, _token) = setup(&e);
+    e.set_auths(&[]);
+    assert!(client.try_consume_credit(&consumer).is_err());
+}
+
+#[test]
+fn test_views_require_consumer_auth() {
+    let e = Env::default();
+    let (client, _admin, consumer, _token) = setup(&e);
+    client.deposit_query_credit(&consumer, &1_000_i128);
+    e.set_auths(&[]);
+    let subject = Address::generate(&e);
+    assert!(client.try_get_reputation(&consumer, &subject).is_err());
+    assert!(client
+        .try_get_attestation_summary(&consumer, &subject)
+        .is_err());
+    assert!(client.try_explain_score(&consumer, &subject).is_err());
+    assert!(client
+        .try_get_cached_reputation(&consumer, &subject)
+        .is_err());
+}
+
+#[test]
+fn test_withdraw_credit_pays_consumer() {
+    let e = Env::default();
+    let (client, admin, consumer, token) = setup(&e);
+    client.deposit_query_credit(&consumer, &1_000_i128);
+    client.consume_credit(&consumer);
+    assert_eq!(client.withdraw_query_credit(&consumer, &600_i128), 300);
+    assert_eq!(token.balance(&consumer), 1_000_000 - 1_000 + 600);
+    assert_eq!(client.get_reserved_balance(), 300 + 100);
+    assert_eq!(
+        client.try_withdraw_query_credit(&consumer, &301_i128).err(),
+        Some(Ok(BondError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_withdraw_query_credit(&consumer, &0_i128).err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+
+    // A consumer removed from the allow-list can still take its credit back.
+    client.set_query_consumer(&admin, &consumer, &false);
+    assert_eq!(client.withdraw_query_credit(&consumer, &300_i128), 0);
+    assert_eq!(token.balance(&client.address), 100);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &0_u32);
+    assert_eq!(client.collect_fees(&admin), 100);
+    assert_eq!(token.balance(&treasury), 100);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_withdraw_credit_requires_consumer_auth() {
+    let e = Env::default();
+    let (client, _admin, consumer, _token) = setup(&e);
+    client.deposit_query_credit(&consumer, &1_000_i128);
+    e.set_auths(&[]);
+    assert!(client
+        .try_withdraw_query_credit(&consumer, &1_000_i128)
+        .is_err());
+}
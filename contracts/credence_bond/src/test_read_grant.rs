@@ -0,0 +1,86 @@
+//! Tests for attestation read grants: subject/verifier access, expiring grants, revocation
+//! and aggregate-only access for other callers.
+
+use crate::{testutils, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, u64) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    let subject = Address::generate(e);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(e, "kyc"), &0_u64);
+    (client, attester, subject, att.id)
+}
+
+#[test]
+fn test_subject_and_verifier_can_read() {
+    let e = Env::default();
+    let (client, attester, subject, id) = setup(&e);
+    assert_eq!(client.get_attestation(&subject, &id).id, id);
+    assert_eq!(client.get_attestation(&attester, &id).id, id);
+}
+
+#[test]
+fn test_granted_reader_can_read_until_expiry() {
+    let e = Env::default();
+    let (client, _attester, subject, id) = setup(&e);
+    let reader = Address::generate(&e);
+    client.grant_read(&subject, &reader, &2000_u64);
+    assert_eq!(client.get_read_grant(&subject, &reader), Some(2000));
+    assert_eq!(client.get_attestation(&reader, &id).identity, subject);
+}
+
+#[test]
+fn test_reader_without_grant_gets_aggregates() {
+    let e = Env::default();
+    let (client, _attester, subject, _id) = setup(&e);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_subject_attestation_count(&consumer, &subject), 1);
+    assert_eq!(client.get_subject_aggregate_weight(&consumer, &subject), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_reader_without_grant_rejected() {
+    let e = Env::default();
+    let (client, _attester, _subject, id) = setup(&e);
+    let reader = Address::generate(&e);
+    client.get_attestation(&reader, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_expired_grant_rejected() {
+    let e = Env::default();
+    let (client, _attester, subject, id) = setup(&e);
+    let reader = Address::generate(&e);
+    client.grant_read(&subject, &reader, &2000_u64);
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    client.get_attestation(&reader, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_revoked_grant_rejected() {
+    let e = Env::default();
+    let (client, _attester, subject, id) = setup(&e);
+    let reader = Address::generate(&e);
+    client.grant_read(&subject, &reader, &2000_u64);
+    client.revoke_read(&subject, &reader);
+    client.get_attestation(&reader, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_grant_in_past_rejected() {
+    let e = Env::default();
+    let (client, _attester, subject, _id) = setup(&e);
+    client.grant_read(&subject, &Address::generate(&e), &1000_u64);
+}
@@ -245,7 +245,7 @@ fn test_fee_collection_reentrancy_blocked() {
     let (bond_id, admin, _identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.deposit_fees(&500_i128);
+    client.deposit_fees(&admin, &500_i128);
 
     let attacker_id = e.register_contract(None, FeeAttacker);
     let attacker_client = FeeAttackerClient::new(&e, &attacker_id);
@@ -312,7 +312,7 @@ fn test_lock_released_after_fee_collection() {
     let (bond_id, admin, _identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.deposit_fees(&200_i128);
+    client.deposit_fees(&admin, &200_i128);
 
     let benign_id = e.register_contract(None, BenignCallback);
     client.set_callback(&benign_id);
@@ -368,7 +368,7 @@ fn test_normal_fee_collection_succeeds() {
     let (bond_id, admin, _identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.deposit_fees(&750_i128);
+    client.deposit_fees(&admin, &750_i128);
     let collected = client.collect_fees(&admin);
     assert_eq!(collected, 750_i128);
 }
@@ -386,7 +386,7 @@ fn test_sequential_operations_succeed() {
     client.slash_bond(&admin, &1_000_i128);
     assert!(!client.is_locked());
 
-    client.deposit_fees(&100_i128);
+    client.deposit_fees(&admin, &100_i128);
     let fees = client.collect_fees(&admin);
     assert_eq!(fees, 100_i128);
     assert!(!client.is_locked());
@@ -0,0 +1,145 @@
+//! Tests for referral attribution: referrer recorded on the bond, aggregate counters per
+//! referrer, and the referrer share of bond-creation fees.
+
+use crate::referral::ReferralStats;
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    client.set_fee_config(&admin, &Address::generate(e), &100_u32); // 1%
+    (client, admin, Address::generate(e))
+}
+
+#[test]
+fn test_referrer_recorded_on_bond() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let referrer = Address::generate(&e);
+    client.create_bond_with_referrer(&identity, &1000_i128, &86400_u64, &false, &0_u64, &referrer);
+    assert_eq!(client.get_bond_referrer(), Some(referrer));
+}
+
+#[test]
+fn test_plain_create_bond_clears_referrer() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let referrer = Address::generate(&e);
+    client.create_bond_with_referrer(&identity, &1000_i128, &86400_u64, &false, &0_u64, &referrer);
+    e.ledger().with_mut(|li| li.timestamp += 86400);
+    client.withdraw_bond(&identity);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(client.get_bond_referrer(), None);
+}
+
+#[test]
+fn test_fee_split_between_pool_and_referrer() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_referral_fee_share(&admin, &2500_u32);
+    assert_eq!(client.export_config().referral_fee_bps, 2500);
+    let referrer = Address::generate(&e);
+    let bond = client.create_bond_with_referrer(
+        &identity,
+        &10_000_i128,
+        &86400_u64,
+        &false,
+        &0_u64,
+        &referrer,
+    );
+    assert_eq!(bond.bonded_amount, 9_900);
+    assert_eq!(client.get_unclaimed_referral_fees(&referrer), 25);
+    assert_eq!(client.collect_fees(&admin), 75);
+}
+
+#[test]
+fn test_stats_aggregate_per_referrer() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_referral_fee_share(&admin, &5000_u32);
+    let referrer = Address::generate(&e);
+    client.create_bond_with_referrer(&identity, &1000_i128, &86400_u64, &false, &0_u64, &referrer);
+    e.ledger().with_mut(|li| li.timestamp += 86400);
+    client.withdraw_bond(&identity);
+    client.create_bond_with_referrer(&identity, &3000_i128, &86400_u64, &false, &0_u64, &referrer);
+    assert_eq!(
+        client.get_referral_stats(&referrer),
+        ReferralStats {
+            bonds_referred: 2,
+            amount_referred: 4000,
+            fees_earned: 20,
+        }
+    );
+    let other = Address::generate(&e);
+    assert_eq!(client.get_referral_stats(&other).bonds_referred, 0);
+}
+
+#[test]
+fn test_claim_referral_fees() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_referral_fee_share(&admin, &10_000_u32);
+    let referrer = Address::generate(&e);
+    client.create_bond_with_referrer(&identity, &1000_i128, &86400_u64, &false, &0_u64, &referrer);
+    assert_eq!(client.claim_referral_fees(&referrer), 10);
+    assert_eq!(client.get_unclaimed_referral_fees(&referrer), 0);
+    assert_eq!(client.get_referral_stats(&referrer).fees_earned, 10);
+}
+
+#[test]
+fn test_claim_referral_fees_pays_token() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_referral_fee_share(&admin, &10_000_u32);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    token::StellarAssetClient::new(&e, &sac.address()).mint(&identity, &1000);
+    client.set_token(&admin, &sac.address());
+    let referrer = Address::generate(&e);
+    client.create_bond_with_referrer(&identity, &1000_i128, &86400_u64, &false, &0_u64, &referrer);
+    assert_eq!(client.get_reserved_balance(), 10);
+
+    assert_eq!(client.claim_referral_fees(&referrer), 10);
+    let token = token::Client::new(&e, &sac.address());
+    assert_eq!(token.balance(&referrer), 10);
+    assert_eq!(token.balance(&client.address), 990);
+    assert_eq!(client.get_reserved_balance(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #122)")]
+fn test_claim_without_fees() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let referrer = Address::generate(&e);
+    client.create_bond_with_referrer(&identity, &1000_i128, &86400_u64, &false, &0_u64, &referrer);
+    client.claim_referral_fees(&referrer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_self_referral_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond_with_referrer(&identity, &1000_i128, &86400_u64, &false, &0_u64, &identity);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_fee_share_over_max_rejected() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.set_referral_fee_share(&admin, &10_001_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_fee_share_admin_only() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    client.set_referral_fee_share(&Address::generate(&e), &100_u32);
+}
@@ -1,14 +1,16 @@
-//! Tests for replay attack prevention: nonce validation and rejection of replayed transactions.
+//! Tests for replay attack prevention: nonce validation and rejection of replayed transactions,
+//! including attestations relayed with an attester's ed25519 signature.
 
 #![cfg(test)]
 
 use crate::*;
-use soroban_sdk::testutils::Address as _;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, Ledger};
 use soroban_sdk::{Env, String};
 
-fn setup(e: &Env) -> (CredenceBondClient, soroban_sdk::Address) {
+fn setup(e: &Env) -> (CredenceBondClient<'_>, soroban_sdk::Address) {
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = soroban_sdk::Address::generate(e);
     client.initialize(&admin);
@@ -37,7 +39,7 @@ fn nonce_increments_after_add_attestation() {
 }
 
 #[test]
-#[should_panic(expected = "invalid nonce")]
+#[should_panic(expected = "Error(Contract, #208)")]
 fn replay_add_attestation_rejected() {
     let e = Env::default();
     let (client, attester) = setup(&e);
@@ -48,7 +50,7 @@ fn replay_add_attestation_rejected() {
 }
 
 #[test]
-#[should_panic(expected = "invalid nonce")]
+#[should_panic(expected = "Error(Contract, #208)")]
 fn wrong_nonce_rejected() {
     let e = Env::default();
     let (client, attester) = setup(&e);
@@ -68,12 +70,17 @@ fn nonce_increments_after_revoke() {
         &client.get_nonce(&attester),
     );
     let nonce_before = client.get_nonce(&attester);
-    client.revoke_attestation(&attester, &att.id, &nonce_before);
+    client.revoke_attestation(
+        &attester,
+        &att.id,
+        &RevocationReason::IssuedInError,
+        &nonce_before,
+    );
     assert_eq!(client.get_nonce(&attester), nonce_before + 1);
 }
 
 #[test]
-#[should_panic(expected = "invalid nonce")]
+#[should_panic(expected = "Error(Contract, #208)")]
 fn replay_revoke_rejected() {
     let e = Env::default();
     let (client, attester) = setup(&e);
@@ -85,6 +92,119 @@ fn replay_revoke_rejected() {
         &client.get_nonce(&attester),
     );
     let used_nonce = client.get_nonce(&attester) - 1;
-    client.revoke_attestation(&attester, &att.id, &used_nonce);
-    client.revoke_attestation(&attester, &att.id, &used_nonce);
+    client.revoke_attestation(
+        &attester,
+        &att.id,
+        &RevocationReason::IssuedInError,
+        &used_nonce,
+    );
+    client.revoke_attestation(
+        &attester,
+        &att.id,
+        &RevocationReason::IssuedInError,
+        &used_nonce,
+    );
+}
+
+/// Register a signing key for `attester` and return it.
+fn register_signing_key(e: &Env, client: &CredenceBondClient, attester: &Address) -> SigningKey {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.set_attester_signing_key(
+        attester,
+        &BytesN::from_array(e, &key.verifying_key().to_bytes()),
+    );
+    key
+}
+
+fn sign(e: &Env, key: &SigningKey, payload: &Bytes) -> BytesN<64> {
+    let mut buf = [0u8; 512];
+    let len = payload.len() as usize;
+    payload.copy_into_slice(&mut buf[..len]);
+    BytesN::from_array(e, &key.sign(&buf[..len]).to_bytes())
+}
+
+#[test]
+fn signed_attestation_relayed_without_attester_auth() {
+    let e = Env::default();
+    let (client, attester) = setup(&e);
+    let key = register_signing_key(&e, &client, &attester);
+    let subject = soroban_sdk::Address::generate(&e);
+    let data = String::from_str(&e, "kyc");
+    let payload = client.get_attestation_signing_payload(&attester, &subject, &data, &0, &1_000);
+    let signature = sign(&e, &key, &payload);
+
+    e.set_auths(&[]);
+    let att = client.add_attestation_signed(&attester, &subject, &data, &0, &1_000, &signature);
+    assert_eq!(att.verifier, attester);
+    assert_eq!(att.identity, subject);
+    assert_eq!(client.get_nonce(&attester), 1);
+
+    // The same signature cannot be replayed.
+    assert_eq!(
+        client
+            .try_add_attestation_signed(&attester, &subject, &data, &0, &1_000, &signature)
+            .err(),
+        Some(Ok(BondError::InvalidNonce))
+    );
+}
+
+#[test]
+fn signed_attestation_rejects_tampered_data() {
+    let e = Env::default();
+    let (client, attester) = setup(&e);
+    let key = register_signing_key(&e, &client, &attester);
+    let subject = soroban_sdk::Address::generate(&e);
+    let payload = client.get_attestation_signing_payload(
+        &attester,
+        &subject,
+        &String::from_str(&e, "kyc"),
+        &0,
+        &1_000,
+    );
+    let signature = sign(&e, &key, &payload);
+    let forged = String::from_str(&e, "accredited");
+    assert!(client
+        .try_add_attestation_signed(&attester, &subject, &forged, &0, &1_000, &signature)
+        .is_err());
+    assert_eq!(client.get_nonce(&attester), 0);
+}
+
+#[test]
+fn signed_attestation_expires() {
+    let e = Env::default();
+    let (client, attester) = setup(&e);
+    let key = register_signing_key(&e, &client, &attester);
+    let subject = soroban_sdk::Address::generate(&e);
+    let data = String::from_str(&e, "kyc");
+    let payload = client.get_attestation_signing_payload(&attester, &subject, &data, &0, &1_000);
+    let signature = sign(&e, &key, &payload);
+    e.ledger().with_mut(|li| li.timestamp = 1_001);
+    assert_eq!(
+        client
+            .try_add_attestation_signed(&attester, &subject, &data, &0, &1_000, &signature)
+            .err(),
+        Some(Ok(BondError::InvalidNonce))
+    );
+}
+
+#[test]
+fn signed_attestation_requires_registered_key() {
+    let e = Env::default();
+    let (client, attester) = setup(&e);
+    let subject = soroban_sdk::Address::generate(&e);
+    let data = String::from_str(&e, "kyc");
+    assert_eq!(client.get_attester_signing_key(&attester), None);
+    assert_eq!(
+        client
+            .try_add_attestation_signed(
+                &attester,
+                &subject,
+                &data,
+                &0,
+                &1_000,
+                &BytesN::from_array(&e, &[0; 64])
+            )
+            .err(),
+        Some(Ok(BondError::ConfigNotSet))
+    );
 }
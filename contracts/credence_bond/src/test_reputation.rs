@@ -0,0 +1,112 @@
+//! Tests for the reputation score: formula components, the cache refreshed on attestation
+//! and slash changes, and admin configuration.
+
+use crate::reputation::{self, ReputationConfig};
+use crate::{BondError, BondTier, CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String};
+
+const DAY: u64 = 86_400;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &2_000_000_000_i128, &(400 * DAY), &false, &0_u64);
+    let reader = Address::generate(e);
+    client.set_query_consumer(&admin, &reader, &true);
+    (client, admin, identity, reader)
+}
+
+fn attest(client: &CredenceBondClient<'_>, e: &Env, subject: &Address) -> (Address, u64) {
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    let nonce = client.get_nonce(&attester);
+    let data = String::from_str(e, "kyc");
+    let id = client.add_attestation(&attester, subject, &data, &nonce).id;
+    (attester, id)
+}
+
+#[test]
+fn test_score_formula() {
+    let config = reputation::default_config();
+    assert_eq!(
+        reputation::score(&config, 5, &BondTier::Gold, 10, 0),
+        5 + 200 + 10
+    );
+    assert_eq!(
+        reputation::score(&config, 5, &BondTier::Gold, 1_000, 0),
+        5 + 200 + 365
+    );
+    assert_eq!(reputation::score(&config, 5, &BondTier::Bronze, 10, 1), 0);
+}
+
+#[test]
+fn test_reputation_combines_components() {
+    let e = Env::default();
+    let (client, _admin, identity, reader) = setup(&e);
+    attest(&client, &e, &identity);
+    e.ledger().with_mut(|li| li.timestamp += 10 * DAY);
+
+    let rep = client.get_reputation(&reader, &identity);
+    assert!(rep.active_weight > 0);
+    assert_eq!(rep.tier, BondTier::Silver);
+    assert_eq!(rep.bond_age_days, 10);
+    assert_eq!(rep.slash_count, 0);
+    assert_eq!(rep.score, rep.active_weight + 100 + 10);
+}
+
+#[test]
+fn test_cache_refreshed_on_attestation_changes() {
+    let e = Env::default();
+    let (client, _admin, identity, reader) = setup(&e);
+    assert_eq!(client.get_cached_reputation(&reader, &identity), None);
+
+    let (attester, id) = attest(&client, &e, &identity);
+    let cached = client.get_cached_reputation(&reader, &identity).unwrap();
+    assert_eq!(cached, client.get_reputation(&reader, &identity));
+    assert!(cached.active_weight > 0);
+
+    let nonce = client.get_nonce(&attester);
+    client.revoke_attestation(&attester, &id, &RevocationReason::IssuedInError, &nonce);
+    let cached = client.get_cached_reputation(&reader, &identity).unwrap();
+    assert_eq!(cached.active_weight, 0);
+}
+
+#[test]
+fn test_slash_counts_against_reputation() {
+    let e = Env::default();
+    let (client, admin, identity, reader) = setup(&e);
+    client.slash(&admin, &1_000);
+    client.slash(&admin, &1_000);
+
+    let cached = client.get_cached_reputation(&reader, &identity).unwrap();
+    assert_eq!(cached.slash_count, 2);
+    assert_eq!(cached.score, 0);
+    assert_eq!(client.get_reputation(&reader, &identity).slash_count, 2);
+}
+
+#[test]
+fn test_set_reputation_config() {
+    let e = Env::default();
+    let (client, admin, identity, reader) = setup(&e);
+    let config = ReputationConfig {
+        weight_points: 0,
+        tier_points: 7,
+        age_points_per_day: 0,
+        max_age_days: 0,
+        slash_penalty: 0,
+    };
+    let outsider = Address::generate(&e);
+    assert_eq!(
+        client.try_set_reputation_config(&outsider, &config),
+        Err(Ok(BondError::NotAdmin))
+    );
+    client.set_reputation_config(&admin, &config);
+    assert_eq!(client.get_reputation_config(), config);
+    assert_eq!(client.get_reputation(&reader, &identity).score, 7);
+}
@@ -0,0 +1,123 @@
+//! Tests for the reserved balance: pending fees, pending penalties and escrowed claims must
+//! stay covered by the bond token balance after a withdrawal.
+
+use crate::invariants::INV_RESERVES_UNCOVERED;
+use crate::{testutils, BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env};
+
+const BOND: i128 = 2_000_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|l| l.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    token::StellarAssetClient::new(e, &sac.address()).mint(&identity, &BOND);
+    client.set_token(&admin, &sac.address());
+    client.create_bond_funded(&identity, &BOND, &100_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_withdraw_keeps_pending_fees_covered() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    assert_eq!(client.get_reserved_balance(), 0);
+
+    // Fees recorded without matching tokens in the contract.
+    testutils::set_fee_pool(&e, &client.address, 1_000);
+    assert_eq!(client.get_reserved_balance(), 1_000);
+    e.ledger().with_mut(|l| l.timestamp += 100);
+    assert!(client.try_withdraw(&BOND).is_err());
+    client.withdraw(&(BOND - 1_000));
+}
+
+#[test]
+fn test_early_exit_penalty_reserved_until_collected() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.set_early_exit_config(&admin, &Address::generate(&e), &1_000_u32);
+    e.ledger().with_mut(|l| l.timestamp += 50);
+
+    client.withdraw_early(&1_000_000);
+    // 10% penalty, half the lock time remaining: 5% of the withdrawal.
+    assert_eq!(client.get_reserved_balance(), 50_000);
+    assert_eq!(client.collect_penalties(&admin), 50_000);
+    assert_eq!(client.get_reserved_balance(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #117)")]
+fn test_withdraw_early_breaching_reserves_panics() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.set_early_exit_config(&admin, &Address::generate(&e), &0_u32);
+    testutils::set_fee_pool(&e, &client.address, 1_000);
+    client.withdraw_early(&BOND);
+}
+
+#[test]
+fn test_uncovered_reserves_reported_by_invariants() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    testutils::set_fee_pool(&e, &client.address, BOND + 1);
+    assert!(client.check_invariants().contains(INV_RESERVES_UNCOVERED));
+}
+
+fn token_client<'a>(e: &'a Env, client: &CredenceBondClient<'_>) -> token::Client<'a> {
+    token::Client::new(e, &client.get_token_info().unwrap().address)
+}
+
+#[test]
+fn test_collect_fees_pays_treasury() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32);
+    let token = token_client(&e, &client);
+    token::StellarAssetClient::new(&e, &token.address).mint(&admin, &1_000);
+    client.deposit_fees(&admin, &1_000);
+    assert_eq!(client.get_reserved_balance(), 1_000);
+
+    assert_eq!(client.collect_fees(&admin), 1_000);
+    assert_eq!(token.balance(&treasury), 1_000);
+    assert_eq!(token.balance(&client.address), BOND);
+    assert_eq!(client.get_reserved_balance(), 0);
+}
+
+#[test]
+fn test_collect_penalties_pays_treasury() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_early_exit_config(&admin, &treasury, &1_000_u32);
+    e.ledger().with_mut(|l| l.timestamp += 50);
+    client.withdraw_early(&1_000_000);
+
+    assert_eq!(client.collect_penalties(&admin), 50_000);
+    assert_eq!(token_client(&e, &client).balance(&treasury), 50_000);
+}
+
+#[test]
+fn test_deposit_fees_admin_only_and_positive() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    assert_eq!(
+        client.try_deposit_fees(&identity, &1_000).err(),
+        Some(Ok(BondError::NotAdmin))
+    );
+    assert_eq!(
+        client.try_deposit_fees(&admin, &0).err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_deposit_fees(&admin, &-1).err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+    assert_eq!(client.get_reserved_balance(), 0);
+}
@@ -0,0 +1,119 @@
+//! Tests for revocation reasons and the per-attester revocation registry view.
+
+use crate::{CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, IntoVal, String, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    let subject = Address::generate(e);
+    (client, admin, attester, subject)
+}
+
+fn add(client: &CredenceBondClient<'_>, attester: &Address, subject: &Address, data: &str) -> u64 {
+    let nonce = client.get_nonce(attester);
+    let data = String::from_str(&client.env, data);
+    client.add_attestation(attester, subject, &data, &nonce).id
+}
+
+fn revoke(client: &CredenceBondClient<'_>, attester: &Address, id: u64, reason: RevocationReason) {
+    client.revoke_attestation(attester, &id, &reason, &client.get_nonce(attester));
+}
+
+#[test]
+fn test_reason_stored_on_attestation_and_emitted() {
+    let e = Env::default();
+    let (client, _admin, attester, subject) = setup(&e);
+    let id = add(&client, &attester, &subject, "kyc");
+    assert_eq!(
+        client.get_attestation(&subject, &id).revocation_reason,
+        RevocationReason::NotRevoked
+    );
+
+    revoke(&client, &attester, id, RevocationReason::Fraud);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "attestation_revoked"), subject.clone()).into_val(&e)
+    );
+    let data: (u64, Address, RevocationReason) = data.into_val(&e);
+    assert_eq!(data, (id, attester, RevocationReason::Fraud));
+
+    let att = client.get_attestation(&subject, &id);
+    assert!(att.revoked);
+    assert_eq!(att.revocation_reason, RevocationReason::Fraud);
+}
+
+#[test]
+fn test_revocations_listed_by_attester_and_reason() {
+    let e = Env::default();
+    let (client, _admin, attester, subject) = setup(&e);
+    let other = Address::generate(&e);
+    client.register_attester(&other);
+    let a = add(&client, &attester, &subject, "kyc");
+    let b = add(&client, &attester, &subject, "aml");
+    let c = add(&client, &attester, &subject, "pep");
+    let d = add(&client, &other, &subject, "kyc");
+
+    e.ledger().with_mut(|l| l.timestamp = 500);
+    revoke(&client, &attester, a, RevocationReason::Superseded);
+    revoke(&client, &attester, b, RevocationReason::Fraud);
+    revoke(&client, &attester, c, RevocationReason::Superseded);
+    revoke(&client, &other, d, RevocationReason::Superseded);
+
+    let superseded =
+        client.get_revocations_by_reason(&attester, &RevocationReason::Superseded, &0, &10);
+    assert_eq!(superseded.len(), 2);
+    let first = superseded.get(0).unwrap();
+    assert_eq!(first.attestation_id, a);
+    assert_eq!(first.identity, subject);
+    assert_eq!(first.revoked_at, 500);
+    assert_eq!(superseded.get(1).unwrap().attestation_id, c);
+
+    let fraud = client.get_revocations_by_reason(&attester, &RevocationReason::Fraud, &0, &10);
+    assert_eq!(fraud.len(), 1);
+    assert_eq!(fraud.get(0).unwrap().attestation_id, b);
+    assert!(client
+        .get_revocations_by_reason(&attester, &RevocationReason::SubjectRequest, &0, &10)
+        .is_empty());
+
+    let page = client.get_revocations_by_reason(&attester, &RevocationReason::Superseded, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().attestation_id, c);
+    assert!(client
+        .get_revocations_by_reason(&attester, &RevocationReason::Superseded, &5, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_challenge_records_issued_in_error() {
+    let e = Env::default();
+    let (client, admin, attester, subject) = setup(&e);
+    client.set_challenge_window(&admin, &3600_u64);
+    let id = add(&client, &attester, &subject, "kyc");
+    client.challenge_attestation(&subject, &id);
+
+    assert_eq!(
+        client.get_attestation(&subject, &id).revocation_reason,
+        RevocationReason::IssuedInError
+    );
+    let listed =
+        client.get_revocations_by_reason(&attester, &RevocationReason::IssuedInError, &0, &10);
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap().attestation_id, id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #124)")]
+fn test_revoke_rejects_not_revoked_reason() {
+    let e = Env::default();
+    let (client, _admin, attester, subject) = setup(&e);
+    let id = add(&client, &attester, &subject, "kyc");
+    revoke(&client, &attester, id, RevocationReason::NotRevoked);
+}
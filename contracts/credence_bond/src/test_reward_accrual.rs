@@ -0,0 +1,113 @@
+//! Tests for reward accrual: yield proportional to locked stake and time, checkpoints on
+//! balance changes, the pool cap and pool funding permissions.
+
+use crate::reward_accrual::SECONDS_PER_YEAR;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env, Symbol};
+
+const BOND: i128 = 1_000_000;
+
+fn setup(e: &Env, pool: i128) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|l| l.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    let minter = token::StellarAssetClient::new(e, &sac.address());
+    minter.mint(&identity, &BOND);
+    minter.mint(&admin, &pool);
+    client.set_token(&admin, &sac.address());
+    client.create_bond_funded(&identity, &BOND, &(2 * SECONDS_PER_YEAR), &false, &0_u64);
+    client.fund_reward_pool(&admin, &pool);
+    // 10% a year.
+    client.set_reward_rate(&admin, &1_000_u32);
+    (client, admin, identity, sac.address())
+}
+
+fn advance(e: &Env, seconds: u64) {
+    e.ledger().with_mut(|l| l.timestamp += seconds);
+}
+
+#[test]
+fn test_yield_proportional_to_amount_and_time() {
+    let e = Env::default();
+    let (client, _admin, identity, token) = setup(&e, BOND);
+    advance(&e, SECONDS_PER_YEAR / 2);
+    assert_eq!(client.get_pending_rewards(&identity), 50_000);
+    advance(&e, SECONDS_PER_YEAR / 2);
+    assert_eq!(client.get_pending_rewards(&identity), 100_000);
+
+    assert_eq!(client.claim_rewards(&identity), 100_000);
+    assert_eq!(client.get_reward_pool(), BOND - 100_000);
+    assert_eq!(client.get_pending_rewards(&identity), 0);
+    let token = token::Client::new(&e, &token);
+    assert_eq!(token.balance(&identity), 100_000);
+    assert_eq!(token.balance(&client.address), 2 * BOND - 100_000);
+}
+
+#[test]
+fn test_balance_changes_checkpoint_accrual() {
+    let e = Env::default();
+    let (client, _admin, identity, _token) = setup(&e, BOND);
+    advance(&e, SECONDS_PER_YEAR / 2);
+    client.withdraw(&(BOND / 2));
+    // The first half year was credited at the full balance.
+    assert_eq!(client.get_rewards_balance(&identity), 50_000);
+    advance(&e, SECONDS_PER_YEAR / 2);
+    assert_eq!(client.get_pending_rewards(&identity), 75_000);
+}
+
+#[test]
+fn test_rate_change_applies_from_now() {
+    let e = Env::default();
+    let (client, admin, identity, _token) = setup(&e, BOND);
+    advance(&e, SECONDS_PER_YEAR / 2);
+    client.set_reward_rate(&admin, &0_u32);
+    advance(&e, SECONDS_PER_YEAR / 2);
+    assert_eq!(client.get_pending_rewards(&identity), 50_000);
+}
+
+#[test]
+fn test_yield_capped_by_pool() {
+    let e = Env::default();
+    let (client, _admin, identity, _token) = setup(&e, 10);
+    advance(&e, SECONDS_PER_YEAR);
+    assert_eq!(client.get_pending_rewards(&identity), 10);
+    assert_eq!(client.claim_rewards(&identity), 10);
+    assert_eq!(client.get_reward_pool(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #122)")]
+fn test_claim_without_rewards() {
+    let e = Env::default();
+    let (client, _admin, identity, _token) = setup(&e, BOND);
+    client.claim_rewards(&identity);
+}
+
+#[test]
+fn test_pool_funding_permissions() {
+    let e = Env::default();
+    let (client, admin, _identity, token) = setup(&e, BOND);
+    let treasury = Address::generate(&e);
+    token::StellarAssetClient::new(&e, &token).mint(&treasury, &500);
+    assert_eq!(
+        client.try_fund_reward_pool(&treasury, &500),
+        Err(Ok(BondError::Unauthorized))
+    );
+    client.set_role_address(
+        &admin,
+        &Symbol::new(&e, "treasury"),
+        &Some(treasury.clone()),
+    );
+    assert_eq!(client.fund_reward_pool(&treasury, &500), BOND + 500);
+    assert_eq!(client.get_reserved_balance(), BOND + 500);
+    assert_eq!(
+        client.try_set_reward_rate(&admin, &10_001_u32),
+        Err(Ok(BondError::InvalidConfig))
+    );
+}
@@ -1,10 +1,13 @@
-//! Tests for Rolling Bond: auto-renewal, withdrawal request with notice period, renewal events.
+//! Tests for Rolling Bond: auto-renewal, withdrawal request with notice period and its
+//! cancellation, renewal events and keeper batch renewal.
 
-use crate::{CredenceBond, CredenceBondClient};
-use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{Address, Env};
+use crate::rolling_bond::{self, PeriodMode, THIRTY_DAYS};
+use crate::{time_math, BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
     let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
@@ -36,7 +39,7 @@ fn test_request_withdrawal() {
 }
 
 #[test]
-#[should_panic(expected = "not a rolling bond")]
+#[should_panic(expected = "Error(Contract, #112)")]
 fn test_request_withdrawal_non_rolling() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
@@ -46,7 +49,7 @@ fn test_request_withdrawal_non_rolling() {
 }
 
 #[test]
-#[should_panic(expected = "withdrawal already requested")]
+#[should_panic(expected = "Error(Contract, #110)")]
 fn test_request_withdrawal_twice() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
@@ -109,3 +112,324 @@ fn test_withdraw_after_notice_period() {
     let bond = client.withdraw(&500);
     assert_eq!(bond.bonded_amount, 500);
 }
+
+#[test]
+fn test_renewal_bonus_bps_capped() {
+    use crate::rolling_bond::renewal_bonus_bps;
+    assert_eq!(renewal_bonus_bps(0, 100, 5), 0);
+    assert_eq!(renewal_bonus_bps(3, 100, 5), 300);
+    assert_eq!(renewal_bonus_bps(9, 100, 5), 500);
+}
+
+#[test]
+fn test_renewal_bonus_raises_effective_tier_not_balance() {
+    use crate::BondTier;
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin) = setup(&e);
+    client.set_renewal_bonus_config(&admin, &500_u32, &4_u32);
+    let identity = Address::generate(&e);
+    // Just below the Silver threshold.
+    client.create_bond(&identity, &950_000_000_i128, &100_u64, &true, &10_u64);
+    assert_eq!(client.get_tier(), BondTier::Bronze);
+
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    client.renew_if_rolling();
+    assert_eq!(client.get_renewal_count(), 1);
+    assert_eq!(client.get_effective_tier_amount(), 997_500_000);
+    assert_eq!(client.get_tier(), BondTier::Bronze);
+
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    client.renew_if_rolling();
+    assert_eq!(client.get_effective_tier_amount(), 1_045_000_000);
+    assert_eq!(client.get_tier(), BondTier::Silver);
+    assert_eq!(client.get_identity_state().bonded_amount, 950_000_000);
+}
+
+#[test]
+fn test_renewal_bonus_stops_after_max_renewals() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin) = setup(&e);
+    client.set_renewal_bonus_config(&admin, &100_u32, &2_u32);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &10_000_i128, &100_u64, &true, &10_u64);
+    for t in [1100_u64, 1200, 1300] {
+        e.ledger().with_mut(|li| li.timestamp = t);
+        client.renew_if_rolling();
+    }
+    assert_eq!(client.get_renewal_count(), 3);
+    assert_eq!(client.get_effective_tier_amount(), 10_200);
+}
+
+#[test]
+fn test_renewal_bonus_ignored_for_non_rolling() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_renewal_bonus_config(&admin, &100_u32, &2_u32);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &10_000_i128, &100_u64, &false, &0_u64);
+    assert_eq!(client.get_effective_tier_amount(), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_renewal_bonus_config_bounded() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_renewal_bonus_config(&admin, &5_000_u32, &3_u32);
+}
+
+#[test]
+fn test_auto_exit_requests_withdrawal_at_target_period() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond_with_auto_exit(&identity, &1000_i128, &100_u64, &10_u64, &2_u32);
+    assert_eq!(client.get_auto_exit_period(), Some(2));
+
+    // End of period 1: normal renewal.
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    let bond = client.renew_if_rolling();
+    assert_eq!(bond.bond_start, 1100);
+    assert_eq!(bond.withdrawal_requested_at, 0);
+
+    // End of period 2: treated as a withdrawal request at the period end.
+    e.ledger().with_mut(|li| li.timestamp = 1205);
+    let bond = client.renew_if_rolling();
+    assert_eq!(bond.bond_start, 1100);
+    assert_eq!(bond.withdrawal_requested_at, 1200);
+    assert_eq!(client.get_renewal_count(), 1);
+
+    // Further calls do not renew; notice is counted from the period end.
+    e.ledger().with_mut(|li| li.timestamp = 1210);
+    let bond = client.renew_if_rolling();
+    assert_eq!(bond.withdrawal_requested_at, 1200);
+    let bond = client.withdraw(&1000_i128);
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+fn test_new_bond_clears_auto_exit() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond_with_auto_exit(&identity, &1000_i128, &100_u64, &10_u64, &1_u32);
+    client.request_withdrawal();
+    e.ledger().with_mut(|li| li.timestamp += 10);
+    client.withdraw_bond(&identity);
+    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
+    assert_eq!(client.get_auto_exit_period(), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_auto_exit_zero_period_rejected() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond_with_auto_exit(&identity, &1000_i128, &100_u64, &10_u64, &0_u32);
+}
+
+#[test]
+fn test_period_boundaries_fixed() {
+    let mode = PeriodMode::Fixed(THIRTY_DAYS);
+    assert_eq!(
+        rolling_bond::boundary(mode, 1000, 2),
+        1000 + 2 * THIRTY_DAYS
+    );
+    assert_eq!(rolling_bond::period_start_at(mode, 1000, 999), 1000);
+    assert_eq!(
+        rolling_bond::period_start_at(mode, 1000, 1000 + THIRTY_DAYS - 1),
+        1000
+    );
+    // A boundary starts the next period.
+    assert_eq!(
+        rolling_bond::period_start_at(mode, 1000, 1000 + THIRTY_DAYS),
+        1000 + THIRTY_DAYS
+    );
+    assert_eq!(
+        rolling_bond::next_boundary(mode, 1000, 1000 + THIRTY_DAYS),
+        1000 + 2 * THIRTY_DAYS
+    );
+}
+
+#[test]
+fn test_period_boundaries_calendar_month() {
+    let mode = PeriodMode::CalendarMonth;
+    let anchor = time_math::timestamp_from_civil(2024, 1, 31, 60);
+    let feb29 = time_math::timestamp_from_civil(2024, 2, 29, 60);
+    let mar31 = time_math::timestamp_from_civil(2024, 3, 31, 60);
+    assert_eq!(rolling_bond::next_boundary(mode, anchor, anchor), feb29);
+    assert_eq!(
+        rolling_bond::period_start_at(mode, anchor, feb29 - 1),
+        anchor
+    );
+    assert_eq!(rolling_bond::period_start_at(mode, anchor, feb29), feb29);
+    // Mar 1..30 lies in the period that started Feb 29, although its day is past the
+    // anchor's clamped day.
+    let mar30 = time_math::timestamp_from_civil(2024, 3, 30, 0);
+    assert_eq!(rolling_bond::period_start_at(mode, anchor, mar30), feb29);
+    assert_eq!(rolling_bond::next_boundary(mode, anchor, mar30), mar31);
+    assert_eq!(rolling_bond::period_index_at(mode, anchor, mar31), 2);
+}
+
+#[test]
+fn test_create_bond_with_calendar_month_period() {
+    let e = Env::default();
+    let start = time_math::timestamp_from_civil(2023, 1, 31, 0);
+    e.ledger().with_mut(|li| li.timestamp = start);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    let bond = client.create_bond_with_period_mode(
+        &identity,
+        &1000_i128,
+        &10_u64,
+        &PeriodMode::CalendarMonth,
+    );
+    assert!(bond.is_rolling);
+    assert_eq!(bond.bond_start, start);
+    assert_eq!(bond.bond_duration, 28 * time_math::SECONDS_PER_DAY);
+    assert_eq!(
+        client.get_period_mode(),
+        Some((PeriodMode::CalendarMonth, start))
+    );
+
+    let feb28 = time_math::timestamp_from_civil(2023, 2, 28, 0);
+    e.ledger().with_mut(|li| li.timestamp = feb28 - 1);
+    assert_eq!(client.renew_if_rolling().bond_start, start);
+    e.ledger().with_mut(|li| li.timestamp = feb28);
+    let bond = client.renew_if_rolling();
+    assert_eq!(bond.bond_start, feb28);
+    assert_eq!(bond.bond_duration, 31 * time_math::SECONDS_PER_DAY);
+}
+
+#[test]
+fn test_anchored_renewal_does_not_drift() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond_with_period_mode(
+        &identity,
+        &1000_i128,
+        &10_u64,
+        &PeriodMode::Fixed(THIRTY_DAYS),
+    );
+    // Renewed five days late: the new period still starts on the boundary.
+    let late = 1000 + THIRTY_DAYS + 5 * time_math::SECONDS_PER_DAY;
+    e.ledger().with_mut(|li| li.timestamp = late);
+    let bond = client.renew_if_rolling();
+    assert_eq!(bond.bond_start, 1000 + THIRTY_DAYS);
+    assert_eq!(bond.bond_duration, THIRTY_DAYS);
+    // Missed periods are skipped rather than replayed.
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1000 + 3 * THIRTY_DAYS + 1);
+    let bond = client.renew_if_rolling();
+    assert_eq!(bond.bond_start, 1000 + 3 * THIRTY_DAYS);
+}
+
+#[test]
+fn test_unanchored_renewal_starts_at_renewal_time() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
+    assert_eq!(client.get_period_mode(), None);
+    e.ledger().with_mut(|li| li.timestamp = 1150);
+    let bond = client.renew_if_rolling();
+    assert_eq!(bond.bond_start, 1150);
+    assert_eq!(bond.bond_duration, 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_zero_fixed_period_rejected() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond_with_period_mode(&identity, &1000_i128, &10_u64, &PeriodMode::Fixed(0));
+}
+
+#[test]
+fn test_cancel_withdrawal_request() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
+    client.request_withdrawal();
+    e.ledger().with_mut(|li| li.timestamp = 1009);
+
+    let bond = client.cancel_withdrawal_request();
+    assert_eq!(bond.withdrawal_requested_at, 0);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "withdrawal_request_cancelled"),).into_val(&e)
+    );
+    assert_eq!(
+        client.try_withdraw(&100_i128).err(),
+        Some(Ok(BondError::WithdrawalNotRequested))
+    );
+    // A new request starts a fresh notice period.
+    assert_eq!(client.request_withdrawal().withdrawal_requested_at, 1009);
+}
+
+#[test]
+fn test_cancel_withdrawal_request_rejected() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
+    assert_eq!(
+        client.try_cancel_withdrawal_request().err(),
+        Some(Ok(BondError::WithdrawalNotRequested))
+    );
+    client.request_withdrawal();
+    e.ledger().with_mut(|li| li.timestamp = 1010);
+    assert_eq!(
+        client.try_cancel_withdrawal_request().err(),
+        Some(Ok(BondError::LockupElapsed))
+    );
+}
+
+#[test]
+fn test_renew_rolling_batch() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
+    let stranger = Address::generate(&e);
+    let batch = vec![&e, stranger.clone(), identity.clone(), identity.clone()];
+
+    // Period still running: nothing to renew.
+    assert_eq!(client.renew_rolling_batch(&batch), 0);
+
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    // Renewed once; the duplicate finds a fresh period.
+    assert_eq!(client.renew_rolling_batch(&batch), 1);
+    assert_eq!(client.get_identity_state().bond_start, 1100);
+}
+
+#[test]
+fn test_renew_rolling_batch_skips_non_rolling() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    assert_eq!(client.renew_rolling_batch(&vec![&e, identity]), 0);
+    assert_eq!(
+        client.try_renew_rolling_batch(&vec![&e]),
+        Err(Ok(BondError::InvalidInput))
+    );
+}
@@ -0,0 +1,65 @@
+//! Tests for storage schema versioning and the `migrate` entrypoint.
+
+use crate::schema_version::{SchemaKey, CURRENT_VERSION};
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+/// Simulate an instance deployed before versioning.
+fn make_legacy(e: &Env, client: &CredenceBondClient<'_>) {
+    e.as_contract(&client.address, || {
+        e.storage().instance().remove(&SchemaKey::Version);
+    });
+}
+
+#[test]
+fn test_initialize_stamps_current_version() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.get_schema_version(), CURRENT_VERSION);
+    assert_eq!(client.export_config().schema_version, CURRENT_VERSION);
+}
+
+#[test]
+fn test_migrate_legacy_instance_is_idempotent() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    make_legacy(&e, &client);
+    assert_eq!(client.get_schema_version(), 0);
+
+    assert_eq!(client.migrate(&admin, &0_u32), CURRENT_VERSION);
+    assert_eq!(client.get_schema_version(), CURRENT_VERSION);
+    assert_eq!(client.get_identity_state().bonded_amount, 1000);
+
+    // Re-running is a no-op.
+    assert_eq!(client.migrate(&admin, &0_u32), CURRENT_VERSION);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #121)")]
+fn test_migrate_rejects_wrong_source_version() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    make_legacy(&e, &client);
+    client.migrate(&admin, &7_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_migrate_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    make_legacy(&e, &client);
+    client.migrate(&Address::generate(&e), &0_u32);
+}
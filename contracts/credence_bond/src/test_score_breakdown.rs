@@ -0,0 +1,113 @@
+//! Tests for the subject score breakdown: per-claim weights and each deduction add up to the
+//! aggregate weight.
+
+use crate::{testutils, BondTier, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &2_000_000_000_i128, &86400_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+fn attester(client: &CredenceBondClient<'_>, e: &Env) -> Address {
+    let a = Address::generate(e);
+    client.register_attester(&a);
+    a
+}
+
+fn add(client: &CredenceBondClient<'_>, attester: &Address, subject: &Address, data: &str) -> u64 {
+    let nonce = client.get_nonce(attester);
+    let data = String::from_str(&client.env, data);
+    client.add_attestation(attester, subject, &data, &nonce).id
+}
+
+#[test]
+fn test_breakdown_groups_by_claim() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let a = attester(&client, &e);
+    let b = attester(&client, &e);
+    add(&client, &a, &identity, "kyc");
+    add(&client, &b, &identity, "kyc");
+    add(&client, &a, &identity, "aml");
+    let consumer = testutils::query_consumer(&e, &client.address);
+
+    let breakdown = client.explain_score(&consumer, &identity);
+    assert_eq!(breakdown.claims.len(), 2);
+    let aml = breakdown.claims.get(0).unwrap();
+    let kyc = breakdown.claims.get(1).unwrap();
+    assert_eq!(aml.claim, String::from_str(&e, "aml"));
+    assert_eq!(aml.attestation_count, 1);
+    assert_eq!(kyc.attestation_count, 2);
+    assert_eq!(breakdown.raw_weight, aml.raw_weight + kyc.raw_weight);
+    assert_eq!(breakdown.counter_deduction, 0);
+    assert_eq!(breakdown.verifier_cap_deduction, 0);
+    assert_eq!(breakdown.aggregate_weight, breakdown.raw_weight);
+    assert_eq!(
+        breakdown.aggregate_weight,
+        client.get_subject_aggregate_weight(&consumer, &identity)
+    );
+    assert_eq!(breakdown.tier, BondTier::Silver);
+    assert_eq!(breakdown.slash_ratio_bps, 0);
+}
+
+#[test]
+fn test_breakdown_shows_counter_and_cap_deductions() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let a = attester(&client, &e);
+    let b = attester(&client, &e);
+    let original = add(&client, &a, &identity, "kyc");
+    add(&client, &a, &identity, "aml");
+    let nonce = client.get_nonce(&b);
+    let counter_id = client.counter_attest(&b, &original, &String::from_str(&e, "forged"), &nonce);
+    let counter_weight = client.get_counter_attestation(&counter_id).unwrap().weight as u64;
+    client.set_verifier_weight_cap(&admin, &5_000_u32);
+    let consumer = testutils::query_consumer(&e, &client.address);
+
+    let breakdown = client.explain_score(&consumer, &identity);
+    assert_eq!(breakdown.counter_deduction, counter_weight);
+    assert!(breakdown.verifier_cap_deduction > 0);
+    assert_eq!(
+        breakdown.aggregate_weight,
+        breakdown.raw_weight - breakdown.counter_deduction - breakdown.verifier_cap_deduction
+    );
+    assert_eq!(
+        breakdown.aggregate_weight,
+        client.get_subject_aggregate_weight(&consumer, &identity)
+    );
+}
+
+#[test]
+fn test_breakdown_reports_expired_and_pending_weight() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let a = attester(&client, &e);
+    let template =
+        client.create_template(&admin, &BytesN::from_array(&e, &[1; 32]), &50_u32, &100_u64);
+    let nonce = client.get_nonce(&a);
+    let expiring = client.add_attestation_with_template(
+        &a,
+        &identity,
+        &template,
+        &String::from_str(&e, "kyc"),
+        &nonce,
+    );
+    e.ledger().with_mut(|l| l.timestamp += 100);
+    client.set_challenge_window(&admin, &3600_u64);
+    add(&client, &a, &identity, "aml");
+    let consumer = testutils::query_consumer(&e, &client.address);
+
+    let breakdown = client.explain_score(&consumer, &identity);
+    assert_eq!(breakdown.expired_weight, expiring.weight as u64);
+    assert!(breakdown.pending_weight > 0);
+    assert_eq!(breakdown.claims.len(), 0);
+    assert_eq!(breakdown.aggregate_weight, 0);
+}
@@ -0,0 +1,253 @@
+//! Tests for slash appeals: pending governance slashes, appeal through the dispute contract,
+//! resolution and finalization into treasury settlement.
+
+use crate::dispute_linkage::LinkedDisputeOutcome;
+use crate::slash_appeal::AppealStatus;
+use crate::slash_records::SlashSource;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, token, vec, Address, Env};
+
+const BOND: i128 = 1_000_000;
+const SLASH: i128 = 100_000;
+const WINDOW: u64 = 3_600;
+
+/// Dispute contract stand-in recording the last `create_slash_dispute` call.
+#[contract]
+pub struct MockDispute;
+
+#[contractimpl]
+impl MockDispute {
+    pub fn create_slash_dispute(
+        e: Env,
+        disputer: Address,
+        bond_contract: Address,
+        slash_request_id: u64,
+        stake: i128,
+        _token: Address,
+        _resolution_deadline: u64,
+    ) -> u64 {
+        disputer.require_auth();
+        bond_contract.require_auth();
+        e.storage()
+            .instance()
+            .set(&0_u32, &(disputer, bond_contract, slash_request_id, stake));
+        9
+    }
+
+    pub fn last(e: Env) -> (Address, Address, u64, i128) {
+        e.storage().instance().get(&0_u32).unwrap()
+    }
+
+    /// Deliver a resolution to the bond contract, as the dispute contract does.
+    pub fn resolve(e: Env, outcome: LinkedDisputeOutcome) {
+        let (_, bond, proposal_id, _): (Address, Address, u64, i128) =
+            e.storage().instance().get(&0_u32).unwrap();
+        CredenceBondClient::new(&e, &bond).resolve_slash_appeal(
+            &proposal_id,
+            &outcome,
+            &e.current_contract_address(),
+        );
+    }
+}
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    identity: Address,
+    treasury: Address,
+    dispute: Address,
+    token: token::Client<'a>,
+}
+
+/// Funded bond with an approved slash proposal 0, a treasury settling automatically and a
+/// one-hour appeal window.
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    token::StellarAssetClient::new(e, &sac.address()).mint(&identity, &BOND);
+    client.set_token(&admin, &sac.address());
+    client.create_bond_funded(&identity, &BOND, &86_400_u64, &false, &0_u64);
+    let governor = Address::generate(e);
+    client.initialize_governance(&admin, &vec![e, governor.clone()], &5100, &1, &0);
+    client.propose_slash(&admin, &identity, &SLASH);
+    client.governance_vote(&governor, &0_u64, &true);
+    let treasury = Address::generate(e);
+    client.set_slash_settlement(&admin, &treasury, &false, &true);
+    client.set_slash_appeal_window(&admin, &WINDOW);
+    let dispute = e.register(MockDispute, ());
+    client.set_conflict_dispute_contract(&admin, &dispute);
+    Setup {
+        client,
+        admin,
+        identity,
+        treasury,
+        dispute,
+        token: token::Client::new(e, &sac.address()),
+    }
+}
+
+#[test]
+fn test_governance_slash_held_until_window_elapses() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.execute_slash_with_governance(&s.admin, &0);
+    assert_eq!(s.client.get_identity_state().slashed_amount, SLASH);
+    assert_eq!(s.token.balance(&s.treasury), 0);
+    assert_eq!(s.client.get_pending_slash_proceeds(), 0);
+
+    let pending = s.client.get_pending_slash(&0).unwrap();
+    assert_eq!(pending.status, AppealStatus::Pending);
+    assert_eq!(pending.finalize_after, 1000 + WINDOW);
+    assert_eq!(
+        s.client.try_finalize_slash(&0),
+        Err(Ok(BondError::NoticeNotElapsed))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp += WINDOW);
+    let finalized = s.client.finalize_slash(&0);
+    assert_eq!(finalized.status, AppealStatus::Finalized);
+    assert_eq!(s.token.balance(&s.treasury), SLASH);
+    assert_eq!(
+        s.client.try_finalize_slash(&0),
+        Err(Ok(BondError::InvalidDisputeState))
+    );
+}
+
+#[test]
+fn test_no_window_finalizes_immediately() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.set_slash_appeal_window(&s.admin, &0);
+    s.client.execute_slash_with_governance(&s.admin, &0);
+    assert_eq!(s.client.get_pending_slash(&0), None);
+    assert_eq!(s.token.balance(&s.treasury), SLASH);
+}
+
+#[test]
+fn test_appeal_opens_dispute_and_blocks_finalization() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.execute_slash_with_governance(&s.admin, &0);
+    let dispute_id = s.client.appeal_slash(&s.identity, &0, &500, &86_400);
+    assert_eq!(dispute_id, 9);
+    assert_eq!(
+        MockDisputeClient::new(&e, &s.dispute).last(),
+        (s.identity.clone(), s.client.address.clone(), 0, 500)
+    );
+    assert_eq!(
+        s.client.get_dispute_status(&s.identity).open_dispute_ids,
+        vec![&e, 9_u64]
+    );
+
+    e.ledger().with_mut(|li| li.timestamp += WINDOW);
+    assert_eq!(
+        s.client.try_finalize_slash(&0),
+        Err(Ok(BondError::InvalidDisputeState))
+    );
+    assert_eq!(
+        s.client
+            .try_reverse_slash(&SlashSource::Proposal(0), &SLASH, &s.admin),
+        Err(Ok(BondError::InvalidDisputeState))
+    );
+}
+
+#[test]
+fn test_appeal_upheld_finalizes_slash() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.execute_slash_with_governance(&s.admin, &0);
+    s.client.appeal_slash(&s.identity, &0, &500, &86_400);
+    MockDisputeClient::new(&e, &s.dispute).resolve(&LinkedDisputeOutcome::FavorSlasher);
+    assert_eq!(
+        s.client.get_pending_slash(&0).unwrap().status,
+        AppealStatus::Finalized
+    );
+    assert_eq!(s.token.balance(&s.treasury), SLASH);
+}
+
+#[test]
+fn test_appeal_won_reverses_slash() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.execute_slash_with_governance(&s.admin, &0);
+    s.client.appeal_slash(&s.identity, &0, &500, &86_400);
+    let resolved =
+        s.client
+            .resolve_slash_appeal(&0, &LinkedDisputeOutcome::FavorDisputer, &s.admin);
+    assert_eq!(resolved.status, AppealStatus::Overturned);
+    assert_eq!(s.client.get_identity_state().slashed_amount, 0);
+    assert_eq!(
+        s.client
+            .get_slash_record(&SlashSource::Proposal(0))
+            .unwrap()
+            .reversed,
+        SLASH
+    );
+    assert_eq!(s.client.get_pending_slash_proceeds(), 0);
+    assert_eq!(
+        s.client
+            .get_dispute_status(&s.identity)
+            .last_dispute_outcome,
+        LinkedDisputeOutcome::FavorDisputer
+    );
+    assert_eq!(
+        s.client.try_finalize_slash(&0),
+        Err(Ok(BondError::InvalidDisputeState))
+    );
+}
+
+#[test]
+fn test_appeal_rules() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.execute_slash_with_governance(&s.admin, &0);
+    let outsider = Address::generate(&e);
+    assert_eq!(
+        s.client.try_appeal_slash(&outsider, &0, &500, &86_400),
+        Err(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_resolve_slash_appeal(&0, &LinkedDisputeOutcome::FavorSlasher, &s.admin),
+        Err(Ok(BondError::InvalidDisputeState))
+    );
+    assert_eq!(
+        s.client
+            .try_resolve_slash_appeal(&0, &LinkedDisputeOutcome::FavorSlasher, &outsider),
+        Err(Ok(BondError::Unauthorized))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp += WINDOW);
+    assert_eq!(
+        s.client.try_appeal_slash(&s.identity, &0, &500, &86_400),
+        Err(Ok(BondError::ClaimExpired))
+    );
+}
+
+#[test]
+fn test_appeal_window_bounded() {
+    let e = Env::default();
+    let s = setup(&e);
+    assert_eq!(
+        s.client
+            .try_set_slash_appeal_window(&s.admin, &(30 * 86_400 + 1)),
+        Err(Ok(BondError::InvalidConfig))
+    );
+    assert_eq!(s.client.get_slash_appeal_window(), WINDOW);
+}
+
+#[test]
+fn test_appeal_window_independent_of_challenge_window() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.set_challenge_window(&s.admin, &60);
+    assert_eq!(s.client.get_slash_appeal_window(), WINDOW);
+    assert_eq!(s.client.get_challenge_window(), 60);
+}
@@ -0,0 +1,102 @@
+//! Tests for the slash history ledger: one record per executed slash, linked to the
+//! proposal or dispute it executed, paginated per identity.
+
+use crate::slash_history::{SlashHistoryRecord, SlashReason};
+use crate::slash_records::SlashSource;
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1_000_i128, &86400_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_admin_slash_recorded() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.slash(&admin, &100_i128);
+    assert_eq!(
+        client.get_slash_history_record(&0),
+        Some(SlashHistoryRecord {
+            id: 0,
+            identity: identity.clone(),
+            amount: 100,
+            executed_by: admin,
+            reason: SlashReason::Admin,
+            timestamp: 1000,
+            proposal_id: None,
+            dispute_id: None,
+        })
+    );
+    assert_eq!(client.get_slash_history_count(&identity), 1);
+    assert_eq!(client.get_slash_history_record(&1), None);
+}
+
+#[test]
+fn test_capped_and_empty_slashes() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.slash(&admin, &5_000_i128);
+    // Nothing left to slash, so nothing is recorded.
+    client.slash(&admin, &10_i128);
+    assert_eq!(client.get_slash_history_count(&identity), 1);
+    assert_eq!(client.get_slash_history_record(&0).unwrap().amount, 1_000);
+}
+
+#[test]
+fn test_governance_slash_linked_to_proposal() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let governor = Address::generate(&e);
+    client.initialize_governance(
+        &admin,
+        &vec![&e, governor.clone()],
+        &5100_u32,
+        &1_u32,
+        &0_u64,
+    );
+    client.propose_slash(&admin, &identity, &100_i128);
+    client.governance_vote(&governor, &0_u64, &true);
+    client.execute_slash_with_governance(&admin, &0_u64);
+    client.slash_for_source(&admin, &50_i128, &SlashSource::Dispute(9));
+
+    let page = client.get_slash_history_page(&identity, &0, &10);
+    assert_eq!(page.len(), 2);
+    let governance = page.get_unchecked(0);
+    assert_eq!(governance.reason, SlashReason::Proposal);
+    assert_eq!(governance.proposal_id, Some(0));
+    assert_eq!(governance.amount, 100);
+    let dispute = page.get_unchecked(1);
+    assert_eq!(dispute.reason, SlashReason::Dispute);
+    assert_eq!(dispute.dispute_id, Some(9));
+    assert_eq!(dispute.proposal_id, None);
+}
+
+#[test]
+fn test_history_pagination() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    for _ in 0..5 {
+        client.slash(&admin, &10_i128);
+    }
+    let page = client.get_slash_history_page(&identity, &3, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get_unchecked(0).id, 3);
+    assert_eq!(page.get_unchecked(1).id, 4);
+    assert_eq!(client.get_slash_history_page(&identity, &5, &10).len(), 0);
+    assert_eq!(
+        client
+            .get_slash_history_page(&Address::generate(&e), &0, &10)
+            .len(),
+        0
+    );
+}
@@ -0,0 +1,159 @@
+//! Tests for slash idempotency: a proposal or dispute decision changes the bond balance at
+//! most once, whether it is executed through governance or the admin path, and slashes
+//! without a source are refused once governance is on.
+
+use crate::slash_records::{SlashRecord, SlashSource};
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1_000_i128, &86400_u64, &false, &0_u64);
+    let governor = Address::generate(e);
+    client.initialize_governance(
+        &admin,
+        &vec![e, governor.clone()],
+        &5100_u32,
+        &1_u32,
+        &0_u64,
+    );
+    client.propose_slash(&admin, &identity, &100_i128);
+    client.governance_vote(&governor, &0_u64, &true);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_governance_execution_is_recorded() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.execute_slash_with_governance(&admin, &0_u64);
+    assert_eq!(
+        client.get_slash_record(&SlashSource::Proposal(0)),
+        Some(SlashRecord {
+            source: SlashSource::Proposal(0),
+            identity,
+            amount: 100,
+            executed_at: 1000,
+            reversed: 0,
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #308)")]
+fn test_admin_path_after_governance_execution() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.execute_slash_with_governance(&admin, &0_u64);
+    client.slash_for_source(&admin, &100_i128, &SlashSource::Proposal(0));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #308)")]
+fn test_governance_execution_after_admin_path() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.slash_for_source(&admin, &100_i128, &SlashSource::Proposal(0));
+    client.execute_slash_with_governance(&admin, &0_u64);
+}
+
+#[test]
+fn test_single_balance_change_across_paths() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.execute_slash_with_governance(&admin, &0_u64);
+    let replay = client.try_slash_for_source(&admin, &100_i128, &SlashSource::Proposal(0));
+    assert!(replay.is_err());
+    assert_eq!(client.get_identity_state().slashed_amount, 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #308)")]
+fn test_dispute_slash_applied_once() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.slash_for_source(&admin, &200_i128, &SlashSource::Dispute(7));
+    client.slash_for_source(&admin, &200_i128, &SlashSource::Dispute(7));
+}
+
+#[test]
+fn test_distinct_sources_each_apply() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.slash_for_source(&admin, &200_i128, &SlashSource::Dispute(7));
+    client.slash_for_source(&admin, &300_i128, &SlashSource::Dispute(8));
+    client.execute_slash_with_governance(&admin, &0_u64);
+    assert_eq!(client.get_identity_state().slashed_amount, 600);
+}
+
+#[test]
+fn test_record_stores_capped_amount() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.slash_for_source(&admin, &950_i128, &SlashSource::Dispute(2));
+    client.slash_for_source(&admin, &200_i128, &SlashSource::Dispute(1));
+    assert_eq!(
+        client
+            .get_slash_record(&SlashSource::Dispute(1))
+            .unwrap()
+            .amount,
+        50
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #303)")]
+fn test_unknown_proposal_rejected() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.slash_for_source(&admin, &100_i128, &SlashSource::Proposal(9));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_non_admin_cannot_slash_for_source() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    client.slash_for_source(&Address::generate(&e), &100_i128, &SlashSource::Dispute(1));
+}
+
+#[test]
+fn test_slash_for_source_requires_admin_signature() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    e.set_auths(&[]);
+    assert!(client
+        .try_slash_for_source(&admin, &100_i128, &SlashSource::Dispute(1))
+        .is_err());
+    assert_eq!(client.get_slash_record(&SlashSource::Dispute(1)), None);
+    assert_eq!(client.get_identity_state().slashed_amount, 0);
+}
+
+#[test]
+fn test_unreferenced_slash_refused_under_governance() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.execute_slash_with_governance(&admin, &0_u64);
+    assert_eq!(
+        client.try_slash(&admin, &100_i128).err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_slash_bond(&admin, &100_i128).err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(
+        client
+            .try_slash_with_beneficiary(&admin, &100_i128, &Address::generate(&e))
+            .err(),
+        Some(Ok(BondError::Unauthorized))
+    );
+    assert_eq!(client.get_identity_state().slashed_amount, 100);
+}
@@ -0,0 +1,114 @@
+//! Tests for partial slash reversal: restoring an active bond, refund crediting once the bond
+//! is closed, record accounting and authorization.
+
+use crate::slash_records::SlashSource;
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1_000_i128, &86400_u64, &false, &0_u64);
+    client.slash_for_source(&admin, &400_i128, &SlashSource::Dispute(7));
+    (client, admin, identity)
+}
+
+#[test]
+fn test_partial_reversal_restores_active_bond() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let record = client.reverse_slash(&SlashSource::Dispute(7), &200_i128, &admin);
+    assert_eq!(record.amount, 400);
+    assert_eq!(record.reversed, 200);
+    assert_eq!(client.get_identity_state().slashed_amount, 200);
+    assert_eq!(client.get_slash_refund(&identity), 0);
+    assert_eq!(
+        client
+            .get_slash_record(&SlashSource::Dispute(7))
+            .unwrap()
+            .reversed,
+        200
+    );
+}
+
+#[test]
+fn test_reversal_emits_linked_event() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.reverse_slash(&SlashSource::Dispute(7), &100_i128, &admin);
+    let expected_topics = (Symbol::new(&e, "slash_reversed"), SlashSource::Dispute(7)).into_val(&e);
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("slash_reversed not emitted");
+    let data: (Address, i128, i128, i128) = data.into_val(&e);
+    assert_eq!(data, (admin, 100, 100, 0));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_cumulative_reversal_capped_at_slash() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.reverse_slash(&SlashSource::Dispute(7), &300_i128, &admin);
+    client.reverse_slash(&SlashSource::Dispute(7), &101_i128, &admin);
+}
+
+#[test]
+fn test_reversal_after_close_credits_refund() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp += 86400);
+    client.withdraw_bond(&identity);
+    let reserved_before = client.get_reserved_balance();
+
+    client.reverse_slash(&SlashSource::Dispute(7), &150_i128, &admin);
+    assert_eq!(client.get_identity_state().slashed_amount, 400);
+    assert_eq!(client.get_slash_refund(&identity), 150);
+    assert_eq!(client.get_reserved_balance(), reserved_before + 150);
+
+    assert_eq!(client.claim_slash_refund(&identity), 150);
+    assert_eq!(client.get_slash_refund(&identity), 0);
+    assert_eq!(client.get_reserved_balance(), reserved_before);
+}
+
+#[test]
+fn test_dispute_contract_may_reverse_dispute_slash() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    let dispute_contract = Address::generate(&e);
+    client.set_conflict_dispute_contract(&admin, &dispute_contract);
+    client.reverse_slash(&SlashSource::Dispute(7), &50_i128, &dispute_contract);
+    assert_eq!(client.get_identity_state().slashed_amount, 350);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_stranger_cannot_reverse() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    client.reverse_slash(&SlashSource::Dispute(7), &50_i128, &Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #309)")]
+fn test_reverse_unknown_slash() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.reverse_slash(&SlashSource::Dispute(8), &50_i128, &admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #122)")]
+fn test_claim_without_refund() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.claim_slash_refund(&identity);
+}
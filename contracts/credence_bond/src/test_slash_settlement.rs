@@ -0,0 +1,186 @@
+//! Tests for slash settlement: pending proceeds, manual and automatic transfer to the
+//! treasury, compensation withholding and treasury notification.
+
+use crate::slash_records::SlashSource;
+use crate::slash_settlement::TreasuryFundSource;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, token, Address, Env};
+
+const BOND: i128 = 1_000_000;
+
+/// Treasury stand-in recording the last `receive_fee` call.
+#[contract]
+pub struct MockTreasury;
+
+#[contractimpl]
+impl MockTreasury {
+    pub fn receive_fee(e: Env, from: Address, amount: i128, source: TreasuryFundSource) {
+        from.require_auth();
+        e.storage().instance().set(&0_u32, &(from, amount, source));
+    }
+
+    pub fn last(e: Env) -> (Address, i128, TreasuryFundSource) {
+        e.storage().instance().get(&0_u32).unwrap()
+    }
+}
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, token::Client<'_>) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    token::StellarAssetClient::new(e, &sac.address()).mint(&identity, &BOND);
+    client.set_token(&admin, &sac.address());
+    client.create_bond_funded(&identity, &BOND, &86_400_u64, &false, &0_u64);
+    (client, admin, token::Client::new(e, &sac.address()))
+}
+
+#[test]
+fn test_settle_transfers_pending_proceeds() {
+    let e = Env::default();
+    let (client, admin, token) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_slash_settlement(&admin, &treasury, &false, &false);
+
+    client.slash(&admin, &300_000);
+    assert_eq!(client.get_pending_slash_proceeds(), 300_000);
+    assert_eq!(token.balance(&treasury), 0);
+
+    assert_eq!(client.settle_slash(), 300_000);
+    assert_eq!(token.balance(&treasury), 300_000);
+    assert_eq!(token.balance(&client.address), BOND - 300_000);
+    assert_eq!(client.get_pending_slash_proceeds(), 0);
+    assert_eq!(client.get_settled_slash_proceeds(), 300_000);
+    assert_eq!(client.settle_slash(), 0);
+}
+
+#[test]
+fn test_over_slash_accrues_only_amount_slashed() {
+    let e = Env::default();
+    let (client, admin, _token) = setup(&e);
+    client.slash(&admin, &(BOND + 500));
+    assert_eq!(client.get_pending_slash_proceeds(), BOND);
+}
+
+#[test]
+fn test_auto_settle_notifies_treasury_contract() {
+    let e = Env::default();
+    let (client, admin, token) = setup(&e);
+    let treasury = e.register(MockTreasury, ());
+    client.set_slash_settlement(&admin, &treasury, &true, &true);
+    assert_eq!(
+        client.export_config().slash_settlement,
+        client.get_slash_settlement()
+    );
+
+    client.slash_bond(&admin, &250_000);
+    assert_eq!(token.balance(&treasury), 250_000);
+    assert_eq!(client.get_pending_slash_proceeds(), 0);
+    assert_eq!(
+        MockTreasuryClient::new(&e, &treasury).last(),
+        (
+            client.address.clone(),
+            250_000,
+            TreasuryFundSource::SlashedFunds
+        )
+    );
+}
+
+#[test]
+fn test_compensation_share_stays_in_escrow() {
+    let e = Env::default();
+    let (client, admin, token) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_compensation_config(&admin, &treasury, &4_000_u32, &100_u64);
+    client.set_slash_settlement(&admin, &treasury, &false, &true);
+
+    let victim = Address::generate(&e);
+    client.slash_with_beneficiary(&admin, &100_000, &victim);
+    assert_eq!(token.balance(&treasury), 60_000);
+    assert_eq!(client.get_settled_slash_proceeds(), 60_000);
+}
+
+#[test]
+fn test_compensation_claim_pays_beneficiary() {
+    let e = Env::default();
+    let (client, admin, token) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_compensation_config(&admin, &treasury, &4_000_u32, &100_u64);
+    client.set_slash_settlement(&admin, &treasury, &false, &true);
+
+    let victim = Address::generate(&e);
+    let slash_id = client.slash_with_beneficiary(&admin, &100_000, &victim);
+    assert_eq!(client.claim_compensation(&victim, &slash_id), 40_000);
+    assert_eq!(token.balance(&victim), 40_000);
+    assert_eq!(token.balance(&client.address), BOND - 100_000);
+}
+
+#[test]
+fn test_expired_compensation_settled_to_treasury() {
+    let e = Env::default();
+    let (client, admin, token) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_compensation_config(&admin, &treasury, &4_000_u32, &100_u64);
+    client.set_slash_settlement(&admin, &treasury, &false, &true);
+
+    let slash_id = client.slash_with_beneficiary(&admin, &100_000, &Address::generate(&e));
+    e.ledger().with_mut(|li| li.timestamp += 101);
+    assert_eq!(client.sweep_expired_compensation(&slash_id), 40_000);
+    assert_eq!(token.balance(&treasury), 100_000);
+    assert_eq!(client.get_pending_slash_proceeds(), 0);
+}
+
+#[test]
+fn test_restored_slash_is_not_settled() {
+    let e = Env::default();
+    let (client, admin, _token) = setup(&e);
+    client.set_slash_settlement(&admin, &Address::generate(&e), &false, &false);
+    let source = SlashSource::Dispute(1);
+    client.slash_for_source(&admin, &200_000, &source);
+    client.reverse_slash(&source, &150_000, &admin);
+    assert_eq!(client.get_pending_slash_proceeds(), 50_000);
+    assert_eq!(client.settle_slash(), 50_000);
+}
+
+#[test]
+fn test_settle_requires_config() {
+    let e = Env::default();
+    let (client, admin, _token) = setup(&e);
+    client.slash(&admin, &1_000);
+    assert_eq!(client.try_settle_slash(), Err(Ok(BondError::ConfigNotSet)));
+}
+
+#[test]
+fn test_set_slash_settlement_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, _token) = setup(&e);
+    let outsider = Address::generate(&e);
+    assert_eq!(
+        client.try_set_slash_settlement(&outsider, &outsider, &false, &false),
+        Err(Ok(BondError::NotAdmin))
+    );
+}
+
+#[test]
+fn test_pending_proceeds_survive_admin_nomination() {
+    let e = Env::default();
+    let (client, admin, _token) = setup(&e);
+    client.slash(&admin, &300_000);
+    client.transfer_admin(&admin, &Address::generate(&e));
+    assert_eq!(client.get_pending_slash_proceeds(), 300_000);
+}
+
+#[test]
+fn test_set_slash_settlement_requires_admin_auth() {
+    let e = Env::default();
+    let (client, admin, _token) = setup(&e);
+    e.set_auths(&[]);
+    let treasury = Address::generate(&e);
+    assert!(client
+        .try_set_slash_settlement(&admin, &treasury, &false, &true)
+        .is_err());
+}
@@ -10,20 +10,22 @@
 //! 7. Integration with withdrawals
 //! 8. Cumulative slashing scenarios
 
-#![cfg(test)]
-//! Comprehensive unit tests for slashing functionality.
+//!
 //! Covers: successful slash, unauthorized rejection, over-slash prevention,
 //! slash history (via events), and slash events.
 
+#![cfg(test)]
+
 use crate::{CredenceBond, CredenceBondClient};
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, Env, IntoVal};
 
 // ============================================================================
 // Test Setup Utilities
 // ============================================================================
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
     let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
@@ -96,10 +98,10 @@ fn test_slash_entire_amount() {
 // ============================================================================
 
 #[test]
-#[should_panic(expected = "not admin")]
+#[should_panic(expected = "Error(Contract, #102)")]
 fn test_slash_unauthorized_rejection() {
     let e = Env::default();
-    let (client, _admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (_client, _admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
 
     let (client, _admin, identity) = setup(&e);
     client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
@@ -108,7 +110,7 @@ fn test_slash_unauthorized_rejection() {
 }
 
 #[test]
-#[should_panic(expected = "not admin")]
+#[should_panic(expected = "Error(Contract, #102)")]
 fn test_slash_unauthorized_different_address() {
     let e = Env::default();
     let (client, _admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
@@ -121,7 +123,40 @@ fn test_slash_unauthorized_different_address() {
 }
 
 #[test]
-#[should_panic(expected = "not admin")]
+fn test_slash_requires_admin_signature() {
+    let e = Env::default();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+
+    // Passing the admin address without the admin's signature is rejected.
+    let beneficiary = Address::generate(&e);
+    assert!(client.try_slash(&admin, &100_i128).is_err());
+    assert!(client.try_slash_bond(&admin, &100_i128).is_err());
+    assert!(client
+        .try_slash_with_beneficiary(&admin, &100_i128, &beneficiary)
+        .is_err());
+    assert_eq!(client.get_identity_state().slashed_amount, 0);
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "slash",
+                args: (&admin, 100_i128).into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .slash(&admin, &100_i128);
+    assert_eq!(client.get_identity_state().slashed_amount, 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
 fn test_slash_identity_cannot_slash_own_bond() {
     let e = Env::default();
     let (client, _admin, identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
@@ -186,7 +221,7 @@ fn test_slash_zero_amount() {
 }
 
 #[test]
-#[should_panic(expected = "slashing caused overflow")]
+#[should_panic(expected = "Error(Contract, #113)")]
 fn test_slash_overflow_prevention() {
     let e = Env::default();
     let (client, admin, _identity) = setup_with_bond(&e, i128::MAX - 100, 86400_u64);
@@ -335,7 +370,7 @@ fn test_withdraw_after_slash_respects_available() {
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #107)")]
 fn test_withdraw_more_than_available_after_slash() {
     let e = Env::default();
     let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
@@ -346,7 +381,7 @@ fn test_withdraw_more_than_available_after_slash() {
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #107)")]
 fn test_withdraw_when_fully_slashed() {
     let e = Env::default();
     let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
@@ -499,7 +534,7 @@ fn test_slash_result_matches_get_state() {
 // ============================================================================
 
 #[test]
-#[should_panic(expected = "not admin")]
+#[should_panic(expected = "Error(Contract, #102)")]
 fn test_error_message_not_admin() {
     let e = Env::default();
     let (client, _admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
@@ -509,7 +544,7 @@ fn test_error_message_not_admin() {
 }
 
 #[test]
-#[should_panic(expected = "no bond")]
+#[should_panic(expected = "Error(Contract, #104)")]
 fn test_error_message_no_bond() {
     let e = Env::default();
     let (client, admin, _identity) = setup(&e);
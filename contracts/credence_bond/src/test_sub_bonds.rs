@@ -0,0 +1,178 @@
+//! Tests for splitting a bond into sub-bonds with their own lock-ups and merging them back.
+
+use crate::sub_bonds::MAX_SUB_BONDS;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
+
+fn setup(e: &Env, is_rolling: bool) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &10_000_i128, &86400_u64, &is_rolling, &3600_u64);
+    (client, identity)
+}
+
+#[test]
+fn test_split_creates_sub_bond() {
+    let e = Env::default();
+    let (client, identity) = setup(&e, false);
+    let sub = client.split_bond(&identity, &4_000_i128, &172_800_u64);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (Symbol::new(&e, "bond_split"), identity.clone()).into_val(&e)
+    );
+    assert_eq!(sub.id, 0);
+    assert_eq!(sub.amount, 4_000);
+    assert_eq!(sub.start, 1000);
+    assert_eq!(sub.duration, 172_800);
+    assert_eq!(client.get_sub_bond(&identity, &0), Some(sub.clone()));
+    assert_eq!(client.get_sub_bonds(&identity), vec![&e, sub]);
+    // The amount stays bonded.
+    assert_eq!(client.get_identity_state().bonded_amount, 10_000);
+}
+
+#[test]
+fn test_split_holds_back_withdrawals() {
+    let e = Env::default();
+    let (client, identity) = setup(&e, false);
+    client.split_bond(&identity, &4_000_i128, &172_800_u64);
+    assert_eq!(
+        client.try_withdraw(&6_001_i128).err(),
+        Some(Ok(BondError::InsufficientBalance))
+    );
+    client.withdraw(&6_000_i128);
+    assert_eq!(client.get_identity_state().bonded_amount, 4_000);
+    assert_eq!(
+        client.try_withdraw_bond(&identity).err(),
+        Some(Ok(BondError::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_split_beyond_available_rejected() {
+    let e = Env::default();
+    let (client, identity) = setup(&e, false);
+    client.split_bond(&identity, &6_000_i128, &172_800_u64);
+    assert_eq!(
+        client
+            .try_split_bond(&identity, &4_001_i128, &172_800_u64)
+            .err(),
+        Some(Ok(BondError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_request_unbond(&4_001_i128).err(),
+        Some(Ok(BondError::InsufficientBalance))
+    );
+    client.split_bond(&identity, &4_000_i128, &172_800_u64);
+}
+
+#[test]
+fn test_split_invalid_input_rejected() {
+    let e = Env::default();
+    let (client, identity) = setup(&e, false);
+    assert_eq!(
+        client
+            .try_split_bond(&identity, &0_i128, &172_800_u64)
+            .err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_split_bond(&identity, &1_000_i128, &0_u64).err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+    let other = Address::generate(&e);
+    assert!(client
+        .try_split_bond(&other, &1_000_i128, &172_800_u64)
+        .is_err());
+}
+
+#[test]
+fn test_split_rolling_bond_rejected() {
+    let e = Env::default();
+    let (client, identity) = setup(&e, true);
+    assert_eq!(
+        client
+            .try_split_bond(&identity, &1_000_i128, &172_800_u64)
+            .err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_split_count_capped() {
+    let e = Env::default();
+    let (client, identity) = setup(&e, false);
+    for _ in 0..MAX_SUB_BONDS {
+        client.split_bond(&identity, &100_i128, &86400_u64);
+    }
+    assert_eq!(
+        client
+            .try_split_bond(&identity, &100_i128, &86400_u64)
+            .err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_merge_extends_lockup() {
+    let e = Env::default();
+    let (client, identity) = setup(&e, false);
+    client.split_bond(&identity, &1_000_i128, &3600_u64);
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    client.split_bond(&identity, &2_000_i128, &172_800_u64);
+
+    // A sub-bond ending before the bond leaves the lock-up unchanged.
+    let bond = client.merge_bonds(&identity, &vec![&e, 0_u32]);
+    assert_eq!(bond.bond_duration, 86400);
+    assert_eq!(client.get_sub_bond(&identity, &0), None);
+
+    let bond = client.merge_bonds(&identity, &vec![&e, 1_u32]);
+    assert_eq!(bond.bond_duration, 2000 + 172_800 - 1000);
+    assert_eq!(
+        client.get_identity_state().bond_duration,
+        bond.bond_duration
+    );
+    assert_eq!(client.get_sub_bonds(&identity).len(), 0);
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1000 + bond.bond_duration);
+    client.withdraw_bond(&identity);
+}
+
+#[test]
+fn test_merge_unknown_id_rejected() {
+    let e = Env::default();
+    let (client, identity) = setup(&e, false);
+    client.split_bond(&identity, &1_000_i128, &3600_u64);
+    assert_eq!(
+        client.try_merge_bonds(&identity, &vec![&e, 1_u32]).err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+    assert_eq!(
+        client.try_merge_bonds(&identity, &vec![&e]).err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+    // Merging the same id twice fails the second time.
+    assert_eq!(
+        client
+            .try_merge_bonds(&identity, &vec![&e, 0_u32, 0_u32])
+            .err(),
+        Some(Ok(BondError::InvalidInput))
+    );
+    assert_eq!(client.get_sub_bonds(&identity).len(), 1);
+}
+
+#[test]
+fn test_split_requires_identity_auth() {
+    let e = Env::default();
+    let (client, identity) = setup(&e, false);
+    e.set_auths(&[]);
+    assert!(client
+        .try_split_bond(&identity, &1_000_i128, &3600_u64)
+        .is_err());
+}
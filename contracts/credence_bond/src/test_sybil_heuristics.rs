@@ -0,0 +1,94 @@
+//! Tests for the attester graph views: edge counts, shared subjects and concentration.
+
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    client.initialize(&Address::generate(e));
+    let a = Address::generate(e);
+    let b = Address::generate(e);
+    client.register_attester(&a);
+    client.register_attester(&b);
+    (client, a, b)
+}
+
+fn attest(e: &Env, client: &CredenceBondClient, attester: &Address, subject: &Address, data: &str) {
+    let nonce = client.get_nonce(attester);
+    client.add_attestation(attester, subject, &String::from_str(e, data), &nonce);
+}
+
+#[test]
+fn test_edge_counts_and_subjects() {
+    let e = Env::default();
+    let (client, a, _b) = setup(&e);
+    let s1 = Address::generate(&e);
+    let s2 = Address::generate(&e);
+    attest(&e, &client, &a, &s1, "kyc");
+    attest(&e, &client, &a, &s1, "aml");
+    attest(&e, &client, &a, &s2, "kyc");
+
+    assert_eq!(client.get_attestation_edge_count(&a, &s1), 2);
+    assert_eq!(client.get_attestation_edge_count(&a, &s2), 1);
+    assert_eq!(client.get_attestation_edge_count(&a, &a), 0);
+    assert_eq!(client.get_attester_subjects(&a), vec![&e, s1, s2]);
+}
+
+#[test]
+fn test_shared_subject_count() {
+    let e = Env::default();
+    let (client, a, b) = setup(&e);
+    let shared1 = Address::generate(&e);
+    let shared2 = Address::generate(&e);
+    attest(&e, &client, &a, &shared1, "kyc");
+    attest(&e, &client, &a, &shared2, "kyc");
+    attest(&e, &client, &a, &Address::generate(&e), "kyc");
+    attest(&e, &client, &b, &shared1, "kyc");
+    attest(&e, &client, &b, &shared1, "aml");
+    attest(&e, &client, &b, &shared2, "kyc");
+
+    assert_eq!(client.get_shared_subject_count(&a, &b), 2);
+    assert_eq!(client.get_shared_subject_count(&b, &a), 2);
+    assert_eq!(
+        client.get_shared_subject_count(&a, &Address::generate(&e)),
+        0
+    );
+}
+
+#[test]
+fn test_concentration() {
+    let e = Env::default();
+    let (client, a, _b) = setup(&e);
+    let empty = client.get_attester_concentration(&a);
+    assert_eq!(empty.total_attestations, 0);
+    assert_eq!(empty.top_subject, None);
+    assert_eq!(empty.concentration_bps, 0);
+
+    let favored = Address::generate(&e);
+    attest(&e, &client, &a, &Address::generate(&e), "kyc");
+    attest(&e, &client, &a, &favored, "kyc");
+    attest(&e, &client, &a, &favored, "aml");
+    attest(&e, &client, &a, &favored, "pep");
+
+    let c = client.get_attester_concentration(&a);
+    assert_eq!(c.total_attestations, 4);
+    assert_eq!(c.distinct_subjects, 2);
+    assert_eq!(c.top_subject, Some(favored));
+    assert_eq!(c.top_subject_count, 3);
+    assert_eq!(c.concentration_bps, 7_500);
+}
+
+#[test]
+fn test_revocation_keeps_edges() {
+    let e = Env::default();
+    let (client, a, _b) = setup(&e);
+    let subject = Address::generate(&e);
+    let nonce = client.get_nonce(&a);
+    let att = client.add_attestation(&a, &subject, &String::from_str(&e, "kyc"), &nonce);
+    let nonce = client.get_nonce(&a);
+    client.revoke_attestation(&a, &att.id, &crate::RevocationReason::Fraud, &nonce);
+    assert_eq!(client.get_attestation_edge_count(&a, &subject), 1);
+}
@@ -0,0 +1,95 @@
+//! Tests for the `testutils` state setters and time-travel helpers.
+
+use crate::testutils;
+use crate::{CredenceBond, CredenceBondClient, IdentityBond};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    (client, contract_id)
+}
+
+fn bond(identity: &Address, amount: i128, is_rolling: bool) -> IdentityBond {
+    IdentityBond {
+        identity: identity.clone(),
+        bonded_amount: amount,
+        bond_start: 1000,
+        bond_duration: 100,
+        slashed_amount: 0,
+        active: true,
+        is_rolling,
+        withdrawal_requested_at: 0,
+        notice_period_duration: 10,
+    }
+}
+
+#[test]
+fn test_set_bond_and_admin() {
+    let e = Env::default();
+    let (client, contract_id) = setup(&e);
+    let identity = Address::generate(&e);
+    let admin = Address::generate(&e);
+    testutils::set_admin(&e, &contract_id, &admin);
+    testutils::set_bond(&e, &contract_id, &bond(&identity, 5_000, false));
+    assert_eq!(client.get_identity_state().bonded_amount, 5_000);
+    // Admin was installed without initialize.
+    client.slash(&admin, &100_i128);
+    assert_eq!(client.get_identity_state().slashed_amount, 100);
+}
+
+#[test]
+fn test_set_attestation_counters() {
+    let e = Env::default();
+    let (client, contract_id) = setup(&e);
+    let subject = Address::generate(&e);
+    testutils::set_subject_attestation_count(&e, &contract_id, &subject, 7);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_subject_attestation_count(&consumer, &subject), 7);
+    testutils::set_attestation_counter(&e, &contract_id, 42);
+
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let att = client.add_attestation(
+        &attester,
+        &subject,
+        &soroban_sdk::String::from_str(&e, "x"),
+        &0_u64,
+    );
+    assert_eq!(att.id, 42);
+}
+
+#[test]
+fn test_governance_fixtures() {
+    let e = Env::default();
+    let (client, contract_id) = setup(&e);
+    let g1 = Address::generate(&e);
+    testutils::set_governance(&e, &contract_id, vec![&e, g1.clone()], 5000, 1);
+    assert_eq!(client.get_governors().len(), 1);
+    let id = testutils::add_open_proposal(&e, &contract_id, &g1, &g1, 250);
+    assert_eq!(client.get_slash_proposal(&id).unwrap().amount, 250);
+}
+
+#[test]
+fn test_advance_periods_renews_rolling_bond() {
+    let e = Env::default();
+    let (_client, contract_id) = setup(&e);
+    let identity = Address::generate(&e);
+    testutils::set_bond(&e, &contract_id, &bond(&identity, 1_000, true));
+    let renewed = testutils::advance_periods(&e, &contract_id, 3);
+    assert_eq!(e.ledger().timestamp(), 1300);
+    assert_eq!(renewed.bond_start, 1300);
+}
+
+#[test]
+fn test_advance_time() {
+    let e = Env::default();
+    setup(&e);
+    testutils::advance_time(&e, 50);
+    assert_eq!(e.ledger().timestamp(), 1050);
+}
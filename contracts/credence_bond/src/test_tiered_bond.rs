@@ -1,11 +1,14 @@
 //! Tests for Tiered Bond System: Bronze, Silver, Gold, Platinum by bonded amount.
 
-use crate::tiered_bond::{get_tier_for_amount, TIER_BRONZE_MAX, TIER_GOLD_MAX, TIER_SILVER_MAX};
-use crate::{BondTier, CredenceBond, CredenceBondClient};
+use crate::tiered_bond::{
+    default_thresholds, tier_for, TierThresholds, TIER_BRONZE_MAX, TIER_GOLD_MAX, TIER_SILVER_MAX,
+};
+use crate::{testutils, BondTier, CredenceBond, CredenceBondClient};
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Address, Env};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
     let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
@@ -15,6 +18,7 @@ fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
 
 #[test]
 fn test_tier_thresholds() {
+    let get_tier_for_amount = |amount| tier_for(amount, &default_thresholds());
     assert_eq!(get_tier_for_amount(0), BondTier::Bronze);
     assert_eq!(get_tier_for_amount(TIER_BRONZE_MAX - 1), BondTier::Bronze);
     assert_eq!(get_tier_for_amount(TIER_BRONZE_MAX), BondTier::Silver);
@@ -74,3 +78,58 @@ fn test_tier_unchanged_within_threshold() {
     client.top_up(&(TIER_BRONZE_MAX / 2 - 1));
     assert_eq!(client.get_tier(), BondTier::Bronze);
 }
+
+#[test]
+fn test_default_tier_thresholds() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.get_tier_thresholds(), default_thresholds());
+    assert_eq!(client.export_config().tier_thresholds, default_thresholds());
+}
+
+#[test]
+fn test_configured_tier_thresholds() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &5_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(client.get_tier(), BondTier::Bronze);
+
+    client.set_tier_thresholds(&admin, &0, &1_000, &5_000, &10_000);
+    assert_eq!(
+        client.get_tier_thresholds(),
+        TierThresholds {
+            bronze: 0,
+            silver: 1_000,
+            gold: 5_000,
+            platinum: 10_000,
+        }
+    );
+    assert_eq!(client.get_tier(), BondTier::Gold);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(
+        client.get_verification_snapshot(&consumer, &identity).tier,
+        BondTier::Gold
+    );
+    client.top_up(&5_000);
+    assert_eq!(client.get_tier(), BondTier::Platinum);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_tier_thresholds_must_increase() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup(&e);
+    client.set_tier_thresholds(&admin, &0, &1_000, &1_000, &10_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_tier_thresholds_unauthorized() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _admin) = setup(&e);
+    client.set_tier_thresholds(&Address::generate(&e), &0, &1_000, &5_000, &10_000);
+}
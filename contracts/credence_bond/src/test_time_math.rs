@@ -0,0 +1,170 @@
+//! Boundary tests for chain-time math: half-open periods, notice windows, epoch indices,
+//! saturation and leap years.
+
+use crate::time_math::*;
+
+#[test]
+fn test_period_end_and_boundary() {
+    assert_eq!(period_end(1_000, 100), 1_100);
+    assert!(!is_period_ended(999, 1_000, 100));
+    assert!(!is_period_ended(1_000, 1_000, 100));
+    assert!(!is_period_ended(1_099, 1_000, 100));
+    assert!(is_period_ended(1_100, 1_000, 100));
+    assert!(is_period_ended(1_101, 1_000, 100));
+}
+
+#[test]
+fn test_zero_duration_period_ends_immediately() {
+    assert!(is_period_ended(1_000, 1_000, 0));
+    assert_eq!(remaining_in_period(1_000, 1_000, 0), 0);
+    assert_eq!(periods_elapsed(5_000, 1_000, 0), 0);
+}
+
+#[test]
+fn test_period_end_saturates() {
+    assert_eq!(period_end(u64::MAX - 1, 10), u64::MAX);
+    assert!(!is_period_ended(u64::MAX - 1, u64::MAX - 1, 10));
+    assert!(is_period_ended(u64::MAX, u64::MAX - 1, 10));
+}
+
+#[test]
+fn test_remaining_in_period() {
+    assert_eq!(remaining_in_period(1_000, 1_000, 100), 100);
+    assert_eq!(remaining_in_period(1_099, 1_000, 100), 1);
+    assert_eq!(remaining_in_period(1_100, 1_000, 100), 0);
+    assert_eq!(remaining_in_period(2_000, 1_000, 100), 0);
+    assert_eq!(remaining_in_period(500, 1_000, 100), 600);
+}
+
+#[test]
+fn test_periods_elapsed_boundaries() {
+    assert_eq!(periods_elapsed(999, 1_000, 100), 0);
+    assert_eq!(periods_elapsed(1_000, 1_000, 100), 0);
+    assert_eq!(periods_elapsed(1_099, 1_000, 100), 0);
+    assert_eq!(periods_elapsed(1_100, 1_000, 100), 1);
+    assert_eq!(periods_elapsed(1_199, 1_000, 100), 1);
+    assert_eq!(periods_elapsed(1_200, 1_000, 100), 2);
+    assert_eq!(periods_elapsed(u64::MAX, 0, 1), u64::MAX);
+}
+
+#[test]
+fn test_epoch_index() {
+    assert_eq!(epoch_index(0, 86_400), 0);
+    assert_eq!(epoch_index(86_399, 86_400), 0);
+    assert_eq!(epoch_index(86_400, 86_400), 1);
+    assert_eq!(epoch_index(100, 0), 0);
+}
+
+#[test]
+fn test_notice_window_boundaries() {
+    // Requested at 1_000 with 50s notice: window is [1_000, 1_050).
+    assert!(!in_notice_window(999, 1_000, 50));
+    assert!(in_notice_window(1_000, 1_000, 50));
+    assert!(in_notice_window(1_049, 1_000, 50));
+    assert!(!in_notice_window(1_050, 1_000, 50));
+
+    assert!(!notice_elapsed(1_049, 1_000, 50));
+    assert!(notice_elapsed(1_050, 1_000, 50));
+    assert!(notice_elapsed(1_500, 1_000, 50));
+}
+
+#[test]
+fn test_notice_never_requested() {
+    assert!(!in_notice_window(10, 0, 50));
+    assert!(!notice_elapsed(u64::MAX, 0, 0));
+}
+
+#[test]
+fn test_zero_notice_elapses_immediately() {
+    assert!(!in_notice_window(1_000, 1_000, 0));
+    assert!(notice_elapsed(1_000, 1_000, 0));
+}
+
+#[test]
+fn test_notice_end_saturates() {
+    assert_eq!(notice_end(u64::MAX - 5, 10), u64::MAX);
+    assert!(in_notice_window(u64::MAX - 1, u64::MAX - 5, 10));
+}
+
+#[test]
+fn test_window_and_elapsed_partition_time_after_request() {
+    for now in 1_000..1_100 {
+        assert_ne!(
+            in_notice_window(now, 1_000, 50),
+            notice_elapsed(now, 1_000, 50)
+        );
+    }
+}
+
+#[test]
+fn test_leap_years() {
+    assert!(is_leap_year(2024));
+    assert!(!is_leap_year(2023));
+    assert!(!is_leap_year(1900));
+    assert!(is_leap_year(2000));
+    assert!(!is_leap_year(2100));
+}
+
+#[test]
+fn test_days_in_month() {
+    assert_eq!(days_in_month(2024, 2), 29);
+    assert_eq!(days_in_month(2023, 2), 28);
+    assert_eq!(days_in_month(2100, 2), 28);
+    assert_eq!(days_in_month(2000, 2), 29);
+    assert_eq!(days_in_month(2023, 1), 31);
+    assert_eq!(days_in_month(2023, 4), 30);
+    assert_eq!(days_in_month(2023, 12), 31);
+    let total: u32 = (1..=12).map(|m| days_in_month(2024, m)).sum();
+    assert_eq!(total, 366);
+    assert_eq!(SECONDS_PER_DAY * total as u64, 31_622_400);
+}
+
+#[test]
+#[should_panic(expected = "invalid month")]
+fn test_invalid_month() {
+    let _ = days_in_month(2024, 13);
+}
+
+#[test]
+fn test_civil_conversions() {
+    assert_eq!(days_from_civil(1970, 1, 1), 0);
+    assert_eq!(civil_from_days(0), (1970, 1, 1));
+    // 2024-02-29T00:00:00Z
+    assert_eq!(timestamp_from_civil(2024, 2, 29, 0), 1_709_164_800);
+    assert_eq!(civil_from_timestamp(1_709_164_800), (2024, 2, 29));
+    assert_eq!(civil_from_timestamp(1_709_164_800 - 1), (2024, 2, 28));
+    assert_eq!(
+        civil_from_days(days_from_civil(2000, 12, 31) + 1),
+        (2001, 1, 1)
+    );
+    for days in (0..200_000).step_by(37) {
+        let (y, m, d) = civil_from_days(days);
+        assert_eq!(days_from_civil(y, m, d), days);
+    }
+}
+
+#[test]
+fn test_add_months_clamps_day_and_keeps_time() {
+    let jan31 = timestamp_from_civil(2023, 1, 31, 3_600);
+    assert_eq!(add_months(jan31, 0), jan31);
+    assert_eq!(
+        add_months(jan31, 1),
+        timestamp_from_civil(2023, 2, 28, 3_600)
+    );
+    assert_eq!(
+        add_months(jan31, 2),
+        timestamp_from_civil(2023, 3, 31, 3_600)
+    );
+    assert_eq!(
+        add_months(jan31, 11),
+        timestamp_from_civil(2023, 12, 31, 3_600)
+    );
+    assert_eq!(
+        add_months(jan31, 12),
+        timestamp_from_civil(2024, 1, 31, 3_600)
+    );
+    assert_eq!(
+        add_months(jan31, 13),
+        timestamp_from_civil(2024, 2, 29, 3_600)
+    );
+}
@@ -0,0 +1,144 @@
+//! Tests for the token adaptor modes against a Stellar Asset Contract and a mock non-SAC
+//! SEP-41 token with allowance-only semantics.
+
+use crate::token_adaptor::TokenMode;
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, token, Address, Env, String};
+
+/// Custom SEP-41 token: `transfer_from` spends a tracked allowance, `transfer` is rejected.
+#[contract]
+pub struct CustomToken;
+
+#[contractimpl]
+impl CustomToken {
+    pub fn decimals(_e: Env) -> u32 {
+        7
+    }
+
+    pub fn symbol(e: Env) -> String {
+        String::from_str(&e, "CUST")
+    }
+
+    pub fn approve(e: Env, from: Address, spender: Address, amount: i128, _expiration: u32) {
+        e.storage().instance().set(&(from, spender), &amount);
+    }
+
+    pub fn transfer(_e: Env, _from: Address, _to: Address, _amount: i128) {
+        panic!("transfer not supported");
+    }
+
+    pub fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        let key = (from, spender);
+        let allowance: i128 = e.storage().instance().get(&key).unwrap_or(0);
+        if allowance < amount {
+            panic!("insufficient allowance");
+        }
+        e.storage().instance().set(&key, &(allowance - amount));
+        let received: i128 = e.storage().instance().get(&to).unwrap_or(0);
+        e.storage().instance().set(&to, &(received + amount));
+    }
+
+    pub fn received(e: Env, to: Address) -> i128 {
+        e.storage().instance().get(&to).unwrap_or(0)
+    }
+}
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin, Address::generate(e))
+}
+
+fn setup_custom(
+    e: &Env,
+    mode: TokenMode,
+) -> (CredenceBondClient<'_>, Address, CustomTokenClient<'_>) {
+    let (client, admin, identity) = setup(e);
+    let token_id = e.register(CustomToken, ());
+    client.set_token(&admin, &token_id);
+    client.set_token_mode(&admin, &mode);
+    (client, identity, CustomTokenClient::new(e, &token_id))
+}
+
+fn setup_sac(e: &Env, mode: TokenMode) -> (CredenceBondClient<'_>, Address, token::Client<'_>) {
+    let (client, admin, identity) = setup(e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    token::StellarAssetClient::new(e, &sac.address()).mint(&identity, &5_000_000_000);
+    client.set_token(&admin, &sac.address());
+    client.set_token_mode(&admin, &mode);
+    (client, identity, token::Client::new(e, &sac.address()))
+}
+
+#[test]
+fn test_default_mode_is_auto() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    assert_eq!(client.get_token_mode(), TokenMode::Auto);
+    assert_eq!(client.export_config().token_mode, TokenMode::Auto);
+}
+
+#[test]
+fn test_sep41_mode_pulls_allowance_from_custom_token() {
+    let e = Env::default();
+    let (client, identity, token) = setup_custom(&e, TokenMode::Sep41);
+    token.approve(&identity, &client.address, &2_000_000_000, &0);
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    assert_eq!(token.received(&client.address), 2_000_000_000);
+}
+
+#[test]
+#[should_panic]
+fn test_sep41_mode_without_allowance_fails() {
+    let e = Env::default();
+    let (client, identity, _token) = setup_custom(&e, TokenMode::Sep41);
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+}
+
+#[test]
+#[should_panic]
+fn test_sac_mode_rejects_custom_token() {
+    let e = Env::default();
+    let (client, identity, token) = setup_custom(&e, TokenMode::Sac);
+    token.approve(&identity, &client.address, &2_000_000_000, &0);
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+}
+
+#[test]
+fn test_sac_mode_transfers_inline() {
+    let e = Env::default();
+    let (client, identity, token) = setup_sac(&e, TokenMode::Sac);
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    assert_eq!(token.balance(&client.address), 2_000_000_000);
+    assert_eq!(token.allowance(&identity, &client.address), 0);
+}
+
+#[test]
+fn test_sep41_mode_uses_sac_allowance() {
+    let e = Env::default();
+    let (client, identity, token) = setup_sac(&e, TokenMode::Sep41);
+    token.approve(&identity, &client.address, &3_000_000_000, &1000);
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    assert_eq!(token.balance(&client.address), 2_000_000_000);
+    assert_eq!(token.allowance(&identity, &client.address), 1_000_000_000);
+}
+
+#[test]
+fn test_auto_mode_falls_back_for_custom_token() {
+    let e = Env::default();
+    let (client, identity, token) = setup_custom(&e, TokenMode::Auto);
+    token.approve(&identity, &client.address, &2_000_000_000, &0);
+    client.create_bond_funded(&identity, &2_000_000_000, &86400_u64, &false, &0_u64);
+    assert_eq!(token.received(&client.address), 2_000_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_token_mode_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.set_token_mode(&identity, &TokenMode::Sac);
+}
@@ -0,0 +1,94 @@
+//! Tests for bond token metadata caching and decimals-aware config validation.
+
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+/// Minimal token exposing only the metadata getters, with 18 decimals.
+#[contract]
+pub struct WideToken;
+
+#[contractimpl]
+impl WideToken {
+    pub fn decimals(_e: Env) -> u32 {
+        18
+    }
+
+    pub fn symbol(e: Env) -> String {
+        String::from_str(&e, "WIDE")
+    }
+}
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_set_token_caches_metadata() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    assert_eq!(client.get_token_info(), None);
+
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    let token = soroban_sdk::token::Client::new(&e, &sac.address());
+    let info = client.set_token(&admin, &sac.address());
+    assert_eq!(info.address, sac.address());
+    assert_eq!(info.decimals, 7);
+    assert_eq!(info.symbol, token.symbol());
+    assert_eq!(client.get_token_info(), Some(info));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_tier_thresholds_validated_against_decimals() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let token = e.register(WideToken, ());
+    client.set_token(&admin, &token);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_min_amount_validated_against_decimals() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    client.set_token(&admin, &sac.address());
+    // 7 decimals: one unit is 10_000_000.
+    client.set_probation_config(&admin, &0_u64, &9_999_999_i128, &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_configured_tier_thresholds_validated_against_decimals() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    client.set_token(&admin, &sac.address());
+    // 7 decimals: one unit is 10_000_000.
+    client.set_tier_thresholds(&admin, &0, &9_999_999, &50_000_000, &200_000_000);
+}
+
+#[test]
+fn test_whole_unit_min_amount_accepted() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    client.set_token(&admin, &sac.address());
+    client.set_probation_config(&admin, &0_u64, &10_000_000_i128, &0_u64);
+    assert_eq!(client.get_probation_config().min_amount, 10_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_token_unauthorized() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    client.set_token(&Address::generate(&e), &sac.address());
+}
@@ -0,0 +1,89 @@
+//! Tests for recovering tokens sent to the contract by mistake.
+
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+fn token_held_by(e: &Env, holder: &Address, amount: i128) -> Address {
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    token::StellarAssetClient::new(e, &sac.address()).mint(holder, &amount);
+    sac.address()
+}
+
+#[test]
+fn test_recover_stray_token() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let stray = token_held_by(&e, &client.address, 1_000);
+    let to = Address::generate(&e);
+
+    client.recover_token(&admin, &stray, &to, &600);
+    let stray = token::Client::new(&e, &stray);
+    assert_eq!(stray.balance(&to), 600);
+    assert_eq!(stray.balance(&client.address), 400);
+    assert_eq!(client.get_admin_action_count(), 1);
+}
+
+#[test]
+fn test_bond_tokens_not_recoverable() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let first = token_held_by(&e, &client.address, 1_000);
+    let second = token_held_by(&e, &client.address, 1_000);
+    let to = Address::generate(&e);
+
+    client.set_token(&admin, &first);
+    assert!(client.try_recover_token(&admin, &first, &to, &1).is_err());
+
+    // A replaced bond token may still back existing bonds.
+    client.set_token(&admin, &second);
+    assert!(client.try_recover_token(&admin, &first, &to, &1).is_err());
+    assert!(client.try_recover_token(&admin, &second, &to, &1).is_err());
+    assert_eq!(
+        token::Client::new(&e, &first).balance(&client.address),
+        1_000
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #118)")]
+fn test_recover_bond_token_panics() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let bond_token = token_held_by(&e, &client.address, 1_000);
+    client.set_token(&admin, &bond_token);
+    client.recover_token(&admin, &bond_token, &Address::generate(&e), &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_recover_token_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let stray = token_held_by(&e, &client.address, 1_000);
+    let other = Address::generate(&e);
+    client.recover_token(&other, &stray, &other, &1);
+}
+
+#[test]
+fn test_recover_token_requires_admin_auth() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let stray = token_held_by(&e, &client.address, 1_000);
+    e.set_auths(&[]);
+    let to = Address::generate(&e);
+    assert!(client.try_recover_token(&admin, &stray, &to, &1).is_err());
+    assert_eq!(
+        token::Client::new(&e, &stray).balance(&client.address),
+        1_000
+    );
+}
@@ -0,0 +1,127 @@
+//! Tests for the unbond cooldown: scheduled withdrawals, claim after the delay, slashes
+//! during the cooldown and interaction with direct withdrawals and the unbonding queue.
+
+use crate::unbond_cooldown::PendingUnbond;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+const DELAY: u64 = 7 * 86_400;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &10_000_i128, &86400_u64, &false, &0_u64);
+    client.set_unbond_delay(&admin, &DELAY);
+    (client, admin)
+}
+
+#[test]
+fn test_request_and_claim_after_delay() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let pending = client.request_unbond(&4_000_i128);
+    assert_eq!(
+        pending,
+        PendingUnbond {
+            amount: 4_000,
+            requested_at: 1000,
+            unlock_at: 1000 + DELAY,
+        }
+    );
+    assert_eq!(client.get_pending_unbond(), Some(pending));
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + DELAY - 1);
+    assert_eq!(
+        client.try_claim_unbonded(),
+        Err(Ok(BondError::NoticeNotElapsed))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + DELAY);
+    assert_eq!(client.claim_unbonded(), 4_000);
+    assert_eq!(client.get_identity_state().bonded_amount, 6_000);
+    assert_eq!(client.get_pending_unbond(), None);
+}
+
+#[test]
+fn test_direct_exits_blocked_while_enabled() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 86_400);
+    assert_eq!(
+        client.try_withdraw(&100_i128).err(),
+        Some(Ok(BondError::WithdrawalNotRequested))
+    );
+    assert_eq!(
+        client.try_queue_unbonding(&100_i128),
+        Err(Ok(BondError::WithdrawalNotRequested))
+    );
+    let identity = client.get_identity_state().identity;
+    assert_eq!(
+        client.try_withdraw_bond(&identity),
+        Err(Ok(BondError::WithdrawalNotRequested))
+    );
+
+    client.set_unbond_delay(&admin, &0);
+    assert_eq!(client.withdraw(&100_i128).bonded_amount, 9_900);
+}
+
+#[test]
+fn test_pending_amount_stays_slashable() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.request_unbond(&8_000_i128);
+    client.slash(&admin, &5_000_i128);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + DELAY);
+    // Only 5_000 is left after the slash.
+    assert_eq!(client.claim_unbonded(), 5_000);
+    let bond = client.get_identity_state();
+    assert_eq!(bond.bonded_amount - bond.slashed_amount, 0);
+}
+
+#[test]
+fn test_request_validation() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(
+        client.try_request_unbond(&0_i128).err(),
+        Some(Ok(BondError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_request_unbond(&10_001_i128).err(),
+        Some(Ok(BondError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_claim_unbonded(),
+        Err(Ok(BondError::WithdrawalNotRequested))
+    );
+    client.request_unbond(&1_000_i128);
+    assert_eq!(
+        client.try_request_unbond(&1_000_i128).err(),
+        Some(Ok(BondError::WithdrawalAlreadyRequested))
+    );
+    assert_eq!(
+        client.try_set_unbond_delay(&Address::generate(&e), &0),
+        Err(Ok(BondError::NotAdmin))
+    );
+}
+
+#[test]
+fn test_pending_amount_reserved_from_direct_withdrawals() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.request_unbond(&9_000_i128);
+    // Disabling the cooldown keeps the scheduled request reserved.
+    client.set_unbond_delay(&admin, &0);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 86_400);
+    assert_eq!(
+        client.try_withdraw(&2_000_i128).err(),
+        Some(Ok(BondError::InsufficientBalance))
+    );
+    assert_eq!(client.withdraw(&1_000_i128).bonded_amount, 9_000);
+}
@@ -0,0 +1,154 @@
+//! Tests for the throttled unbonding queue: FIFO release, per-epoch capacity, partial
+//! processing, queue position / ETA views and interaction with direct withdrawals, which a
+//! configured queue refuses.
+
+use crate::unbonding_queue::capacity_for;
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &10_000_i128, &86400_u64, &false, &0_u64);
+    // 10% of TVL per 1000-second epoch.
+    client.set_unbonding_config(&admin, &1000_u64, &1000_u32);
+    (client, admin)
+}
+
+#[test]
+fn test_capacity_for() {
+    assert_eq!(capacity_for(10_000, 1000), 1000);
+    assert_eq!(capacity_for(5, 1000), 1);
+    assert_eq!(capacity_for(0, 1000), 0);
+}
+
+#[test]
+fn test_process_within_capacity() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let id = client.queue_unbonding(&400_i128);
+    assert_eq!(client.get_unbonding_queue_length(), 1);
+    assert_eq!(client.process_unbonding_queue(&10_u32), 400);
+    assert_eq!(client.get_identity_state().bonded_amount, 9_600);
+    assert_eq!(client.get_unbonding_request(&id).processed_amount, 400);
+    assert_eq!(client.get_unbonding_queue_length(), 0);
+}
+
+#[test]
+fn test_fifo_throttled_across_epochs() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let first = client.queue_unbonding(&700_i128);
+    let second = client.queue_unbonding(&700_i128);
+
+    // Epoch capacity is 1000: first completes, second partially released.
+    assert_eq!(client.process_unbonding_queue(&10_u32), 1000);
+    assert_eq!(client.get_unbonding_request(&first).processed_amount, 700);
+    assert_eq!(client.get_unbonding_request(&second).processed_amount, 300);
+
+    // Capacity exhausted for this epoch.
+    assert_eq!(client.process_unbonding_queue(&10_u32), 0);
+
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    assert_eq!(client.process_unbonding_queue(&10_u32), 400);
+    assert_eq!(client.get_unbonding_request(&second).processed_amount, 700);
+    assert_eq!(client.get_identity_state().bonded_amount, 8_600);
+}
+
+#[test]
+fn test_status_position_and_eta() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let first = client.queue_unbonding(&800_i128);
+    let second = client.queue_unbonding(&1500_i128);
+
+    let s1 = client.get_unbonding_status(&first);
+    assert_eq!(s1.position, 0);
+    assert_eq!(s1.amount_ahead, 0);
+    assert_eq!(s1.eta, 1000);
+
+    // 2300 through, 1000 this epoch, 1000 per epoch after: two more epochs (epoch 3).
+    let s2 = client.get_unbonding_status(&second);
+    assert_eq!(s2.position, 1);
+    assert_eq!(s2.amount_ahead, 800);
+    assert_eq!(s2.eta, 3000);
+
+    client.process_unbonding_queue(&10_u32);
+    assert_eq!(client.get_unbonding_status(&first).eta, 0);
+    assert_eq!(client.get_unbonding_status(&second).position, 0);
+}
+
+#[test]
+fn test_max_requests_limits_processing() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.queue_unbonding(&100_i128);
+    client.queue_unbonding(&100_i128);
+    assert_eq!(client.process_unbonding_queue(&1_u32), 100);
+    assert_eq!(client.get_unbonding_queue_length(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #107)")]
+fn test_queued_amount_reserved_from_direct_withdraw() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(&e, &e.register(CredenceBond, ()));
+    client.initialize(&Address::generate(&e));
+    client.create_bond(&Address::generate(&e), &10_000_i128, &0_u64, &false, &0_u64);
+    client.queue_unbonding(&9_500_i128);
+    client.withdraw(&600_i128);
+}
+
+#[test]
+fn test_configured_queue_refuses_direct_withdrawals() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let identity = client.get_identity_state().identity;
+    assert_eq!(
+        client.try_withdraw_early(&100_i128).err(),
+        Some(Ok(BondError::WithdrawalNotRequested))
+    );
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 86400);
+    assert_eq!(
+        client.try_withdraw(&100_i128).err(),
+        Some(Ok(BondError::WithdrawalNotRequested))
+    );
+    assert_eq!(
+        client.try_withdraw_bond(&identity).err(),
+        Some(Ok(BondError::WithdrawalNotRequested))
+    );
+    assert_eq!(client.get_identity_state().bonded_amount, 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #107)")]
+fn test_queue_more_than_balance_rejected() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.queue_unbonding(&6_000_i128);
+    client.queue_unbonding(&6_000_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_invalid_capacity_rejected() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_unbonding_config(&admin, &1000_u64, &0_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_config_unauthorized() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let other = Address::generate(&e);
+    client.set_unbonding_config(&other, &1000_u64, &500_u32);
+}
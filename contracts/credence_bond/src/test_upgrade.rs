@@ -0,0 +1,154 @@
+//! Tests for contract upgrades: admin gating, governance approval and the version getter.
+
+use crate::governance_actions::ProposalAction;
+use crate::{upgrade, BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec};
+
+/// Smallest WASM module the host accepts: an empty module carrying the
+/// `contractenvmetav0` section (protocol 22).
+const EMPTY_WASM: [u8; 40] = [
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x00, 0x1e, 0x11, b'c', b'o', b'n', b't', b'r',
+    b'a', b'c', b't', b'e', b'n', b'v', b'm', b'e', b't', b'a', b'v', b'0', 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x00,
+];
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+fn upload(e: &Env) -> BytesN<32> {
+    e.deployer()
+        .upload_contract_wasm(Bytes::from_array(e, &EMPTY_WASM))
+}
+
+#[test]
+fn test_version_reports_crate_version() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(
+        client.version(),
+        String::from_str(&e, env!("CARGO_PKG_VERSION"))
+    );
+}
+
+#[test]
+fn test_upgrade_by_admin() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let hash = upload(&e);
+    client.upgrade(&admin, &hash);
+    let topics = (Symbol::new(&e, "contract_upgraded"), admin.clone()).into_val(&e);
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| *t == topics)
+        .unwrap();
+    let upgraded_to: BytesN<32> = data.into_val(&e);
+    assert_eq!(upgraded_to, hash);
+}
+
+#[test]
+fn test_upgrade_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let hash = upload(&e);
+    assert_eq!(
+        client.try_upgrade(&Address::generate(&e), &hash),
+        Err(Ok(BondError::NotAdmin))
+    );
+}
+
+#[test]
+fn test_upgrade_with_governance_needs_approved_proposal() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let g1 = Address::generate(&e);
+    let g2 = Address::generate(&e);
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(&e, [g1.clone(), g2.clone()]),
+        &10_000,
+        &1,
+        &0,
+    );
+    client.set_upgrade_governance(&admin, &true);
+    assert!(client.export_config().upgrade_governance_required);
+    let hash = upload(&e);
+
+    assert_eq!(
+        client.try_upgrade(&admin, &hash),
+        Err(Ok(BondError::ProposalNotFound))
+    );
+
+    let id = client.propose_upgrade(&g1, &hash);
+    client.vote_upgrade(&g1, &id, &true);
+    assert!(!client.is_upgrade_approved(&id));
+    assert_eq!(
+        client.try_upgrade(&admin, &hash),
+        Err(Ok(BondError::ProposalNotApproved))
+    );
+
+    client.vote_upgrade(&g2, &id, &true);
+    assert!(client.is_upgrade_approved(&id));
+    client.upgrade(&admin, &hash);
+
+    // The contract now runs the new (empty) WASM, so read the spent proposal from storage.
+    let proposal = e.as_contract(&client.address, || upgrade::get_proposal(&e, id).unwrap());
+    assert!(proposal.executed);
+}
+
+#[test]
+fn test_upgrade_proposal_voting_rules() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let g1 = Address::generate(&e);
+    client.initialize_governance(&admin, &Vec::from_array(&e, [g1.clone()]), &5100, &1, &0);
+    let hash = BytesN::from_array(&e, &[7; 32]);
+
+    let outsider = Address::generate(&e);
+    assert_eq!(
+        client.try_propose_upgrade(&outsider, &hash),
+        Err(Ok(BondError::Unauthorized))
+    );
+    let id = client.propose_upgrade(&admin, &hash);
+    assert_eq!(
+        client.try_vote_upgrade(&outsider, &id, &true),
+        Err(Ok(BondError::NotGovernor))
+    );
+    client.vote_upgrade(&g1, &id, &false);
+    assert_eq!(
+        client.try_vote_upgrade(&g1, &id, &true),
+        Err(Ok(BondError::AlreadyVoted))
+    );
+    assert!(!client.is_upgrade_approved(&id));
+    assert_eq!(
+        client.try_vote_upgrade(&g1, &99, &true),
+        Err(Ok(BondError::ProposalNotFound))
+    );
+}
+
+#[test]
+fn test_disabling_upgrade_governance_needs_approval() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let g1 = Address::generate(&e);
+    client.initialize_governance(&admin, &Vec::from_array(&e, [g1.clone()]), &5100, &1, &0);
+    client.set_upgrade_governance(&admin, &true);
+    assert_eq!(
+        client.try_set_upgrade_governance(&admin, &false),
+        Err(Ok(BondError::Unauthorized))
+    );
+    assert!(client.is_upgrade_governance_required());
+
+    let id = client.propose_action(&admin, &ProposalAction::DisableUpgradeGovernance);
+    client.vote_action(&g1, &id, &true);
+    client.execute_action(&admin, &id);
+    assert!(!client.is_upgrade_governance_required());
+}
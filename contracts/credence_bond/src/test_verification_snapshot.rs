@@ -0,0 +1,128 @@
+//! Tests for the per-identity verification snapshot: bond and attestation mutations keep it
+//! in sync with the values verifiers would otherwise read separately.
+
+use crate::verification_snapshot::slash_ratio_bps;
+use crate::{testutils, BondTier, CredenceBond, CredenceBondClient, RevocationReason};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &2_000_000_000_i128, &86400_u64, &false, &0_u64);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, identity, attester)
+}
+
+#[test]
+fn test_snapshot_after_create_bond() {
+    let e = Env::default();
+    let (client, _admin, identity, _attester) = setup(&e);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    let snapshot = client.get_verification_snapshot(&consumer, &identity);
+    assert_eq!(snapshot.tier, BondTier::Silver);
+    assert_eq!(snapshot.slash_ratio_bps, 0);
+    assert_eq!(snapshot.attestation_count, 0);
+    assert_eq!(snapshot.updated_at, 1000);
+}
+
+#[test]
+fn test_snapshot_tracks_attestations() {
+    let e = Env::default();
+    let (client, admin, identity, attester) = setup(&e);
+    client.set_attester_stake(&admin, &attester, &1_000_000_i128);
+    let a = client.add_attestation(&attester, &identity, &String::from_str(&e, "kyc"), &0_u64);
+    let b = client.add_attestation(&attester, &identity, &String::from_str(&e, "aml"), &1_u64);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    let snapshot = client.get_verification_snapshot(&consumer, &identity);
+    assert_eq!(snapshot.attestation_count, 2);
+    assert_eq!(snapshot.active_weight, (a.weight + b.weight) as u64);
+    assert_eq!(
+        snapshot.attestation_count,
+        client.get_subject_attestation_count(&consumer, &identity)
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    client.revoke_attestation(&attester, &a.id, &RevocationReason::IssuedInError, &2_u64);
+    let snapshot = client.get_verification_snapshot(&consumer, &identity);
+    assert_eq!(snapshot.attestation_count, 1);
+    assert_eq!(snapshot.active_weight, b.weight as u64);
+    assert_eq!(snapshot.updated_at, 2000);
+}
+
+#[test]
+fn test_snapshot_tracks_slash_and_unslash() {
+    let e = Env::default();
+    let (client, admin, identity, _attester) = setup(&e);
+    client.slash(&admin, &500_000_000_i128);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(
+        client
+            .get_verification_snapshot(&consumer, &identity)
+            .slash_ratio_bps,
+        2500
+    );
+    e.as_contract(&client.address, || {
+        crate::slashing::unslash_bond(&e, &admin, 500_000_000);
+    });
+    assert_eq!(
+        client
+            .get_verification_snapshot(&consumer, &identity)
+            .slash_ratio_bps,
+        0
+    );
+}
+
+#[test]
+fn test_snapshot_tracks_tier_changes() {
+    let e = Env::default();
+    let (client, _admin, identity, _attester) = setup(&e);
+    client.top_up(&4_000_000_000_i128);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(
+        client.get_verification_snapshot(&consumer, &identity).tier,
+        BondTier::Gold
+    );
+    assert_eq!(
+        client.get_verification_snapshot(&consumer, &identity).tier,
+        client.get_tier()
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 86400);
+    client.withdraw(&5_500_000_000_i128);
+    assert_eq!(
+        client.get_verification_snapshot(&consumer, &identity).tier,
+        BondTier::Bronze
+    );
+    assert_eq!(
+        client.get_verification_snapshot(&consumer, &identity).tier,
+        client.get_tier()
+    );
+}
+
+#[test]
+fn test_snapshot_for_unknown_identity_is_empty() {
+    let e = Env::default();
+    let (client, _admin, _identity, _attester) = setup(&e);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    let snapshot = client.get_verification_snapshot(&consumer, &Address::generate(&e));
+    assert_eq!(snapshot.tier, BondTier::Bronze);
+    assert_eq!(snapshot.attestation_count, 0);
+    assert_eq!(snapshot.active_weight, 0);
+    assert_eq!(snapshot.updated_at, 0);
+}
+
+#[test]
+fn test_slash_ratio_bps() {
+    assert_eq!(slash_ratio_bps(0, 0), 0);
+    assert_eq!(slash_ratio_bps(1000, 0), 0);
+    assert_eq!(slash_ratio_bps(1000, 1), 10);
+    assert_eq!(slash_ratio_bps(1000, 1000), 10_000);
+    assert_eq!(slash_ratio_bps(1000, 2000), 10_000);
+}
@@ -9,12 +9,12 @@ use soroban_sdk::{Env, String};
 fn setup(
     e: &Env,
 ) -> (
-    CredenceBondClient,
+    CredenceBondClient<'_>,
     soroban_sdk::Address,
     soroban_sdk::Address,
 ) {
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = soroban_sdk::Address::generate(e);
     client.initialize(&admin);
@@ -78,3 +78,118 @@ fn get_weight_config_returns_set_values() {
     assert_eq!(mult, 200);
     assert_eq!(max, 10_000);
 }
+
+#[test]
+fn verifier_cap_defaults_to_no_cap() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    assert_eq!(client.get_verifier_weight_cap(), 10_000);
+    let subject = soroban_sdk::Address::generate(&e);
+    client.add_attestation(&attester, &subject, &String::from_str(&e, "a"), &0);
+    client.add_attestation(&attester, &subject, &String::from_str(&e, "b"), &1);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_subject_aggregate_weight(&consumer, &subject), 2);
+}
+
+#[test]
+fn verifier_cap_limits_single_verifier_share() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let other = soroban_sdk::Address::generate(&e);
+    client.register_attester(&other);
+    client.set_attester_stake(&admin, &attester, &1_000_000); // weight 10_000
+    client.set_attester_stake(&admin, &other, &100_000); // weight 1_000
+    client.set_verifier_weight_cap(&admin, &4000);
+    let subject = soroban_sdk::Address::generate(&e);
+    client.add_attestation(&attester, &subject, &String::from_str(&e, "a"), &0);
+    client.add_attestation(&other, &subject, &String::from_str(&e, "b"), &0);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    // raw total 11_000 -> cap 4_400; 4_400 + 1_000
+    assert_eq!(
+        client.get_subject_aggregate_weight(&consumer, &subject),
+        5_400
+    );
+}
+
+#[test]
+fn verifier_cap_ignores_revoked() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_verifier_weight_cap(&admin, &5000);
+    let subject = soroban_sdk::Address::generate(&e);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "a"), &0);
+    client.revoke_attestation(&attester, &att.id, &RevocationReason::IssuedInError, &1);
+    let consumer = testutils::query_consumer(&e, &client.address);
+    assert_eq!(client.get_subject_aggregate_weight(&consumer, &subject), 0);
+}
+
+#[test]
+fn apply_verifier_cap_math() {
+    let e = Env::default();
+    let weights = soroban_sdk::Vec::from_array(&e, [600_u64, 300, 100]);
+    assert_eq!(
+        weighted_attestation::apply_verifier_cap(&weights, 10_000),
+        1000
+    );
+    assert_eq!(
+        weighted_attestation::apply_verifier_cap(&weights, 4000),
+        800
+    );
+    let single = soroban_sdk::Vec::from_array(&e, [1000_u64]);
+    assert_eq!(weighted_attestation::apply_verifier_cap(&single, 4000), 400);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn verifier_cap_zero_rejected() {
+    let e = Env::default();
+    let (client, admin, _attester) = setup(&e);
+    client.set_verifier_weight_cap(&admin, &0);
+}
+
+#[test]
+fn weight_follows_attester_bond() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.create_bond(&attester, &1_000_000_i128, &86_400_u64, &false, &0_u64);
+    assert_eq!(
+        client.get_attester_stake(&attester),
+        (1_000_000, WeightSource::Bond)
+    );
+    client.slash(&admin, &400_000);
+    assert_eq!(
+        client.get_attester_stake(&attester),
+        (600_000, WeightSource::Bond)
+    );
+    let subject = soroban_sdk::Address::generate(&e);
+    let att = client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "data"),
+        &client.get_nonce(&attester),
+    );
+    assert_eq!(att.weight, 6_000); // 600_000 * 100 / 10_000
+}
+
+#[test]
+fn manual_stake_overrides_bond() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.create_bond(&attester, &1_000_000_i128, &86_400_u64, &false, &0_u64);
+    client.set_attester_stake(&admin, &attester, &10_000_i128);
+    assert_eq!(
+        client.get_attester_stake(&attester),
+        (10_000, WeightSource::Override)
+    );
+    client.clear_attester_stake(&admin, &attester);
+    assert_eq!(
+        client.get_attester_stake(&attester),
+        (1_000_000, WeightSource::Bond)
+    );
+
+    let other = soroban_sdk::Address::generate(&e);
+    assert_eq!(
+        client.get_attester_stake(&other),
+        (0, WeightSource::Default)
+    );
+}
@@ -11,6 +11,7 @@ use soroban_sdk::testutils::{Address as _, Ledger};
 use soroban_sdk::{Address, Env};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
     let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
@@ -45,7 +46,7 @@ fn test_withdraw_bond_partial_withdrawal() {
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #107)")]
 fn test_withdraw_bond_insufficient_balance() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
@@ -56,7 +57,7 @@ fn test_withdraw_bond_insufficient_balance() {
 }
 
 #[test]
-#[should_panic(expected = "use withdraw for post lock-up")]
+#[should_panic(expected = "Error(Contract, #108)")]
 fn test_withdraw_bond_early_withdrawal_rejection() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1_000);
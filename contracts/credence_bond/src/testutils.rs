@@ -0,0 +1,151 @@
+//! Test Utilities
+//!
+//! Direct state setters and time-travel helpers for tests, available under `cfg(test)` or the
+//! `testutils` feature. They write contract storage via `Env::as_contract`, so integrators can
+//! put a bond, attestation counters or governance into a given state without replaying the
+//! full flow. Never enable the `testutils` feature in a deployed build.
+
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::governance_approval::{self, ProposalStatus, SlashProposal};
+use crate::{query_access, rolling_bond, time_math, DataKey, IdentityBond};
+
+/// Overwrite the stored bond.
+pub fn set_bond(e: &Env, contract: &Address, bond: &IdentityBond) {
+    e.as_contract(contract, || {
+        e.storage().instance().set(&DataKey::Bond, bond);
+    });
+}
+
+/// Read the stored bond, if any.
+#[must_use]
+pub fn get_bond(e: &Env, contract: &Address) -> Option<IdentityBond> {
+    e.as_contract(contract, || e.storage().instance().get(&DataKey::Bond))
+}
+
+/// Set the admin without calling `initialize`.
+pub fn set_admin(e: &Env, contract: &Address, admin: &Address) {
+    e.as_contract(contract, || {
+        e.storage().instance().set(&DataKey::Admin, admin);
+    });
+}
+
+/// Set the fee pool drained by `collect_fees`, without moving tokens.
+pub fn set_fee_pool(e: &Env, contract: &Address, amount: i128) {
+    e.as_contract(contract, || {
+        e.storage().instance().set(&Symbol::new(e, "fees"), &amount);
+    });
+}
+
+/// Generate an address and put it on the query consumer allow-list, for reading gated views.
+pub fn query_consumer(e: &Env, contract: &Address) -> Address {
+    let consumer = Address::generate(e);
+    e.as_contract(contract, || {
+        query_access::set_authorized(e, &consumer, true);
+    });
+    consumer
+}
+
+/// Set the global attestation id counter (next id to assign).
+pub fn set_attestation_counter(e: &Env, contract: &Address, value: u64) {
+    e.as_contract(contract, || {
+        e.storage()
+            .instance()
+            .set(&DataKey::AttestationCounter, &value);
+    });
+}
+
+/// Set a subject's attestation count.
+pub fn set_subject_attestation_count(e: &Env, contract: &Address, subject: &Address, count: u32) {
+    e.as_contract(contract, || {
+        e.storage()
+            .instance()
+            .set(&DataKey::SubjectAttestationCount(subject.clone()), &count);
+    });
+}
+
+/// Install governors and quorum config without admin checks.
+pub fn set_governance(
+    e: &Env,
+    contract: &Address,
+    governors: Vec<Address>,
+    quorum_bps: u32,
+    min_governors: u32,
+) {
+    e.as_contract(contract, || {
+        governance_approval::initialize_governance(e, governors, quorum_bps, min_governors);
+    });
+}
+
+/// Insert an open slash proposal against `identity`. Returns its id.
+pub fn add_open_proposal(
+    e: &Env,
+    contract: &Address,
+    proposer: &Address,
+    identity: &Address,
+    amount: i128,
+) -> u64 {
+    e.as_contract(contract, || {
+        let id: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::GovernanceNextProposalId)
+            .unwrap_or(0);
+        let proposal = SlashProposal {
+            id,
+            identity: identity.clone(),
+            amount,
+            proposed_by: proposer.clone(),
+            proposed_at: e.ledger().timestamp(),
+            status: ProposalStatus::Open,
+            voting_deadline: None,
+        };
+        e.storage()
+            .instance()
+            .set(&DataKey::GovernanceProposal(id), &proposal);
+        e.storage()
+            .instance()
+            .set(&DataKey::GovernanceNextProposalId, &(id + 1));
+        governance_approval::index_open(e, id);
+        id
+    })
+}
+
+/// Advance the ledger timestamp by `seconds`.
+pub fn advance_time(e: &Env, seconds: u64) {
+    e.ledger().with_mut(|li| {
+        li.timestamp = li
+            .timestamp
+            .checked_add(seconds)
+            .expect("timestamp overflow")
+    });
+}
+
+/// Move the ledger to the end of the bond's current period (bond_start + bond_duration).
+pub fn advance_to_period_end(e: &Env, contract: &Address) {
+    let bond = get_bond(e, contract).unwrap_or_else(|| panic!("no bond"));
+    let end = time_math::period_end(bond.bond_start, bond.bond_duration);
+    e.ledger().with_mut(|li| {
+        if li.timestamp < end {
+            li.timestamp = end;
+        }
+    });
+}
+
+/// Advance `periods` full periods, renewing a rolling bond at each period end as
+/// `renew_if_rolling` would (without emitting events). Returns the resulting bond.
+pub fn advance_periods(e: &Env, contract: &Address, periods: u32) -> IdentityBond {
+    let mut bond = get_bond(e, contract).unwrap_or_else(|| panic!("no bond"));
+    for _ in 0..periods {
+        advance_to_period_end(e, contract);
+        if bond.is_rolling {
+            e.as_contract(contract, || {
+                rolling_bond::renew(e, &mut bond, e.ledger().timestamp());
+            });
+            set_bond(e, contract, &bond);
+            e.as_contract(contract, || rolling_bond::record_renewal(e));
+        }
+    }
+    bond
+}
@@ -3,30 +3,121 @@
 //! Assigns identity tiers (Bronze, Silver, Gold, Platinum) based on bonded amount thresholds.
 //! Supports tier upgrade on bond increase and tier downgrade on partial withdrawal.
 //! Emits tier change events when tier changes.
+//!
+//! Thresholds default to USDC-scale amounts (6 decimals) and can be reconfigured by the admin
+//! for tokens with other decimals.
 
-use crate::BondTier;
-use soroban_sdk::Env;
+use crate::{BondError, BondTier};
+use soroban_sdk::{contracttype, panic_with_error, Env, Symbol};
 
-/// Tier thresholds (in smallest unit, e.g. 6 decimals for USDC).
+/// Default tier thresholds (in smallest unit, e.g. 6 decimals for USDC).
 /// Bronze: [0, BRONZE_MAX), Silver: [BRONZE_MAX, SILVER_MAX), Gold: [SILVER_MAX, GOLD_MAX), Platinum: [GOLD_MAX, ..)
 pub const TIER_BRONZE_MAX: i128 = 1_000_000_000; // 1000 * 10^6
 pub const TIER_SILVER_MAX: i128 = 5_000_000_000; // 5000 * 10^6
 pub const TIER_GOLD_MAX: i128 = 20_000_000_000; // 20000 * 10^6
 
-/// Returns the tier for a given bonded amount.
+/// Minimum bonded amount of each tier, in the bond token's smallest unit. Amounts below
+/// `bronze` still report Bronze, the lowest tier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierThresholds {
+    pub bronze: i128,
+    pub silver: i128,
+    pub gold: i128,
+    pub platinum: i128,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum TierKey {
+    /// Configured `TierThresholds`.
+    Thresholds,
+}
+
+/// Thresholds used until the admin configures others.
 #[must_use]
-pub fn get_tier_for_amount(amount: i128) -> BondTier {
-    if amount < TIER_BRONZE_MAX {
-        BondTier::Bronze
-    } else if amount < TIER_SILVER_MAX {
-        BondTier::Silver
-    } else if amount < TIER_GOLD_MAX {
+pub fn default_thresholds() -> TierThresholds {
+    TierThresholds {
+        bronze: 0,
+        silver: TIER_BRONZE_MAX,
+        gold: TIER_SILVER_MAX,
+        platinum: TIER_GOLD_MAX,
+    }
+}
+
+/// Configured thresholds, or the defaults.
+#[must_use]
+pub fn get_thresholds(e: &Env) -> TierThresholds {
+    e.storage()
+        .instance()
+        .get(&TierKey::Thresholds)
+        .unwrap_or_else(default_thresholds)
+}
+
+/// Set the thresholds. They must be non-negative and strictly increasing, and each non-zero
+/// threshold must be at least one whole unit of the bond token (if set). Admin only
+/// (enforced by caller).
+pub fn set_thresholds(e: &Env, thresholds: &TierThresholds) {
+    if thresholds.bronze < 0
+        || thresholds.silver <= thresholds.bronze
+        || thresholds.gold <= thresholds.silver
+        || thresholds.platinum <= thresholds.gold
+    {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    require_whole_units(e, thresholds);
+    e.storage().instance().set(&TierKey::Thresholds, thresholds);
+    e.events()
+        .publish((Symbol::new(e, "tier_thresholds_set"),), thresholds.clone());
+}
+
+/// Panics unless every non-zero threshold is at least one whole unit of the bond token.
+pub fn require_whole_units(e: &Env, thresholds: &TierThresholds) {
+    let amounts = [
+        thresholds.bronze,
+        thresholds.silver,
+        thresholds.gold,
+        thresholds.platinum,
+    ];
+    if amounts
+        .iter()
+        .any(|amount| crate::token_info::is_below_whole_unit(e, *amount))
+    {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+}
+
+/// Tier of `amount` under `thresholds`.
+#[must_use]
+pub fn tier_for(amount: i128, thresholds: &TierThresholds) -> BondTier {
+    if amount >= thresholds.platinum {
+        BondTier::Platinum
+    } else if amount >= thresholds.gold {
         BondTier::Gold
+    } else if amount >= thresholds.silver {
+        BondTier::Silver
     } else {
-        BondTier::Platinum
+        BondTier::Bronze
     }
 }
 
+/// Smallest amount reaching `tier` under `thresholds`.
+#[must_use]
+pub fn min_amount_for(tier: &BondTier, thresholds: &TierThresholds) -> i128 {
+    match tier {
+        BondTier::Bronze => thresholds.bronze,
+        BondTier::Silver => thresholds.silver,
+        BondTier::Gold => thresholds.gold,
+        BondTier::Platinum => thresholds.platinum,
+    }
+}
+
+/// Returns the tier for a given bonded amount under the configured thresholds.
+#[must_use]
+pub fn get_tier_for_amount(e: &Env, amount: i128) -> BondTier {
+    tier_for(amount, &get_thresholds(e))
+}
+
 /// Emits a tier change event if the tier changed.
 pub fn emit_tier_change_if_needed(
     e: &Env,
@@ -0,0 +1,155 @@
+//! Chain-Time Math
+//!
+//! Single home for period, notice and epoch arithmetic on ledger timestamps, so every call
+//! site shares the same boundary semantics.
+//!
+//! ## Boundary semantics
+//! - A period starting at `start` with length `duration` covers the half-open interval
+//!   `[start, start + duration)`. At `now == period_end` the period has ended: the
+//!   boundary belongs to the next period.
+//! - A notice requested at `requested_at` with length `notice` runs over
+//!   `[requested_at, requested_at + notice)`. At `now == notice_end` it has elapsed.
+//!   `requested_at == 0` means "never requested".
+//! - Sums saturate at `u64::MAX` instead of wrapping, so a huge duration means "never ends".
+//!
+//! ## Leap seconds and calendar days
+//! Ledger timestamps are Unix seconds, which ignore leap seconds (every day is exactly
+//! 86_400 seconds). Second-based periods therefore need no leap handling. Calendar-aware
+//! callers can use `is_leap_year` and `days_in_month` for leap years, and
+//! `civil_from_timestamp` / `timestamp_from_civil` / `add_months` for UTC calendar dates
+//! (proleptic Gregorian, 1970 onwards).
+
+/// Seconds in a (Unix) day.
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// End of the period `[start, start + duration)` (saturating).
+#[must_use]
+pub fn period_end(start: u64, duration: u64) -> u64 {
+    start.saturating_add(duration)
+}
+
+/// True once `now` reached the period end (the end timestamp itself counts as ended).
+#[must_use]
+pub fn is_period_ended(now: u64, start: u64, duration: u64) -> bool {
+    now >= period_end(start, duration)
+}
+
+/// Seconds left until the period end (0 once ended).
+#[must_use]
+pub fn remaining_in_period(now: u64, start: u64, duration: u64) -> u64 {
+    period_end(start, duration).saturating_sub(now)
+}
+
+/// Number of complete periods of `duration` between `start` and `now`. Zero before
+/// `start` or for a zero duration. At `now == start + k * duration`, `k` periods have
+/// elapsed.
+#[must_use]
+pub fn periods_elapsed(now: u64, start: u64, duration: u64) -> u64 {
+    if duration == 0 || now < start {
+        return 0;
+    }
+    (now - start) / duration
+}
+
+/// Index of the epoch of length `epoch_length` containing `now`, counted from timestamp 0.
+#[must_use]
+pub fn epoch_index(now: u64, epoch_length: u64) -> u64 {
+    periods_elapsed(now, 0, epoch_length)
+}
+
+/// End of a notice period requested at `requested_at` (saturating).
+#[must_use]
+pub fn notice_end(requested_at: u64, notice: u64) -> u64 {
+    requested_at.saturating_add(notice)
+}
+
+/// True while a requested notice is running: `requested_at <= now < notice_end`.
+#[must_use]
+pub fn in_notice_window(now: u64, requested_at: u64, notice: u64) -> bool {
+    requested_at != 0 && now >= requested_at && now < notice_end(requested_at, notice)
+}
+
+/// True once a requested notice has elapsed: `now >= notice_end`. False if never requested.
+#[must_use]
+pub fn notice_elapsed(now: u64, requested_at: u64, notice: u64) -> bool {
+    requested_at != 0 && now >= notice_end(requested_at, notice)
+}
+
+/// Gregorian leap year rule: divisible by 4, except centuries not divisible by 400.
+#[must_use]
+pub fn is_leap_year(year: u32) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+/// Days in `month` (1-12) of `year`. Panics on an invalid month.
+#[must_use]
+pub fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => panic!("invalid month"),
+    }
+}
+
+/// Days since 1970-01-01 of the UTC date `year-month-day`. Dates before 1970 are not
+/// supported.
+#[must_use]
+pub fn days_from_civil(year: u32, month: u32, day: u32) -> u64 {
+    let y = u64::from(if month <= 2 { year - 1 } else { year });
+    let m = u64::from(month);
+    let era = y / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + u64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    (era * 146_097 + doe)
+        .checked_sub(719_468)
+        .expect("date before 1970")
+}
+
+/// UTC date `(year, month, day)` of the day `days` after 1970-01-01.
+#[must_use]
+pub fn civil_from_days(days: u64) -> (u32, u32, u32) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year as u32, month as u32, day as u32)
+}
+
+/// UTC date `(year, month, day)` of a timestamp.
+#[must_use]
+pub fn civil_from_timestamp(ts: u64) -> (u32, u32, u32) {
+    civil_from_days(ts / SECONDS_PER_DAY)
+}
+
+/// Timestamp of `year-month-day` at `second_of_day` UTC.
+#[must_use]
+pub fn timestamp_from_civil(year: u32, month: u32, day: u32, second_of_day: u64) -> u64 {
+    days_from_civil(year, month, day)
+        .saturating_mul(SECONDS_PER_DAY)
+        .saturating_add(second_of_day)
+}
+
+/// `ts` moved `months` calendar months forward, keeping the time of day. The day of month
+/// is clamped to the target month's length (Jan 31 + 1 month = Feb 28 or 29).
+#[must_use]
+pub fn add_months(ts: u64, months: u64) -> u64 {
+    let (year, month, day) = civil_from_timestamp(ts);
+    let total = u64::from(month - 1) + months;
+    let year = year.saturating_add((total / 12) as u32);
+    let month = (total % 12) as u32 + 1;
+    let day = day.min(days_in_month(year, month));
+    timestamp_from_civil(year, month, day, ts % SECONDS_PER_DAY)
+}
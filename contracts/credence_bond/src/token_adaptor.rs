@@ -0,0 +1,65 @@
+//! Token Adaptor
+//!
+//! Stellar Asset Contracts accept a `transfer` authorized inline by the sender, while some
+//! custom SEP-41 tokens only move funds a spender was approved for. The adaptor hides that
+//! difference behind a configurable mode used by every token movement of this contract.
+
+use soroban_sdk::{contracttype, token, Address, Env};
+
+use crate::funding::FundingPath;
+
+/// How tokens are pulled from a user.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenMode {
+    /// Try an inline `transfer`, fall back to `transfer_from` (default).
+    Auto = 0,
+    /// Stellar Asset Contract: inline `transfer` authorized by the sender only.
+    Sac = 1,
+    /// Generic SEP-41 token: `transfer_from` against a prior allowance only.
+    Sep41 = 2,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum TokenAdaptorKey {
+    Mode,
+}
+
+/// Configured token mode (`Auto` if never set).
+#[must_use]
+pub fn get_mode(e: &Env) -> TokenMode {
+    e.storage()
+        .instance()
+        .get(&TokenAdaptorKey::Mode)
+        .unwrap_or(TokenMode::Auto)
+}
+
+/// Set the token mode. Admin only (enforced by caller).
+pub fn set_mode(e: &Env, mode: TokenMode) {
+    e.storage().instance().set(&TokenAdaptorKey::Mode, &mode);
+}
+
+/// Move `amount` of `token` from `from` into this contract. Caller must enforce `from` auth.
+pub fn pull(e: &Env, token: &Address, from: &Address, amount: i128) -> FundingPath {
+    let client = token::Client::new(e, token);
+    let this = e.current_contract_address();
+    match get_mode(e) {
+        TokenMode::Sac => {
+            client.transfer(from, &this, &amount);
+            FundingPath::InlineTransfer
+        }
+        TokenMode::Sep41 => {
+            client.transfer_from(&this, from, &this, &amount);
+            FundingPath::Allowance
+        }
+        TokenMode::Auto => {
+            if client.try_transfer(from, &this, &amount).is_ok() {
+                FundingPath::InlineTransfer
+            } else {
+                client.transfer_from(&this, from, &this, &amount);
+                FundingPath::Allowance
+            }
+        }
+    }
+}
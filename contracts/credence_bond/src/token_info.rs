@@ -0,0 +1,74 @@
+//! Bond Token Info
+//!
+//! Caches the bond token's address, `decimals()` and `symbol()` when the admin sets the
+//! token, so raw i128 configuration values (tier thresholds, minimum amounts) can be checked
+//! against the token's scale: a threshold below one whole token unit almost always means the
+//! config was written for a token with different decimals.
+
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, String};
+
+use crate::{tiered_bond, BondError};
+
+/// Largest supported token decimals (10^decimals must fit comfortably in i128).
+pub const MAX_DECIMALS: u32 = 30;
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum TokenInfoKey {
+    /// Cached `TokenInfo`.
+    BondTokenInfo,
+}
+
+/// Cached metadata of the bond token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenInfo {
+    pub address: Address,
+    pub decimals: u32,
+    pub symbol: String,
+}
+
+/// Returns the cached token info, if a token was set.
+#[must_use]
+pub fn get(e: &Env) -> Option<TokenInfo> {
+    e.storage().instance().get(&TokenInfoKey::BondTokenInfo)
+}
+
+/// One whole token unit in the smallest denomination (`10^decimals`), if a token was set.
+#[must_use]
+pub fn one_unit(e: &Env) -> Option<i128> {
+    get(e).map(|info| 10_i128.pow(info.decimals))
+}
+
+/// True if `amount` is non-zero but below one whole token unit (false without a token).
+#[must_use]
+pub fn is_below_whole_unit(e: &Env, amount: i128) -> bool {
+    match one_unit(e) {
+        Some(unit) => amount != 0 && amount < unit,
+        None => false,
+    }
+}
+
+/// Fetch and cache the token's metadata, then validate existing configuration against its
+/// decimals. Admin only (enforced by caller).
+pub fn set_token(e: &Env, token: &Address) -> TokenInfo {
+    let client = token::Client::new(e, token);
+    let decimals = client.decimals();
+    if decimals > MAX_DECIMALS {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    let info = TokenInfo {
+        address: token.clone(),
+        decimals,
+        symbol: client.symbol(),
+    };
+    e.storage()
+        .instance()
+        .set(&TokenInfoKey::BondTokenInfo, &info);
+
+    tiered_bond::require_whole_units(e, &tiered_bond::get_thresholds(e));
+    if is_below_whole_unit(e, crate::probation::get_config(e).min_amount) {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    info
+}
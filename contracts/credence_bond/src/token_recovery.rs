@@ -0,0 +1,51 @@
+//! Stray Token Recovery
+//!
+//! Tokens sent directly to the contract by mistake are otherwise stuck. The admin can return
+//! them with `recover_token`, except for operational tokens: the current bond token and
+//! every token ever configured as the bond token, since bonded balances are held in those.
+
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, Symbol};
+
+use crate::{token_info, BondError};
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum RecoveryKey {
+    /// Set for every token that was configured as the bond token.
+    Operational(Address),
+}
+
+/// Remember `token` as operational. Called whenever the bond token is set.
+pub fn mark_operational(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set(&RecoveryKey::Operational(token.clone()), &true);
+}
+
+/// True if `token` is or was the bond token.
+#[must_use]
+pub fn is_operational(e: &Env, token: &Address) -> bool {
+    if token_info::get(e).is_some_and(|info| info.address == *token) {
+        return true;
+    }
+    e.storage()
+        .instance()
+        .get(&RecoveryKey::Operational(token.clone()))
+        .unwrap_or(false)
+}
+
+/// Transfer `amount` of a non-operational `token` held by the contract to `to`. Admin only
+/// (enforced by caller).
+pub fn recover(e: &Env, token: &Address, to: &Address, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    if is_operational(e, token) {
+        panic_with_error!(e, BondError::TokenNotRecoverable);
+    }
+    token::Client::new(e, token).transfer(&e.current_contract_address(), to, &amount);
+    e.events().publish(
+        (Symbol::new(e, "token_recovered"), token.clone()),
+        (to.clone(), amount),
+    );
+}
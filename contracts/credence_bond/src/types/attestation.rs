@@ -4,7 +4,7 @@
 //! subject (identity), timestamp, weight. Supports serialization via ContractType
 //! and validation methods for storage efficiency and safety.
 
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String};
 
 /// Maximum allowed attestation weight (prevents overflow and caps influence).
 pub const MAX_ATTESTATION_WEIGHT: u32 = 1_000_000;
@@ -12,6 +12,25 @@ pub const MAX_ATTESTATION_WEIGHT: u32 = 1_000_000;
 /// Default weight when attester has no stake configured.
 pub const DEFAULT_ATTESTATION_WEIGHT: u32 = 1;
 
+/// Why an attestation was revoked, recorded for audits of verifier behavior.
+/// `NotRevoked` marks live attestations (contract types cannot be optional fields).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RevocationReason {
+    NotRevoked,
+    /// Issued in error (also used for challenged and successfully countered attestations).
+    /// Named `IssuedInError` because a contract type variant cannot be called `Error`.
+    IssuedInError,
+    /// The claim was fraudulent.
+    Fraud,
+    /// The claim no longer holds.
+    Expired,
+    /// The subject asked for the revocation.
+    SubjectRequest,
+    /// Replaced by a newer attestation.
+    Superseded,
+}
+
 /// Attestation record: a verifier's credibility attestation for an identity.
 ///
 /// # Fields
@@ -22,6 +41,11 @@ pub const DEFAULT_ATTESTATION_WEIGHT: u32 = 1;
 /// * `weight` - Credibility weight (e.g. derived from attester bond); capped by protocol.
 /// * `attestation_data` - Opaque attestation payload (e.g. claim type or hash).
 /// * `revoked` - Whether this attestation has been revoked.
+/// * `template_id` - Claim template this attestation follows, if any.
+/// * `expires_at` - Ledger timestamp after which the attestation lapses (0 = never).
+/// * `active_from` - Ledger timestamp at which the attestation leaves its challenge period
+///   and starts carrying weight (equal to `timestamp` when there is no challenge window).
+/// * `revocation_reason` - Why the attestation was revoked (`NotRevoked` while live).
 ///
 /// # Serialization
 /// Uses `#[contracttype]` for Soroban instance storage; space-efficient (u64, u32, bool, Address, String).
@@ -35,6 +59,10 @@ pub struct Attestation {
     pub weight: u32,
     pub attestation_data: String,
     pub revoked: bool,
+    pub template_id: Option<u32>,
+    pub expires_at: u64,
+    pub active_from: u64,
+    pub revocation_reason: RevocationReason,
 }
 
 impl Attestation {
@@ -58,6 +86,27 @@ impl Attestation {
     pub fn is_active(&self) -> bool {
         !self.revoked
     }
+
+    /// Returns true if this attestation is active and has not expired at `now`.
+    #[must_use]
+    #[inline]
+    pub fn is_active_at(&self, now: u64) -> bool {
+        self.is_active() && now >= self.active_from && !self.is_expired_at(now)
+    }
+
+    /// Returns true if this attestation is not revoked but still in its challenge period.
+    #[must_use]
+    #[inline]
+    pub fn is_pending_at(&self, now: u64) -> bool {
+        self.is_active() && now < self.active_from
+    }
+
+    /// Returns true if this attestation has an expiry and it has passed at `now`.
+    #[must_use]
+    #[inline]
+    pub fn is_expired_at(&self, now: u64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
 }
 
 /// Key used to detect duplicate attestations: same verifier, identity, and data.
@@ -69,3 +118,14 @@ pub struct AttestationDedupKey {
     pub identity: Address,
     pub attestation_data: String,
 }
+
+/// Key used to detect duplicate hashed attestations: same verifier, identity, and content
+/// hash. Kept apart from `AttestationDedupKey`, whose data of a hashed attestation is only
+/// its schema identifier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationHashDedupKey {
+    pub verifier: Address,
+    pub identity: Address,
+    pub data_hash: BytesN<32>,
+}
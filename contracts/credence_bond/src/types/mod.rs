@@ -4,4 +4,6 @@
 
 pub mod attestation;
 
-pub use attestation::{Attestation, AttestationDedupKey};
+pub use attestation::{
+    Attestation, AttestationDedupKey, AttestationHashDedupKey, RevocationReason,
+};
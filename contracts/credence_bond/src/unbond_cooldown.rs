@@ -0,0 +1,131 @@
+//! Unbond Cooldown
+//!
+//! Optional protocol-wide cooldown on exits. With an `unbond_delay` set, the identity cannot
+//! withdraw directly: `request_unbond(amount)` schedules the amount, which becomes claimable
+//! with `claim_unbonded` once the delay has passed. The scheduled amount is reserved from
+//! other withdrawals but stays slashable, so misbehavior caught during the cooldown still
+//! costs the full bond; the claim pays out at most what is left after slashes.
+
+use soroban_sdk::{contracttype, panic_with_error, Env, Symbol};
+
+use crate::{sub_bonds, unbonding_queue, BondError, IdentityBond};
+
+/// A scheduled withdrawal waiting out the cooldown.
+///
+/// # Fields
+/// * `amount` - Amount requested.
+/// * `requested_at` - Ledger timestamp of the request.
+/// * `unlock_at` - Timestamp from which the amount can be claimed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUnbond {
+    pub amount: i128,
+    pub requested_at: u64,
+    pub unlock_at: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum UnbondKey {
+    /// Cooldown in seconds (0 = disabled).
+    UnbondDelay,
+    /// Scheduled withdrawal of the bond identity.
+    PendingUnbond,
+}
+
+#[must_use]
+pub fn get_delay(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&UnbondKey::UnbondDelay)
+        .unwrap_or(0)
+}
+
+/// Set the cooldown. Admin only (enforced by caller). A pending request keeps the unlock
+/// time it was scheduled with.
+pub fn set_delay(e: &Env, seconds: u64) {
+    e.storage()
+        .instance()
+        .set(&UnbondKey::UnbondDelay, &seconds);
+}
+
+/// True when direct withdrawals must go through the cooldown.
+#[must_use]
+pub fn is_enabled(e: &Env) -> bool {
+    get_delay(e) > 0
+}
+
+#[must_use]
+pub fn get_pending(e: &Env) -> Option<PendingUnbond> {
+    e.storage().instance().get(&UnbondKey::PendingUnbond)
+}
+
+/// Amount scheduled by a pending request (0 without one).
+#[must_use]
+pub fn pending_amount(e: &Env) -> i128 {
+    get_pending(e).map(|p| p.amount).unwrap_or(0)
+}
+
+/// Amount reserved from direct withdrawals: queued unbondings, the scheduled request and
+/// sub-bonds.
+#[must_use]
+pub fn reserved(e: &Env) -> i128 {
+    unbonding_queue::get_pending_total(e)
+        .saturating_add(pending_amount(e))
+        .saturating_add(sub_bonds::locked(e))
+}
+
+/// Schedule `amount` for withdrawal after the cooldown. Caller must enforce identity auth.
+pub fn request(e: &Env, bond: &IdentityBond, amount: i128) -> PendingUnbond {
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    if get_pending(e).is_some() {
+        panic_with_error!(e, BondError::WithdrawalAlreadyRequested);
+    }
+    let reserved = unbonding_queue::get_pending_total(e)
+        .saturating_add(sub_bonds::locked(e))
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    if reserved > unbonding_queue::tvl(bond) {
+        panic_with_error!(e, BondError::InsufficientBalance);
+    }
+    let now = e.ledger().timestamp();
+    let pending = PendingUnbond {
+        amount,
+        requested_at: now,
+        unlock_at: now.saturating_add(get_delay(e)),
+    };
+    e.storage()
+        .instance()
+        .set(&UnbondKey::PendingUnbond, &pending);
+    e.events().publish(
+        (Symbol::new(e, "unbond_requested"), bond.identity.clone()),
+        (amount, pending.unlock_at),
+    );
+    pending
+}
+
+/// Release the pending request once unlocked, deducting it from `bond` in place. Slashes
+/// taken during the cooldown shrink the payout. Returns the amount released.
+pub fn claim(e: &Env, bond: &mut IdentityBond) -> i128 {
+    let pending =
+        get_pending(e).unwrap_or_else(|| panic_with_error!(e, BondError::WithdrawalNotRequested));
+    if e.ledger().timestamp() < pending.unlock_at {
+        panic_with_error!(e, BondError::NoticeNotElapsed);
+    }
+    let available =
+        (unbonding_queue::tvl(bond) - unbonding_queue::get_pending_total(e) - sub_bonds::locked(e))
+            .max(0);
+    let amount = pending.amount.min(available);
+    bond.bonded_amount = bond
+        .bonded_amount
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage().instance().remove(&UnbondKey::PendingUnbond);
+    e.events().publish(
+        (Symbol::new(e, "unbond_claimed"), bond.identity.clone()),
+        (amount, pending.amount - amount),
+    );
+    amount
+}
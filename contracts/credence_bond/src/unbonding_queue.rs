@@ -0,0 +1,311 @@
+//! Unbonding Queue
+//!
+//! Withdrawal requests enter a FIFO queue and are released subject to a per-epoch exit
+//! capacity, expressed in basis points of the total value locked (TVL) at the start of the
+//! epoch. During a rush of exits, each epoch only releases its capacity and the rest waits,
+//! preserving the bond's credibility guarantees. A request larger than the remaining capacity
+//! is partially processed and stays at the head of the queue.
+//!
+//! Once the admin sets a config, the queue is the only exit: `withdraw`, `withdraw_early`
+//! and `withdraw_bond` are refused so they cannot bypass the capacity.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{time_math, BondError, DataKey, IdentityBond};
+
+/// Default epoch length (1 day).
+pub const DEFAULT_EPOCH_LENGTH: u64 = 86_400;
+
+/// Default per-epoch exit capacity (10% of TVL).
+pub const DEFAULT_EXIT_CAPACITY_BPS: u32 = 1_000;
+
+/// Queue throttling config.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnbondingConfig {
+    /// Epoch length in seconds.
+    pub epoch_length: u64,
+    /// Maximum share of TVL released per epoch (basis points).
+    pub exit_capacity_bps: u32,
+}
+
+/// A queued withdrawal request.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnbondingRequest {
+    pub id: u64,
+    pub identity: Address,
+    pub amount: i128,
+    /// Amount already released to the identity.
+    pub processed_amount: i128,
+    pub requested_at: u64,
+}
+
+impl UnbondingRequest {
+    /// Amount still waiting in the queue.
+    #[must_use]
+    pub fn remaining(&self) -> i128 {
+        self.amount - self.processed_amount
+    }
+}
+
+/// Queue position and estimated release time for a request.
+///
+/// # Fields
+/// * `position` - Number of unfinished requests ahead of this one.
+/// * `amount_ahead` - Total amount still queued ahead of this one.
+/// * `eta` - Estimated timestamp at which the request is fully releasable (0 if done).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnbondingStatus {
+    pub position: u32,
+    pub amount_ahead: i128,
+    pub eta: u64,
+}
+
+/// Returns the queue config (defaults if never set).
+#[must_use]
+pub fn get_config(e: &Env) -> UnbondingConfig {
+    e.storage()
+        .instance()
+        .get(&DataKey::UnbondingConfig)
+        .unwrap_or(UnbondingConfig {
+            epoch_length: DEFAULT_EPOCH_LENGTH,
+            exit_capacity_bps: DEFAULT_EXIT_CAPACITY_BPS,
+        })
+}
+
+/// True once a config was set; direct withdrawals are then refused.
+#[must_use]
+pub fn is_configured(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::UnbondingConfig)
+}
+
+/// Set the queue config. Admin only (enforced by caller).
+pub fn set_config(e: &Env, epoch_length: u64, exit_capacity_bps: u32) {
+    if epoch_length == 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    if exit_capacity_bps == 0 || exit_capacity_bps > 10_000 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage().instance().set(
+        &DataKey::UnbondingConfig,
+        &UnbondingConfig {
+            epoch_length,
+            exit_capacity_bps,
+        },
+    );
+}
+
+fn get_u64(e: &Env, key: &DataKey) -> u64 {
+    e.storage().instance().get(key).unwrap_or(0)
+}
+
+/// Total amount waiting in the queue (reserved from direct withdrawals).
+#[must_use]
+pub fn get_pending_total(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::UnbondingPendingTotal)
+        .unwrap_or(0)
+}
+
+fn set_pending_total(e: &Env, amount: i128) {
+    e.storage()
+        .instance()
+        .set(&DataKey::UnbondingPendingTotal, &amount);
+}
+
+/// Number of unfinished requests in the queue.
+#[must_use]
+pub fn queue_length(e: &Env) -> u32 {
+    let head = get_u64(e, &DataKey::UnbondingHead);
+    let tail = get_u64(e, &DataKey::UnbondingTail);
+    (tail - head) as u32
+}
+
+/// Get a request by id.
+#[must_use]
+pub fn get_request(e: &Env, id: u64) -> Option<UnbondingRequest> {
+    e.storage().instance().get(&DataKey::UnbondingRequest(id))
+}
+
+/// Total value locked in the bond (bonded minus slashed).
+#[must_use]
+pub fn tvl(bond: &IdentityBond) -> i128 {
+    bond.bonded_amount
+        .checked_sub(bond.slashed_amount)
+        .expect("slashed amount exceeds bonded amount")
+        .max(0)
+}
+
+/// Per-epoch capacity for a TVL: `tvl * bps / 10_000`, at least 1 while TVL is positive so
+/// the queue can always drain.
+#[must_use]
+pub fn capacity_for(tvl: i128, exit_capacity_bps: u32) -> i128 {
+    if tvl <= 0 {
+        return 0;
+    }
+    let cap = tvl
+        .checked_mul(exit_capacity_bps as i128)
+        .expect("exit capacity overflow")
+        / 10_000;
+    cap.max(1)
+}
+
+/// Returns (epoch, capacity, used) for the current epoch. Capacity is snapshotted from the
+/// TVL the first time the epoch is touched.
+fn current_epoch(e: &Env, bond: &IdentityBond) -> (u64, i128, i128) {
+    let config = get_config(e);
+    let epoch = time_math::epoch_index(e.ledger().timestamp(), config.epoch_length);
+    if let Some((stored, capacity, used)) = e
+        .storage()
+        .instance()
+        .get::<_, (u64, i128, i128)>(&DataKey::UnbondingEpoch)
+    {
+        if stored == epoch {
+            return (epoch, capacity, used);
+        }
+    }
+    (epoch, capacity_for(tvl(bond), config.exit_capacity_bps), 0)
+}
+
+/// Append a withdrawal request to the queue. Caller must enforce identity auth.
+pub fn enqueue(e: &Env, bond: &IdentityBond, amount: i128) -> u64 {
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    let pending = get_pending_total(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    if pending
+        .saturating_add(crate::unbond_cooldown::pending_amount(e))
+        .saturating_add(crate::sub_bonds::locked(e))
+        > tvl(bond)
+    {
+        panic_with_error!(e, BondError::InsufficientBalance);
+    }
+    set_pending_total(e, pending);
+
+    let id = get_u64(e, &DataKey::UnbondingTail);
+    e.storage().instance().set(
+        &DataKey::UnbondingTail,
+        &id.checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow)),
+    );
+    let request = UnbondingRequest {
+        id,
+        identity: bond.identity.clone(),
+        amount,
+        processed_amount: 0,
+        requested_at: e.ledger().timestamp(),
+    };
+    e.storage()
+        .instance()
+        .set(&DataKey::UnbondingRequest(id), &request);
+    e.events().publish(
+        (Symbol::new(e, "unbonding_queued"), bond.identity.clone()),
+        (id, amount),
+    );
+    id
+}
+
+/// Release queued requests FIFO until the epoch capacity, `max_requests` or the queue is
+/// exhausted. Updates the bond in place; returns the total amount released.
+pub fn process(e: &Env, bond: &mut IdentityBond, max_requests: u32) -> i128 {
+    let (epoch, capacity, mut used) = current_epoch(e, bond);
+    let mut head = get_u64(e, &DataKey::UnbondingHead);
+    let tail = get_u64(e, &DataKey::UnbondingTail);
+    let mut pending = get_pending_total(e);
+    let mut released: i128 = 0;
+    let mut handled: u32 = 0;
+
+    while head < tail && handled < max_requests && used < capacity {
+        let mut request = get_request(e, head)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::UnbondingNotFound));
+        // A slash may have reduced the bond below what is queued; shrink the request.
+        let available = tvl(bond);
+        if request.remaining() > available {
+            let shortfall = request.remaining() - available;
+            request.amount -= shortfall;
+            pending -= shortfall;
+        }
+
+        let take = request.remaining().min(capacity - used);
+        bond.bonded_amount = bond
+            .bonded_amount
+            .checked_sub(take)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+        request.processed_amount += take;
+        used += take;
+        released += take;
+        pending -= take;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::UnbondingRequest(head), &request);
+        e.events().publish(
+            (
+                Symbol::new(e, "unbonding_processed"),
+                request.identity.clone(),
+            ),
+            (request.id, take, request.remaining()),
+        );
+        if request.remaining() > 0 {
+            break;
+        }
+        head += 1;
+        handled += 1;
+    }
+
+    e.storage().instance().set(&DataKey::UnbondingHead, &head);
+    e.storage()
+        .instance()
+        .set(&DataKey::UnbondingEpoch, &(epoch, capacity, used));
+    set_pending_total(e, pending);
+    released
+}
+
+/// Queue position and ETA for a request, estimating future epochs at the current TVL.
+#[must_use]
+pub fn status(e: &Env, bond: &IdentityBond, id: u64) -> UnbondingStatus {
+    let request =
+        get_request(e, id).unwrap_or_else(|| panic_with_error!(e, BondError::UnbondingNotFound));
+    let head = get_u64(e, &DataKey::UnbondingHead);
+    if id < head || request.remaining() == 0 {
+        return UnbondingStatus {
+            position: 0,
+            amount_ahead: 0,
+            eta: 0,
+        };
+    }
+
+    let mut amount_ahead: i128 = 0;
+    for ahead in head..id {
+        if let Some(r) = get_request(e, ahead) {
+            amount_ahead += r.remaining();
+        }
+    }
+
+    let config = get_config(e);
+    let (epoch, capacity, used) = current_epoch(e, bond);
+    let through = amount_ahead + request.remaining();
+    let left = capacity - used;
+    let eta = if through <= left {
+        e.ledger().timestamp()
+    } else {
+        let per_epoch = capacity_for(tvl(bond), config.exit_capacity_bps).max(1);
+        let outstanding = through - left;
+        let epochs = ((outstanding + per_epoch - 1) / per_epoch) as u64;
+        epoch
+            .saturating_add(epochs)
+            .saturating_mul(config.epoch_length)
+    };
+
+    UnbondingStatus {
+        position: (id - head) as u32,
+        amount_ahead,
+        eta,
+    }
+}
@@ -0,0 +1,177 @@
+//! Contract Upgrades
+//!
+//! The admin replaces the contract's WASM with `upgrade`. Storage is kept, so a build with
+//! a new layout must be followed by `migrate`. When upgrade governance is switched on, the
+//! new WASM hash must first be proposed and approved by the slash governors (same quorum
+//! and majority rules as slash proposals; delegations are not honoured), and each approved
+//! proposal can be executed once.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Symbol};
+
+use crate::{governance_approval, BondError};
+
+/// A proposal to upgrade to `wasm_hash`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeProposal {
+    pub id: u64,
+    pub wasm_hash: BytesN<32>,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    /// True once the upgrade has been applied.
+    pub executed: bool,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum UpgradeKey {
+    /// True if `upgrade` requires an approved proposal.
+    GovernanceRequired,
+    UpgradeNextId,
+    Proposal(u64),
+    /// Latest proposal for a WASM hash.
+    ForHash(BytesN<32>),
+    Vote(u64, Address),
+}
+
+#[must_use]
+pub fn is_governance_required(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&UpgradeKey::GovernanceRequired)
+        .unwrap_or(false)
+}
+
+/// Switch upgrade governance on or off. Admin only, or an approved action (enforced by caller).
+pub fn set_governance_required(e: &Env, required: bool) {
+    e.storage()
+        .instance()
+        .set(&UpgradeKey::GovernanceRequired, &required);
+    e.events()
+        .publish((Symbol::new(e, "upgrade_governance_set"),), required);
+}
+
+#[must_use]
+pub fn get_proposal(e: &Env, id: u64) -> Option<UpgradeProposal> {
+    e.storage().instance().get(&UpgradeKey::Proposal(id))
+}
+
+/// Latest proposal for `wasm_hash`, if any.
+#[must_use]
+pub fn get_proposal_for(e: &Env, wasm_hash: &BytesN<32>) -> Option<UpgradeProposal> {
+    let id: u64 = e
+        .storage()
+        .instance()
+        .get(&UpgradeKey::ForHash(wasm_hash.clone()))?;
+    get_proposal(e, id)
+}
+
+/// Propose upgrading to `wasm_hash`. Caller must check the proposer is the admin or a
+/// governor. Returns the proposal id.
+pub fn propose(e: &Env, proposer: &Address, wasm_hash: &BytesN<32>) -> u64 {
+    let id: u64 = e
+        .storage()
+        .instance()
+        .get(&UpgradeKey::UpgradeNextId)
+        .unwrap_or(0);
+    let next_id = id
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&UpgradeKey::UpgradeNextId, &next_id);
+    let proposal = UpgradeProposal {
+        id,
+        wasm_hash: wasm_hash.clone(),
+        proposed_by: proposer.clone(),
+        proposed_at: e.ledger().timestamp(),
+        executed: false,
+    };
+    e.storage()
+        .instance()
+        .set(&UpgradeKey::Proposal(id), &proposal);
+    e.storage()
+        .instance()
+        .set(&UpgradeKey::ForHash(wasm_hash.clone()), &id);
+    e.events().publish(
+        (Symbol::new(e, "upgrade_proposed"), proposer.clone()),
+        (id, wasm_hash.clone()),
+    );
+    id
+}
+
+/// Record a governor's vote on an open upgrade proposal.
+pub fn vote(e: &Env, voter: &Address, id: u64, approve: bool) {
+    let proposal =
+        get_proposal(e, id).unwrap_or_else(|| panic_with_error!(e, BondError::ProposalNotFound));
+    if proposal.executed {
+        panic_with_error!(e, BondError::ProposalNotOpen);
+    }
+    let governors = governance_approval::get_governors(e);
+    if governors.is_empty() {
+        panic_with_error!(e, BondError::GovernanceNotInitialized);
+    }
+    if !governors.contains(voter) {
+        panic_with_error!(e, BondError::NotGovernor);
+    }
+    let key = UpgradeKey::Vote(id, voter.clone());
+    if e.storage().instance().has(&key) {
+        panic_with_error!(e, BondError::AlreadyVoted);
+    }
+    e.storage().instance().set(&key, &approve);
+    e.events().publish(
+        (Symbol::new(e, "upgrade_vote_cast"), voter.clone()),
+        (id, approve),
+    );
+}
+
+/// True if the governors have approved proposal `id`.
+#[must_use]
+pub fn is_approved(e: &Env, id: u64) -> bool {
+    let governors = governance_approval::get_governors(e);
+    let mut approve = 0u32;
+    let mut voted = 0u32;
+    for g in governors.iter() {
+        if let Some(v) = e
+            .storage()
+            .instance()
+            .get::<_, bool>(&UpgradeKey::Vote(id, g))
+        {
+            voted += 1;
+            if v {
+                approve += 1;
+            }
+        }
+    }
+    !governors.is_empty() && governance_approval::meets_quorum(e, governors.len(), approve, voted)
+}
+
+/// Replace the contract WASM with `wasm_hash`, consuming its approved proposal when
+/// governance is required. Admin only (enforced by caller).
+pub fn apply(e: &Env, admin: &Address, wasm_hash: &BytesN<32>) {
+    if is_governance_required(e) {
+        let mut proposal = get_proposal_for(e, wasm_hash)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::ProposalNotFound));
+        if proposal.executed {
+            panic_with_error!(e, BondError::ProposalNotOpen);
+        }
+        if !is_approved(e, proposal.id) {
+            panic_with_error!(e, BondError::ProposalNotApproved);
+        }
+        proposal.executed = true;
+        e.storage()
+            .instance()
+            .set(&UpgradeKey::Proposal(proposal.id), &proposal);
+    }
+    update(e, admin, wasm_hash);
+}
+
+/// Replace the contract WASM with `wasm_hash` without checking upgrade proposals. Caller
+/// must have authorized the upgrade.
+pub(crate) fn update(e: &Env, by: &Address, wasm_hash: &BytesN<32>) {
+    e.deployer().update_current_contract_wasm(wasm_hash.clone());
+    e.events().publish(
+        (Symbol::new(e, "contract_upgraded"), by.clone()),
+        wasm_hash.clone(),
+    );
+}
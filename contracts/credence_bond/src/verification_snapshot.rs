@@ -0,0 +1,115 @@
+//! Verification Snapshot
+//!
+//! Verifier-heavy integrations need an identity's tier, attestation weight and slash state
+//! together. Reading them separately takes several storage reads and a scan of the subject's
+//! attestations. This module keeps a compact `VerificationSnapshot` per identity, updated
+//! incrementally whenever the bond or the identity's attestations change, so a verifier
+//! gets everything in one read.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{challenge_period, rolling_bond, tiered_bond, BondTier, DataKey, IdentityBond};
+
+/// Compact verification view of an identity.
+///
+/// # Fields
+/// * `tier` - Tier of the identity's bond (Bronze if it never bonded here).
+/// * `active_weight` - Sum of the weights of the identity's non-revoked attestations that
+///   have left their challenge period.
+/// * `pending_weight` - Sum of the weights of attestations still in their challenge period.
+/// * `attestation_count` - Number of non-revoked attestations, pending ones included.
+/// * `slash_ratio_bps` - Slashed amount over bonded amount, in basis points.
+/// * `updated_at` - Ledger timestamp of the last update.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationSnapshot {
+    pub tier: BondTier,
+    pub active_weight: u64,
+    pub pending_weight: u64,
+    pub attestation_count: u32,
+    pub slash_ratio_bps: u32,
+    pub updated_at: u64,
+}
+
+/// Storage keys of this module.
+#[contracttype]
+pub enum SnapshotKey {
+    /// Snapshot of an identity.
+    Snapshot(Address),
+}
+
+/// Snapshot of `identity` (empty snapshot if nothing was ever recorded).
+#[must_use]
+pub fn get(e: &Env, identity: &Address) -> VerificationSnapshot {
+    e.storage()
+        .instance()
+        .get(&SnapshotKey::Snapshot(identity.clone()))
+        .unwrap_or(VerificationSnapshot {
+            tier: BondTier::Bronze,
+            active_weight: 0,
+            pending_weight: 0,
+            attestation_count: 0,
+            slash_ratio_bps: 0,
+            updated_at: 0,
+        })
+}
+
+/// Snapshot of `identity` with the weight of attestations still in their challenge period
+/// moved from `active_weight` to `pending_weight`.
+#[must_use]
+pub fn view(e: &Env, identity: &Address) -> VerificationSnapshot {
+    let mut snapshot = get(e, identity);
+    let pending = challenge_period::pending_weight(e, identity, e.ledger().timestamp());
+    snapshot.active_weight = snapshot.active_weight.saturating_sub(pending);
+    snapshot.pending_weight = pending;
+    snapshot
+}
+
+fn save(e: &Env, identity: &Address, mut snapshot: VerificationSnapshot) {
+    snapshot.updated_at = e.ledger().timestamp();
+    e.storage()
+        .instance()
+        .set(&SnapshotKey::Snapshot(identity.clone()), &snapshot);
+}
+
+/// Slashed share of the bonded amount in basis points (capped at 10_000).
+#[must_use]
+pub fn slash_ratio_bps(bonded: i128, slashed: i128) -> u32 {
+    if bonded <= 0 || slashed <= 0 {
+        return 0;
+    }
+    if slashed >= bonded {
+        return 10_000;
+    }
+    (slashed.saturating_mul(10_000) / bonded) as u32
+}
+
+/// Update the bond-derived fields (tier, slash ratio) from the current bond. No-op without
+/// a bond.
+pub fn refresh_bond(e: &Env) {
+    let bond: IdentityBond = match e.storage().instance().get(&DataKey::Bond) {
+        Some(b) => b,
+        None => return,
+    };
+    let mut snapshot = get(e, &bond.identity);
+    snapshot.tier =
+        tiered_bond::get_tier_for_amount(e, rolling_bond::effective_tier_amount(e, &bond));
+    snapshot.slash_ratio_bps = slash_ratio_bps(bond.bonded_amount, bond.slashed_amount);
+    save(e, &bond.identity, snapshot);
+}
+
+/// Account for a new attestation of `weight` on `subject`.
+pub fn on_attestation_added(e: &Env, subject: &Address, weight: u32) {
+    let mut snapshot = get(e, subject);
+    snapshot.attestation_count = snapshot.attestation_count.saturating_add(1);
+    snapshot.active_weight = snapshot.active_weight.saturating_add(weight as u64);
+    save(e, subject, snapshot);
+}
+
+/// Account for the revocation of an attestation of `weight` on `subject`.
+pub fn on_attestation_revoked(e: &Env, subject: &Address, weight: u32) {
+    let mut snapshot = get(e, subject);
+    snapshot.attestation_count = snapshot.attestation_count.saturating_sub(1);
+    snapshot.active_weight = snapshot.active_weight.saturating_sub(weight as u64);
+    save(e, subject, snapshot);
+}
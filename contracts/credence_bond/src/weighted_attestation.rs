@@ -1,13 +1,17 @@
 //! Weighted attestation system: attestation value depends on attester's credibility.
 //!
-//! Weight is derived from the attester's bond (or configured stake), with
-//! a configurable multiplier and a protocol cap. When attester bond changes,
-//! new attestations use the new weight; existing attestations retain their stored weight.
+//! Weight is derived from the attester's live bond (bonded minus slashed amount), or from a
+//! stake the admin configured as an override, with a configurable multiplier and a protocol
+//! cap. When attester bond changes, new attestations use the new weight; existing
+//! attestations retain their stored weight.
+//!
+//! A subject's aggregate weight caps each verifier's contribution at a configurable fraction
+//! of the subject's raw total, so a single (possibly Sybil) verifier cannot dominate it.
 
-use soroban_sdk::Env;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, Vec};
 
 use crate::types::attestation::MAX_ATTESTATION_WEIGHT;
-use crate::DataKey;
+use crate::{BondError, DataKey};
 
 /// Default weight multiplier in basis points (1 = 0.01%). weight = stake * multiplier_bps / 10_000.
 pub const DEFAULT_WEIGHT_MULTIPLIER_BPS: u32 = 100;
@@ -15,6 +19,21 @@ pub const DEFAULT_WEIGHT_MULTIPLIER_BPS: u32 = 100;
 /// Default maximum attestation weight when no config is set.
 pub const DEFAULT_MAX_WEIGHT: u32 = 100_000;
 
+/// Default per-verifier share cap of a subject's aggregate weight (100% = no cap).
+pub const DEFAULT_VERIFIER_CAP_BPS: u32 = 10_000;
+
+/// Where an attester's stake, and so its attestation weight, came from.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WeightSource {
+    /// No stake: the default weight applies.
+    Default = 0,
+    /// The attester's own bond, net of slashes.
+    Bond = 1,
+    /// A stake set by the admin with `set_attester_stake`.
+    Override = 2,
+}
+
 /// Storage key for weight config (multiplier bps, max weight). Stored as (u32, u32).
 fn weight_config_key(e: &Env) -> soroban_sdk::Symbol {
     soroban_sdk::Symbol::new(e, "weight_cfg")
@@ -37,7 +56,7 @@ pub fn set_weight_config(e: &Env, multiplier_bps: u32, max_weight: u32) {
         .set(&weight_config_key(e), &(multiplier_bps, cap));
 }
 
-/// Returns the attester's stake (bond amount or configured stake). 0 if not set.
+/// Returns the attester's stake override. 0 if not set.
 #[must_use]
 pub fn get_attester_stake(e: &Env, attester: &soroban_sdk::Address) -> i128 {
     e.storage()
@@ -46,32 +65,122 @@ pub fn get_attester_stake(e: &Env, attester: &soroban_sdk::Address) -> i128 {
         .unwrap_or(0)
 }
 
-/// Sets attester stake (e.g. from bond). Caller must be admin.
+/// Returns the stake weight is computed from and its source: the admin override if set,
+/// otherwise the attester's active bond net of slashes, otherwise 0.
+#[must_use]
+pub fn effective_stake(e: &Env, attester: &Address) -> (i128, WeightSource) {
+    let key = DataKey::AttesterStake(attester.clone());
+    if e.storage().instance().has(&key) {
+        return (get_attester_stake(e, attester), WeightSource::Override);
+    }
+    let bond: Option<crate::IdentityBond> = e.storage().instance().get(&DataKey::Bond);
+    match bond {
+        Some(b) if b.active && b.identity == *attester => (
+            b.bonded_amount.saturating_sub(b.slashed_amount).max(0),
+            WeightSource::Bond,
+        ),
+        _ => (0, WeightSource::Default),
+    }
+}
+
+/// Sets an attester stake override, used instead of the attester's bond. Caller must be admin.
 pub fn set_attester_stake(e: &Env, attester: &soroban_sdk::Address, amount: i128) {
     if amount < 0 {
-        panic!("attester stake cannot be negative");
+        panic_with_error!(e, BondError::InvalidAmount);
     }
     e.storage()
         .instance()
         .set(&DataKey::AttesterStake(attester.clone()), &amount);
 }
 
+/// Removes an attester stake override so weight follows the attester's bond again. Caller
+/// must be admin.
+pub fn clear_attester_stake(e: &Env, attester: &Address) {
+    e.storage()
+        .instance()
+        .remove(&DataKey::AttesterStake(attester.clone()));
+}
+
 /// Computes attestation weight from attester stake using config. Capped by config max and MAX_ATTESTATION_WEIGHT.
 /// If stake is 0, returns default weight (1) so attestations are still allowed.
 #[must_use]
 pub fn compute_weight(e: &Env, attester: &soroban_sdk::Address) -> u32 {
+    compute_weight_with_source(e, attester).0
+}
+
+/// Same as [`compute_weight`], also returning where the stake came from.
+#[must_use]
+pub fn compute_weight_with_source(e: &Env, attester: &Address) -> (u32, WeightSource) {
     use crate::types::attestation::DEFAULT_ATTESTATION_WEIGHT;
 
-    let stake = get_attester_stake(e, attester);
+    let (stake, source) = effective_stake(e, attester);
     let (multiplier_bps, max_weight) = get_weight_config(e);
 
-    if stake <= 0 {
-        return DEFAULT_ATTESTATION_WEIGHT;
+    if stake <= 0 || !crate::features::is_enabled(e, crate::features::FEATURE_WEIGHTED_ATTESTATION)
+    {
+        return (DEFAULT_ATTESTATION_WEIGHT, WeightSource::Default);
     }
 
     // weight = (stake * multiplier_bps / 10_000) capped at max_weight and MAX_ATTESTATION_WEIGHT
     let stake_u64 = stake.unsigned_abs() as u64;
     let w = (stake_u64 * (multiplier_bps as u64) / 10_000) as u32;
     let capped = core::cmp::min(w, max_weight);
-    core::cmp::min(capped, MAX_ATTESTATION_WEIGHT).max(DEFAULT_ATTESTATION_WEIGHT)
+    (
+        core::cmp::min(capped, MAX_ATTESTATION_WEIGHT).max(DEFAULT_ATTESTATION_WEIGHT),
+        source,
+    )
+}
+
+/// Returns the max share (bps) of a subject's raw total weight one verifier may contribute.
+#[must_use]
+pub fn get_verifier_cap_bps(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::VerifierWeightCapBps)
+        .unwrap_or(DEFAULT_VERIFIER_CAP_BPS)
+}
+
+/// Sets the per-verifier share cap (admin only; caller must enforce).
+pub fn set_verifier_cap_bps(e: &Env, cap_bps: u32) {
+    if cap_bps == 0 || cap_bps > 10_000 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&DataKey::VerifierWeightCapBps, &cap_bps);
+}
+
+/// Caps each verifier's weight at `cap_bps` of the raw total and returns the capped sum.
+#[must_use]
+pub fn apply_verifier_cap(per_verifier: &Vec<u64>, cap_bps: u32) -> u64 {
+    let raw_total: u64 = per_verifier
+        .iter()
+        .fold(0_u64, |acc, w| acc.saturating_add(w));
+    let cap = ((raw_total as u128) * (cap_bps as u128) / 10_000) as u64;
+    per_verifier
+        .iter()
+        .fold(0_u64, |acc, w| acc.saturating_add(core::cmp::min(w, cap)))
+}
+
+/// Aggregate weight of a subject's active attestations with the per-verifier cap applied.
+#[must_use]
+pub fn aggregate_weight(e: &Env, subject: &Address) -> u64 {
+    let ids: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&DataKey::SubjectAttestations(subject.clone()))
+        .unwrap_or(Vec::new(e));
+    let now = e.ledger().timestamp();
+    let mut by_verifier: Map<Address, u64> = Map::new(e);
+    for id in ids.iter() {
+        let att: Option<crate::Attestation> = e.storage().instance().get(&DataKey::Attestation(id));
+        if let Some(att) = att {
+            if att.is_active_at(now) {
+                let current = by_verifier.get(att.verifier.clone()).unwrap_or(0);
+                let weight = crate::counter_attestation::net_weight(e, &att);
+                by_verifier.set(att.verifier, current.saturating_add(weight as u64));
+            }
+        }
+    }
+    apply_verifier_cap(&by_verifier.values(), get_verifier_cap_bps(e))
 }
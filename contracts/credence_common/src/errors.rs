@@ -0,0 +1,77 @@
+//! # Error-Code Namespace
+//!
+//! Each Credence contract allocates its `#[contracterror]` codes from its own range, so a
+//! client integrating several contracts never sees the same numeric code with two
+//! meanings. Codes are allocated sequentially from the start of the range and never reused.
+//!
+//! | Range   | Owner                                     |
+//! |---------|-------------------------------------------|
+//! | 100-199 | `credence_bond` (bond lifecycle)          |
+//! | 200-299 | `credence_bond` (attestations)            |
+//! | 300-399 | `credence_bond` (governance / slashing)   |
+//! | 400-499 | `credence_treasury`                       |
+//! | 500-599 | `dispute_resolution`                      |
+//! | 600-699 | `arbitration`                             |
+//! | 700-799 | `credence_delegation`                     |
+//! | 800-899 | `credence_registry`                       |
+//!
+//! `#[contracterror]` discriminants must be literals, so contracts spell out the numbers
+//! and check them against these ranges in their tests.
+
+use core::ops::Range;
+
+/// Bond lifecycle errors.
+pub const BOND: Range<u32> = 100..200;
+/// Attestation errors.
+pub const ATTESTATION: Range<u32> = 200..300;
+/// Governance and slashing errors.
+pub const GOVERNANCE: Range<u32> = 300..400;
+/// Treasury errors.
+pub const TREASURY: Range<u32> = 400..500;
+/// Dispute resolution errors.
+pub const DISPUTE: Range<u32> = 500..600;
+/// Arbitration errors.
+pub const ARBITRATION: Range<u32> = 600..700;
+/// Delegation errors.
+pub const DELEGATION: Range<u32> = 700..800;
+/// Registry errors.
+pub const REGISTRY: Range<u32> = 800..900;
+
+/// Every allocated range, in ascending order.
+pub const ALL: [Range<u32>; 8] = [
+    BOND,
+    ATTESTATION,
+    GOVERNANCE,
+    TREASURY,
+    DISPUTE,
+    ARBITRATION,
+    DELEGATION,
+    REGISTRY,
+];
+
+/// Returns the range that owns `code`, if any.
+#[must_use]
+pub fn owner_of(code: u32) -> Option<Range<u32>> {
+    ALL.into_iter().find(|r| r.contains(&code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranges_do_not_overlap() {
+        for pair in ALL.windows(2) {
+            assert!(pair[0].start < pair[0].end);
+            assert!(pair[0].end <= pair[1].start);
+        }
+    }
+
+    #[test]
+    fn test_owner_of() {
+        assert_eq!(owner_of(101), Some(BOND));
+        assert_eq!(owner_of(513), Some(DISPUTE));
+        assert_eq!(owner_of(42), None);
+        assert_eq!(owner_of(900), None);
+    }
+}
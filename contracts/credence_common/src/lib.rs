@@ -0,0 +1,6 @@
+#![no_std]
+
+//! Definitions shared by all Credence contracts. Dependency-free so it can be used from
+//! contracts built against different Soroban SDK versions.
+
+pub mod errors;
@@ -27,13 +27,36 @@ pub struct Delegation {
     pub revoked: bool,
 }
 
+/// Complete delegation configuration, for diffing deployments (see `export_config`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegationConfig {
+    pub admin: Option<Address>,
+    pub pending_admin: Option<Address>,
+    pub schema_version: u32,
+    pub features: u32,
+}
+
+/// Feature flag: attestation delegations may be created.
+pub const FEATURE_ATTESTATION_DELEGATION: u32 = 1 << 0;
+/// Feature flag: management delegations may be created.
+pub const FEATURE_MANAGEMENT_DELEGATION: u32 = 1 << 1;
+/// All features known to this contract version.
+pub const ALL_FEATURES: u32 = FEATURE_ATTESTATION_DELEGATION | FEATURE_MANAGEMENT_DELEGATION;
+
 #[contracttype]
 #[derive(Clone)]
 enum DataKey {
     Admin,
     Delegation(Address, Address, DelegationType),
+    Features,
+    SchemaVersion,
+    PendingAdmin,
 }
 
+/// Storage layout version this build reads and writes (see `migrate`).
+pub const SCHEMA_VERSION: u32 = 1;
+
 #[contract]
 pub struct CredenceDelegation;
 
@@ -45,6 +68,92 @@ impl CredenceDelegation {
             panic!("already initialized");
         }
         e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+    }
+
+    /// Bring stored data from schema `from_version` up to the layout of this build.
+    /// Idempotent: returns the current version without changes once migrated. Admin only.
+    pub fn migrate(e: Env, from_version: u32) -> u32 {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        let stored = Self::get_schema_version(e.clone());
+        if stored == SCHEMA_VERSION {
+            return stored;
+        }
+        if from_version != stored {
+            panic!("schema version mismatch");
+        }
+        if stored > SCHEMA_VERSION {
+            panic!("schema version newer than contract");
+        }
+        // 0 -> 1: versioning introduced; the layout itself is unchanged.
+        e.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        e.events().publish(
+            (Symbol::new(&e, "schema_migrated"),),
+            (stored, SCHEMA_VERSION),
+        );
+        SCHEMA_VERSION
+    }
+
+    /// Nominate `new_admin` as the next admin. The current admin stays in charge until the
+    /// nominee calls `accept_admin`; a new nomination replaces a pending one. Admin only.
+    pub fn transfer_admin(e: Env, new_admin: Address) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        e.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        e.events().publish(
+            (Symbol::new(&e, "admin_transfer_started"), admin),
+            new_admin,
+        );
+    }
+
+    /// Complete a pending admin transfer. Requires the nominee's auth. Returns the new admin.
+    pub fn accept_admin(e: Env) -> Address {
+        let new_admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic!("no pending admin transfer"));
+        new_admin.require_auth();
+        let previous: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        e.storage().instance().set(&DataKey::Admin, &new_admin);
+        e.storage().instance().remove(&DataKey::PendingAdmin);
+        e.events().publish(
+            (Symbol::new(&e, "admin_transferred"),),
+            (previous, new_admin.clone()),
+        );
+        new_admin
+    }
+
+    /// Nominated admin awaiting acceptance, if any.
+    pub fn get_pending_admin(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Storage layout version of the stored data (0 if deployed before versioning).
+    pub fn get_schema_version(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(0)
     }
 
     /// Create a delegation from owner to delegate with a given type and expiry.
@@ -57,6 +166,14 @@ impl CredenceDelegation {
     ) -> Delegation {
         owner.require_auth();
 
+        let feature = match delegation_type {
+            DelegationType::Attestation => FEATURE_ATTESTATION_DELEGATION,
+            DelegationType::Management => FEATURE_MANAGEMENT_DELEGATION,
+        };
+        if Self::get_features(e.clone()) & feature == 0 {
+            panic!("feature disabled");
+        }
+
         if expires_at <= e.ledger().timestamp() {
             panic!("expiry must be in the future");
         }
@@ -78,6 +195,40 @@ impl CredenceDelegation {
         d
     }
 
+    /// Enabled optional subsystems as a bitmask (FEATURE_*). All features if never set.
+    pub fn get_features(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::Features)
+            .unwrap_or(ALL_FEATURES)
+    }
+
+    /// Replace the enabled feature mask. Admin only.
+    pub fn set_features(e: Env, features: u32) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        if features & !ALL_FEATURES != 0 {
+            panic!("unknown feature flag");
+        }
+        e.storage().instance().set(&DataKey::Features, &features);
+        e.events()
+            .publish((Symbol::new(&e, "features_updated"),), features);
+    }
+
+    /// Complete current configuration (admin, feature flags).
+    pub fn export_config(e: Env) -> DelegationConfig {
+        DelegationConfig {
+            admin: e.storage().instance().get(&DataKey::Admin),
+            pending_admin: e.storage().instance().get(&DataKey::PendingAdmin),
+            schema_version: Self::get_schema_version(e.clone()),
+            features: Self::get_features(e),
+        }
+    }
+
     /// Revoke an existing delegation. Only the owner can revoke.
     pub fn revoke_delegation(
         e: Env,
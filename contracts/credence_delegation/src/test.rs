@@ -344,3 +344,72 @@ fn test_revoke_attestation_does_not_affect_management() {
     // Management delegation is unaffected
     assert!(client.is_valid_delegate(&attester, &subject, &DelegationType::Management));
 }
+
+// ---------------------------------------------------------------------------
+// Feature flags
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_features_default_and_update() {
+    let (_e, client) = setup();
+    assert_eq!(client.get_features(), ALL_FEATURES);
+    client.set_features(&FEATURE_ATTESTATION_DELEGATION);
+    assert_eq!(client.get_features(), FEATURE_ATTESTATION_DELEGATION);
+}
+
+#[test]
+#[should_panic(expected = "feature disabled")]
+fn test_delegate_when_feature_disabled() {
+    let (e, client) = setup();
+    client.set_features(&FEATURE_ATTESTATION_DELEGATION);
+    let owner = Address::generate(&e);
+    let delegate = Address::generate(&e);
+    client.delegate(&owner, &delegate, &DelegationType::Management, &86400_u64);
+}
+
+#[test]
+fn test_export_config() {
+    let (_e, client) = setup();
+    client.set_features(&FEATURE_MANAGEMENT_DELEGATION);
+    let config = client.export_config();
+    assert!(config.admin.is_some());
+    assert_eq!(config.features, FEATURE_MANAGEMENT_DELEGATION);
+}
+
+#[test]
+fn test_schema_version_and_migrate() {
+    let (e, client) = setup();
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    // Simulate an instance deployed before versioning.
+    e.as_contract(&client.address, || {
+        e.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 0);
+    assert!(client.try_migrate(&5).is_err());
+    assert_eq!(client.migrate(&0), SCHEMA_VERSION);
+    assert_eq!(client.migrate(&0), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let (e, client) = setup();
+    let admin = client.export_config().admin.unwrap();
+    let new_admin = Address::generate(&e);
+    client.transfer_admin(&new_admin);
+    assert_eq!(e.auths()[0].0, admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    assert_eq!(client.accept_admin(), new_admin);
+    assert_eq!(e.auths()[0].0, new_admin);
+    assert_eq!(client.export_config().admin, Some(new_admin));
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+#[should_panic(expected = "no pending admin transfer")]
+fn test_accept_admin_without_transfer() {
+    let (_e, client) = setup();
+    client.accept_admin();
+}
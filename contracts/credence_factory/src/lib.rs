@@ -0,0 +1,295 @@
+#![no_std]
+
+//! Credence Factory
+//!
+//! Deploys a full protocol instance (bond, treasury, dispute resolution, arbitration,
+//! delegation) from uploaded WASM hashes in a single invocation, initializes every contract
+//! with the same admin, wires the cross-contract addresses, and records the deployed
+//! addresses for discovery via `get_instance(instance_id)`.
+//!
+//! Wiring performed on each new instance:
+//! - every component is initialized with the instance admin;
+//! - the bond's fee treasury is set to the instance treasury (fee 0 bps until configured);
+//! - the bond is registered as a treasury depositor;
+//! - the bond's address book lists the instance dispute, arbitration and delegation contracts;
+//! - dispute resolution escalates to the instance arbitration contract.
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, vec, Address, BytesN, Env, IntoVal, Symbol, Val, Vec,
+};
+
+/// WASM hashes of the protocol components, as uploaded to the network.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolWasm {
+    pub bond: BytesN<32>,
+    pub treasury: BytesN<32>,
+    pub dispute: BytesN<32>,
+    pub arbitration: BytesN<32>,
+    pub delegation: BytesN<32>,
+}
+
+/// Addresses of one deployed protocol instance.
+///
+/// # Fields
+/// * `admin` - Admin of every component.
+/// * `bond` / `treasury` / `dispute` / `arbitration` / `delegation` - Component contracts.
+/// * `deployed_at` - Ledger timestamp of the deployment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolInstance {
+    pub admin: Address,
+    pub bond: Address,
+    pub treasury: Address,
+    pub dispute: Address,
+    pub arbitration: Address,
+    pub delegation: Address,
+    pub deployed_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Admin,
+    Wasm,
+    InstanceCount,
+    Instance(u64),
+    SchemaVersion,
+}
+
+/// Component indices, mixed into the deployment salt so each component gets its own address.
+const COMPONENT_BOND: u8 = 0;
+const COMPONENT_TREASURY: u8 = 1;
+const COMPONENT_DISPUTE: u8 = 2;
+const COMPONENT_ARBITRATION: u8 = 3;
+const COMPONENT_DELEGATION: u8 = 4;
+
+/// Storage layout version this build reads and writes (see `migrate`).
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[contract]
+pub struct CredenceFactory;
+
+#[contractimpl]
+impl CredenceFactory {
+    /// Initialize the factory with the admin allowed to set WASM hashes.
+    pub fn initialize(e: Env, admin: Address) {
+        if e.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+    }
+
+    /// Bring stored data from schema `from_version` up to the layout of this build.
+    /// Idempotent: returns the current version without changes once migrated. Admin only.
+    pub fn migrate(e: Env, from_version: u32) -> u32 {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        let stored = Self::get_schema_version(e.clone());
+        if stored == SCHEMA_VERSION {
+            return stored;
+        }
+        if from_version != stored {
+            panic!("schema version mismatch");
+        }
+        if stored > SCHEMA_VERSION {
+            panic!("schema version newer than contract");
+        }
+        // 0 -> 1: versioning introduced; the layout itself is unchanged.
+        e.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        e.events().publish(
+            (Symbol::new(&e, "schema_migrated"),),
+            (stored, SCHEMA_VERSION),
+        );
+        SCHEMA_VERSION
+    }
+
+    /// Storage layout version of the stored data (0 if deployed before versioning).
+    pub fn get_schema_version(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(0)
+    }
+
+    /// Set the WASM hashes new instances are deployed from. Admin only.
+    pub fn set_wasm_hashes(e: Env, wasm: ProtocolWasm) {
+        Self::require_admin(&e);
+        e.storage().instance().set(&DataKey::Wasm, &wasm);
+        e.events()
+            .publish((Symbol::new(&e, "wasm_hashes_updated"),), wasm);
+    }
+
+    pub fn get_wasm_hashes(e: Env) -> Option<ProtocolWasm> {
+        e.storage().instance().get(&DataKey::Wasm)
+    }
+
+    /// Deploy, initialize and wire a protocol instance administered by `admin`.
+    /// Returns the new instance id.
+    pub fn deploy_instance(e: Env, admin: Address) -> u64 {
+        admin.require_auth();
+        if !e.storage().instance().has(&DataKey::Admin) {
+            panic!("not initialized");
+        }
+        let wasm: ProtocolWasm = e
+            .storage()
+            .instance()
+            .get(&DataKey::Wasm)
+            .unwrap_or_else(|| panic!("wasm hashes not set"));
+        let instance_id: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::InstanceCount)
+            .unwrap_or(0);
+
+        let deploy = |component: u8, hash: BytesN<32>| -> Address {
+            e.deployer()
+                .with_current_contract(salt(&e, instance_id, component))
+                .deploy_v2(hash, ())
+        };
+        let instance = ProtocolInstance {
+            admin: admin.clone(),
+            bond: deploy(COMPONENT_BOND, wasm.bond),
+            treasury: deploy(COMPONENT_TREASURY, wasm.treasury),
+            dispute: deploy(COMPONENT_DISPUTE, wasm.dispute),
+            arbitration: deploy(COMPONENT_ARBITRATION, wasm.arbitration),
+            delegation: deploy(COMPONENT_DELEGATION, wasm.delegation),
+            deployed_at: e.ledger().timestamp(),
+        };
+        wire(&e, &instance);
+        record(&e, &instance)
+    }
+
+    /// Addresses of a deployed instance.
+    pub fn get_instance(e: Env, instance_id: u64) -> ProtocolInstance {
+        e.storage()
+            .instance()
+            .get(&DataKey::Instance(instance_id))
+            .unwrap_or_else(|| panic!("instance not found"))
+    }
+
+    /// Number of instances deployed so far (ids are `0..count`).
+    pub fn get_instance_count(e: Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::InstanceCount)
+            .unwrap_or(0)
+    }
+
+    pub fn get_admin(e: Env) -> Address {
+        e.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"))
+    }
+}
+
+impl CredenceFactory {
+    fn require_admin(e: &Env) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+    }
+}
+
+/// Deterministic salt for a component of an instance: `[component, 0.., instance_id (be)]`.
+fn salt(e: &Env, instance_id: u64, component: u8) -> BytesN<32> {
+    let mut bytes = [0u8; 32];
+    bytes[0] = component;
+    bytes[24..].copy_from_slice(&instance_id.to_be_bytes());
+    BytesN::from_array(e, &bytes)
+}
+
+fn call(e: &Env, contract: &Address, func: &str, args: Vec<Val>) {
+    e.invoke_contract::<()>(contract, &Symbol::new(e, func), args);
+}
+
+/// Initialize every component with the instance admin and connect them to each other.
+pub(crate) fn wire(e: &Env, instance: &ProtocolInstance) {
+    let admin = &instance.admin;
+    for contract in [
+        &instance.bond,
+        &instance.treasury,
+        &instance.dispute,
+        &instance.arbitration,
+        &instance.delegation,
+    ] {
+        call(e, contract, "initialize", vec![e, admin.into_val(e)]);
+    }
+    call(
+        e,
+        &instance.bond,
+        "set_fee_config",
+        vec![
+            e,
+            admin.into_val(e),
+            instance.treasury.into_val(e),
+            0_u32.into_val(e),
+        ],
+    );
+    call(
+        e,
+        &instance.treasury,
+        "add_depositor",
+        vec![e, instance.bond.into_val(e)],
+    );
+    for (role, address) in [
+        ("dispute", &instance.dispute),
+        ("arbitration", &instance.arbitration),
+        ("delegation", &instance.delegation),
+    ] {
+        call(
+            e,
+            &instance.bond,
+            "set_role_address",
+            vec![
+                e,
+                admin.into_val(e),
+                Symbol::new(e, role).into_val(e),
+                Some(address.clone()).into_val(e),
+            ],
+        );
+    }
+    call(
+        e,
+        &instance.dispute,
+        "set_arbitration_contract",
+        vec![e, instance.arbitration.into_val(e)],
+    );
+}
+
+/// Store a wired instance under the next id and announce it.
+pub(crate) fn record(e: &Env, instance: &ProtocolInstance) -> u64 {
+    let instance_id: u64 = e
+        .storage()
+        .instance()
+        .get(&DataKey::InstanceCount)
+        .unwrap_or(0);
+    e.storage()
+        .instance()
+        .set(&DataKey::Instance(instance_id), instance);
+    e.storage().instance().set(
+        &DataKey::InstanceCount,
+        &instance_id.checked_add(1).expect("instance id overflow"),
+    );
+    e.events().publish(
+        (Symbol::new(e, "instance_deployed"), instance_id),
+        instance.clone(),
+    );
+    instance_id
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,242 @@
+#![cfg(test)]
+
+use super::*;
+use credence_bond::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::Env;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Stand-in for the components the test cannot register natively: records the wiring calls.
+#[contract]
+pub struct MockComponent;
+
+#[contracttype]
+#[derive(Clone)]
+enum MockKey {
+    Admin,
+    Depositor(Address),
+    Arbitration,
+}
+
+#[contractimpl]
+impl MockComponent {
+    pub fn initialize(e: Env, admin: Address) {
+        if e.storage().instance().has(&MockKey::Admin) {
+            panic!("already initialized");
+        }
+        e.storage().instance().set(&MockKey::Admin, &admin);
+    }
+
+    pub fn add_depositor(e: Env, depositor: Address) {
+        e.storage()
+            .instance()
+            .set(&MockKey::Depositor(depositor), &true);
+    }
+
+    pub fn set_arbitration_contract(e: Env, arbitration: Address) {
+        e.storage()
+            .instance()
+            .set(&MockKey::Arbitration, &arbitration);
+    }
+
+    pub fn arbitration(e: Env) -> Option<Address> {
+        e.storage().instance().get(&MockKey::Arbitration)
+    }
+
+    pub fn admin(e: Env) -> Option<Address> {
+        e.storage().instance().get(&MockKey::Admin)
+    }
+
+    pub fn is_depositor(e: Env, depositor: Address) -> bool {
+        e.storage().instance().has(&MockKey::Depositor(depositor))
+    }
+}
+
+fn setup() -> (Env, CredenceFactoryClient<'static>, Address) {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let contract_id = e.register(CredenceFactory, ());
+    let client = CredenceFactoryClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    (e, client, admin)
+}
+
+fn wasm(e: &Env) -> ProtocolWasm {
+    ProtocolWasm {
+        bond: BytesN::from_array(e, &[1; 32]),
+        treasury: BytesN::from_array(e, &[2; 32]),
+        dispute: BytesN::from_array(e, &[3; 32]),
+        arbitration: BytesN::from_array(e, &[4; 32]),
+        delegation: BytesN::from_array(e, &[5; 32]),
+    }
+}
+
+/// Components registered natively, in place of a WASM deployment.
+fn components(e: &Env, admin: &Address) -> ProtocolInstance {
+    ProtocolInstance {
+        admin: admin.clone(),
+        bond: e.register(CredenceBond, ()),
+        treasury: e.register(MockComponent, ()),
+        dispute: e.register(MockComponent, ()),
+        arbitration: e.register(MockComponent, ()),
+        delegation: e.register(MockComponent, ()),
+        deployed_at: e.ledger().timestamp(),
+    }
+}
+
+fn wire_and_record(e: &Env, factory: &Address, instance: &ProtocolInstance) -> u64 {
+    e.as_contract(factory, || {
+        wire(e, instance);
+        record(e, instance)
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Configuration
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_set_wasm_hashes() {
+    let (e, client, admin) = setup();
+    assert_eq!(client.get_wasm_hashes(), None);
+    client.set_wasm_hashes(&wasm(&e));
+    assert_eq!(client.get_wasm_hashes(), Some(wasm(&e)));
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+#[should_panic(expected = "already initialized")]
+fn test_initialize_twice() {
+    let (e, client, _admin) = setup();
+    client.initialize(&Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "not initialized")]
+fn test_set_wasm_hashes_requires_initialize() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let client = CredenceFactoryClient::new(&e, &e.register(CredenceFactory, ()));
+    client.set_wasm_hashes(&wasm(&e));
+}
+
+#[test]
+#[should_panic(expected = "wasm hashes not set")]
+fn test_deploy_requires_wasm_hashes() {
+    let (e, client, _admin) = setup();
+    client.deploy_instance(&Address::generate(&e));
+}
+
+// ---------------------------------------------------------------------------
+// Wiring and discovery
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_wire_initializes_components_with_admin() {
+    let (e, client, _admin) = setup();
+    let instance_admin = Address::generate(&e);
+    let instance = components(&e, &instance_admin);
+    wire_and_record(&e, &client.address, &instance);
+
+    for contract in [&instance.treasury, &instance.dispute, &instance.arbitration] {
+        assert_eq!(
+            MockComponentClient::new(&e, contract).admin(),
+            Some(instance_admin.clone())
+        );
+    }
+    let bond = CredenceBondClient::new(&e, &instance.bond);
+    assert_eq!(bond.export_config().admin, Some(instance_admin));
+}
+
+#[test]
+fn test_wire_connects_bond_and_treasury() {
+    let (e, client, _admin) = setup();
+    let instance = components(&e, &Address::generate(&e));
+    wire_and_record(&e, &client.address, &instance);
+
+    let bond = CredenceBondClient::new(&e, &instance.bond);
+    assert_eq!(bond.get_fee_config(), (Some(instance.treasury.clone()), 0));
+    assert!(MockComponentClient::new(&e, &instance.treasury).is_depositor(&instance.bond));
+}
+
+#[test]
+fn test_wire_connects_dispute_delegation_and_arbitration() {
+    let (e, client, _admin) = setup();
+    let instance = components(&e, &Address::generate(&e));
+    wire_and_record(&e, &client.address, &instance);
+
+    let bond = CredenceBondClient::new(&e, &instance.bond);
+    assert_eq!(
+        bond.resolve(&Symbol::new(&e, "dispute")),
+        Some(instance.dispute.clone())
+    );
+    assert_eq!(
+        bond.resolve(&Symbol::new(&e, "arbitration")),
+        Some(instance.arbitration.clone())
+    );
+    assert_eq!(
+        bond.resolve(&Symbol::new(&e, "delegation")),
+        Some(instance.delegation.clone())
+    );
+    assert_eq!(
+        MockComponentClient::new(&e, &instance.dispute).arbitration(),
+        Some(instance.arbitration)
+    );
+}
+
+#[test]
+fn test_instances_recorded_for_discovery() {
+    let (e, client, _admin) = setup();
+    e.ledger().with_mut(|li| li.timestamp = 1234);
+    let first = components(&e, &Address::generate(&e));
+    let second = components(&e, &Address::generate(&e));
+    assert_eq!(wire_and_record(&e, &client.address, &first), 0);
+    assert_eq!(wire_and_record(&e, &client.address, &second), 1);
+
+    assert_eq!(client.get_instance_count(), 2);
+    assert_eq!(client.get_instance(&0), first);
+    assert_eq!(client.get_instance(&1), second);
+    assert_eq!(client.get_instance(&0).deployed_at, 1234);
+}
+
+#[test]
+#[should_panic(expected = "instance not found")]
+fn test_get_unknown_instance() {
+    let (_e, client, _admin) = setup();
+    client.get_instance(&0);
+}
+
+#[test]
+fn test_salts_are_distinct_per_component_and_instance() {
+    let e = Env::default();
+    assert_ne!(salt(&e, 0, COMPONENT_BOND), salt(&e, 0, COMPONENT_TREASURY));
+    assert_ne!(salt(&e, 0, COMPONENT_BOND), salt(&e, 1, COMPONENT_BOND));
+    assert_ne!(
+        salt(&e, 0, COMPONENT_DISPUTE),
+        salt(&e, 0, COMPONENT_DELEGATION)
+    );
+    assert_ne!(
+        salt(&e, 0, COMPONENT_ARBITRATION),
+        salt(&e, 0, COMPONENT_DELEGATION)
+    );
+}
+
+#[test]
+fn test_schema_version_and_migrate() {
+    let (e, client, _admin) = setup();
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    // Simulate an instance deployed before versioning.
+    e.as_contract(&client.address, || {
+        e.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 0);
+    assert!(client.try_migrate(&5).is_err());
+    assert_eq!(client.migrate(&0), SCHEMA_VERSION);
+    assert_eq!(client.migrate(&0), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
@@ -34,6 +34,25 @@ pub struct RegistryEntry {
     pub active: bool,
 }
 
+/// Complete registry configuration, for diffing deployments (see `export_config`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistryConfig {
+    /// Admin address (`None` before initialization)
+    pub admin: Option<Address>,
+    /// Storage layout version (0 if deployed before versioning)
+    pub schema_version: u32,
+    /// Enabled feature bitmask (FEATURE_*)
+    pub features: u32,
+}
+
+/// Feature flag: registrations can be deactivated and reactivated.
+pub const FEATURE_DEACTIVATION: u32 = 1 << 0;
+/// Feature flag: admin rights can be transferred.
+pub const FEATURE_ADMIN_TRANSFER: u32 = 1 << 1;
+/// All features known to this contract version.
+pub const ALL_FEATURES: u32 = FEATURE_DEACTIVATION | FEATURE_ADMIN_TRANSFER;
+
 /// Storage keys for the registry contract
 #[contracttype]
 #[derive(Clone)]
@@ -46,8 +65,15 @@ enum DataKey {
     BondToIdentity(Address),
     /// List of all registered identities
     RegisteredIdentities,
+    /// Enabled feature bitmask (FEATURE_*)
+    Features,
+    /// Storage layout version (u32, see `migrate`).
+    SchemaVersion,
 }
 
+/// Storage layout version this build reads and writes (see `migrate`).
+pub const SCHEMA_VERSION: u32 = 1;
+
 #[contract]
 pub struct CredenceRegistry;
 
@@ -68,6 +94,9 @@ impl CredenceRegistry {
         admin.require_auth();
 
         e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
 
         // Initialize empty registered identities list
         let identities: Vec<Address> = Vec::new(&e);
@@ -79,6 +108,44 @@ impl CredenceRegistry {
             .publish((Symbol::new(&e, "registry_initialized"),), admin.clone());
     }
 
+    /// Bring stored data from schema `from_version` up to the layout of this build.
+    /// Idempotent: returns the current version without changes once migrated. Admin only.
+    pub fn migrate(e: Env, from_version: u32) -> u32 {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        let stored = Self::get_schema_version(e.clone());
+        if stored == SCHEMA_VERSION {
+            return stored;
+        }
+        if from_version != stored {
+            panic!("schema version mismatch");
+        }
+        if stored > SCHEMA_VERSION {
+            panic!("schema version newer than contract");
+        }
+        // 0 -> 1: versioning introduced; the layout itself is unchanged.
+        e.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        e.events().publish(
+            (Symbol::new(&e, "schema_migrated"),),
+            (stored, SCHEMA_VERSION),
+        );
+        SCHEMA_VERSION
+    }
+
+    /// Storage layout version of the stored data (0 if deployed before versioning).
+    pub fn get_schema_version(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(0)
+    }
+
     /// Register a new identity-to-bond mapping.
     ///
     /// # Arguments
@@ -214,6 +281,8 @@ impl CredenceRegistry {
     /// # Events
     /// Emits `identity_deactivated` with the updated `RegistryEntry`
     pub fn deactivate(e: Env, identity: Address) {
+        Self::require_feature(&e, FEATURE_DEACTIVATION);
+
         // Verify admin authorization
         let admin: Address = e
             .storage()
@@ -254,6 +323,8 @@ impl CredenceRegistry {
     /// # Events
     /// Emits `identity_reactivated` with the updated `RegistryEntry`
     pub fn reactivate(e: Env, identity: Address) {
+        Self::require_feature(&e, FEATURE_DEACTIVATION);
+
         // Verify admin authorization
         let admin: Address = e
             .storage()
@@ -281,6 +352,64 @@ impl CredenceRegistry {
             .publish((Symbol::new(&e, "identity_reactivated"),), entry);
     }
 
+    /// Get the enabled optional subsystems.
+    ///
+    /// # Returns
+    /// Bitmask of `FEATURE_*` flags (all features if never set)
+    pub fn get_features(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::Features)
+            .unwrap_or(ALL_FEATURES)
+    }
+
+    /// Replace the enabled feature mask.
+    ///
+    /// # Arguments
+    /// * `features` - Bitmask of `FEATURE_*` flags
+    ///
+    /// # Panics
+    /// * If caller is not admin
+    /// * If `features` contains unknown bits
+    ///
+    /// # Events
+    /// Emits `features_updated` with the new mask
+    pub fn set_features(e: Env, features: u32) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+
+        admin.require_auth();
+
+        if features & !ALL_FEATURES != 0 {
+            panic!("unknown feature flag");
+        }
+        e.storage().instance().set(&DataKey::Features, &features);
+
+        e.events()
+            .publish((Symbol::new(&e, "features_updated"),), features);
+    }
+
+    fn require_feature(e: &Env, feature: u32) {
+        if Self::get_features(e.clone()) & feature == 0 {
+            panic!("feature disabled");
+        }
+    }
+
+    /// Get the complete current configuration.
+    ///
+    /// # Returns
+    /// A `RegistryConfig` with the admin and feature flags
+    pub fn export_config(e: Env) -> RegistryConfig {
+        RegistryConfig {
+            admin: e.storage().instance().get(&DataKey::Admin),
+            schema_version: Self::get_schema_version(e.clone()),
+            features: Self::get_features(e),
+        }
+    }
+
     /// Get all registered identities.
     ///
     /// # Returns
@@ -317,6 +446,8 @@ impl CredenceRegistry {
     /// # Events
     /// Emits `admin_transferred` with the new admin address
     pub fn transfer_admin(e: Env, new_admin: Address) {
+        Self::require_feature(&e, FEATURE_ADMIN_TRANSFER);
+
         // Verify current admin authorization
         let admin: Address = e
             .storage()
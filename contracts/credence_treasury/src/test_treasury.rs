@@ -4,9 +4,12 @@
 
 #![cfg(test)]
 
-use crate::{CredenceTreasury, CredenceTreasuryClient, FundSource};
+use crate::{
+    CredenceTreasury, CredenceTreasuryClient, DataKey, FundSource, SignerChange, SpendCategory,
+    UpgradeProposal, ALL_FEATURES, FEATURE_DEPOSITORS, SCHEMA_VERSION,
+};
 use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{token, Address, Bytes, BytesN, Env, String};
 
 fn setup(e: &Env) -> (CredenceTreasuryClient<'_>, Address) {
     let contract_id = e.register(CredenceTreasury, ());
@@ -336,3 +339,613 @@ fn test_get_approval_count_nonexistent_proposal() {
     let (client, _admin) = setup(&e);
     assert_eq!(client.get_approval_count(&99), 0);
 }
+
+#[test]
+fn test_features_default_and_update() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.get_features(), ALL_FEATURES);
+    client.set_features(&FEATURE_DEPOSITORS);
+    assert_eq!(client.get_features(), FEATURE_DEPOSITORS);
+}
+
+#[test]
+#[should_panic(expected = "feature disabled")]
+fn test_propose_withdrawal_when_feature_disabled() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let signer = Address::generate(&e);
+    client.add_signer(&signer);
+    client.receive_fee(&admin, &1000, &FundSource::ProtocolFee);
+    client.set_features(&FEATURE_DEPOSITORS);
+    client.propose_withdrawal(&signer, &Address::generate(&e), &100);
+}
+
+#[test]
+fn test_export_config() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.add_signer(&Address::generate(&e));
+    client.add_signer(&Address::generate(&e));
+    client.set_threshold(&2);
+    client.set_features(&FEATURE_DEPOSITORS);
+    let config = client.export_config();
+    assert_eq!(config.admin, Some(admin));
+    assert_eq!(config.signer_count, 2);
+    assert_eq!(config.threshold, 2);
+    assert_eq!(config.features, FEATURE_DEPOSITORS);
+}
+
+fn setup_signers(e: &Env) -> (CredenceTreasuryClient<'_>, Address, Address, Address) {
+    let (client, _admin) = setup(e);
+    let s1 = Address::generate(e);
+    let s2 = Address::generate(e);
+    let s3 = Address::generate(e);
+    client.add_signer(&s1);
+    client.add_signer(&s2);
+    client.add_signer(&s3);
+    client.set_threshold(&2);
+    client.disable_admin_signer_ops();
+    (client, s1, s2, s3)
+}
+
+#[test]
+fn test_signer_change_add_via_proposal() {
+    let e = Env::default();
+    let (client, s1, s2, _s3) = setup_signers(&e);
+    let newcomer = Address::generate(&e);
+    let id = client.propose_signer_change(&s1, &SignerChange::AddSigner(newcomer.clone()));
+    client.approve_signer_change(&s1, &id);
+    client.approve_signer_change(&s2, &id);
+    client.execute_signer_change(&id);
+    assert!(client.is_signer(&newcomer));
+    assert!(client.get_signer_proposal(&id).executed);
+}
+
+#[test]
+fn test_signer_change_remove_and_threshold() {
+    let e = Env::default();
+    let (client, s1, s2, s3) = setup_signers(&e);
+    let id = client.propose_signer_change(&s1, &SignerChange::SetThreshold(3));
+    client.approve_signer_change(&s1, &id);
+    client.approve_signer_change(&s2, &id);
+    client.execute_signer_change(&id);
+    assert_eq!(client.get_threshold(), 3);
+
+    let id = client.propose_signer_change(&s2, &SignerChange::RemoveSigner(s3.clone()));
+    client.approve_signer_change(&s1, &id);
+    client.approve_signer_change(&s2, &id);
+    client.approve_signer_change(&s3, &id);
+    client.execute_signer_change(&id);
+    assert!(!client.is_signer(&s3));
+    assert_eq!(client.get_threshold(), 2);
+}
+
+#[test]
+#[should_panic(expected = "insufficient approvals to execute")]
+fn test_signer_change_below_threshold() {
+    let e = Env::default();
+    let (client, s1, _s2, _s3) = setup_signers(&e);
+    let id = client.propose_signer_change(&s1, &SignerChange::AddSigner(Address::generate(&e)));
+    client.approve_signer_change(&s1, &id);
+    client.execute_signer_change(&id);
+}
+
+#[test]
+#[should_panic(expected = "insufficient approvals to execute")]
+fn test_signer_change_ignores_removed_approvers() {
+    let e = Env::default();
+    let (client, s1, s2, s3) = setup_signers(&e);
+    let id = client.propose_signer_change(&s1, &SignerChange::AddSigner(Address::generate(&e)));
+    client.approve_signer_change(&s1, &id);
+    client.approve_signer_change(&s3, &id);
+    assert_eq!(client.get_signer_approval_count(&id), 2);
+
+    let removal = client.propose_signer_change(&s2, &SignerChange::RemoveSigner(s3.clone()));
+    client.approve_signer_change(&s1, &removal);
+    client.approve_signer_change(&s2, &removal);
+    client.execute_signer_change(&removal);
+    assert_eq!(client.get_signer_approval_count(&id), 1);
+    client.execute_signer_change(&id);
+}
+
+#[test]
+#[should_panic(expected = "only signer can propose signer change")]
+fn test_signer_change_non_signer_propose() {
+    let e = Env::default();
+    let (client, _s1, _s2, _s3) = setup_signers(&e);
+    let outsider = Address::generate(&e);
+    client.propose_signer_change(&outsider, &SignerChange::AddSigner(outsider.clone()));
+}
+
+#[test]
+#[should_panic(expected = "admin signer management disabled")]
+fn test_admin_signer_ops_disabled() {
+    let e = Env::default();
+    let (client, _s1, _s2, _s3) = setup_signers(&e);
+    assert!(client.is_admin_signer_ops_disabled());
+    assert!(client.export_config().admin_signer_ops_disabled);
+    client.add_signer(&Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "threshold must be set before disabling admin signer management")]
+fn test_disable_admin_ops_requires_threshold() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.disable_admin_signer_ops();
+}
+
+#[test]
+fn test_admin_signer_ops_enabled_by_default() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let signer = Address::generate(&e);
+    client.add_signer(&signer);
+    assert!(client.is_signer(&signer));
+    assert!(!client.is_admin_signer_ops_disabled());
+}
+
+// ---------------------------------------------------------------------------
+// Spend categories
+// ---------------------------------------------------------------------------
+
+fn setup_budget(e: &Env) -> (CredenceTreasuryClient<'_>, Address, Address) {
+    let (client, admin) = setup(e);
+    client.receive_fee(&admin, &10_000, &FundSource::ProtocolFee);
+    let s1 = Address::generate(e);
+    let s2 = Address::generate(e);
+    client.add_signer(&s1);
+    client.add_signer(&s2);
+    client.set_threshold(&1);
+    (client, s1, s2)
+}
+
+#[test]
+fn test_withdrawal_defaults_to_operations() {
+    let e = Env::default();
+    let (client, s1, _s2) = setup_budget(&e);
+    let id = client.propose_withdrawal(&s1, &Address::generate(&e), &100);
+    assert_eq!(client.get_proposal(&id).category, SpendCategory::Operations);
+    client.approve_withdrawal(&s1, &id);
+    client.execute_withdrawal(&id);
+    assert_eq!(
+        client.get_spend_by_category(&SpendCategory::Operations),
+        100
+    );
+}
+
+#[test]
+fn test_spend_accumulates_per_category() {
+    let e = Env::default();
+    let (client, s1, _s2) = setup_budget(&e);
+    for (category, amount) in [
+        (SpendCategory::Audits, 300),
+        (SpendCategory::Audits, 200),
+        (SpendCategory::Grants, 1_000),
+    ] {
+        let id =
+            client.propose_categorized_withdrawal(&s1, &Address::generate(&e), &amount, &category);
+        client.approve_withdrawal(&s1, &id);
+        client.execute_withdrawal(&id);
+    }
+    assert_eq!(client.get_spend_by_category(&SpendCategory::Audits), 500);
+    assert_eq!(client.get_spend_by_category(&SpendCategory::Grants), 1_000);
+    assert_eq!(
+        client.get_spend_by_category(&SpendCategory::ArbitratorRewards),
+        0
+    );
+    assert_eq!(client.get_balance(), 8_500);
+}
+
+#[test]
+fn test_unexecuted_proposal_not_counted() {
+    let e = Env::default();
+    let (client, s1, _s2) = setup_budget(&e);
+    client.propose_categorized_withdrawal(
+        &s1,
+        &Address::generate(&e),
+        &100,
+        &SpendCategory::Grants,
+    );
+    assert_eq!(client.get_spend_by_category(&SpendCategory::Grants), 0);
+}
+
+#[test]
+#[should_panic(expected = "category cap exceeded: all signers must approve")]
+fn test_over_cap_needs_all_signers() {
+    let e = Env::default();
+    let (client, s1, _s2) = setup_budget(&e);
+    client.set_spend_cap(&SpendCategory::Grants, &500);
+    let id = client.propose_categorized_withdrawal(
+        &s1,
+        &Address::generate(&e),
+        &600,
+        &SpendCategory::Grants,
+    );
+    client.approve_withdrawal(&s1, &id);
+    client.execute_withdrawal(&id);
+}
+
+#[test]
+fn test_over_cap_executes_with_all_signers() {
+    let e = Env::default();
+    let (client, s1, s2) = setup_budget(&e);
+    client.set_spend_cap(&SpendCategory::Grants, &500);
+    let within = client.propose_categorized_withdrawal(
+        &s1,
+        &Address::generate(&e),
+        &500,
+        &SpendCategory::Grants,
+    );
+    client.approve_withdrawal(&s1, &within);
+    client.execute_withdrawal(&within);
+
+    let over = client.propose_categorized_withdrawal(
+        &s1,
+        &Address::generate(&e),
+        &1,
+        &SpendCategory::Grants,
+    );
+    client.approve_withdrawal(&s1, &over);
+    client.approve_withdrawal(&s2, &over);
+    client.execute_withdrawal(&over);
+    assert_eq!(client.get_spend_by_category(&SpendCategory::Grants), 501);
+}
+
+#[test]
+fn test_cap_set_and_cleared() {
+    let e = Env::default();
+    let (client, _s1, _s2) = setup_budget(&e);
+    assert_eq!(client.get_spend_cap(&SpendCategory::Audits), None);
+    client.set_spend_cap(&SpendCategory::Audits, &2_000);
+    assert_eq!(client.get_spend_cap(&SpendCategory::Audits), Some(2_000));
+    assert_eq!(
+        client.export_config().spend_caps.get(SpendCategory::Audits),
+        Some(2_000)
+    );
+    client.clear_spend_cap(&SpendCategory::Audits);
+    assert_eq!(client.get_spend_cap(&SpendCategory::Audits), None);
+    assert!(client.export_config().spend_caps.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "spend cap must be non-negative")]
+fn test_negative_cap_rejected() {
+    let e = Env::default();
+    let (client, _s1, _s2) = setup_budget(&e);
+    client.set_spend_cap(&SpendCategory::Audits, &-1);
+}
+
+#[test]
+#[should_panic]
+fn test_set_spend_cap_requires_admin_auth() {
+    let e = Env::default();
+    let contract_id = e.register(CredenceTreasury, ());
+    let client = CredenceTreasuryClient::new(&e, &contract_id);
+    e.mock_all_auths();
+    client.initialize(&Address::generate(&e));
+    e.set_auths(&[]);
+    client.set_spend_cap(&SpendCategory::Audits, &100);
+}
+
+// --- Emergency freeze ---
+
+fn setup_freeze(e: &Env) -> (CredenceTreasuryClient<'_>, Address, Address, u64) {
+    let (client, s1, s2) = setup_budget(e);
+    client.set_threshold(&2);
+    let id = client.propose_withdrawal(&s1, &Address::generate(e), &100);
+    client.approve_withdrawal(&s1, &id);
+    client.approve_withdrawal(&s2, &id);
+    (client, s1, s2, id)
+}
+
+#[test]
+#[should_panic(expected = "treasury frozen")]
+fn test_single_signer_freeze_blocks_execution() {
+    let e = Env::default();
+    let (client, s1, _s2, id) = setup_freeze(&e);
+    client.freeze(&s1, &7);
+    assert!(client.is_frozen());
+    client.execute_withdrawal(&id);
+}
+
+#[test]
+fn test_freeze_allows_proposals_and_approvals() {
+    let e = Env::default();
+    let (client, s1, s2, _id) = setup_freeze(&e);
+    client.freeze(&s1, &7);
+    let id = client.propose_withdrawal(&s2, &Address::generate(&e), &50);
+    client.approve_withdrawal(&s2, &id);
+    assert_eq!(client.get_approval_count(&id), 1);
+}
+
+#[test]
+fn test_unfreeze_requires_threshold() {
+    let e = Env::default();
+    let (client, s1, s2, id) = setup_freeze(&e);
+    client.freeze(&s1, &7);
+    client.approve_unfreeze(&s1, &0);
+    client.approve_unfreeze(&s1, &0);
+    assert!(client.is_frozen());
+    assert_eq!(client.get_unfreeze_approval_count(), 1);
+    client.approve_unfreeze(&s2, &0);
+    assert!(!client.is_frozen());
+    client.execute_withdrawal(&id);
+    assert!(client.get_proposal(&id).executed);
+}
+
+#[test]
+fn test_unfreeze_approvals_reset_per_freeze() {
+    let e = Env::default();
+    let (client, s1, s2, _id) = setup_freeze(&e);
+    client.freeze(&s1, &1);
+    client.approve_unfreeze(&s1, &0);
+    client.approve_unfreeze(&s2, &0);
+    client.freeze(&s2, &2);
+    assert_eq!(client.get_unfreeze_approval_count(), 0);
+    client.approve_unfreeze(&s1, &0);
+    assert!(client.is_frozen());
+}
+
+#[test]
+fn test_freeze_event_carries_signer_and_reason() {
+    use soroban_sdk::testutils::Events;
+    use soroban_sdk::{IntoVal, Symbol};
+    let e = Env::default();
+    let (client, s1, _s2, _id) = setup_freeze(&e);
+    client.freeze(&s1, &42);
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "treasury_frozen"), s1).into_val(&e)
+    );
+    let reason: u32 = data.into_val(&e);
+    assert_eq!(reason, 42);
+}
+
+#[test]
+#[should_panic(expected = "only signer can freeze")]
+fn test_non_signer_cannot_freeze() {
+    let e = Env::default();
+    let (client, _s1, _s2, _id) = setup_freeze(&e);
+    client.freeze(&Address::generate(&e), &1);
+}
+
+#[test]
+#[should_panic(expected = "treasury already frozen")]
+fn test_double_freeze_rejected() {
+    let e = Env::default();
+    let (client, s1, s2, _id) = setup_freeze(&e);
+    client.freeze(&s1, &1);
+    client.freeze(&s2, &1);
+}
+
+#[test]
+#[should_panic(expected = "treasury not frozen")]
+fn test_unfreeze_when_not_frozen_rejected() {
+    let e = Env::default();
+    let (client, s1, _s2, _id) = setup_freeze(&e);
+    client.approve_unfreeze(&s1, &0);
+}
+
+#[test]
+fn test_schema_version_and_migrate() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    // Simulate an instance deployed before versioning.
+    e.as_contract(&client.address, || {
+        e.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 0);
+    assert!(client.try_migrate(&5).is_err());
+    assert_eq!(client.migrate(&0), SCHEMA_VERSION);
+    assert_eq!(client.migrate(&0), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
+
+fn stray_token(e: &Env, holder: &Address, amount: i128) -> Address {
+    let token = e
+        .register_stellar_asset_contract_v2(Address::generate(e))
+        .address();
+    token::StellarAssetClient::new(e, &token).mint(holder, &amount);
+    token
+}
+
+#[test]
+fn test_recover_token() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let operational = stray_token(&e, &client.address, 500);
+    let stray = stray_token(&e, &client.address, 300);
+    client.add_operational_token(&operational);
+    assert_eq!(client.export_config().operational_tokens.len(), 1);
+
+    let to = Address::generate(&e);
+    client.recover_token(&stray, &to, &300);
+    assert_eq!(token::Client::new(&e, &stray).balance(&to), 300);
+    assert_eq!(token::Client::new(&e, &stray).balance(&client.address), 0);
+
+    assert!(client.try_recover_token(&operational, &to, &1).is_err());
+    assert_eq!(
+        token::Client::new(&e, &operational).balance(&client.address),
+        500
+    );
+}
+
+#[test]
+#[should_panic(expected = "no operational token configured")]
+fn test_recover_token_requires_operational_token() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let stray = stray_token(&e, &client.address, 300);
+    client.recover_token(&stray, &Address::generate(&e), &300);
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let new_admin = Address::generate(&e);
+    client.transfer_admin(&new_admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(
+        client.export_config().pending_admin,
+        Some(new_admin.clone())
+    );
+
+    assert_eq!(client.accept_admin(), new_admin);
+    assert_eq!(
+        e.auths()[0].0,
+        new_admin,
+        "accept_admin must be authorized by the nominee"
+    );
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+#[should_panic(expected = "no pending admin transfer")]
+fn test_accept_admin_without_transfer() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.accept_admin();
+}
+
+/// Smallest WASM module the host accepts: an empty module carrying the
+/// `contractenvmetav0` section (protocol 22).
+const EMPTY_WASM: [u8; 40] = [
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x00, 0x1e, 0x11, b'c', b'o', b'n', b't', b'r',
+    b'a', b'c', b't', b'e', b'n', b'v', b'm', b'e', b't', b'a', b'v', b'0', 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x00,
+];
+
+fn upload_empty_wasm(e: &Env) -> BytesN<32> {
+    e.deployer()
+        .upload_contract_wasm(Bytes::from_array(e, &EMPTY_WASM))
+}
+
+#[test]
+fn test_version() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(
+        client.version(),
+        String::from_str(&e, env!("CARGO_PKG_VERSION"))
+    );
+}
+
+#[test]
+fn test_upgrade_by_admin() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let hash = upload_empty_wasm(&e);
+    client.upgrade(&admin, &hash);
+    assert_eq!(
+        e.auths()[0].0,
+        admin,
+        "upgrade must be authorized by the admin"
+    );
+}
+
+#[test]
+#[should_panic(expected = "only admin can upgrade")]
+fn test_upgrade_non_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let hash = upload_empty_wasm(&e);
+    client.upgrade(&Address::generate(&e), &hash);
+}
+
+#[test]
+#[should_panic(expected = "upgrade not proposed")]
+fn test_upgrade_requires_proposal_when_approval_required() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_upgrade_approval_required(&true);
+    assert!(client.export_config().upgrade_approval_required);
+    let hash = upload_empty_wasm(&e);
+    client.upgrade(&admin, &hash);
+}
+
+#[test]
+#[should_panic(expected = "insufficient approvals to upgrade")]
+fn test_upgrade_below_threshold() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let s1 = Address::generate(&e);
+    let s2 = Address::generate(&e);
+    client.add_signer(&s1);
+    client.add_signer(&s2);
+    client.set_threshold(&2);
+    client.set_upgrade_approval_required(&true);
+    let hash = upload_empty_wasm(&e);
+    let id = client.propose_upgrade(&s1, &hash);
+    client.approve_upgrade(&s1, &id);
+    client.upgrade(&admin, &hash);
+}
+
+#[test]
+fn test_upgrade_with_signer_approval() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let s1 = Address::generate(&e);
+    let s2 = Address::generate(&e);
+    client.add_signer(&s1);
+    client.add_signer(&s2);
+    client.set_threshold(&2);
+    client.set_upgrade_approval_required(&true);
+    let hash = upload_empty_wasm(&e);
+    let id = client.propose_upgrade(&s1, &hash);
+    client.approve_upgrade(&s1, &id);
+    client.approve_upgrade(&s2, &id);
+    assert_eq!(client.get_upgrade_approval_count(&id), 2);
+    client.upgrade(&admin, &hash);
+
+    // The contract now runs the new (empty) WASM, so read the spent proposal from storage.
+    let proposal: UpgradeProposal = e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .get(&DataKey::UpgradeProposal(id))
+            .unwrap()
+    });
+    assert!(proposal.executed);
+}
+
+#[test]
+#[should_panic(expected = "upgrade approval can only be disabled by signer proposal")]
+fn test_admin_cannot_disable_upgrade_approval() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.set_upgrade_approval_required(&true);
+    client.set_upgrade_approval_required(&false);
+}
+
+#[test]
+fn test_signer_proposal_disables_upgrade_approval() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let s1 = Address::generate(&e);
+    let s2 = Address::generate(&e);
+    client.add_signer(&s1);
+    client.add_signer(&s2);
+    client.set_threshold(&2);
+    client.set_upgrade_approval_required(&true);
+    let id = client.propose_signer_change(&s1, &SignerChange::DisableUpgradeApproval);
+    client.approve_signer_change(&s1, &id);
+    client.approve_signer_change(&s2, &id);
+    client.execute_signer_change(&id);
+    assert!(!client.is_upgrade_approval_required());
+}
+
+#[test]
+#[should_panic(expected = "only signer can propose upgrade")]
+fn test_propose_upgrade_non_signer() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.propose_upgrade(&Address::generate(&e), &BytesN::from_array(&e, &[7; 32]));
+}
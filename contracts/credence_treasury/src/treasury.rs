@@ -3,7 +3,9 @@
 //! Manages protocol fees and slashed funds with multi-signature withdrawal support.
 //! Tracks fund sources (protocol fees vs slashed funds) and emits treasury events.
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, token, Address, BytesN, Env, Map, String, Symbol, Vec,
+};
 
 /// Fund source for accounting and reporting.
 #[contracttype]
@@ -15,6 +17,16 @@ pub enum FundSource {
     SlashedFunds = 1,
 }
 
+/// Budget category a withdrawal is spent on, for on-chain budget enforcement and reporting.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SpendCategory {
+    Operations = 0,
+    Audits = 1,
+    ArbitratorRewards = 2,
+    Grants = 3,
+}
+
 /// A withdrawal proposal (multi-sig). Created by a signer; executable when approval count >= threshold.
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -29,8 +41,75 @@ pub struct WithdrawalProposal {
     pub proposer: Address,
     /// True once executed.
     pub executed: bool,
+    /// Budget category the withdrawal is spent on.
+    pub category: SpendCategory,
+}
+
+/// A change to the multi-sig membership, threshold or upgrade approval, applied by signer
+/// proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignerChange {
+    AddSigner(Address),
+    RemoveSigner(Address),
+    SetThreshold(u32),
+    /// Stop requiring signer-approved upgrade proposals. Once they are required, only the
+    /// signers can lift the requirement.
+    DisableUpgradeApproval,
+}
+
+/// A signer-set change proposal. Created by a signer; executable when approval count >= threshold.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignerProposal {
+    /// Requested change.
+    pub change: SignerChange,
+    /// Ledger timestamp when proposed.
+    pub proposed_at: u64,
+    /// Proposer (signer who created the proposal).
+    pub proposer: Address,
+    /// True once executed.
+    pub executed: bool,
 }
 
+/// A proposal to upgrade the treasury WASM. Created by a signer; once approval count >=
+/// threshold the admin may apply it with `upgrade`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct UpgradeProposal {
+    /// Hash of the uploaded WASM to upgrade to.
+    pub wasm_hash: BytesN<32>,
+    /// Ledger timestamp when proposed.
+    pub proposed_at: u64,
+    /// Proposer (signer who created the proposal).
+    pub proposer: Address,
+    /// True once the upgrade has been applied.
+    pub executed: bool,
+}
+
+/// Complete treasury configuration, for diffing deployments (see `export_config`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryConfig {
+    pub admin: Option<Address>,
+    pub pending_admin: Option<Address>,
+    pub schema_version: u32,
+    pub signer_count: u32,
+    pub threshold: u32,
+    pub features: u32,
+    pub admin_signer_ops_disabled: bool,
+    pub spend_caps: Map<SpendCategory, i128>,
+    pub operational_tokens: Vec<Address>,
+    pub upgrade_approval_required: bool,
+}
+
+/// Feature flag: multi-sig withdrawals (propose/approve/execute).
+pub const FEATURE_WITHDRAWALS: u32 = 1 << 0;
+/// Feature flag: non-admin depositors may call receive_fee.
+pub const FEATURE_DEPOSITORS: u32 = 1 << 1;
+/// All features known to this contract version.
+pub const ALL_FEATURES: u32 = FEATURE_WITHDRAWALS | FEATURE_DEPOSITORS;
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -54,8 +133,53 @@ pub enum DataKey {
     Approval(u64, Address),
     /// Approval count per proposal (cached for execution check).
     ApprovalCount(u64),
+    /// Enabled feature bitmask (FEATURE_*).
+    Features,
+    /// Next signer-change proposal id.
+    SignerProposalCounter,
+    /// Signer-change proposal by id.
+    SignerProposal(u64),
+    /// Signer-change approval: (proposal_id, signer) -> true.
+    SignerApproval(u64, Address),
+    /// Signers that approved a signer-change proposal, in approval order.
+    SignerApprovers(u64),
+    /// True once the admin can no longer add/remove signers or set the threshold.
+    AdminSignerOpsDisabled,
+    /// Cumulative executed withdrawals per spend category.
+    SpendByCategory(SpendCategory),
+    /// Cumulative spend cap per category (uncapped if absent).
+    SpendCap(SpendCategory),
+    /// True while withdrawal execution is frozen.
+    Frozen,
+    /// Number of freezes so far; unfreeze approvals are scoped to the current one.
+    FreezeEpoch,
+    /// Unfreeze approval: (freeze_epoch, signer) -> true.
+    UnfreezeApproval(u64, Address),
+    /// Unfreeze approval count per freeze epoch.
+    UnfreezeApprovalCount(u64),
+    /// Storage layout version (u32, see `migrate`).
+    SchemaVersion,
+    /// Tokens the treasury operates in; never recoverable (Vec<Address>).
+    OperationalTokens,
+    /// Nominated admin awaiting acceptance (see `transfer_admin`).
+    PendingAdmin,
+    /// True if `upgrade` requires a signer-approved upgrade proposal.
+    UpgradeApprovalRequired,
+    /// Next upgrade proposal id.
+    UpgradeProposalCounter,
+    /// Upgrade proposal by id.
+    UpgradeProposal(u64),
+    /// Latest upgrade proposal id for a WASM hash.
+    UpgradeProposalForHash(BytesN<32>),
+    /// Upgrade approval: (proposal_id, signer) -> true.
+    UpgradeApproval(u64, Address),
+    /// Approval count per upgrade proposal.
+    UpgradeApprovalCount(u64),
 }
 
+/// Storage layout version this build reads and writes (see `migrate`).
+pub const SCHEMA_VERSION: u32 = 1;
+
 #[contract]
 pub struct CredenceTreasury;
 
@@ -67,6 +191,9 @@ impl CredenceTreasury {
     pub fn initialize(e: Env, admin: Address) {
         admin.require_auth();
         e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
         e.storage().instance().set(&DataKey::TotalBalance, &0_i128);
         e.storage()
             .instance()
@@ -83,6 +210,230 @@ impl CredenceTreasury {
             .publish((Symbol::new(&e, "treasury_initialized"),), admin);
     }
 
+    /// Bring stored data from schema `from_version` up to the layout of this build.
+    /// Idempotent: returns the current version without changes once migrated. Admin only.
+    pub fn migrate(e: Env, from_version: u32) -> u32 {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        let stored = Self::get_schema_version(e.clone());
+        if stored == SCHEMA_VERSION {
+            return stored;
+        }
+        if from_version != stored {
+            panic!("schema version mismatch");
+        }
+        if stored > SCHEMA_VERSION {
+            panic!("schema version newer than contract");
+        }
+        // 0 -> 1: versioning introduced; the layout itself is unchanged.
+        e.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        e.events().publish(
+            (Symbol::new(&e, "schema_migrated"),),
+            (stored, SCHEMA_VERSION),
+        );
+        SCHEMA_VERSION
+    }
+
+    /// Nominate `new_admin` as the next admin. The current admin stays in charge until the
+    /// nominee calls `accept_admin`; a new nomination replaces a pending one. Admin only.
+    pub fn transfer_admin(e: Env, new_admin: Address) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        e.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        e.events().publish(
+            (Symbol::new(&e, "admin_transfer_started"), admin),
+            new_admin,
+        );
+    }
+
+    /// Complete a pending admin transfer. Requires the nominee's auth. Returns the new admin.
+    pub fn accept_admin(e: Env) -> Address {
+        let new_admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic!("no pending admin transfer"));
+        new_admin.require_auth();
+        let previous: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        e.storage().instance().set(&DataKey::Admin, &new_admin);
+        e.storage().instance().remove(&DataKey::PendingAdmin);
+        e.events().publish(
+            (Symbol::new(&e, "admin_transferred"),),
+            (previous, new_admin.clone()),
+        );
+        new_admin
+    }
+
+    /// Nominated admin awaiting acceptance, if any.
+    pub fn get_pending_admin(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Storage layout version of the stored data (0 if deployed before versioning).
+    pub fn get_schema_version(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(0)
+    }
+
+    /// Crate version of the deployed build.
+    pub fn version(e: Env) -> String {
+        String::from_str(&e, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Replace the contract WASM with the uploaded `new_wasm_hash`. When upgrade approval is
+    /// required, the hash needs an unexecuted upgrade proposal with approval count >=
+    /// threshold; the upgrade consumes it. Admin only.
+    pub fn upgrade(e: Env, admin: Address, new_wasm_hash: BytesN<32>) {
+        if admin != Self::get_admin(e.clone()) {
+            panic!("only admin can upgrade");
+        }
+        admin.require_auth();
+        if Self::is_upgrade_approval_required(e.clone()) {
+            let id: u64 = e
+                .storage()
+                .instance()
+                .get(&DataKey::UpgradeProposalForHash(new_wasm_hash.clone()))
+                .unwrap_or_else(|| panic!("upgrade not proposed"));
+            let mut proposal = Self::get_upgrade_proposal(e.clone(), id);
+            if proposal.executed {
+                panic!("proposal already executed");
+            }
+            let threshold = Self::get_threshold(e.clone());
+            if threshold == 0 || Self::get_upgrade_approval_count(e.clone(), id) < threshold {
+                panic!("insufficient approvals to upgrade");
+            }
+            proposal.executed = true;
+            e.storage()
+                .instance()
+                .set(&DataKey::UpgradeProposal(id), &proposal);
+        }
+        e.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        e.events()
+            .publish((Symbol::new(&e, "contract_upgraded"), admin), new_wasm_hash);
+    }
+
+    /// Require (or stop requiring) signer approval for upgrades. Admin only. Once required,
+    /// turning it off needs an executed `DisableUpgradeApproval` signer proposal.
+    pub fn set_upgrade_approval_required(e: Env, required: bool) {
+        let admin = Self::get_admin(e.clone());
+        admin.require_auth();
+        if !required && Self::is_upgrade_approval_required(e.clone()) {
+            panic!("upgrade approval can only be disabled by signer proposal");
+        }
+        Self::apply_upgrade_approval_required(&e, required);
+    }
+
+    fn apply_upgrade_approval_required(e: &Env, required: bool) {
+        e.storage()
+            .instance()
+            .set(&DataKey::UpgradeApprovalRequired, &required);
+        e.events()
+            .publish((Symbol::new(e, "upgrade_approval_set"),), required);
+    }
+
+    /// True if `upgrade` requires a signer-approved upgrade proposal.
+    pub fn is_upgrade_approval_required(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::UpgradeApprovalRequired)
+            .unwrap_or(false)
+    }
+
+    /// Propose upgrading to `wasm_hash`. Only a signer can propose.
+    /// @return proposal_id The id of the new upgrade proposal
+    pub fn propose_upgrade(e: Env, proposer: Address, wasm_hash: BytesN<32>) -> u64 {
+        proposer.require_auth();
+        if !Self::is_signer(e.clone(), proposer.clone()) {
+            panic!("only signer can propose upgrade");
+        }
+        let id: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::UpgradeProposalCounter)
+            .unwrap_or(0);
+        let next_id = id.checked_add(1).expect("proposal counter overflow");
+        e.storage()
+            .instance()
+            .set(&DataKey::UpgradeProposalCounter, &next_id);
+        let proposal = UpgradeProposal {
+            wasm_hash: wasm_hash.clone(),
+            proposed_at: e.ledger().timestamp(),
+            proposer: proposer.clone(),
+            executed: false,
+        };
+        e.storage()
+            .instance()
+            .set(&DataKey::UpgradeProposal(id), &proposal);
+        e.storage()
+            .instance()
+            .set(&DataKey::UpgradeProposalForHash(wasm_hash.clone()), &id);
+        e.events().publish(
+            (Symbol::new(&e, "upgrade_proposed"), id),
+            (wasm_hash, proposer),
+        );
+        id
+    }
+
+    /// Approve an upgrade proposal. Only signers can approve.
+    pub fn approve_upgrade(e: Env, approver: Address, proposal_id: u64) {
+        approver.require_auth();
+        if !Self::is_signer(e.clone(), approver.clone()) {
+            panic!("only signer can approve");
+        }
+        let proposal = Self::get_upgrade_proposal(e.clone(), proposal_id);
+        if proposal.executed {
+            panic!("proposal already executed");
+        }
+        let key = DataKey::UpgradeApproval(proposal_id, approver.clone());
+        if e.storage().instance().get(&key).unwrap_or(false) {
+            return;
+        }
+        e.storage().instance().set(&key, &true);
+        let count = Self::get_upgrade_approval_count(e.clone(), proposal_id)
+            .checked_add(1)
+            .expect("approval count overflow");
+        e.storage()
+            .instance()
+            .set(&DataKey::UpgradeApprovalCount(proposal_id), &count);
+        e.events()
+            .publish((Symbol::new(&e, "upgrade_approved"), proposal_id), approver);
+    }
+
+    /// Get an upgrade proposal by id.
+    pub fn get_upgrade_proposal(e: Env, proposal_id: u64) -> UpgradeProposal {
+        e.storage()
+            .instance()
+            .get(&DataKey::UpgradeProposal(proposal_id))
+            .unwrap_or_else(|| panic!("proposal not found"))
+    }
+
+    /// Number of approvals on an upgrade proposal.
+    pub fn get_upgrade_approval_count(e: Env, proposal_id: u64) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::UpgradeApprovalCount(proposal_id))
+            .unwrap_or(0)
+    }
+
     /// Receive protocol fee or slashed funds. Caller must be admin or an authorized depositor.
     /// @param e The contract environment
     /// @param from Caller (must be auth'd)
@@ -103,7 +454,7 @@ impl CredenceTreasury {
             .instance()
             .get(&DataKey::Depositor(from.clone()))
             .unwrap_or(false);
-        if from != admin && !is_depositor {
+        if from != admin && !(is_depositor && Self::has_feature(&e, FEATURE_DEPOSITORS)) {
             panic!("only admin or authorized depositor can receive_fee");
         }
         let total: i128 = e
@@ -161,12 +512,11 @@ impl CredenceTreasury {
 
     /// Add a signer for multi-sig withdrawals. Threshold must be <= signer count after add.
     pub fn add_signer(e: Env, signer: Address) {
-        let admin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
-        admin.require_auth();
+        Self::require_admin_signer_ops(&e);
+        Self::apply_add_signer(&e, signer);
+    }
+
+    fn apply_add_signer(e: &Env, signer: Address) {
         let already = e
             .storage()
             .instance()
@@ -188,17 +538,16 @@ impl CredenceTreasury {
             .instance()
             .set(&DataKey::SignerCount, &new_count);
         e.events()
-            .publish((Symbol::new(&e, "signer_added"),), signer);
+            .publish((Symbol::new(e, "signer_added"),), signer);
     }
 
     /// Remove a signer. Threshold is auto-capped to new signer count if needed.
     pub fn remove_signer(e: Env, signer: Address) {
-        let admin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
-        admin.require_auth();
+        Self::require_admin_signer_ops(&e);
+        Self::apply_remove_signer(&e, signer);
+    }
+
+    fn apply_remove_signer(e: &Env, signer: Address) {
         let exists = e
             .storage()
             .instance()
@@ -224,17 +573,16 @@ impl CredenceTreasury {
             e.storage().instance().set(&DataKey::Threshold, &new_count);
         }
         e.events()
-            .publish((Symbol::new(&e, "signer_removed"),), signer);
+            .publish((Symbol::new(e, "signer_removed"),), signer);
     }
 
     /// Set the number of approvals required to execute a withdrawal. Must be <= signer count.
     pub fn set_threshold(e: Env, threshold: u32) {
-        let admin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
-        admin.require_auth();
+        Self::require_admin_signer_ops(&e);
+        Self::apply_set_threshold(&e, threshold);
+    }
+
+    fn apply_set_threshold(e: &Env, threshold: u32) {
         let count: u32 = e
             .storage()
             .instance()
@@ -245,13 +593,197 @@ impl CredenceTreasury {
         }
         e.storage().instance().set(&DataKey::Threshold, &threshold);
         e.events()
-            .publish((Symbol::new(&e, "threshold_updated"),), threshold);
+            .publish((Symbol::new(e, "threshold_updated"),), threshold);
+    }
+
+    fn require_admin_signer_ops(e: &Env) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        if Self::is_admin_signer_ops_disabled(e.clone()) {
+            panic!("admin signer management disabled");
+        }
+    }
+
+    /// Permanently hand signer management to the signers: afterwards add_signer, remove_signer
+    /// and set_threshold fail and changes go through signer proposals. Admin only; requires a
+    /// threshold of at least 1.
+    pub fn disable_admin_signer_ops(e: Env) {
+        Self::require_admin_signer_ops(&e);
+        if Self::get_threshold(e.clone()) == 0 {
+            panic!("threshold must be set before disabling admin signer management");
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::AdminSignerOpsDisabled, &true);
+        e.events()
+            .publish((Symbol::new(&e, "admin_signer_ops_disabled"),), ());
+    }
+
+    /// True once admin signer management was disabled.
+    pub fn is_admin_signer_ops_disabled(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::AdminSignerOpsDisabled)
+            .unwrap_or(false)
+    }
+
+    /// Propose adding/removing a signer or changing the threshold. Only a signer can propose.
+    /// @return proposal_id The id of the new signer-change proposal
+    pub fn propose_signer_change(e: Env, proposer: Address, change: SignerChange) -> u64 {
+        proposer.require_auth();
+        if !Self::is_signer(e.clone(), proposer.clone()) {
+            panic!("only signer can propose signer change");
+        }
+        if let SignerChange::SetThreshold(threshold) = change {
+            if threshold == 0 {
+                panic!("threshold must be positive");
+            }
+        }
+        let id: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::SignerProposalCounter)
+            .unwrap_or(0);
+        let next_id = id.checked_add(1).expect("proposal counter overflow");
+        e.storage()
+            .instance()
+            .set(&DataKey::SignerProposalCounter, &next_id);
+        let proposal = SignerProposal {
+            change: change.clone(),
+            proposed_at: e.ledger().timestamp(),
+            proposer: proposer.clone(),
+            executed: false,
+        };
+        e.storage()
+            .instance()
+            .set(&DataKey::SignerProposal(id), &proposal);
+        e.events().publish(
+            (Symbol::new(&e, "signer_change_proposed"), id),
+            (change, proposer),
+        );
+        id
+    }
+
+    /// Approve a signer-change proposal. Only signers can approve.
+    pub fn approve_signer_change(e: Env, approver: Address, proposal_id: u64) {
+        approver.require_auth();
+        if !Self::is_signer(e.clone(), approver.clone()) {
+            panic!("only signer can approve");
+        }
+        let proposal = Self::get_signer_proposal(e.clone(), proposal_id);
+        if proposal.executed {
+            panic!("proposal already executed");
+        }
+        let key = DataKey::SignerApproval(proposal_id, approver.clone());
+        if e.storage().instance().get(&key).unwrap_or(false) {
+            return;
+        }
+        e.storage().instance().set(&key, &true);
+        let approvers_key = DataKey::SignerApprovers(proposal_id);
+        let mut approvers: Vec<Address> = e
+            .storage()
+            .instance()
+            .get(&approvers_key)
+            .unwrap_or(Vec::new(&e));
+        approvers.push_back(approver.clone());
+        e.storage().instance().set(&approvers_key, &approvers);
+        e.events().publish(
+            (Symbol::new(&e, "signer_change_approved"), proposal_id),
+            approver,
+        );
+    }
+
+    /// Execute a signer-change proposal. Callable by anyone once approval count >= threshold
+    /// (and the threshold is at least 1). Approvals are recounted against the current signers,
+    /// so approvals from signers removed since do not count.
+    pub fn execute_signer_change(e: Env, proposal_id: u64) {
+        let mut proposal = Self::get_signer_proposal(e.clone(), proposal_id);
+        if proposal.executed {
+            panic!("proposal already executed");
+        }
+        let threshold = Self::get_threshold(e.clone());
+        if threshold == 0 || Self::get_signer_approval_count(e.clone(), proposal_id) < threshold {
+            panic!("insufficient approvals to execute");
+        }
+        proposal.executed = true;
+        e.storage()
+            .instance()
+            .set(&DataKey::SignerProposal(proposal_id), &proposal);
+        match proposal.change.clone() {
+            SignerChange::AddSigner(signer) => Self::apply_add_signer(&e, signer),
+            SignerChange::RemoveSigner(signer) => {
+                let count: u32 = e
+                    .storage()
+                    .instance()
+                    .get(&DataKey::SignerCount)
+                    .unwrap_or(0);
+                if Self::is_signer(e.clone(), signer.clone()) && count <= 1 {
+                    panic!("cannot remove last signer");
+                }
+                Self::apply_remove_signer(&e, signer)
+            }
+            SignerChange::SetThreshold(threshold) => Self::apply_set_threshold(&e, threshold),
+            SignerChange::DisableUpgradeApproval => {
+                Self::apply_upgrade_approval_required(&e, false)
+            }
+        }
+        e.events().publish(
+            (Symbol::new(&e, "signer_change_executed"), proposal_id),
+            proposal.change,
+        );
+    }
+
+    /// Get a signer-change proposal by id.
+    pub fn get_signer_proposal(e: Env, proposal_id: u64) -> SignerProposal {
+        e.storage()
+            .instance()
+            .get(&DataKey::SignerProposal(proposal_id))
+            .unwrap_or_else(|| panic!("proposal not found"))
+    }
+
+    /// Number of approvals on a signer-change proposal from addresses that are still signers.
+    pub fn get_signer_approval_count(e: Env, proposal_id: u64) -> u32 {
+        let approvers: Vec<Address> = e
+            .storage()
+            .instance()
+            .get(&DataKey::SignerApprovers(proposal_id))
+            .unwrap_or(Vec::new(&e));
+        approvers
+            .iter()
+            .filter(|a| Self::is_signer(e.clone(), a.clone()))
+            .count() as u32
     }
 
     /// Propose a withdrawal. Only a signer can propose. Creates a proposal that can be approved and executed.
+    /// The withdrawal is tagged `SpendCategory::Operations`; use `propose_categorized_withdrawal` for other budgets.
     /// @return proposal_id The id of the new proposal
     pub fn propose_withdrawal(e: Env, proposer: Address, recipient: Address, amount: i128) -> u64 {
+        Self::propose_categorized_withdrawal(
+            e,
+            proposer,
+            recipient,
+            amount,
+            SpendCategory::Operations,
+        )
+    }
+
+    /// Propose a withdrawal tagged with a spend category. Only a signer can propose.
+    /// @return proposal_id The id of the new proposal
+    pub fn propose_categorized_withdrawal(
+        e: Env,
+        proposer: Address,
+        recipient: Address,
+        amount: i128,
+        category: SpendCategory,
+    ) -> u64 {
         proposer.require_auth();
+        if !Self::has_feature(&e, FEATURE_WITHDRAWALS) {
+            panic!("feature disabled");
+        }
         let is_signer = e
             .storage()
             .instance()
@@ -286,6 +818,7 @@ impl CredenceTreasury {
             proposed_at: e.ledger().timestamp(),
             proposer: proposer.clone(),
             executed: false,
+            category,
         };
         e.storage()
             .instance()
@@ -295,7 +828,7 @@ impl CredenceTreasury {
             .set(&DataKey::ApprovalCount(id), &0_u32);
         e.events().publish(
             (Symbol::new(&e, "treasury_withdrawal_proposed"), id),
-            (recipient, amount, proposer),
+            (recipient, amount, proposer, category),
         );
         id
     }
@@ -355,6 +888,9 @@ impl CredenceTreasury {
         if proposal.executed {
             panic!("proposal already executed");
         }
+        if Self::is_frozen(e.clone()) {
+            panic!("treasury frozen");
+        }
         let threshold: u32 = e.storage().instance().get(&DataKey::Threshold).unwrap_or(0);
         let approvals: u32 = e
             .storage()
@@ -364,6 +900,21 @@ impl CredenceTreasury {
         if approvals < threshold {
             panic!("insufficient approvals to execute");
         }
+        let spent = Self::get_spend_by_category(e.clone(), proposal.category);
+        let new_spent = spent
+            .checked_add(proposal.amount)
+            .expect("category spend overflow");
+        if let Some(cap) = Self::get_spend_cap(e.clone(), proposal.category) {
+            // Spending beyond the category budget needs every signer, not just the threshold.
+            let signer_count: u32 = e
+                .storage()
+                .instance()
+                .get(&DataKey::SignerCount)
+                .unwrap_or(0);
+            if new_spent > cap && approvals < signer_count {
+                panic!("category cap exceeded: all signers must approve");
+            }
+        }
         let total: i128 = e
             .storage()
             .instance()
@@ -382,10 +933,263 @@ impl CredenceTreasury {
         e.storage()
             .instance()
             .set(&DataKey::Proposal(proposal_id), &proposal);
+        e.storage()
+            .instance()
+            .set(&DataKey::SpendByCategory(proposal.category), &new_spent);
         e.events().publish(
             (Symbol::new(&e, "treasury_withdrawal_executed"), proposal_id),
             (proposal.recipient.clone(), proposal.amount),
         );
+        e.events().publish(
+            (
+                Symbol::new(&e, "treasury_spend_recorded"),
+                proposal.category,
+            ),
+            (proposal_id, proposal.amount, new_spent),
+        );
+    }
+
+    /// Emergency freeze of withdrawal execution. Any single signer can freeze immediately on
+    /// suspected compromise; proposals and approvals keep working. `reason` is an
+    /// operator-defined code carried in the event.
+    pub fn freeze(e: Env, signer: Address, reason: u32) {
+        signer.require_auth();
+        if !Self::is_signer(e.clone(), signer.clone()) {
+            panic!("only signer can freeze");
+        }
+        if Self::is_frozen(e.clone()) {
+            panic!("treasury already frozen");
+        }
+        let epoch = Self::get_freeze_epoch(&e)
+            .checked_add(1)
+            .expect("freeze epoch overflow");
+        e.storage().instance().set(&DataKey::FreezeEpoch, &epoch);
+        e.storage().instance().set(&DataKey::Frozen, &true);
+        e.events()
+            .publish((Symbol::new(&e, "treasury_frozen"), signer), reason);
+    }
+
+    /// Approve lifting the freeze. The treasury unfreezes once approvals for the current
+    /// freeze reach the threshold; the approver reaching it is reported as the actor.
+    pub fn approve_unfreeze(e: Env, signer: Address, reason: u32) {
+        signer.require_auth();
+        if !Self::is_signer(e.clone(), signer.clone()) {
+            panic!("only signer can approve");
+        }
+        if !Self::is_frozen(e.clone()) {
+            panic!("treasury not frozen");
+        }
+        let epoch = Self::get_freeze_epoch(&e);
+        let key = DataKey::UnfreezeApproval(epoch, signer.clone());
+        if e.storage().instance().get(&key).unwrap_or(false) {
+            return;
+        }
+        e.storage().instance().set(&key, &true);
+        let count = Self::get_unfreeze_approval_count(e.clone())
+            .checked_add(1)
+            .expect("approval count overflow");
+        e.storage()
+            .instance()
+            .set(&DataKey::UnfreezeApprovalCount(epoch), &count);
+        e.events().publish(
+            (Symbol::new(&e, "unfreeze_approved"), signer.clone()),
+            (reason, count),
+        );
+        let threshold = Self::get_threshold(e.clone());
+        if threshold > 0 && count >= threshold {
+            e.storage().instance().set(&DataKey::Frozen, &false);
+            e.events()
+                .publish((Symbol::new(&e, "treasury_unfrozen"), signer), reason);
+        }
+    }
+
+    /// True while withdrawal execution is frozen.
+    pub fn is_frozen(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::Frozen)
+            .unwrap_or(false)
+    }
+
+    /// Unfreeze approvals collected for the current freeze.
+    pub fn get_unfreeze_approval_count(e: Env) -> u32 {
+        let epoch = Self::get_freeze_epoch(&e);
+        e.storage()
+            .instance()
+            .get(&DataKey::UnfreezeApprovalCount(epoch))
+            .unwrap_or(0)
+    }
+
+    fn get_freeze_epoch(e: &Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::FreezeEpoch)
+            .unwrap_or(0)
+    }
+
+    /// Set the cumulative spend cap of a category. Admin only. Executing a withdrawal that
+    /// takes the category's cumulative spend above the cap requires approval by all signers.
+    pub fn set_spend_cap(e: Env, category: SpendCategory, cap: i128) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        if cap < 0 {
+            panic!("spend cap must be non-negative");
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::SpendCap(category), &cap);
+        e.events()
+            .publish((Symbol::new(&e, "spend_cap_set"), category), cap);
+    }
+
+    /// Remove the spend cap of a category. Admin only.
+    pub fn clear_spend_cap(e: Env, category: SpendCategory) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        e.storage().instance().remove(&DataKey::SpendCap(category));
+        e.events()
+            .publish((Symbol::new(&e, "spend_cap_cleared"), category), ());
+    }
+
+    /// Cumulative spend cap of a category (`None` if uncapped).
+    pub fn get_spend_cap(e: Env, category: SpendCategory) -> Option<i128> {
+        e.storage().instance().get(&DataKey::SpendCap(category))
+    }
+
+    /// Cumulative amount withdrawn under a category.
+    pub fn get_spend_by_category(e: Env, category: SpendCategory) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SpendByCategory(category))
+            .unwrap_or(0)
+    }
+
+    /// Enabled optional subsystems as a bitmask (FEATURE_*). All features if never set.
+    pub fn get_features(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::Features)
+            .unwrap_or(ALL_FEATURES)
+    }
+
+    /// Replace the enabled feature mask. Admin only.
+    pub fn set_features(e: Env, features: u32) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        if features & !ALL_FEATURES != 0 {
+            panic!("unknown feature flag");
+        }
+        e.storage().instance().set(&DataKey::Features, &features);
+        e.events()
+            .publish((Symbol::new(&e, "features_updated"),), features);
+    }
+
+    fn spend_caps(e: &Env) -> Map<SpendCategory, i128> {
+        let mut caps = Map::new(e);
+        for category in [
+            SpendCategory::Operations,
+            SpendCategory::Audits,
+            SpendCategory::ArbitratorRewards,
+            SpendCategory::Grants,
+        ] {
+            if let Some(cap) = Self::get_spend_cap(e.clone(), category) {
+                caps.set(category, cap);
+            }
+        }
+        caps
+    }
+
+    fn has_feature(e: &Env, feature: u32) -> bool {
+        Self::get_features(e.clone()) & feature == feature
+    }
+
+    /// Mark `token` as a token the treasury operates in, excluding it from `recover_token`.
+    /// One-way: an operational token can never be unmarked. Admin only.
+    pub fn add_operational_token(e: Env, token: Address) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        let mut tokens = Self::get_operational_tokens(e.clone());
+        if tokens.contains(&token) {
+            return;
+        }
+        tokens.push_back(token.clone());
+        e.storage()
+            .instance()
+            .set(&DataKey::OperationalTokens, &tokens);
+        e.events()
+            .publish((Symbol::new(&e, "operational_token_added"),), token);
+    }
+
+    /// Tokens excluded from `recover_token`.
+    pub fn get_operational_tokens(e: Env) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&DataKey::OperationalTokens)
+            .unwrap_or(Vec::new(&e))
+    }
+
+    /// Return `amount` of a token sent to the treasury by mistake to `to`. Operational
+    /// tokens are excluded, and recovery is refused until at least one is configured so the
+    /// treasury's own funds can never be swept. Blocked while frozen. Admin only.
+    pub fn recover_token(e: Env, token: Address, to: Address, amount: i128) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        admin.require_auth();
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+        if Self::is_frozen(e.clone()) {
+            panic!("treasury frozen");
+        }
+        let operational = Self::get_operational_tokens(e.clone());
+        if operational.is_empty() {
+            panic!("no operational token configured");
+        }
+        if operational.contains(&token) {
+            panic!("operational token not recoverable");
+        }
+        token::Client::new(&e, &token).transfer(&e.current_contract_address(), &to, &amount);
+        e.events()
+            .publish((Symbol::new(&e, "token_recovered"), token), (to, amount));
+    }
+
+    /// Complete current configuration (admin, multi-sig parameters, feature flags).
+    pub fn export_config(e: Env) -> TreasuryConfig {
+        TreasuryConfig {
+            admin: e.storage().instance().get(&DataKey::Admin),
+            pending_admin: e.storage().instance().get(&DataKey::PendingAdmin),
+            schema_version: Self::get_schema_version(e.clone()),
+            signer_count: e
+                .storage()
+                .instance()
+                .get(&DataKey::SignerCount)
+                .unwrap_or(0),
+            threshold: Self::get_threshold(e.clone()),
+            features: Self::get_features(e.clone()),
+            admin_signer_ops_disabled: Self::is_admin_signer_ops_disabled(e.clone()),
+            spend_caps: Self::spend_caps(&e),
+            operational_tokens: Self::get_operational_tokens(e.clone()),
+            upgrade_approval_required: Self::is_upgrade_approval_required(e.clone()),
+        }
     }
 
     /// Get total treasury balance.
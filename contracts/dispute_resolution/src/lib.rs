@@ -7,8 +7,25 @@
 //! | Key                          | Tier         | Lifecycle      |
 //! |------------------------------|--------------|----------------|
 //! | `DataKey::DisputeCounter`    | `instance()` | Entire contract|
+//! | `DataKey::Admin`             | `instance()` | Entire contract|
+//! | `DataKey::ArbitrationContract`| `instance()`| Entire contract|
+//! | `DataKey::TokenMode`         | `instance()` | Entire contract|
+//! | `DataKey::SchemaVersion`     | `instance()` | Entire contract|
+//! | `DataKey::MigrationCursor`   | `instance()` | During migration|
+//! | `DataKey::Metrics`           | `instance()` | Entire contract|
 //! | `DataKey::Dispute(id)`       | `persistent()`| Per dispute   |
 //! | `DataKey::Vote(id, address)` | `persistent()`| Per vote      |
+//! | `DataKey::Participants(id)`  | `persistent()`| Per dispute   |
+//! | `DataKey::Escalation(id)`    | `persistent()`| Per dispute   |
+//! | `DataKey::EvidencePhase(id)` | `persistent()`| Per dispute   |
+//! | `DataKey::Evidence(id, address)`| `persistent()`| Per party  |
+//! | `DataKey::StakeToken(address)`| `persistent()`| Per token     |
+//! | `DataKey::BlindTally(id)`    | `persistent()`| Per blind dispute|
+//! | `DataKey::BondContract(id)`  | `persistent()`| Per slash dispute|
+//! | `DataKey::ArbitratorConfig`  | `instance()` | Entire contract|
+//! | `DataKey::Arbitrator(address)`| `persistent()`| Per arbitrator|
+//! | `DataKey::VoteWeight(id, address)`| `persistent()`| Per weighted vote|
+//! | `DataKey::StakeTally(id)`    | `persistent()`| Per weighted dispute|
 //!
 //! **Why two tiers?**
 //! `instance()` storage shares the contract's rent TTL and is intended for a
@@ -16,10 +33,77 @@
 //! `persistent()` storage is independently rentable — each dispute and each
 //! vote has its own TTL that can be bumped cheaply, preventing unbounded
 //! growth of the instance footprint.
+//!
+//! ## Escalation
+//!
+//! A dispute whose voting ended in a tie or with fewer than `MIN_PARTICIPATION_VOTES`
+//! votes can be escalated to the weighted `arbitration` contract by paying
+//! `ESCALATION_FEE`. The dispute then defers its outcome to the arbitration result, which is
+//! delivered back through `on_arbitration_resolved`.
+//!
+//! ## Slash Execution
+//!
+//! A bond contract opens a dispute against one of its own pending slashes with
+//! `create_slash_dispute`, which records the bond contract next to the slash proposal id
+//! (`slash_request_id`). When such a dispute resolves, directly or through arbitration, the
+//! dispute contract calls the bond's `resolve_slash_appeal` with the outcome, so a
+//! `FavorDisputer` outcome cancels the slash and `FavorSlasher` executes it without anyone
+//! having to act on the result.
+//!
+//! ## Evidence Phase
+//!
+//! Disputes opened with `create_dispute_with_evidence` have a commit-reveal evidence phase.
+//! The disputer and the respondent commit `sha256(uri || salt)` before the commit deadline
+//! and reveal the URI and salt before the reveal deadline; voting opens only afterwards.
+//! Commitments that were never revealed are disregarded and listed in the dispute record.
+//!
+//! ## Token Adaptor
+//!
+//! Stakes and fees are pulled according to the configured `TokenMode`: `Sep41` (default)
+//! spends an allowance via `transfer_from`, `Sac` uses a `transfer` authorized inline by the
+//! payer, and `Auto` tries the inline transfer before falling back to the allowance.
+//! Refunds are a plain `transfer` in every mode.
+//!
+//! ## Blind Tallies
+//!
+//! Disputes opened with `create_blind_dispute` hide vote directions while voting is open:
+//! the record's tallies stay at zero, votes emit `BlindVoteCast` without a direction, and
+//! `has_voted` only reports participation. The tallies are moved into the record when the
+//! dispute resolves or expires, and `DisputeResolved` then reveals the breakdown. This
+//! hides votes from the contract interface, not from raw ledger state.
+//!
+//! ## Arbitrator Registry
+//!
+//! Once the admin sets an `ArbitratorConfig`, only registered arbitrators can vote.
+//! Arbitrators register by staking at least `min_stake` of the configured token, which the
+//! contract holds in escrow. Votes are weighted by the voter's stake, and the stake-weighted
+//! tally decides `resolve_dispute`. When the winning side holds at least `supermajority_bps`
+//! of the weight, arbitrators who voted the other way lose `slash_bps` of their stake. The
+//! slashed tokens stay in the contract like forfeited dispute stakes. Escalated disputes
+//! are decided by arbitration and slash no one.
+//! `unregister_arbitrator` stops an arbitrator from voting. Its stake becomes withdrawable
+//! after `unbonding_delay` and stays slashable until then.
+//!
+//! Without a config every address may vote with a weight of one. Disputes that receive
+//! votes both before and after the config is set count only the weighted votes.
+//!
+//! ## Token Recovery
+//!
+//! The admin can return tokens sent to the contract by mistake with `recover_token`. Every
+//! token ever used for a stake or fee is excluded: its balance holds escrowed stakes and
+//! forfeited funds that have no other exit.
+//!
+//! ## Metrics
+//!
+//! `get_metrics` returns aggregate counters (disputes, resolutions, expiries, escalations,
+//! votes and total resolution time) that are updated as disputes progress, so dashboards do
+//! not have to index every event. Apart from `total_disputes`, the counters only cover
+//! activity since the contract version that introduced them was deployed.
 
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractevent, contractimpl, contracttype, Address, Env,
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, Address,
+    Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
 };
 
 // ─── TTL constants ────────────────────────────────────────────────────────────
@@ -45,6 +129,45 @@ pub enum DataKey {
     Dispute(u64),
     /// Boolean vote record keyed by (dispute_id, arbitrator). Stored in `persistent()`.
     Vote(u64, Address),
+    /// Contract admin (configures escalation). Stored in `instance()`.
+    Admin,
+    /// Arbitration contract that receives escalations. Stored in `instance()`.
+    ArbitrationContract,
+    /// Escalation link keyed by dispute ID. Stored in `persistent()`.
+    Escalation(u64),
+    /// Evidence phase of a dispute, if it has one. Stored in `persistent()`.
+    EvidencePhase(u64),
+    /// Evidence commitment keyed by (dispute_id, party). Stored in `persistent()`.
+    Evidence(u64, Address),
+    /// How stakes and fees are pulled from payers. Stored in `instance()`.
+    TokenMode,
+    /// Arbitrators who voted on a dispute, in vote order. Stored in `persistent()`.
+    Participants(u64),
+    /// Storage layout version of the stored data. Stored in `instance()`.
+    SchemaVersion,
+    /// Set for every token used for a stake or fee; never recoverable. Stored in `persistent()`.
+    StakeToken(Address),
+    /// Next dispute ID to scan while migrating to schema 2. Stored in `instance()`.
+    MigrationCursor,
+    /// Hidden `(votes_for_disputer, votes_for_slasher)` of a blind dispute until it
+    /// resolves. Stored in `persistent()`.
+    BlindTally(u64),
+    /// Aggregate `DisputeMetrics` counters. Stored in `instance()`.
+    Metrics,
+    /// Bond contract whose pending slash a dispute contests. Stored in `persistent()`.
+    BondContract(u64),
+    /// `ArbitratorConfig`; its presence makes registration mandatory for voting. Stored in
+    /// `instance()`.
+    ArbitratorConfig,
+    /// Registered arbitrator record. Stored in `persistent()`.
+    Arbitrator(Address),
+    /// Stake weight of a vote keyed by (dispute_id, arbitrator). Stored in `persistent()`.
+    VoteWeight(u64, Address),
+    /// Stake-weighted `(weight_for_disputer, weight_for_slasher)` of a dispute. Stored in
+    /// `persistent()`.
+    StakeTally(u64),
+    /// Enabled feature bitmask (`FEATURE_*`). Stored in `instance()`.
+    Features,
 }
 
 // ─── Domain types ─────────────────────────────────────────────────────────────
@@ -56,6 +179,20 @@ pub enum DisputeStatus {
     Resolved,
     Rejected,
     Expired,
+    /// Deferred to the arbitration contract; awaiting its outcome.
+    Escalated,
+}
+
+/// How tokens are pulled from a payer (disputer or escalating caller).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TokenMode {
+    /// Try an inline `transfer`, fall back to `transfer_from`.
+    Auto = 0,
+    /// Stellar Asset Contract: inline `transfer` authorized by the payer only.
+    Sac = 1,
+    /// Generic SEP-41 token: `transfer_from` against a prior allowance only (default).
+    Sep41 = 2,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -66,18 +203,41 @@ pub enum DisputeOutcome {
     FavorSlasher,
 }
 
+/// Contract errors. Codes are allocated from the dispute range (`credence_common::errors::DISPUTE`,
+/// 500-599) of the shared Credence error namespace.
 #[contracterror]
 #[derive(Clone, Debug, PartialEq)]
 pub enum Error {
-    DisputeNotFound = 1,
-    AlreadyVoted = 2,
-    DisputeNotOpen = 3,
-    DeadlineNotReached = 4,
-    DeadlineExpired = 5,
-    Unauthorized = 6,
-    InsufficientStake = 7,
-    InvalidDeadline = 8,
-    TransferFailed = 9,
+    DisputeNotFound = 501,
+    AlreadyVoted = 502,
+    DisputeNotOpen = 503,
+    DeadlineNotReached = 504,
+    DeadlineExpired = 505,
+    Unauthorized = 506,
+    InsufficientStake = 507,
+    InvalidDeadline = 508,
+    TransferFailed = 509,
+    NotEscalatable = 510,
+    EscalationNotConfigured = 511,
+    NotEscalated = 512,
+    AlreadyInitialized = 513,
+    EvidencePhaseActive = 514,
+    OutsideEvidenceWindow = 515,
+    EvidenceHashMismatch = 516,
+    AlreadyCommitted = 517,
+    NoCommitment = 518,
+    InvalidEvidencePhase = 519,
+    SchemaVersionMismatch = 520,
+    SchemaVersionUnsupported = 521,
+    InvalidAmount = 522,
+    TokenNotRecoverable = 523,
+    NotArbitrator = 524,
+    AlreadyRegistered = 525,
+    ArbitratorsNotConfigured = 526,
+    StakeLocked = 527,
+    InvalidArbitratorConfig = 528,
+    UnknownFeature = 529,
+    FeatureDisabled = 530,
 }
 
 // ─── Events ───────────────────────────────────────────────────────────────────
@@ -92,6 +252,30 @@ pub struct DisputeCreated {
     pub deadline: u64,
 }
 
+/// A dispute was opened against a bond contract's pending slash.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashDisputeLinked {
+    pub dispute_id: u64,
+    pub bond_contract: Address,
+    pub slash_request_id: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaMigrated {
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenRecovered {
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VoteCast {
@@ -100,6 +284,14 @@ pub struct VoteCast {
     pub favor_disputer: bool,
 }
 
+/// Vote on a blind dispute; the direction is withheld until resolution.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlindVoteCast {
+    pub dispute_id: u64,
+    pub arbitrator: Address,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, PartialEq)]
 pub struct DisputeResolved {
@@ -116,6 +308,67 @@ pub struct DisputeExpired {
     pub expired_at: u64,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeEscalated {
+    pub dispute_id: u64,
+    pub escalated_by: Address,
+    pub arbitration_dispute_id: u64,
+    pub fee: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvidenceCommitted {
+    pub dispute_id: u64,
+    pub party: Address,
+    pub hash: BytesN<32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvidenceRevealed {
+    pub dispute_id: u64,
+    pub party: Address,
+    pub uri: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitratorRegistered {
+    pub arbitrator: Address,
+    pub stake: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitratorUnregistered {
+    pub arbitrator: Address,
+    pub unbonding_at: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitratorStakeWithdrawn {
+    pub arbitrator: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeaturesUpdated {
+    pub features: u32,
+}
+
+/// An arbitrator lost part of its stake for voting against a supermajority.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitratorSlashed {
+    pub dispute_id: u64,
+    pub arbitrator: Address,
+    pub amount: i128,
+}
+
 // ─── Data structures ──────────────────────────────────────────────────────────
 
 /// A single dispute record.
@@ -136,13 +389,181 @@ pub struct Dispute {
     pub votes_for_disputer: u64,
     pub votes_for_slasher: u64,
     pub created_at: u64,
+    /// Parties whose evidence commitment was never revealed (disregarded).
+    pub unrevealed_evidence: Vec<Address>,
+}
+
+/// Evidence phase requested when opening a dispute.
+///
+/// * `respondent` — the slashing side, the only party besides the disputer that may
+///   submit evidence
+/// * `commit_period` — seconds after creation during which commitments are accepted
+/// * `reveal_period` — seconds after the commit deadline during which reveals are accepted
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EvidenceTerms {
+    pub respondent: Address,
+    pub commit_period: u64,
+    pub reveal_period: u64,
+}
+
+/// Commit-reveal evidence phase of a dispute.
+///
+/// Commitments are accepted until `commit_deadline`; reveals in
+/// `(commit_deadline, reveal_deadline]`. Voting opens after `reveal_deadline`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EvidencePhase {
+    pub respondent: Address,
+    pub commit_deadline: u64,
+    pub reveal_deadline: u64,
+    /// Set once unrevealed commitments have been recorded on the dispute.
+    pub closed: bool,
+}
+
+/// A party's evidence commitment: `hash = sha256(uri || salt)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EvidenceCommitment {
+    pub hash: BytesN<32>,
+    pub committed_at: u64,
+    pub revealed: bool,
+    /// Revealed evidence URI (empty until revealed).
+    pub uri: String,
 }
 
+/// Link from a dispute to the arbitration dispute it was escalated to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EscalationLink {
+    pub arbitration: Address,
+    pub arbitration_dispute_id: u64,
+    pub escalated_by: Address,
+    pub fee: i128,
+}
+
+/// Arbitrator staking parameters (see `set_arbitrator_config`).
+///
+/// * `token` — token arbitrators stake; cannot change once set
+/// * `min_stake` — smallest stake accepted by `register_arbitrator`
+/// * `unbonding_delay` — seconds from `unregister_arbitrator` until the stake can be withdrawn
+/// * `slash_bps` — share of its stake an arbitrator loses for voting against a supermajority
+/// * `supermajority_bps` — share of the vote weight the winning side needs for the losing
+///   side to be slashed (above 5_000)
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ArbitratorConfig {
+    pub token: Address,
+    pub min_stake: i128,
+    pub unbonding_delay: u64,
+    pub slash_bps: u32,
+    pub supermajority_bps: u32,
+}
+
+/// A registered arbitrator.
+///
+/// * `stake` — tokens held in escrow; the weight of its votes
+/// * `registered_at` — ledger timestamp of the registration
+/// * `unbonding_at` — when the stake becomes withdrawable after `unregister_arbitrator`
+///   (0 while the arbitrator is active)
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Arbitrator {
+    pub stake: i128,
+    pub registered_at: u64,
+    pub unbonding_at: u64,
+}
+
+/// Complete configuration, for diffing deployments (see `export_config`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DisputeConfig {
+    pub admin: Option<Address>,
+    pub schema_version: u32,
+    pub arbitration_contract: Option<Address>,
+    pub features: u32,
+    pub min_stake: i128,
+    pub escalation_fee: i128,
+    pub min_participation_votes: u64,
+    pub escalation_voting_period: u64,
+    pub token_mode: TokenMode,
+}
+
+/// Aggregate protocol counters (see `get_metrics`).
+///
+/// * `total_disputes` — disputes ever created
+/// * `resolved` / `expired` — disputes that reached `Resolved` / `Expired`, including
+///   escalated disputes resolved through arbitration
+/// * `escalated` — disputes escalated to arbitration
+/// * `votes_cast` — accepted arbitrator votes, blind or not
+/// * `total_resolution_time` — sum of `resolved_at - created_at` over resolved disputes, in
+///   seconds; `average_resolution_time` is this divided by `resolved` (0 if none)
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct DisputeMetrics {
+    pub total_disputes: u64,
+    pub resolved: u64,
+    pub expired: u64,
+    pub escalated: u64,
+    pub votes_cast: u64,
+    pub total_resolution_time: u64,
+    pub average_resolution_time: u64,
+}
+
+// ─── Cross-contract interface ─────────────────────────────────────────────────
+
+/// Subset of the `arbitration` contract used for escalation.
+#[contractclient(name = "ArbitrationClient")]
+pub trait ArbitrationInterface {
+    fn create_escalated_dispute(
+        e: Env,
+        creator: Address,
+        description: String,
+        duration: u64,
+        callback: Address,
+        callback_ref: u64,
+    ) -> u64;
+}
+
+/// `credence_bond`'s `LinkedDisputeOutcome` code passed to its
+/// `resolve_slash_appeal(proposal_id, outcome, authorized_by)` to cancel the disputed slash.
+pub const BOND_FAVOR_DISPUTER: u32 = 1;
+/// `credence_bond` outcome code executing the disputed slash.
+pub const BOND_FAVOR_SLASHER: u32 = 2;
+
+/// Arbitration outcome meaning the disputer wins.
+pub const ARBITRATION_FAVOR_DISPUTER: u32 = 1;
+/// Arbitration outcome meaning the slasher wins.
+pub const ARBITRATION_FAVOR_SLASHER: u32 = 2;
+
 // ─── Constants ────────────────────────────────────────────────────────────────
 
 /// Minimum token amount required to open a dispute.
 pub const MIN_STAKE: i128 = 100;
 
+/// Token amount (in the dispute's token) paid to escalate a dispute to arbitration.
+pub const ESCALATION_FEE: i128 = 50;
+
+/// Disputes with fewer total votes than this fail the participation threshold and may be
+/// escalated.
+pub const MIN_PARTICIPATION_VOTES: u64 = 3;
+
+/// Voting period given to the arbitration contract for an escalated dispute (~3 days).
+pub const ESCALATION_VOTING_PERIOD: u64 = 259_200;
+
+/// Feature flag: disputes require a token stake held in escrow.
+pub const FEATURE_STAKED_DISPUTES: u32 = 1 << 0;
+/// Feature flag: unresolved disputes can be expired after their deadline.
+pub const FEATURE_EXPIRY: u32 = 1 << 1;
+/// Features supported by this contract version, all enabled at `initialize`.
+pub const ALL_FEATURES: u32 = FEATURE_STAKED_DISPUTES | FEATURE_EXPIRY;
+
+/// Storage layout version this build reads and writes (see `migrate`).
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Disputes scanned per `migrate` call by the 1 -> 2 step.
+pub const MIGRATION_BATCH: u64 = 100;
+
 // ─── Contract ─────────────────────────────────────────────────────────────────
 
 #[contract]
@@ -165,6 +586,59 @@ impl DisputeContract {
         Ok(dispute)
     }
 
+    /// Record unrevealed evidence commitments on the dispute once the reveal deadline has
+    /// passed. No-op for disputes without an evidence phase or already settled.
+    ///
+    /// # Errors
+    /// * `EvidencePhaseActive` — the reveal deadline has not passed yet
+    fn settle_evidence_phase(
+        env: &Env,
+        dispute_id: u64,
+        dispute: &mut Dispute,
+    ) -> Result<(), Error> {
+        let key = DataKey::EvidencePhase(dispute_id);
+        let Some(mut phase) = env.storage().persistent().get::<_, EvidencePhase>(&key) else {
+            return Ok(());
+        };
+        if phase.closed {
+            return Ok(());
+        }
+        if env.ledger().timestamp() <= phase.reveal_deadline {
+            return Err(Error::EvidencePhaseActive);
+        }
+        for party in [dispute.disputer.clone(), phase.respondent.clone()] {
+            let commitment: Option<EvidenceCommitment> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Evidence(dispute_id, party.clone()));
+            if commitment.is_some_and(|c| !c.revealed) {
+                dispute.unrevealed_evidence.push_back(party);
+            }
+        }
+        phase.closed = true;
+        env.storage().persistent().set(&key, &phase);
+        Self::save_dispute(env, dispute_id, dispute);
+        Ok(())
+    }
+
+    /// Load the evidence phase of a dispute and check `party` is one of its two sides.
+    fn load_evidence_phase(
+        env: &Env,
+        dispute_id: u64,
+        party: &Address,
+    ) -> Result<EvidencePhase, Error> {
+        let dispute = Self::load_dispute(env, dispute_id)?;
+        let phase: EvidencePhase = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EvidencePhase(dispute_id))
+            .ok_or(Error::InvalidEvidencePhase)?;
+        if *party != dispute.disputer && *party != phase.respondent {
+            return Err(Error::Unauthorized);
+        }
+        Ok(phase)
+    }
+
     /// Persist a `Dispute` back to `persistent()` storage and bump its TTL.
     fn save_dispute(env: &Env, dispute_id: u64, dispute: &Dispute) {
         let key = DataKey::Dispute(dispute_id);
@@ -174,15 +648,235 @@ impl DisputeContract {
             .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
     }
 
+    /// Apply `update` to the stored `DisputeMetrics`.
+    fn update_metrics(env: &Env, update: impl FnOnce(&mut DisputeMetrics)) {
+        let mut metrics: DisputeMetrics = env
+            .storage()
+            .instance()
+            .get(&DataKey::Metrics)
+            .unwrap_or_default();
+        update(&mut metrics);
+        env.storage().instance().set(&DataKey::Metrics, &metrics);
+    }
+
+    /// Count a resolution and its duration since the dispute was created.
+    fn record_resolution(env: &Env, dispute: &Dispute) {
+        let elapsed = env.ledger().timestamp().saturating_sub(dispute.created_at);
+        Self::update_metrics(env, |m| {
+            m.resolved += 1;
+            m.total_resolution_time = m.total_resolution_time.saturating_add(elapsed);
+        });
+    }
+
+    /// Execute or cancel the slash contested by a resolved dispute in its bond contract.
+    /// No-op for disputes not opened with `create_slash_dispute`.
+    fn notify_bond(env: &Env, dispute_id: u64, dispute: &Dispute) {
+        let Some(bond) = Self::get_bond_contract(env.clone(), dispute_id) else {
+            return;
+        };
+        let outcome = match dispute.outcome {
+            DisputeOutcome::FavorDisputer => BOND_FAVOR_DISPUTER,
+            _ => BOND_FAVOR_SLASHER,
+        };
+        // The bond returns the updated pending slash; only the call's success matters here.
+        let args: Vec<Val> = (
+            dispute.slash_request_id,
+            outcome,
+            env.current_contract_address(),
+        )
+            .into_val(env);
+        env.invoke_contract::<Val>(&bond, &Symbol::new(env, "resolve_slash_appeal"), args);
+    }
+
+    /// True if every bit of `feature` is enabled.
+    fn has_feature(env: &Env, feature: u32) -> bool {
+        Self::get_features(env.clone()) & feature == feature
+    }
+
+    /// Move `amount` of `token` from `from` into the contract per the configured
+    /// `TokenMode`. Caller must enforce `from` auth.
+    fn pull_tokens(env: &Env, token: &Address, from: &Address, amount: i128) {
+        Self::mark_stake_token(env, token);
+        let token_client = soroban_sdk::token::Client::new(env, token);
+        let contract_address = env.current_contract_address();
+        match Self::get_token_mode(env.clone()) {
+            TokenMode::Sac => token_client.transfer(from, &contract_address, &amount),
+            TokenMode::Sep41 => {
+                token_client.transfer_from(&contract_address, from, &contract_address, &amount)
+            }
+            TokenMode::Auto => {
+                if token_client
+                    .try_transfer(from, &contract_address, &amount)
+                    .is_err()
+                {
+                    token_client.transfer_from(&contract_address, from, &contract_address, &amount);
+                }
+            }
+        }
+    }
+
+    /// Exclude `token` from `recover_token` for good.
+    fn mark_stake_token(env: &Env, token: &Address) {
+        let key = DataKey::StakeToken(token.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+    }
+
+    /// Mark the tokens of up to `MIGRATION_BATCH` disputes created before stake tokens
+    /// were tracked. Returns true once every dispute has been scanned.
+    fn mark_legacy_stake_tokens(env: &Env) -> bool {
+        let count = Self::get_dispute_count(env.clone());
+        let start: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MigrationCursor)
+            .unwrap_or(1);
+        let end = start.saturating_add(MIGRATION_BATCH).min(count + 1);
+        for dispute_id in start..end {
+            let dispute: Option<Dispute> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Dispute(dispute_id));
+            if let Some(dispute) = dispute {
+                Self::mark_stake_token(env, &dispute.token);
+            }
+        }
+        if end > count {
+            env.storage().instance().remove(&DataKey::MigrationCursor);
+            true
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::MigrationCursor, &end);
+            false
+        }
+    }
+
+    /// Move `amount` of `token` held by the contract to `to`.
+    fn push_tokens(env: &Env, token: &Address, to: &Address, amount: i128) {
+        if amount <= 0 {
+            return;
+        }
+        soroban_sdk::token::Client::new(env, token).transfer(
+            &env.current_contract_address(),
+            to,
+            &amount,
+        );
+    }
+
+    /// Persist an arbitrator record and bump its TTL.
+    fn save_arbitrator(env: &Env, arbitrator: &Address, record: &Arbitrator) {
+        let key = DataKey::Arbitrator(arbitrator.clone());
+        env.storage().persistent().set(&key, record);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+    }
+
+    /// Weight of `arbitrator`'s vote: its stake once the arbitrator registry is configured,
+    /// `None` (one vote per address) otherwise.
+    ///
+    /// # Errors
+    /// * `NotArbitrator` — registry configured and `arbitrator` is not an active arbitrator
+    fn vote_weight(env: &Env, arbitrator: &Address) -> Result<Option<i128>, Error> {
+        if !env.storage().instance().has(&DataKey::ArbitratorConfig) {
+            return Ok(None);
+        }
+        let record =
+            Self::get_arbitrator(env.clone(), arbitrator.clone()).ok_or(Error::NotArbitrator)?;
+        if record.unbonding_at != 0 {
+            return Err(Error::NotArbitrator);
+        }
+        Ok(Some(record.stake))
+    }
+
+    /// Stake-weighted `(for_disputer, for_slasher)` of a dispute, if it received weighted
+    /// votes.
+    fn stake_tally(env: &Env, dispute_id: u64) -> Option<(i128, i128)> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StakeTally(dispute_id))
+    }
+
+    /// Whether the votes favour the disputer: by stake weight if the dispute received
+    /// weighted votes, by head count otherwise. Ties favour the slasher.
+    fn disputer_wins(env: &Env, dispute_id: u64, dispute: &Dispute) -> bool {
+        match Self::stake_tally(env, dispute_id) {
+            Some((for_disputer, for_slasher)) => for_disputer > for_slasher,
+            None => dispute.votes_for_disputer > dispute.votes_for_slasher,
+        }
+    }
+
+    /// Whether the vote is tied: by stake weight if weighted, by head count otherwise.
+    fn is_tied(env: &Env, dispute_id: u64, for_disputer: u64, for_slasher: u64) -> bool {
+        match Self::stake_tally(env, dispute_id) {
+            Some((weight_disputer, weight_slasher)) => weight_disputer == weight_slasher,
+            None => for_disputer == for_slasher,
+        }
+    }
+
+    /// Slash the weighted voters on the losing side of a resolved dispute when the winning
+    /// side holds at least `supermajority_bps` of the weight. No-op for unweighted disputes.
+    fn slash_minority(env: &Env, dispute_id: u64, outcome: &DisputeOutcome) {
+        let Some((for_disputer, for_slasher)) = Self::stake_tally(env, dispute_id) else {
+            return;
+        };
+        let Some(config) = Self::get_arbitrator_config(env.clone()) else {
+            return;
+        };
+        let favor_disputer = *outcome == DisputeOutcome::FavorDisputer;
+        let winning = if favor_disputer {
+            for_disputer
+        } else {
+            for_slasher
+        };
+        let total = for_disputer.saturating_add(for_slasher);
+        if total == 0
+            || winning.saturating_mul(10_000)
+                < total.saturating_mul(config.supermajority_bps as i128)
+        {
+            return;
+        }
+        let storage = env.storage().persistent();
+        for arbitrator in Self::get_participants(env.clone(), dispute_id).iter() {
+            if !storage.has(&DataKey::VoteWeight(dispute_id, arbitrator.clone())) {
+                continue;
+            }
+            let vote: Option<bool> = storage.get(&DataKey::Vote(dispute_id, arbitrator.clone()));
+            if vote != Some(!favor_disputer) {
+                continue;
+            }
+            let Some(mut record) = Self::get_arbitrator(env.clone(), arbitrator.clone()) else {
+                continue;
+            };
+            let amount = record.stake.saturating_mul(config.slash_bps as i128) / 10_000;
+            if amount <= 0 {
+                continue;
+            }
+            record.stake -= amount;
+            Self::save_arbitrator(env, &arbitrator, &record);
+            ArbitratorSlashed {
+                dispute_id,
+                arbitrator,
+                amount,
+            }
+            .publish(env);
+        }
+    }
+
     // ── Public interface ──────────────────────────────────────────────────────
 
     /// Open a new dispute against a slash request.
     ///
     /// The disputer's `stake` is transferred from their account to the contract
-    /// and held until the dispute is resolved or expired.
+    /// and held until the dispute is resolved or expired. With `FEATURE_STAKED_DISPUTES`
+    /// off, disputes are opened without a stake.
     ///
     /// # Errors
-    /// * `InsufficientStake` — `stake < MIN_STAKE`
+    /// * `InsufficientStake` — `stake < MIN_STAKE` while staked disputes are on
+    /// * `InvalidAmount` — non-zero `stake` while staked disputes are off
     /// * `InvalidDeadline` — `resolution_deadline == 0`
     pub fn create_dispute(
         env: Env,
@@ -194,9 +888,13 @@ impl DisputeContract {
     ) -> Result<u64, Error> {
         disputer.require_auth();
 
-        if stake < MIN_STAKE {
+        let staked = Self::has_feature(&env, FEATURE_STAKED_DISPUTES);
+        if staked && stake < MIN_STAKE {
             return Err(Error::InsufficientStake);
         }
+        if !staked && stake != 0 {
+            return Err(Error::InvalidAmount);
+        }
 
         if resolution_deadline == 0 {
             return Err(Error::InvalidDeadline);
@@ -205,10 +903,10 @@ impl DisputeContract {
         let current_time = env.ledger().timestamp();
         let deadline = current_time + resolution_deadline;
 
-        // Transfer stake into the contract — one storage-read-free cross-contract call.
-        let token_client = soroban_sdk::token::Client::new(&env, &token);
-        let contract_address = env.current_contract_address();
-        token_client.transfer_from(&contract_address, &disputer, &contract_address, &stake);
+        // Transfer stake into the contract.
+        if stake > 0 {
+            Self::pull_tokens(&env, &token, &disputer, stake);
+        }
 
         // Increment the global counter (instance storage — always loaded with the contract).
         let counter: u64 = env
@@ -233,6 +931,7 @@ impl DisputeContract {
             votes_for_disputer: 0,
             votes_for_slasher: 0,
             created_at: current_time,
+            unrevealed_evidence: Vec::new(&env),
         };
         Self::save_dispute(&env, dispute_id, &dispute);
 
@@ -248,33 +947,292 @@ impl DisputeContract {
         Ok(dispute_id)
     }
 
-    /// Retrieve a dispute record by ID.
+    /// Open a dispute against a pending slash of `bond_contract`, which must authorize the
+    /// call (the bond contract opens it on the disputer's behalf). The outcome is delivered
+    /// to the bond contract when the dispute resolves (see "Slash Execution").
     ///
-    /// Panics with `"Dispute not found"` if the ID does not exist, preserving
-    /// the original public API contract expected by callers and tests.
-    pub fn get_dispute(env: &Env, dispute_id: u64) -> Dispute {
-        Self::load_dispute(env, dispute_id).expect("Dispute not found")
+    /// # Errors
+    /// * Same as `create_dispute`
+    pub fn create_slash_dispute(
+        env: Env,
+        disputer: Address,
+        bond_contract: Address,
+        slash_request_id: u64,
+        stake: i128,
+        token: Address,
+        resolution_deadline: u64,
+    ) -> Result<u64, Error> {
+        bond_contract.require_auth();
+        let dispute_id = Self::create_dispute(
+            env.clone(),
+            disputer,
+            slash_request_id,
+            stake,
+            token,
+            resolution_deadline,
+        )?;
+        let key = DataKey::BondContract(dispute_id);
+        env.storage().persistent().set(&key, &bond_contract);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        SlashDisputeLinked {
+            dispute_id,
+            bond_contract,
+            slash_request_id,
+        }
+        .publish(&env);
+        Ok(dispute_id)
+    }
+
+    /// Bond contract whose pending slash `dispute_id` contests, if it was opened with
+    /// `create_slash_dispute`.
+    pub fn get_bond_contract(env: Env, dispute_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BondContract(dispute_id))
     }
 
-    /// Cast an arbitrator vote on an open dispute.
+    /// Open a dispute with a commit-reveal evidence phase between the disputer and
+    /// `terms.respondent`. Voting opens once the reveal window closes.
     ///
     /// # Errors
-    /// * `DisputeNotFound` — unknown `dispute_id`
-    /// * `DisputeNotOpen` — dispute is no longer accepting votes
-    /// * `DeadlineExpired` — voting period has closed
-    /// * `AlreadyVoted` — `arbitrator` has already cast a vote on this dispute
-    pub fn cast_vote(
+    /// * Same as `create_dispute`
+    /// * `InvalidEvidencePhase` — periods are zero or reveals would close after the
+    ///   resolution deadline
+    pub fn create_dispute_with_evidence(
         env: Env,
-        arbitrator: Address,
-        dispute_id: u64,
-        favor_disputer: bool,
-    ) -> Result<(), Error> {
-        arbitrator.require_auth();
-
-        // Single persistent-storage read: load-or-error (replaces has() + get()).
-        let mut dispute = Self::load_dispute(&env, dispute_id)?;
-
-        if dispute.status != DisputeStatus::Open {
+        disputer: Address,
+        slash_request_id: u64,
+        stake: i128,
+        token: Address,
+        resolution_deadline: u64,
+        terms: EvidenceTerms,
+    ) -> Result<u64, Error> {
+        if terms.commit_period == 0
+            || terms.reveal_period == 0
+            || terms.commit_period.saturating_add(terms.reveal_period) >= resolution_deadline
+            || terms.respondent == disputer
+        {
+            return Err(Error::InvalidEvidencePhase);
+        }
+        let dispute_id = Self::create_dispute(
+            env.clone(),
+            disputer,
+            slash_request_id,
+            stake,
+            token,
+            resolution_deadline,
+        )?;
+        let now = env.ledger().timestamp();
+        let commit_deadline = now + terms.commit_period;
+        let phase = EvidencePhase {
+            respondent: terms.respondent,
+            commit_deadline,
+            reveal_deadline: commit_deadline + terms.reveal_period,
+            closed: false,
+        };
+        let key = DataKey::EvidencePhase(dispute_id);
+        env.storage().persistent().set(&key, &phase);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        Ok(dispute_id)
+    }
+
+    /// Open a dispute whose vote directions and tallies stay hidden until it resolves
+    /// (see "Blind Tallies").
+    ///
+    /// # Errors
+    /// * Same as `create_dispute`
+    pub fn create_blind_dispute(
+        env: Env,
+        disputer: Address,
+        slash_request_id: u64,
+        stake: i128,
+        token: Address,
+        resolution_deadline: u64,
+    ) -> Result<u64, Error> {
+        let dispute_id = Self::create_dispute(
+            env.clone(),
+            disputer,
+            slash_request_id,
+            stake,
+            token,
+            resolution_deadline,
+        )?;
+        let key = DataKey::BlindTally(dispute_id);
+        env.storage().persistent().set(&key, &(0_u64, 0_u64));
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        Ok(dispute_id)
+    }
+
+    /// Returns `true` if `dispute_id` hides its votes until resolution.
+    pub fn is_blind(env: Env, dispute_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::BlindTally(dispute_id))
+    }
+
+    /// Current `(votes_for_disputer, votes_for_slasher)`, including hidden blind tallies.
+    fn tallies(env: &Env, dispute_id: u64, dispute: &Dispute) -> (u64, u64) {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BlindTally(dispute_id))
+            .unwrap_or((dispute.votes_for_disputer, dispute.votes_for_slasher))
+    }
+
+    /// Move the hidden tallies of a blind dispute into its record. The blind marker stays.
+    fn reveal_tallies(env: &Env, dispute_id: u64, dispute: &mut Dispute) {
+        let key = DataKey::BlindTally(dispute_id);
+        if let Some((for_disputer, for_slasher)) = env.storage().persistent().get(&key) {
+            dispute.votes_for_disputer = for_disputer;
+            dispute.votes_for_slasher = for_slasher;
+        }
+    }
+
+    /// Commit to evidence by its hash, `sha256(uri || salt)`. One commitment per party.
+    ///
+    /// # Errors
+    /// * `InvalidEvidencePhase` — dispute has no evidence phase
+    /// * `Unauthorized` — `party` is neither the disputer nor the respondent
+    /// * `OutsideEvidenceWindow` — the commit deadline has passed
+    /// * `AlreadyCommitted` — `party` already committed
+    pub fn commit_evidence(
+        env: Env,
+        party: Address,
+        dispute_id: u64,
+        hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        party.require_auth();
+        let phase = Self::load_evidence_phase(&env, dispute_id, &party)?;
+        let now = env.ledger().timestamp();
+        if now > phase.commit_deadline {
+            return Err(Error::OutsideEvidenceWindow);
+        }
+        let key = DataKey::Evidence(dispute_id, party.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::AlreadyCommitted);
+        }
+        let commitment = EvidenceCommitment {
+            hash: hash.clone(),
+            committed_at: now,
+            revealed: false,
+            uri: String::from_str(&env, ""),
+        };
+        env.storage().persistent().set(&key, &commitment);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        EvidenceCommitted {
+            dispute_id,
+            party,
+            hash,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Reveal committed evidence after the commit deadline and before the reveal deadline.
+    ///
+    /// # Errors
+    /// * `InvalidEvidencePhase` — dispute has no evidence phase
+    /// * `Unauthorized` — `party` is neither the disputer nor the respondent
+    /// * `OutsideEvidenceWindow` — not within the reveal window
+    /// * `NoCommitment` — `party` has no unrevealed commitment
+    /// * `EvidenceHashMismatch` — `sha256(uri || salt)` differs from the commitment
+    pub fn reveal_evidence(
+        env: Env,
+        party: Address,
+        dispute_id: u64,
+        uri: String,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        party.require_auth();
+        let phase = Self::load_evidence_phase(&env, dispute_id, &party)?;
+        let now = env.ledger().timestamp();
+        if now <= phase.commit_deadline || now > phase.reveal_deadline {
+            return Err(Error::OutsideEvidenceWindow);
+        }
+        let key = DataKey::Evidence(dispute_id, party.clone());
+        let mut commitment: EvidenceCommitment = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::NoCommitment)?;
+        if commitment.revealed {
+            return Err(Error::NoCommitment);
+        }
+        if Self::evidence_hash(&env, &uri, &salt) != commitment.hash {
+            return Err(Error::EvidenceHashMismatch);
+        }
+        commitment.revealed = true;
+        commitment.uri = uri.clone();
+        env.storage().persistent().set(&key, &commitment);
+
+        EvidenceRevealed {
+            dispute_id,
+            party,
+            uri,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Commitment hash for evidence: `sha256(uri || salt)`.
+    pub fn evidence_hash(env: &Env, uri: &String, salt: &BytesN<32>) -> BytesN<32> {
+        let mut preimage: Bytes = uri.to_bytes();
+        preimage.append(&Bytes::from(salt.clone()));
+        env.crypto().sha256(&preimage).into()
+    }
+
+    /// Returns the evidence phase of a dispute, if it has one.
+    pub fn get_evidence_phase(env: Env, dispute_id: u64) -> Option<EvidencePhase> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EvidencePhase(dispute_id))
+    }
+
+    /// Returns a party's evidence commitment on a dispute, if any.
+    pub fn get_evidence(env: Env, dispute_id: u64, party: Address) -> Option<EvidenceCommitment> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Evidence(dispute_id, party))
+    }
+
+    /// Retrieve a dispute record by ID.
+    ///
+    /// Panics with `"Dispute not found"` if the ID does not exist, preserving
+    /// the original public API contract expected by callers and tests.
+    pub fn get_dispute(env: &Env, dispute_id: u64) -> Dispute {
+        Self::load_dispute(env, dispute_id).expect("Dispute not found")
+    }
+
+    /// Cast an arbitrator vote on an open dispute. Once the arbitrator registry is
+    /// configured the vote carries the arbitrator's stake as its weight.
+    ///
+    /// # Errors
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeNotOpen` — dispute is no longer accepting votes
+    /// * `DeadlineExpired` — voting period has closed
+    /// * `AlreadyVoted` — `arbitrator` has already cast a vote on this dispute
+    /// * `NotArbitrator` — the arbitrator registry is configured and `arbitrator` is not an
+    ///   active registered arbitrator
+    pub fn cast_vote(
+        env: Env,
+        arbitrator: Address,
+        dispute_id: u64,
+        favor_disputer: bool,
+    ) -> Result<(), Error> {
+        arbitrator.require_auth();
+
+        // Single persistent-storage read: load-or-error (replaces has() + get()).
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+
+        if dispute.status != DisputeStatus::Open {
             return Err(Error::DisputeNotOpen);
         }
 
@@ -282,6 +1240,8 @@ impl DisputeContract {
             return Err(Error::DeadlineExpired);
         }
 
+        Self::settle_evidence_phase(&env, dispute_id, &mut dispute)?;
+
         let vote_key = DataKey::Vote(dispute_id, arbitrator.clone());
         let vote_storage = env.storage().persistent();
 
@@ -289,10 +1249,55 @@ impl DisputeContract {
             return Err(Error::AlreadyVoted);
         }
 
+        let weight = Self::vote_weight(&env, &arbitrator)?;
+
         // Record the vote in persistent storage with a fresh TTL.
         vote_storage.set(&vote_key, &favor_disputer);
         vote_storage.extend_ttl(&vote_key, BUMP_THRESHOLD, BUMP_TARGET);
 
+        if let Some(weight) = weight {
+            let weight_key = DataKey::VoteWeight(dispute_id, arbitrator.clone());
+            vote_storage.set(&weight_key, &weight);
+            vote_storage.extend_ttl(&weight_key, BUMP_THRESHOLD, BUMP_TARGET);
+            let (for_disputer, for_slasher) = Self::stake_tally(&env, dispute_id).unwrap_or((0, 0));
+            let tally = if favor_disputer {
+                (for_disputer.saturating_add(weight), for_slasher)
+            } else {
+                (for_disputer, for_slasher.saturating_add(weight))
+            };
+            let tally_key = DataKey::StakeTally(dispute_id);
+            vote_storage.set(&tally_key, &tally);
+            vote_storage.extend_ttl(&tally_key, BUMP_THRESHOLD, BUMP_TARGET);
+        }
+
+        // Votes are only accepted before the deadline, so every participant is eligible.
+        let participants_key = DataKey::Participants(dispute_id);
+        let mut participants: Vec<Address> = vote_storage
+            .get(&participants_key)
+            .unwrap_or(Vec::new(&env));
+        participants.push_back(arbitrator.clone());
+        vote_storage.set(&participants_key, &participants);
+        vote_storage.extend_ttl(&participants_key, BUMP_THRESHOLD, BUMP_TARGET);
+        Self::update_metrics(&env, |m| m.votes_cast += 1);
+
+        let blind_key = DataKey::BlindTally(dispute_id);
+        let blind: Option<(u64, u64)> = vote_storage.get(&blind_key);
+        if let Some((for_disputer, for_slasher)) = blind {
+            let tally = if favor_disputer {
+                (for_disputer + 1, for_slasher)
+            } else {
+                (for_disputer, for_slasher + 1)
+            };
+            vote_storage.set(&blind_key, &tally);
+            vote_storage.extend_ttl(&blind_key, BUMP_THRESHOLD, BUMP_TARGET);
+            BlindVoteCast {
+                dispute_id,
+                arbitrator,
+            }
+            .publish(&env);
+            return Ok(());
+        }
+
         if favor_disputer {
             dispute.votes_for_disputer += 1;
         } else {
@@ -314,9 +1319,10 @@ impl DisputeContract {
 
     /// Resolve a dispute after its deadline has passed.
     ///
-    /// Whichever side holds the majority vote wins. On a `FavorDisputer`
-    /// outcome the staked tokens are returned to the disputer; otherwise they
-    /// remain in the contract (forfeited to the slasher side).
+    /// Whichever side holds the majority vote wins, by stake weight for weighted disputes
+    /// (see "Arbitrator Registry"). On a `FavorDisputer` outcome the staked tokens are
+    /// returned to the disputer; otherwise they remain in the contract (forfeited to the
+    /// slasher side). Arbitrators who voted against a supermajority are slashed.
     ///
     /// # Errors
     /// * `DisputeNotFound` — unknown `dispute_id`
@@ -333,11 +1339,11 @@ impl DisputeContract {
             return Err(Error::DeadlineNotReached);
         }
 
-        let token_client = soroban_sdk::token::Client::new(&env, &dispute.token);
-        let contract_address = env.current_contract_address();
+        Self::settle_evidence_phase(&env, dispute_id, &mut dispute)?;
+        Self::reveal_tallies(&env, dispute_id, &mut dispute);
 
-        let outcome = if dispute.votes_for_disputer > dispute.votes_for_slasher {
-            token_client.transfer(&contract_address, &dispute.disputer, &dispute.stake);
+        let outcome = if Self::disputer_wins(&env, dispute_id, &dispute) {
+            Self::push_tokens(&env, &dispute.token, &dispute.disputer, dispute.stake);
             DisputeOutcome::FavorDisputer
         } else {
             DisputeOutcome::FavorSlasher
@@ -345,8 +1351,11 @@ impl DisputeContract {
 
         dispute.status = DisputeStatus::Resolved;
         dispute.outcome = outcome.clone();
+        Self::record_resolution(&env, &dispute);
 
         Self::save_dispute(&env, dispute_id, &dispute);
+        Self::slash_minority(&env, dispute_id, &outcome);
+        Self::notify_bond(&env, dispute_id, &dispute);
 
         DisputeResolved {
             dispute_id,
@@ -366,7 +1375,11 @@ impl DisputeContract {
     /// * `DisputeNotFound` — unknown `dispute_id`
     /// * `DisputeNotOpen` — dispute is already resolved/expired
     /// * `DeadlineNotReached` — deadline has not yet passed
+    /// * `FeatureDisabled` — `FEATURE_EXPIRY` is off
     pub fn expire_dispute(env: Env, dispute_id: u64) -> Result<(), Error> {
+        if !Self::has_feature(&env, FEATURE_EXPIRY) {
+            return Err(Error::FeatureDisabled);
+        }
         let mut dispute = Self::load_dispute(&env, dispute_id)?;
 
         if dispute.status != DisputeStatus::Open {
@@ -377,7 +1390,9 @@ impl DisputeContract {
             return Err(Error::DeadlineNotReached);
         }
 
+        Self::reveal_tallies(&env, dispute_id, &mut dispute);
         dispute.status = DisputeStatus::Expired;
+        Self::update_metrics(&env, |m| m.expired += 1);
 
         Self::save_dispute(&env, dispute_id, &dispute);
 
@@ -390,6 +1405,452 @@ impl DisputeContract {
         Ok(())
     }
 
+    /// Set the admin that configures escalation. Can only be called once.
+    ///
+    /// # Errors
+    /// * `AlreadyInitialized` — an admin is already set
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        env.storage()
+            .instance()
+            .set(&DataKey::Features, &ALL_FEATURES);
+        Ok(())
+    }
+
+    /// Bring stored data from schema `from_version` up to the layout of this build.
+    /// Idempotent: returns the current version without changes once migrated. Admin only.
+    ///
+    /// The 1 -> 2 step marks the stake tokens of existing disputes in batches of
+    /// `MIGRATION_BATCH`; until it completes the call returns 1 and must be repeated.
+    ///
+    /// # Errors
+    /// * `Unauthorized` — contract has no admin
+    /// * `SchemaVersionMismatch` — `from_version` is not the stored version
+    /// * `SchemaVersionUnsupported` — stored data is newer than this build
+    pub fn migrate(env: Env, from_version: u32) -> Result<u32, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        admin.require_auth();
+        let stored = Self::get_schema_version(env.clone());
+        if stored == SCHEMA_VERSION {
+            return Ok(stored);
+        }
+        if from_version != stored {
+            return Err(Error::SchemaVersionMismatch);
+        }
+        if stored > SCHEMA_VERSION {
+            return Err(Error::SchemaVersionUnsupported);
+        }
+        // 0 -> 1: versioning introduced; the layout itself is unchanged.
+        // 1 -> 2: stake tokens of existing disputes become non-recoverable.
+        if !Self::mark_legacy_stake_tokens(&env) {
+            env.storage()
+                .instance()
+                .set(&DataKey::SchemaVersion, &1_u32);
+            return Ok(1);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        SchemaMigrated {
+            from_version: stored,
+            to_version: SCHEMA_VERSION,
+        }
+        .publish(&env);
+        Ok(SCHEMA_VERSION)
+    }
+
+    /// Return `amount` of a token sent to the contract by mistake to `to`. Tokens ever used
+    /// for a stake or fee are excluded. Unavailable until the data is migrated to the
+    /// current schema, so stakes of older disputes are known. Admin only.
+    ///
+    /// # Errors
+    /// * `Unauthorized` — contract has no admin
+    /// * `SchemaVersionMismatch` — stored data is not on the current schema
+    /// * `InvalidAmount` — `amount <= 0`
+    /// * `TokenNotRecoverable` — `token` was used for a stake or fee
+    pub fn recover_token(env: Env, token: Address, to: Address, amount: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        admin.require_auth();
+        if Self::get_schema_version(env.clone()) != SCHEMA_VERSION {
+            return Err(Error::SchemaVersionMismatch);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if Self::is_stake_token(env.clone(), token.clone()) {
+            return Err(Error::TokenNotRecoverable);
+        }
+        Self::push_tokens(&env, &token, &to, amount);
+        TokenRecovered { token, to, amount }.publish(&env);
+        Ok(())
+    }
+
+    /// Returns true if `token` was used for a stake or fee (not recoverable).
+    pub fn is_stake_token(env: Env, token: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StakeToken(token))
+            .unwrap_or(false)
+    }
+
+    /// Storage layout version of the stored data (0 if deployed before versioning).
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(0)
+    }
+
+    /// Set the arbitration contract that receives escalated disputes. Admin only.
+    ///
+    /// # Errors
+    /// * `Unauthorized` — contract has no admin
+    pub fn set_arbitration_contract(env: Env, arbitration: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::ArbitrationContract, &arbitration);
+        Ok(())
+    }
+
+    /// Select how stakes and fees are pulled from payers. Admin only.
+    ///
+    /// # Errors
+    /// * `Unauthorized` — contract has no admin
+    pub fn set_token_mode(env: Env, mode: TokenMode) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::TokenMode, &mode);
+        Ok(())
+    }
+
+    /// Returns the configured token mode (`Sep41` if never set).
+    pub fn get_token_mode(env: Env) -> TokenMode {
+        env.storage()
+            .instance()
+            .get(&DataKey::TokenMode)
+            .unwrap_or(TokenMode::Sep41)
+    }
+
+    /// Escalate a tied or under-participated dispute to the arbitration contract.
+    ///
+    /// Only the parties can escalate: the disputer, or the respondent (the slashing side)
+    /// of a dispute opened with an evidence phase. The caller pays `ESCALATION_FEE` in the
+    /// dispute's token. The dispute moves to `Escalated` and its final outcome is set by
+    /// `on_arbitration_resolved`.
+    ///
+    /// # Errors
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `Unauthorized` — `caller` is neither the disputer nor the respondent
+    /// * `DeadlineNotReached` — voting period is still active
+    /// * `NotEscalatable` — dispute is not open/expired, or had a clear majority with
+    ///   sufficient participation
+    /// * `EscalationNotConfigured` — no arbitration contract set
+    pub fn escalate_dispute(env: Env, caller: Address, dispute_id: u64) -> Result<u64, Error> {
+        caller.require_auth();
+
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+
+        let respondent = env
+            .storage()
+            .persistent()
+            .get::<_, EvidencePhase>(&DataKey::EvidencePhase(dispute_id))
+            .map(|phase| phase.respondent);
+        if caller != dispute.disputer && respondent.as_ref() != Some(&caller) {
+            return Err(Error::Unauthorized);
+        }
+
+        if dispute.status != DisputeStatus::Open && dispute.status != DisputeStatus::Expired {
+            return Err(Error::NotEscalatable);
+        }
+
+        if env.ledger().timestamp() <= dispute.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let (for_disputer, for_slasher) = Self::tallies(&env, dispute_id, &dispute);
+        let total_votes = for_disputer + for_slasher;
+        let is_tie = Self::is_tied(&env, dispute_id, for_disputer, for_slasher);
+        if !is_tie && total_votes >= MIN_PARTICIPATION_VOTES {
+            return Err(Error::NotEscalatable);
+        }
+
+        let arbitration: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::ArbitrationContract)
+            .ok_or(Error::EscalationNotConfigured)?;
+
+        let contract_address = env.current_contract_address();
+        Self::pull_tokens(&env, &dispute.token, &caller, ESCALATION_FEE);
+
+        let arbitration_dispute_id = ArbitrationClient::new(&env, &arbitration)
+            .create_escalated_dispute(
+                &contract_address,
+                &String::from_str(&env, "dispute_resolution escalation"),
+                &ESCALATION_VOTING_PERIOD,
+                &contract_address,
+                &dispute_id,
+            );
+
+        let link = EscalationLink {
+            arbitration,
+            arbitration_dispute_id,
+            escalated_by: caller.clone(),
+            fee: ESCALATION_FEE,
+        };
+        let key = DataKey::Escalation(dispute_id);
+        env.storage().persistent().set(&key, &link);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        dispute.status = DisputeStatus::Escalated;
+        Self::update_metrics(&env, |m| m.escalated += 1);
+        Self::save_dispute(&env, dispute_id, &dispute);
+
+        DisputeEscalated {
+            dispute_id,
+            escalated_by: caller,
+            arbitration_dispute_id,
+            fee: ESCALATION_FEE,
+        }
+        .publish(&env);
+
+        Ok(arbitration_dispute_id)
+    }
+
+    /// Callback from the arbitration contract delivering the escalated dispute's outcome.
+    ///
+    /// `arbitration_dispute_id` must be the arbitration dispute this dispute was escalated
+    /// to, so an outcome of an unrelated arbitration dispute naming this one as its
+    /// callback is rejected.
+    ///
+    /// `ARBITRATION_FAVOR_DISPUTER` returns the stake to the disputer; any other outcome
+    /// (including a tie) resolves in favour of the slasher, matching `resolve_dispute`.
+    ///
+    /// # Errors
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `NotEscalated` — dispute is not awaiting an arbitration outcome
+    /// * `Unauthorized` — `arbitration_dispute_id` is not the linked arbitration dispute
+    pub fn on_arbitration_resolved(
+        env: Env,
+        dispute_id: u64,
+        arbitration_dispute_id: u64,
+        outcome: u32,
+    ) -> Result<(), Error> {
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+        let link = Self::get_escalation(env.clone(), dispute_id).ok_or(Error::NotEscalated)?;
+        link.arbitration.require_auth();
+        if arbitration_dispute_id != link.arbitration_dispute_id {
+            return Err(Error::Unauthorized);
+        }
+
+        if dispute.status != DisputeStatus::Escalated {
+            return Err(Error::NotEscalated);
+        }
+        Self::reveal_tallies(&env, dispute_id, &mut dispute);
+
+        let outcome = if outcome == ARBITRATION_FAVOR_DISPUTER {
+            Self::push_tokens(&env, &dispute.token, &dispute.disputer, dispute.stake);
+            DisputeOutcome::FavorDisputer
+        } else {
+            DisputeOutcome::FavorSlasher
+        };
+
+        dispute.status = DisputeStatus::Resolved;
+        dispute.outcome = outcome.clone();
+        Self::record_resolution(&env, &dispute);
+        Self::save_dispute(&env, dispute_id, &dispute);
+        Self::notify_bond(&env, dispute_id, &dispute);
+
+        DisputeResolved {
+            dispute_id,
+            outcome,
+            votes_for_disputer: dispute.votes_for_disputer,
+            votes_for_slasher: dispute.votes_for_slasher,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Configure the arbitrator registry. From then on only registered arbitrators can vote
+    /// and votes are weighted by stake. Admin only.
+    ///
+    /// # Errors
+    /// * `Unauthorized` — contract has no admin
+    /// * `InvalidArbitratorConfig` — `min_stake <= 0`, `slash_bps > 10_000`,
+    ///   `supermajority_bps` not in `(5_000, 10_000]`, or a different token than the one
+    ///   already configured
+    pub fn set_arbitrator_config(env: Env, config: ArbitratorConfig) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        admin.require_auth();
+        if config.min_stake <= 0
+            || config.slash_bps > 10_000
+            || config.supermajority_bps <= 5_000
+            || config.supermajority_bps > 10_000
+        {
+            return Err(Error::InvalidArbitratorConfig);
+        }
+        if Self::get_arbitrator_config(env.clone()).is_some_and(|c| c.token != config.token) {
+            return Err(Error::InvalidArbitratorConfig);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ArbitratorConfig, &config);
+        Ok(())
+    }
+
+    /// Returns the arbitrator registry config, or `None` while every address may vote.
+    pub fn get_arbitrator_config(env: Env) -> Option<ArbitratorConfig> {
+        env.storage().instance().get(&DataKey::ArbitratorConfig)
+    }
+
+    /// Register `arbitrator` by staking `stake` of the configured token, pulled per the
+    /// configured `TokenMode` and held until withdrawn.
+    ///
+    /// # Errors
+    /// * `ArbitratorsNotConfigured` — no arbitrator config set
+    /// * `InsufficientStake` — `stake < min_stake`
+    /// * `AlreadyRegistered` — `arbitrator` is registered or still unbonding
+    pub fn register_arbitrator(env: Env, arbitrator: Address, stake: i128) -> Result<(), Error> {
+        arbitrator.require_auth();
+        let config =
+            Self::get_arbitrator_config(env.clone()).ok_or(Error::ArbitratorsNotConfigured)?;
+        if stake < config.min_stake {
+            return Err(Error::InsufficientStake);
+        }
+        if Self::get_arbitrator(env.clone(), arbitrator.clone()).is_some() {
+            return Err(Error::AlreadyRegistered);
+        }
+        Self::pull_tokens(&env, &config.token, &arbitrator, stake);
+        let record = Arbitrator {
+            stake,
+            registered_at: env.ledger().timestamp(),
+            unbonding_at: 0,
+        };
+        Self::save_arbitrator(&env, &arbitrator, &record);
+        ArbitratorRegistered { arbitrator, stake }.publish(&env);
+        Ok(())
+    }
+
+    /// Stop `arbitrator` from voting and start its unbonding delay. Returns when the stake
+    /// becomes withdrawable. The stake stays slashable until then.
+    ///
+    /// # Errors
+    /// * `NotArbitrator` — `arbitrator` is not an active registered arbitrator
+    pub fn unregister_arbitrator(env: Env, arbitrator: Address) -> Result<u64, Error> {
+        arbitrator.require_auth();
+        let mut record =
+            Self::get_arbitrator(env.clone(), arbitrator.clone()).ok_or(Error::NotArbitrator)?;
+        if record.unbonding_at != 0 {
+            return Err(Error::NotArbitrator);
+        }
+        let delay = Self::get_arbitrator_config(env.clone())
+            .map(|c| c.unbonding_delay)
+            .unwrap_or(0);
+        record.unbonding_at = env.ledger().timestamp().saturating_add(delay);
+        Self::save_arbitrator(&env, &arbitrator, &record);
+        ArbitratorUnregistered {
+            arbitrator,
+            unbonding_at: record.unbonding_at,
+        }
+        .publish(&env);
+        Ok(record.unbonding_at)
+    }
+
+    /// Return an unregistered arbitrator's remaining stake once its unbonding delay has
+    /// passed, and remove its record. Returns the amount paid out.
+    ///
+    /// # Errors
+    /// * `NotArbitrator` — `arbitrator` is not registered
+    /// * `StakeLocked` — not unregistered, or the unbonding delay has not passed
+    pub fn withdraw_arbitrator_stake(env: Env, arbitrator: Address) -> Result<i128, Error> {
+        arbitrator.require_auth();
+        let record =
+            Self::get_arbitrator(env.clone(), arbitrator.clone()).ok_or(Error::NotArbitrator)?;
+        if record.unbonding_at == 0 || env.ledger().timestamp() < record.unbonding_at {
+            return Err(Error::StakeLocked);
+        }
+        let config =
+            Self::get_arbitrator_config(env.clone()).ok_or(Error::ArbitratorsNotConfigured)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Arbitrator(arbitrator.clone()));
+        if record.stake > 0 {
+            Self::push_tokens(&env, &config.token, &arbitrator, record.stake);
+        }
+        ArbitratorStakeWithdrawn {
+            arbitrator,
+            amount: record.stake,
+        }
+        .publish(&env);
+        Ok(record.stake)
+    }
+
+    /// Returns the record of `arbitrator`, if registered or unbonding.
+    pub fn get_arbitrator(env: Env, arbitrator: Address) -> Option<Arbitrator> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Arbitrator(arbitrator))
+    }
+
+    /// Stake-weighted `(for_disputer, for_slasher)` of a dispute; `(0, 0)` for unweighted
+    /// disputes and for blind disputes still open.
+    pub fn get_vote_weights(env: Env, dispute_id: u64) -> (i128, i128) {
+        let hidden = Self::is_blind(env.clone(), dispute_id)
+            && Self::load_dispute(&env, dispute_id).is_ok_and(|d| d.status == DisputeStatus::Open);
+        if hidden {
+            return (0, 0);
+        }
+        Self::stake_tally(&env, dispute_id).unwrap_or((0, 0))
+    }
+
+    /// Returns the escalation link for a dispute, if it was escalated.
+    pub fn get_escalation(env: Env, dispute_id: u64) -> Option<EscalationLink> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Escalation(dispute_id))
+    }
+
+    /// Arbitrators who voted on `dispute_id` before its deadline, in vote order. One read
+    /// for reward eligibility and performance tracking instead of a `has_voted` lookup per
+    /// arbitrator.
+    pub fn get_participants(env: Env, dispute_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Participants(dispute_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
     /// Returns `true` if `arbitrator` has already cast a vote on `dispute_id`.
     pub fn has_voted(env: Env, dispute_id: u64, arbitrator: Address) -> bool {
         env.storage()
@@ -397,6 +1858,65 @@ impl DisputeContract {
             .has(&DataKey::Vote(dispute_id, arbitrator))
     }
 
+    /// Returns the enabled optional subsystems as a bitmask (`FEATURE_*`). All features if
+    /// never set.
+    pub fn get_features(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Features)
+            .unwrap_or(ALL_FEATURES)
+    }
+
+    /// Replace the enabled feature mask. Admin only.
+    ///
+    /// # Errors
+    /// * `Unauthorized` — no admin is set
+    /// * `UnknownFeature` — `features` has a bit outside `ALL_FEATURES`
+    pub fn set_features(env: Env, features: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        admin.require_auth();
+        if features & !ALL_FEATURES != 0 {
+            return Err(Error::UnknownFeature);
+        }
+        env.storage().instance().set(&DataKey::Features, &features);
+        FeaturesUpdated { features }.publish(&env);
+        Ok(())
+    }
+
+    /// Returns the complete current configuration, including compile-time parameters.
+    pub fn export_config(env: Env) -> DisputeConfig {
+        DisputeConfig {
+            admin: env.storage().instance().get(&DataKey::Admin),
+            schema_version: Self::get_schema_version(env.clone()),
+            arbitration_contract: env.storage().instance().get(&DataKey::ArbitrationContract),
+            features: Self::get_features(env.clone()),
+            min_stake: MIN_STAKE,
+            escalation_fee: ESCALATION_FEE,
+            min_participation_votes: MIN_PARTICIPATION_VOTES,
+            escalation_voting_period: ESCALATION_VOTING_PERIOD,
+            token_mode: Self::get_token_mode(env.clone()),
+        }
+    }
+
+    /// Returns aggregate counters for dashboards (see `DisputeMetrics`).
+    pub fn get_metrics(env: Env) -> DisputeMetrics {
+        let mut metrics: DisputeMetrics = env
+            .storage()
+            .instance()
+            .get(&DataKey::Metrics)
+            .unwrap_or_default();
+        metrics.total_disputes = Self::get_dispute_count(env.clone());
+        metrics.average_resolution_time = metrics
+            .total_resolution_time
+            .checked_div(metrics.resolved)
+            .unwrap_or(0);
+        metrics
+    }
+
     /// Returns the total number of disputes ever created (monotonically
     /// increasing; IDs start at 1).
     pub fn get_dispute_count(env: Env) -> u64 {
@@ -2,7 +2,7 @@
 
 use super::*;
 use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{contract, contractimpl, Address, Env};
 
 fn setup_token<'a>(
     env: &'a Env,
@@ -74,7 +74,7 @@ fn test_create_dispute_sets_deadline() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #7)")]
+#[should_panic(expected = "Error(Contract, #507)")]
 fn test_create_dispute_fails_insufficient_stake() {
     let env = Env::default();
     env.mock_all_auths();
@@ -90,7 +90,7 @@ fn test_create_dispute_fails_insufficient_stake() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #8)")]
+#[should_panic(expected = "Error(Contract, #508)")]
 fn test_create_dispute_fails_invalid_deadline() {
     let env = Env::default();
     env.mock_all_auths();
@@ -194,7 +194,7 @@ fn test_cast_vote_favor_slasher() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #2)")]
+#[should_panic(expected = "Error(Contract, #502)")]
 fn test_cast_vote_fails_already_voted() {
     let env = Env::default();
     env.mock_all_auths();
@@ -215,7 +215,7 @@ fn test_cast_vote_fails_already_voted() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #5)")]
+#[should_panic(expected = "Error(Contract, #505)")]
 fn test_cast_vote_fails_after_deadline() {
     let env = Env::default();
     env.mock_all_auths();
@@ -235,7 +235,7 @@ fn test_cast_vote_fails_after_deadline() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #1)")]
+#[should_panic(expected = "Error(Contract, #501)")]
 fn test_cast_vote_fails_dispute_not_found() {
     let env = Env::default();
     env.mock_all_auths();
@@ -296,6 +296,40 @@ fn test_multiple_arbitrators_vote() {
     assert_eq!(dispute.votes_for_slasher, 2);
 }
 
+#[test]
+fn test_get_participants_lists_voters_in_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    assert_eq!(client.get_participants(&dispute_id).len(), 0);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.cast_vote(&first, &dispute_id, &true);
+    client.cast_vote(&second, &dispute_id, &false);
+
+    // Late votes are rejected and never become participants.
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let late = Address::generate(&env);
+    assert_eq!(
+        client.try_cast_vote(&late, &dispute_id, &true),
+        Err(Ok(Error::DeadlineExpired))
+    );
+
+    let participants = client.get_participants(&dispute_id);
+    assert_eq!(participants, Vec::from_array(&env, [first, second]));
+    assert_eq!(client.get_participants(&999).len(), 0);
+}
+
 // ── resolve_dispute ───────────────────────────────────────────────────────────
 
 #[test]
@@ -357,7 +391,7 @@ fn test_resolve_dispute_favor_slasher_stake_forfeited() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #4)")]
+#[should_panic(expected = "Error(Contract, #504)")]
 fn test_resolve_dispute_fails_before_deadline() {
     let env = Env::default();
     env.mock_all_auths();
@@ -376,7 +410,7 @@ fn test_resolve_dispute_fails_before_deadline() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #1)")]
+#[should_panic(expected = "Error(Contract, #501)")]
 fn test_resolve_dispute_fails_not_found() {
     let env = Env::default();
     env.mock_all_auths();
@@ -388,7 +422,7 @@ fn test_resolve_dispute_fails_not_found() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #3)")]
+#[should_panic(expected = "Error(Contract, #503)")]
 fn test_resolve_dispute_fails_already_resolved() {
     let env = Env::default();
     env.mock_all_auths();
@@ -433,7 +467,7 @@ fn test_expire_dispute_success() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #4)")]
+#[should_panic(expected = "Error(Contract, #504)")]
 fn test_expire_dispute_fails_before_deadline() {
     let env = Env::default();
     env.mock_all_auths();
@@ -452,7 +486,7 @@ fn test_expire_dispute_fails_before_deadline() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #1)")]
+#[should_panic(expected = "Error(Contract, #501)")]
 fn test_expire_dispute_fails_not_found() {
     let env = Env::default();
     env.mock_all_auths();
@@ -464,7 +498,7 @@ fn test_expire_dispute_fails_not_found() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #3)")]
+#[should_panic(expected = "Error(Contract, #503)")]
 fn test_expire_already_resolved_dispute_fails() {
     let env = Env::default();
     env.mock_all_auths();
@@ -485,7 +519,7 @@ fn test_expire_already_resolved_dispute_fails() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #3)")]
+#[should_panic(expected = "Error(Contract, #503)")]
 fn test_cannot_vote_on_expired_dispute() {
     let env = Env::default();
     env.mock_all_auths();
@@ -525,3 +559,1176 @@ fn test_get_dispute_not_found_panics() {
 
     client.get_dispute(&999);
 }
+
+#[test]
+fn test_get_features() {
+    let env = Env::default();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_features(), ALL_FEATURES);
+}
+
+#[test]
+fn test_set_features() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_set_features(&FEATURE_EXPIRY),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.initialize(&Address::generate(&env));
+    assert_eq!(client.get_features(), ALL_FEATURES);
+
+    client.set_features(&FEATURE_EXPIRY);
+    assert_eq!(client.get_features(), FEATURE_EXPIRY);
+    assert_eq!(client.export_config().features, FEATURE_EXPIRY);
+    assert_eq!(
+        client.try_set_features(&(1 << 5)),
+        Err(Ok(Error::UnknownFeature))
+    );
+}
+
+#[test]
+fn test_set_features_requires_admin_auth() {
+    let env = Env::default();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    client.initialize(&Address::generate(&env));
+
+    env.set_auths(&[]);
+    assert!(client.try_set_features(&0).is_err());
+}
+
+#[test]
+fn test_unstaked_disputes_when_staking_disabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+    client.set_features(&FEATURE_EXPIRY);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+
+    assert_eq!(
+        client.try_create_dispute(&disputer, &1, &500, &token_id, &100),
+        Err(Ok(Error::InvalidAmount))
+    );
+    let dispute_id = client.create_dispute(&disputer, &1, &0, &token_id, &100);
+    assert_eq!(client.get_dispute(&dispute_id).stake, 0);
+    assert_eq!(token_client.balance(&disputer), 1000);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&dispute_id);
+    assert_eq!(
+        client.get_dispute(&dispute_id).status,
+        DisputeStatus::Expired
+    );
+}
+
+#[test]
+fn test_expiry_disabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+    client.set_features(&FEATURE_STAKED_DISPUTES);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    assert_eq!(
+        client.try_expire_dispute(&dispute_id),
+        Err(Ok(Error::FeatureDisabled))
+    );
+}
+
+#[test]
+fn test_export_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let config = client.export_config();
+    assert_eq!(config.admin, None);
+    assert_eq!(config.arbitration_contract, None);
+    assert_eq!(config.min_stake, MIN_STAKE);
+
+    let admin = Address::generate(&env);
+    let arbitration = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_arbitration_contract(&arbitration);
+    let config = client.export_config();
+    assert_eq!(config.admin, Some(admin));
+    assert_eq!(config.arbitration_contract, Some(arbitration));
+    assert_eq!(config.features, ALL_FEATURES);
+    assert_eq!(config.escalation_fee, ESCALATION_FEE);
+}
+
+// ── escalation ────────────────────────────────────────────────────────────────
+
+mod mock_arbitration {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String};
+
+    /// Stand-in for the `arbitration` contract: records the escalation and lets tests
+    /// deliver an outcome back to the originating dispute contract.
+    #[contract]
+    pub struct MockArbitration;
+
+    #[contractimpl]
+    impl MockArbitration {
+        pub fn create_escalated_dispute(
+            e: Env,
+            creator: Address,
+            _description: String,
+            _duration: u64,
+            callback: Address,
+            callback_ref: u64,
+        ) -> u64 {
+            creator.require_auth();
+            e.storage()
+                .instance()
+                .set(&symbol_short!("cb"), &(callback, callback_ref));
+            7
+        }
+
+        pub fn resolve(e: Env, outcome: u32) {
+            Self::resolve_dispute(e, 7, outcome);
+        }
+
+        /// Deliver `outcome` as if arbitration dispute `dispute_id` had resolved.
+        pub fn resolve_dispute(e: Env, dispute_id: u64, outcome: u32) {
+            let (callback, callback_ref): (Address, u64) =
+                e.storage().instance().get(&symbol_short!("cb")).unwrap();
+            super::DisputeContractClient::new(&e, &callback).on_arbitration_resolved(
+                &callback_ref,
+                &dispute_id,
+                &outcome,
+            );
+        }
+    }
+}
+
+fn setup_escalation(
+    env: &Env,
+) -> (
+    DisputeContractClient<'_>,
+    mock_arbitration::MockArbitrationClient<'_>,
+    Address,
+    soroban_sdk::token::Client<'_>,
+    u64,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(env, &contract_id);
+    let arbitration_id = env.register(mock_arbitration::MockArbitration, ());
+    let arbitration = mock_arbitration::MockArbitrationClient::new(env, &arbitration_id);
+
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    client.set_arbitration_contract(&arbitration_id);
+
+    let disputer = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let (token_id, _, token_client) = setup_token(env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &1000, &1000);
+
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    (client, arbitration, disputer, token_client, dispute_id)
+}
+
+#[test]
+fn test_escalate_tied_dispute() {
+    let env = Env::default();
+    let (client, _arbitration, disputer, token_client, dispute_id) = setup_escalation(&env);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let arbitration_dispute_id = client.escalate_dispute(&disputer, &dispute_id);
+    assert_eq!(arbitration_dispute_id, 7);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Escalated);
+    let link = client.get_escalation(&dispute_id).unwrap();
+    assert_eq!(link.arbitration_dispute_id, 7);
+    assert_eq!(link.escalated_by, disputer);
+    assert_eq!(link.fee, ESCALATION_FEE);
+    assert_eq!(token_client.balance(&disputer), 1000 - 500 - ESCALATION_FEE);
+}
+
+#[test]
+fn test_arbitration_outcome_favor_disputer_returns_stake() {
+    let env = Env::default();
+    let (client, arbitration, disputer, token_client, dispute_id) = setup_escalation(&env);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.escalate_dispute(&disputer, &dispute_id);
+    arbitration.resolve(&ARBITRATION_FAVOR_DISPUTER);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Resolved);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorDisputer);
+    assert_eq!(token_client.balance(&disputer), 1000 - ESCALATION_FEE);
+}
+
+#[test]
+fn test_arbitration_tie_favors_slasher() {
+    let env = Env::default();
+    let (client, arbitration, disputer, _token_client, dispute_id) = setup_escalation(&env);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.escalate_dispute(&disputer, &dispute_id);
+    arbitration.resolve(&0);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorSlasher);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #510)")]
+fn test_escalate_clear_majority_rejected() {
+    let env = Env::default();
+    let (client, _arbitration, disputer, _token_client, dispute_id) = setup_escalation(&env);
+
+    for _ in 0..3 {
+        client.cast_vote(&Address::generate(&env), &dispute_id, &true);
+    }
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.escalate_dispute(&disputer, &dispute_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #504)")]
+fn test_escalate_before_deadline_rejected() {
+    let env = Env::default();
+    let (client, _arbitration, disputer, _token_client, dispute_id) = setup_escalation(&env);
+
+    client.escalate_dispute(&disputer, &dispute_id);
+}
+
+#[test]
+fn test_escalate_by_non_party_rejected() {
+    let env = Env::default();
+    let (client, _arbitration, _disputer, _token_client, dispute_id) = setup_escalation(&env);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    assert_eq!(
+        client.try_escalate_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::Open);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #512)")]
+fn test_callback_without_escalation_rejected() {
+    let env = Env::default();
+    let (client, _arbitration, _disputer, _token_client, dispute_id) = setup_escalation(&env);
+
+    client.on_arbitration_resolved(&dispute_id, &7, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #506)")]
+fn test_callback_for_other_arbitration_dispute_rejected() {
+    let env = Env::default();
+    let (client, arbitration, disputer, _token_client, dispute_id) = setup_escalation(&env);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.escalate_dispute(&disputer, &dispute_id);
+    arbitration.resolve_dispute(&8, &ARBITRATION_FAVOR_DISPUTER);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #511)")]
+fn test_escalate_without_arbitration_configured() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &1000, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.escalate_dispute(&disputer, &dispute_id);
+}
+
+// ── evidence phase ────────────────────────────────────────────────────────────
+
+fn setup_evidence(env: &Env) -> (DisputeContractClient<'_>, Address, Address, u64) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(env, &contract_id);
+
+    let disputer = Address::generate(env);
+    let respondent = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let (token_id, _, token_client) = setup_token(env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+
+    // Commits until +100, reveals until +200, voting until +1000.
+    let terms = EvidenceTerms {
+        respondent: respondent.clone(),
+        commit_period: 100,
+        reveal_period: 100,
+    };
+    let dispute_id =
+        client.create_dispute_with_evidence(&disputer, &1, &500, &token_id, &1000, &terms);
+    (client, disputer, respondent, dispute_id)
+}
+
+fn commit(
+    env: &Env,
+    client: &DisputeContractClient<'_>,
+    party: &Address,
+    dispute_id: u64,
+    uri: &str,
+) -> (soroban_sdk::String, BytesN<32>) {
+    let uri = soroban_sdk::String::from_str(env, uri);
+    let salt = BytesN::from_array(env, &[7; 32]);
+    let hash = DisputeContract::evidence_hash(env, &uri, &salt);
+    client.commit_evidence(party, &dispute_id, &hash);
+    (uri, salt)
+}
+
+#[test]
+fn test_evidence_commit_reveal_then_vote() {
+    let env = Env::default();
+    let (client, disputer, respondent, dispute_id) = setup_evidence(&env);
+    let start = env.ledger().timestamp();
+
+    let (uri_d, salt_d) = commit(&env, &client, &disputer, dispute_id, "ipfs://disputer");
+    let (uri_r, salt_r) = commit(&env, &client, &respondent, dispute_id, "ipfs://respondent");
+
+    env.ledger().set_timestamp(start + 150);
+    client.reveal_evidence(&disputer, &dispute_id, &uri_d, &salt_d);
+    client.reveal_evidence(&respondent, &dispute_id, &uri_r, &salt_r);
+    let evidence = client.get_evidence(&dispute_id, &disputer).unwrap();
+    assert!(evidence.revealed);
+    assert_eq!(evidence.uri, uri_d);
+
+    env.ledger().set_timestamp(start + 201);
+    client.cast_vote(&Address::generate(&env), &dispute_id, &true);
+    assert!(client.get_evidence_phase(&dispute_id).unwrap().closed);
+    assert_eq!(client.get_dispute(&dispute_id).unrevealed_evidence.len(), 0);
+}
+
+#[test]
+fn test_unrevealed_commitment_noted() {
+    let env = Env::default();
+    let (client, disputer, respondent, dispute_id) = setup_evidence(&env);
+    let start = env.ledger().timestamp();
+
+    let (uri, salt) = commit(&env, &client, &disputer, dispute_id, "ipfs://disputer");
+    commit(&env, &client, &respondent, dispute_id, "ipfs://respondent");
+    env.ledger().set_timestamp(start + 150);
+    client.reveal_evidence(&disputer, &dispute_id, &uri, &salt);
+
+    env.ledger().set_timestamp(start + 201);
+    client.cast_vote(&Address::generate(&env), &dispute_id, &false);
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(
+        dispute.unrevealed_evidence,
+        soroban_sdk::vec![&env, respondent]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #514)")]
+fn test_vote_during_evidence_phase_rejected() {
+    let env = Env::default();
+    let (client, _disputer, _respondent, dispute_id) = setup_evidence(&env);
+    client.cast_vote(&Address::generate(&env), &dispute_id, &true);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #516)")]
+fn test_reveal_hash_mismatch_rejected() {
+    let env = Env::default();
+    let (client, disputer, _respondent, dispute_id) = setup_evidence(&env);
+    let (_uri, salt) = commit(&env, &client, &disputer, dispute_id, "ipfs://disputer");
+    env.ledger().set_timestamp(env.ledger().timestamp() + 150);
+    let forged = soroban_sdk::String::from_str(&env, "ipfs://forged");
+    client.reveal_evidence(&disputer, &dispute_id, &forged, &salt);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #515)")]
+fn test_reveal_before_commit_deadline_rejected() {
+    let env = Env::default();
+    let (client, disputer, _respondent, dispute_id) = setup_evidence(&env);
+    let (uri, salt) = commit(&env, &client, &disputer, dispute_id, "ipfs://disputer");
+    client.reveal_evidence(&disputer, &dispute_id, &uri, &salt);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #515)")]
+fn test_commit_after_deadline_rejected() {
+    let env = Env::default();
+    let (client, disputer, _respondent, dispute_id) = setup_evidence(&env);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 101);
+    commit(&env, &client, &disputer, dispute_id, "ipfs://late");
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #506)")]
+fn test_third_party_commit_rejected() {
+    let env = Env::default();
+    let (client, _disputer, _respondent, dispute_id) = setup_evidence(&env);
+    commit(
+        &env,
+        &client,
+        &Address::generate(&env),
+        dispute_id,
+        "ipfs://x",
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #517)")]
+fn test_double_commit_rejected() {
+    let env = Env::default();
+    let (client, disputer, _respondent, dispute_id) = setup_evidence(&env);
+    commit(&env, &client, &disputer, dispute_id, "ipfs://a");
+    commit(&env, &client, &disputer, dispute_id, "ipfs://b");
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #519)")]
+fn test_evidence_phase_must_end_before_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let terms = EvidenceTerms {
+        respondent: Address::generate(&env),
+        commit_period: 500,
+        reveal_period: 500,
+    };
+    client.create_dispute_with_evidence(&disputer, &1, &500, &token_id, &1000, &terms);
+}
+
+// ── error namespace ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_error_codes_in_dispute_range() {
+    let codes = [
+        Error::DisputeNotFound,
+        Error::AlreadyVoted,
+        Error::DisputeNotOpen,
+        Error::DeadlineNotReached,
+        Error::DeadlineExpired,
+        Error::Unauthorized,
+        Error::InsufficientStake,
+        Error::InvalidDeadline,
+        Error::TransferFailed,
+        Error::NotEscalatable,
+        Error::EscalationNotConfigured,
+        Error::NotEscalated,
+        Error::AlreadyInitialized,
+        Error::EvidencePhaseActive,
+        Error::OutsideEvidenceWindow,
+        Error::EvidenceHashMismatch,
+        Error::AlreadyCommitted,
+        Error::NoCommitment,
+        Error::InvalidEvidencePhase,
+        Error::SchemaVersionMismatch,
+        Error::SchemaVersionUnsupported,
+        Error::InvalidAmount,
+        Error::TokenNotRecoverable,
+        Error::NotArbitrator,
+        Error::AlreadyRegistered,
+        Error::ArbitratorsNotConfigured,
+        Error::StakeLocked,
+        Error::InvalidArbitratorConfig,
+        Error::UnknownFeature,
+        Error::FeatureDisabled,
+    ];
+    for code in codes {
+        assert!(credence_common::errors::DISPUTE.contains(&(code as u32)));
+    }
+}
+
+// ── token adaptor ─────────────────────────────────────────────────────────────
+
+/// Custom SEP-41 token: `transfer_from` spends a tracked allowance and `transfer` moves
+/// tracked balances; neither is a Stellar Asset Contract.
+#[contract]
+pub struct CustomToken;
+
+#[contractimpl]
+impl CustomToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().instance().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().instance().get(&id).unwrap_or(0)
+    }
+
+    pub fn approve(env: Env, from: Address, spender: Address, amount: i128, _expiration: u32) {
+        env.storage().instance().set(&(from, spender), &amount);
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        Self::move_balance(&env, &from, &to, amount);
+    }
+
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        let key = (from.clone(), spender);
+        let allowance: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        if allowance < amount {
+            panic!("insufficient allowance");
+        }
+        env.storage().instance().set(&key, &(allowance - amount));
+        Self::move_balance(&env, &from, &to, amount);
+    }
+
+    fn move_balance(env: &Env, from: &Address, to: &Address, amount: i128) {
+        let from_balance = Self::balance(env.clone(), from.clone());
+        if from_balance < amount {
+            panic!("insufficient balance");
+        }
+        env.storage().instance().set(from, &(from_balance - amount));
+        let to_balance = Self::balance(env.clone(), to.clone());
+        env.storage().instance().set(to, &(to_balance + amount));
+    }
+}
+
+fn setup_custom_token(env: &Env) -> (DisputeContractClient<'_>, CustomTokenClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    let token = CustomTokenClient::new(env, &env.register(CustomToken, ()));
+    let disputer = Address::generate(env);
+    token.mint(&disputer, &1000);
+    (client, token, disputer)
+}
+
+#[test]
+fn test_token_mode_defaults_to_sep41() {
+    let env = Env::default();
+    let (client, _token, _disputer) = setup_custom_token(&env);
+    assert_eq!(client.get_token_mode(), TokenMode::Sep41);
+    assert_eq!(client.export_config().token_mode, TokenMode::Sep41);
+}
+
+#[test]
+fn test_sep41_mode_spends_custom_token_allowance() {
+    let env = Env::default();
+    let (client, token, disputer) = setup_custom_token(&env);
+    token.approve(&disputer, &client.address, &500, &0);
+    client.create_dispute(&disputer, &1, &500, &token.address, &3600);
+    assert_eq!(token.balance(&client.address), 500);
+    assert_eq!(token.balance(&disputer), 500);
+}
+
+#[test]
+fn test_sep41_mode_without_allowance_fails() {
+    let env = Env::default();
+    let (client, token, disputer) = setup_custom_token(&env);
+    let result = client.try_create_dispute(&disputer, &1, &500, &token.address, &3600);
+    assert!(result.is_err());
+    assert_eq!(token.balance(&disputer), 1000);
+}
+
+#[test]
+fn test_sac_mode_transfers_inline_without_allowance() {
+    let env = Env::default();
+    let (client, token, disputer) = setup_custom_token(&env);
+    client.set_token_mode(&TokenMode::Sac);
+    client.create_dispute(&disputer, &1, &500, &token.address, &3600);
+    assert_eq!(token.balance(&client.address), 500);
+}
+
+#[test]
+fn test_auto_mode_refund_uses_transfer() {
+    let env = Env::default();
+    let (client, token, disputer) = setup_custom_token(&env);
+    client.set_token_mode(&TokenMode::Auto);
+    let id = client.create_dispute(&disputer, &1, &500, &token.address, &3600);
+    let arbitrator = Address::generate(&env);
+    client.cast_vote(&arbitrator, &id, &true);
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+    client.resolve_dispute(&id);
+    assert_eq!(token.balance(&disputer), 1000);
+}
+
+#[test]
+fn test_set_token_mode_without_admin_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register(DisputeContract, ()));
+    assert_eq!(
+        client.try_set_token_mode(&TokenMode::Sac),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+// ── schema versioning ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_schema_version_and_migrate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    assert_eq!(client.try_migrate(&0), Err(Ok(Error::Unauthorized)));
+
+    client.initialize(&Address::generate(&env));
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert_eq!(client.export_config().schema_version, SCHEMA_VERSION);
+
+    // Simulate an instance deployed before versioning.
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 0);
+    assert_eq!(
+        client.try_migrate(&3),
+        Err(Ok(Error::SchemaVersionMismatch))
+    );
+    assert_eq!(client.migrate(&0), SCHEMA_VERSION);
+    assert_eq!(client.migrate(&0), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
+
+// ── token recovery ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_recover_token_excludes_stake_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (stake_token, _, stake_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    stake_client.approve(&disputer, &contract_id, &500, &1000);
+    client.create_dispute(&disputer, &1, &500, &stake_token, &3600);
+    let (stray, _, stray_client) = setup_token(&env, &token_admin, &contract_id, 300);
+
+    let to = Address::generate(&env);
+    assert!(client.is_stake_token(&stake_token));
+    assert_eq!(
+        client.try_recover_token(&stake_token, &to, &100),
+        Err(Ok(Error::TokenNotRecoverable))
+    );
+    assert_eq!(
+        client.try_recover_token(&stray, &to, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    client.recover_token(&stray, &to, &300);
+    assert_eq!(stray_client.balance(&to), 300);
+    assert_eq!(stake_client.balance(&contract_id), 500);
+}
+
+#[test]
+fn test_migration_marks_legacy_stake_tokens_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (first, _, first_client) = setup_token(&env, &token_admin, &disputer, 100_000);
+    let (last, _, last_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    first_client.approve(&disputer, &contract_id, &100_000, &1000);
+    last_client.approve(&disputer, &contract_id, &1000, &1000);
+    for i in 0..MIGRATION_BATCH {
+        client.create_dispute(&disputer, &i, &MIN_STAKE, &first, &3600);
+    }
+    client.create_dispute(&disputer, &0, &MIN_STAKE, &last, &3600);
+
+    // Simulate a schema 1 instance that never tracked stake tokens.
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &1_u32);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::StakeToken(first.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::StakeToken(last.clone()));
+    });
+    let to = Address::generate(&env);
+    assert_eq!(
+        client.try_recover_token(&first, &to, &1),
+        Err(Ok(Error::SchemaVersionMismatch))
+    );
+
+    assert_eq!(client.migrate(&1), 1);
+    assert!(client.is_stake_token(&first));
+    assert!(!client.is_stake_token(&last));
+    assert_eq!(client.migrate(&1), SCHEMA_VERSION);
+    assert!(client.is_stake_token(&last));
+    assert_eq!(
+        client.try_recover_token(&first, &to, &1),
+        Err(Ok(Error::TokenNotRecoverable))
+    );
+}
+
+// ── blind tallies ────────────────────────────────────────────────────────────
+
+fn setup_blind(env: &Env) -> (DisputeContractClient<'_>, Address, u64) {
+    env.mock_all_auths();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(env, &contract_id);
+    let disputer = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let (token_id, _, token_client) = setup_token(env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_blind_dispute(&disputer, &1, &500, &token_id, &100);
+    (client, disputer, dispute_id)
+}
+
+#[test]
+fn test_blind_dispute_hides_tallies_until_resolution() {
+    let env = Env::default();
+    let (client, _disputer, dispute_id) = setup_blind(&env);
+    assert!(client.is_blind(&dispute_id));
+
+    let arbitrator = Address::generate(&env);
+    client.cast_vote(&arbitrator, &dispute_id, &true);
+    client.cast_vote(&Address::generate(&env), &dispute_id, &true);
+    client.cast_vote(&Address::generate(&env), &dispute_id, &false);
+
+    assert!(client.has_voted(&dispute_id, &arbitrator));
+    assert_eq!(client.get_participants(&dispute_id).len(), 3);
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.votes_for_disputer, 0);
+    assert_eq!(dispute.votes_for_slasher, 0);
+    assert_eq!(
+        client.try_cast_vote(&arbitrator, &dispute_id, &false),
+        Err(Ok(Error::AlreadyVoted))
+    );
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&dispute_id);
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorDisputer);
+    assert_eq!(dispute.votes_for_disputer, 2);
+    assert_eq!(dispute.votes_for_slasher, 1);
+}
+
+#[test]
+fn test_blind_dispute_escalation_uses_hidden_tally() {
+    let env = Env::default();
+    let (client, disputer, dispute_id) = setup_blind(&env);
+    for _ in 0..3 {
+        client.cast_vote(&Address::generate(&env), &dispute_id, &false);
+    }
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    assert_eq!(
+        client.try_escalate_dispute(&disputer, &dispute_id),
+        Err(Ok(Error::NotEscalatable))
+    );
+}
+
+#[test]
+fn test_blind_dispute_expiry_reveals_tallies() {
+    let env = Env::default();
+    let (client, _disputer, dispute_id) = setup_blind(&env);
+    client.cast_vote(&Address::generate(&env), &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&dispute_id);
+    assert_eq!(client.get_dispute(&dispute_id).votes_for_slasher, 1);
+}
+
+// ── metrics ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_metrics_track_resolution_expiry_and_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &1000, &1000);
+    assert_eq!(client.get_metrics(), DisputeMetrics::default());
+
+    let resolved_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    let expired_id = client.create_dispute(&disputer, &2, &500, &token_id, &100);
+    client.cast_vote(&Address::generate(&env), &resolved_id, &true);
+    client.cast_vote(&Address::generate(&env), &resolved_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 250);
+    client.resolve_dispute(&resolved_id);
+    client.expire_dispute(&expired_id);
+
+    let metrics = client.get_metrics();
+    assert_eq!(metrics.total_disputes, 2);
+    assert_eq!(metrics.resolved, 1);
+    assert_eq!(metrics.expired, 1);
+    assert_eq!(metrics.escalated, 0);
+    assert_eq!(metrics.votes_cast, 2);
+    assert_eq!(metrics.total_resolution_time, 250);
+    assert_eq!(metrics.average_resolution_time, 250);
+}
+
+#[test]
+fn test_metrics_count_escalated_resolution() {
+    let env = Env::default();
+    let (client, arbitration, disputer, _token_client, dispute_id) = setup_escalation(&env);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.escalate_dispute(&disputer, &dispute_id);
+    let metrics = client.get_metrics();
+    assert_eq!(metrics.escalated, 1);
+    assert_eq!(metrics.resolved, 0);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 400);
+    arbitration.resolve(&ARBITRATION_FAVOR_SLASHER);
+    let metrics = client.get_metrics();
+    assert_eq!(metrics.resolved, 1);
+    assert_eq!(metrics.total_resolution_time, 600);
+    assert_eq!(metrics.average_resolution_time, 600);
+}
+
+// ── slash execution ───────────────────────────────────────────────────────────
+
+mod mock_bond {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    /// Stand-in for `credence_bond`: records the outcome delivered for a disputed slash.
+    #[contract]
+    pub struct MockBond;
+
+    #[contractimpl]
+    impl MockBond {
+        pub fn resolve_slash_appeal(
+            e: Env,
+            proposal_id: u64,
+            outcome: u32,
+            authorized_by: Address,
+        ) -> u64 {
+            authorized_by.require_auth();
+            e.storage().instance().set(
+                &symbol_short!("res"),
+                &(proposal_id, outcome, authorized_by),
+            );
+            proposal_id
+        }
+
+        pub fn last(e: Env) -> Option<(u64, u32, Address)> {
+            e.storage().instance().get(&symbol_short!("res"))
+        }
+    }
+}
+
+fn setup_slash_dispute(
+    env: &Env,
+) -> (
+    DisputeContractClient<'_>,
+    mock_bond::MockBondClient<'_>,
+    u64,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(env, &contract_id);
+    let bond_id = env.register(mock_bond::MockBond, ());
+
+    let disputer = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let (token_id, _, token_client) = setup_token(env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+
+    let dispute_id = client.create_slash_dispute(&disputer, &bond_id, &4, &500, &token_id, &100);
+    (
+        client,
+        mock_bond::MockBondClient::new(env, &bond_id),
+        dispute_id,
+    )
+}
+
+#[test]
+fn test_create_slash_dispute_links_bond() {
+    let env = Env::default();
+    let (client, bond, dispute_id) = setup_slash_dispute(&env);
+
+    assert_eq!(
+        client.get_bond_contract(&dispute_id),
+        Some(bond.address.clone())
+    );
+    assert_eq!(client.get_dispute(&dispute_id).slash_request_id, 4);
+    assert_eq!(bond.last(), None);
+}
+
+#[test]
+fn test_resolution_cancels_slash_in_bond() {
+    let env = Env::default();
+    let (client, bond, dispute_id) = setup_slash_dispute(&env);
+
+    client.cast_vote(&Address::generate(&env), &dispute_id, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&dispute_id);
+
+    assert_eq!(
+        bond.last(),
+        Some((4, BOND_FAVOR_DISPUTER, client.address.clone()))
+    );
+}
+
+#[test]
+fn test_resolution_executes_slash_in_bond() {
+    let env = Env::default();
+    let (client, bond, dispute_id) = setup_slash_dispute(&env);
+
+    client.cast_vote(&Address::generate(&env), &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&dispute_id);
+
+    assert_eq!(
+        bond.last(),
+        Some((4, BOND_FAVOR_SLASHER, client.address.clone()))
+    );
+}
+
+#[test]
+fn test_plain_dispute_does_not_call_bond() {
+    let env = Env::default();
+    let (client, _arbitration, _disputer, _token_client, dispute_id) = setup_escalation(&env);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&dispute_id);
+    assert_eq!(client.get_bond_contract(&dispute_id), None);
+}
+
+// ── arbitrator registry ──────────────────────────────────────────────────────
+
+fn arbitrator_config(token: &Address) -> ArbitratorConfig {
+    ArbitratorConfig {
+        token: token.clone(),
+        min_stake: 100,
+        unbonding_delay: 500,
+        slash_bps: 1_000,
+        supermajority_bps: 6_667,
+    }
+}
+
+fn setup_registry(
+    env: &Env,
+) -> (
+    DisputeContractClient<'_>,
+    soroban_sdk::token::StellarAssetClient<'_>,
+    soroban_sdk::token::Client<'_>,
+    u64,
+) {
+    env.mock_all_auths();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    client.set_token_mode(&TokenMode::Sac);
+    let disputer = Address::generate(env);
+    let (token_id, token_admin_client, token_client) =
+        setup_token(env, &Address::generate(env), &disputer, 1000);
+    client.set_arbitrator_config(&arbitrator_config(&token_id));
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    (client, token_admin_client, token_client, dispute_id)
+}
+
+fn register_arbitrator(
+    env: &Env,
+    client: &DisputeContractClient<'_>,
+    token_admin_client: &soroban_sdk::token::StellarAssetClient<'_>,
+    stake: i128,
+) -> Address {
+    let arbitrator = Address::generate(env);
+    token_admin_client.mint(&arbitrator, &stake);
+    client.register_arbitrator(&arbitrator, &stake);
+    arbitrator
+}
+
+#[test]
+fn test_registry_requires_registration_to_vote() {
+    let env = Env::default();
+    let (client, token_admin_client, _, dispute_id) = setup_registry(&env);
+    assert_eq!(
+        client.try_cast_vote(&Address::generate(&env), &dispute_id, &true),
+        Err(Ok(Error::NotArbitrator))
+    );
+    let arbitrator = register_arbitrator(&env, &client, &token_admin_client, 100);
+    client.cast_vote(&arbitrator, &dispute_id, &true);
+    assert!(client.has_voted(&dispute_id, &arbitrator));
+}
+
+#[test]
+fn test_register_arbitrator_escrows_stake() {
+    let env = Env::default();
+    let (client, token_admin_client, token_client, _) = setup_registry(&env);
+    let arbitrator = register_arbitrator(&env, &client, &token_admin_client, 300);
+
+    assert_eq!(token_client.balance(&arbitrator), 0);
+    assert_eq!(token_client.balance(&client.address), 800);
+    let record = client.get_arbitrator(&arbitrator).unwrap();
+    assert_eq!(record.stake, 300);
+    assert_eq!(record.unbonding_at, 0);
+
+    token_admin_client.mint(&arbitrator, &300);
+    assert_eq!(
+        client.try_register_arbitrator(&arbitrator, &300),
+        Err(Ok(Error::AlreadyRegistered))
+    );
+    assert_eq!(
+        client.try_register_arbitrator(&Address::generate(&env), &99),
+        Err(Ok(Error::InsufficientStake))
+    );
+}
+
+#[test]
+fn test_stake_weighted_vote_slashes_minority() {
+    let env = Env::default();
+    let (client, token_admin_client, _, dispute_id) = setup_registry(&env);
+    let whale = register_arbitrator(&env, &client, &token_admin_client, 500);
+    let first = register_arbitrator(&env, &client, &token_admin_client, 100);
+    let second = register_arbitrator(&env, &client, &token_admin_client, 100);
+
+    client.cast_vote(&whale, &dispute_id, &false);
+    client.cast_vote(&first, &dispute_id, &true);
+    client.cast_vote(&second, &dispute_id, &true);
+    assert_eq!(client.get_vote_weights(&dispute_id), (200, 500));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&dispute_id);
+
+    // Two of three heads favour the disputer, but the stake weight favours the slasher.
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.votes_for_disputer, 2);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorSlasher);
+    // 500 of 700 is a supermajority, so the minority loses 10%.
+    assert_eq!(client.get_arbitrator(&first).unwrap().stake, 90);
+    assert_eq!(client.get_arbitrator(&second).unwrap().stake, 90);
+    assert_eq!(client.get_arbitrator(&whale).unwrap().stake, 500);
+}
+
+#[test]
+fn test_no_slash_without_supermajority() {
+    let env = Env::default();
+    let (client, token_admin_client, _, dispute_id) = setup_registry(&env);
+    let winner = register_arbitrator(&env, &client, &token_admin_client, 300);
+    let loser = register_arbitrator(&env, &client, &token_admin_client, 200);
+
+    client.cast_vote(&winner, &dispute_id, &true);
+    client.cast_vote(&loser, &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&dispute_id);
+
+    assert_eq!(
+        client.get_dispute(&dispute_id).outcome,
+        DisputeOutcome::FavorDisputer
+    );
+    assert_eq!(client.get_arbitrator(&loser).unwrap().stake, 200);
+}
+
+#[test]
+fn test_unregister_then_withdraw_after_delay() {
+    let env = Env::default();
+    let (client, token_admin_client, token_client, dispute_id) = setup_registry(&env);
+    let arbitrator = register_arbitrator(&env, &client, &token_admin_client, 200);
+
+    let now = env.ledger().timestamp();
+    assert_eq!(client.unregister_arbitrator(&arbitrator), now + 500);
+    assert_eq!(
+        client.try_cast_vote(&arbitrator, &dispute_id, &true),
+        Err(Ok(Error::NotArbitrator))
+    );
+    assert_eq!(
+        client.try_unregister_arbitrator(&arbitrator),
+        Err(Ok(Error::NotArbitrator))
+    );
+    assert_eq!(
+        client.try_withdraw_arbitrator_stake(&arbitrator),
+        Err(Ok(Error::StakeLocked))
+    );
+
+    env.ledger().set_timestamp(now + 500);
+    assert_eq!(client.withdraw_arbitrator_stake(&arbitrator), 200);
+    assert_eq!(token_client.balance(&arbitrator), 200);
+    assert_eq!(client.get_arbitrator(&arbitrator), None);
+}
+
+#[test]
+fn test_withdraw_requires_unregistration() {
+    let env = Env::default();
+    let (client, token_admin_client, _, _) = setup_registry(&env);
+    let arbitrator = register_arbitrator(&env, &client, &token_admin_client, 200);
+    assert_eq!(
+        client.try_withdraw_arbitrator_stake(&arbitrator),
+        Err(Ok(Error::StakeLocked))
+    );
+    assert_eq!(
+        client.try_withdraw_arbitrator_stake(&Address::generate(&env)),
+        Err(Ok(Error::NotArbitrator))
+    );
+}
+
+#[test]
+fn test_register_without_config_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register(DisputeContract, ()));
+    assert_eq!(client.get_arbitrator_config(), None);
+    assert_eq!(
+        client.try_register_arbitrator(&Address::generate(&env), &100),
+        Err(Ok(Error::ArbitratorsNotConfigured))
+    );
+}
+
+#[test]
+fn test_invalid_arbitrator_config_rejected() {
+    let env = Env::default();
+    let (client, _, token_client, _) = setup_registry(&env);
+    let mut config = arbitrator_config(&token_client.address);
+    config.supermajority_bps = 5_000;
+    assert_eq!(
+        client.try_set_arbitrator_config(&config),
+        Err(Ok(Error::InvalidArbitratorConfig))
+    );
+    let other_token = Address::generate(&env);
+    assert_eq!(
+        client.try_set_arbitrator_config(&arbitrator_config(&other_token)),
+        Err(Ok(Error::InvalidArbitratorConfig))
+    );
+    let mut config = arbitrator_config(&token_client.address);
+    config.min_stake = 250;
+    client.set_arbitrator_config(&config);
+    assert_eq!(client.get_arbitrator_config(), Some(config));
+}